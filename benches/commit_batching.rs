@@ -1,12 +1,12 @@
 mod fixtures;
 
 use divan::{Bencher, black_box};
-use fixtures::{RepoWalkFixture, graph_service_fixture, repo_walk_hidden_branches_fixture, repo_walk_linear_fixture, repo_walk_many_refs_fixture, repo_walk_merge_fixture};
+use fixtures::{RepoWalkFixture, graph_service_fixture, repo_walk_hidden_branches_fixture, repo_walk_linear_fixture, repo_walk_many_refs_fixture, repo_walk_merge_fixture, write_commit_graph};
 use guitar::{
-    core::{batcher::Batcher, oids::Oids, walker::Walker},
+    core::{batcher::Batcher, commit_graph::CommitGraph, oids::Oids, walker::Walker},
     git::queries::commits::get_sorted_oids,
 };
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 fn main() {
     divan::main();
@@ -22,14 +22,15 @@ struct CommitBatchFixture {
 
 fn commit_batch_fixture(fixture: RepoWalkFixture) -> CommitBatchFixture {
     let repo = Rc::new(RefCell::new(git2::Repository::open(&fixture.path).unwrap()));
-    let batcher = Batcher::new(repo.clone(), &fixture.hidden_branch_names, &[]).unwrap();
+    let commit_graph = CommitGraph::open(&repo.borrow()).map(Rc::new);
+    let batcher = Batcher::new(repo.clone(), commit_graph, &fixture.hidden_branch_names, &[], &HashMap::new()).unwrap();
     let amount = fixture.amount;
     let expected_commits = fixture.expected_commits;
 
     CommitBatchFixture { _fixture: fixture, batcher, _repo: repo, amount, expected_commits }
 }
 
-fn sorted_oid_pages(fixture: CommitBatchFixture) -> usize {
+fn sorted_oid_pages(fixture: &CommitBatchFixture) -> usize {
     let mut oids = Oids::default();
     let mut sorted = Vec::new();
 
@@ -45,8 +46,8 @@ fn sorted_oid_pages(fixture: CommitBatchFixture) -> usize {
     sorted.len()
 }
 
-fn walker_walk_pages(fixture: RepoWalkFixture, full_walk: bool) -> usize {
-    let mut walker = Walker::new(fixture.path.display().to_string(), fixture.amount, fixture.hidden_branch_names, fixture.include_head_reflog_roots, fixture.graph_lane_limit).unwrap();
+fn walker_walk_pages(fixture: &RepoWalkFixture, full_walk: bool) -> usize {
+    let mut walker = Walker::new(fixture.path.display().to_string(), fixture.amount, fixture.hidden_branch_names.clone(), fixture.include_head_reflog_roots, true, fixture.graph_lane_limit).unwrap();
 
     if full_walk {
         while walker.walk() {}
@@ -71,7 +72,23 @@ fn batcher_walk_linear_history(bencher: Bencher) {
     bencher
         .counter(divan::counter::ItemsCount::new(commits))
         .with_inputs(|| commit_batch_fixture(repo_walk_linear_fixture(commits, amount)))
-        .bench_local_values(|fixture| black_box(sorted_oid_pages(fixture)));
+        .bench_local_refs(|fixture| black_box(sorted_oid_pages(fixture)));
+}
+
+fn with_commit_graph(fixture: RepoWalkFixture) -> RepoWalkFixture {
+    write_commit_graph(&fixture.path);
+    fixture
+}
+
+#[divan::bench(sample_count = 20, sample_size = 1)]
+fn batcher_walk_linear_history_commit_graph(bencher: Bencher) {
+    let commits = 256usize;
+    let amount = 64usize;
+
+    bencher
+        .counter(divan::counter::ItemsCount::new(commits))
+        .with_inputs(|| commit_batch_fixture(with_commit_graph(repo_walk_linear_fixture(commits, amount))))
+        .bench_local_refs(|fixture| black_box(sorted_oid_pages(fixture)));
 }
 
 #[divan::bench(sample_count = 20, sample_size = 1)]
@@ -83,7 +100,7 @@ fn batcher_walk_many_refs(bencher: Bencher) {
     bencher
         .counter(divan::counter::ItemsCount::new(commits.saturating_add(refs)))
         .with_inputs(|| commit_batch_fixture(repo_walk_many_refs_fixture(commits, refs, amount)))
-        .bench_local_values(|fixture| black_box(sorted_oid_pages(fixture)));
+        .bench_local_refs(|fixture| black_box(sorted_oid_pages(fixture)));
 }
 
 #[divan::bench(sample_count = 20, sample_size = 1)]
@@ -96,7 +113,7 @@ fn batcher_walk_hidden_branches(bencher: Bencher) {
     bencher
         .counter(divan::counter::ItemsCount::new(visible_commits.saturating_add(hidden_branches.saturating_mul(hidden_commits))))
         .with_inputs(|| commit_batch_fixture(repo_walk_hidden_branches_fixture(visible_commits, hidden_branches, hidden_commits, amount)))
-        .bench_local_values(|fixture| black_box(sorted_oid_pages(fixture)));
+        .bench_local_refs(|fixture| black_box(sorted_oid_pages(fixture)));
 }
 
 #[divan::bench(sample_count = 20, sample_size = 1)]
@@ -104,7 +121,18 @@ fn walker_first_page_linear_history(bencher: Bencher) {
     let commits = 256usize;
     let amount = 64usize;
 
-    bencher.counter(divan::counter::ItemsCount::new(amount)).with_inputs(|| repo_walk_linear_fixture(commits, amount)).bench_local_values(|fixture| black_box(walker_walk_pages(fixture, false)));
+    bencher.counter(divan::counter::ItemsCount::new(amount)).with_inputs(|| repo_walk_linear_fixture(commits, amount)).bench_local_refs(|fixture| black_box(walker_walk_pages(fixture, false)));
+}
+
+#[divan::bench(sample_count = 20, sample_size = 1)]
+fn walker_first_page_linear_history_commit_graph(bencher: Bencher) {
+    let commits = 256usize;
+    let amount = 64usize;
+
+    bencher
+        .counter(divan::counter::ItemsCount::new(amount))
+        .with_inputs(|| with_commit_graph(repo_walk_linear_fixture(commits, amount)))
+        .bench_local_refs(|fixture| black_box(walker_walk_pages(fixture, false)));
 }
 
 #[divan::bench(sample_count = 20, sample_size = 1)]
@@ -112,7 +140,7 @@ fn walker_full_walk_linear_history(bencher: Bencher) {
     let commits = 256usize;
     let amount = 64usize;
 
-    bencher.counter(divan::counter::ItemsCount::new(commits)).with_inputs(|| repo_walk_linear_fixture(commits, amount)).bench_local_values(|fixture| black_box(walker_walk_pages(fixture, true)));
+    bencher.counter(divan::counter::ItemsCount::new(commits)).with_inputs(|| repo_walk_linear_fixture(commits, amount)).bench_local_refs(|fixture| black_box(walker_walk_pages(fixture, true)));
 }
 
 #[divan::bench(sample_count = 20, sample_size = 1)]
@@ -123,7 +151,18 @@ fn walker_full_walk_merge_heavy(bencher: Bencher) {
     bencher
         .counter(divan::counter::ItemsCount::new(rounds.saturating_mul(3).saturating_add(1)))
         .with_inputs(|| repo_walk_merge_fixture(rounds, amount))
-        .bench_local_values(|fixture| black_box(walker_walk_pages(fixture, true)));
+        .bench_local_refs(|fixture| black_box(walker_walk_pages(fixture, true)));
+}
+
+#[divan::bench(sample_count = 20, sample_size = 1)]
+fn walker_full_walk_merge_heavy_commit_graph(bencher: Bencher) {
+    let rounds = 16usize;
+    let amount = 64usize;
+
+    bencher
+        .counter(divan::counter::ItemsCount::new(rounds.saturating_mul(3).saturating_add(1)))
+        .with_inputs(|| with_commit_graph(repo_walk_merge_fixture(rounds, amount)))
+        .bench_local_refs(|fixture| black_box(walker_walk_pages(fixture, true)));
 }
 
 #[divan::bench(sample_count = 50, sample_size = 10)]
@@ -134,12 +173,12 @@ fn sorted_oid_pages_medium(bencher: Bencher) {
     bencher
         .counter(divan::counter::ItemsCount::new(rounds.saturating_mul(4)))
         .with_inputs(|| commit_batch_fixture(repo_walk_merge_fixture(rounds, amount)))
-        .bench_local_values(|fixture| black_box(sorted_oid_pages(fixture)));
+        .bench_local_refs(|fixture| black_box(sorted_oid_pages(fixture)));
 }
 
 fn walk_all_pages(rounds: usize) -> usize {
     let fixture = graph_service_fixture(rounds);
-    let mut walker = Walker::new(fixture.path.display().to_string(), fixture.amount, fixture.hidden_branch_names, fixture.include_head_reflog_roots, fixture.graph_lane_limit).unwrap();
+    let mut walker = Walker::new(fixture.path.display().to_string(), fixture.amount, fixture.hidden_branch_names, fixture.include_head_reflog_roots, true, fixture.graph_lane_limit).unwrap();
 
     while walker.walk() {}
 
@@ -39,9 +39,11 @@ fn spawn_fixture_service(fixture: &GraphServiceFixture) -> RunningService {
             amount: fixture.amount,
             hidden_branch_names: fixture.hidden_branch_names.clone(),
             include_head_reflog_roots: fixture.include_head_reflog_roots,
+            use_replace_refs: true,
             graph_lane_limit: fixture.graph_lane_limit,
             worktrees: fixture.worktrees.clone(),
             symbols: fixture.symbols.clone(),
+            walk_cache_path: None,
         },
         cmd_rx,
         event_tx,
@@ -68,9 +68,11 @@ fn render_graph_projection_uncommitted_row(bencher: Bencher) {
         branches: Vec::new(),
         tags: Vec::new(),
         is_stash: false,
+        is_shallow: false,
         stash_lane: None,
         worktrees: Vec::new(),
         reflog: None,
+        is_merge: false,
     }];
     bench_render(bencher, &fixture.theme, &fixture.symbols, &rows, &fixture.history, fixture.head_alias, 0, rows.len(), true);
 }
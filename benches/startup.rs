@@ -1,7 +1,7 @@
 mod fixtures;
 
 use divan::{Bencher, black_box, counter::ItemsCount};
-use fixtures::{TempFixture, add_path, commit_file, temp_repo, write_text};
+use fixtures::{TempFixture, add_path, commit_file, temp_repo, write_commit_graph, write_text};
 use guitar::{App, core::graph_service::GraphCommand, git::actions::worktrees::create_worktree};
 use im::HashSet;
 use std::{
@@ -52,47 +52,48 @@ fn startup_fixture(commits: usize, linked_worktrees: usize, dirty_files: usize)
 }
 
 fn reload_app(fixture: &StartupFixture) -> App {
-    let mut app = App { recent_save_path: Some(fixture.recent_path.clone()), ..Default::default() };
-    app.branches.hidden_branch_names = HashSet::new();
+    let config = fixture.path.join(".bench-config");
+    let mut app = App { recent_save_path: Some(fixture.recent_path.clone()), walk_cache_dir: Some(config.join("walks")), ..Default::default() };
+    app.tab.branches.hidden_branch_names = HashSet::new();
     app.reload(Some(fixture.path.display().to_string()));
     app
 }
 
 fn shutdown_app(app: &mut App) {
-    if let Some(cancel) = &app.walker_cancel {
+    if let Some(cancel) = &app.tab.walker_cancel {
         cancel.store(true, std::sync::atomic::Ordering::SeqCst);
     }
-    if let Some(tx) = app.graph_tx.take() {
+    if let Some(tx) = app.tab.graph_tx.take() {
         let _ = tx.send(GraphCommand::Shutdown);
     }
-    if let Some(handle) = app.walker_handle.take() {
+    if let Some(handle) = app.tab.walker_handle.take() {
         let _ = handle.join();
     }
 }
 
 fn reload_startup_components(fixture: StartupFixture) -> usize {
     let mut app = reload_app(&fixture);
-    let loaded = app.repo.is_some() as usize + app.worktrees.entries.len() + app.submodules.entries.len() + app.recent.len() + app.graph_tx.is_some() as usize;
+    let loaded = app.tab.repo.is_some() as usize + app.tab.worktrees.entries.len() + app.tab.submodules.entries.len() + app.recent.len() + app.tab.graph_tx.is_some() as usize;
 
-    assert!(app.repo.is_some());
-    assert_eq!(app.worktrees.entries.len(), fixture.expected_worktrees);
+    assert!(app.tab.repo.is_some());
+    assert_eq!(app.tab.worktrees.entries.len(), fixture.expected_worktrees);
     shutdown_app(&mut app);
     loaded
 }
 
 fn reload_until_first_graph_progress(fixture: StartupFixture) -> usize {
     let mut app = reload_app(&fixture);
-    let repo = app.repo.clone().unwrap();
+    let repo = app.tab.repo.clone().unwrap();
     let deadline = Instant::now() + Duration::from_secs(10);
 
-    while !app.is_uncommitted_loaded && Instant::now() < deadline {
+    while !app.tab.is_uncommitted_loaded && Instant::now() < deadline {
         app.sync(&repo);
         thread::sleep(Duration::from_millis(1));
     }
 
-    assert!(app.is_uncommitted_loaded);
-    assert!(app.uncommitted.staged.added.len() + app.uncommitted.unstaged.added.len() >= fixture.expected_dirty_files);
-    let loaded = app.worktrees.entries.len() + app.uncommitted.staged.added.len() + app.uncommitted.unstaged.added.len();
+    assert!(app.tab.is_uncommitted_loaded);
+    assert!(app.tab.uncommitted.staged.added.len() + app.tab.uncommitted.unstaged.added.len() >= fixture.expected_dirty_files);
+    let loaded = app.tab.worktrees.entries.len() + app.tab.uncommitted.staged.added.len() + app.tab.uncommitted.unstaged.added.len();
     shutdown_app(&mut app);
     loaded
 }
@@ -121,6 +122,22 @@ fn app_reload_until_first_graph_progress(bencher: Bencher) {
         .bench_local_values(|fixture| black_box(reload_until_first_graph_progress(fixture)));
 }
 
+#[divan::bench(sample_count = 20, sample_size = 1)]
+fn app_reload_until_first_graph_progress_commit_graph(bencher: Bencher) {
+    let commits = 96usize;
+    let linked_worktrees = 8usize;
+    let dirty_files = 24usize;
+
+    bencher
+        .counter(ItemsCount::new(commits.saturating_add(linked_worktrees).saturating_add(dirty_files)))
+        .with_inputs(|| {
+            let fixture = startup_fixture(commits, linked_worktrees, dirty_files);
+            write_commit_graph(&fixture.path);
+            fixture
+        })
+        .bench_local_values(|fixture| black_box(reload_until_first_graph_progress(fixture)));
+}
+
 #[divan::bench(sample_count = 50, sample_size = 10)]
 fn app_default_state(bencher: Bencher) {
     bencher.bench_local(|| black_box(App::default()));
@@ -8,16 +8,28 @@ use crate::{
     },
     git::{
         auth::{AuthChallenge, AuthSession, NetworkResult},
-        os::path::try_into_git_repo_root,
-        queries::{diffs::get_filenames_diff_at_oid, files::FileSearchResult, submodules::list_submodules, worktrees::list_worktrees},
+        os::path::{discover_repo_root, open_repo, try_into_git_repo_root},
+        queries::{
+            diffs::get_filenames_diff_at_oid,
+            files::FileSearchResult,
+            grafts::get_replacements,
+            grep::{GrepMatch, GrepScope},
+            submodules::list_submodules,
+            worktrees::list_worktrees,
+        },
     },
     helpers::{
         branch_visibility::{current_branch_names, load_branch_visibility, prune_hidden_branches, save_branch_visibility},
+        custom_commands::{CustomCommand, CustomCommandRun},
         heatmap::{DAYS, WEEKS, empty_heatmap},
+        journal::{load_journal, load_journal_from_path, save_journal, save_journal_to_path},
         keymap::{Command, KeyBinding, KeymapEditError, KeymapSelection},
         layout::LayoutConfig,
         localisation::{Language, errors, load_language, load_language_from_path, modal, operations, save_language, save_language_to_path, set_active_language, settings},
+        marks::{Marks, load_marks, load_marks_from_path},
+        process::ProcessLog,
         recent::{load_recent, save_recent, save_recent_to_path},
+        session::RepositorySession,
         symbols::{SymbolTheme, load_symbol_theme, load_symbol_theme_from_path, save_symbol_theme, save_symbol_theme_to_path},
     },
 };
@@ -28,19 +40,29 @@ use crate::{
     },
     core::{
         branches::Branches,
+        commit_graph::CommitGraph,
+        content_search::ContentSearchTask,
+        diff_service::{DiffCommand, DiffEvent, DiffServiceConfig, FileDiff, spawn_diff_service},
         graph_service::{
             Generation, GraphCommand, GraphEvent, GraphFileHistoryRow, GraphHistory, GraphIndexIdentity, GraphLookupKind, GraphLookupResult, GraphPane, GraphPaneRow, GraphRow, GraphServiceConfig,
             GraphVersion, RequestId, spawn_graph_service,
         },
         oids::Oids,
         tags::Tags,
+        walk_cache::{walk_cache_dir, walk_cache_path},
     },
     git::{
-        actions::network::NetworkRequest,
+        actions::{
+            journal::{JournalEntry, RefState},
+            network::NetworkRequest,
+            patching::PatchMail,
+            resetting::ResetPreview,
+        },
         queries::{
             commits::get_git_user_info,
             diffs::get_filenames_diff_at_workdir,
             helpers::{FileChange, UncommittedChanges},
+            signatures::{SignatureStatus, verify_commit},
         },
     },
     helpers::{colors::ColorPicker, keymap::InputMode, palette::*, spinner::Spinner},
@@ -61,15 +83,15 @@ use ratatui::{
     widgets::{Block, Borders, ListItem},
 };
 use std::{
-    cell::{Cell, RefCell},
-    collections::HashMap,
+    cell::{Cell, OnceCell, RefCell},
+    collections::{HashMap, HashSet, VecDeque},
     io,
     rc::Rc,
     sync::{Arc, atomic::AtomicBool, mpsc::channel},
     thread::JoinHandle,
     time::{Duration, Instant},
 };
-use std::{env, io::stdout, path::PathBuf};
+use std::{io::stdout, path::PathBuf};
 
 #[derive(PartialEq, Eq, Debug)]
 pub enum Viewport {
@@ -97,6 +119,13 @@ pub enum Focus {
     ModalCommit,
     ModalCherrypick,
     ModalRevert,
+    ModalMainline,
+    ModalJournal,
+    ModalReset,
+    ModalExportGraph,
+    ModalExportPatches,
+    ModalApplyPatchPath,
+    ModalApplyPatch,
     ModalCreateBranch,
     ModalRenameBranch,
     ModalCreateWorktreeName,
@@ -104,19 +133,26 @@ pub enum Focus {
     ModalDeleteBranch,
     ModalWorktreeChooser,
     ModalRemoveWorktree,
+    ModalBatch,
     ModalLockWorktree,
     ModalRemoteAction,
     ModalRemoteDelete,
     ModalRemoteName,
     ModalRemoteUrl,
     ModalGraphLaneLimit,
+    ModalViewerFileSizeLimit,
+    ModalDeepen,
     ModalGrep,
     ModalFileSearch,
+    ModalContentSearch,
+    ModalCommandPalette,
     ModalTag,
     ModalDeleteTag,
     ModalKeyCapture,
     ModalAuth,
     ModalNetworkProgress,
+    ModalHookProgress,
+    ModalCustomCommand,
     ModalOperationProgress,
     ModalOperationConflict,
     ModalOperationSuccess,
@@ -140,15 +176,64 @@ impl OperationKind {
             OperationKind::Merge => operations::MERGE(),
         }
     }
+
+    pub fn command(self) -> Command {
+        match self {
+            OperationKind::Rebase => Command::Rebase,
+            OperationKind::Cherrypick => Command::Cherrypick,
+            OperationKind::Revert => Command::Revert,
+            OperationKind::Merge => Command::Merge,
+        }
+    }
+}
+
+// What runs once gating hooks pass; post hooks only hand focus back.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookFollowUp {
+    Commit { message: Option<String> },
+    Rebase { oid: Oid },
+    CommitMerge,
+    Network { request: NetworkRequest, journal: Option<Command> },
+    Return { focus: Focus },
+}
+
+// A custom command on a worker thread; the outcome is kept until its output modal closes.
+pub struct CustomCommandTask {
+    pub name: String,
+    pub log: ProcessLog,
+    pub handle: Option<JoinHandle<Result<(), git2::Error>>>,
+    pub outcome: Option<Result<(), String>>,
+    // The repository it started in and its refs then, to tell whether the graph needs refreshing.
+    pub repo_path: Option<String>,
+    pub refs_before: Option<RefState>,
+}
+
+// Hooks running on a worker thread, with their output shared for the progress modal.
+pub struct HookTask {
+    pub title: String,
+    pub log: ProcessLog,
+    pub handle: JoinHandle<Result<(), git2::Error>>,
+    pub follow_up: HookFollowUp,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum PendingOperationAction {
     Start { kind: OperationKind, oid: Oid },
+    StartSequence { kind: OperationKind },
     Continue,
     Abort,
 }
 
+impl PendingOperationAction {
+    pub fn command(self) -> Command {
+        match self {
+            PendingOperationAction::Start { kind, .. } | PendingOperationAction::StartSequence { kind } => kind.command(),
+            PendingOperationAction::Continue => Command::ContinueOperation,
+            PendingOperationAction::Abort => Command::AbortOperation,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct ViewerLayoutSignature {
     pub graph_width: u16,
@@ -207,9 +292,12 @@ pub struct PaneWindowCache {
 pub enum PendingGraphLookup {
     SelectIndex,
     SelectPaneRow,
+    Jump,
+    VisualRange,
     CacheGraphRow,
     OpenInspector,
     RestoreSelection,
+    RestorePaneRow(GraphPane),
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -228,12 +316,17 @@ pub struct GraphClientCache {
     pub requested_graph: Option<(RequestId, usize, usize)>,
     pub pending_lookup: Option<(RequestId, PendingGraphLookup)>,
     pub pending_selection_restore: Option<GraphSelectionRestore>,
+    // Diff service requests still in flight; an answer for any other request id is stale.
+    pub requested_diff: Option<(RequestId, GraphIndexIdentity)>,
+    pub requested_viewer: Option<RequestId>,
     pub index_rows: HashMap<usize, GraphRow>,
     pub graph_window: Option<GraphWindowCache>,
     pub branches_window: Option<PaneWindowCache>,
     pub tags_window: Option<PaneWindowCache>,
     pub stashes_window: Option<PaneWindowCache>,
     pub reflogs_window: Option<PaneWindowCache>,
+    pub signatures: HashMap<Oid, SignatureStatus>,
+    pub requested_signatures: HashSet<Oid>,
 }
 
 impl GraphClientCache {
@@ -298,6 +391,7 @@ pub enum SettingsSelectionKind {
     KeyBinding(KeymapSelection),
     LayoutCommand(Command),
     GraphLaneLimit,
+    ViewerFileSizeLimit,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -322,6 +416,7 @@ pub enum ContextMenuAction {
     GraphCommand(Command),
     OpenRecentRepository(usize),
     RemoteAction { name: String, index: usize },
+    BranchDrop { branch: String, index: usize, action: BranchDropAction },
     SwitchSettingsTab(SettingsTab),
     Settings,
     Splash,
@@ -420,72 +515,44 @@ pub enum ScrollbarTarget {
     StatusBottom,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct ScrollbarDrag {
-    pub target: ScrollbarTarget,
-    pub grab_offset: usize,
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct SharedMouseDrag {
-    pub layout: LayoutDrag,
-    pub scrollbar: ScrollbarDrag,
-    pub start_column: u16,
-    pub start_row: u16,
-}
-
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum MouseDrag {
-    Layout(LayoutDrag),
-    Scrollbar(ScrollbarDrag),
-    Shared(SharedMouseDrag),
+// Rows marked for a batch action in the side and status panes; the graph keeps its marks in `graph_marked_oids`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PaneMarks {
+    pub branches: Vec<String>,
+    pub tags: Vec<String>,
+    pub stashes: Vec<Oid>,
+    pub staged: Vec<String>,
+    pub unstaged: Vec<String>,
 }
 
+// Visual mode remembers the row it started on; the range runs from there to the cursor.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub enum MouseSelectionTarget {
-    Graph(usize),
-    Viewer(usize),
-    Branches(usize),
-    Tags(usize),
-    Stashes(usize),
-    Reflogs(usize),
-    Worktrees(usize),
-    Submodules(usize),
-    Inspector(usize),
-    StatusTop(usize),
-    StatusBottom(usize),
-    Search(usize),
-    Splash(usize),
-    Settings(usize),
-    SettingsTab(SettingsTab),
+pub struct VisualSelection {
+    pub focus: Focus,
+    pub anchor: usize,
 }
 
-pub struct App {
-    // Global application state and user-facing configuration.
-    pub logo: Vec<Span<'static>>,
+// Everything that belongs to one open repository. The active tab lives in `App::tab`;
+// background tabs park theirs in `App::tabs`, graph service thread and all.
+pub struct RepoTab {
     pub path: Option<String>,
-    pub recent: Vec<String>,
     pub repo: Option<Rc<Repository>>,
-    pub spinner: Spinner,
-    pub keymaps: IndexMap<InputMode, IndexMap<KeyBinding, Command>>,
-    pub mode: InputMode,
-    pub last_input_direction: Option<Direction>,
-    pub theme: Theme,
-    pub symbols: SymbolTheme,
-    pub language: Language,
+    // Set by `--work-tree`/`GIT_WORK_TREE` for repositories whose git dir lives elsewhere.
+    pub work_tree: Option<PathBuf>,
     pub heatmap: [[usize; WEEKS]; DAYS],
 
     // Git identity used when creating commits.
     pub name: String,
     pub email: String,
 
-    // Background history walker and graph rendering helpers.
-    pub color: Rc<RefCell<ColorPicker>>,
+    // Background history walker and diff worker.
     pub graph: GraphClientCache,
     pub graph_tx: Option<std::sync::mpsc::Sender<GraphCommand>>,
     pub graph_rx: Option<std::sync::mpsc::Receiver<GraphEvent>>,
     pub walker_cancel: Option<Arc<AtomicBool>>,
     pub walker_handle: Option<std::thread::JoinHandle<()>>,
+    pub diff_tx: Option<std::sync::mpsc::Sender<DiffCommand>>,
+    pub diff_rx: Option<std::sync::mpsc::Receiver<DiffEvent>>,
 
     // Repository metadata consumed by graph, branch, tag, and stash panes.
     pub oids: Oids,
@@ -497,53 +564,39 @@ pub struct App {
     pub submodules: Submodules,
     pub submodule_stack: Vec<SubmoduleStackEntry>,
     pub uncommitted: UncommittedChanges,
+    // Read on the first reachability query after a reload.
+    pub commit_graph: OnceCell<Option<Rc<CommitGraph>>>,
+    // Replace refs the graph honours, so commit views read what the row stands for.
+    pub replacements: HashMap<Oid, Oid>,
 
     // Cached file and diff data for the currently selected graph or status row.
     pub current_diff: Vec<FileChange>,
     pub current_diff_identity: Option<GraphIndexIdentity>,
     pub is_uncommitted_loaded: bool,
     pub file_name: Option<String>,
+    pub viewer_diff: Option<FileDiff>,
     pub viewer_lines: Vec<ListItem<'static>>,
     pub viewer_split_rows: Vec<SplitViewerRow>,
     pub viewer_edges: Vec<usize>,
     pub viewer_hunks: Vec<usize>,
-    pub viewer_mode: ViewerMode,
-    pub is_viewer_layout_dirty: bool,
+    pub viewer_line_rows: Vec<usize>,
     pub viewer_layout_signature: Option<ViewerLayoutSignature>,
 
-    // Last computed terminal rectangles.
-    pub layout: Layout,
-
-    // Persistent layout switches and current interaction target.
-    pub layout_config: LayoutConfig,
-    pub mouse_drag: Option<MouseDrag>,
-    pub last_mouse_click: Option<(MouseSelectionTarget, Instant)>,
-    pub context_menu: Option<ContextMenuState>,
-    pub modal_area: Option<Rect>,
+    // Current screen and interaction target.
     pub viewport: Viewport,
     pub focus: Focus,
 
     // Pane selections and scroll offsets.
     pub branches_selected: usize,
     pub branches_scroll: Cell<usize>,
-
-    // Tags
     pub tags_selected: usize,
     pub tags_scroll: Cell<usize>,
-
-    // Stashes
     pub stashes_selected: usize,
     pub stashes_scroll: Cell<usize>,
-
-    // Reflogs
     pub reflogs_selected: usize,
     pub reflogs_scroll: Cell<usize>,
-
-    // Worktrees
     pub worktrees_selected: usize,
     pub worktrees_scroll: Cell<usize>,
-
-    // Submodules
     pub submodules_selected: usize,
     pub submodules_scroll: Cell<usize>,
 
@@ -559,10 +612,157 @@ pub struct App {
     // Graph
     pub graph_selected: usize,
     pub graph_scroll: Cell<usize>,
-
-    // Viewer
+    pub graph_marked_oids: Vec<Oid>,
+    pub graph_marks: Marks,
+    // Counted lookups still to run once the current one answers.
+    pub graph_repeat: Option<(Command, usize)>,
+    pub jump_list: Vec<Oid>,
+    pub jump_index: usize,
+    pub pane_marks: PaneMarks,
+    pub visual: Option<VisualSelection>,
+    // The command to rerun once a visual range outside the cache has been looked up.
+    pub pending_visual_command: Option<Command>,
+    // Saved session of the repository being opened, applied as the graph and panes arrive.
+    pub pending_session: Option<RepositorySession>,
+    pub pending_session_rows: Vec<(GraphPane, Option<String>, usize)>,
+
+    // Viewer, inspector and status panes.
     pub viewer_selected: usize,
     pub viewer_scroll: Cell<usize>,
+    pub inspector_selected: usize,
+    pub inspector_scroll: Cell<usize>,
+    pub status_top_selected: usize,
+    pub status_top_scroll: Cell<usize>,
+    pub status_bottom_selected: usize,
+    pub status_bottom_scroll: Cell<usize>,
+
+    // Journal
+    pub journal: Vec<JournalEntry>,
+    pub pending_journal: VecDeque<(Command, RefState)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BatchAction {
+    Stage,
+    Unstage,
+    DiscardStaged,
+    DiscardUnstaged,
+    DeleteBranches,
+    DeleteTags,
+    DropStashes,
+    Cherrypick,
+    Revert,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScrollbarDrag {
+    pub target: ScrollbarTarget,
+    pub grab_offset: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SharedMouseDrag {
+    pub layout: LayoutDrag,
+    pub scrollbar: ScrollbarDrag,
+    pub start_column: u16,
+    pub start_row: u16,
+}
+
+// A branch label picked up in the graph or branches pane; `drop_index` is the graph row under the cursor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BranchDrag {
+    pub name: String,
+    pub is_local: bool,
+    pub start_column: u16,
+    pub start_row: u16,
+    pub is_dragging: bool,
+    pub drop_index: Option<usize>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BranchDropAction {
+    Rebase,
+    Merge,
+    Reset,
+    CreateBranch,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MouseDrag {
+    Layout(LayoutDrag),
+    Scrollbar(ScrollbarDrag),
+    Shared(SharedMouseDrag),
+    Branch(BranchDrag),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MouseSelectionTarget {
+    Graph(usize),
+    Viewer(usize),
+    Branches(usize),
+    Tags(usize),
+    Stashes(usize),
+    Reflogs(usize),
+    Worktrees(usize),
+    Submodules(usize),
+    Inspector(usize),
+    StatusTop(usize),
+    StatusBottom(usize),
+    Search(usize),
+    Splash(usize),
+    Settings(usize),
+    SettingsTab(SettingsTab),
+}
+
+pub struct App {
+    // Global application state and user-facing configuration.
+    pub logo: Vec<Span<'static>>,
+    pub recent: Vec<String>,
+    pub spinner: Spinner,
+    pub keymaps: IndexMap<InputMode, IndexMap<KeyBinding, Command>>,
+    pub mode: InputMode,
+    // Keys pressed so far in a multi-key sequence and when the last one came in.
+    pub pending_keys: Vec<KeyBinding>,
+    pub pending_keys_at: Option<Instant>,
+    // A count typed before a graph motion, such as the 10 in 10j.
+    pub pending_count: Option<usize>,
+    // SetMark or JumpToMark waiting for the mark name.
+    pub pending_mark: Option<Command>,
+    pub last_input_direction: Option<Direction>,
+    pub theme: Theme,
+    pub symbols: SymbolTheme,
+    pub language: Language,
+
+    // The open repository of the active tab; parked tabs keep theirs in `tabs`.
+    pub tab: RepoTab,
+
+    // Graph rendering helpers.
+    pub color: Rc<RefCell<ColorPicker>>,
+
+    // Viewer presentation shared by every tab.
+    pub viewer_mode: ViewerMode,
+    pub is_viewer_layout_dirty: bool,
+
+    // Last computed terminal rectangles.
+    pub layout: Layout,
+
+    // Persistent layout switches and current interaction target.
+    pub layout_config: LayoutConfig,
+    pub mouse_drag: Option<MouseDrag>,
+    pub graph_message_x: u16,
+    pub last_mouse_click: Option<(MouseSelectionTarget, Instant)>,
+    pub context_menu: Option<ContextMenuState>,
+    pub modal_area: Option<Rect>,
+
+    // Graph
+    pub marks_save_path: Option<PathBuf>,
+    pub session_save_path: Option<PathBuf>,
+    // Directory for stored graph walks; tests point it at a temporary folder.
+    pub walk_cache_dir: Option<PathBuf>,
+
+    // Viewer
+    // File line to select once the viewer's diff arrives, set when opening a content search result.
+    pub viewer_pending_line: Option<usize>,
 
     // Splash
     pub splash_selected: usize,
@@ -581,18 +781,6 @@ pub struct App {
     pub symbol_theme_save_path: Option<PathBuf>,
     pub language_save_path: Option<PathBuf>,
 
-    // Inspector
-    pub inspector_selected: usize,
-    pub inspector_scroll: Cell<usize>,
-
-    // Status top
-    pub status_top_selected: usize,
-    pub status_top_scroll: Cell<usize>,
-
-    // Status bottom
-    pub status_bottom_selected: usize,
-    pub status_bottom_scroll: Cell<usize>,
-
     // Modal selections and text input buffers.
     pub modal_checkout_selected: i32,
 
@@ -600,10 +788,36 @@ pub struct App {
     pub modal_solo_selected: i32,
     pub modal_branch_action: BranchModalAction,
 
+    // Modal mainline
+    pub modal_mainline_selected: i32,
+    pub modal_mainline_kind: OperationKind,
+    pub modal_mainline_preview: Vec<FileChange>,
+
+    // Modal reset
+    pub modal_reset_kind: Command,
+    pub modal_reset_target: Option<Oid>,
+    pub modal_reset_label: String,
+    pub modal_reset_branch: Option<String>,
+    pub modal_reset_preview: ResetPreview,
+
+    // Modal batch
+    pub modal_batch_action: Option<BatchAction>,
+
+    // Modal patches
+    pub modal_patch_oids: Vec<Oid>,
+    pub modal_patch_path: PathBuf,
+    pub modal_patch_mails: Vec<PatchMail>,
+    pub modal_patch_selected: i32,
+
+    // Journal
+    pub journal_save_path: Option<PathBuf>,
+    pub modal_journal_selected: i32,
+
     // Modal editor
     pub modal_input: TextInput,
     pub pending_cherrypick_oid: Option<Oid>,
     pub pending_revert_oid: Option<Oid>,
+    pub pending_mainline: Option<u32>,
     pub pending_branch_target_oid: Option<Oid>,
     pub modal_rename_branch_source: Option<String>,
     pub modal_worktree_name: String,
@@ -620,6 +834,21 @@ pub struct App {
     pub modal_file_search_selected: i32,
     pub modal_file_search_scroll: Cell<usize>,
     pub modal_file_search_return_focus: Focus,
+    pub modal_content_search_results: Vec<GrepMatch>,
+    pub modal_content_search_selected: i32,
+    pub modal_content_search_scroll: Cell<usize>,
+    pub modal_content_search_return_focus: Focus,
+    pub modal_content_search_scope: GrepScope,
+    pub modal_content_search_is_regex: bool,
+    pub modal_content_search_task: Option<ContentSearchTask>,
+    // The query the results belong to, and when a newer one typed since is due to start.
+    pub modal_content_search_query: String,
+    pub modal_content_search_due: Option<Instant>,
+    pub modal_content_search_error: Option<String>,
+    pub modal_content_search_is_truncated: bool,
+    pub modal_palette_selected: usize,
+    pub modal_palette_scroll: Cell<usize>,
+    pub modal_palette_return_focus: Focus,
 
     // Modal delete a branch
     pub modal_delete_branch_selected: i32,
@@ -648,6 +877,25 @@ pub struct App {
     pub modal_network_title: String,
     pub modal_network_message: String,
 
+    // Git hooks and the one-shot flag that skips them for the next operation.
+    pub hook_task: Option<HookTask>,
+    pub skip_hooks_once: bool,
+    // A signed commit on a worker thread, so gpg or ssh-keygen cannot stall the UI.
+    pub commit_task: Option<JoinHandle<Result<Oid, git2::Error>>>,
+
+    // Custom commands from custom_commands.json and the ones still running.
+    pub custom_commands: Vec<CustomCommand>,
+    pub custom_command_task: Option<CustomCommandTask>,
+    pub background_custom_commands: Vec<CustomCommandTask>,
+    pub pending_suspended_command: Option<CustomCommandRun>,
+
+    // A short message in the status bar that does not take focus, with when it was set.
+    pub status_notice: Option<(String, Instant)>,
+
+    // Open repositories; `tabs[active_tab]` is an empty slot while its state lives in `tab`.
+    pub tabs: Vec<RepoTab>,
+    pub active_tab: usize,
+
     // Main loop shutdown flag.
     pub is_exit: bool,
 }
@@ -671,6 +919,7 @@ impl App {
             self.load_theme_config();
             self.load_symbol_theme_config();
             self.load_keymap();
+            self.load_custom_commands();
             self.reload(None);
 
             while !self.is_exit {
@@ -679,14 +928,24 @@ impl App {
                 }
 
                 // Pull at most one walker update per tick to keep redraws responsive.
-                if let Some(repo) = &self.repo.clone() {
+                if let Some(repo) = &self.tab.repo.clone() {
                     self.sync(repo);
                 }
                 self.poll_network_request();
+                self.poll_hook_task();
+                self.poll_commit_task();
+                self.poll_custom_commands();
+                self.poll_content_search();
+                self.expire_pending_keys();
+                if let Some(run) = self.pending_suspended_command.take() {
+                    self.run_suspended_command(terminal, run, has_keyboard_enhancement)?;
+                }
 
                 terminal.draw(|frame| self.draw(frame))?;
                 self.run_pending_operation_action();
             }
+            self.save_session();
+            self.shutdown_graph_services();
 
             Ok(())
         })();
@@ -704,12 +963,12 @@ impl App {
     pub fn draw(&mut self, frame: &mut Frame) {
         // Layout must be recomputed every frame because terminal size and focus can change.
         self.layout(frame);
-        if self.viewport == Viewport::Viewer {
+        if self.tab.viewport == Viewport::Viewer {
             let signature = self.current_viewer_layout_signature();
-            if self.viewer_layout_signature != Some(signature) {
+            if self.tab.viewer_layout_signature != Some(signature) {
                 self.is_viewer_layout_dirty = true;
             }
-            self.viewer_layout_signature = Some(signature);
+            self.tab.viewer_layout_signature = Some(signature);
         }
         if self.is_viewer_layout_dirty {
             self.refresh_viewer_for_layout_change();
@@ -718,7 +977,7 @@ impl App {
 
         frame.render_widget(Block::default().style(self.theme.background_style()), frame.area());
 
-        let is_splash = self.viewport == Viewport::Splash;
+        let is_splash = self.tab.viewport == Viewport::Splash;
 
         frame.render_widget(
             Block::default().borders(if is_splash { Borders::NONE } else { Borders::ALL }).border_style(Style::default().fg(self.theme.COLOR_BORDER)).border_set(self.symbols.border.block_set()),
@@ -726,9 +985,9 @@ impl App {
         );
 
         // Repo-dependent panes render only after a repository has opened successfully.
-        if let Some(repo) = &self.repo.clone() {
+        if let Some(repo) = &self.tab.repo.clone() {
             // The central viewport is mutually exclusive, while side panes can be toggled.
-            match self.viewport {
+            match self.tab.viewport {
                 Viewport::Graph => {
                     self.draw_graph(frame, repo);
                 },
@@ -748,7 +1007,7 @@ impl App {
             }
 
             // Side panes are hidden on splash/settings because those views own the frame.
-            match self.viewport {
+            match self.tab.viewport {
                 Viewport::Splash => {},
                 Viewport::Settings => {},
                 _ => {
@@ -776,7 +1035,7 @@ impl App {
                     if self.layout_config.is_status {
                         self.draw_status(frame);
                     }
-                    if self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts) {
+                    if self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts) {
                         self.draw_inspector(frame, repo);
                     }
                 },
@@ -788,7 +1047,7 @@ impl App {
 
             // Modals render last so they overlay panes without changing pane layout.
             self.modal_area = None;
-            match self.focus {
+            match self.tab.focus {
                 Focus::ModalCheckout => {
                     self.draw_modal_checkout(frame);
                 },
@@ -804,6 +1063,9 @@ impl App {
                 Focus::ModalRemoveWorktree => {
                     self.draw_modal_remove_worktree(frame);
                 },
+                Focus::ModalBatch => {
+                    self.draw_modal_batch(frame);
+                },
                 Focus::ModalRemoteAction => {
                     self.draw_modal_remote_action(frame);
                 },
@@ -828,6 +1090,27 @@ impl App {
                 Focus::ModalRevert => {
                     self.draw_modal_input(frame, modal::PROMPT_REVERT_COMMIT());
                 },
+                Focus::ModalMainline => {
+                    self.draw_modal_mainline(frame);
+                },
+                Focus::ModalJournal => {
+                    self.draw_modal_journal(frame);
+                },
+                Focus::ModalReset => {
+                    self.draw_modal_reset(frame);
+                },
+                Focus::ModalExportGraph => {
+                    self.draw_modal_input(frame, modal::PROMPT_EXPORT_GRAPH());
+                },
+                Focus::ModalExportPatches => {
+                    self.draw_modal_input(frame, modal::PROMPT_EXPORT_PATCHES());
+                },
+                Focus::ModalApplyPatchPath => {
+                    self.draw_modal_input(frame, modal::PROMPT_APPLY_PATCH());
+                },
+                Focus::ModalApplyPatch => {
+                    self.draw_modal_apply_patch(frame);
+                },
                 Focus::ModalCreateBranch => {
                     self.draw_modal_input(frame, modal::PROMPT_CREATE_BRANCH());
                 },
@@ -849,12 +1132,24 @@ impl App {
                 Focus::ModalGraphLaneLimit => {
                     self.draw_modal_input(frame, modal::PROMPT_GRAPH_LANE_LIMIT());
                 },
+                Focus::ModalViewerFileSizeLimit => {
+                    self.draw_modal_input(frame, modal::PROMPT_VIEWER_FILE_SIZE_LIMIT());
+                },
+                Focus::ModalDeepen => {
+                    self.draw_modal_input(frame, modal::PROMPT_DEEPEN());
+                },
                 Focus::ModalGrep => {
                     self.draw_modal_input(frame, modal::PROMPT_FIND_SHA());
                 },
                 Focus::ModalFileSearch => {
                     self.draw_modal_file_search(frame, modal::PROMPT_FIND_FILE());
                 },
+                Focus::ModalContentSearch => {
+                    self.draw_modal_content_search(frame);
+                },
+                Focus::ModalCommandPalette => {
+                    self.draw_modal_command_palette(frame, modal::PROMPT_COMMAND_PALETTE());
+                },
                 Focus::ModalTag => {
                     self.draw_modal_input(frame, modal::PROMPT_CREATE_TAG());
                 },
@@ -867,6 +1162,12 @@ impl App {
                 Focus::ModalNetworkProgress => {
                     self.draw_modal_network_progress(frame);
                 },
+                Focus::ModalHookProgress => {
+                    self.draw_modal_hook_progress(frame);
+                },
+                Focus::ModalCustomCommand => {
+                    self.draw_modal_custom_command(frame);
+                },
                 _ => {},
             }
         } else {
@@ -876,66 +1177,88 @@ impl App {
         if self.context_menu.is_some() && !self.is_modal_focus() {
             self.draw_context_menu(frame);
         }
+        if (!self.pending_keys.is_empty() || self.pending_mark.is_some()) && !self.is_modal_focus() {
+            self.draw_which_key(frame);
+        }
     }
 
     pub fn reload(&mut self, override_path: Option<String>) {
-        let existing_hidden_branch_names = self.branches.hidden_branch_names.clone();
-        let previous_path = self.path.clone();
+        // Actions reload once refs have moved, which is when their journal entry is complete.
+        self.finish_journal();
+        // Opening a path may leave the current repository, so remember where it was.
+        if override_path.is_some() {
+            self.save_session();
+        }
+
+        let existing_hidden_branch_names = self.tab.branches.hidden_branch_names.clone();
+        let previous_path = self.tab.path.clone();
         let has_override_path = override_path.is_some();
-        let pending_selection_restore = if override_path.is_none() && self.graph_selected != 0 {
-            self.graph_identity_at(self.graph_selected)
-                .map(|identity| GraphSelectionRestore { oid: identity.oid, selected_offset: self.graph_selected.saturating_sub(self.graph_scroll.get()) })
+        let mut pending_selection_restore = if override_path.is_none() && self.tab.graph_selected != 0 {
+            self.graph_identity_at(self.tab.graph_selected)
+                .map(|identity| GraphSelectionRestore { oid: identity.oid, selected_offset: self.tab.graph_selected.saturating_sub(self.tab.graph_scroll.get()) })
                 .filter(|restore| restore.oid != Oid::zero())
         } else {
             None
         };
 
         // Clear derived data; the walker will repopulate it asynchronously.
-        self.heatmap = empty_heatmap();
-        self.current_diff = Vec::new();
-        self.current_diff_identity = None;
-        self.is_uncommitted_loaded = false;
-        self.uncommitted = UncommittedChanges::default();
-        self.viewer_lines = Vec::new();
-        self.viewer_split_rows = Vec::new();
-        self.viewer_edges = Vec::new();
-        self.viewer_hunks = Vec::new();
-        self.branches = Branches::default();
-        self.tags = Tags::default();
-        self.stashes = Stashes::default();
-        self.reflogs = HeadReflogs::default();
-        self.worktrees = Worktrees::default();
-        self.submodules = Submodules::default();
+        self.tab.heatmap = empty_heatmap();
+        self.tab.current_diff = Vec::new();
+        self.tab.current_diff_identity = None;
+        self.tab.is_uncommitted_loaded = false;
+        self.tab.uncommitted = UncommittedChanges::default();
+        self.tab.viewer_diff = None;
+        self.tab.viewer_lines = Vec::new();
+        self.tab.viewer_split_rows = Vec::new();
+        self.tab.viewer_edges = Vec::new();
+        self.tab.viewer_hunks = Vec::new();
+        self.tab.viewer_line_rows = Vec::new();
+        self.tab.branches = Branches::default();
+        self.tab.tags = Tags::default();
+        self.tab.stashes = Stashes::default();
+        self.tab.reflogs = HeadReflogs::default();
+        self.tab.worktrees = Worktrees::default();
+        self.tab.submodules = Submodules::default();
         self.clear_file_history_search();
-        self.branches.hidden_branch_names = existing_hidden_branch_names.clone();
+        self.tab.branches.hidden_branch_names = existing_hidden_branch_names.clone();
 
-        // Prefer an explicit path, then the current path, then the first non-flag CLI arg.
+        // Prefer an explicit path, then the current path set from the command line, then the current directory.
         let path = if let Some(path) = override_path {
             path
-        } else if let Some(path) = self.path.clone() {
+        } else if let Some(path) = self.tab.path.clone() {
             path
         } else {
-            env::args().skip(1).find(|arg| !arg.starts_with('-')).unwrap_or_else(|| ".".to_string())
+            ".".to_string()
         };
         let canonical_path = std::fs::canonicalize(path).unwrap_or_else(|_| PathBuf::from("."));
-        let absolute_path: PathBuf = try_into_git_repo_root(&canonical_path).unwrap_or(canonical_path.clone());
+        let absolute_path: PathBuf = discover_repo_root(&canonical_path).or_else(|| try_into_git_repo_root(&canonical_path)).unwrap_or(canonical_path.clone());
+
+        // An explicit work tree belongs to the repository it was given for.
+        if previous_path.as_deref() != Some(absolute_path.to_string_lossy().as_ref()) && has_override_path {
+            self.tab.work_tree = None;
+        }
 
         // Failure keeps the app usable by falling back to the splash screen.
-        let repo = match Repository::open(&absolute_path) {
+        let repo = match open_repo(&absolute_path, self.tab.work_tree.as_deref()) {
             Ok(r) => Some(Rc::new(r)),
             Err(_) => None,
         };
 
         let absolute_path = absolute_path.display().to_string();
-        self.path = Some(absolute_path.clone());
-        self.repo = repo;
+        self.tab.path = Some(absolute_path.clone());
+        self.tab.repo = repo;
+        self.tab.commit_graph = OnceCell::new();
+        self.tab.replacements = match &self.tab.repo {
+            Some(repo) if !self.layout_config.is_graph_original_parents => get_replacements(repo),
+            _ => HashMap::new(),
+        };
         self.refresh_theme_assets();
 
         // Repository-specific state starts only after Repository::open succeeds.
-        if let Some(repo) = &self.repo {
+        if let Some(repo) = &self.tab.repo.clone() {
             let current_path = PathBuf::from(&absolute_path);
-            self.worktrees = Worktrees::from_entries(list_worktrees(repo, Some(current_path.as_path())).unwrap_or_default());
-            self.submodules = Submodules::from_entries(list_submodules(repo).unwrap_or_default());
+            self.tab.worktrees = Worktrees::from_entries(list_worktrees(repo, Some(current_path.as_path())).unwrap_or_default());
+            self.tab.submodules = Submodules::from_entries(list_submodules(repo).unwrap_or_default());
 
             let same_repo_reload = !has_override_path && previous_path.as_deref() == Some(absolute_path.as_str());
             let mut hidden_branch_names = if same_repo_reload { existing_hidden_branch_names } else { load_branch_visibility(&absolute_path) };
@@ -943,7 +1266,16 @@ impl App {
             if prune_hidden_branches(&mut hidden_branch_names, &current_names) {
                 save_branch_visibility(&absolute_path, &hidden_branch_names);
             }
-            self.branches.hidden_branch_names = hidden_branch_names;
+            self.tab.branches.hidden_branch_names = hidden_branch_names;
+            if !same_repo_reload {
+                self.tab.journal = if let Some(path) = &self.journal_save_path { load_journal_from_path(path.as_path(), &absolute_path) } else { load_journal(&absolute_path) };
+                self.tab.graph_marks = if let Some(path) = &self.marks_save_path { load_marks_from_path(path.as_path(), &absolute_path) } else { load_marks(&absolute_path) };
+                self.tab.jump_list.clear();
+                self.tab.jump_index = 0;
+                self.tab.pane_marks = PaneMarks::default();
+                self.tab.visual = None;
+                pending_selection_restore = self.begin_session_restore(&absolute_path);
+            }
 
             // Recent paths are append-only here; the splash screen controls selection.
             if !self.recent.iter().any(|v| v == &absolute_path) {
@@ -951,65 +1283,133 @@ impl App {
                 self.save_recent();
             }
 
-            // Cancel the previous walker before spawning a new one for this repository state.
-            if let Some(tx) = self.graph_tx.take() {
-                let _ = tx.send(GraphCommand::Shutdown);
-            }
-            self.graph_rx = None;
-
-            if let Some(cancel_flag) = &self.walker_cancel {
-                cancel_flag.store(true, std::sync::atomic::Ordering::SeqCst);
-            }
-
-            // Join the old worker off-thread so reload never stalls the UI loop.
-            if let Some(handle) = self.walker_handle.take() {
-                std::thread::spawn(move || {
-                    let _ = handle.join();
-                });
-            }
-
             // Commit actions require a concrete identity, so missing config is treated as fatal.
             let (name, email) = get_git_user_info(repo).expect("Couldn't get user credentials");
-            self.name = name.unwrap();
-            self.email = email.unwrap();
+            self.tab.name = name.unwrap();
+            self.tab.email = email.unwrap();
 
             // The spinner reflects walker activity, not individual git network commands.
             self.spinner.start();
 
-            // Each reload gets a fresh channel so stale walker results cannot be received.
+            // A new generation makes the client drop anything the previous walk still sends.
+            let generation = self.tab.graph.generation.saturating_add(1);
+            self.tab.graph = GraphClientCache { generation, pending_selection_restore, ..Default::default() };
+
+            // Move only serializable state into the worker thread.
+            let hidden_branch_names = self.tab.branches.hidden_branch_names.clone();
+            let include_head_reflog_roots = self.layout_config.is_graph_reflogs;
+            let use_replace_refs = !self.layout_config.is_graph_original_parents;
+            let graph_lane_limit = self.layout_config.graph_lane_limit;
+            let worktrees = self.tab.worktrees.entries.clone();
+
+            // The diff worker only needs replacing when the repository itself changes; new generations reopen it.
+            if !same_repo_reload || self.tab.diff_tx.is_none() {
+                self.stop_diff_service();
+                let (command_tx, command_rx) = channel();
+                let (event_tx, event_rx) = channel();
+                self.tab.diff_tx = Some(command_tx);
+                self.tab.diff_rx = Some(event_rx);
+                spawn_diff_service(DiffServiceConfig { generation, path: absolute_path.clone(), work_tree: self.tab.work_tree.clone() }, command_rx, event_tx);
+            }
+
+            // Actions on the open repository extend the running walk instead of starting over.
+            if same_repo_reload && self.tab.walker_handle.as_ref().is_some_and(|handle| !handle.is_finished()) {
+                let refresh = GraphCommand::Refresh {
+                    generation,
+                    hidden_branch_names: hidden_branch_names.clone(),
+                    include_head_reflog_roots,
+                    use_replace_refs,
+                    graph_lane_limit,
+                    worktrees: worktrees.clone(),
+                    symbols: Box::new(self.symbols.clone()),
+                };
+                if self.tab.graph_tx.as_ref().is_some_and(|tx| tx.send(refresh).is_ok()) {
+                    return;
+                }
+            }
+
+            // Cancel the previous walker before spawning a new one for this repository state.
+            self.stop_graph_service();
+
+            // Each new worker gets a fresh channel so stale walker results cannot be received.
             let cancel = Arc::new(AtomicBool::new(false));
             let cancel_clone = cancel.clone();
-            self.walker_cancel = Some(cancel);
-
-            let generation = self.graph.generation.saturating_add(1);
-            self.graph = GraphClientCache { generation, pending_selection_restore, ..Default::default() };
+            self.tab.walker_cancel = Some(cancel);
 
             let (command_tx, command_rx) = channel();
             let (event_tx, event_rx) = channel();
-            self.graph_tx = Some(command_tx);
-            self.graph_rx = Some(event_rx);
+            self.tab.graph_tx = Some(command_tx);
+            self.tab.graph_rx = Some(event_rx);
 
-            // Move only serializable state into the worker thread.
-            let hidden_branch_names = self.branches.hidden_branch_names.clone();
-            let include_head_reflog_roots = self.layout_config.is_graph_reflogs;
-            let graph_lane_limit = self.layout_config.graph_lane_limit;
-            let worktrees = self.worktrees.entries.clone();
+            let cache_dir = self.walk_cache_dir.clone().unwrap_or_else(walk_cache_dir);
+            let walk_cache_path = Some(walk_cache_path(&cache_dir, &absolute_path));
 
             // The worker streams partial graph state so large repositories become usable quickly.
             let handle = spawn_graph_service(
-                GraphServiceConfig { generation, path: absolute_path, amount: 10000, hidden_branch_names, include_head_reflog_roots, graph_lane_limit, worktrees, symbols: self.symbols.clone() },
+                GraphServiceConfig {
+                    generation,
+                    path: absolute_path,
+                    amount: 10000,
+                    hidden_branch_names,
+                    include_head_reflog_roots,
+                    use_replace_refs,
+                    graph_lane_limit,
+                    worktrees,
+                    symbols: self.symbols.clone(),
+                    walk_cache_path,
+                },
                 command_rx,
                 event_tx,
                 cancel_clone,
             );
 
-            self.walker_handle = Some(handle);
+            self.tab.walker_handle = Some(handle);
+        }
+    }
+
+    pub(crate) fn stop_graph_service(&mut self) {
+        if let Some(tx) = self.tab.graph_tx.take() {
+            let _ = tx.send(GraphCommand::Shutdown);
+        }
+        self.tab.graph_rx = None;
+
+        if let Some(cancel_flag) = &self.tab.walker_cancel {
+            cancel_flag.store(true, std::sync::atomic::Ordering::SeqCst);
+        }
+
+        // Join the old worker off-thread so reload never stalls the UI loop.
+        if let Some(handle) = self.tab.walker_handle.take() {
+            std::thread::spawn(move || {
+                let _ = handle.join();
+            });
+        }
+    }
+
+    // Walks extended since they were stored are saved as their workers stop, so exit waits for every tab's.
+    fn shutdown_graph_services(&mut self) {
+        let mut handles = Vec::new();
+        for tab in self.tabs.iter_mut().chain(std::iter::once(&mut self.tab)) {
+            if let Some(tx) = tab.graph_tx.take() {
+                let _ = tx.send(GraphCommand::Shutdown);
+            }
+            handles.extend(tab.walker_handle.take());
+        }
+        for handle in handles {
+            let _ = handle.join();
+        }
+    }
+
+    // Dropping the sender also ends the worker once it finishes whatever diff it is computing.
+    pub(crate) fn stop_diff_service(&mut self) {
+        if let Some(tx) = self.tab.diff_tx.take() {
+            let _ = tx.send(DiffCommand::Shutdown);
         }
+        self.tab.diff_rx = None;
     }
 
     pub fn sync(&mut self, repo: &git2::Repository) {
         let mut events = Vec::new();
-        if let Some(rx) = &self.graph_rx {
+        if let Some(rx) = &self.tab.graph_rx {
             while let Ok(event) = rx.try_recv() {
                 events.push(event);
             }
@@ -1018,33 +1418,74 @@ impl App {
         for event in events {
             self.handle_graph_event(repo, event);
         }
+
+        let diff_events: Vec<DiffEvent> = self.tab.diff_rx.as_ref().map(|rx| rx.try_iter().collect()).unwrap_or_default();
+        for event in diff_events {
+            self.handle_diff_event(event);
+        }
+    }
+
+    fn handle_diff_event(&mut self, event: DiffEvent) {
+        match event {
+            DiffEvent::FileList { generation, request_id, changes, .. } => {
+                if generation != self.tab.graph.generation {
+                    return;
+                }
+                let Some((pending_id, identity)) = self.tab.graph.requested_diff else {
+                    return;
+                };
+                if request_id != pending_id {
+                    return;
+                }
+                self.tab.graph.requested_diff = None;
+                self.tab.current_diff = changes;
+                self.tab.current_diff_identity = Some(identity);
+                // A session restored onto this commit was waiting for its files.
+                if self.tab.pending_session.is_some()
+                    && self.tab.graph.pending_selection_restore.is_none()
+                    && let Some(repo) = self.tab.repo.clone()
+                {
+                    self.finish_session_restore(&repo);
+                }
+            },
+            DiffEvent::File { generation, request_id, diff, .. } => {
+                if generation != self.tab.graph.generation || self.tab.graph.requested_viewer != Some(request_id) {
+                    return;
+                }
+                self.tab.graph.requested_viewer = None;
+                self.apply_viewer_diff(diff);
+            },
+        }
     }
 
     fn handle_graph_event(&mut self, repo: &git2::Repository, event: GraphEvent) {
         match event {
             GraphEvent::Progress { generation, version, total, is_first, is_complete } => {
-                if generation != self.graph.generation {
+                if generation != self.tab.graph.generation {
                     return;
                 }
-                self.graph.version = version;
-                self.graph.total = total;
-                self.graph.is_complete = is_complete;
+                self.tab.graph.version = version;
+                self.tab.graph.total = total;
+                self.tab.graph.is_complete = is_complete;
 
                 if is_first {
-                    if self.viewport == Viewport::Splash {
-                        self.viewport = Viewport::Graph;
+                    if self.tab.viewport == Viewport::Splash {
+                        self.tab.viewport = Viewport::Graph;
                     }
 
                     match get_filenames_diff_at_workdir(repo) {
                         Ok(uncommitted) => {
-                            self.uncommitted = uncommitted;
+                            self.tab.uncommitted = uncommitted;
                         },
                         Err(error) => {
-                            self.uncommitted = UncommittedChanges::default();
+                            self.tab.uncommitted = UncommittedChanges::default();
                             self.show_error(errors::with_error(errors::FILE_DIFF(), error));
                         },
                     }
-                    self.is_uncommitted_loaded = true;
+                    self.tab.is_uncommitted_loaded = true;
+                    if self.tab.graph.pending_selection_restore.is_none() {
+                        self.finish_session_restore(repo);
+                    }
                 }
 
                 if is_complete {
@@ -1053,101 +1494,117 @@ impl App {
                 self.request_pending_graph_selection_restore_lookup();
             },
             GraphEvent::GraphWindow { generation, request_id, version, start, end, total, head_alias, rows, history } => {
-                if generation != self.graph.generation {
+                if generation != self.tab.graph.generation {
                     return;
                 }
-                let Some((pending_id, pending_start, pending_end)) = self.graph.requested_graph else {
+                let Some((pending_id, pending_start, pending_end)) = self.tab.graph.requested_graph else {
                     return;
                 };
                 if request_id < pending_id || start != pending_start || end != pending_end {
                     return;
                 }
-                self.graph.version = self.graph.version.max(version);
-                self.graph.total = total;
-                self.graph.graph_window = Some(GraphWindowCache { version, start, end, head_alias, rows, history });
-                self.graph.requested_graph = None;
+                self.tab.graph.version = self.tab.graph.version.max(version);
+                self.tab.graph.total = total;
+                self.tab.graph.graph_window = Some(GraphWindowCache { version, start, end, head_alias, rows, history });
+                self.tab.graph.requested_graph = None;
 
-                if self.graph_selected != 0
-                    && let Some(identity) = self.graph_identity_at(self.graph_selected)
-                {
-                    self.current_diff = get_filenames_diff_at_oid(repo, identity.oid);
-                    self.current_diff_identity = Some(identity);
-                }
+                self.request_selected_commit_diff();
             },
             GraphEvent::PaneWindow { generation, version, pane, start, end, total, rows } => {
-                if generation != self.graph.generation {
+                if generation != self.tab.graph.generation {
                     return;
                 }
                 let cache = PaneWindowCache { version, start, end, total, rows };
                 match pane {
-                    GraphPane::Branches => self.graph.branches_window = Some(cache),
-                    GraphPane::Tags => self.graph.tags_window = Some(cache),
-                    GraphPane::Stashes => self.graph.stashes_window = Some(cache),
-                    GraphPane::Reflogs => self.graph.reflogs_window = Some(cache),
+                    GraphPane::Branches => self.tab.graph.branches_window = Some(cache),
+                    GraphPane::Tags => self.tab.graph.tags_window = Some(cache),
+                    GraphPane::Stashes => self.tab.graph.stashes_window = Some(cache),
+                    GraphPane::Reflogs => self.tab.graph.reflogs_window = Some(cache),
                 }
+                self.restore_session_pane_row(pane, total);
             },
             GraphEvent::FileHistory { generation, request_id, path, rows, error } => {
-                if generation != self.graph.generation || self.search_request_id != Some(request_id) || self.search_path.as_deref() != Some(path.as_str()) {
+                if generation != self.tab.graph.generation || self.tab.search_request_id != Some(request_id) || self.tab.search_path.as_deref() != Some(path.as_str()) {
                     return;
                 }
 
-                self.search_is_loading = false;
-                self.search_request_id = None;
-                self.search_error = error;
-                self.search_rows = rows;
-                self.search_selected = self.search_selected.min(self.search_rows.len().saturating_sub(1));
-                self.search_scroll.set(0);
+                self.tab.search_is_loading = false;
+                self.tab.search_request_id = None;
+                self.tab.search_error = error;
+                self.tab.search_rows = rows;
+                self.tab.search_selected = self.tab.search_selected.min(self.tab.search_rows.len().saturating_sub(1));
+                self.tab.search_scroll.set(0);
             },
             GraphEvent::LookupResult { generation, request_id, result, .. } => {
-                if generation != self.graph.generation {
+                if generation != self.tab.graph.generation {
                     return;
                 }
-                let Some((pending_id, action)) = self.graph.pending_lookup.take() else {
+                let Some((pending_id, action)) = self.tab.graph.pending_lookup.take() else {
                     return;
                 };
                 if request_id != pending_id {
-                    self.graph.pending_lookup = Some((pending_id, action));
+                    self.tab.graph.pending_lookup = Some((pending_id, action));
                     return;
                 }
                 let was_restore_lookup = matches!(action, PendingGraphLookup::RestoreSelection);
+                let repeat = self.tab.graph_repeat.take();
                 match (action, result) {
                     (PendingGraphLookup::SelectIndex, GraphLookupResult::Index(Some(index))) => {
-                        self.select_graph_index_from_lookup(repo, index);
+                        self.select_graph_index_from_lookup(index);
                         self.modal_input.clear();
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
+                        if let Some((command, remaining)) = repeat {
+                            self.continue_graph_repeat(command, remaining);
+                        }
+                    },
+                    (PendingGraphLookup::Jump, GraphLookupResult::Index(Some(index))) => {
+                        self.select_graph_index_from_lookup(index);
+                        self.tab.focus = Focus::Viewport;
+                    },
+                    (PendingGraphLookup::Jump, GraphLookupResult::Index(None)) => {
+                        self.show_error(errors::JUMP_TARGET_NOT_IN_GRAPH());
+                    },
+                    (PendingGraphLookup::VisualRange, GraphLookupResult::Oids(oids)) => {
+                        self.finish_visual_range(Self::visual_rows_from_oids(oids));
+                    },
+                    (PendingGraphLookup::VisualRange, GraphLookupResult::PaneRows(rows)) => {
+                        let rows = self.visual_rows_from_pane_rows(&rows);
+                        self.finish_visual_range(rows);
                     },
                     (PendingGraphLookup::RestoreSelection, GraphLookupResult::Index(Some(index))) => {
-                        let selected_offset = self.graph.pending_selection_restore.map(|restore| restore.selected_offset).unwrap_or_default();
-                        self.graph.pending_selection_restore = None;
-                        self.restore_graph_index_from_lookup(repo, index, selected_offset);
+                        let selected_offset = self.tab.graph.pending_selection_restore.map(|restore| restore.selected_offset).unwrap_or_default();
+                        self.tab.graph.pending_selection_restore = None;
+                        self.restore_graph_index_from_lookup(index, selected_offset);
+                        self.finish_session_restore(repo);
                     },
                     (PendingGraphLookup::RestoreSelection, GraphLookupResult::Index(None)) => {
-                        if self.graph.is_complete {
-                            self.graph.pending_selection_restore = None;
+                        if self.tab.graph.is_complete {
+                            self.tab.graph.pending_selection_restore = None;
+                            self.abandon_session_restore(repo);
                         }
                     },
+                    // A saved row that is gone leaves the pane at the top.
+                    (PendingGraphLookup::RestorePaneRow(pane), GraphLookupResult::Index(index)) => {
+                        self.set_pane_selected(pane, index.unwrap_or_default());
+                    },
                     (PendingGraphLookup::SelectPaneRow, GraphLookupResult::PaneRow(Some(row))) => {
                         self.open_graph_pane_row(row);
                         self.modal_input.clear();
                     },
                     (PendingGraphLookup::CacheGraphRow, GraphLookupResult::GraphRow(Some(row))) => {
                         let index = row.index;
-                        let oid = row.oid;
                         self.cache_graph_row(row);
-                        if index == self.graph_selected && index != 0 {
-                            self.current_diff = get_filenames_diff_at_oid(repo, oid);
-                            self.current_diff_identity = self.graph_identity_at(index);
+                        if index == self.tab.graph_selected {
+                            self.request_selected_commit_diff();
                         }
                     },
                     (PendingGraphLookup::OpenInspector, GraphLookupResult::GraphRow(Some(row))) => {
                         let index = row.index;
-                        let oid = row.oid;
                         self.cache_graph_row(row);
-                        if index == self.graph_selected {
-                            self.current_diff = get_filenames_diff_at_oid(repo, oid);
-                            self.current_diff_identity = self.graph_identity_at(index);
+                        if index == self.tab.graph_selected {
+                            self.request_selected_commit_diff();
                             self.layout_config.is_inspector = true;
-                            self.focus = Focus::Inspector;
+                            self.tab.focus = Focus::Inspector;
                         }
                     },
                     _ => {},
@@ -1155,14 +1612,20 @@ impl App {
                 if !was_restore_lookup {
                     self.request_pending_graph_selection_restore_lookup();
                 }
+                self.request_pending_session_row_lookup();
             },
             GraphEvent::Heatmap { generation, heatmap } => {
-                if generation == self.graph.generation {
-                    self.heatmap = heatmap;
+                if generation == self.tab.graph.generation {
+                    self.tab.heatmap = heatmap;
+                }
+            },
+            GraphEvent::Signatures { generation, statuses } => {
+                if generation == self.tab.graph.generation {
+                    self.tab.graph.signatures.extend(statuses);
                 }
             },
             GraphEvent::Error { generation, message } => {
-                if generation == self.graph.generation {
+                if generation == self.tab.graph.generation {
                     self.show_error(message);
                     self.spinner.stop();
                 }
@@ -1174,6 +1637,15 @@ impl App {
         self.recent = load_recent();
     }
 
+    pub fn save_journal(&self) {
+        let Some(repo_path) = &self.tab.path else { return };
+        if let Some(path) = &self.journal_save_path {
+            save_journal_to_path(path.as_path(), repo_path, &self.tab.journal);
+        } else {
+            save_journal(repo_path, &self.tab.journal);
+        }
+    }
+
     pub fn save_recent(&self) {
         if let Some(path) = &self.recent_save_path {
             save_recent_to_path(path.as_path(), &self.recent);
@@ -1183,11 +1655,16 @@ impl App {
     }
 
     pub(crate) fn graph_commit_count(&self) -> usize {
-        self.graph.total.max(self.oids.get_commit_count())
+        self.tab.graph.total.max(self.tab.oids.get_commit_count())
     }
 
     pub(crate) fn graph_row_at(&self, index: usize) -> Option<&GraphRow> {
-        self.graph.row_at(index)
+        self.tab.graph.row_at(index)
+    }
+
+    // The commit whose message, files and diffs stand for `oid` in the graph.
+    pub(crate) fn commit_oid(&self, oid: Oid) -> Oid {
+        self.tab.replacements.get(&oid).copied().unwrap_or(oid)
     }
 
     pub(crate) fn graph_identity_at(&self, index: usize) -> Option<GraphIndexIdentity> {
@@ -1195,11 +1672,11 @@ impl App {
             return Some(GraphIndexIdentity { index: row.index, alias: row.alias, oid: row.oid });
         }
 
-        if self.graph_tx.is_some() {
+        if self.tab.graph_tx.is_some() {
             return None;
         }
 
-        self.oids.get_sorted_aliases().get(index).map(|&alias| GraphIndexIdentity { index, alias, oid: *self.oids.get_oid_by_alias(alias) })
+        self.tab.oids.get_sorted_aliases().get(index).map(|&alias| GraphIndexIdentity { index, alias, oid: *self.tab.oids.get_oid_by_alias(alias) })
     }
 
     pub(crate) fn graph_alias_at(&self, index: usize) -> Option<u32> {
@@ -1211,65 +1688,101 @@ impl App {
     }
 
     pub(crate) fn selected_commit_diff_is_loaded(&self) -> bool {
-        self.graph_selected != 0 && self.graph_identity_at(self.graph_selected).is_some_and(|identity| self.current_diff_identity == Some(identity))
+        self.tab.graph_selected != 0 && self.graph_identity_at(self.tab.graph_selected).is_some_and(|identity| self.tab.current_diff_identity == Some(identity))
+    }
+
+    // Loads the selected commit's file list. With a diff service running the list arrives through `sync`
+    // and the status pane shows its loading state meanwhile; without one it is read in place.
+    pub(crate) fn request_selected_commit_diff(&mut self) {
+        let identity = if self.tab.graph_selected != 0 { self.graph_identity_at(self.tab.graph_selected) } else { None };
+        let Some(identity) = identity else {
+            self.tab.current_diff.clear();
+            self.tab.current_diff_identity = None;
+            self.tab.graph.requested_diff = None;
+            return;
+        };
+        if self.tab.current_diff_identity == Some(identity) || self.tab.graph.requested_diff.is_some_and(|(_, requested)| requested == identity) {
+            return;
+        }
+
+        self.tab.current_diff.clear();
+        self.tab.current_diff_identity = None;
+        if let Some(tx) = self.tab.diff_tx.clone() {
+            let request_id = self.tab.graph.next_request_id();
+            if tx.send(DiffCommand::QueryFileList { generation: self.tab.graph.generation, request_id, oid: self.commit_oid(identity.oid) }).is_ok() {
+                self.tab.graph.requested_diff = Some((request_id, identity));
+                return;
+            }
+        }
+
+        self.tab.graph.requested_diff = None;
+        if let Some(repo) = self.tab.repo.clone() {
+            self.tab.current_diff = get_filenames_diff_at_oid(&repo, self.commit_oid(identity.oid));
+            self.tab.current_diff_identity = Some(identity);
+        }
     }
 
     pub(crate) fn request_graph_window(&mut self, start: usize, end: usize) {
-        let Some(tx) = self.graph_tx.clone() else {
+        let Some(tx) = self.tab.graph_tx.clone() else {
             return;
         };
 
-        if self.graph.graph_window.as_ref().is_some_and(|window| window.start <= start && end <= window.end && window.version >= self.graph.version) {
+        if self.tab.graph.graph_window.as_ref().is_some_and(|window| window.start <= start && end <= window.end && window.version >= self.tab.graph.version) {
             return;
         }
 
-        if self.graph.requested_graph.is_some_and(|(_, requested_start, requested_end)| requested_start <= start && end <= requested_end) {
+        if self.tab.graph.requested_graph.is_some_and(|(_, requested_start, requested_end)| requested_start <= start && end <= requested_end) {
             return;
         }
 
-        let request_id = self.graph.next_request_id();
-        self.graph.requested_graph = Some((request_id, start, end));
-        let _ = tx.send(GraphCommand::QueryGraphWindow { generation: self.graph.generation, request_id, start, end });
+        let request_id = self.tab.graph.next_request_id();
+        self.tab.graph.requested_graph = Some((request_id, start, end));
+        let _ = tx.send(GraphCommand::QueryGraphWindow { generation: self.tab.graph.generation, request_id, start, end });
     }
 
     pub(crate) fn request_pane_window(&mut self, pane: GraphPane, start: usize, end: usize) {
-        let Some(tx) = self.graph_tx.clone() else {
+        let Some(tx) = self.tab.graph_tx.clone() else {
             return;
         };
 
         let cache = match pane {
-            GraphPane::Branches => self.graph.branches_window.as_ref(),
-            GraphPane::Tags => self.graph.tags_window.as_ref(),
-            GraphPane::Stashes => self.graph.stashes_window.as_ref(),
-            GraphPane::Reflogs => self.graph.reflogs_window.as_ref(),
+            GraphPane::Branches => self.tab.graph.branches_window.as_ref(),
+            GraphPane::Tags => self.tab.graph.tags_window.as_ref(),
+            GraphPane::Stashes => self.tab.graph.stashes_window.as_ref(),
+            GraphPane::Reflogs => self.tab.graph.reflogs_window.as_ref(),
         };
 
-        if cache.is_some_and(|window| window.start <= start && end <= window.end && window.version >= self.graph.version) {
+        if cache.is_some_and(|window| window.start <= start && end <= window.end && window.version >= self.tab.graph.version) {
             return;
         }
 
-        let _ = tx.send(GraphCommand::QueryPaneWindow { generation: self.graph.generation, pane, start, end });
+        let _ = tx.send(GraphCommand::QueryPaneWindow { generation: self.tab.graph.generation, pane, start, end });
     }
 
     pub(crate) fn request_graph_lookup(&mut self, kind: GraphLookupKind, action: PendingGraphLookup) {
-        let Some(tx) = self.graph_tx.clone() else {
+        let Some(tx) = self.tab.graph_tx.clone() else {
             return;
         };
 
-        if matches!(action, PendingGraphLookup::SelectIndex | PendingGraphLookup::SelectPaneRow) {
-            self.graph.pending_selection_restore = None;
+        if matches!(action, PendingGraphLookup::SelectIndex | PendingGraphLookup::SelectPaneRow | PendingGraphLookup::Jump) {
+            self.tab.graph.pending_selection_restore = None;
+            self.tab.pending_session = None;
+        }
+        // Selections found by lookup go on the jump list, once per counted motion.
+        if matches!(action, PendingGraphLookup::SelectIndex | PendingGraphLookup::SelectPaneRow) && self.tab.graph_repeat.is_none() {
+            self.record_jump();
         }
 
-        let request_id = self.graph.next_request_id();
-        self.graph.pending_lookup = Some((request_id, action));
-        let _ = tx.send(GraphCommand::Lookup { generation: self.graph.generation, request_id, kind });
+        let request_id = self.tab.graph.next_request_id();
+        self.tab.graph.pending_lookup = Some((request_id, action));
+        let _ = tx.send(GraphCommand::Lookup { generation: self.tab.graph.generation, request_id, kind });
     }
 
     fn request_pending_graph_selection_restore_lookup(&mut self) {
-        let Some(restore) = self.graph.pending_selection_restore else {
+        let Some(restore) = self.tab.graph.pending_selection_restore else {
             return;
         };
-        if self.graph.pending_lookup.is_some() {
+        if self.tab.graph.pending_lookup.is_some() {
             return;
         }
         self.request_graph_lookup(GraphLookupKind::Oid { oid: restore.oid }, PendingGraphLookup::RestoreSelection);
@@ -1283,65 +1796,77 @@ impl App {
     }
 
     pub(crate) fn clear_file_history_search(&mut self) {
-        self.search_path = None;
-        self.search_rows.clear();
-        self.search_is_loading = false;
-        self.search_error = None;
-        self.search_request_id = None;
-        self.search_selected = 0;
-        self.search_scroll.set(0);
+        self.tab.search_path = None;
+        self.tab.search_rows.clear();
+        self.tab.search_is_loading = false;
+        self.tab.search_error = None;
+        self.tab.search_request_id = None;
+        self.tab.search_selected = 0;
+        self.tab.search_scroll.set(0);
     }
 
     pub(crate) fn request_file_history_search(&mut self, path: String) {
-        self.search_path = Some(path.clone());
-        self.search_rows.clear();
-        self.search_is_loading = true;
-        self.search_error = None;
-        self.search_selected = 0;
-        self.search_scroll.set(0);
-
-        let Some(tx) = self.graph_tx.clone() else {
-            self.search_is_loading = false;
-            self.search_error = Some(errors::FILE_HISTORY_WORKER_UNAVAILABLE().to_string());
-            self.search_request_id = None;
+        self.tab.search_path = Some(path.clone());
+        self.tab.search_rows.clear();
+        self.tab.search_is_loading = true;
+        self.tab.search_error = None;
+        self.tab.search_selected = 0;
+        self.tab.search_scroll.set(0);
+
+        let Some(tx) = self.tab.graph_tx.clone() else {
+            self.tab.search_is_loading = false;
+            self.tab.search_error = Some(errors::FILE_HISTORY_WORKER_UNAVAILABLE().to_string());
+            self.tab.search_request_id = None;
             return;
         };
 
-        let request_id = self.graph.next_request_id();
-        self.search_request_id = Some(request_id);
-        if tx.send(GraphCommand::QueryFileHistory { generation: self.graph.generation, request_id, path }).is_err() {
-            self.search_is_loading = false;
-            self.search_error = Some(errors::FILE_HISTORY_WORKER_UNAVAILABLE().to_string());
-            self.search_request_id = None;
+        let request_id = self.tab.graph.next_request_id();
+        self.tab.search_request_id = Some(request_id);
+        if tx.send(GraphCommand::QueryFileHistory { generation: self.tab.graph.generation, request_id, path }).is_err() {
+            self.tab.search_is_loading = false;
+            self.tab.search_error = Some(errors::FILE_HISTORY_WORKER_UNAVAILABLE().to_string());
+            self.tab.search_request_id = None;
+        }
+    }
+
+    // Signatures are verified on the graph worker and cached until the next reload.
+    pub(crate) fn request_signatures(&mut self, oids: impl IntoIterator<Item = Oid>) {
+        let oids: Vec<Oid> = oids.into_iter().filter(|oid| !self.tab.graph.signatures.contains_key(oid) && !self.tab.graph.requested_signatures.contains(oid)).collect();
+        if oids.is_empty() {
+            return;
+        }
+        let Some(tx) = self.tab.graph_tx.clone() else {
+            // Without a worker the checks run inline so the inspector never waits forever.
+            if let Some(repo) = self.tab.repo.clone() {
+                self.tab.graph.signatures.extend(oids.into_iter().map(|oid| (oid, verify_commit(&repo, oid))));
+            }
+            return;
+        };
+
+        if tx.send(GraphCommand::VerifySignatures { generation: self.tab.graph.generation, oids: oids.clone() }).is_ok() {
+            self.tab.graph.requested_signatures.extend(oids);
         }
     }
 
     pub(crate) fn cache_graph_row(&mut self, row: GraphRow) {
-        self.graph.index_rows.insert(row.index, row);
+        self.tab.graph.index_rows.insert(row.index, row);
     }
 
-    fn select_graph_index_from_lookup(&mut self, repo: &git2::Repository, index: usize) {
-        self.graph.pending_selection_restore = None;
-        self.set_graph_index_from_lookup(repo, index);
+    fn select_graph_index_from_lookup(&mut self, index: usize) {
+        self.tab.graph.pending_selection_restore = None;
+        self.tab.pending_session = None;
+        self.set_graph_index_from_lookup(index);
     }
 
-    fn restore_graph_index_from_lookup(&mut self, repo: &git2::Repository, index: usize, selected_offset: usize) {
-        self.set_graph_index_from_lookup(repo, index);
-        self.graph_scroll.set(self.graph_selected.saturating_sub(selected_offset));
+    fn restore_graph_index_from_lookup(&mut self, index: usize, selected_offset: usize) {
+        self.set_graph_index_from_lookup(index);
+        self.tab.graph_scroll.set(self.tab.graph_selected.saturating_sub(selected_offset));
     }
 
-    fn set_graph_index_from_lookup(&mut self, repo: &git2::Repository, index: usize) {
-        self.graph_selected = index.min(self.graph_commit_count().saturating_sub(1));
-        self.graph_scroll.set(self.graph_selected);
-        self.current_diff.clear();
-        self.current_diff_identity = None;
-
-        if self.graph_selected != 0
-            && let Some(identity) = self.graph_identity_at(self.graph_selected)
-        {
-            self.current_diff = get_filenames_diff_at_oid(repo, identity.oid);
-            self.current_diff_identity = Some(identity);
-        }
+    fn set_graph_index_from_lookup(&mut self, index: usize) {
+        self.tab.graph_selected = index.min(self.graph_commit_count().saturating_sub(1));
+        self.tab.graph_scroll.set(self.tab.graph_selected);
+        self.request_selected_commit_diff();
     }
 
     fn refresh_theme_assets(&mut self) {
@@ -1,6 +1,6 @@
 use crate::{
     app::app::{App, Focus},
-    app::draw::pane_window::{aligned_pane_rows, blank_lines, preloaded_pane_window, zebra_list_items},
+    app::draw::pane_window::{aligned_pane_rows, blank_lines, highlight_marked_items, preloaded_pane_window, zebra_list_items},
     core::graph_service::{GraphPane, GraphPaneRow},
     helpers::colors::ColorPicker,
     helpers::layout::scrollbar_content_length,
@@ -26,30 +26,30 @@ impl App {
         let visible_height = self.layout.branches.height.saturating_sub(2) as usize;
 
         // Shared pane list pattern: clamp selection, trap scroll, then slice visible rows.
-        let total_lines = self.graph.branches_window.as_ref().map(|window| window.total).unwrap_or_else(|| self.branches.get_sorted_aliases().len());
+        let total_lines = self.tab.graph.branches_window.as_ref().map(|window| window.total).unwrap_or_else(|| self.tab.branches.get_sorted_aliases().len());
 
         if total_lines == 0 {
-            self.branches_selected = 0;
-        } else if self.branches_selected >= total_lines {
-            self.branches_selected = total_lines.saturating_sub(1);
+            self.tab.branches_selected = 0;
+        } else if self.tab.branches_selected >= total_lines {
+            self.tab.branches_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.branches_selected, &self.branches_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.branches_selected, &self.tab.branches_scroll, total_lines, visible_height);
 
-        let start = self.branches_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.branches_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
         let (preload_start, preload_end) = preloaded_pane_window(start, end, total_lines, visible_height);
         self.request_pane_window(GraphPane::Branches, preload_start, preload_end);
 
         // Render local/remote and visible/hidden state through the branch icon.
         let mut lines: Vec<Line<'_>> = Vec::new();
-        let lines_are_windowed = self.graph_tx.is_some();
-        let known_empty = self.graph.branches_window.as_ref().is_some_and(|window| window.total == 0);
-        if let Some(rows) = self.graph.branches_window.as_ref().and_then(|window| aligned_pane_rows(window, start, end)) {
+        let lines_are_windowed = self.tab.graph_tx.is_some();
+        let known_empty = self.tab.graph.branches_window.as_ref().is_some_and(|window| window.total == 0);
+        if let Some(rows) = self.tab.graph.branches_window.as_ref().and_then(|window| aligned_pane_rows(window, start, end)) {
             let color_picker = ColorPicker::from_theme(&self.theme);
             for row in rows {
                 if let Some(GraphPaneRow::Branch { name, is_local, lane, .. }) = row {
-                    let is_visible = !self.branches.hidden_branch_names.contains(name);
+                    let is_visible = !self.tab.branches.hidden_branch_names.contains(name);
                     let truncated = truncate_with_ellipsis(name, max_text_width.saturating_sub(1));
                     let icon = if is_visible {
                         if *is_local { self.symbols.branch.local_visible.as_str() } else { self.symbols.branch.remote_visible.as_str() }
@@ -64,10 +64,10 @@ impl App {
                     lines.push(Line::default());
                 }
             }
-        } else if self.graph_tx.is_none() {
-            for (branch_alias, branch_name) in self.branches.get_sorted_aliases().iter() {
-                let is_visible = !self.branches.hidden_branch_names.contains(branch_name);
-                let is_local = self.branches.is_local(branch_name);
+        } else if self.tab.graph_tx.is_none() {
+            for (branch_alias, branch_name) in self.tab.branches.get_sorted_aliases().iter() {
+                let is_visible = !self.tab.branches.hidden_branch_names.contains(branch_name);
+                let is_local = self.tab.branches.is_local(branch_name);
 
                 let truncated = truncate_with_ellipsis(branch_name, max_text_width.saturating_sub(1));
                 let icon = if is_visible {
@@ -77,7 +77,7 @@ impl App {
                 } else {
                     self.symbols.branch.remote_hidden.as_str()
                 };
-                let color = if is_visible { self.branches.get_color(&self.theme, branch_alias) } else { self.theme.COLOR_TEXT };
+                let color = if is_visible { self.tab.branches.get_color(&self.theme, branch_alias) } else { self.theme.COLOR_TEXT };
 
                 lines.push(Line::from(Span::styled(format!("{icon} {truncated}"), Style::default().fg(color))));
             }
@@ -100,7 +100,8 @@ impl App {
         // Selection is skipped for the synthetic empty row; striping still fills the pane.
         let display_start = if branches_empty || lines_are_windowed { 0 } else { start };
         let display_end = if branches_empty || lines_are_windowed { lines.len() } else { end };
-        let list_items = zebra_list_items(&lines[display_start..display_end], visible_height, start, self.branches_selected, self.focus == Focus::Branches, !branches_empty, &self.theme);
+        let list_items = zebra_list_items(&lines[display_start..display_end], visible_height, start, self.tab.branches_selected, self.tab.focus == Focus::Branches, !branches_empty, &self.theme);
+        let list_items = if branches_empty { list_items } else { highlight_marked_items(list_items, start, |index| self.is_pane_row_selected(Focus::Branches, index), &self.theme) };
 
         if self.layout_config.is_zen {
             // Zen mode frames the pane as a full standalone list.
@@ -109,14 +110,14 @@ impl App {
             frame.render_widget(list, self.layout.branches);
 
             let scroll_range = scrollbar_content_length(total_lines, visible_height);
-            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.branches_scroll.get());
+            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.branches_scroll.get());
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                 .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
                 .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-                .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Branches { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Branches { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.branches_scrollbar, &mut scrollbar_state);
 
@@ -129,7 +130,7 @@ impl App {
         frame.render_widget(list, self.layout.branches);
 
         let scroll_range = scrollbar_content_length(total_lines, visible_height);
-        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.branches_scroll.get());
+        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.branches_scroll.get());
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some(self.symbols.border.horizontal.as_str()))
             .end_symbol(Some(if self.layout_config.is_tags || self.layout_config.is_stashes || self.layout_config.is_reflogs || self.layout_config.is_worktrees || self.layout_config.is_search {
@@ -140,7 +141,7 @@ impl App {
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
             .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-            .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Branches { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Branches { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.branches_scrollbar, &mut scrollbar_state);
     }
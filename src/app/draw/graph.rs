@@ -1,5 +1,11 @@
 use crate::app::app::{App, Focus};
-use crate::core::renderers::{GRAPH_COMMITTER_WIDTH, render_committer_projection, render_date_projection, render_graph_projection, render_message_projection, render_sha_projection};
+use crate::core::{
+    chunk::NONE,
+    renderers::{
+        GRAPH_COMMITTER_WIDTH, GRAPH_SIGNATURE_WIDTH, render_committer_projection, render_date_projection, render_graph_projection, render_message_projection, render_sha_projection,
+        render_signature_projection,
+    },
+};
 use crate::helpers::{layout::scrollbar_content_length, localisation::empty};
 use ratatui::Frame;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
@@ -21,26 +27,19 @@ impl App {
         let total_lines = self.graph_commit_count();
         let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(2) as usize } else { self.layout.graph.height as usize };
 
-        let previous_selected = self.graph_selected;
+        let previous_selected = self.tab.graph_selected;
         if total_lines == 0 {
-            self.graph_selected = 0;
-        } else if self.graph_selected >= total_lines {
-            self.graph_selected = total_lines.saturating_sub(1);
+            self.tab.graph_selected = 0;
+        } else if self.tab.graph_selected >= total_lines {
+            self.tab.graph_selected = total_lines.saturating_sub(1);
         }
-        if self.graph_selected != previous_selected {
-            self.current_diff.clear();
-            self.current_diff_identity = None;
-            if self.graph_selected != 0
-                && let Some(identity) = self.graph_identity_at(self.graph_selected)
-            {
-                self.current_diff = crate::git::queries::diffs::get_filenames_diff_at_oid(repo, identity.oid);
-                self.current_diff_identity = Some(identity);
-            }
+        if self.tab.graph_selected != previous_selected {
+            self.request_selected_commit_diff();
         }
 
-        self.trap_selection(self.graph_selected, &self.graph_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.graph_selected, &self.tab.graph_scroll, total_lines, visible_height);
 
-        let start = self.graph_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.graph_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
 
         let (preload_start, preload_end) = graph_preload_window(start, end, total_lines, visible_height);
@@ -65,50 +64,66 @@ impl App {
             },
         }
 
+        // Signature checks spawn external tools, so only rows on screen are verified.
+        if self.layout_config.is_graph_signatures
+            && let Some(window) = self.tab.graph.graph_window.as_ref()
+        {
+            let oids: Vec<_> = window.rows.iter().filter(|row| row.alias != NONE && (start..end).contains(&row.index)).map(|row| row.oid).collect();
+            self.request_signatures(oids);
+        }
+
         let visible_len = end.saturating_sub(start);
-        let (sha_range, graph_range, date_range, committer_range, message_range) = if let Some(window) = self.graph.graph_window.as_ref().filter(|window| window.start < end && start < window.end) {
-            // SHA, graph, and message columns are rendered from the cached window, then reindexed
-            // into the requested viewport so scrolling still looks like movement while loading.
-            let render_uncommitted_row = graph_window_has_stable_visible_page(window, start, end);
-            let source_sha = if self.layout_config.is_shas { Some(render_sha_projection(&self.theme, &window.rows, self.graph_selected)) } else { None };
-            let source_date = if self.layout_config.is_graph_dates { Some(render_date_projection(&self.theme, &window.rows, self.graph_selected)) } else { None };
-            let source_committer = if self.layout_config.is_graph_committers { Some(render_committer_projection(&self.theme, &window.rows, self.graph_selected)) } else { None };
-            let source_graph = render_graph_projection(&self.theme, &self.symbols, &window.rows, &window.history, window.head_alias, window.start, window.end, render_uncommitted_row);
-            let source_message = render_message_projection(
-                &self.theme,
-                &self.symbols,
-                &window.rows,
-                self.layout_config.is_graph_reflogs,
-                self.layout_config.is_graph_refs,
-                self.graph_selected,
-                &self.uncommitted,
-                render_uncommitted_row,
-            );
-
-            (
-                source_sha.as_ref().map(|lines| align_projection(lines, window.start, start, end)),
-                align_projection(&source_graph, window.start, start, end),
-                source_date.as_ref().map(|lines| align_projection(lines, window.start, start, end)),
-                source_committer.as_ref().map(|lines| align_projection(lines, window.start, start, end)),
-                align_projection(&source_message, window.start, start, end),
-            )
-        } else {
-            (
-                self.layout_config.is_shas.then(|| blank_projection(visible_len)),
-                blank_projection(visible_len),
-                self.layout_config.is_graph_dates.then(|| blank_projection(visible_len)),
-                self.layout_config.is_graph_committers.then(|| blank_projection(visible_len)),
-                blank_projection(visible_len),
-            )
-        };
+        let (sha_range, graph_range, date_range, committer_range, signature_range, message_range) =
+            if let Some(window) = self.tab.graph.graph_window.as_ref().filter(|window| window.start < end && start < window.end) {
+                // SHA, graph, and message columns are rendered from the cached window, then reindexed
+                // into the requested viewport so scrolling still looks like movement while loading.
+                let render_uncommitted_row = graph_window_has_stable_visible_page(window, start, end);
+                let source_sha = if self.layout_config.is_shas { Some(render_sha_projection(&self.theme, &window.rows, self.tab.graph_selected)) } else { None };
+                let source_date = if self.layout_config.is_graph_dates { Some(render_date_projection(&self.theme, &window.rows, self.tab.graph_selected)) } else { None };
+                let source_committer = if self.layout_config.is_graph_committers { Some(render_committer_projection(&self.theme, &window.rows, self.tab.graph_selected)) } else { None };
+                let source_signature = if self.layout_config.is_graph_signatures { Some(render_signature_projection(&self.theme, &window.rows, &self.tab.graph.signatures)) } else { None };
+                let source_graph = render_graph_projection(&self.theme, &self.symbols, &window.rows, &window.history, window.head_alias, window.start, window.end, render_uncommitted_row);
+                let source_message = render_message_projection(
+                    &self.theme,
+                    &self.symbols,
+                    &window.rows,
+                    self.layout_config.is_graph_reflogs,
+                    self.layout_config.is_graph_refs,
+                    self.tab.graph_selected,
+                    &self.tab.uncommitted,
+                    render_uncommitted_row,
+                );
+
+                (
+                    source_sha.as_ref().map(|lines| align_projection(lines, window.start, start, end)),
+                    align_projection(&source_graph, window.start, start, end),
+                    source_date.as_ref().map(|lines| align_projection(lines, window.start, start, end)),
+                    source_committer.as_ref().map(|lines| align_projection(lines, window.start, start, end)),
+                    source_signature.as_ref().map(|lines| align_projection(lines, window.start, start, end)),
+                    align_projection(&source_message, window.start, start, end),
+                )
+            } else {
+                (
+                    self.layout_config.is_shas.then(|| blank_projection(visible_len)),
+                    blank_projection(visible_len),
+                    self.layout_config.is_graph_dates.then(|| blank_projection(visible_len)),
+                    self.layout_config.is_graph_committers.then(|| blank_projection(visible_len)),
+                    self.layout_config.is_graph_signatures.then(|| blank_projection(visible_len)),
+                    blank_projection(visible_len),
+                )
+            };
 
         // Build table rows and measure the graph column from rendered span widths.
         let mut rows = Vec::with_capacity(visible_height);
         let width = graph_range.iter().map(|line| line.spans.iter().filter(|span| !span.content.is_empty()).map(|span| span.content.chars().count()).sum::<usize>()).max().unwrap_or(0) as u16;
+        let drop_index = self.branch_drop_index();
         let search_highlight_indices: HashSet<usize> =
-            if self.layout_config.is_search && self.search_path.is_some() { self.search_rows.iter().map(|row| row.graph_index).filter(|&index| index != 0).collect() } else { HashSet::new() };
+            if self.layout_config.is_search && self.tab.search_path.is_some() { self.tab.search_rows.iter().map(|row| row.graph_index).filter(|&index| index != 0).collect() } else { HashSet::new() };
         for idx in 0..visible_height {
-            let optional_cell_count = usize::from(self.layout_config.is_shas) + usize::from(self.layout_config.is_graph_dates) + usize::from(self.layout_config.is_graph_committers);
+            let optional_cell_count = usize::from(self.layout_config.is_shas)
+                + usize::from(self.layout_config.is_graph_dates)
+                + usize::from(self.layout_config.is_graph_committers)
+                + usize::from(self.layout_config.is_graph_signatures);
             let mut cells = Vec::with_capacity(2 + optional_cell_count);
 
             if let Some(sha) = &sha_range {
@@ -121,15 +136,27 @@ impl App {
             if let Some(committer) = &committer_range {
                 cells.push(WidgetCell::from(committer.get(idx).cloned().unwrap_or_default()));
             }
+            if let Some(signature) = &signature_range {
+                cells.push(WidgetCell::from(signature.get(idx).cloned().unwrap_or_default()));
+            }
             cells.push(WidgetCell::from(message_range.get(idx).cloned().unwrap_or_default()));
 
             let mut row = Row::new(cells);
 
             // Selection highlighting is focus-sensitive so inactive panes stay quiet.
             let global_idx = idx + start;
-            let is_selected = idx < visible_len && global_idx == self.graph_selected && self.focus == Focus::Viewport;
+            let is_selected = idx < visible_len && global_idx == self.tab.graph_selected && self.tab.focus == Focus::Viewport;
             let is_search_highlighted = idx < visible_len && search_highlight_indices.contains(&global_idx);
-            if is_selected || is_search_highlighted {
+            let is_marked = idx < visible_len
+                && (drop_index == Some(global_idx)
+                    || (global_idx != 0 && self.is_in_visual_range(Focus::Viewport, global_idx))
+                    || (!self.tab.graph_marked_oids.is_empty() && self.graph_oid_at(global_idx).is_some_and(|oid| self.tab.graph_marked_oids.contains(&oid))));
+            // The cursor on a marked row is drawn one shade brighter, so it stays visible inside a run of marks.
+            if is_marked && is_selected {
+                row = row.style(Style::default().bg(self.theme.background_or_default(self.theme.COLOR_GREY_600)));
+            } else if is_marked {
+                row = row.style(Style::default().bg(self.theme.background_or_default(self.theme.COLOR_GREY_700)));
+            } else if is_selected || is_search_highlighted {
                 row = row.style(Style::default().bg(self.theme.background_or_default(self.theme.COLOR_GREY_800)));
             } else if global_idx.is_multiple_of(2) {
                 row = row.style(Style::default().bg(self.theme.background_or_default(self.theme.COLOR_GREY_900)));
@@ -150,8 +177,20 @@ impl App {
         if self.layout_config.is_graph_committers {
             constraints.push(ratatui::layout::Constraint::Length(GRAPH_COMMITTER_WIDTH as u16));
         }
+        if self.layout_config.is_graph_signatures {
+            constraints.push(ratatui::layout::Constraint::Length(GRAPH_SIGNATURE_WIDTH as u16));
+        }
         constraints.push(ratatui::layout::Constraint::Min(0));
 
+        // Dragging a branch label hit-tests against where the message column starts.
+        self.graph_message_x = constraints
+            .iter()
+            .map(|constraint| match constraint {
+                ratatui::layout::Constraint::Length(length) => length + 1,
+                _ => 0,
+            })
+            .fold(self.layout.graph.x.saturating_add(1), u16::saturating_add);
+
         if self.layout_config.is_zen {
             // Zen mode owns the full rounded graph frame.
             let table = Table::new(rows, constraints)
@@ -161,13 +200,13 @@ impl App {
             frame.render_widget(table, self.layout.graph);
 
             if total_lines > visible_height {
-                let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.graph_scroll.get());
+                let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.graph_scroll.get());
                 let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                     .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                     .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                     .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                     .thumb_symbol(self.symbols.scrollbar.thumb.as_str())
-                    .thumb_style(Style::default().fg(if self.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                    .thumb_style(Style::default().fg(if self.tab.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
                 frame.render_stateful_widget(scrollbar, self.layout.graph_scrollbar, &mut scrollbar_state);
             }
@@ -183,21 +222,21 @@ impl App {
         frame.render_widget(table, self.layout.graph);
 
         if total_lines > visible_height {
-            let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.graph_scroll.get());
+            let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.graph_scroll.get());
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                .begin_symbol(if (self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts)) || self.layout_config.is_status {
+                .begin_symbol(if (self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts)) || self.layout_config.is_status {
                     Some(self.symbols.border.horizontal.as_str())
                 } else {
                     Some(self.symbols.scrollbar.begin.as_str())
                 })
-                .end_symbol(if (self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts)) || self.layout_config.is_status {
+                .end_symbol(if (self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts)) || self.layout_config.is_status {
                     Some(self.symbols.border.horizontal.as_str())
                 } else {
                     Some(self.symbols.scrollbar.end.as_str())
                 })
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(self.symbols.scrollbar.thumb.as_str())
-                .thumb_style(Style::default().fg(if self.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if self.tab.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.graph_scrollbar, &mut scrollbar_state);
         }
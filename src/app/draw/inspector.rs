@@ -1,5 +1,6 @@
 use crate::{
     app::app::{App, Focus, PendingGraphLookup},
+    git::queries::{helpers::FileStatus, signatures::SignatureStatus},
     helpers::{
         colors::ColorPicker,
         layout::scrollbar_content_length,
@@ -26,27 +27,27 @@ impl App {
         let visible_height = if self.layout_config.is_zen { self.layout.inspector.height.saturating_sub(2) as usize } else { self.layout.inspector.height.saturating_sub(1) as usize };
 
         // The inspector is intentionally empty for the uncommitted pseudo-row.
-        let is_showing_uncommitted = self.graph_selected == 0;
+        let is_showing_uncommitted = self.tab.graph_selected == 0;
 
         let mut lines: Vec<Line<'_>> = Vec::new();
 
-        if is_showing_uncommitted && self.uncommitted.has_conflicts {
+        if is_showing_uncommitted && self.tab.uncommitted.has_conflicts {
             lines = vec![
                 Line::from(Span::styled(inspector::REPOSITORY_STATE(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))),
                 Line::from(Span::styled(inspector::OPERATION_CONFLICTS(), Style::default().fg(self.theme.COLOR_ORANGE))),
                 Line::default(),
                 Line::from(Span::styled(inspector::CONFLICTED_FILES(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))),
-                Line::from(Span::styled(self.uncommitted.conflict_count.to_string(), Style::default().fg(self.theme.COLOR_ORANGE))),
+                Line::from(Span::styled(self.tab.uncommitted.conflict_count.to_string(), Style::default().fg(self.theme.COLOR_ORANGE))),
                 Line::default(),
                 Line::from(Span::styled(inspector::NEXT_ACTION(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))),
                 Line::from(Span::styled(inspector::RESOLVE_CONFLICTS_ACTION(), Style::default().fg(self.theme.COLOR_TEXT))),
             ];
         } else if !is_showing_uncommitted {
             // Commit metadata is read lazily for the selected graph row.
-            if let Some(identity) = self.graph_identity_at(self.graph_selected) {
+            if let Some(identity) = self.graph_identity_at(self.tab.graph_selected) {
                 let alias = identity.alias;
                 let oid = identity.oid;
-                let commit = repo.find_commit(oid).unwrap();
+                let commit = repo.find_commit(self.commit_oid(oid)).unwrap();
                 let author = commit.author();
                 let committer = commit.committer();
                 let summary = commit.summary().map(str::to_string).unwrap_or_else(|| format!("{} {}", self.symbols.empty_state.mark, empty::NO_SUMMARY()));
@@ -63,7 +64,27 @@ impl App {
                     let text = truncate_with_ellipsis(&format!("#{}", parent_id), max_text_width);
                     lines.push(Line::from(Span::styled(text, Style::default().fg(self.theme.COLOR_TEXT))));
                 }
-                if let Some(row) = self.graph_row_at(self.graph_selected)
+                // Picking a mainline previews what the cherry-pick or revert would apply against that parent.
+                if self.tab.focus == Focus::ModalMainline {
+                    lines.push(Line::default());
+                    lines.push(Line::from(Span::styled(inspector::MAINLINE_DIFF(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))));
+                    for file_change in &self.modal_mainline_preview {
+                        let (symbol, color) = match file_change.status {
+                            FileStatus::Added => (self.symbols.status.added_spaced.as_str(), self.theme.COLOR_GREEN),
+                            FileStatus::Modified => (self.symbols.status.modified_spaced.as_str(), self.theme.COLOR_BLUE),
+                            FileStatus::Deleted => (self.symbols.status.deleted_spaced.as_str(), self.theme.COLOR_RED),
+                            FileStatus::Renamed => (self.symbols.status.renamed_arrow_spaced.as_str(), self.theme.COLOR_YELLOW),
+                            FileStatus::Other => (self.symbols.status.other_spaced.as_str(), self.theme.COLOR_TEXT),
+                        };
+                        let text = truncate_with_ellipsis(&file_change.filename, max_text_width.saturating_sub(symbol.chars().count()));
+                        lines.push(Line::from(vec![Span::styled(symbol.to_string(), Style::default().fg(color)), Span::styled(text, Style::default().fg(self.theme.COLOR_TEXT))]));
+                    }
+                    if self.modal_mainline_preview.is_empty() {
+                        let text = format!("{} {}", self.symbols.empty_state.mark, empty::NO_CHANGES());
+                        lines.push(Line::from(Span::styled(truncate_with_ellipsis(&text, max_text_width), Style::default().fg(self.theme.COLOR_GREY_800))));
+                    }
+                }
+                if let Some(row) = self.graph_row_at(self.tab.graph_selected)
                     && !row.branches.is_empty()
                 {
                     let color_picker = ColorPicker::from_theme(&self.theme);
@@ -74,17 +95,17 @@ impl App {
                         let color = branch.lane.map(|lane| color_picker.get_lane_ref(lane)).unwrap_or(self.theme.COLOR_TEXT);
                         lines.push(Line::from(Span::styled(text, Style::default().fg(color))));
                     }
-                } else if let Some(branches) = self.branches.all.get(&alias)
-                    && let Some(color) = self.branches.colors.get(&alias)
+                } else if let Some(branches) = self.tab.branches.all.get(&alias)
+                    && let Some(color) = self.tab.branches.colors.get(&alias)
                 {
                     lines.push(Line::default());
                     lines.push(Line::from(Span::styled(inspector::FEATURED_BRANCHES(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))));
-                    for branch in branches.iter().filter(|branch| !self.branches.hidden_branch_names.contains(*branch)) {
+                    for branch in branches.iter().filter(|branch| !self.tab.branches.hidden_branch_names.contains(*branch)) {
                         let text = truncate_with_ellipsis(&format!("{} {}", self.symbols.branch.local_visible, branch), max_text_width);
                         lines.push(Line::from(Span::styled(text, Style::default().fg(*color))));
                     }
                 }
-                if let Some(row) = self.graph_row_at(self.graph_selected)
+                if let Some(row) = self.graph_row_at(self.tab.graph_selected)
                     && let Some(entry) = &row.reflog
                 {
                     lines.push(Line::default());
@@ -94,10 +115,13 @@ impl App {
                     for line in wrapped {
                         lines.push(Line::from(Span::styled(line, Style::default().fg(self.theme.COLOR_TEXT))));
                     }
-                } else if let Some(entry) = self.reflogs.latest_for_alias(alias) {
+                } else if let Some(entry) = self.tab.reflogs.latest_for_alias(alias) {
                     lines.push(Line::default());
                     lines.push(Line::from(Span::styled(inspector::HEAD_REFLOG(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))));
-                    lines.push(Line::from(Span::styled(truncate_with_ellipsis(&entry.selector, max_text_width), Style::default().fg(self.reflogs.get_color(alias).unwrap_or(self.theme.COLOR_TEXT)))));
+                    lines.push(Line::from(Span::styled(
+                        truncate_with_ellipsis(&entry.selector, max_text_width),
+                        Style::default().fg(self.tab.reflogs.get_color(alias).unwrap_or(self.theme.COLOR_TEXT)),
+                    )));
                     lines.push(Line::from(Span::styled(timestamp_to_utc(entry.time), Style::default().fg(self.theme.COLOR_TEXT))));
                     let wrapped = wrap_words(sanitize(entry.message.clone()), max_text_width);
                     for line in wrapped {
@@ -114,8 +138,21 @@ impl App {
                     Line::from(Span::styled(committer.email().unwrap_or("").to_string(), Style::default().fg(self.theme.COLOR_TEXT))),
                     Line::from(Span::styled(timestamp_to_utc(committer.when()).to_string(), Style::default().fg(self.theme.COLOR_TEXT))),
                     Line::default(),
-                    Line::from(Span::styled(inspector::MESSAGE_SUMMARY(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))),
+                    Line::from(Span::styled(inspector::SIGNATURE(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))),
                 ]);
+                // Verification runs on the graph worker; the section fills in once it reports back.
+                self.request_signatures([oid]);
+                let signature = match self.tab.graph.signatures.get(&oid) {
+                    Some(SignatureStatus::Good { signer }) => (format!("{} {}", inspector::SIGNATURE_GOOD(), signer), self.theme.COLOR_GRASS),
+                    Some(SignatureStatus::Bad) => (inspector::SIGNATURE_BAD().to_string(), self.theme.COLOR_RED),
+                    Some(SignatureStatus::UnknownKey) => (inspector::SIGNATURE_UNKNOWN_KEY().to_string(), self.theme.COLOR_YELLOW),
+                    Some(SignatureStatus::Unsigned) => (inspector::SIGNATURE_UNSIGNED().to_string(), self.theme.COLOR_TEXT),
+                    None => (inspector::SIGNATURE_CHECKING().to_string(), self.theme.COLOR_GREY_600),
+                };
+                for line in wrap_words(signature.0, max_text_width) {
+                    lines.push(Line::from(Span::styled(line, Style::default().fg(signature.1))));
+                }
+                lines.extend(vec![Line::default(), Line::from(Span::styled(inspector::MESSAGE_SUMMARY(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED)))]);
                 let wrapped = wrap_words(sanitize(summary), max_text_width);
                 for line in wrapped {
                     lines.push(Line::from(Span::styled(line, Style::default().fg(self.theme.COLOR_TEXT))));
@@ -126,8 +163,8 @@ impl App {
                     lines.push(Line::from(Span::styled(line, Style::default().fg(self.theme.COLOR_TEXT))));
                 }
             } else {
-                if self.graph_tx.is_some() && self.graph.pending_lookup.is_none() {
-                    self.request_graph_row_lookup(self.graph_selected, PendingGraphLookup::CacheGraphRow);
+                if self.tab.graph_tx.is_some() && self.tab.graph.pending_lookup.is_none() {
+                    self.request_graph_row_lookup(self.tab.graph_selected, PendingGraphLookup::CacheGraphRow);
                 }
 
                 lines = centered_loading_lines(visible_height, max_text_width, Style::default().fg(self.theme.COLOR_GREY_800));
@@ -138,14 +175,14 @@ impl App {
         let total_lines = lines.len();
 
         if total_lines == 0 {
-            self.inspector_selected = 0;
-        } else if self.inspector_selected >= total_lines {
-            self.inspector_selected = total_lines.saturating_sub(1);
+            self.tab.inspector_selected = 0;
+        } else if self.tab.inspector_selected >= total_lines {
+            self.tab.inspector_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.inspector_selected, &self.inspector_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.inspector_selected, &self.tab.inspector_scroll, total_lines, visible_height);
 
-        let start = self.inspector_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.inspector_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
 
         // Selection highlight dims text to keep metadata subordinate to graph selection.
@@ -153,7 +190,7 @@ impl App {
             .iter()
             .enumerate()
             .map(|(i, line)| {
-                if start + i == self.inspector_selected && self.focus == Focus::Inspector {
+                if start + i == self.tab.inspector_selected && self.tab.focus == Focus::Inspector {
                     let spans: Vec<Span> = line.iter().map(|span| Span::styled(span.content.clone(), span.style.fg(self.theme.COLOR_HIGHLIGHTED))).collect();
                     ListItem::new(Line::from(spans)).style(Style::default().bg(self.theme.background_or_default(self.theme.COLOR_GREY_800)).fg(self.theme.COLOR_HIGHLIGHTED))
                 } else {
@@ -168,13 +205,13 @@ impl App {
 
             frame.render_widget(list, self.layout.inspector);
 
-            let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.inspector_scroll.get());
+            let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.inspector_scroll.get());
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                 .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
-                .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Inspector { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Inspector { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.inspector_scrollbar, &mut scrollbar_state);
 
@@ -186,19 +223,19 @@ impl App {
 
         frame.render_widget(list, self.layout.inspector);
 
-        let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.inspector_scroll.get());
+        let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.inspector_scroll.get());
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
             .end_symbol(if self.layout_config.is_status { Some(self.symbols.border.vertical.as_str()) } else { Some(self.symbols.scrollbar.end.as_str()) })
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
-            .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Inspector { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Inspector { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.inspector_scrollbar, &mut scrollbar_state);
     }
 }
 
-fn centered_loading_lines(visible_height: usize, max_width: usize, style: Style) -> Vec<Line<'static>> {
+pub(crate) fn centered_loading_lines(visible_height: usize, max_width: usize, style: Style) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
     for _ in 0..empty_state_top_padding(visible_height) {
         lines.push(Line::from(""));
@@ -0,0 +1,74 @@
+use crate::{
+    app::{
+        app::App,
+        draw::modals::shared::{action_row, modal_block},
+        input::patching::{PATCH_TARGETS, patch_target_label},
+    },
+    helpers::{localisation::modal, text::truncate_with_ellipsis},
+};
+use ratatui::Frame;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, Paragraph, Widget},
+};
+
+// Long mailboxes are cut short; the count of hidden patches is shown instead.
+const PREVIEW_ROWS: usize = 8;
+
+impl App {
+    pub fn draw_modal_apply_patch(&mut self, frame: &mut Frame) {
+        let mut length = 42;
+        let mut lines = Vec::new();
+        let text_style = Style::default().fg(self.theme.COLOR_TEXT);
+        let muted_style = Style::default().fg(self.theme.COLOR_GREY_600);
+
+        lines.push(Line::default());
+        let path_line = format!("{} {}", modal::PATH_LABEL(), truncate_with_ellipsis(&self.modal_patch_path.display().to_string(), 60));
+        length = length.max(path_line.chars().count() + 4);
+        lines.push(Line::from(Span::styled(path_line, text_style)));
+        lines.push(Line::default());
+
+        lines.push(Line::from(Span::styled(modal::PATCHES_IN_FILE(), text_style)));
+        for (idx, mail) in self.modal_patch_mails.iter().enumerate().take(PREVIEW_ROWS) {
+            let subject = if mail.subject.is_empty() { modal::PATCH_WITHOUT_SUBJECT() } else { mail.subject.as_str() };
+            let author = mail.author.as_ref().map(|(name, _)| format!(" ({name})")).unwrap_or_default();
+            let text = format!("{}/{} {}{author}", idx + 1, self.modal_patch_mails.len(), truncate_with_ellipsis(subject, 60));
+            length = length.max(text.chars().count() + 4);
+            lines.push(Line::from(Span::styled(text, muted_style)));
+        }
+        if self.modal_patch_mails.len() > PREVIEW_ROWS {
+            lines.push(Line::from(Span::styled(format!("+{}", self.modal_patch_mails.len() - PREVIEW_ROWS), muted_style)));
+        }
+        lines.push(Line::default());
+
+        let selected = self.modal_patch_selected.rem_euclid(PATCH_TARGETS.len() as i32) as usize;
+        for (idx, target) in PATCH_TARGETS.iter().enumerate() {
+            let is_selected = idx == selected;
+            let marker = if is_selected { &self.symbols.modal.selected } else { &self.symbols.modal.unselected };
+            let text = format!("{} {}", marker, patch_target_label(*target));
+            length = length.max(text.chars().count() + 4);
+            lines.push(Line::from(Span::styled(text, Style::default().fg(if is_selected { self.theme.COLOR_GRASS } else { self.theme.COLOR_TEXT }))));
+        }
+
+        lines.push(Line::default());
+        lines.push(action_row(&[(modal::ACTION_CONFIRM(), modal::KEY_ENTER())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED)));
+
+        let bg_block = Block::default().style(Style::default().fg(self.theme.COLOR_BORDER));
+        bg_block.render(frame.area(), frame.buffer_mut());
+
+        let modal_width = (length + 8).min((frame.area().width as f32 * 0.8) as usize) as u16;
+        let modal_height = (lines.len() + 4).min((frame.area().height as f32 * 0.8) as usize) as u16;
+        let x = frame.area().x + (frame.area().width.saturating_sub(modal_width)) / 2;
+        let y = frame.area().y + (frame.area().height.saturating_sub(modal_height)) / 2;
+        let modal_area = Rect::new(x, y, modal_width, modal_height);
+        self.modal_area = Some(modal_area);
+        self.theme.clear_area(modal_area, frame.buffer_mut());
+
+        let modal_block = modal_block(self.theme.COLOR_GREY_600, self.theme.COLOR_HIGHLIGHTED, &self.symbols);
+
+        let paragraph = Paragraph::new(Text::from(lines)).block(modal_block).alignment(Alignment::Center);
+        paragraph.render(modal_area, frame.buffer_mut());
+    }
+}
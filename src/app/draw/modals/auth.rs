@@ -109,7 +109,7 @@ impl App {
         render_modal_text_input(frame, area, input, masked, text_style, border_style, Some(Span::styled(format!(" {label} "), label_style)), active, &self.symbols);
     }
 
-    fn draw_auth_text_modal(&mut self, frame: &mut Frame, lines: Vec<Line>, border_color: ratatui::style::Color) {
+    pub(crate) fn draw_auth_text_modal(&mut self, frame: &mut Frame, lines: Vec<Line>, border_color: ratatui::style::Color) {
         let max_modal_width = (frame.area().width as f32 * 0.8) as usize;
         let content_width = lines.iter().map(|line| line.width()).max().unwrap_or(0);
         let modal_width = (content_width + 10).max(34).min(max_modal_width) as u16;
@@ -0,0 +1,68 @@
+use crate::app::{
+    app::{App, BatchAction},
+    draw::modals::shared::{action_row, modal_block},
+};
+use crate::helpers::{localisation::modal, text::truncate_with_ellipsis};
+use ratatui::Frame;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, Paragraph, Widget},
+};
+
+// Long selections list the first rows and a count of the rest.
+const MAX_LISTED_ITEMS: usize = 12;
+
+impl App {
+    pub fn draw_modal_batch(&mut self, frame: &mut Frame) {
+        let Some(action) = self.modal_batch_action else { return };
+        let items = self.batch_items(action);
+        let title = match action {
+            BatchAction::Stage => modal::batch_stage(items.len()),
+            BatchAction::Unstage => modal::batch_unstage(items.len()),
+            BatchAction::DiscardStaged | BatchAction::DiscardUnstaged => modal::batch_discard(items.len()),
+            BatchAction::DeleteBranches => modal::batch_delete_branches(items.len()),
+            BatchAction::DeleteTags => modal::batch_delete_tags(items.len()),
+            BatchAction::DropStashes => modal::batch_drop_stashes(items.len()),
+            BatchAction::Cherrypick => modal::batch_cherrypick(items.len()),
+            BatchAction::Revert => modal::batch_revert(items.len()),
+        };
+
+        let max_item_width = (frame.area().width as f32 * 0.8) as usize;
+        let mut length = 42.max(title.chars().count() + 8);
+        let mut lines = Vec::new();
+
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(title, Style::default().fg(self.theme.COLOR_TEXT))));
+        lines.push(Line::default());
+
+        for item in items.iter().take(MAX_LISTED_ITEMS) {
+            let item = truncate_with_ellipsis(item, max_item_width.saturating_sub(16));
+            length = length.max(item.chars().count() + 8);
+            lines.push(Line::from(Span::styled(item, Style::default().fg(self.theme.COLOR_GRAPEFRUIT))));
+        }
+        if items.len() > MAX_LISTED_ITEMS {
+            lines.push(Line::from(Span::styled(format!("+{}", items.len() - MAX_LISTED_ITEMS), Style::default().fg(self.theme.COLOR_GREY_600))));
+        }
+
+        lines.push(Line::default());
+        lines.push(action_row(&[(modal::ACTION_CONFIRM(), modal::KEY_ENTER())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED)));
+
+        let bg_block = Block::default().style(Style::default().fg(self.theme.COLOR_BORDER));
+        bg_block.render(frame.area(), frame.buffer_mut());
+
+        let modal_width = (length + 8).min(max_item_width) as u16;
+        let modal_height = (lines.len() + 4).min((frame.area().height as f32 * 0.8) as usize) as u16;
+        let x = frame.area().x + (frame.area().width.saturating_sub(modal_width)) / 2;
+        let y = frame.area().y + (frame.area().height.saturating_sub(modal_height)) / 2;
+        let modal_area = Rect::new(x, y, modal_width, modal_height);
+        self.modal_area = Some(modal_area);
+        self.theme.clear_area(modal_area, frame.buffer_mut());
+
+        let modal_block = modal_block(self.theme.COLOR_GREY_600, self.theme.COLOR_HIGHLIGHTED, &self.symbols);
+
+        let paragraph = Paragraph::new(Text::from(lines)).block(modal_block).alignment(Alignment::Center);
+        paragraph.render(modal_area, frame.buffer_mut());
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{
+    app::{
+        app::App,
+        draw::modals::shared::{action_row, modal_block, render_modal_text_input},
+    },
+    git::queries::grep::{GrepMatch, GrepScope},
+    helpers::{localisation::modal, text::truncate_with_ellipsis},
+};
+use ratatui::Frame;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph, Widget},
+};
+
+// "path:line" first, then the snippet with the matched part highlighted, cut to the row width.
+fn result_spans(result: &GrepMatch, max_width: usize, text_color: Color, location_color: Color, match_color: Color) -> Vec<Span<'static>> {
+    let location = format!("{}:{} ", result.path, result.line);
+    let location_width = location.chars().count().min(max_width);
+    let mut spans = vec![Span::styled(truncate_with_ellipsis(&location, max_width), Style::default().fg(location_color))];
+
+    let mut remaining = max_width.saturating_sub(location_width);
+    let chars: Vec<char> = result.snippet.chars().collect();
+    let (start, end) = result.matched;
+    for (range, color) in [(0..start, text_color), (start..end, match_color), (end..chars.len(), text_color)] {
+        let part: String = chars[range.start.min(chars.len())..range.end.min(chars.len())].iter().take(remaining).collect();
+        remaining = remaining.saturating_sub(part.chars().count());
+        if !part.is_empty() {
+            spans.push(Span::styled(part, Style::default().fg(color)));
+        }
+    }
+
+    spans
+}
+
+impl App {
+    pub fn draw_modal_content_search(&mut self, frame: &mut Frame) {
+        let length = 96;
+        let height = 24;
+
+        let bg_block = Block::default().style(Style::default().fg(self.theme.COLOR_BORDER));
+        bg_block.render(frame.area(), frame.buffer_mut());
+
+        let modal_width = length.min((frame.area().width as f32 * 0.85) as usize) as u16;
+        let modal_height = height.min((frame.area().height as f32 * 0.8) as usize) as u16;
+        let x = frame.area().x + (frame.area().width.saturating_sub(modal_width)) / 2;
+        let y = frame.area().y + (frame.area().height.saturating_sub(modal_height)) / 2;
+        let modal_area = Rect::new(x, y, modal_width, modal_height);
+        self.modal_area = Some(modal_area);
+
+        self.theme.clear_area(modal_area, frame.buffer_mut());
+
+        let modal_block = modal_block(self.theme.COLOR_GREY_600, self.theme.COLOR_HIGHLIGHTED, &self.symbols);
+        modal_block.render(modal_area, frame.buffer_mut());
+
+        let inner_width = modal_area.width.saturating_sub(8);
+        let inner_x = modal_area.x + 4;
+        let title_area = Rect { x: inner_x, y: modal_area.y + 2, width: inner_width, height: 1 };
+        let input_area = Rect { x: modal_area.x + 1, y: modal_area.y + 4, width: modal_area.width.saturating_sub(2), height: 5 };
+        let status_area = Rect { x: inner_x, y: modal_area.y + 9, width: inner_width, height: 1 };
+        let action_area = Rect { x: inner_x, y: modal_area.y + modal_area.height.saturating_sub(3), width: inner_width, height: 1 };
+        let list_y = modal_area.y + 11;
+        let list_bottom = action_area.y.saturating_sub(1);
+        let list_area = Rect { x: modal_area.x + 1, y: list_y, width: inner_width, height: list_bottom.saturating_sub(list_y) };
+
+        let scope = match self.modal_content_search_scope {
+            GrepScope::Workdir => modal::WORKING_TREE().to_string(),
+            GrepScope::Tree(oid) => oid.to_string()[..7].to_string(),
+        };
+        let title = format!("{} ({scope})", modal::PROMPT_SEARCH_CONTENTS());
+        frame.render_widget(Paragraph::new(Line::from(Span::styled(title, Style::default().fg(self.theme.COLOR_TEXT)))).alignment(Alignment::Center), title_area);
+
+        render_modal_text_input(frame, input_area, &mut self.modal_input, false, Style::default().fg(self.theme.COLOR_TEXT), Style::default().fg(self.theme.COLOR_GREY_800), None, true, &self.symbols);
+
+        // Mode, then what the search is doing: still running, how many matches, or why it stopped.
+        let mode = if self.modal_content_search_is_regex { modal::REGEX() } else { modal::LITERAL() };
+        let (status, status_color) = if let Some(error) = &self.modal_content_search_error {
+            (error.lines().last().unwrap_or_default().trim().to_string(), self.theme.COLOR_RED)
+        } else if self.modal_content_search_task.is_some() {
+            (modal::SEARCHING().to_string(), self.theme.COLOR_GREY_800)
+        } else if self.modal_content_search_is_truncated {
+            (modal::TOO_MANY_MATCHES().to_string(), self.theme.COLOR_ORANGE)
+        } else if self.modal_input.value().is_empty() {
+            (String::new(), self.theme.COLOR_GREY_800)
+        } else {
+            (modal::match_count(self.modal_content_search_results.len()), self.theme.COLOR_GREY_800)
+        };
+        let mut status_spans = vec![Span::styled(mode, Style::default().fg(self.theme.COLOR_HIGHLIGHTED))];
+        if !status.is_empty() {
+            status_spans.push(Span::styled(format!("  {status}"), Style::default().fg(status_color)));
+        }
+        frame.render_widget(Paragraph::new(Line::from(status_spans)), status_area);
+
+        let total = self.modal_content_search_results.len();
+        let visible_height = list_area.height as usize;
+        let mut selected = usize::try_from(self.modal_content_search_selected).unwrap_or(0);
+        if total == 0 {
+            selected = 0;
+            self.modal_content_search_selected = 0;
+            self.modal_content_search_scroll.set(0);
+        } else {
+            selected = selected.min(total.saturating_sub(1));
+            self.modal_content_search_selected = selected as i32;
+            self.trap_selection(selected, &self.modal_content_search_scroll, total, visible_height);
+        }
+
+        let start = self.modal_content_search_scroll.get().min(total.saturating_sub(visible_height));
+        let end = (start + visible_height).min(total);
+        let max_width = list_area.width.saturating_sub(2) as usize;
+
+        let list_items: Vec<ListItem<'static>> = if total == 0 {
+            let message = if self.modal_input.value().is_empty() {
+                modal::TYPE_TO_SEARCH()
+            } else if self.modal_content_search_task.is_some() || self.modal_content_search_error.is_some() {
+                ""
+            } else {
+                modal::NO_MATCHES()
+            };
+            vec![ListItem::new(Line::from(Span::styled(message, Style::default().fg(self.theme.COLOR_GREY_800))))]
+        } else {
+            self.modal_content_search_results[start..end]
+                .iter()
+                .enumerate()
+                .map(|(idx, result)| {
+                    let is_selected = start + idx == selected;
+                    let text_color = if is_selected { self.theme.COLOR_HIGHLIGHTED } else { self.theme.COLOR_TEXT };
+                    let mut spans = vec![Span::raw("  ")];
+                    spans.extend(result_spans(result, max_width, text_color, self.theme.COLOR_GREY_600, self.theme.COLOR_GRASS));
+                    ListItem::new(Line::from(spans))
+                })
+                .collect()
+        };
+
+        frame.render_widget(List::new(list_items), list_area);
+        frame.render_widget(
+            Paragraph::new(action_row(
+                &[(modal::ACTION_CHOOSE(), modal::KEY_ENTER()), (modal::ACTION_MOVE(), modal::KEY_CTRL_J_K()), (modal::ACTION_TOGGLE_REGEX(), modal::KEY_TAB())],
+                Style::default().fg(self.theme.COLOR_HIGHLIGHTED),
+            ))
+            .alignment(Alignment::Center),
+            action_area,
+        );
+    }
+}
+
+#[cfg(test)]
+#[path = "../../../tests/app/draw/modals/content_search.rs"]
+mod tests;
@@ -0,0 +1,51 @@
+use crate::{
+    app::{app::App, draw::modals::shared::action_row},
+    helpers::{
+        localisation::{common, modal},
+        text::truncate_with_ellipsis,
+    },
+};
+use ratatui::{
+    Frame,
+    style::Style,
+    text::{Line, Span},
+};
+
+// The tail of the output; longer logs are better read by running the command in a terminal.
+const CUSTOM_COMMAND_LOG_LINES: usize = 16;
+
+impl App {
+    pub fn draw_modal_custom_command(&mut self, frame: &mut Frame) {
+        let Some(task) = &self.custom_command_task else {
+            return;
+        };
+        let text_width = ((frame.area().width as f32 * 0.8) as usize).saturating_sub(10).clamp(1, 90);
+        let output: Vec<String> = task.log.lock().map(|lines| lines.iter().rev().take(CUSTOM_COMMAND_LOG_LINES).rev().cloned().collect()).unwrap_or_default();
+
+        let mut lines = vec![
+            Line::default(),
+            Line::from(Span::styled(modal::RUNNING_COMMAND(), Style::default().fg(self.theme.COLOR_TEXT))),
+            Line::from(Span::styled(task.name.clone(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))),
+            Line::default(),
+        ];
+        lines.extend(output.iter().map(|line| Line::from(Span::styled(truncate_with_ellipsis(line, text_width), Style::default().fg(self.theme.COLOR_GREY_600)))));
+        if !output.is_empty() {
+            lines.push(Line::default());
+        }
+
+        match &task.outcome {
+            None => lines.push(Line::from(Span::styled(common::WORKING(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED)))),
+            Some(Ok(())) => lines.push(Line::from(Span::styled(modal::COMMAND_FINISHED(), Style::default().fg(self.theme.COLOR_GRASS)))),
+            Some(Err(error)) => {
+                lines.push(Line::from(Span::styled(modal::COMMAND_FAILED(), Style::default().fg(self.theme.COLOR_RED))));
+                lines.push(Line::from(Span::styled(truncate_with_ellipsis(error, text_width), Style::default().fg(self.theme.COLOR_TEXT))));
+            },
+        }
+        if task.outcome.is_some() {
+            lines.push(Line::default());
+            lines.push(action_row(&[(modal::ACTION_OK(), modal::KEY_ENTER())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED)));
+        }
+
+        self.draw_auth_text_modal(frame, lines, self.theme.COLOR_BORDER);
+    }
+}
@@ -15,7 +15,7 @@ impl App {
     pub fn draw_modal_delete_branch(&mut self, frame: &mut Frame, repo: &git2::Repository) {
         let mut length = 30;
         let mut height = 8;
-        let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+        let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
             return;
         };
         let mut lines = Vec::new();
@@ -31,7 +31,7 @@ impl App {
         branches.iter().enumerate().for_each(|(idx, branch)| {
             height += 1;
             let is_selected = idx == self.modal_delete_branch_selected as usize;
-            let is_local = self.branches.local.values().any(|branches| branches.iter().any(|b| b.as_str() == branch));
+            let is_local = self.tab.branches.local.values().any(|branches| branches.iter().any(|b| b.as_str() == branch));
 
             let marker = if is_local { &self.symbols.branch.local_visible } else { &self.symbols.branch.remote_visible };
             let line_text = format!("{marker} {branch} ");
@@ -12,7 +12,7 @@ impl App {
     pub fn draw_modal_delete_tag(&mut self, frame: &mut Frame) {
         let mut length = 30;
         let mut height = 8;
-        let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+        let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
             return;
         };
         let mut lines = Vec::new();
@@ -23,7 +23,7 @@ impl App {
 
         // Tag choices come from the selected commit alias.
         let tags: Vec<String> =
-            self.graph_row_at(self.graph_selected).map(|row| row.tags.iter().map(|tag| tag.name.clone()).collect()).unwrap_or_else(|| self.tags.local.get(&alias).cloned().unwrap_or_default());
+            self.graph_row_at(self.tab.graph_selected).map(|row| row.tags.iter().map(|tag| tag.name.clone()).collect()).unwrap_or_else(|| self.tab.tags.local.get(&alias).cloned().unwrap_or_default());
         tags.iter().enumerate().for_each(|(idx, tag)| {
             height += 1;
             let is_selected = idx == self.modal_delete_tag_selected as usize;
@@ -0,0 +1,39 @@
+use crate::{
+    app::app::App,
+    helpers::{
+        localisation::{common, modal},
+        text::truncate_with_ellipsis,
+    },
+};
+use ratatui::{
+    Frame,
+    style::Style,
+    text::{Line, Span},
+};
+
+// Enough output to see why a hook is slow or failing without swamping the modal.
+const HOOK_LOG_LINES: usize = 8;
+
+impl App {
+    pub fn draw_modal_hook_progress(&mut self, frame: &mut Frame) {
+        let Some(task) = &self.hook_task else {
+            return;
+        };
+        let text_width = ((frame.area().width as f32 * 0.8) as usize).saturating_sub(10).clamp(1, 70);
+        let output: Vec<String> = task.log.lock().map(|lines| lines.iter().rev().take(HOOK_LOG_LINES).rev().cloned().collect()).unwrap_or_default();
+
+        let mut lines = vec![
+            Line::default(),
+            Line::from(Span::styled(modal::RUNNING_HOOKS(), Style::default().fg(self.theme.COLOR_TEXT))),
+            Line::from(Span::styled(task.title.clone(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))),
+            Line::default(),
+        ];
+        lines.extend(output.iter().map(|line| Line::from(Span::styled(truncate_with_ellipsis(line, text_width), Style::default().fg(self.theme.COLOR_GREY_600)))));
+        if !output.is_empty() {
+            lines.push(Line::default());
+        }
+        lines.push(Line::from(Span::styled(common::WORKING(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED))));
+
+        self.draw_auth_text_modal(frame, lines, self.theme.COLOR_BORDER);
+    }
+}
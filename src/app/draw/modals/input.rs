@@ -1,6 +1,6 @@
 use crate::{
     app::{
-        app::App,
+        app::{App, Focus},
         draw::modals::shared::{action_row, modal_block, render_modal_text_input},
     },
     helpers::localisation::modal,
@@ -27,7 +27,13 @@ impl App {
         lines.push(Line::from(Span::styled(title, Style::default().fg(self.theme.COLOR_TEXT))));
         lines.push(Line::default());
         lines.extend(vec![Line::default(); fill]);
-        lines.push(action_row(&[(modal::ACTION_CONFIRM(), modal::KEY_ENTER())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED)));
+        // The commit prompt can bypass verification hooks for this one commit.
+        let skip_hooks = format!("[{}] {}", if self.skip_hooks_once { "x" } else { " " }, modal::SKIP_HOOKS());
+        let mut actions = vec![(modal::ACTION_CONFIRM(), modal::KEY_ENTER())];
+        if self.tab.focus == Focus::ModalCommit {
+            actions.push((skip_hooks.as_str(), modal::KEY_TAB()));
+        }
+        lines.push(action_row(&actions, Style::default().fg(self.theme.COLOR_HIGHLIGHTED)));
 
         // Paint a plain overlay before clearing the modal rectangle.
         let bg_block = Block::default().style(Style::default().fg(self.theme.COLOR_BORDER));
@@ -0,0 +1,84 @@
+use crate::{
+    app::{app::App, draw::modals::shared::modal_block},
+    git::actions::journal::JournalEntry,
+    helpers::{
+        keymap::command_to_visual_string,
+        localisation::{empty, modal},
+        time::timestamp_to_utc_date_time,
+    },
+};
+use ratatui::Frame;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, Paragraph, Widget},
+};
+
+fn short_oid(oid: &Option<String>) -> &str {
+    oid.as_deref().map(|oid| &oid[..oid.len().min(7)]).unwrap_or("0000000")
+}
+
+fn short_ref(name: &str) -> &str {
+    ["refs/heads/", "refs/tags/", "refs/remotes/"].iter().find_map(|prefix| name.strip_prefix(prefix)).unwrap_or(name)
+}
+
+// Summarises the first change of an entry, with a count of anything else it touched.
+fn entry_detail(entry: &JournalEntry) -> String {
+    let changes = entry.refs.len() + entry.dropped_stashes.len();
+    let detail = if let Some(journal_ref) = entry.refs.first() {
+        format!("{} {}..{}", short_ref(&journal_ref.name), short_oid(&journal_ref.before), short_oid(&journal_ref.after))
+    } else if let Some(stash) = entry.dropped_stashes.first() {
+        format!("stash #{}", &stash.oid[..stash.oid.len().min(7)])
+    } else {
+        format!("HEAD {}..{}", short_ref(&entry.head_before), short_ref(&entry.head_after))
+    };
+
+    if changes > 1 { format!("{detail} +{}", changes - 1) } else { detail }
+}
+
+impl App {
+    pub fn draw_modal_journal(&mut self, frame: &mut Frame) {
+        let mut length = 40;
+        let mut height = 8;
+        let mut lines = Vec::new();
+        lines.push(Line::default());
+        lines.push(Line::from(vec![Span::styled(modal::SELECT_JOURNAL_ENTRY(), Style::default().fg(self.theme.COLOR_TEXT))]));
+        lines.push(Line::default());
+
+        if self.tab.journal.is_empty() {
+            height += 1;
+            lines.push(Line::from(Span::styled(empty::NO_JOURNAL_ENTRIES(), Style::default().fg(self.theme.COLOR_GREY_600))));
+        }
+
+        // Newest first; everything from the top down to the selection gets undone.
+        let max_rows = ((frame.area().height as f32 * 0.6) as usize).saturating_sub(8).max(1);
+        let selected = self.modal_journal_selected.max(0) as usize;
+        let start = selected.saturating_sub(max_rows - 1);
+        self.tab.journal.iter().rev().enumerate().skip(start).take(max_rows).for_each(|(idx, entry)| {
+            height += 1;
+            let text = format!("{} {} {} ", timestamp_to_utc_date_time(git2::Time::new(entry.time, 0)), command_to_visual_string(&entry.operation), entry_detail(entry));
+            length = (10 + text.chars().count()).max(length);
+            let color = if idx <= selected { self.theme.COLOR_GRASS } else { self.theme.COLOR_TEXT };
+            lines.push(Line::from(Span::styled(text, Style::default().fg(color))));
+        });
+
+        // Paint a plain overlay before clearing the modal rectangle.
+        let bg_block = Block::default().style(Style::default().fg(self.theme.COLOR_BORDER));
+        bg_block.render(frame.area(), frame.buffer_mut());
+
+        let modal_width = length.min((frame.area().width as f32 * 0.8) as usize) as u16;
+        let modal_height = height.min((frame.area().height as f32 * 0.6) as usize) as u16;
+        let x = frame.area().x + (frame.area().width.saturating_sub(modal_width)) / 2;
+        let y = frame.area().y + (frame.area().height.saturating_sub(modal_height)) / 2;
+        let modal_area = Rect::new(x, y, modal_width, modal_height);
+        self.modal_area = Some(modal_area);
+        self.theme.clear_area(modal_area, frame.buffer_mut());
+
+        let modal_block = modal_block(self.theme.COLOR_GREY_600, self.theme.COLOR_HIGHLIGHTED, &self.symbols);
+
+        let paragraph = Paragraph::new(Text::from(lines)).block(modal_block).alignment(Alignment::Center);
+
+        paragraph.render(modal_area, frame.buffer_mut());
+    }
+}
@@ -40,6 +40,9 @@ impl App {
             lines.push(Line::default());
             let message = match error {
                 KeymapEditError::Conflict { mode, key, command } => modal::keymap_conflict(&input_mode_to_visual_string(*mode), &keybinding_to_visual_string(key), &command_to_visual_string(command)),
+                KeymapEditError::PrefixConflict { mode, key, command } => {
+                    modal::keymap_prefix_conflict(input_mode_to_visual_string(*mode), &keybinding_to_visual_string(key), &command_to_visual_string(command))
+                },
                 KeymapEditError::MissingMode(mode) => modal::keymap_missing_mode(&input_mode_to_visual_string(*mode)),
                 KeymapEditError::MissingBinding { mode, key } => modal::keymap_missing_binding(&input_mode_to_visual_string(*mode), &keybinding_to_visual_string(key)),
                 KeymapEditError::CommandChanged { mode, key, expected, actual } => {
@@ -53,7 +56,7 @@ impl App {
 
         lines.push(Line::default());
         let line = if self.modal_key_capture_candidate.is_some() && self.modal_key_capture_error.is_none() {
-            action_row(&[(modal::ACTION_SAVE(), modal::KEY_ENTER())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED))
+            action_row(&[(modal::ACTION_SAVE(), modal::KEY_ENTER()), (modal::ACTION_REMOVE_KEY(), modal::KEY_BACKSPACE())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED))
         } else {
             Line::from(Span::styled(modal::PRESS_KEY(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED)))
         };
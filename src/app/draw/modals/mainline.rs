@@ -0,0 +1,50 @@
+use crate::{
+    app::{app::App, draw::modals::shared::modal_block},
+    helpers::localisation::modal,
+};
+use ratatui::Frame;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Paragraph, Widget},
+};
+
+impl App {
+    pub fn draw_modal_mainline(&mut self, frame: &mut Frame) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let mut length = 30;
+        let mut height = 8;
+        let mut lines = Vec::new();
+        let line_text = modal::SELECT_MAINLINE_PARENT();
+        lines.push(Line::default());
+        lines.push(Line::from(vec![Span::styled(line_text, Style::default().fg(self.theme.COLOR_TEXT))]));
+        lines.push(Line::default());
+
+        self.mainline_parents().iter().enumerate().for_each(|(idx, oid)| {
+            height += 1;
+            let is_selected = idx == self.modal_mainline_selected as usize;
+            let summary = repo.find_commit(*oid).ok().and_then(|commit| commit.summary().map(str::to_string)).unwrap_or_default();
+            let sha = oid.to_string();
+            let text = format!("{} #{} {summary} ", idx + 1, &sha[..7]);
+            length = (10 + text.chars().count()).max(length);
+            let style = Style::default().fg(if is_selected { self.theme.COLOR_GRASS } else { self.theme.COLOR_TEXT });
+            lines.push(Line::from(Span::styled(text, style)));
+        });
+
+        // No overlay here: the inspector keeps showing the diff against the highlighted parent.
+        let modal_width = length.min((frame.area().width as f32 * 0.6) as usize) as u16;
+        let modal_height = height.min((frame.area().height as f32 * 0.6) as usize) as u16;
+        let x = frame.area().x + (frame.area().width.saturating_sub(modal_width)) / 2;
+        let y = frame.area().y + (frame.area().height.saturating_sub(modal_height)) / 2;
+        let modal_area = Rect::new(x, y, modal_width, modal_height);
+        self.modal_area = Some(modal_area);
+        self.theme.clear_area(modal_area, frame.buffer_mut());
+
+        let modal_block = modal_block(self.theme.COLOR_GREY_600, self.theme.COLOR_HIGHLIGHTED, &self.symbols);
+
+        let paragraph = Paragraph::new(Text::from(lines)).block(modal_block).alignment(Alignment::Center);
+
+        paragraph.render(modal_area, frame.buffer_mut());
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    app::{
+        app::App,
+        draw::modals::shared::{action_row, modal_block, render_modal_text_input},
+        input::palette::PaletteEntry,
+    },
+    helpers::localisation::modal,
+};
+use ratatui::Frame;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::{Block, List, ListItem, Paragraph, Widget},
+};
+use std::collections::HashSet;
+
+fn entry_spans(entry: &PaletteEntry, width: usize, text_color: Color, match_color: Color, key_color: Color) -> Vec<Span<'static>> {
+    let matched: HashSet<usize> = entry.matched_indices.iter().copied().collect();
+    let reason = entry.disabled.map(|reason| format!(" — {reason}")).unwrap_or_default();
+    let keys_width = entry.keys.chars().count();
+    let label_width = width.saturating_sub(keys_width + 1);
+
+    let mut text: Vec<(char, bool)> = entry.label.chars().enumerate().map(|(idx, ch)| (ch, matched.contains(&idx))).collect();
+    text.extend(reason.chars().map(|ch| (ch, false)));
+    if text.len() > label_width {
+        text.truncate(label_width.saturating_sub(3));
+        text.extend("...".chars().map(|ch| (ch, false)));
+    }
+
+    let used = text.len();
+    let mut spans: Vec<Span<'static>> = text
+        .into_iter()
+        .map(|(ch, is_match)| {
+            let color = if is_match && entry.disabled.is_none() { match_color } else { text_color };
+            Span::styled(ch.to_string(), Style::default().fg(color))
+        })
+        .collect();
+    spans.push(Span::raw(" ".repeat(width.saturating_sub(used + keys_width))));
+    spans.push(Span::styled(entry.keys.clone(), Style::default().fg(key_color)));
+    spans
+}
+
+impl App {
+    pub fn draw_modal_command_palette(&mut self, frame: &mut Frame, title: &str) {
+        let length = 76;
+        let height = 20;
+
+        let bg_block = Block::default().style(Style::default().fg(self.theme.COLOR_BORDER));
+        bg_block.render(frame.area(), frame.buffer_mut());
+
+        let modal_width = length.min((frame.area().width as f32 * 0.85) as usize) as u16;
+        let modal_height = height.min((frame.area().height as f32 * 0.8) as usize) as u16;
+        let x = frame.area().x + (frame.area().width.saturating_sub(modal_width)) / 2;
+        let y = frame.area().y + (frame.area().height.saturating_sub(modal_height)) / 2;
+        let modal_area = Rect::new(x, y, modal_width, modal_height);
+        self.modal_area = Some(modal_area);
+
+        self.theme.clear_area(modal_area, frame.buffer_mut());
+
+        let modal_block = modal_block(self.theme.COLOR_GREY_600, self.theme.COLOR_HIGHLIGHTED, &self.symbols);
+        modal_block.render(modal_area, frame.buffer_mut());
+
+        let inner_width = modal_area.width.saturating_sub(8);
+        let inner_x = modal_area.x + 4;
+        let title_area = Rect { x: inner_x, y: modal_area.y + 2, width: inner_width, height: 1 };
+        let input_area = Rect { x: modal_area.x + 1, y: modal_area.y + 4, width: modal_area.width.saturating_sub(2), height: 5 };
+        let action_area = Rect { x: inner_x, y: modal_area.y + modal_area.height.saturating_sub(3), width: inner_width, height: 1 };
+        let list_y = modal_area.y + 10;
+        let list_bottom = action_area.y.saturating_sub(1);
+        let list_area = Rect { x: modal_area.x + 1, y: list_y, width: inner_width, height: list_bottom.saturating_sub(list_y) };
+
+        frame.render_widget(Paragraph::new(Line::from(Span::styled(title.to_string(), Style::default().fg(self.theme.COLOR_TEXT)))).alignment(Alignment::Center), title_area);
+
+        render_modal_text_input(frame, input_area, &mut self.modal_input, false, Style::default().fg(self.theme.COLOR_TEXT), Style::default().fg(self.theme.COLOR_GREY_800), None, true, &self.symbols);
+
+        let entries = self.palette_entries();
+        let total = entries.len();
+        let visible_height = list_area.height as usize;
+        let mut selected = self.modal_palette_selected;
+        if total == 0 {
+            selected = 0;
+            self.modal_palette_selected = 0;
+            self.modal_palette_scroll.set(0);
+        } else {
+            selected = selected.min(total.saturating_sub(1));
+            self.modal_palette_selected = selected;
+            self.trap_selection(selected, &self.modal_palette_scroll, total, visible_height);
+        }
+
+        let start = self.modal_palette_scroll.get().min(total.saturating_sub(visible_height));
+        let end = (start + visible_height).min(total);
+        let row_width = list_area.width.saturating_sub(2) as usize;
+
+        let list_items: Vec<ListItem<'static>> = if total == 0 {
+            vec![ListItem::new(Line::from(Span::styled(modal::NO_MATCHES(), Style::default().fg(self.theme.COLOR_GREY_800))))]
+        } else {
+            entries[start..end]
+                .iter()
+                .enumerate()
+                .map(|(idx, entry)| {
+                    let is_selected = start + idx == selected;
+                    let text_color = match (entry.disabled.is_some(), is_selected) {
+                        (true, _) => self.theme.COLOR_GREY_600,
+                        (false, true) => self.theme.COLOR_HIGHLIGHTED,
+                        (false, false) => self.theme.COLOR_TEXT,
+                    };
+                    let marker = if is_selected { "> " } else { "  " };
+                    let mut spans = vec![Span::styled(marker, Style::default().fg(self.theme.COLOR_HIGHLIGHTED))];
+                    spans.extend(entry_spans(entry, row_width, text_color, self.theme.COLOR_GRASS, self.theme.COLOR_GREY_600));
+                    ListItem::new(Line::from(spans))
+                })
+                .collect()
+        };
+
+        frame.render_widget(List::new(list_items), list_area);
+        frame.render_widget(
+            Paragraph::new(action_row(&[(modal::ACTION_RUN(), modal::KEY_ENTER()), (modal::ACTION_MOVE(), modal::KEY_CTRL_J_K())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED)))
+                .alignment(Alignment::Center),
+            action_area,
+        );
+    }
+}
@@ -18,7 +18,7 @@ use ratatui::{
 
 impl App {
     pub fn draw_modal_rebase(&mut self, frame: &mut Frame) {
-        let title = match self.focus {
+        let title = match self.tab.focus {
             Focus::ModalOperationProgress => self.modal_operation_kind.label().to_string(),
             Focus::ModalOperationConflict => format!("{} {}", self.modal_operation_kind.label(), operations::CONFLICT()),
             Focus::ModalOperationSuccess => format!("{} {}", self.modal_operation_kind.label(), operations::COMPLETE()),
@@ -30,17 +30,17 @@ impl App {
         let wrapped_message = wrap_words(self.modal_operation_message.clone(), text_width);
         let mut lines = Vec::new();
         lines.push(Line::default());
-        lines.push(Line::from(Span::styled(title, Style::default().fg(if self.focus == Focus::ModalOperationConflict { self.theme.COLOR_ORANGE } else { self.theme.COLOR_TEXT }))));
+        lines.push(Line::from(Span::styled(title, Style::default().fg(if self.tab.focus == Focus::ModalOperationConflict { self.theme.COLOR_ORANGE } else { self.theme.COLOR_TEXT }))));
         lines.push(Line::default());
         for line in wrapped_message {
             lines.push(Line::from(Span::styled(line, Style::default().fg(self.theme.COLOR_TEXT))));
         }
         lines.push(Line::default());
-        if self.focus == Focus::ModalOperationConflict {
+        if self.tab.focus == Focus::ModalOperationConflict {
             lines.push(Line::from(Span::styled(operations::RESOLVE_CONFLICTS(), Style::default().fg(self.theme.COLOR_TEXT))));
             lines.push(Line::default());
         }
-        let action_line = if self.focus == Focus::ModalOperationProgress {
+        let action_line = if self.tab.focus == Focus::ModalOperationProgress {
             Line::from(Span::styled(common::WORKING(), Style::default().fg(self.theme.COLOR_HIGHLIGHTED)))
         } else {
             action_row(&[(modal::ACTION_OK(), modal::KEY_ENTER())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED))
@@ -58,7 +58,7 @@ impl App {
 
         self.theme.clear_area(modal_area, frame.buffer_mut());
 
-        let border_color = if self.focus == Focus::ModalOperationConflict { self.theme.COLOR_ORANGE } else { self.theme.COLOR_BORDER };
+        let border_color = if self.tab.focus == Focus::ModalOperationConflict { self.theme.COLOR_ORANGE } else { self.theme.COLOR_BORDER };
         let modal_block = modal_block(border_color, self.theme.COLOR_HIGHLIGHTED, &self.symbols);
 
         Paragraph::new(Text::from(lines)).block(modal_block).alignment(Alignment::Center).render(modal_area, frame.buffer_mut());
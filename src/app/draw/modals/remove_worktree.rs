@@ -20,7 +20,7 @@ impl App {
         lines.push(Line::from(Span::styled(modal::REMOVE_SELECTED_WORKTREE(), Style::default().fg(self.theme.COLOR_TEXT))));
         lines.push(Line::default());
 
-        if let Some(entry) = self.modal_worktree_target.and_then(|idx| self.worktrees.entries.get(idx)) {
+        if let Some(entry) = self.modal_worktree_target.and_then(|idx| self.tab.worktrees.entries.get(idx)) {
             length = length.max(entry.name.len() + 12);
             length = length.max(entry.path.display().to_string().len() + 8);
             lines.push(Line::from(Span::styled(format!("{} {}", modal::NAME_LABEL(), entry.name), Style::default().fg(self.theme.COLOR_GRAPEFRUIT))));
@@ -0,0 +1,93 @@
+use crate::{
+    app::{
+        app::App,
+        draw::modals::shared::{action_row, modal_block},
+    },
+    git::queries::commits::get_current_branch,
+    helpers::{
+        keymap::{Command, command_to_visual_string},
+        localisation::{empty, modal},
+    },
+};
+use ratatui::Frame;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span, Text},
+    widgets::{Block, Paragraph, Widget},
+};
+
+// Long previews are cut short; the count of hidden rows is shown instead.
+const PREVIEW_ROWS: usize = 8;
+
+impl App {
+    pub fn draw_modal_reset(&mut self, frame: &mut Frame) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let is_hard = self.modal_reset_kind == Command::HardReset;
+        let mut length = 42;
+        let mut lines = Vec::new();
+        let text_style = Style::default().fg(self.theme.COLOR_TEXT);
+        let muted_style = Style::default().fg(self.theme.COLOR_GREY_600);
+        let danger_style = Style::default().fg(self.theme.COLOR_GRAPEFRUIT);
+
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(command_to_visual_string(&self.modal_reset_kind).to_lowercase(), if is_hard { danger_style } else { text_style })));
+        let branch = self.modal_reset_branch.clone().or_else(|| get_current_branch(&repo)).unwrap_or_else(|| "HEAD".to_string());
+        lines.push(Line::from(Span::styled(format!("{} {branch}", modal::BRANCH_LABEL()), text_style)));
+        lines.push(Line::from(Span::styled(format!("{} {}", modal::TARGET_LABEL(), self.modal_reset_label), text_style)));
+        lines.push(Line::default());
+
+        let unreachable = &self.modal_reset_preview.unreachable;
+        if unreachable.is_empty() {
+            lines.push(Line::from(Span::styled(empty::NO_UNREACHABLE_COMMITS(), muted_style)));
+        } else {
+            lines.push(Line::from(Span::styled(modal::RESET_UNREACHABLE_COMMITS(), text_style)));
+            for oid in unreachable.iter().take(PREVIEW_ROWS) {
+                let summary = repo.find_commit(*oid).ok().and_then(|commit| commit.summary().map(str::to_string)).unwrap_or_default();
+                let text = format!("#{} {summary}", &oid.to_string()[..7]);
+                length = length.max(text.chars().count() + 4);
+                lines.push(Line::from(Span::styled(text, danger_style)));
+            }
+            if unreachable.len() > PREVIEW_ROWS {
+                lines.push(Line::from(Span::styled(format!("+{}", unreachable.len() - PREVIEW_ROWS), muted_style)));
+            }
+        }
+
+        // Soft and mixed resets leave the worktree alone, so only a hard reset lists files.
+        if is_hard {
+            lines.push(Line::default());
+            let overwritten = &self.modal_reset_preview.overwritten;
+            if overwritten.is_empty() {
+                lines.push(Line::from(Span::styled(empty::NO_OVERWRITTEN_FILES(), muted_style)));
+            } else {
+                lines.push(Line::from(Span::styled(modal::RESET_OVERWRITTEN_FILES(), text_style)));
+                for path in overwritten.iter().take(PREVIEW_ROWS) {
+                    length = length.max(path.chars().count() + 4);
+                    lines.push(Line::from(Span::styled(path.clone(), danger_style)));
+                }
+                if overwritten.len() > PREVIEW_ROWS {
+                    lines.push(Line::from(Span::styled(format!("+{}", overwritten.len() - PREVIEW_ROWS), muted_style)));
+                }
+            }
+        }
+
+        lines.push(Line::default());
+        lines.push(action_row(&[(modal::ACTION_CONFIRM(), modal::KEY_ENTER())], Style::default().fg(self.theme.COLOR_HIGHLIGHTED)));
+
+        let bg_block = Block::default().style(Style::default().fg(self.theme.COLOR_BORDER));
+        bg_block.render(frame.area(), frame.buffer_mut());
+
+        let modal_width = (length + 8).min((frame.area().width as f32 * 0.8) as usize) as u16;
+        let modal_height = (lines.len() + 4).min((frame.area().height as f32 * 0.8) as usize) as u16;
+        let x = frame.area().x + (frame.area().width.saturating_sub(modal_width)) / 2;
+        let y = frame.area().y + (frame.area().height.saturating_sub(modal_height)) / 2;
+        let modal_area = Rect::new(x, y, modal_width, modal_height);
+        self.modal_area = Some(modal_area);
+        self.theme.clear_area(modal_area, frame.buffer_mut());
+
+        let modal_block = modal_block(self.theme.COLOR_GREY_600, self.theme.COLOR_HIGHLIGHTED, &self.symbols);
+
+        let paragraph = Paragraph::new(Text::from(lines)).block(modal_block).alignment(Alignment::Center);
+        paragraph.render(modal_area, frame.buffer_mut());
+    }
+}
@@ -29,7 +29,7 @@ impl App {
         let max_line_width = (frame.area().width as usize).saturating_sub(16).max(24);
 
         for (idx, entry_idx) in self.modal_worktree_candidates.iter().enumerate() {
-            let Some(entry) = self.worktrees.entries.get(*entry_idx) else {
+            let Some(entry) = self.tab.worktrees.entries.get(*entry_idx) else {
                 continue;
             };
 
@@ -48,6 +48,11 @@ pub(super) fn zebra_list_items<'a>(lines: &[Line<'a>], visible_height: usize, gl
         .collect()
 }
 
+// Marked rows and the live visual range use the same background as marked graph rows.
+pub(super) fn highlight_marked_items<'a>(items: Vec<ListItem<'a>>, global_start: usize, is_marked: impl Fn(usize) -> bool, theme: &Theme) -> Vec<ListItem<'a>> {
+    items.into_iter().enumerate().map(|(idx, item)| if is_marked(global_start + idx) { item.style(Style::default().bg(theme.background_or_default(theme.COLOR_GREY_700))) } else { item }).collect()
+}
+
 #[cfg(test)]
 #[path = "../../tests/app/draw/pane_window.rs"]
 mod tests;
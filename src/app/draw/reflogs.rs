@@ -29,25 +29,25 @@ impl App {
         let has_next = self.layout_config.is_worktrees || self.layout_config.is_search;
         let visible_height =
             if self.layout_config.is_zen { self.layout.reflogs.height.saturating_sub(2) as usize } else { self.layout.reflogs.height.saturating_sub(if has_previous { 1 } else { 2 }) as usize };
-        let total_lines = self.graph.reflogs_window.as_ref().map(|window| window.total).unwrap_or(self.reflogs.entries.len());
+        let total_lines = self.tab.graph.reflogs_window.as_ref().map(|window| window.total).unwrap_or(self.tab.reflogs.entries.len());
 
         if total_lines == 0 {
-            self.reflogs_selected = 0;
-        } else if self.reflogs_selected >= total_lines {
-            self.reflogs_selected = total_lines.saturating_sub(1);
+            self.tab.reflogs_selected = 0;
+        } else if self.tab.reflogs_selected >= total_lines {
+            self.tab.reflogs_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.reflogs_selected, &self.reflogs_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.reflogs_selected, &self.tab.reflogs_scroll, total_lines, visible_height);
 
-        let start = self.reflogs_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.reflogs_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
         let (preload_start, preload_end) = preloaded_pane_window(start, end, total_lines, visible_height);
         self.request_pane_window(GraphPane::Reflogs, preload_start, preload_end);
 
         let mut lines: Vec<Line<'_>> = Vec::new();
-        let lines_are_windowed = self.graph_tx.is_some();
-        let known_empty = self.graph.reflogs_window.as_ref().is_some_and(|window| window.total == 0);
-        if let Some(rows) = self.graph.reflogs_window.as_ref().and_then(|window| aligned_pane_rows(window, start, end)) {
+        let lines_are_windowed = self.tab.graph_tx.is_some();
+        let known_empty = self.tab.graph.reflogs_window.as_ref().is_some_and(|window| window.total == 0);
+        if let Some(rows) = self.tab.graph.reflogs_window.as_ref().and_then(|window| aligned_pane_rows(window, start, end)) {
             let color_picker = ColorPicker::from_theme(&self.theme);
             for row in rows {
                 if let Some(GraphPaneRow::Reflog { selector, message, lane, .. }) = row {
@@ -58,10 +58,10 @@ impl App {
                     lines.push(Line::default());
                 }
             }
-        } else if self.graph_tx.is_none() {
-            for entry in &self.reflogs.entries {
+        } else if self.tab.graph_tx.is_none() {
+            for entry in &self.tab.reflogs.entries {
                 let label = truncate_with_ellipsis(&format!("{} {}", entry.selector, entry.message), max_text_width.saturating_sub(1));
-                let color = self.reflogs.get_color(entry.new_alias).unwrap_or(self.theme.COLOR_TEXT);
+                let color = self.tab.reflogs.get_color(entry.new_alias).unwrap_or(self.theme.COLOR_TEXT);
                 lines.push(Line::from(Span::styled(format!("{} {label}", self.symbols.entity.reflog), Style::default().fg(color))));
             }
         } else if !known_empty {
@@ -81,21 +81,21 @@ impl App {
 
         let display_start = if reflogs_empty || lines_are_windowed { 0 } else { start };
         let display_end = if reflogs_empty || lines_are_windowed { lines.len() } else { end };
-        let list_items = zebra_list_items(&lines[display_start..display_end], visible_height, start, self.reflogs_selected, self.focus == Focus::Reflogs, !reflogs_empty, &self.theme);
+        let list_items = zebra_list_items(&lines[display_start..display_end], visible_height, start, self.tab.reflogs_selected, self.tab.focus == Focus::Reflogs, !reflogs_empty, &self.theme);
 
         if self.layout_config.is_zen {
             let list = List::new(list_items).block(Block::default().borders(Borders::ALL).padding(padding).border_set(self.symbols.border.block_set()));
             frame.render_widget(list, self.layout.reflogs);
 
             let scroll_range = scrollbar_content_length(total_lines, visible_height);
-            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.reflogs_scroll.get());
+            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.reflogs_scroll.get());
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                 .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
                 .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-                .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Reflogs { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Reflogs { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.reflogs_scrollbar, &mut scrollbar_state);
             return;
@@ -110,14 +110,14 @@ impl App {
         frame.render_widget(list, self.layout.reflogs);
 
         let scroll_range = scrollbar_content_length(total_lines, visible_height);
-        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.reflogs_scroll.get());
+        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.reflogs_scroll.get());
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some(if has_previous { self.symbols.border.vertical.as_str() } else { self.symbols.border.horizontal.as_str() }))
             .end_symbol(Some(if has_next { self.symbols.border.vertical.as_str() } else { self.symbols.border.horizontal.as_str() }))
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
             .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-            .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Reflogs { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Reflogs { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.reflogs_scrollbar, &mut scrollbar_state);
     }
@@ -39,6 +39,8 @@ const SETTINGS_GRAPH_COMMANDS: &[(&str, Command, fn() -> &'static str)] = &[
     ("@", Command::ToggleGraphDates, settings_text::COMMITTER_DATE_TIME),
     ("#", Command::ToggleGraphCommitters, settings_text::COMMITTERS),
     ("$", Command::ToggleGraphRefs, settings_text::REFS),
+    ("%", Command::ToggleGraphSignatures, settings_text::SIGNATURES),
+    ("^", Command::ToggleOriginalParents, settings_text::ORIGINAL_PARENTS),
 ];
 
 impl App {
@@ -153,6 +155,20 @@ impl App {
                     self.symbols.form.checkbox_off.clone()
                 }
             },
+            Command::ToggleGraphSignatures => {
+                if self.layout_config.is_graph_signatures {
+                    self.symbols.form.checkbox_on.clone()
+                } else {
+                    self.symbols.form.checkbox_off.clone()
+                }
+            },
+            Command::ToggleOriginalParents => {
+                if self.layout_config.is_graph_original_parents {
+                    self.symbols.form.checkbox_on.clone()
+                } else {
+                    self.symbols.form.checkbox_off.clone()
+                }
+            },
             Command::ResetLayout => settings_text::ENTER_ACTION().to_string(),
             _ => String::new(),
         }
@@ -247,6 +263,14 @@ impl App {
             Style::default().fg(self.theme.COLOR_TEXT).bg(self.theme.background_or_default(self.theme.COLOR_GREY_900)),
         ));
         self.add_settings_selection(lines, SettingsSelectionKind::GraphLaneLimit);
+
+        lines.push(self.settings_filled_line(
+            settings_text::VIEWER_FILE_SIZE_LIMIT(),
+            format!(" {} KiB {} ", self.layout_config.viewer_file_size_limit, settings_text::ENTER_ACTION()).as_str(),
+            width,
+            Style::default().fg(self.theme.COLOR_TEXT).bg(self.theme.background_or_default(self.theme.COLOR_GREY_900)),
+        ));
+        self.add_settings_selection(lines, SettingsSelectionKind::ViewerFileSizeLimit);
     }
 
     fn append_settings_general(&mut self, lines: &mut Vec<Line<'static>>, width: usize) {
@@ -287,7 +311,7 @@ impl App {
         } else {
             let recent = self.recent.clone();
             for (idx, path) in recent.iter().enumerate() {
-                let mut style = Style::default().fg(if Some(path) == self.path.as_ref() { self.theme.COLOR_GRASS } else { self.theme.COLOR_TEXT });
+                let mut style = Style::default().fg(if Some(path) == self.tab.path.as_ref() { self.theme.COLOR_GRASS } else { self.theme.COLOR_TEXT });
                 if idx.is_multiple_of(2) {
                     style = style.bg(self.theme.background_or_default(self.theme.COLOR_GREY_900));
                 }
@@ -548,7 +572,7 @@ impl App {
         for (day_idx, label) in self.symbols.weekday.labels().iter().enumerate() {
             let mut spans = Vec::new();
             spans.push(Span::styled(format!(" {}  ", label), Style::default().fg(self.theme.COLOR_TEXT)));
-            spans.extend(self.heatmap[day_idx][week_start..].iter().map(|&count| {
+            spans.extend(self.tab.heatmap[day_idx][week_start..].iter().map(|&count| {
                 let span = heat_cell(count, &self.theme, &self.symbols);
                 Span::styled(span.content.to_string(), span.style)
             }));
@@ -580,7 +604,7 @@ impl App {
 
         // Only the most recent weeks that fit are rendered.
         let max_weeks_fit = (usable_width / cell_width).max(1);
-        let total_weeks = self.heatmap[0].len();
+        let total_weeks = self.tab.heatmap[0].len();
         let visible_weeks = max_weeks_fit.min(total_weeks);
 
         let week_start = (total_weeks.saturating_sub(visible_weeks).saturating_add(2)).min(total_weeks);
@@ -616,7 +640,7 @@ impl App {
         self.last_input_direction = None;
         self.settings_selected = 0;
 
-        if let Some(repo) = self.repo.clone() {
+        if let Some(repo) = self.tab.repo.clone() {
             let _ = self.settings_lines(&repo);
             let content_start = self.settings_tab_hitboxes.first().map(|hitbox| hitbox.line.saturating_add(1)).unwrap_or(0);
             let selection = self.settings_selections.iter().find(|selection| selection.line >= content_start).or_else(|| self.settings_selections.first());
@@ -676,7 +700,7 @@ impl App {
                 }
 
                 // Highlight only while settings has viewport focus.
-                if absolute_idx == self.settings_selected && self.focus == Focus::Viewport {
+                if absolute_idx == self.settings_selected && self.tab.focus == Focus::Viewport {
                     let spans: Vec<Span> = item
                         .spans
                         .iter()
@@ -705,7 +729,7 @@ impl App {
                 .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(self.symbols.scrollbar.thumb.as_str())
-                .thumb_style(Style::default().fg(if self.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if self.tab.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.app, &mut scrollbar_state);
 
@@ -723,7 +747,7 @@ impl App {
             .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(self.symbols.scrollbar.thumb.as_str())
-            .thumb_style(Style::default().fg(if self.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if self.tab.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.app, &mut scrollbar_state);
     }
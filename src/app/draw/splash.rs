@@ -38,18 +38,18 @@ impl App {
         let _max_text_width = available_width.saturating_sub(2);
 
         // Reuse viewer scroll fields because splash behaves like a simple list.
-        let total_lines = self.viewer_lines.len();
+        let total_lines = self.tab.viewer_lines.len();
         let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(4) as usize } else { self.layout.graph.height.saturating_sub(2) as usize };
 
         if total_lines == 0 {
-            self.viewer_selected = 0;
-        } else if self.viewer_selected >= total_lines {
-            self.viewer_selected = total_lines.saturating_sub(1);
+            self.tab.viewer_selected = 0;
+        } else if self.tab.viewer_selected >= total_lines {
+            self.tab.viewer_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.viewer_selected, &self.viewer_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.viewer_selected, &self.tab.viewer_scroll, total_lines, visible_height);
 
-        let start = self.viewer_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.viewer_scroll.get().min(total_lines.saturating_sub(visible_height));
         let _end = (start + visible_height).min(total_lines);
 
         // Lines are assembled manually so the logo and recent list share centering.
@@ -59,7 +59,7 @@ impl App {
         let content_rows =
             if self.spinner.is_running() {
                 1
-            } else if self.recent.is_empty() && self.repo.is_none() {
+            } else if self.recent.is_empty() && self.tab.repo.is_none() {
                 5
             } else if self.recent.is_empty() {
                 3
@@ -111,7 +111,7 @@ impl App {
             lines.push(Line::from(vec![Span::styled(splash_text::MADE_WITH().to_string(), Style::default().fg(self.theme.COLOR_TEXT))]).centered());
             lines.push(Line::default());
             lines.push(Line::from(vec![Span::styled(splash_text::REPOSITORY_URL().to_string(), Style::default().fg(self.theme.COLOR_TEXT))]).centered());
-            if self.repo.is_none() {
+            if self.tab.repo.is_none() {
                 lines.push(Line::default());
                 lines.push(Line::from(vec![Span::styled(splash_text::NOT_A_VALID_GIT_REPOSITORY().to_string(), Style::default().fg(self.theme.COLOR_ORANGE))]).centered());
             }
@@ -122,7 +122,7 @@ impl App {
             lines.push(Line::default());
             // Recent repositories are selectable only when loading has finished.
             self.recent.iter().enumerate().for_each(|(i, path)| {
-                let style = if Some(path) == self.path.as_ref() {
+                let style = if Some(path) == self.tab.path.as_ref() {
                     self.theme.COLOR_GRASS
                 } else {
                     self.theme.COLOR_TEXT
@@ -131,7 +131,7 @@ impl App {
                 let mut line = Line::from(Span::styled(path.clone(), Style::default().fg(style))).centered();
 
                 // Brackets make the current splash selection visible without changing row width too much.
-                if i == self.splash_selected && self.focus == Focus::Viewport && !self.spinner.is_running() {
+                if i == self.splash_selected && self.tab.focus == Focus::Viewport && !self.spinner.is_running() {
                     let mut spans = Vec::new();
                     spans.push(Span::styled(self.symbols.splash.selected_left.clone(), Style::default().fg(self.theme.COLOR_GRASS)));
                     spans.extend(line.spans.clone());
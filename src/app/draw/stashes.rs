@@ -4,7 +4,7 @@ use crate::helpers::text::{center_line, empty_state_top_padding};
 use crate::{
     app::{
         app::{App, Focus},
-        draw::pane_window::{aligned_pane_rows, blank_lines, preloaded_pane_window, zebra_list_items},
+        draw::pane_window::{aligned_pane_rows, blank_lines, highlight_marked_items, preloaded_pane_window, zebra_list_items},
     },
     core::graph_service::{GraphPane, GraphPaneRow},
     helpers::colors::ColorPicker,
@@ -35,26 +35,26 @@ impl App {
         };
 
         // Shared pane list pattern: clamp selection, trap scroll, then slice visible rows.
-        let total_lines = self.graph.stashes_window.as_ref().map(|window| window.total).unwrap_or(self.oids.stashes.len());
+        let total_lines = self.tab.graph.stashes_window.as_ref().map(|window| window.total).unwrap_or(self.tab.oids.stashes.len());
 
         if total_lines == 0 {
-            self.stashes_selected = 0;
-        } else if self.stashes_selected >= total_lines {
-            self.stashes_selected = total_lines.saturating_sub(1);
+            self.tab.stashes_selected = 0;
+        } else if self.tab.stashes_selected >= total_lines {
+            self.tab.stashes_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.stashes_selected, &self.stashes_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.stashes_selected, &self.tab.stashes_scroll, total_lines, visible_height);
 
-        let start = self.stashes_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.stashes_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
         let (preload_start, preload_end) = preloaded_pane_window(start, end, total_lines, visible_height);
         self.request_pane_window(GraphPane::Stashes, preload_start, preload_end);
 
         // Stashes are stored as commit aliases, so each row reads its summary from git.
         let mut lines: Vec<Line<'_>> = Vec::new();
-        let lines_are_windowed = self.graph_tx.is_some();
-        let known_empty = self.graph.stashes_window.as_ref().is_some_and(|window| window.total == 0);
-        if let Some(rows) = self.graph.stashes_window.as_ref().and_then(|window| aligned_pane_rows(window, start, end)) {
+        let lines_are_windowed = self.tab.graph_tx.is_some();
+        let known_empty = self.tab.graph.stashes_window.as_ref().is_some_and(|window| window.total == 0);
+        if let Some(rows) = self.tab.graph.stashes_window.as_ref().and_then(|window| aligned_pane_rows(window, start, end)) {
             let color_picker = ColorPicker::from_theme(&self.theme);
             for row in rows {
                 if let Some(GraphPaneRow::Stash { summary, lane, .. }) = row {
@@ -65,14 +65,14 @@ impl App {
                     lines.push(Line::default());
                 }
             }
-        } else if self.graph_tx.is_none() {
-            for stash_alias in &self.oids.stashes {
-                let oid = self.oids.get_oid_by_alias(*stash_alias);
+        } else if self.tab.graph_tx.is_none() {
+            for stash_alias in &self.tab.oids.stashes {
+                let oid = self.tab.oids.get_oid_by_alias(*stash_alias);
                 let commit = repo.find_commit(*oid).unwrap();
                 let message = commit.summary().unwrap_or(empty::NO_MESSAGE()).to_string();
 
                 let truncated = truncate_with_ellipsis(message.as_str(), max_text_width.saturating_sub(1));
-                let color = if let Some(color) = self.stashes.colors.get(stash_alias) { *color } else { self.theme.COLOR_TEXT };
+                let color = if let Some(color) = self.tab.stashes.colors.get(stash_alias) { *color } else { self.theme.COLOR_TEXT };
 
                 lines.push(Line::from(Span::styled(format!("{} {truncated}", self.symbols.graph.commit_stash), Style::default().fg(color))));
             }
@@ -95,7 +95,8 @@ impl App {
         // Selection is skipped for the synthetic empty row; striping still fills the pane.
         let display_start = if stashes_empty || lines_are_windowed { 0 } else { start };
         let display_end = if stashes_empty || lines_are_windowed { lines.len() } else { end };
-        let list_items = zebra_list_items(&lines[display_start..display_end], visible_height, start, self.stashes_selected, self.focus == Focus::Stashes, !stashes_empty, &self.theme);
+        let list_items = zebra_list_items(&lines[display_start..display_end], visible_height, start, self.tab.stashes_selected, self.tab.focus == Focus::Stashes, !stashes_empty, &self.theme);
+        let list_items = if stashes_empty { list_items } else { highlight_marked_items(list_items, start, |index| self.is_pane_row_selected(Focus::Stashes, index), &self.theme) };
 
         if self.layout_config.is_zen {
             // Zen mode frames the pane as a full standalone list.
@@ -104,14 +105,14 @@ impl App {
             frame.render_widget(list, self.layout.stashes);
 
             let scroll_range = scrollbar_content_length(total_lines, visible_height);
-            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.stashes_scroll.get());
+            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.stashes_scroll.get());
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                 .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
                 .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-                .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Stashes { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Stashes { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.stashes_scrollbar, &mut scrollbar_state);
 
@@ -128,7 +129,7 @@ impl App {
         frame.render_widget(list, self.layout.stashes);
 
         let scroll_range = scrollbar_content_length(total_lines, visible_height);
-        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.stashes_scroll.get());
+        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.stashes_scroll.get());
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some(if self.layout_config.is_branches || self.layout_config.is_tags { self.symbols.border.vertical.as_str() } else { self.symbols.border.horizontal.as_str() }))
             .end_symbol(Some(if self.layout_config.is_reflogs || self.layout_config.is_worktrees || self.layout_config.is_search {
@@ -139,7 +140,7 @@ impl App {
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
             .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-            .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Stashes { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Stashes { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.stashes_scrollbar, &mut scrollbar_state);
     }
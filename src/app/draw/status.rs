@@ -1,5 +1,8 @@
 use crate::{
-    app::app::{App, Focus},
+    app::{
+        app::{App, Focus},
+        draw::pane_window::highlight_marked_items,
+    },
     git::queries::helpers::FileStatus,
     helpers::{
         layout::scrollbar_content_length,
@@ -38,7 +41,7 @@ impl App {
         // Top is staged or commit diff; bottom exists only for unstaged uncommitted changes.
         let mut is_staged_changes = false;
         let mut is_unstaged_changes = false;
-        let is_showing_uncommitted = self.graph_selected == 0;
+        let is_showing_uncommitted = self.tab.graph_selected == 0;
 
         let mut lines_status_top: Vec<StatusRow<'_>> = Vec::new();
         let mut lines_status_bottom: Vec<StatusRow<'_>> = Vec::new();
@@ -51,7 +54,7 @@ impl App {
         let max_status_bottom_width = self.layout.status_bottom.width.saturating_sub(5) as usize;
         let visible_height_status_top = self.layout.status_top.height.saturating_sub(2) as usize;
         let visible_height_status_bottom = self.layout.status_bottom.height.saturating_sub(2) as usize;
-        let is_uncommitted_loading = is_showing_uncommitted && !self.is_uncommitted_loaded;
+        let is_uncommitted_loading = is_showing_uncommitted && !self.tab.is_uncommitted_loaded;
         let is_commit_diff_loading = !is_showing_uncommitted && !self.selected_commit_diff_is_loaded();
 
         // The pseudo-row splits uncommitted files into staged and unstaged panes.
@@ -61,7 +64,7 @@ impl App {
             lines_status_top = centered_loading_lines(visible_height_status_top, max_status_top_width + 3, Style::default().fg(self.theme.COLOR_GREY_800));
             lines_status_bottom = centered_loading_lines(visible_height_status_bottom, max_status_bottom_width + 3, Style::default().fg(self.theme.COLOR_GREY_800));
         } else if is_showing_uncommitted {
-            for file in self.uncommitted.conflicts.iter() {
+            for file in self.tab.uncommitted.conflicts.iter() {
                 lines_status_top.push(StatusRow::file(
                     file,
                     &self.symbols.status.conflict_spaced,
@@ -70,7 +73,7 @@ impl App {
                     max_status_top_width,
                 ));
             }
-            for file in self.uncommitted.staged.modified.iter() {
+            for file in self.tab.uncommitted.staged.modified.iter() {
                 lines_status_top.push(StatusRow::file(
                     file,
                     &self.symbols.status.modified_spaced,
@@ -79,7 +82,7 @@ impl App {
                     max_status_top_width,
                 ));
             }
-            for file in self.uncommitted.staged.added.iter() {
+            for file in self.tab.uncommitted.staged.added.iter() {
                 lines_status_top.push(StatusRow::file(
                     file,
                     &self.symbols.status.added_spaced,
@@ -88,7 +91,7 @@ impl App {
                     max_status_top_width,
                 ));
             }
-            for file in self.uncommitted.staged.deleted.iter() {
+            for file in self.tab.uncommitted.staged.deleted.iter() {
                 lines_status_top.push(StatusRow::file(
                     file,
                     &self.symbols.status.deleted_spaced,
@@ -113,7 +116,7 @@ impl App {
                 is_staged_changes = true;
             }
 
-            for file in self.uncommitted.conflicts.iter() {
+            for file in self.tab.uncommitted.conflicts.iter() {
                 lines_status_bottom.push(StatusRow::file(
                     file,
                     &self.symbols.status.conflict_spaced,
@@ -122,7 +125,7 @@ impl App {
                     max_status_bottom_width,
                 ));
             }
-            for file in self.uncommitted.unstaged.modified.iter() {
+            for file in self.tab.uncommitted.unstaged.modified.iter() {
                 lines_status_bottom.push(StatusRow::file(
                     file,
                     &self.symbols.status.modified_spaced,
@@ -131,7 +134,7 @@ impl App {
                     max_status_bottom_width,
                 ));
             }
-            for file in self.uncommitted.unstaged.added.iter() {
+            for file in self.tab.uncommitted.unstaged.added.iter() {
                 lines_status_bottom.push(StatusRow::file(
                     file,
                     &self.symbols.status.added_spaced,
@@ -140,7 +143,7 @@ impl App {
                     max_status_bottom_width,
                 ));
             }
-            for file in self.uncommitted.unstaged.deleted.iter() {
+            for file in self.tab.uncommitted.unstaged.deleted.iter() {
                 lines_status_bottom.push(StatusRow::file(
                     file,
                     &self.symbols.status.deleted_spaced,
@@ -169,7 +172,7 @@ impl App {
             lines_status_top = centered_loading_lines(visible_height_status_top, max_status_top_width + 3, Style::default().fg(self.theme.COLOR_GREY_800));
         } else {
             // Commit rows use the selected commit's file diff in the top pane only.
-            for file_change in self.current_diff.iter() {
+            for file_change in self.tab.current_diff.iter() {
                 let (symbol, color) = match file_change.status {
                     FileStatus::Added => (self.symbols.status.added_spaced.as_str(), self.theme.COLOR_GREEN),
                     FileStatus::Modified => (self.symbols.status.modified_spaced.as_str(), self.theme.COLOR_BLUE),
@@ -196,7 +199,7 @@ impl App {
             }
         }
 
-        let search_highlight_path = if self.layout_config.is_search { self.search_path.as_deref() } else { None };
+        let search_highlight_path = if self.layout_config.is_search { self.tab.search_path.as_deref() } else { None };
 
         // Top status pane shows staged files on the pseudo-row or commit file changes otherwise.
         {
@@ -205,14 +208,14 @@ impl App {
             let visible_height = visible_height_status_top;
 
             if total_lines == 0 {
-                self.status_top_selected = 0;
-            } else if self.status_top_selected >= total_lines {
-                self.status_top_selected = total_lines.saturating_sub(1);
+                self.tab.status_top_selected = 0;
+            } else if self.tab.status_top_selected >= total_lines {
+                self.tab.status_top_selected = total_lines.saturating_sub(1);
             }
 
-            self.trap_selection(self.status_top_selected, &self.status_top_scroll, total_lines, visible_height);
+            self.trap_selection(self.tab.status_top_selected, &self.tab.status_top_scroll, total_lines, visible_height);
 
-            let start = self.status_top_scroll.get().min(total_lines.saturating_sub(visible_height));
+            let start = self.tab.status_top_scroll.get().min(total_lines.saturating_sub(visible_height));
             let end = (start + visible_height).min(total_lines);
 
             // Selection is disabled for synthetic empty-state rows.
@@ -220,12 +223,17 @@ impl App {
                 &lines_status_top[start..end],
                 visible_height,
                 start,
-                self.status_top_selected,
-                self.focus == Focus::StatusTop,
+                self.tab.status_top_selected,
+                self.tab.focus == Focus::StatusTop,
                 is_staged_changes && !status_top_empty,
                 search_highlight_path,
                 &self.theme,
             );
+            let list_items = if is_showing_uncommitted && !status_top_empty {
+                highlight_marked_items(list_items, start, |index| self.is_pane_row_selected(Focus::StatusTop, index), &self.theme)
+            } else {
+                list_items
+            };
 
             if self.layout_config.is_zen {
                 // Zen mode frames the pane as a full standalone list.
@@ -234,13 +242,13 @@ impl App {
 
                 frame.render_widget(list, self.layout.status_top);
 
-                let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.status_top_scroll.get());
+                let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.status_top_scroll.get());
                 let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                     .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                     .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                     .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                     .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
-                    .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::StatusTop { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                    .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::StatusTop { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
                 frame.render_stateful_widget(scrollbar, self.layout.status_top_scrollbar, &mut scrollbar_state);
             } else {
@@ -248,23 +256,23 @@ impl App {
                 let list = List::new(list_items).block(
                     Block::default()
                         .padding(padding)
-                        .borders(if self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts) { Borders::TOP } else { Borders::NONE })
+                        .borders(if self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts) { Borders::TOP } else { Borders::NONE })
                         .border_style(Style::default().fg(self.theme.COLOR_BORDER)),
                 );
 
                 frame.render_widget(list, self.layout.status_top);
 
-                let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.status_top_scroll.get());
+                let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.status_top_scroll.get());
                 let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
-                    .begin_symbol(if self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts) {
+                    .begin_symbol(if self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts) {
                         Some(self.symbols.border.vertical.as_str())
                     } else {
                         Some(self.symbols.scrollbar.begin.as_str())
                     })
-                    .end_symbol(if self.graph_selected == 0 { Some(self.symbols.border.t_right.as_str()) } else { Some(self.symbols.scrollbar.end.as_str()) })
+                    .end_symbol(if self.tab.graph_selected == 0 { Some(self.symbols.border.t_right.as_str()) } else { Some(self.symbols.scrollbar.end.as_str()) })
                     .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                     .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
-                    .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::StatusTop { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                    .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::StatusTop { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
                 frame.render_stateful_widget(scrollbar, self.layout.status_top_scrollbar, &mut scrollbar_state);
             }
@@ -278,14 +286,14 @@ impl App {
                 let visible_height = visible_height_status_bottom;
 
                 if total_lines == 0 {
-                    self.status_bottom_selected = 0;
-                } else if self.status_bottom_selected >= total_lines {
-                    self.status_bottom_selected = total_lines.saturating_sub(1);
+                    self.tab.status_bottom_selected = 0;
+                } else if self.tab.status_bottom_selected >= total_lines {
+                    self.tab.status_bottom_selected = total_lines.saturating_sub(1);
                 }
 
-                self.trap_selection(self.status_bottom_selected, &self.status_bottom_scroll, total_lines, visible_height);
+                self.trap_selection(self.tab.status_bottom_selected, &self.tab.status_bottom_scroll, total_lines, visible_height);
 
-                let start = self.status_bottom_scroll.get().min(total_lines.saturating_sub(visible_height));
+                let start = self.tab.status_bottom_scroll.get().min(total_lines.saturating_sub(visible_height));
                 let end = (start + visible_height).min(total_lines);
 
                 // Selection is disabled for synthetic empty-state rows.
@@ -293,12 +301,13 @@ impl App {
                     &lines_status_bottom[start..end],
                     visible_height,
                     start,
-                    self.status_bottom_selected,
-                    self.focus == Focus::StatusBottom,
+                    self.tab.status_bottom_selected,
+                    self.tab.focus == Focus::StatusBottom,
                     is_unstaged_changes && !status_bottom_empty,
                     search_highlight_path,
                     &self.theme,
                 );
+                let list_items = if status_bottom_empty { list_items } else { highlight_marked_items(list_items, start, |index| self.is_pane_row_selected(Focus::StatusBottom, index), &self.theme) };
 
                 if self.layout_config.is_zen {
                     // Zen mode frames the pane as a full standalone list.
@@ -307,13 +316,13 @@ impl App {
 
                     frame.render_widget(list, self.layout.status_bottom);
 
-                    let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.status_bottom_scroll.get());
+                    let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.status_bottom_scroll.get());
                     let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                         .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                         .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                         .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                         .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
-                        .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::StatusBottom { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                        .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::StatusBottom { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
                     frame.render_stateful_widget(scrollbar, self.layout.status_bottom_scrollbar, &mut scrollbar_state);
 
@@ -325,13 +334,13 @@ impl App {
 
                 frame.render_widget(list, self.layout.status_bottom);
 
-                let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.status_bottom_scroll.get());
+                let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.status_bottom_scroll.get());
                 let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                     .begin_symbol(Some(self.symbols.border.vertical.as_str()))
                     .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                     .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                     .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
-                    .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::StatusBottom { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                    .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::StatusBottom { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
                 frame.render_stateful_widget(scrollbar, self.layout.status_bottom_scrollbar, &mut scrollbar_state);
             }
@@ -9,18 +9,22 @@ use ratatui::{
     text::{Line, Span, Text},
     widgets::Block,
 };
+use std::time::Duration;
+
+// Long enough to read a failure from a command that finished in the background.
+const STATUS_NOTICE_DURATION: Duration = Duration::from_secs(8);
 
 impl App {
     fn submodule_stack_status_label(&self) -> Option<String> {
-        let first = self.submodule_stack.first()?;
+        let first = self.tab.submodule_stack.first()?;
         let root = first.parent_path.file_name().and_then(|value| value.to_str()).map(str::to_string).unwrap_or_else(|| first.parent_path.display().to_string());
         let mut parts = vec![root];
-        parts.extend(self.submodule_stack.iter().map(|entry| entry.submodule_path.display().to_string()));
+        parts.extend(self.tab.submodule_stack.iter().map(|entry| entry.submodule_path.display().to_string()));
         Some(format!("{} {} ", self.symbols.submodule.default, parts.join(&self.symbols.submodule.stack_separator)))
     }
 
     pub fn draw_statusbar(&mut self, frame: &mut Frame, repo: &git2::Repository) {
-        let mut left_spans: Vec<Span> = match self.worktrees.current_name() {
+        let mut left_spans: Vec<Span> = match self.tab.worktrees.current_name() {
             Some(name) => vec![Span::styled(format!("  {} {name} ", self.symbols.worktree.current), Style::default().fg(self.theme.COLOR_GRASS))],
             None => vec![Span::raw("  ")],
         };
@@ -34,58 +38,66 @@ impl App {
                 None => left_spans.push(Span::styled(status_text::NO_HEAD_NO_COMMITS(), Style::default().fg(self.theme.COLOR_TEXT))),
             },
         }
+        if self.skip_hooks_once {
+            left_spans.push(Span::styled(format!("  {}", status_text::HOOKS_SKIPPED()), Style::default().fg(self.theme.COLOR_ORANGE)));
+        }
+        if let Some((notice, _)) = self.status_notice.as_ref().filter(|(_, at)| at.elapsed() < STATUS_NOTICE_DURATION) {
+            left_spans.push(Span::styled(format!("  {notice}"), Style::default().fg(self.theme.COLOR_RED)));
+        }
         let lines = Line::from(left_spans);
 
         let status_paragraph = ratatui::widgets::Paragraph::new(Text::from(lines)).left_aligned().block(Block::default());
 
         frame.render_widget(status_paragraph, self.layout.statusbar_left);
 
-        let total = match self.focus {
-            Focus::Viewport => match self.viewport {
+        let total = match self.tab.focus {
+            Focus::Viewport => match self.tab.viewport {
                 Viewport::Graph => self.graph_commit_count(),
                 Viewport::Viewer => self.viewer_row_count(),
                 _ => 0,
             },
             Focus::StatusTop => {
-                if self.graph_selected == 0 {
-                    self.uncommitted.conflicts.len() + self.uncommitted.staged.modified.len() + self.uncommitted.staged.added.len() + self.uncommitted.staged.deleted.len()
+                if self.tab.graph_selected == 0 {
+                    self.tab.uncommitted.conflicts.len() + self.tab.uncommitted.staged.modified.len() + self.tab.uncommitted.staged.added.len() + self.tab.uncommitted.staged.deleted.len()
                 } else {
-                    self.current_diff.len()
+                    self.tab.current_diff.len()
                 }
             },
-            Focus::StatusBottom => self.uncommitted.conflicts.len() + self.uncommitted.unstaged.modified.len() + self.uncommitted.unstaged.added.len() + self.uncommitted.unstaged.deleted.len(),
-            Focus::Branches => self.graph.branches_window.as_ref().map(|window| window.total).unwrap_or_else(|| current_branch_names(repo).len()),
-            Focus::Tags => self.graph.tags_window.as_ref().map(|window| window.total).unwrap_or(self.tags.sorted.len()),
-            Focus::Stashes => self.graph.stashes_window.as_ref().map(|window| window.total).unwrap_or(self.oids.stashes.len()),
-            Focus::Reflogs => self.graph.reflogs_window.as_ref().map(|window| window.total).unwrap_or(self.reflogs.entries.len()),
-            Focus::Worktrees => self.worktrees.entries.len(),
-            Focus::Submodules => self.submodules.entries.len(),
-            Focus::Search => self.search_rows.len(),
+            Focus::StatusBottom => {
+                self.tab.uncommitted.conflicts.len() + self.tab.uncommitted.unstaged.modified.len() + self.tab.uncommitted.unstaged.added.len() + self.tab.uncommitted.unstaged.deleted.len()
+            },
+            Focus::Branches => self.tab.graph.branches_window.as_ref().map(|window| window.total).unwrap_or_else(|| current_branch_names(repo).len()),
+            Focus::Tags => self.tab.graph.tags_window.as_ref().map(|window| window.total).unwrap_or(self.tab.tags.sorted.len()),
+            Focus::Stashes => self.tab.graph.stashes_window.as_ref().map(|window| window.total).unwrap_or(self.tab.oids.stashes.len()),
+            Focus::Reflogs => self.tab.graph.reflogs_window.as_ref().map(|window| window.total).unwrap_or(self.tab.reflogs.entries.len()),
+            Focus::Worktrees => self.tab.worktrees.entries.len(),
+            Focus::Submodules => self.tab.submodules.entries.len(),
+            Focus::Search => self.tab.search_rows.len(),
             _ => 0,
         };
 
         let cursor = if total == 0 {
             0
         } else {
-            match self.focus {
-                Focus::Viewport => match self.viewport {
-                    Viewport::Graph => self.graph_selected + 1,
-                    Viewport::Viewer => self.viewer_selected + 1,
+            match self.tab.focus {
+                Focus::Viewport => match self.tab.viewport {
+                    Viewport::Graph => self.tab.graph_selected + 1,
+                    Viewport::Viewer => self.tab.viewer_selected + 1,
                     _ => 0,
                 },
-                Focus::StatusTop => self.status_top_selected + 1,
-                Focus::StatusBottom => self.status_bottom_selected + 1,
+                Focus::StatusTop => self.tab.status_top_selected + 1,
+                Focus::StatusBottom => self.tab.status_bottom_selected + 1,
                 Focus::Branches => {
                     let branch_names = current_branch_names(repo);
-                    let hidden = branch_names.iter().filter(|branch| self.branches.hidden_branch_names.contains(*branch)).count();
+                    let hidden = branch_names.iter().filter(|branch| self.tab.branches.hidden_branch_names.contains(*branch)).count();
                     branch_names.len().saturating_sub(hidden)
                 },
-                Focus::Tags => self.tags_selected + 1,
-                Focus::Stashes => self.stashes_selected + 1,
-                Focus::Reflogs => self.reflogs_selected + 1,
-                Focus::Worktrees => self.worktrees_selected + 1,
-                Focus::Submodules => self.submodules_selected + 1,
-                Focus::Search => self.search_selected + 1,
+                Focus::Tags => self.tab.tags_selected + 1,
+                Focus::Stashes => self.tab.stashes_selected + 1,
+                Focus::Reflogs => self.tab.reflogs_selected + 1,
+                Focus::Worktrees => self.tab.worktrees_selected + 1,
+                Focus::Submodules => self.tab.submodules_selected + 1,
+                Focus::Search => self.tab.search_selected + 1,
                 _ => 0,
             }
         };
@@ -101,7 +113,16 @@ impl App {
             action_hint.push(Span::styled(format!("{} ", self.symbols.graph.commit_branch), Style::default().fg(self.theme.COLOR_GRASS)));
         }
 
-        let mut right_spans = vec![Span::styled(if total == 0 { "".to_string() } else { format!("{}/{}{} ", cursor, total, icon_spinner) }, Style::default().fg(self.theme.COLOR_TEXT))];
+        let mut right_spans = Vec::new();
+        // A count being typed, like vim's showcmd.
+        if let Some(range) = self.visual_range() {
+            right_spans.push(Span::styled(format!("{} {} ", status_text::VISUAL(), range.count()), Style::default().fg(self.theme.COLOR_HIGHLIGHTED)));
+        }
+        if let Some(count) = self.pending_count {
+            let digits = if count == 0 { String::new() } else { format!("{count} ") };
+            right_spans.push(Span::styled(format!("{} {digits}", status_text::COUNT()), Style::default().fg(self.theme.COLOR_HIGHLIGHTED)));
+        }
+        right_spans.push(Span::styled(if total == 0 { "".to_string() } else { format!("{}/{}{} ", cursor, total, icon_spinner) }, Style::default().fg(self.theme.COLOR_TEXT)));
 
         right_spans.extend(action_hint);
 
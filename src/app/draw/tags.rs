@@ -4,7 +4,7 @@ use crate::helpers::text::{center_line, empty_state_top_padding};
 use crate::{
     app::{
         app::{App, Focus},
-        draw::pane_window::{aligned_pane_rows, blank_lines, preloaded_pane_window, zebra_list_items},
+        draw::pane_window::{aligned_pane_rows, blank_lines, highlight_marked_items, preloaded_pane_window, zebra_list_items},
     },
     core::graph_service::{GraphPane, GraphPaneRow},
     helpers::colors::ColorPicker,
@@ -35,26 +35,26 @@ impl App {
         };
 
         // Shared pane list pattern: clamp selection, trap scroll, then slice visible rows.
-        let total_lines = self.graph.tags_window.as_ref().map(|window| window.total).unwrap_or_else(|| self.tags.get_sorted_aliases().len());
+        let total_lines = self.tab.graph.tags_window.as_ref().map(|window| window.total).unwrap_or_else(|| self.tab.tags.get_sorted_aliases().len());
 
         if total_lines == 0 {
-            self.tags_selected = 0;
-        } else if self.tags_selected >= total_lines {
-            self.tags_selected = total_lines.saturating_sub(1);
+            self.tab.tags_selected = 0;
+        } else if self.tab.tags_selected >= total_lines {
+            self.tab.tags_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.tags_selected, &self.tags_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.tags_selected, &self.tab.tags_scroll, total_lines, visible_height);
 
-        let start = self.tags_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.tags_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
         let (preload_start, preload_end) = preloaded_pane_window(start, end, total_lines, visible_height);
         self.request_pane_window(GraphPane::Tags, preload_start, preload_end);
 
         // Tag rows are already sorted by name in the worker projection.
         let mut lines: Vec<Line<'_>> = Vec::new();
-        let lines_are_windowed = self.graph_tx.is_some();
-        let known_empty = self.graph.tags_window.as_ref().is_some_and(|window| window.total == 0);
-        if let Some(rows) = self.graph.tags_window.as_ref().and_then(|window| aligned_pane_rows(window, start, end)) {
+        let lines_are_windowed = self.tab.graph_tx.is_some();
+        let known_empty = self.tab.graph.tags_window.as_ref().is_some_and(|window| window.total == 0);
+        if let Some(rows) = self.tab.graph.tags_window.as_ref().and_then(|window| aligned_pane_rows(window, start, end)) {
             let color_picker = ColorPicker::from_theme(&self.theme);
             for row in rows {
                 if let Some(GraphPaneRow::Tag { name, lane, .. }) = row {
@@ -65,10 +65,10 @@ impl App {
                     lines.push(Line::default());
                 }
             }
-        } else if self.graph_tx.is_none() {
-            for (tag_alias, tag_name) in self.tags.get_sorted_aliases() {
+        } else if self.tab.graph_tx.is_none() {
+            for (tag_alias, tag_name) in self.tab.tags.get_sorted_aliases() {
                 let truncated = truncate_with_ellipsis(tag_name, max_text_width.saturating_sub(1));
-                let color = self.tags.get_color(&self.theme, tag_alias);
+                let color = self.tab.tags.get_color(&self.theme, tag_alias);
 
                 lines.push(Line::from(Span::styled(format!("{} {truncated}", self.symbols.entity.tag), Style::default().fg(color))));
             }
@@ -91,7 +91,8 @@ impl App {
         // Selection is skipped for the synthetic empty row; striping still fills the pane.
         let display_start = if tags_empty || lines_are_windowed { 0 } else { start };
         let display_end = if tags_empty || lines_are_windowed { lines.len() } else { end };
-        let list_items = zebra_list_items(&lines[display_start..display_end], visible_height, start, self.tags_selected, self.focus == Focus::Tags, !tags_empty, &self.theme);
+        let list_items = zebra_list_items(&lines[display_start..display_end], visible_height, start, self.tab.tags_selected, self.tab.focus == Focus::Tags, !tags_empty, &self.theme);
+        let list_items = if tags_empty { list_items } else { highlight_marked_items(list_items, start, |index| self.is_pane_row_selected(Focus::Tags, index), &self.theme) };
 
         if self.layout_config.is_zen {
             // Zen mode frames the pane as a full standalone list.
@@ -100,14 +101,14 @@ impl App {
             frame.render_widget(list, self.layout.tags);
 
             let scroll_range = scrollbar_content_length(total_lines, visible_height);
-            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tags_scroll.get());
+            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.tags_scroll.get());
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                 .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
                 .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-                .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Tags { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Tags { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.tags_scrollbar, &mut scrollbar_state);
 
@@ -124,7 +125,7 @@ impl App {
         frame.render_widget(list, self.layout.tags);
 
         let scroll_range = scrollbar_content_length(total_lines, visible_height);
-        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tags_scroll.get());
+        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.tags_scroll.get());
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some(if self.layout_config.is_branches { self.symbols.border.vertical.as_str() } else { self.symbols.border.horizontal.as_str() }))
             .end_symbol(Some(if self.layout_config.is_stashes || self.layout_config.is_reflogs || self.layout_config.is_worktrees || self.layout_config.is_search {
@@ -135,7 +136,7 @@ impl App {
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
             .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-            .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Tags { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Tags { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.tags_scrollbar, &mut scrollbar_state);
     }
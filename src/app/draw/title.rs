@@ -9,32 +9,42 @@ use ratatui::{
 };
 impl App {
     pub fn draw_title(&mut self, frame: &mut Frame) {
-        let available_width = self.layout.title_left.width.saturating_sub(15) as usize;
+        // Tab bar, only once a second repository is open
+        let mut tabs = Vec::new();
+        if self.tabs.len() > 1 {
+            for (index, name) in self.tab_names().into_iter().enumerate() {
+                let color = if index == self.active_tab { self.theme.COLOR_HIGHLIGHTED } else { self.theme.COLOR_GREY_600 };
+                let label = name.unwrap_or_else(|| status_text::NEW_TAB().to_string());
+                tabs.push(Span::styled(format!(" {}:{}", index + 1, label), Style::default().fg(color)));
+            }
+        }
+        let tabs_width: usize = tabs.iter().map(|span| span.content.chars().count()).sum();
+        let available_width = (self.layout.title_left.width.saturating_sub(15) as usize).saturating_sub(tabs_width);
 
         // Logo and path
-        let path = if self.viewport == Viewport::Viewer
-            && let Some(file_name) = self.file_name.clone()
+        let path = if self.tab.viewport == Viewport::Viewer
+            && let Some(file_name) = self.tab.file_name.clone()
         {
-            match &self.path {
+            match &self.tab.path {
                 Some(base) => format!("{}/{}", base, file_name),
                 None => file_name.clone(),
             }
         } else {
-            self.path.clone().unwrap_or_else(|| ".".to_string())
+            self.tab.path.clone().unwrap_or_else(|| ".".to_string())
         };
 
         let logo = self.logo.clone();
         let separator = Span::styled(" |", Style::default().fg(self.theme.COLOR_TEXT));
         let folder = Span::styled(format!(" {} {}", self.symbols.entity.folder, truncate_start_with_ellipsis(path.as_str(), available_width)), Style::default().fg(self.theme.COLOR_TEXT));
 
-        let line = Line::from([logo, vec![separator, folder]].concat());
+        let line = if tabs.is_empty() { Line::from([logo, vec![separator, folder]].concat()) } else { Line::from([logo, vec![separator.clone()], tabs, vec![separator, folder]].concat()) };
         let paragraph = ratatui::widgets::Paragraph::new(line).left_aligned().block(Block::default());
 
         frame.render_widget(paragraph, self.layout.title_left);
 
-        let focus_name = match self.focus {
-            Focus::Viewport if self.viewport == Viewport::Settings => settings::SETTINGS(),
-            Focus::Viewport if self.viewport == Viewport::Viewer => status_text::VIEWER(),
+        let focus_name = match self.tab.focus {
+            Focus::Viewport if self.tab.viewport == Viewport::Settings => settings::SETTINGS(),
+            Focus::Viewport if self.tab.viewport == Viewport::Viewer => status_text::VIEWER(),
             Focus::Viewport => status_text::GRAPH(),
             Focus::Branches => settings::BRANCHES(),
             Focus::Tags => settings::TAGS(),
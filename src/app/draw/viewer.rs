@@ -1,13 +1,17 @@
 use crate::{
     app::{
         app::{App, Focus, ViewerLayoutSignature, Viewport},
+        draw::inspector::centered_loading_lines,
         state::defaults::{SplitViewerRow, ViewerMode},
     },
-    git::queries::{
-        diffs::{get_conflict_file, get_file_at_oid, get_file_at_workdir, get_file_diff_at_oid, get_file_diff_at_workdir},
-        helpers::{ConflictFile, FileChanges, Hunk},
+    core::diff_service::{DiffCommand, FileDiff, load_file_diff},
+    git::queries::helpers::{ConflictFile, FileChanges, Hunk, LfsPointer},
+    helpers::{
+        keymap::Command,
+        layout::scrollbar_content_length,
+        localisation::viewer,
+        text::{format_size, hex_dump, wrap_words},
     },
-    helpers::{layout::scrollbar_content_length, text::wrap_words},
 };
 use git2::Oid;
 use ratatui::Frame;
@@ -61,21 +65,31 @@ impl App {
 
     pub fn viewer_row_count(&self) -> usize {
         match self.viewer_mode {
-            ViewerMode::Full => self.viewer_lines.len(),
-            ViewerMode::Hunks => self.viewer_hunks.len(),
-            ViewerMode::Split => self.viewer_split_rows.len(),
+            ViewerMode::Full => self.tab.viewer_lines.len(),
+            ViewerMode::Hunks => self.tab.viewer_hunks.len(),
+            ViewerMode::Split => self.tab.viewer_split_rows.len(),
         }
     }
 
     pub fn split_unified_index(&self, split_idx: usize) -> usize {
-        self.viewer_split_rows.get(split_idx).and_then(|row| row.unified_indices.first().copied()).unwrap_or(0)
+        self.tab.viewer_split_rows.get(split_idx).and_then(|row| row.unified_indices.first().copied()).unwrap_or(0)
     }
 
     pub fn closest_split_row_for_unified(&self, unified_idx: usize) -> usize {
-        self.viewer_split_rows.iter().enumerate().min_by_key(|(_, row)| row.unified_indices.iter().map(|idx| idx.abs_diff(unified_idx)).min().unwrap_or(usize::MAX)).map(|(idx, _)| idx).unwrap_or(0)
+        self.tab
+            .viewer_split_rows
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, row)| row.unified_indices.iter().map(|idx| idx.abs_diff(unified_idx)).min().unwrap_or(usize::MAX))
+            .map(|(idx, _)| idx)
+            .unwrap_or(0)
     }
 
     pub fn draw_viewer(&mut self, frame: &mut Frame) {
+        if self.tab.graph.requested_viewer.is_some() {
+            self.draw_viewer_loading(frame);
+            return;
+        }
         if self.viewer_mode == ViewerMode::Split {
             self.draw_split_viewer(frame);
             return;
@@ -90,8 +104,8 @@ impl App {
 
         // Hunk mode presents only changed-line anchors while reusing the full viewer rows.
         let active_lines: Vec<&ListItem> = match self.viewer_mode {
-            ViewerMode::Full => self.viewer_lines.iter().collect(),
-            ViewerMode::Hunks => self.viewer_hunks.iter().filter_map(|&i| self.viewer_lines.get(i)).collect(),
+            ViewerMode::Full => self.tab.viewer_lines.iter().collect(),
+            ViewerMode::Hunks => self.tab.viewer_hunks.iter().filter_map(|&i| self.tab.viewer_lines.get(i)).collect(),
             ViewerMode::Split => Vec::new(),
         };
 
@@ -99,14 +113,14 @@ impl App {
         let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(2) as usize } else { self.layout.graph.height as usize };
 
         if total_lines == 0 {
-            self.viewer_selected = 0;
-        } else if self.viewer_selected >= total_lines {
-            self.viewer_selected = total_lines.saturating_sub(1);
+            self.tab.viewer_selected = 0;
+        } else if self.tab.viewer_selected >= total_lines {
+            self.tab.viewer_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.viewer_selected, &self.viewer_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.viewer_selected, &self.tab.viewer_scroll, total_lines, visible_height);
 
-        let start = self.viewer_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.viewer_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
 
         // Clone visible rows so selection styling does not mutate the cached viewer data.
@@ -116,7 +130,7 @@ impl App {
             .map(|(i, line)| {
                 let absolute_idx = start + i;
                 let mut item = (*line).clone();
-                if absolute_idx == self.viewer_selected && self.focus == Focus::Viewport {
+                if absolute_idx == self.tab.viewer_selected && self.tab.focus == Focus::Viewport {
                     item = item.style(Style::default().bg(self.theme.background_or_default(self.theme.COLOR_GREY_800)));
                 }
                 item
@@ -130,13 +144,13 @@ impl App {
 
             frame.render_widget(list, self.layout.graph);
 
-            let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.viewer_scroll.get());
+            let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.viewer_scroll.get());
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                 .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(self.symbols.scrollbar.thumb.as_str())
-                .thumb_style(Style::default().fg(if self.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if self.tab.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.graph_scrollbar, &mut scrollbar_state);
 
@@ -149,44 +163,57 @@ impl App {
 
         frame.render_widget(list, self.layout.graph);
 
-        let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.viewer_scroll.get());
+        let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.viewer_scroll.get());
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(if self.layout_config.is_inspector || self.layout_config.is_status { Some(self.symbols.border.horizontal.as_str()) } else { Some(self.symbols.scrollbar.begin.as_str()) })
             .end_symbol(if self.layout_config.is_inspector || self.layout_config.is_status { Some(self.symbols.border.horizontal.as_str()) } else { Some(self.symbols.scrollbar.end.as_str()) })
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(self.symbols.scrollbar.thumb.as_str())
-            .thumb_style(Style::default().fg(if self.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if self.tab.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.graph_scrollbar, &mut scrollbar_state);
     }
 
+    // The file is still with the diff service; every viewer mode waits in the same frame.
+    fn draw_viewer_loading(&self, frame: &mut Frame) {
+        let padding = ratatui::widgets::Padding { left: 1, right: 1, top: 0, bottom: 0 };
+        let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(2) as usize } else { self.layout.graph.height as usize };
+        let max_text_width = (self.layout.graph.width as usize).saturating_sub(4);
+        let borders = if self.layout_config.is_zen { Borders::ALL } else { Borders::RIGHT | Borders::LEFT };
+
+        let lines = centered_loading_lines(visible_height, max_text_width, Style::default().fg(self.theme.COLOR_GREY_800));
+        let paragraph =
+            Paragraph::new(lines).block(Block::default().padding(padding).borders(borders).border_style(Style::default().fg(self.theme.COLOR_BORDER)).border_set(self.symbols.border.block_set()));
+        frame.render_widget(paragraph, self.layout.graph);
+    }
+
     fn draw_split_viewer(&mut self, frame: &mut Frame) {
         if self.layout.viewer_split_left.width == 0 || self.layout.viewer_split_right.width == 0 {
             return;
         }
 
-        let total_lines = self.viewer_split_rows.len();
+        let total_lines = self.tab.viewer_split_rows.len();
         let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(2) as usize } else { self.layout.graph.height as usize };
 
         if total_lines == 0 {
-            self.viewer_selected = 0;
-        } else if self.viewer_selected >= total_lines {
-            self.viewer_selected = total_lines.saturating_sub(1);
+            self.tab.viewer_selected = 0;
+        } else if self.tab.viewer_selected >= total_lines {
+            self.tab.viewer_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.viewer_selected, &self.viewer_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.viewer_selected, &self.tab.viewer_scroll, total_lines, visible_height);
 
-        let start = self.viewer_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.viewer_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
 
         let mut left_items = Vec::new();
         let mut right_items = Vec::new();
 
-        for (i, row) in self.viewer_split_rows[start..end].iter().enumerate() {
+        for (i, row) in self.tab.viewer_split_rows[start..end].iter().enumerate() {
             let absolute_idx = start + i;
             let mut left = row.left.clone();
             let mut right = row.right.clone();
-            if absolute_idx == self.viewer_selected && self.focus == Focus::Viewport {
+            if absolute_idx == self.tab.viewer_selected && self.tab.focus == Focus::Viewport {
                 let selected = Style::default().bg(self.theme.background_or_default(self.theme.COLOR_GREY_800));
                 left = left.style(selected);
                 right = right.style(selected);
@@ -208,7 +235,7 @@ impl App {
         frame.render_widget(right_list, self.layout.viewer_split_right);
         self.draw_split_divider(frame);
 
-        let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.viewer_scroll.get());
+        let mut scrollbar_state = ScrollbarState::new(scrollbar_content_length(total_lines, visible_height)).position(self.tab.viewer_scroll.get());
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(if !self.layout_config.is_zen && (self.layout_config.is_inspector || self.layout_config.is_status) {
                 Some(self.symbols.border.horizontal.as_str())
@@ -222,7 +249,7 @@ impl App {
             })
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(self.symbols.scrollbar.thumb.as_str())
-            .thumb_style(Style::default().fg(if self.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if self.tab.focus == Focus::Viewport { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.graph_scrollbar, &mut scrollbar_state);
     }
@@ -251,19 +278,19 @@ impl App {
 
     // Resolve the selected status row into a repository-relative path.
     pub fn get_selected_file_name(&self) -> Option<String> {
-        match self.focus {
+        match self.tab.focus {
             Focus::StatusTop => {
-                if self.graph_selected != 0 && !self.current_diff.is_empty() {
+                if self.tab.graph_selected != 0 && !self.tab.current_diff.is_empty() {
                     // Commit status rows come from current_diff.
-                    Some(self.current_diff.get(self.status_top_selected)?.filename.to_string())
-                } else if self.graph_selected == 0 && self.uncommitted.is_staged {
+                    Some(self.tab.current_diff.get(self.tab.status_top_selected)?.filename.to_string())
+                } else if self.tab.graph_selected == 0 && self.tab.uncommitted.is_staged {
                     self.selected_staged_status_file_name()
                 } else {
                     None
                 }
             },
             Focus::StatusBottom => {
-                if self.graph_selected == 0 && self.uncommitted.is_unstaged {
+                if self.tab.graph_selected == 0 && self.tab.uncommitted.is_unstaged {
                     self.selected_unstaged_status_file_name()
                 } else {
                     None
@@ -273,7 +300,7 @@ impl App {
         }
     }
 
-    fn selected_uncommitted_file_name(conflicts: &[String], changes: &FileChanges, index: usize) -> Option<String> {
+    pub(crate) fn selected_uncommitted_file_name(conflicts: &[String], changes: &FileChanges, index: usize) -> Option<String> {
         if index < conflicts.len() {
             return conflicts.get(index).cloned();
         }
@@ -293,85 +320,151 @@ impl App {
     }
 
     pub(crate) fn selected_staged_status_file_name(&self) -> Option<String> {
-        Self::selected_uncommitted_file_name(&self.uncommitted.conflicts, &self.uncommitted.staged, self.status_top_selected)
+        Self::selected_uncommitted_file_name(&self.tab.uncommitted.conflicts, &self.tab.uncommitted.staged, self.tab.status_top_selected)
     }
 
     pub(crate) fn selected_unstaged_status_file_name(&self) -> Option<String> {
-        Self::selected_uncommitted_file_name(&self.uncommitted.conflicts, &self.uncommitted.unstaged, self.status_bottom_selected)
+        Self::selected_uncommitted_file_name(&self.tab.uncommitted.conflicts, &self.tab.uncommitted.unstaged, self.tab.status_bottom_selected)
     }
 
     pub(crate) fn selected_staged_status_file_is_conflict(&self) -> bool {
-        self.status_top_selected < self.uncommitted.conflicts.len()
+        self.tab.status_top_selected < self.tab.uncommitted.conflicts.len()
     }
 
     pub(crate) fn selected_unstaged_status_file_is_conflict(&self) -> bool {
-        self.status_bottom_selected < self.uncommitted.conflicts.len()
+        self.tab.status_bottom_selected < self.tab.uncommitted.conflicts.len()
     }
 
     pub fn open_viewer(&mut self, repo: &git2::Repository) {
         if let Some(file_name) = self.get_selected_file_name() {
-            self.file_name = Some(file_name);
-            let oid = if self.graph_selected != 0 { self.graph_oid_at(self.graph_selected).unwrap_or_else(Oid::zero) } else { Oid::zero() };
+            self.tab.file_name = Some(file_name);
+            let oid = if self.tab.graph_selected != 0 { self.graph_oid_at(self.tab.graph_selected).unwrap_or_else(Oid::zero) } else { Oid::zero() };
             self.update_viewer(oid, repo);
-            self.viewport = Viewport::Viewer;
+            self.tab.viewport = Viewport::Viewer;
         }
     }
 
     pub fn refresh_viewer_for_layout_change(&mut self) {
-        if self.viewport != Viewport::Viewer || self.file_name.is_none() {
+        if self.tab.viewport != Viewport::Viewer || self.tab.file_name.is_none() {
             return;
         }
-        let Some(repo) = self.repo.clone() else {
+        // Rewrapping reuses the loaded diff; a file still loading is laid out for the new size on arrival.
+        let Some(diff) = self.tab.viewer_diff.take() else {
             return;
         };
 
         let old_mode = self.viewer_mode;
         let old_unified_idx = match old_mode {
-            ViewerMode::Full => self.viewer_selected,
-            ViewerMode::Hunks => self.viewer_hunks.get(self.viewer_selected).copied().unwrap_or(0),
-            ViewerMode::Split => self.split_unified_index(self.viewer_selected),
+            ViewerMode::Full => self.tab.viewer_selected,
+            ViewerMode::Hunks => self.tab.viewer_hunks.get(self.tab.viewer_selected).copied().unwrap_or(0),
+            ViewerMode::Split => self.split_unified_index(self.tab.viewer_selected),
         };
-        let oid = if self.graph_selected != 0 { self.graph_oid_at(self.graph_selected).unwrap_or_else(Oid::zero) } else { Oid::zero() };
 
-        self.update_viewer(oid, &repo);
+        self.apply_viewer_diff(diff);
         self.viewer_mode = old_mode;
-        self.viewer_selected = match old_mode {
-            ViewerMode::Full => old_unified_idx.min(self.viewer_lines.len().saturating_sub(1)),
-            ViewerMode::Hunks => self.viewer_hunks.iter().enumerate().min_by_key(|(_, h)| h.abs_diff(old_unified_idx)).map(|(idx, _)| idx).unwrap_or(0),
+        self.tab.viewer_selected = match old_mode {
+            ViewerMode::Full => old_unified_idx.min(self.tab.viewer_lines.len().saturating_sub(1)),
+            ViewerMode::Hunks => self.tab.viewer_hunks.iter().enumerate().min_by_key(|(_, h)| h.abs_diff(old_unified_idx)).map(|(idx, _)| idx).unwrap_or(0),
             ViewerMode::Split => self.closest_split_row_for_unified(old_unified_idx),
         };
-        self.viewer_scroll.set(self.viewer_selected);
+        self.tab.viewer_scroll.set(self.tab.viewer_selected);
     }
 
     pub fn mark_viewer_layout_dirty(&mut self) {
-        if self.viewport == Viewport::Viewer {
+        if self.tab.viewport == Viewport::Viewer {
             self.is_viewer_layout_dirty = true;
         }
     }
 
     pub fn update_viewer(&mut self, oid: Oid, repo: &git2::Repository) {
+        let size_limit = self.layout_config.viewer_file_size_limit.saturating_mul(1024);
+        self.load_viewer_file(oid, repo, Some(size_limit));
+    }
+
+    // None as the size limit loads the file whole, for the explicit "load full diff" request.
+    pub(crate) fn load_viewer_file(&mut self, oid: Oid, repo: &git2::Repository, size_limit: Option<u64>) {
         // The selected filename is owned by App so viewer reloads can reuse it.
-        let filename = self.file_name.clone().unwrap();
+        let filename = self.tab.file_name.clone().unwrap();
+        // Oid::zero represents the uncommitted pseudo-row and reads from the working tree.
+        let is_conflict = oid == Oid::zero() && self.tab.uncommitted.conflicts.iter().any(|path| path == &filename);
+        let oid = self.commit_oid(oid);
+
+        self.tab.viewer_diff = None;
+        self.clear_viewer_rows();
+        if let Some(tx) = self.tab.diff_tx.clone() {
+            let request_id = self.tab.graph.next_request_id();
+            if tx.send(DiffCommand::QueryFile { generation: self.tab.graph.generation, request_id, oid, filename: filename.clone(), is_conflict, size_limit }).is_ok() {
+                self.tab.graph.requested_viewer = Some(request_id);
+                return;
+            }
+        }
 
-        if oid == Oid::zero()
-            && self.uncommitted.conflicts.iter().any(|path| path == &filename)
-            && let Ok(Some(conflict)) = get_conflict_file(repo, &filename)
-        {
-            self.update_conflict_viewer(conflict);
-            return;
+        self.tab.graph.requested_viewer = None;
+        self.apply_viewer_diff(load_file_diff(repo, oid, &filename, is_conflict, size_limit, &mut || false));
+    }
+
+    // Lays out a file the diff service (or the in-place fallback) produced and keeps it for rewrapping.
+    pub(crate) fn apply_viewer_diff(&mut self, diff: FileDiff) {
+        match &diff {
+            FileDiff::Conflict(conflict) => self.update_conflict_viewer(conflict),
+            FileDiff::Patch { original_lines, hunks } => self.update_patch_viewer(original_lines, hunks),
+            FileDiff::Binary { old_size, new_size, preview } => {
+                let size = |size: &Option<u64>| size.map(format_size).unwrap_or_else(|| viewer::MISSING().to_string());
+                let notice = vec![
+                    viewer::BINARY_FILE().to_string(),
+                    format!("{} {}", viewer::BEFORE(), size(old_size)),
+                    format!("{} {}", viewer::AFTER(), size(new_size)),
+                    String::new(),
+                    viewer::HEX_PREVIEW().to_string(),
+                ];
+                self.update_summary_viewer(notice, &hex_dump(preview), false);
+            },
+            FileDiff::LfsPointer { old, new } => {
+                let mut notice = vec![viewer::LFS_POINTER().to_string()];
+                for (label, pointer) in [(viewer::BEFORE(), old), (viewer::AFTER(), new)] {
+                    notice.extend(lfs_pointer_lines(label, pointer.as_ref()));
+                }
+                self.update_summary_viewer(notice, &[], false);
+            },
+            FileDiff::Truncated { lines, hunks, size, limit } => {
+                let mut notice = vec![viewer::TRUNCATED().to_string(), format!("{} {} / {}", viewer::SHOWN(), format_size(*limit), format_size(*size))];
+                let keys = self.palette_keys(&Command::LoadFullDiff);
+                if !keys.is_empty() {
+                    notice.push(format!("{} {keys}", viewer::LOAD_FULL_DIFF()));
+                }
+                self.update_patch_viewer(lines, hunks);
+                self.prepend_viewer_notice(notice);
+            },
         }
+        self.tab.viewer_diff = Some(diff);
+        self.apply_viewer_pending_line();
+    }
 
-        // Oid::zero represents the uncommitted pseudo-row and reads from the working tree.
-        let (original_lines, hunks) = if oid == Oid::zero() {
-            (get_file_at_workdir(repo, &filename), get_file_diff_at_workdir(repo, &filename).unwrap_or_default())
-        } else {
-            (get_file_at_oid(repo, oid, &filename), get_file_diff_at_oid(repo, oid, &filename).unwrap_or_default())
+    // Hunk mode may not show the line at all, so the jump switches to the full view.
+    fn apply_viewer_pending_line(&mut self) {
+        let Some(line) = self.viewer_pending_line.take() else {
+            return;
         };
+        let Some(&row) = self.tab.viewer_line_rows.get(line.saturating_sub(1)) else {
+            return;
+        };
+        if self.viewer_mode == ViewerMode::Hunks {
+            self.viewer_mode = ViewerMode::Full;
+        }
+        self.tab.viewer_selected = if self.viewer_mode == ViewerMode::Split { self.closest_split_row_for_unified(row) } else { row };
+        self.tab.viewer_scroll.set(self.tab.viewer_selected);
+    }
 
-        self.viewer_lines.clear();
-        self.viewer_split_rows.clear();
-        self.viewer_edges.clear();
-        self.viewer_hunks.clear();
+    fn clear_viewer_rows(&mut self) {
+        self.tab.viewer_lines.clear();
+        self.tab.viewer_split_rows.clear();
+        self.tab.viewer_edges.clear();
+        self.tab.viewer_hunks.clear();
+        self.tab.viewer_line_rows.clear();
+    }
+
+    fn update_patch_viewer(&mut self, original_lines: &[String], hunks: &[Hunk]) {
+        self.clear_viewer_rows();
         let mut current_line: usize = 0;
         let mut current_line_old: usize = 0;
 
@@ -384,9 +477,10 @@ impl App {
 
             // Fill unchanged file content before the next hunk starts.
             while current_line < old_start_idx && current_line < original_lines.len() {
+                self.tab.viewer_line_rows.push(self.tab.viewer_lines.len());
                 let wrapped = wrap_words(original_lines[current_line].clone(), (self.layout.graph.width as usize).saturating_sub(8));
                 for (idx, line) in wrapped.into_iter().enumerate() {
-                    self.viewer_lines.push(ListItem::new(
+                    self.tab.viewer_lines.push(ListItem::new(
                         Line::from(vec![
                             Span::styled((if idx == 0 { format!("{:3}  ", current_line + 1) } else { "     ".to_string() }).to_string(), Style::default().fg(self.theme.COLOR_BORDER)),
                             Span::styled(line.to_string(), Style::default().fg(self.theme.COLOR_TEXT)),
@@ -406,7 +500,7 @@ impl App {
                 if let Some(prev) = last_origin
                     && prev != line.origin
                 {
-                    self.viewer_edges.push(self.viewer_lines.len().saturating_sub(1));
+                    self.tab.viewer_edges.push(self.tab.viewer_lines.len().saturating_sub(1));
                 }
                 last_origin = Some(line.origin);
 
@@ -430,14 +524,17 @@ impl App {
                     _ => (Style::default(), "".to_string(), self.theme.COLOR_BORDER, self.theme.COLOR_TEXT, 0),
                 };
 
+                if matches!(line.origin, '+' | ' ') {
+                    self.tab.viewer_line_rows.push(self.tab.viewer_lines.len());
+                }
                 let wrapped = wrap_words(format!("{}{}", prefix, text), (self.layout.graph.width as usize).saturating_sub(9));
                 for (idx, line_wrapped) in wrapped.into_iter().enumerate() {
                     // Hunk mode indexes only changed rows.
                     if line.origin != ' ' {
-                        self.viewer_hunks.push(self.viewer_lines.len());
+                        self.tab.viewer_hunks.push(self.tab.viewer_lines.len());
                     }
 
-                    self.viewer_lines.push(
+                    self.tab.viewer_lines.push(
                         ListItem::new(Line::from(vec![
                             Span::styled((if idx == 0 { format!("{:3}  ", count) } else { "     ".to_string() }).to_string(), Style::default().fg(side)),
                             Span::styled(line_wrapped.to_string(), Style::default().fg(fg)),
@@ -465,9 +562,10 @@ impl App {
 
         // Append unchanged file content after the final hunk.
         while current_line < original_lines.len() {
+            self.tab.viewer_line_rows.push(self.tab.viewer_lines.len());
             let wrapped = wrap_words(original_lines[current_line].clone(), (self.layout.graph.width as usize).saturating_sub(8));
             for (idx, line) in wrapped.into_iter().enumerate() {
-                self.viewer_lines.push(
+                self.tab.viewer_lines.push(
                     ListItem::new(Line::from(vec![
                         Span::styled((if idx == 0 { format!("{:3}  ", current_line + 1) } else { "     ".to_string() }).to_string(), Style::default().fg(self.theme.COLOR_BORDER)),
                         Span::styled(line.to_string(), Style::default().fg(self.theme.COLOR_TEXT)),
@@ -478,15 +576,72 @@ impl App {
             current_line += 1;
         }
 
-        self.build_split_viewer_rows(&original_lines, &hunks);
-        self.viewer_selected = self.viewer_edges.first().copied().unwrap_or(0);
+        self.build_split_viewer_rows(original_lines, hunks);
+        self.tab.viewer_selected = self.tab.viewer_edges.first().copied().unwrap_or(0);
     }
 
-    fn update_conflict_viewer(&mut self, conflict: ConflictFile) {
-        self.viewer_lines.clear();
-        self.viewer_split_rows.clear();
-        self.viewer_edges.clear();
-        self.viewer_hunks.clear();
+    // Notice rows in the warning colour, then the body as plain rows; every row counts as a hunk row
+    // so hunk mode shows the summary too. Split mode repeats each row on the left only.
+    fn update_summary_viewer(&mut self, notice: Vec<String>, body: &[String], numbered: bool) {
+        self.clear_viewer_rows();
+        let (left_width, right_width) = self.split_pane_text_widths();
+
+        for text in notice {
+            let source_idx = self.tab.viewer_lines.len();
+            for line in wrap_words(text.clone(), (self.layout.graph.width as usize).saturating_sub(8)) {
+                self.tab.viewer_hunks.push(self.tab.viewer_lines.len());
+                self.tab.viewer_lines.push(ListItem::new(Line::from(vec![Span::raw("     "), Span::styled(line, Style::default().fg(self.theme.COLOR_ORANGE))])));
+            }
+            self.push_split_pair(Some(SplitCell { number: 0, origin: 'i', text }), None, left_width, right_width, vec![source_idx]);
+        }
+        if !body.is_empty() {
+            self.tab.viewer_edges.push(self.tab.viewer_lines.len());
+        }
+
+        for (idx, text) in body.iter().enumerate() {
+            let source_idx = self.tab.viewer_lines.len();
+            if numbered {
+                self.tab.viewer_line_rows.push(source_idx);
+            }
+            for (wrap_idx, line) in wrap_words(text.clone(), (self.layout.graph.width as usize).saturating_sub(8)).into_iter().enumerate() {
+                let number = if numbered && wrap_idx == 0 { format!("{:3}  ", idx + 1) } else { "     ".to_string() };
+                self.tab.viewer_hunks.push(self.tab.viewer_lines.len());
+                self.tab
+                    .viewer_lines
+                    .push(ListItem::new(Line::from(vec![Span::styled(number, Style::default().fg(self.theme.COLOR_BORDER)), Span::styled(line, Style::default().fg(self.theme.COLOR_TEXT))])));
+            }
+            let origin = if numbered { ' ' } else { '.' };
+            let cell = SplitCell { number: idx + 1, origin, text: text.clone() };
+            self.push_split_pair(Some(cell), None, left_width, right_width, vec![source_idx]);
+        }
+
+        self.tab.viewer_selected = 0;
+    }
+
+    // Puts summary notice rows above rows that are already laid out, shifting every index into them.
+    fn prepend_viewer_notice(&mut self, notice: Vec<String>) {
+        let lines = std::mem::take(&mut self.tab.viewer_lines);
+        let split_rows = std::mem::take(&mut self.tab.viewer_split_rows);
+        let edges = std::mem::take(&mut self.tab.viewer_edges);
+        let hunks = std::mem::take(&mut self.tab.viewer_hunks);
+        let line_rows = std::mem::take(&mut self.tab.viewer_line_rows);
+        let selected = self.tab.viewer_selected;
+
+        self.update_summary_viewer(notice, &[], false);
+        let offset = self.tab.viewer_lines.len();
+        self.tab.viewer_lines.extend(lines);
+        self.tab.viewer_split_rows.extend(split_rows.into_iter().map(|mut row| {
+            row.unified_indices.iter_mut().for_each(|index| *index += offset);
+            row
+        }));
+        self.tab.viewer_edges.extend(edges.into_iter().map(|index| index + offset));
+        self.tab.viewer_hunks.extend(hunks.into_iter().map(|index| index + offset));
+        self.tab.viewer_line_rows.extend(line_rows.into_iter().map(|index| index + offset));
+        self.tab.viewer_selected = selected + offset;
+    }
+
+    fn update_conflict_viewer(&mut self, conflict: &ConflictFile) {
+        self.clear_viewer_rows();
 
         let mut section = ConflictSection::Normal;
         for (idx, line) in conflict.workdir.iter().enumerate() {
@@ -516,17 +671,18 @@ impl App {
             };
 
             if origin != ' ' {
-                self.viewer_hunks.push(self.viewer_lines.len());
+                self.tab.viewer_hunks.push(self.tab.viewer_lines.len());
             }
             if marker.is_some() {
-                self.viewer_edges.push(self.viewer_lines.len());
+                self.tab.viewer_edges.push(self.tab.viewer_lines.len());
             }
+            self.tab.viewer_line_rows.push(self.tab.viewer_lines.len());
 
             self.push_conflict_unified_line(idx + 1, origin, line);
         }
 
-        self.build_conflict_split_rows(&conflict);
-        self.viewer_selected = self.viewer_edges.first().copied().unwrap_or(0);
+        self.build_conflict_split_rows(conflict);
+        self.tab.viewer_selected = self.tab.viewer_edges.first().copied().unwrap_or(0);
     }
 
     fn push_conflict_unified_line(&mut self, number: usize, origin: char, text: &str) {
@@ -549,7 +705,7 @@ impl App {
 
         let wrapped = wrap_words(format!("{}{}", prefix, text), (self.layout.graph.width as usize).saturating_sub(9));
         for (idx, line_wrapped) in wrapped.into_iter().enumerate() {
-            self.viewer_lines.push(
+            self.tab.viewer_lines.push(
                 ListItem::new(Line::from(vec![
                     Span::styled(if idx == 0 { format!("{:3}  ", number) } else { "     ".to_string() }, Style::default().fg(number_fg)),
                     Span::styled(line_wrapped, Style::default().fg(text_fg)),
@@ -567,7 +723,7 @@ impl App {
         let mut rendered_marker = false;
 
         for (idx, line) in conflict.workdir.iter().enumerate() {
-            let source_idx = idx.min(self.viewer_lines.len().saturating_sub(1));
+            let source_idx = idx.min(self.tab.viewer_lines.len().saturating_sub(1));
             match conflict_marker(line) {
                 Some(ConflictMarker::Start) => {
                     rendered_marker = true;
@@ -623,7 +779,7 @@ impl App {
 
         let rows = conflict.ours.len().max(conflict.theirs.len()).max(1);
         for row in 0..rows {
-            let source_idx = row.min(self.viewer_lines.len().saturating_sub(1));
+            let source_idx = row.min(self.tab.viewer_lines.len().saturating_sub(1));
             let left = conflict.ours.get(row).map(|text| SplitCell { number: row + 1, origin: '-', text: text.clone() });
             let right = conflict.theirs.get(row).map(|text| SplitCell { number: row + 1, origin: '+', text: text.clone() });
             self.push_split_pair(left, right, left_width, right_width, vec![source_idx]);
@@ -631,7 +787,7 @@ impl App {
     }
 
     fn build_split_viewer_rows(&mut self, original_lines: &[String], hunks: &[Hunk]) {
-        self.viewer_split_rows.clear();
+        self.tab.viewer_split_rows.clear();
 
         let (left_width, right_width) = self.split_pane_text_widths();
         let mut current_line: usize = 0;
@@ -783,7 +939,7 @@ impl App {
             let left_text = left_wrapped.get(idx).map(String::as_str).unwrap_or("");
             let right_text = right_wrapped.get(idx).map(String::as_str).unwrap_or("");
 
-            self.viewer_split_rows.push(SplitViewerRow {
+            self.tab.viewer_split_rows.push(SplitViewerRow {
                 left: self.split_list_item(left_cell, left_text, idx == 0),
                 right: self.split_list_item(right_cell, right_text, idx == 0),
                 unified_indices: unified_indices.clone(),
@@ -817,15 +973,23 @@ impl App {
         let text_fg = match origin {
             '-' => self.theme.COLOR_RED,
             '+' => self.theme.COLOR_GREEN,
-            '!' => self.theme.COLOR_ORANGE,
+            '!' | 'i' => self.theme.COLOR_ORANGE,
             _ => self.theme.COLOR_TEXT,
         };
-        let number = if show_number { cell.map(|cell| format!("{:3}  ", cell.number)).unwrap_or_else(|| "     ".to_string()) } else { "     ".to_string() };
+        // Summary notices ('i') and unnumbered summary rows ('.') leave the number column blank.
+        let number = if show_number && !matches!(origin, 'i' | '.') { cell.map(|cell| format!("{:3}  ", cell.number)).unwrap_or_else(|| "     ".to_string()) } else { "     ".to_string() };
 
         ListItem::new(Line::from(vec![Span::styled(number, Style::default().fg(number_fg)), Span::styled(text.to_string(), Style::default().fg(text_fg))])).style(item_style)
     }
 }
 
+fn lfs_pointer_lines(label: &str, pointer: Option<&LfsPointer>) -> Vec<String> {
+    match pointer {
+        Some(pointer) => vec![label.to_string(), format!("  {} {}", viewer::OID(), pointer.oid), format!("  {} {}", viewer::SIZE(), format_size(pointer.size))],
+        None => vec![format!("{label} {}", viewer::MISSING())],
+    }
+}
+
 #[cfg(test)]
 #[path = "../../tests/app/draw/viewer.rs"]
 mod tests;
@@ -0,0 +1,83 @@
+use crate::{
+    app::app::App,
+    helpers::keymap::{KeyBinding, command_to_visual_string, key_sequence_continuations, keybinding_to_visual_string},
+};
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem},
+};
+
+impl App {
+    // Rows of next key and what it runs; "+N" counts the longer sequences behind a key.
+    pub(crate) fn which_key_rows(&self) -> Vec<(String, String)> {
+        if self.pending_mark.is_some() {
+            return self.mark_rows();
+        }
+        let Some(mode_map) = self.keymaps.get(&self.mode) else {
+            return Vec::new();
+        };
+        key_sequence_continuations(mode_map, &self.pending_keys)
+            .iter()
+            .map(|continuation| {
+                let label = match (&continuation.command, continuation.sequences) {
+                    (Some(command), 0) => command_to_visual_string(command),
+                    (Some(command), sequences) => format!("{} +{sequences}", command_to_visual_string(command)),
+                    (None, sequences) => format!("+{sequences}"),
+                };
+                (keybinding_to_visual_string(&continuation.key), label)
+            })
+            .collect()
+    }
+
+    pub fn draw_which_key(&mut self, frame: &mut Frame) {
+        let rows = self.which_key_rows();
+        if rows.is_empty() {
+            return;
+        }
+
+        let title = match &self.pending_mark {
+            Some(command) => format!(" {} ", command_to_visual_string(command)),
+            None => KeyBinding::sequence(&self.pending_keys).map(|binding| format!(" {} ", keybinding_to_visual_string(&binding))).unwrap_or_default(),
+        };
+        let key_width = rows.iter().map(|(key, _)| key.chars().count()).max().unwrap_or(0);
+        let label_width = rows.iter().map(|(_, label)| label.chars().count()).max().unwrap_or(0);
+
+        // Anchored to the bottom right, clear of the status bar.
+        let bounds = frame.area();
+        let width = ((key_width + label_width + 7).max(title.chars().count() + 4) as u16).min(bounds.width);
+        let height = ((rows.len() + 2) as u16).min(bounds.height.saturating_sub(2));
+        if width < 8 || height < 3 {
+            return;
+        }
+        let area = Rect::new(bounds.right().saturating_sub(width + 2), bounds.bottom().saturating_sub(height + 2), width, height);
+
+        self.theme.clear_area(area, frame.buffer_mut());
+
+        let menu_bg = self.theme.background_or_default(self.theme.COLOR_GREY_900);
+        let items: Vec<ListItem> = rows
+            .into_iter()
+            .map(|(key, label)| {
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!(" {key:>key_width$} "), Style::default().fg(self.theme.COLOR_HIGHLIGHTED).bg(menu_bg)),
+                    Span::styled(format!(" {label} "), Style::default().fg(self.theme.COLOR_TEXT).bg(menu_bg)),
+                ]))
+            })
+            .collect();
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.theme.COLOR_BORDER).bg(menu_bg))
+            .border_set(self.symbols.border.block_set())
+            .title(Span::styled(title, Style::default().fg(self.theme.COLOR_GREY_600).bg(menu_bg)))
+            .style(Style::default().bg(menu_bg));
+
+        frame.render_widget(List::new(items).block(block).style(Style::default().bg(menu_bg)), area);
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/draw/which_key.rs"]
+mod tests;
@@ -25,7 +25,7 @@ impl App {
         let max_text_width = available_width.saturating_sub(3);
 
         let mut lines: Vec<Line<'_>> = Vec::new();
-        for entry in &self.worktrees.entries {
+        for entry in &self.tab.worktrees.entries {
             let target = entry
                 .branch
                 .as_ref()
@@ -92,17 +92,17 @@ impl App {
         let total_lines = lines.len();
 
         if total_lines == 0 {
-            self.worktrees_selected = 0;
-        } else if self.worktrees_selected >= total_lines {
-            self.worktrees_selected = total_lines.saturating_sub(1);
+            self.tab.worktrees_selected = 0;
+        } else if self.tab.worktrees_selected >= total_lines {
+            self.tab.worktrees_selected = total_lines.saturating_sub(1);
         }
 
-        self.trap_selection(self.worktrees_selected, &self.worktrees_scroll, total_lines, visible_height);
+        self.trap_selection(self.tab.worktrees_selected, &self.tab.worktrees_scroll, total_lines, visible_height);
 
-        let start = self.worktrees_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let start = self.tab.worktrees_scroll.get().min(total_lines.saturating_sub(visible_height));
         let end = (start + visible_height).min(total_lines);
 
-        let list_items = zebra_list_items(&lines[start..end], visible_height, start, self.worktrees_selected, self.focus == Focus::Worktrees, !worktrees_empty, &self.theme);
+        let list_items = zebra_list_items(&lines[start..end], visible_height, start, self.tab.worktrees_selected, self.tab.focus == Focus::Worktrees, !worktrees_empty, &self.theme);
 
         if self.layout_config.is_zen {
             let list = List::new(list_items).block(Block::default().borders(Borders::ALL).padding(padding).border_set(self.symbols.border.block_set()));
@@ -110,14 +110,14 @@ impl App {
             frame.render_widget(list, self.layout.worktrees);
 
             let scroll_range = scrollbar_content_length(total_lines, visible_height);
-            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.worktrees_scroll.get());
+            let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.worktrees_scroll.get());
             let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
                 .begin_symbol(Some(self.symbols.scrollbar.begin.as_str()))
                 .end_symbol(Some(self.symbols.scrollbar.end.as_str()))
                 .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
                 .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
                 .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-                .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Worktrees { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+                .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Worktrees { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
             frame.render_stateful_widget(scrollbar, self.layout.worktrees_scrollbar, &mut scrollbar_state);
 
@@ -133,7 +133,7 @@ impl App {
         frame.render_widget(list, self.layout.worktrees);
 
         let scroll_range = scrollbar_content_length(total_lines, visible_height);
-        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.worktrees_scroll.get());
+        let mut scrollbar_state = ScrollbarState::new(scroll_range).position(self.tab.worktrees_scroll.get());
         let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
             .begin_symbol(Some(if self.layout_config.is_branches || self.layout_config.is_tags || self.layout_config.is_stashes || self.layout_config.is_reflogs {
                 self.symbols.border.vertical.as_str()
@@ -144,7 +144,7 @@ impl App {
             .track_symbol(Some(self.symbols.scrollbar.track.as_str()))
             .thumb_symbol(if total_lines > visible_height { self.symbols.scrollbar.thumb.as_str() } else { self.symbols.scrollbar.inactive_thumb.as_str() })
             .track_style(Style::default().fg(self.theme.COLOR_BORDER))
-            .thumb_style(Style::default().fg(if total_lines > visible_height && self.focus == Focus::Worktrees { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
+            .thumb_style(Style::default().fg(if total_lines > visible_height && self.tab.focus == Focus::Worktrees { self.theme.COLOR_GREY_600 } else { self.theme.COLOR_BORDER }));
 
         frame.render_stateful_widget(scrollbar, self.layout.worktrees_scrollbar, &mut scrollbar_state);
     }
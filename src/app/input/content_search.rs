@@ -0,0 +1,190 @@
+use crate::{
+    app::app::{App, Direction, Focus, Viewport},
+    core::content_search::{ContentSearchEvent, spawn_content_search},
+    git::queries::grep::{GrepPattern, GrepScope},
+};
+use git2::Oid;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::{Duration, Instant};
+
+// Typing waits this long for the next key before searching, so a fast typist starts one walk.
+const CONTENT_SEARCH_DEBOUNCE: Duration = Duration::from_millis(150);
+
+impl App {
+    // Searches the selected commit's tree, or the working tree when the uncommitted row is selected.
+    pub fn on_search_contents(&mut self) {
+        if self.tab.repo.is_none() || matches!(self.tab.viewport, Viewport::Splash | Viewport::Settings) {
+            return;
+        }
+
+        if !matches!(
+            self.tab.focus,
+            Focus::Viewport
+                | Focus::Inspector
+                | Focus::StatusTop
+                | Focus::StatusBottom
+                | Focus::Search
+                | Focus::Branches
+                | Focus::Tags
+                | Focus::Stashes
+                | Focus::Reflogs
+                | Focus::Worktrees
+                | Focus::Submodules
+        ) {
+            return;
+        }
+
+        self.modal_content_search_scope = match self.tab.graph_selected {
+            0 => GrepScope::Workdir,
+            index => self.graph_oid_at(index).map(GrepScope::Tree).unwrap_or(GrepScope::Workdir),
+        };
+        self.modal_content_search_return_focus = self.tab.focus;
+        self.modal_input.clear();
+        self.modal_content_search_query.clear();
+        self.reset_content_search();
+        self.tab.focus = Focus::ModalContentSearch;
+    }
+
+    pub(crate) fn handle_content_search_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc => {
+                self.close_content_search_modal();
+            },
+            KeyCode::Enter => {
+                self.select_content_search_result();
+            },
+            KeyCode::Tab => {
+                self.modal_content_search_is_regex = !self.modal_content_search_is_regex;
+                self.restart_content_search();
+            },
+            KeyCode::Down => {
+                self.move_content_search_selection(Direction::Down);
+            },
+            KeyCode::Up => {
+                self.move_content_search_selection(Direction::Up);
+            },
+            KeyCode::Char('j') | KeyCode::Char('J') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.move_content_search_selection(Direction::Down);
+            },
+            KeyCode::Char('k') | KeyCode::Char('K') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.move_content_search_selection(Direction::Up);
+            },
+            _ => {
+                self.modal_input.on_key(key_event);
+                // Cursor moves leave the query as it is, and so do the results.
+                if self.modal_input.value() != self.modal_content_search_query {
+                    self.modal_content_search_due = Some(Instant::now() + CONTENT_SEARCH_DEBOUNCE);
+                }
+            },
+        }
+
+        true
+    }
+
+    // Dropping the previous task cancels it, so only the latest query streams results.
+    pub(crate) fn restart_content_search(&mut self) {
+        self.reset_content_search();
+        let query = self.modal_input.value().to_string();
+        self.modal_content_search_query = query.clone();
+        if query.is_empty() {
+            return;
+        }
+
+        match GrepPattern::new(&query, self.modal_content_search_is_regex) {
+            Ok(pattern) => {
+                let path = self.tab.path.clone().unwrap_or_else(|| ".".to_string());
+                let size_limit = self.layout_config.viewer_file_size_limit.saturating_mul(1024);
+                self.modal_content_search_task = Some(spawn_content_search(path, self.tab.work_tree.clone(), self.modal_content_search_scope, pattern, size_limit));
+            },
+            Err(error) => {
+                self.modal_content_search_error = Some(error.to_string());
+            },
+        }
+    }
+
+    pub fn poll_content_search(&mut self) {
+        if self.modal_content_search_due.is_some_and(|due| Instant::now() >= due) {
+            self.restart_content_search();
+        }
+        let Some(task) = &self.modal_content_search_task else {
+            return;
+        };
+
+        let mut is_finished = false;
+        for event in task.events.try_iter() {
+            match event {
+                ContentSearchEvent::Match(result) => self.modal_content_search_results.push(result),
+                ContentSearchEvent::Done { is_truncated } => {
+                    self.modal_content_search_is_truncated = is_truncated;
+                    is_finished = true;
+                },
+                ContentSearchEvent::Failed(error) => {
+                    self.modal_content_search_error = Some(error);
+                    is_finished = true;
+                },
+            }
+        }
+        if is_finished {
+            self.modal_content_search_task = None;
+        }
+    }
+
+    pub(crate) fn close_content_search_modal(&mut self) {
+        self.modal_input.clear();
+        self.modal_content_search_query.clear();
+        self.reset_content_search();
+        self.modal_content_search_is_regex = false;
+        self.tab.focus = self.modal_content_search_return_focus;
+        self.modal_content_search_return_focus = Focus::Viewport;
+    }
+
+    fn reset_content_search(&mut self) {
+        self.modal_content_search_task = None;
+        self.modal_content_search_due = None;
+        self.modal_content_search_results.clear();
+        self.modal_content_search_selected = 0;
+        self.modal_content_search_scroll.set(0);
+        self.modal_content_search_error = None;
+        self.modal_content_search_is_truncated = false;
+    }
+
+    fn move_content_search_selection(&mut self, direction: Direction) {
+        let len = self.modal_content_search_results.len();
+        if len == 0 {
+            self.modal_content_search_selected = 0;
+            return;
+        }
+
+        let len = len as i32;
+        let current = self.modal_content_search_selected.rem_euclid(len);
+        self.modal_content_search_selected = match direction {
+            Direction::Up => (current - 1).rem_euclid(len),
+            Direction::Down => (current + 1).rem_euclid(len),
+        };
+    }
+
+    // The viewer loads the file asynchronously, so the matched line is selected once it arrives.
+    fn select_content_search_result(&mut self) {
+        let Some(result) = self.modal_content_search_results.get(self.modal_content_search_selected as usize).cloned() else {
+            return;
+        };
+        let Some(repo) = self.tab.repo.clone() else {
+            return;
+        };
+        let oid = match self.modal_content_search_scope {
+            GrepScope::Workdir => Oid::zero(),
+            GrepScope::Tree(oid) => oid,
+        };
+
+        self.close_content_search_modal();
+        self.tab.file_name = Some(result.path);
+        self.viewer_pending_line = Some(result.line);
+        self.update_viewer(oid, &repo);
+        self.tab.viewport = Viewport::Viewer;
+        self.tab.focus = Focus::Viewport;
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/content_search.rs"]
+mod tests;
@@ -1,13 +1,17 @@
 use crate::{
     app::{
-        app::{App, ContextMenuAction, ContextMenuItem, ContextMenuState, Direction, Focus, MouseSelectionTarget, SettingsSelectionKind, SettingsTab, Viewport},
+        app::{
+            App, BranchDrag, BranchDropAction, ContextMenuAction, ContextMenuItem, ContextMenuState, Direction, Focus, MouseSelectionTarget, OperationKind, SettingsSelectionKind, SettingsTab,
+            Viewport,
+        },
         input::remotes::REMOTE_ACTIONS,
         state::defaults::ViewerMode,
     },
+    core::diff_service::FileDiff,
     git::queries::commits::get_current_branch,
     helpers::{
         keymap::{Command, InputMode, command_to_visual_string},
-        localisation::{menu, settings},
+        localisation::{menu, operations, settings},
     },
 };
 use ratatui::{
@@ -38,6 +42,33 @@ impl App {
         self.context_menu = Some(ContextMenuState { column, row, selected: Self::first_enabled_context_menu_index(&items), items });
     }
 
+    // Dropping a branch on a graph row offers the moves that make sense for that branch and commit.
+    pub(crate) fn open_branch_drop_menu(&mut self, drag: BranchDrag, column: u16, row: u16) {
+        let Some(index) = drag.drop_index.filter(|_| drag.is_dragging) else {
+            return;
+        };
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let Some(oid) = self.graph_oid_at(index) else {
+            return;
+        };
+        self.select_mouse_target(MouseSelectionTarget::Graph(index));
+
+        let current = get_current_branch(&repo);
+        let head = repo.head().ok().and_then(|head| head.target());
+        let tip = repo.resolve_reference_from_short_name(&drag.name).ok().and_then(|reference| reference.peel_to_commit().ok()).map(|commit| commit.id());
+        let is_current = current.as_deref() == Some(drag.name.as_str());
+        let is_idle = Self::active_operation_kind(&repo).is_none();
+        let item = |label: &str, action: BranchDropAction, enabled: bool| Self::item(label, ContextMenuAction::BranchDrop { branch: drag.name.clone(), index, action }, enabled);
+
+        let items = vec![
+            item(menu::REBASE_ONTO_HERE(), BranchDropAction::Rebase, is_idle && is_current && tip != Some(oid)),
+            item(menu::MERGE_INTO_HERE(), BranchDropAction::Merge, is_idle && !is_current && current.is_some() && head == Some(oid) && tip.is_some_and(|tip| tip != oid)),
+            item(menu::RESET_BRANCH_TO_HERE(), BranchDropAction::Reset, drag.is_local && tip.is_some_and(|tip| tip != oid)),
+            item(menu::CREATE_BRANCH_HERE(), BranchDropAction::CreateBranch, true),
+        ];
+        self.context_menu = Some(ContextMenuState { column, row, selected: Self::first_enabled_context_menu_index(&items), items });
+    }
+
     pub(crate) fn close_context_menu(&mut self) {
         self.context_menu = None;
     }
@@ -115,7 +146,7 @@ impl App {
             None => Vec::new(),
         };
 
-        if self.repo.is_some() {
+        if self.tab.repo.is_some() {
             local_items.insert(0, Self::command_item(menu::RELOAD(), Command::Reload));
         }
 
@@ -184,8 +215,11 @@ impl App {
                 | Command::Checkout
                 | Command::HardReset
                 | Command::MixedReset
+                | Command::SoftReset
+                | Command::ResetBranch
                 | Command::ForcePush
                 | Command::PushTags
+                | Command::ApplyPatch
                 | Command::DeleteBranch
                 | Command::RenameBranch
                 | Command::Untag
@@ -224,22 +258,26 @@ impl App {
     }
 
     fn graph_network_context_menu_items(&self, force_graph_focus: bool) -> Vec<ContextMenuItem> {
-        if self.repo.is_none() {
+        if self.tab.repo.is_none() {
             return Vec::new();
         }
 
-        vec![Self::graph_command_item(menu::FETCH(), Command::FetchAll, force_graph_focus), Self::graph_command_item(menu::PUSH(), Command::ForcePush, force_graph_focus)]
+        let mut items = vec![Self::graph_command_item(menu::FETCH(), Command::FetchAll, force_graph_focus), Self::graph_command_item(menu::PUSH(), Command::ForcePush, force_graph_focus)];
+        if self.tab.repo.as_ref().is_some_and(|repo| repo.is_shallow()) {
+            items.insert(1, Self::graph_command_item(menu::DEEPEN_HISTORY(), Command::DeepenHistory, force_graph_focus));
+        }
+        items
     }
 
     fn global_context_menu_items(&self) -> Vec<ContextMenuItem> {
         let mut items = Vec::new();
-        if self.viewport == Viewport::Settings {
+        if self.tab.viewport == Viewport::Settings {
             items.push(Self::command_item(menu::BACK(), Command::Back));
         } else {
-            items.push(Self::item(menu::SETTINGS(), ContextMenuAction::Settings, self.repo.is_some()));
+            items.push(Self::item(menu::SETTINGS(), ContextMenuAction::Settings, self.tab.repo.is_some()));
         }
-        if self.viewport == Viewport::Splash {
-            if self.repo.is_some() {
+        if self.tab.viewport == Viewport::Splash {
+            if self.tab.repo.is_some() {
                 items.push(Self::command_item(menu::BACK(), Command::Back));
             }
         } else {
@@ -269,11 +307,23 @@ impl App {
             Self::graph_command_item(menu::CHECKOUT(), Command::Checkout, force_graph_focus),
             Self::graph_command_item(menu::HARD_RESET(), Command::HardReset, force_graph_focus),
             Self::graph_command_item(menu::MIXED_RESET(), Command::MixedReset, force_graph_focus),
-            Self::graph_command_item(menu::CHERRYPICK(), Command::Cherrypick, force_graph_focus),
-            Self::graph_command_item(menu::REVERT(), Command::Revert, force_graph_focus),
-            Self::graph_command_item(menu::REBASE(), Command::Rebase, force_graph_focus),
-            Self::graph_command_item(menu::MERGE(), Command::Merge, force_graph_focus),
+            Self::graph_command_item(menu::SOFT_RESET(), Command::SoftReset, force_graph_focus),
         ]);
+        if self.tab.graph_marked_oids.is_empty() {
+            items.push(Self::graph_command_item(menu::CHERRYPICK(), Command::Cherrypick, force_graph_focus));
+            items.push(Self::graph_command_item(menu::REVERT(), Command::Revert, force_graph_focus));
+        } else {
+            items.push(Self::graph_command_item(menu::CHERRYPICK_MARKED(), Command::Cherrypick, force_graph_focus));
+            items.push(Self::graph_command_item(menu::REVERT_MARKED(), Command::Revert, force_graph_focus));
+        }
+        items.extend([Self::graph_command_item(menu::REBASE(), Command::Rebase, force_graph_focus), Self::graph_command_item(menu::MERGE(), Command::Merge, force_graph_focus)]);
+        items.push(Self::graph_command_item(if self.tab.graph_marked_oids.is_empty() { menu::EXPORT_PATCH() } else { menu::EXPORT_MARKED_PATCHES() }, Command::ExportPatches, force_graph_focus));
+        let is_marked = self.graph_oid_at(index).is_some_and(|oid| self.tab.graph_marked_oids.contains(&oid));
+        items.push(Self::graph_command_item(if is_marked { menu::UNMARK_COMMIT() } else { menu::MARK_COMMIT() }, Command::MarkCommit, force_graph_focus));
+        if !self.tab.graph_marked_oids.is_empty() {
+            items.push(Self::graph_command_item(menu::MARK_RANGE(), Command::MarkRange, force_graph_focus));
+            items.push(Self::graph_command_item(menu::CLEAR_MARKS(), Command::ClearMarks, force_graph_focus));
+        }
         items.extend(self.graph_network_context_menu_items(force_graph_focus));
 
         if let Some(alias) = self.graph_alias_at(index) {
@@ -285,7 +335,7 @@ impl App {
             if !self.graph_local_branch_choices(alias).is_empty() {
                 items.push(Self::graph_command_item(menu::RENAME_BRANCH(), Command::RenameBranch, force_graph_focus));
             }
-            if let Some(repo) = self.repo.as_ref() {
+            if let Some(repo) = self.tab.repo.as_ref() {
                 let current = get_current_branch(repo);
                 if !self.graph_deletable_branch_choices(alias, current.as_deref()).is_empty() {
                     items.push(Self::graph_command_item(menu::DELETE_BRANCH(), Command::DeleteBranch, force_graph_focus));
@@ -307,24 +357,29 @@ impl App {
 
     fn uncommitted_graph_context_menu_items(&self) -> Vec<ContextMenuItem> {
         let mut items = Vec::new();
-        if self.uncommitted.is_unstaged {
+        if self.tab.uncommitted.is_unstaged {
             items.push(Self::command_item(menu::STAGE_ALL(), Command::Stage));
         }
-        if self.uncommitted.is_staged {
+        if self.tab.uncommitted.is_staged {
             items.push(Self::command_item(menu::UNSTAGE_ALL(), Command::Unstage));
             items.push(Self::command_item(menu::COMMIT(), Command::Commit));
         }
-        if !self.uncommitted.is_clean {
+        if !self.tab.uncommitted.is_clean {
             items.push(Self::command_item(menu::STASH_CHANGES(), Command::Stash));
         }
-        if self.repo.as_ref().is_some_and(|repo| Self::active_operation_kind(repo).is_some()) {
+        if self.tab.repo.as_ref().is_some_and(|repo| Self::active_operation_kind(repo).is_some()) {
             items.push(Self::command_item(menu::CONTINUE_OPERATION(), Command::ContinueOperation));
             items.push(Self::command_item(menu::ABORT_OPERATION(), Command::AbortOperation));
         }
+        items.push(Self::command_item(menu::APPLY_PATCH(), Command::ApplyPatch));
         items.extend(self.graph_network_context_menu_items(false));
         items.push(Self::command_item(menu::FIND(), Command::Find));
-        if self.repo.is_some() {
+        if matches!(self.tab.viewer_diff, Some(FileDiff::Truncated { .. })) {
+            items.insert(0, Self::command_item(menu::LOAD_FULL_DIFF(), Command::LoadFullDiff));
+        }
+        if self.tab.repo.is_some() {
             items.push(Self::command_item(menu::FIND_FILE(), Command::FindFile));
+            items.push(Self::command_item(menu::SEARCH_CONTENTS(), Command::SearchContents));
         }
         items
     }
@@ -332,7 +387,7 @@ impl App {
     fn graph_tag_names_at(&self, index: usize) -> Vec<String> {
         self.graph_row_at(index)
             .map(|row| row.tags.iter().map(|tag| tag.name.clone()).collect())
-            .or_else(|| self.graph_alias_at(index).map(|alias| self.tags.local.get(&alias).cloned().unwrap_or_default()))
+            .or_else(|| self.graph_alias_at(index).map(|alias| self.tab.tags.local.get(&alias).cloned().unwrap_or_default()))
             .unwrap_or_default()
     }
 
@@ -344,7 +399,7 @@ impl App {
         let split_label = if self.viewer_mode == ViewerMode::Split { menu::SHOW_UNIFIED_DIFF() } else { menu::SHOW_SPLIT_DIFF() };
         let mut items =
             vec![Self::command_item(hunk_label, Command::ToggleHunkMode), Self::command_item(split_label, Command::ToggleSplitDiffMode), Self::command_item(menu::BACK_TO_GRAPH(), Command::Back)];
-        if self.repo.is_some() {
+        if self.tab.repo.is_some() {
             items.push(Self::command_item(menu::FIND_FILE(), Command::FindFile));
         }
         items
@@ -362,11 +417,14 @@ impl App {
             items.push(Self::command_item(menu::RENAME_BRANCH(), Command::RenameBranch));
         }
         items.push(Self::command_item(menu::DELETE_BRANCH(), Command::DeleteBranch));
+        items.extend(Self::reset_context_menu_items());
         items
     }
 
     fn tag_context_menu_items(&self) -> Vec<ContextMenuItem> {
-        vec![Self::command_item(menu::OPEN_COMMIT(), Command::Select), Self::command_item(menu::DELETE_TAG(), Command::Untag)]
+        let mut items = vec![Self::command_item(menu::OPEN_COMMIT(), Command::Select), Self::command_item(menu::DELETE_TAG(), Command::Untag)];
+        items.extend(Self::reset_context_menu_items());
+        items
     }
 
     fn stash_context_menu_items(&self) -> Vec<ContextMenuItem> {
@@ -374,12 +432,19 @@ impl App {
     }
 
     fn reflog_context_menu_items(&self) -> Vec<ContextMenuItem> {
-        vec![Self::command_item(menu::OPEN_COMMIT(), Command::Select), Self::command_item(menu::CREATE_BRANCH_HERE(), Command::CreateBranch)]
+        let mut items = vec![Self::command_item(menu::OPEN_COMMIT(), Command::Select), Self::command_item(menu::CREATE_BRANCH_HERE(), Command::CreateBranch)];
+        items.extend(Self::reset_context_menu_items());
+        items
+    }
+
+    // Branch, tag and reflog rows can all be reset targets for the current branch.
+    fn reset_context_menu_items() -> [ContextMenuItem; 3] {
+        [Self::command_item(menu::HARD_RESET(), Command::HardReset), Self::command_item(menu::MIXED_RESET(), Command::MixedReset), Self::command_item(menu::SOFT_RESET(), Command::SoftReset)]
     }
 
     fn worktree_context_menu_items(&self, index: usize) -> Vec<ContextMenuItem> {
         let mut items = Vec::new();
-        let Some(entry) = self.worktrees.entries.get(index) else {
+        let Some(entry) = self.tab.worktrees.entries.get(index) else {
             return items;
         };
         if entry.is_valid {
@@ -397,7 +462,7 @@ impl App {
 
     fn submodule_context_menu_items(&self, index: usize) -> Vec<ContextMenuItem> {
         let mut items = Vec::new();
-        let Some(entry) = self.submodules.entries.get(index) else {
+        let Some(entry) = self.tab.submodules.entries.get(index) else {
             return items;
         };
         if entry.can_open() {
@@ -411,7 +476,7 @@ impl App {
         if entry.is_index_modified {
             items.push(Self::command_item(menu::UNSTAGE_SUBMODULE(), Command::Unstage));
         }
-        if !self.submodule_stack.is_empty() {
+        if !self.tab.submodule_stack.is_empty() {
             items.push(Self::command_item(menu::RETURN_TO_PARENT_REPOSITORY(), Command::ReturnToParentRepository));
         }
         items
@@ -419,8 +484,8 @@ impl App {
 
     fn inspector_context_menu_items(&self) -> Vec<ContextMenuItem> {
         let mut items = vec![Self::command_item(menu::SHOW_FILES_STATUS(), Command::NarrowScope), Self::command_item(menu::BACK_TO_GRAPH(), Command::WidenScope)];
-        if self.graph_selected != 0 {
-            items.extend(self.graph_context_menu_items(self.graph_selected, true, false));
+        if self.tab.graph_selected != 0 {
+            items.extend(self.graph_context_menu_items(self.tab.graph_selected, true, false));
         }
         items
     }
@@ -433,7 +498,7 @@ impl App {
         }
 
         items.push(Self::command_item(menu::OPEN_FILE(), Command::Select));
-        if self.graph_selected == 0 {
+        if self.tab.graph_selected == 0 {
             if is_top {
                 if !self.selected_staged_status_file_is_conflict() {
                     items.push(Self::command_item(menu::UNSTAGE_FILE(), Command::Unstage));
@@ -448,28 +513,28 @@ impl App {
     }
 
     fn status_top_clickable_count_for_context(&self) -> usize {
-        if self.graph_selected == 0 {
-            if !self.is_uncommitted_loaded || !self.uncommitted.is_staged {
+        if self.tab.graph_selected == 0 {
+            if !self.tab.is_uncommitted_loaded || !self.tab.uncommitted.is_staged {
                 return 0;
             }
-            self.uncommitted.conflicts.len() + self.uncommitted.staged.modified.len() + self.uncommitted.staged.added.len() + self.uncommitted.staged.deleted.len()
+            self.tab.uncommitted.conflicts.len() + self.tab.uncommitted.staged.modified.len() + self.tab.uncommitted.staged.added.len() + self.tab.uncommitted.staged.deleted.len()
         } else if self.selected_commit_diff_is_loaded() {
-            self.current_diff.len()
+            self.tab.current_diff.len()
         } else {
             0
         }
     }
 
     fn status_bottom_clickable_count_for_context(&self) -> usize {
-        if self.graph_selected != 0 || !self.is_uncommitted_loaded || !self.uncommitted.is_unstaged {
+        if self.tab.graph_selected != 0 || !self.tab.is_uncommitted_loaded || !self.tab.uncommitted.is_unstaged {
             return 0;
         }
-        self.uncommitted.conflicts.len() + self.uncommitted.unstaged.modified.len() + self.uncommitted.unstaged.added.len() + self.uncommitted.unstaged.deleted.len()
+        self.tab.uncommitted.conflicts.len() + self.tab.uncommitted.unstaged.modified.len() + self.tab.uncommitted.unstaged.added.len() + self.tab.uncommitted.unstaged.deleted.len()
     }
 
     fn search_context_menu_items(&self) -> Vec<ContextMenuItem> {
         let mut items = vec![Self::command_item(menu::OPEN_COMMIT(), Command::Select)];
-        if self.repo.is_some() {
+        if self.tab.repo.is_some() {
             items.push(Self::command_item(menu::FIND_FILE(), Command::FindFile));
         }
         items
@@ -506,6 +571,7 @@ impl App {
             SettingsSelectionKind::Theme(_) | SettingsSelectionKind::SymbolTheme(_) => vec![Self::command_item(menu::APPLY_THEME(), Command::Select)],
             SettingsSelectionKind::KeyBinding(_) => vec![Self::command_item(menu::REBIND_SHORTCUT(), Command::Select)],
             SettingsSelectionKind::GraphLaneLimit => vec![Self::command_item(menu::run_command(settings::GRAPH_LANE_LIMIT().trim()), Command::Select)],
+            SettingsSelectionKind::ViewerFileSizeLimit => vec![Self::command_item(menu::run_command(settings::VIEWER_FILE_SIZE_LIMIT().trim()), Command::Select)],
             SettingsSelectionKind::LayoutCommand(command) => {
                 let label = menu::run_command(&command_to_visual_string(&command));
                 vec![Self::command_item(label, Command::Select)]
@@ -602,12 +668,13 @@ impl App {
         match action {
             ContextMenuAction::Command(command) => self.dispatch_command(&command),
             ContextMenuAction::GraphCommand(command) => {
-                self.viewport = Viewport::Graph;
-                self.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Graph;
+                self.tab.focus = Focus::Viewport;
                 self.dispatch_command(&command);
             },
             ContextMenuAction::OpenRecentRepository(index) => self.open_recent_repository_from_context_menu(index),
             ContextMenuAction::RemoteAction { name, index } => self.activate_remote_context_menu_action(name, index),
+            ContextMenuAction::BranchDrop { branch, index, action } => self.activate_branch_drop(branch, index, action),
             ContextMenuAction::SwitchSettingsTab(tab) => self.switch_settings_tab(tab),
             ContextMenuAction::Settings => self.open_settings_from_context_menu(),
             ContextMenuAction::Splash => self.open_splash_from_context_menu(),
@@ -620,41 +687,66 @@ impl App {
         self.modal_remote_selected = index as i32;
         self.modal_remote_target = Some(name);
         self.modal_input.clear();
-        self.focus = Focus::ModalRemoteAction;
+        self.tab.focus = Focus::ModalRemoteAction;
         self.confirm_remote_action();
     }
 
+    // Each drop action hands off to the modal its keyboard command opens.
+    fn activate_branch_drop(&mut self, branch: String, index: usize, action: BranchDropAction) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let Some(oid) = self.graph_oid_at(index) else {
+            return;
+        };
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
+        self.select_graph_index(index);
+
+        match action {
+            BranchDropAction::Rebase => self.open_operation_progress(OperationKind::Rebase, oid, operations::rebasing_selected_commit()),
+            BranchDropAction::Merge => {
+                if let Some(tip) = repo.resolve_reference_from_short_name(&branch).ok().and_then(|reference| reference.peel_to_commit().ok()) {
+                    self.open_operation_progress(OperationKind::Merge, tip.id(), operations::merging_branch(&branch));
+                }
+            },
+            BranchDropAction::Reset => self.show_branch_reset_modal(branch, oid),
+            BranchDropAction::CreateBranch => {
+                self.pending_branch_target_oid = Some(oid);
+                self.tab.focus = Focus::ModalCreateBranch;
+            },
+        }
+    }
+
     fn open_recent_repository_from_context_menu(&mut self, index: usize) {
         let Some(path) = self.recent.get(index).cloned() else {
             return;
         };
-        self.submodule_stack.clear();
+        self.tab.submodule_stack.clear();
         self.reload(Some(path));
-        self.graph_selected = 0;
-        self.viewport = Viewport::Graph;
-        self.focus = Focus::Viewport;
+        self.tab.graph_selected = 0;
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
         self.last_input_direction = None;
     }
 
     fn open_settings_from_context_menu(&mut self) {
-        if self.viewport != Viewport::Settings {
+        if self.tab.viewport != Viewport::Settings {
             self.settings_tab = SettingsTab::General;
             self.settings_selected = 0;
             self.settings_scroll.set(0);
         }
-        self.viewport = Viewport::Settings;
-        self.focus = Focus::Viewport;
+        self.tab.viewport = Viewport::Settings;
+        self.tab.focus = Focus::Viewport;
         self.last_input_direction = None;
     }
 
     fn open_splash_from_context_menu(&mut self) {
-        self.viewer_selected = 0;
-        self.file_name = None;
-        self.viewport = Viewport::Splash;
-        self.focus = Focus::Viewport;
+        self.tab.viewer_selected = 0;
+        self.tab.file_name = None;
+        self.tab.viewport = Viewport::Splash;
+        self.tab.focus = Focus::Viewport;
         self.last_input_direction = None;
 
-        let selected = self.path.as_ref().and_then(|path| self.recent.iter().position(|recent| recent == path)).unwrap_or(0);
+        let selected = self.tab.path.as_ref().and_then(|path| self.recent.iter().position(|recent| recent == path)).unwrap_or(0);
         self.splash_selected = selected.min(self.recent.len().saturating_sub(1));
     }
 }
@@ -0,0 +1,217 @@
+use crate::{
+    app::app::{App, CustomCommandTask, Focus, Viewport},
+    git::{
+        actions::journal::{RefState, capture_ref_state},
+        queries::diffs::get_filenames_diff_at_workdir,
+    },
+    helpers::{
+        custom_commands::{CustomCommand, CustomCommandContext, CustomCommandOutput, CustomCommandRun, CustomCommandValues, expand_template, load_custom_commands},
+        localisation::{errors, modal},
+    },
+};
+use crossterm::{
+    event::{DisableMouseCapture, EnableMouseCapture, KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use git2::Repository;
+use ratatui::DefaultTerminal;
+use std::{
+    io::{self, Write, stdout},
+    path::PathBuf,
+    sync::Arc,
+    thread,
+    time::Instant,
+};
+
+impl App {
+    pub fn load_custom_commands(&mut self) {
+        self.custom_commands = load_custom_commands();
+    }
+
+    pub(crate) fn custom_command(&self, name: &str) -> Option<&CustomCommand> {
+        self.custom_commands.iter().find(|command| command.name == name)
+    }
+
+    pub(crate) fn custom_command_in_context(&self, command: &CustomCommand, focus: Focus) -> bool {
+        match command.context {
+            CustomCommandContext::Any => self.tab.viewport != Viewport::Splash,
+            CustomCommandContext::Graph => self.tab.viewport == Viewport::Graph && focus == Focus::Viewport,
+            CustomCommandContext::Branches => focus == Focus::Branches,
+            CustomCommandContext::Status => matches!(focus, Focus::StatusTop | Focus::StatusBottom),
+        }
+    }
+
+    pub fn on_custom_command(&mut self, name: &str) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let Some(command) = self.custom_command(name).cloned() else {
+            self.show_error(errors::with_error(errors::CUSTOM_COMMAND_UNKNOWN(), name));
+            return;
+        };
+        if !self.custom_command_in_context(&command, self.tab.focus) {
+            return;
+        }
+
+        let script = match expand_template(&command.command, &self.custom_command_values(&repo)) {
+            Ok(script) => script,
+            Err(placeholder) => {
+                self.show_error(errors::with_error(errors::CUSTOM_COMMAND_NO_VALUE(), placeholder));
+                return;
+            },
+        };
+        let cwd = repo.workdir().map(PathBuf::from).unwrap_or_else(|| repo.path().to_path_buf());
+        let run = CustomCommandRun { name: command.name.clone(), script, cwd };
+
+        match command.output {
+            CustomCommandOutput::Suspend => self.pending_suspended_command = Some(run),
+            CustomCommandOutput::Modal => {
+                self.custom_command_task = Some(self.spawn_custom_command(run, &repo));
+                self.tab.focus = Focus::ModalCustomCommand;
+            },
+            CustomCommandOutput::Background => {
+                let task = self.spawn_custom_command(run, &repo);
+                self.background_custom_commands.push(task);
+            },
+        }
+    }
+
+    fn spawn_custom_command(&self, run: CustomCommandRun, repo: &Repository) -> CustomCommandTask {
+        let log = Arc::default();
+        let task_log = Arc::clone(&log);
+        let name = run.name.clone();
+        let refs_before = capture_ref_state(repo).ok();
+        let handle = thread::spawn(move || run.run(&task_log));
+        CustomCommandTask { name, log, handle: Some(handle), outcome: None, repo_path: self.tab.path.clone(), refs_before }
+    }
+
+    // Placeholder values follow the focused pane: the selected branch, commit or status file.
+    pub(crate) fn custom_command_values(&self, repo: &Repository) -> CustomCommandValues {
+        let head_branch = repo.head().ok().filter(|head| head.is_branch()).and_then(|head| head.shorthand().map(str::to_string));
+        let (sha, branch) = if self.tab.focus == Focus::Branches {
+            let sha = self.branch_alias_at_pane_selection().map(|alias| self.tab.oids.get_oid_by_alias(alias).to_string());
+            (sha, self.branch_name_at_pane_selection())
+        } else {
+            let sha = if self.tab.graph_selected != 0 { self.graph_oid_at(self.tab.graph_selected).map(|oid| oid.to_string()) } else { None };
+            (sha, head_branch)
+        };
+        let repo_path = repo.workdir().unwrap_or_else(|| repo.path()).display().to_string();
+
+        CustomCommandValues {
+            remote_url: Self::remote_url_for_branch(repo, branch.as_deref()),
+            sha,
+            branch,
+            path: self.get_selected_file_name(),
+            repo: Some(repo_path.trim_end_matches(['/', '\\']).to_string()),
+        }
+    }
+
+    // The branch's upstream remote when it has one, otherwise origin or the only remote.
+    fn remote_url_for_branch(repo: &Repository, branch: Option<&str>) -> Option<String> {
+        let upstream_remote = branch.and_then(|branch| {
+            let local = repo.find_branch(branch, git2::BranchType::Local).ok()?;
+            let upstream = local.upstream().ok()?;
+            let name = upstream.get().name()?.to_string();
+            repo.branch_remote_name(&name).ok()?.as_str().map(str::to_string)
+        });
+        let remotes = repo.remotes().ok()?;
+        let remote_name = upstream_remote.or_else(|| remotes.iter().flatten().find(|name| *name == "origin").or_else(|| remotes.iter().flatten().next()).map(str::to_string))?;
+        repo.find_remote(&remote_name).ok()?.url().map(str::to_string)
+    }
+
+    pub fn poll_custom_commands(&mut self) {
+        let mut finished_refs = Vec::new();
+        if let Some(task) = self.custom_command_task.as_mut()
+            && task.handle.as_ref().is_some_and(|handle| handle.is_finished())
+        {
+            task.outcome = task.handle.take().map(Self::join_custom_command);
+            finished_refs.push((task.repo_path.clone(), task.refs_before.clone()));
+        }
+
+        let (finished, running): (Vec<_>, Vec<_>) = self.background_custom_commands.drain(..).partition(|task| task.handle.as_ref().is_some_and(|handle| handle.is_finished()));
+        self.background_custom_commands = running;
+        for mut task in finished {
+            // Background commands report failures in the status bar so they never take focus mid-task.
+            if let Some(Err(error)) = task.handle.take().map(Self::join_custom_command) {
+                self.status_notice = Some((Self::custom_command_notice(&task, error), Instant::now()));
+            }
+            finished_refs.push((task.repo_path.take(), task.refs_before.take()));
+        }
+
+        self.refresh_after_custom_commands(finished_refs);
+    }
+
+    // Commands can change the repository behind our back. Moved refs refresh the graph in place;
+    // otherwise only the working tree may have changed, so just the uncommitted files are re-read.
+    fn refresh_after_custom_commands(&mut self, finished: Vec<(Option<String>, Option<RefState>)>) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let finished: Vec<Option<RefState>> = finished.into_iter().filter(|(path, _)| path.is_some() && *path == self.tab.path).map(|(_, refs)| refs).collect();
+        if finished.is_empty() {
+            return;
+        }
+
+        let refs_now = capture_ref_state(&repo).ok();
+        if finished.iter().any(|refs_before| refs_before.is_none() || *refs_before != refs_now) {
+            self.reload(None);
+        } else if let Ok(uncommitted) = get_filenames_diff_at_workdir(&repo) {
+            self.tab.uncommitted = uncommitted;
+        }
+    }
+
+    fn join_custom_command(handle: thread::JoinHandle<Result<(), git2::Error>>) -> Result<(), String> {
+        match handle.join() {
+            Ok(result) => result.map_err(|error| error.message().to_string()),
+            Err(_) => Err(errors::CUSTOM_COMMAND_PANICKED().to_string()),
+        }
+    }
+
+    // One line fits in the status bar, and a failed command usually explains itself in its last one.
+    fn custom_command_notice(task: &CustomCommandTask, error: String) -> String {
+        let last_line = task.log.lock().ok().and_then(|lines| lines.iter().rev().find(|line| !line.trim().is_empty()).map(|line| line.trim().to_string()));
+        let detail = match last_line {
+            Some(line) => format!("{}: {error} ({line})", task.name),
+            None => format!("{}: {error}", task.name),
+        };
+        errors::with_error(errors::CUSTOM_COMMAND(), detail)
+    }
+
+    // Closing while the command still runs leaves it running in the background.
+    pub(crate) fn close_custom_command_modal(&mut self) {
+        if let Some(task) = self.custom_command_task.take()
+            && task.outcome.is_none()
+        {
+            self.background_custom_commands.push(task);
+        }
+        self.tab.focus = Focus::Viewport;
+    }
+
+    // Hands the terminal to the command, then waits for Enter so its output can be read.
+    pub fn run_suspended_command(&mut self, terminal: &mut DefaultTerminal, run: CustomCommandRun, has_keyboard_enhancement: bool) -> io::Result<()> {
+        if has_keyboard_enhancement {
+            execute!(stdout(), PopKeyboardEnhancementFlags)?;
+        }
+        execute!(stdout(), DisableMouseCapture, LeaveAlternateScreen)?;
+        disable_raw_mode()?;
+
+        let result = run.run_attached();
+        print!("\n{}", modal::PRESS_ENTER_TO_RETURN());
+        stdout().flush()?;
+        let _ = io::stdin().read_line(&mut String::new());
+
+        enable_raw_mode()?;
+        execute!(stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        if has_keyboard_enhancement {
+            execute!(stdout(), PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES))?;
+        }
+        terminal.clear()?;
+
+        if let Err(error) = result {
+            self.show_error(errors::with_error(errors::CUSTOM_COMMAND(), error.message()));
+        }
+        self.reload(None);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/custom_commands.rs"]
+mod tests;
@@ -1,5 +1,6 @@
 use crate::{
-    app::app::{App, Direction, Focus, LayoutDrag, MouseDrag, MouseSelectionTarget, ScrollbarDrag, ScrollbarTarget, SettingsSelectionKind, SharedMouseDrag, Viewport},
+    app::app::{App, BranchDrag, Direction, Focus, LayoutDrag, MouseDrag, MouseSelectionTarget, ScrollbarDrag, ScrollbarTarget, SettingsSelectionKind, SharedMouseDrag, Viewport},
+    core::{graph_service::GraphPaneRow, renderers::branch_label_at},
     helpers::{
         layout::{LAYOUT_HEIGHT_MIN_STACKED_PANE, LAYOUT_WIDTH_MIN_CENTER, LAYOUT_WIDTH_MIN_SIDE_PANE, scrollbar_content_length},
         text::{empty_state_top_padding, sanitize, wrap_words},
@@ -101,7 +102,7 @@ impl App {
             },
             MouseEventKind::Up(MouseButton::Left) => {
                 if self.context_menu.is_none() {
-                    self.finish_mouse_drag();
+                    self.finish_mouse_drag(mouse_event.column, mouse_event.row);
                 }
             },
             MouseEventKind::ScrollUp => {
@@ -177,6 +178,7 @@ impl App {
             return;
         };
 
+        let branch_drag = self.branch_drag_source_at(target, column);
         let now = Instant::now();
         let is_double_click = self.last_mouse_click.is_some_and(|(previous, at)| previous == target && now.duration_since(at) <= DOUBLE_CLICK_THRESHOLD);
         let is_single_click_activation = self.mouse_target_activates_on_single_click(target);
@@ -198,10 +200,59 @@ impl App {
         } else {
             self.last_mouse_click = Some((target, now));
         }
+
+        // The press still selects the row; the branch only moves once the cursor leaves it.
+        if let Some((name, is_local)) = branch_drag {
+            self.mouse_drag = Some(MouseDrag::Branch(BranchDrag { name, is_local, start_column: column, start_row: row, is_dragging: false, drop_index: None }));
+        }
+    }
+
+    // Branch labels in the graph refs column and rows of the branches pane can be dragged.
+    fn branch_drag_source_at(&self, target: MouseSelectionTarget, column: u16) -> Option<(String, bool)> {
+        match target {
+            MouseSelectionTarget::Branches(index) => {
+                if let Some(window) = &self.tab.graph.branches_window
+                    && index >= window.start
+                    && index < window.end
+                {
+                    return match window.rows.get(index - window.start) {
+                        Some(GraphPaneRow::Branch { name, is_local, .. }) => Some((name.clone(), *is_local)),
+                        _ => None,
+                    };
+                }
+                let (_, name) = self.tab.branches.sorted.get(index)?;
+                Some((name.clone(), self.is_local_branch_name(name)))
+            },
+            MouseSelectionTarget::Graph(index) if index != 0 && self.layout_config.is_graph_refs => {
+                let offset = column.checked_sub(self.graph_message_x)? as usize;
+                let label = branch_label_at(&self.symbols, self.graph_row_at(index)?, offset)?;
+                Some((label.name.clone(), label.is_local))
+            },
+            _ => None,
+        }
+    }
+
+    fn update_branch_drag(&mut self, column: u16, row: u16) {
+        let drop_index = match self.graph_mouse_target_at(column, row) {
+            Some(MouseSelectionTarget::Graph(index)) if self.tab.viewport == Viewport::Graph && index != 0 => Some(index),
+            _ => None,
+        };
+        if let Some(MouseDrag::Branch(drag)) = &mut self.mouse_drag {
+            drag.is_dragging |= column != drag.start_column || row != drag.start_row;
+            drag.drop_index = drop_index;
+        }
+    }
+
+    // The graph row a dragged branch would land on, highlighted while the drag is live.
+    pub(crate) fn branch_drop_index(&self) -> Option<usize> {
+        match &self.mouse_drag {
+            Some(MouseDrag::Branch(drag)) if drag.is_dragging => drag.drop_index,
+            _ => None,
+        }
     }
 
     fn handle_mouse_drag(&mut self, column: u16, row: u16) {
-        match self.mouse_drag {
+        match self.mouse_drag.clone() {
             Some(MouseDrag::Layout(drag)) => {
                 self.apply_layout_drag(drag, column, row);
             },
@@ -223,11 +274,14 @@ impl App {
                     self.apply_scrollbar_drag(drag.scrollbar, row);
                 }
             },
+            Some(MouseDrag::Branch(_)) => {
+                self.update_branch_drag(column, row);
+            },
             None => {},
         }
     }
 
-    fn finish_mouse_drag(&mut self) {
+    fn finish_mouse_drag(&mut self, column: u16, row: u16) {
         let Some(drag) = self.mouse_drag.take() else {
             return;
         };
@@ -241,14 +295,17 @@ impl App {
             MouseDrag::Shared(shared) => {
                 self.apply_scrollbar_drag(shared.scrollbar, shared.start_row);
             },
+            MouseDrag::Branch(drag) => {
+                self.open_branch_drop_menu(drag, column, row);
+            },
         }
     }
 
     fn mouse_target_activates_on_single_click(&self, target: MouseSelectionTarget) -> bool {
         match target {
-            MouseSelectionTarget::Settings(index) => {
-                self.settings_selections.iter().any(|selection| selection.line == index && matches!(selection.kind, SettingsSelectionKind::LayoutCommand(_) | SettingsSelectionKind::GraphLaneLimit))
-            },
+            MouseSelectionTarget::Settings(index) => self.settings_selections.iter().any(|selection| {
+                selection.line == index && matches!(selection.kind, SettingsSelectionKind::LayoutCommand(_) | SettingsSelectionKind::GraphLaneLimit | SettingsSelectionKind::ViewerFileSizeLimit)
+            }),
             _ => false,
         }
     }
@@ -272,68 +329,68 @@ impl App {
     pub(crate) fn select_mouse_target(&mut self, target: MouseSelectionTarget) {
         match target {
             MouseSelectionTarget::Graph(index) => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Graph;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Graph;
                 self.select_graph_index(index);
             },
             MouseSelectionTarget::Viewer(index) => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Viewer;
-                self.viewer_selected = index;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Viewer;
+                self.tab.viewer_selected = index;
             },
             MouseSelectionTarget::Branches(index) => {
-                self.focus = Focus::Branches;
-                self.branches_selected = index;
+                self.tab.focus = Focus::Branches;
+                self.tab.branches_selected = index;
             },
             MouseSelectionTarget::Tags(index) => {
-                self.focus = Focus::Tags;
-                self.tags_selected = index;
+                self.tab.focus = Focus::Tags;
+                self.tab.tags_selected = index;
             },
             MouseSelectionTarget::Stashes(index) => {
-                self.focus = Focus::Stashes;
-                self.stashes_selected = index;
+                self.tab.focus = Focus::Stashes;
+                self.tab.stashes_selected = index;
             },
             MouseSelectionTarget::Reflogs(index) => {
-                self.focus = Focus::Reflogs;
-                self.reflogs_selected = index;
+                self.tab.focus = Focus::Reflogs;
+                self.tab.reflogs_selected = index;
             },
             MouseSelectionTarget::Worktrees(index) => {
-                self.focus = Focus::Worktrees;
-                self.worktrees_selected = index;
+                self.tab.focus = Focus::Worktrees;
+                self.tab.worktrees_selected = index;
             },
             MouseSelectionTarget::Submodules(index) => {
-                self.focus = Focus::Submodules;
-                self.submodules_selected = index;
+                self.tab.focus = Focus::Submodules;
+                self.tab.submodules_selected = index;
             },
             MouseSelectionTarget::Search(index) => {
-                self.focus = Focus::Search;
-                self.search_selected = index;
+                self.tab.focus = Focus::Search;
+                self.tab.search_selected = index;
             },
             MouseSelectionTarget::Inspector(index) => {
-                self.focus = Focus::Inspector;
-                self.inspector_selected = index;
+                self.tab.focus = Focus::Inspector;
+                self.tab.inspector_selected = index;
             },
             MouseSelectionTarget::StatusTop(index) => {
-                self.focus = Focus::StatusTop;
-                self.status_top_selected = index;
+                self.tab.focus = Focus::StatusTop;
+                self.tab.status_top_selected = index;
             },
             MouseSelectionTarget::StatusBottom(index) => {
-                self.focus = Focus::StatusBottom;
-                self.status_bottom_selected = index;
+                self.tab.focus = Focus::StatusBottom;
+                self.tab.status_bottom_selected = index;
             },
             MouseSelectionTarget::Splash(index) => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Splash;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Splash;
                 self.splash_selected = index;
             },
             MouseSelectionTarget::Settings(index) => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Settings;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Settings;
                 self.settings_selected = index;
             },
             MouseSelectionTarget::SettingsTab(tab) => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Settings;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Settings;
                 self.switch_settings_tab(tab);
             },
         }
@@ -344,7 +401,7 @@ impl App {
             return None;
         }
 
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Splash => return self.splash_mouse_target_at(column, row),
             Viewport::Settings => return self.settings_mouse_target_at(column, row),
             Viewport::Graph => {},
@@ -371,11 +428,11 @@ impl App {
     fn graph_mouse_target_at(&self, column: u16, row: u16) -> Option<MouseSelectionTarget> {
         let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(2) as usize } else { self.layout.graph.height as usize };
         let row_offset = self.row_offset_in_content(self.layout.graph, column, row, visible_height, self.layout_config.is_zen)?;
-        let index = self.graph_scroll.get().saturating_add(row_offset);
+        let index = self.tab.graph_scroll.get().saturating_add(row_offset);
         if index >= self.graph_commit_count() {
             return None;
         }
-        if self.graph_tx.is_some() && self.graph_row_at(index).is_none() {
+        if self.tab.graph_tx.is_some() && self.graph_row_at(index).is_none() {
             return None;
         }
         Some(MouseSelectionTarget::Graph(index))
@@ -384,14 +441,14 @@ impl App {
     fn viewer_mouse_target_at(&self, column: u16, row: u16) -> Option<MouseSelectionTarget> {
         let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(2) as usize } else { self.layout.graph.height as usize };
         let row_offset = self.row_offset_in_content(self.layout.graph, column, row, visible_height, self.layout_config.is_zen)?;
-        let index = self.viewer_scroll.get().saturating_add(row_offset);
+        let index = self.tab.viewer_scroll.get().saturating_add(row_offset);
         (index < self.viewer_row_count()).then_some(MouseSelectionTarget::Viewer(index))
     }
 
     fn left_pane_mouse_target_at(&self, column: u16, row: u16) -> Option<MouseSelectionTarget> {
         if self.layout_config.is_branches {
             let visible_height = self.layout.branches.height.saturating_sub(2) as usize;
-            if let Some(index) = self.scrolled_row_index(self.layout.branches, column, row, visible_height, self.layout_config.is_zen, self.branches_scroll.get(), self.branch_clickable_count()) {
+            if let Some(index) = self.scrolled_row_index(self.layout.branches, column, row, visible_height, self.layout_config.is_zen, self.tab.branches_scroll.get(), self.branch_clickable_count()) {
                 return self.graph_pane_clickable(index, GraphPaneClickKind::Branches).then_some(MouseSelectionTarget::Branches(index));
             }
         }
@@ -402,7 +459,7 @@ impl App {
             } else {
                 self.layout.tags.height.saturating_sub(if self.layout_config.is_branches { 1 } else { 2 }) as usize
             };
-            if let Some(index) = self.scrolled_row_index(self.layout.tags, column, row, visible_height, self.layout_config.is_zen, self.tags_scroll.get(), self.tag_clickable_count()) {
+            if let Some(index) = self.scrolled_row_index(self.layout.tags, column, row, visible_height, self.layout_config.is_zen, self.tab.tags_scroll.get(), self.tag_clickable_count()) {
                 return self.graph_pane_clickable(index, GraphPaneClickKind::Tags).then_some(MouseSelectionTarget::Tags(index));
             }
         }
@@ -413,7 +470,7 @@ impl App {
             } else {
                 self.layout.stashes.height.saturating_sub(if self.layout_config.is_branches || self.layout_config.is_tags { 1 } else { 2 }) as usize
             };
-            if let Some(index) = self.scrolled_row_index(self.layout.stashes, column, row, visible_height, self.layout_config.is_zen, self.stashes_scroll.get(), self.stash_clickable_count()) {
+            if let Some(index) = self.scrolled_row_index(self.layout.stashes, column, row, visible_height, self.layout_config.is_zen, self.tab.stashes_scroll.get(), self.stash_clickable_count()) {
                 return self.graph_pane_clickable(index, GraphPaneClickKind::Stashes).then_some(MouseSelectionTarget::Stashes(index));
             }
         }
@@ -422,7 +479,7 @@ impl App {
             let has_previous = self.layout_config.is_branches || self.layout_config.is_tags || self.layout_config.is_stashes;
             let visible_height =
                 if self.layout_config.is_zen { self.layout.reflogs.height.saturating_sub(2) as usize } else { self.layout.reflogs.height.saturating_sub(if has_previous { 1 } else { 2 }) as usize };
-            if let Some(index) = self.scrolled_row_index(self.layout.reflogs, column, row, visible_height, self.layout_config.is_zen, self.reflogs_scroll.get(), self.reflog_clickable_count()) {
+            if let Some(index) = self.scrolled_row_index(self.layout.reflogs, column, row, visible_height, self.layout_config.is_zen, self.tab.reflogs_scroll.get(), self.reflog_clickable_count()) {
                 return self.graph_pane_clickable(index, GraphPaneClickKind::Reflogs).then_some(MouseSelectionTarget::Reflogs(index));
             }
         }
@@ -434,7 +491,9 @@ impl App {
             } else {
                 self.layout.worktrees.height.saturating_sub(if has_previous { 1 } else { 2 }) as usize
             };
-            if let Some(index) = self.scrolled_row_index(self.layout.worktrees, column, row, visible_height, self.layout_config.is_zen, self.worktrees_scroll.get(), self.worktrees.entries.len()) {
+            if let Some(index) =
+                self.scrolled_row_index(self.layout.worktrees, column, row, visible_height, self.layout_config.is_zen, self.tab.worktrees_scroll.get(), self.tab.worktrees.entries.len())
+            {
                 return Some(MouseSelectionTarget::Worktrees(index));
             }
         }
@@ -446,7 +505,9 @@ impl App {
             } else {
                 self.layout.submodules.height.saturating_sub(if has_previous { 1 } else { 2 }) as usize
             };
-            if let Some(index) = self.scrolled_row_index(self.layout.submodules, column, row, visible_height, self.layout_config.is_zen, self.submodules_scroll.get(), self.submodules.entries.len()) {
+            if let Some(index) =
+                self.scrolled_row_index(self.layout.submodules, column, row, visible_height, self.layout_config.is_zen, self.tab.submodules_scroll.get(), self.tab.submodules.entries.len())
+            {
                 return Some(MouseSelectionTarget::Submodules(index));
             }
         }
@@ -460,7 +521,7 @@ impl App {
                 || self.layout_config.is_submodules;
             let visible_height =
                 if self.layout_config.is_zen { self.layout.search.height.saturating_sub(2) as usize } else { self.layout.search.height.saturating_sub(if has_previous { 1 } else { 2 }) as usize };
-            if let Some(index) = self.scrolled_row_index(self.layout.search, column, row, visible_height, self.layout_config.is_zen, self.search_scroll.get(), self.search_clickable_count()) {
+            if let Some(index) = self.scrolled_row_index(self.layout.search, column, row, visible_height, self.layout_config.is_zen, self.tab.search_scroll.get(), self.search_clickable_count()) {
                 return Some(MouseSelectionTarget::Search(index));
             }
         }
@@ -469,9 +530,9 @@ impl App {
     }
 
     fn right_pane_mouse_target_at(&self, column: u16, row: u16) -> Option<MouseSelectionTarget> {
-        if self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts) {
+        if self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts) {
             let visible_height = if self.layout_config.is_zen { self.layout.inspector.height.saturating_sub(2) as usize } else { self.layout.inspector.height.saturating_sub(1) as usize };
-            if let Some(index) = self.scrolled_row_index(self.layout.inspector, column, row, visible_height, self.layout_config.is_zen, self.inspector_scroll.get(), usize::MAX)
+            if let Some(index) = self.scrolled_row_index(self.layout.inspector, column, row, visible_height, self.layout_config.is_zen, self.tab.inspector_scroll.get(), usize::MAX)
                 && self.inspector_clickable()
             {
                 return Some(MouseSelectionTarget::Inspector(index));
@@ -481,15 +542,15 @@ impl App {
         if self.layout_config.is_status {
             let top_count = self.status_top_clickable_count();
             let top_visible_height = self.layout.status_top.height.saturating_sub(2) as usize;
-            let top_has_border = self.layout_config.is_zen || (self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts));
-            if let Some(index) = self.scrolled_row_index(self.layout.status_top, column, row, top_visible_height, top_has_border, self.status_top_scroll.get(), top_count) {
+            let top_has_border = self.layout_config.is_zen || (self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts));
+            if let Some(index) = self.scrolled_row_index(self.layout.status_top, column, row, top_visible_height, top_has_border, self.tab.status_top_scroll.get(), top_count) {
                 return Some(MouseSelectionTarget::StatusTop(index));
             }
 
-            if self.graph_selected == 0 {
+            if self.tab.graph_selected == 0 {
                 let bottom_count = self.status_bottom_clickable_count();
                 let bottom_visible_height = self.layout.status_bottom.height.saturating_sub(2) as usize;
-                if let Some(index) = self.scrolled_row_index(self.layout.status_bottom, column, row, bottom_visible_height, true, self.status_bottom_scroll.get(), bottom_count) {
+                if let Some(index) = self.scrolled_row_index(self.layout.status_bottom, column, row, bottom_visible_height, true, self.tab.status_bottom_scroll.get(), bottom_count) {
                     return Some(MouseSelectionTarget::StatusBottom(index));
                 }
             }
@@ -559,65 +620,65 @@ impl App {
     }
 
     fn branch_clickable_count(&self) -> usize {
-        self.graph.branches_window.as_ref().map(|window| window.total).unwrap_or_else(|| self.branches.sorted.len())
+        self.tab.graph.branches_window.as_ref().map(|window| window.total).unwrap_or_else(|| self.tab.branches.sorted.len())
     }
 
     fn tag_clickable_count(&self) -> usize {
-        self.graph.tags_window.as_ref().map(|window| window.total).unwrap_or_else(|| self.tags.sorted.len())
+        self.tab.graph.tags_window.as_ref().map(|window| window.total).unwrap_or_else(|| self.tab.tags.sorted.len())
     }
 
     fn stash_clickable_count(&self) -> usize {
-        self.graph.stashes_window.as_ref().map(|window| window.total).unwrap_or(self.oids.stashes.len())
+        self.tab.graph.stashes_window.as_ref().map(|window| window.total).unwrap_or(self.tab.oids.stashes.len())
     }
 
     fn reflog_clickable_count(&self) -> usize {
-        self.graph.reflogs_window.as_ref().map(|window| window.total).unwrap_or(self.reflogs.entries.len())
+        self.tab.graph.reflogs_window.as_ref().map(|window| window.total).unwrap_or(self.tab.reflogs.entries.len())
     }
 
     fn search_clickable_count(&self) -> usize {
-        self.search_rows.len()
+        self.tab.search_rows.len()
     }
 
     fn graph_pane_clickable(&self, index: usize, kind: GraphPaneClickKind) -> bool {
-        if self.graph_tx.is_none() {
+        if self.tab.graph_tx.is_none() {
             return true;
         }
 
         let window = match kind {
-            GraphPaneClickKind::Branches => self.graph.branches_window.as_ref(),
-            GraphPaneClickKind::Tags => self.graph.tags_window.as_ref(),
-            GraphPaneClickKind::Stashes => self.graph.stashes_window.as_ref(),
-            GraphPaneClickKind::Reflogs => self.graph.reflogs_window.as_ref(),
+            GraphPaneClickKind::Branches => self.tab.graph.branches_window.as_ref(),
+            GraphPaneClickKind::Tags => self.tab.graph.tags_window.as_ref(),
+            GraphPaneClickKind::Stashes => self.tab.graph.stashes_window.as_ref(),
+            GraphPaneClickKind::Reflogs => self.tab.graph.reflogs_window.as_ref(),
         };
 
         window.is_some_and(|window| index >= window.start && index < window.end && index - window.start < window.rows.len())
     }
 
     fn inspector_clickable(&self) -> bool {
-        if self.graph_selected == 0 {
-            return self.uncommitted.has_conflicts;
+        if self.tab.graph_selected == 0 {
+            return self.tab.uncommitted.has_conflicts;
         }
-        self.graph_identity_at(self.graph_selected).is_some()
+        self.graph_identity_at(self.tab.graph_selected).is_some()
     }
 
     fn status_top_clickable_count(&self) -> usize {
-        if self.graph_selected == 0 {
-            if !self.is_uncommitted_loaded || !self.uncommitted.is_staged {
+        if self.tab.graph_selected == 0 {
+            if !self.tab.is_uncommitted_loaded || !self.tab.uncommitted.is_staged {
                 return 0;
             }
-            self.uncommitted.conflicts.len() + self.uncommitted.staged.modified.len() + self.uncommitted.staged.added.len() + self.uncommitted.staged.deleted.len()
+            self.tab.uncommitted.conflicts.len() + self.tab.uncommitted.staged.modified.len() + self.tab.uncommitted.staged.added.len() + self.tab.uncommitted.staged.deleted.len()
         } else if self.selected_commit_diff_is_loaded() {
-            self.current_diff.len()
+            self.tab.current_diff.len()
         } else {
             0
         }
     }
 
     fn status_bottom_clickable_count(&self) -> usize {
-        if self.graph_selected != 0 || !self.is_uncommitted_loaded || !self.uncommitted.is_unstaged {
+        if self.tab.graph_selected != 0 || !self.tab.is_uncommitted_loaded || !self.tab.uncommitted.is_unstaged {
             return 0;
         }
-        self.uncommitted.conflicts.len() + self.uncommitted.unstaged.modified.len() + self.uncommitted.unstaged.added.len() + self.uncommitted.unstaged.deleted.len()
+        self.tab.uncommitted.conflicts.len() + self.tab.uncommitted.unstaged.modified.len() + self.tab.uncommitted.unstaged.added.len() + self.tab.uncommitted.unstaged.deleted.len()
     }
 
     fn scrollbar_drag_at(&mut self, column: u16, row: u16) -> Option<ScrollbarDrag> {
@@ -638,7 +699,7 @@ impl App {
     }
 
     fn scrollbar_info_at(&mut self, column: u16, row: u16) -> Option<ScrollbarInfo> {
-        if self.is_modal_focus() || self.viewport == Viewport::Splash {
+        if self.is_modal_focus() || self.tab.viewport == Viewport::Splash {
             return None;
         }
 
@@ -680,16 +741,16 @@ impl App {
     }
 
     fn scrollbar_rect_for_target(&self, target: ScrollbarTarget) -> Option<Rect> {
-        if self.viewport == Viewport::Splash {
+        if self.tab.viewport == Viewport::Splash {
             return None;
         }
 
-        let is_settings = self.viewport == Viewport::Settings;
-        let is_main_view = matches!(self.viewport, Viewport::Graph | Viewport::Viewer);
+        let is_settings = self.tab.viewport == Viewport::Settings;
+        let is_main_view = matches!(self.tab.viewport, Viewport::Graph | Viewport::Viewer);
 
         let rect = match target {
-            ScrollbarTarget::Graph if self.viewport == Viewport::Graph => self.layout.graph_scrollbar,
-            ScrollbarTarget::Viewer if self.viewport == Viewport::Viewer => self.layout.graph_scrollbar,
+            ScrollbarTarget::Graph if self.tab.viewport == Viewport::Graph => self.layout.graph_scrollbar,
+            ScrollbarTarget::Viewer if self.tab.viewport == Viewport::Viewer => self.layout.graph_scrollbar,
             ScrollbarTarget::Settings if is_settings => self.layout.app,
             ScrollbarTarget::Branches if is_main_view && self.layout_config.is_branches => self.layout.branches_scrollbar,
             ScrollbarTarget::Tags if is_main_view && self.layout_config.is_tags => self.layout.tags_scrollbar,
@@ -698,9 +759,9 @@ impl App {
             ScrollbarTarget::Worktrees if is_main_view && self.layout_config.is_worktrees => self.layout.worktrees_scrollbar,
             ScrollbarTarget::Submodules if is_main_view && self.layout_config.is_submodules => self.layout.submodules_scrollbar,
             ScrollbarTarget::Search if is_main_view && self.layout_config.is_search => self.layout.search_scrollbar,
-            ScrollbarTarget::Inspector if is_main_view && self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts) => self.layout.inspector_scrollbar,
+            ScrollbarTarget::Inspector if is_main_view && self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts) => self.layout.inspector_scrollbar,
             ScrollbarTarget::StatusTop if is_main_view && self.layout_config.is_status => self.layout.status_top_scrollbar,
-            ScrollbarTarget::StatusBottom if is_main_view && self.layout_config.is_status && self.graph_selected == 0 => self.layout.status_bottom_scrollbar,
+            ScrollbarTarget::StatusBottom if is_main_view && self.layout_config.is_status && self.tab.graph_selected == 0 => self.layout.status_bottom_scrollbar,
             _ => return None,
         };
 
@@ -709,19 +770,19 @@ impl App {
 
     fn scrollbar_info_for_target(&mut self, target: ScrollbarTarget, rect: Rect) -> Option<ScrollbarInfo> {
         let (total_lines, visible_height, scroll) = match target {
-            ScrollbarTarget::Graph => (self.graph_commit_count(), self.viewport_visible_height(), self.graph_scroll.get()),
-            ScrollbarTarget::Viewer => (self.viewer_row_count(), self.viewport_visible_height(), self.viewer_scroll.get()),
+            ScrollbarTarget::Graph => (self.graph_commit_count(), self.viewport_visible_height(), self.tab.graph_scroll.get()),
+            ScrollbarTarget::Viewer => (self.viewer_row_count(), self.viewport_visible_height(), self.tab.viewer_scroll.get()),
             ScrollbarTarget::Settings => (self.settings_scroll_line_count(), self.settings_visible_height(), self.settings_scroll.get()),
-            ScrollbarTarget::Branches => (self.branch_clickable_count(), self.branches_visible_height(), self.branches_scroll.get()),
-            ScrollbarTarget::Tags => (self.tag_clickable_count(), self.tags_visible_height(), self.tags_scroll.get()),
-            ScrollbarTarget::Stashes => (self.stash_clickable_count(), self.stashes_visible_height(), self.stashes_scroll.get()),
-            ScrollbarTarget::Reflogs => (self.reflog_clickable_count(), self.reflogs_visible_height(), self.reflogs_scroll.get()),
-            ScrollbarTarget::Worktrees => (self.worktrees.entries.len(), self.worktrees_visible_height(), self.worktrees_scroll.get()),
-            ScrollbarTarget::Submodules => (self.submodules.entries.len(), self.submodules_visible_height(), self.submodules_scroll.get()),
-            ScrollbarTarget::Search => (self.search_clickable_count(), self.search_visible_height(), self.search_scroll.get()),
-            ScrollbarTarget::Inspector => (self.inspector_line_count_for_scrollbar(), self.inspector_visible_height(), self.inspector_scroll.get()),
-            ScrollbarTarget::StatusTop => (self.status_top_clickable_count(), self.status_top_visible_height(), self.status_top_scroll.get()),
-            ScrollbarTarget::StatusBottom => (self.status_bottom_clickable_count(), self.status_bottom_visible_height(), self.status_bottom_scroll.get()),
+            ScrollbarTarget::Branches => (self.branch_clickable_count(), self.branches_visible_height(), self.tab.branches_scroll.get()),
+            ScrollbarTarget::Tags => (self.tag_clickable_count(), self.tags_visible_height(), self.tab.tags_scroll.get()),
+            ScrollbarTarget::Stashes => (self.stash_clickable_count(), self.stashes_visible_height(), self.tab.stashes_scroll.get()),
+            ScrollbarTarget::Reflogs => (self.reflog_clickable_count(), self.reflogs_visible_height(), self.tab.reflogs_scroll.get()),
+            ScrollbarTarget::Worktrees => (self.tab.worktrees.entries.len(), self.worktrees_visible_height(), self.tab.worktrees_scroll.get()),
+            ScrollbarTarget::Submodules => (self.tab.submodules.entries.len(), self.submodules_visible_height(), self.tab.submodules_scroll.get()),
+            ScrollbarTarget::Search => (self.search_clickable_count(), self.search_visible_height(), self.tab.search_scroll.get()),
+            ScrollbarTarget::Inspector => (self.inspector_line_count_for_scrollbar(), self.inspector_visible_height(), self.tab.inspector_scroll.get()),
+            ScrollbarTarget::StatusTop => (self.status_top_clickable_count(), self.status_top_visible_height(), self.tab.status_top_scroll.get()),
+            ScrollbarTarget::StatusBottom => (self.status_bottom_clickable_count(), self.status_bottom_visible_height(), self.tab.status_bottom_scroll.get()),
         };
 
         if visible_height == 0 {
@@ -801,46 +862,46 @@ impl App {
     fn focus_scrollbar_target(&mut self, target: ScrollbarTarget) {
         match target {
             ScrollbarTarget::Graph => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Graph;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Graph;
             },
             ScrollbarTarget::Viewer => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Viewer;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Viewer;
             },
             ScrollbarTarget::Settings => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Settings;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Settings;
                 self.last_input_direction = None;
             },
-            ScrollbarTarget::Branches => self.focus = Focus::Branches,
-            ScrollbarTarget::Tags => self.focus = Focus::Tags,
-            ScrollbarTarget::Stashes => self.focus = Focus::Stashes,
-            ScrollbarTarget::Reflogs => self.focus = Focus::Reflogs,
-            ScrollbarTarget::Worktrees => self.focus = Focus::Worktrees,
-            ScrollbarTarget::Submodules => self.focus = Focus::Submodules,
-            ScrollbarTarget::Search => self.focus = Focus::Search,
-            ScrollbarTarget::Inspector => self.focus = Focus::Inspector,
-            ScrollbarTarget::StatusTop => self.focus = Focus::StatusTop,
-            ScrollbarTarget::StatusBottom => self.focus = Focus::StatusBottom,
+            ScrollbarTarget::Branches => self.tab.focus = Focus::Branches,
+            ScrollbarTarget::Tags => self.tab.focus = Focus::Tags,
+            ScrollbarTarget::Stashes => self.tab.focus = Focus::Stashes,
+            ScrollbarTarget::Reflogs => self.tab.focus = Focus::Reflogs,
+            ScrollbarTarget::Worktrees => self.tab.focus = Focus::Worktrees,
+            ScrollbarTarget::Submodules => self.tab.focus = Focus::Submodules,
+            ScrollbarTarget::Search => self.tab.focus = Focus::Search,
+            ScrollbarTarget::Inspector => self.tab.focus = Focus::Inspector,
+            ScrollbarTarget::StatusTop => self.tab.focus = Focus::StatusTop,
+            ScrollbarTarget::StatusBottom => self.tab.focus = Focus::StatusBottom,
         }
     }
 
     fn set_target_scroll(&self, target: ScrollbarTarget, scroll: usize) {
         match target {
-            ScrollbarTarget::Graph => self.graph_scroll.set(scroll),
-            ScrollbarTarget::Viewer => self.viewer_scroll.set(scroll),
+            ScrollbarTarget::Graph => self.tab.graph_scroll.set(scroll),
+            ScrollbarTarget::Viewer => self.tab.viewer_scroll.set(scroll),
             ScrollbarTarget::Settings => self.settings_scroll.set(scroll),
-            ScrollbarTarget::Branches => self.branches_scroll.set(scroll),
-            ScrollbarTarget::Tags => self.tags_scroll.set(scroll),
-            ScrollbarTarget::Stashes => self.stashes_scroll.set(scroll),
-            ScrollbarTarget::Reflogs => self.reflogs_scroll.set(scroll),
-            ScrollbarTarget::Worktrees => self.worktrees_scroll.set(scroll),
-            ScrollbarTarget::Submodules => self.submodules_scroll.set(scroll),
-            ScrollbarTarget::Search => self.search_scroll.set(scroll),
-            ScrollbarTarget::Inspector => self.inspector_scroll.set(scroll),
-            ScrollbarTarget::StatusTop => self.status_top_scroll.set(scroll),
-            ScrollbarTarget::StatusBottom => self.status_bottom_scroll.set(scroll),
+            ScrollbarTarget::Branches => self.tab.branches_scroll.set(scroll),
+            ScrollbarTarget::Tags => self.tab.tags_scroll.set(scroll),
+            ScrollbarTarget::Stashes => self.tab.stashes_scroll.set(scroll),
+            ScrollbarTarget::Reflogs => self.tab.reflogs_scroll.set(scroll),
+            ScrollbarTarget::Worktrees => self.tab.worktrees_scroll.set(scroll),
+            ScrollbarTarget::Submodules => self.tab.submodules_scroll.set(scroll),
+            ScrollbarTarget::Search => self.tab.search_scroll.set(scroll),
+            ScrollbarTarget::Inspector => self.tab.inspector_scroll.set(scroll),
+            ScrollbarTarget::StatusTop => self.tab.status_top_scroll.set(scroll),
+            ScrollbarTarget::StatusBottom => self.tab.status_bottom_scroll.set(scroll),
         }
     }
 
@@ -887,37 +948,37 @@ impl App {
 
     fn target_selection(&self, target: ScrollbarTarget) -> usize {
         match target {
-            ScrollbarTarget::Graph => self.graph_selected,
-            ScrollbarTarget::Viewer => self.viewer_selected,
+            ScrollbarTarget::Graph => self.tab.graph_selected,
+            ScrollbarTarget::Viewer => self.tab.viewer_selected,
             ScrollbarTarget::Settings => self.settings_selected,
-            ScrollbarTarget::Branches => self.branches_selected,
-            ScrollbarTarget::Tags => self.tags_selected,
-            ScrollbarTarget::Stashes => self.stashes_selected,
-            ScrollbarTarget::Reflogs => self.reflogs_selected,
-            ScrollbarTarget::Worktrees => self.worktrees_selected,
-            ScrollbarTarget::Submodules => self.submodules_selected,
-            ScrollbarTarget::Search => self.search_selected,
-            ScrollbarTarget::Inspector => self.inspector_selected,
-            ScrollbarTarget::StatusTop => self.status_top_selected,
-            ScrollbarTarget::StatusBottom => self.status_bottom_selected,
+            ScrollbarTarget::Branches => self.tab.branches_selected,
+            ScrollbarTarget::Tags => self.tab.tags_selected,
+            ScrollbarTarget::Stashes => self.tab.stashes_selected,
+            ScrollbarTarget::Reflogs => self.tab.reflogs_selected,
+            ScrollbarTarget::Worktrees => self.tab.worktrees_selected,
+            ScrollbarTarget::Submodules => self.tab.submodules_selected,
+            ScrollbarTarget::Search => self.tab.search_selected,
+            ScrollbarTarget::Inspector => self.tab.inspector_selected,
+            ScrollbarTarget::StatusTop => self.tab.status_top_selected,
+            ScrollbarTarget::StatusBottom => self.tab.status_bottom_selected,
         }
     }
 
     fn set_target_selection(&mut self, target: ScrollbarTarget, selection: usize) {
         match target {
             ScrollbarTarget::Graph => self.select_graph_index(selection),
-            ScrollbarTarget::Viewer => self.viewer_selected = selection,
+            ScrollbarTarget::Viewer => self.tab.viewer_selected = selection,
             ScrollbarTarget::Settings => self.settings_selected = selection,
-            ScrollbarTarget::Branches => self.branches_selected = selection,
-            ScrollbarTarget::Tags => self.tags_selected = selection,
-            ScrollbarTarget::Stashes => self.stashes_selected = selection,
-            ScrollbarTarget::Reflogs => self.reflogs_selected = selection,
-            ScrollbarTarget::Worktrees => self.worktrees_selected = selection,
-            ScrollbarTarget::Submodules => self.submodules_selected = selection,
-            ScrollbarTarget::Search => self.search_selected = selection,
-            ScrollbarTarget::Inspector => self.inspector_selected = selection,
-            ScrollbarTarget::StatusTop => self.status_top_selected = selection,
-            ScrollbarTarget::StatusBottom => self.status_bottom_selected = selection,
+            ScrollbarTarget::Branches => self.tab.branches_selected = selection,
+            ScrollbarTarget::Tags => self.tab.tags_selected = selection,
+            ScrollbarTarget::Stashes => self.tab.stashes_selected = selection,
+            ScrollbarTarget::Reflogs => self.tab.reflogs_selected = selection,
+            ScrollbarTarget::Worktrees => self.tab.worktrees_selected = selection,
+            ScrollbarTarget::Submodules => self.tab.submodules_selected = selection,
+            ScrollbarTarget::Search => self.tab.search_selected = selection,
+            ScrollbarTarget::Inspector => self.tab.inspector_selected = selection,
+            ScrollbarTarget::StatusTop => self.tab.status_top_selected = selection,
+            ScrollbarTarget::StatusBottom => self.tab.status_bottom_selected = selection,
         }
     }
 
@@ -983,7 +1044,7 @@ impl App {
     }
 
     fn settings_scroll_line_count(&mut self) -> usize {
-        if let Some(repo) = self.repo.clone() {
+        if let Some(repo) = self.tab.repo.clone() {
             self.settings_lines(&repo).len()
         } else {
             self.settings_selections.iter().map(|selection| selection.line).max().map(|line| line.saturating_add(1)).unwrap_or(0)
@@ -991,40 +1052,40 @@ impl App {
     }
 
     fn inspector_line_count_for_scrollbar(&self) -> usize {
-        if self.graph_selected == 0 {
-            return if self.uncommitted.has_conflicts { 8 } else { 0 };
+        if self.tab.graph_selected == 0 {
+            return if self.tab.uncommitted.has_conflicts { 8 } else { 0 };
         }
 
         let visible_height = self.inspector_visible_height();
         let max_text_width = self.layout.inspector.width.saturating_sub(1) as usize;
         let max_text_width = max_text_width.saturating_sub(2);
-        let Some(repo) = self.repo.as_ref() else {
-            return self.inspector_scroll.get().saturating_add(visible_height).saturating_add(1);
+        let Some(repo) = self.tab.repo.as_ref() else {
+            return self.tab.inspector_scroll.get().saturating_add(visible_height).saturating_add(1);
         };
-        let Some(identity) = self.graph_identity_at(self.graph_selected) else {
+        let Some(identity) = self.graph_identity_at(self.tab.graph_selected) else {
             return empty_state_top_padding(visible_height).saturating_add(1);
         };
-        let Ok(commit) = repo.find_commit(identity.oid) else {
+        let Ok(commit) = repo.find_commit(self.commit_oid(identity.oid)) else {
             return empty_state_top_padding(visible_height).saturating_add(1);
         };
 
         let mut count = 4usize.saturating_add(commit.parent_count());
-        if let Some(row) = self.graph_row_at(self.graph_selected)
+        if let Some(row) = self.graph_row_at(self.tab.graph_selected)
             && !row.branches.is_empty()
         {
             count = count.saturating_add(2).saturating_add(row.branches.len());
-        } else if let Some(branches) = self.branches.all.get(&identity.alias)
-            && self.branches.colors.contains_key(&identity.alias)
+        } else if let Some(branches) = self.tab.branches.all.get(&identity.alias)
+            && self.tab.branches.colors.contains_key(&identity.alias)
         {
-            let visible_branches = branches.iter().filter(|branch| !self.branches.hidden_branch_names.contains(*branch)).count();
+            let visible_branches = branches.iter().filter(|branch| !self.tab.branches.hidden_branch_names.contains(*branch)).count();
             count = count.saturating_add(2).saturating_add(visible_branches);
         }
 
-        if let Some(row) = self.graph_row_at(self.graph_selected)
+        if let Some(row) = self.graph_row_at(self.tab.graph_selected)
             && let Some(entry) = &row.reflog
         {
             count = count.saturating_add(3).saturating_add(wrap_words(sanitize(entry.message.clone()), max_text_width).len());
-        } else if let Some(entry) = self.reflogs.latest_for_alias(identity.alias) {
+        } else if let Some(entry) = self.tab.reflogs.latest_for_alias(identity.alias) {
             count = count.saturating_add(4).saturating_add(wrap_words(sanitize(entry.message.clone()), max_text_width).len());
         }
 
@@ -1047,7 +1108,7 @@ impl App {
         }
 
         if let Some(focus) = self.scroll_focus_at(column, row) {
-            self.focus = focus;
+            self.tab.focus = focus;
             match direction {
                 Direction::Up => self.on_scroll_up(),
                 Direction::Down => self.on_scroll_down(),
@@ -1057,10 +1118,10 @@ impl App {
 
     fn scroll_focus_at(&self, column: u16, row: u16) -> Option<Focus> {
         if self.layout_config.is_zen {
-            return Self::rect_contains(self.layout.app, column, row).then_some(self.focus);
+            return Self::rect_contains(self.layout.app, column, row).then_some(self.tab.focus);
         }
 
-        if matches!(self.viewport, Viewport::Splash | Viewport::Settings) {
+        if matches!(self.tab.viewport, Viewport::Splash | Viewport::Settings) {
             return Self::rect_contains(self.layout.app, column, row).then_some(Focus::Viewport);
         }
 
@@ -1085,10 +1146,10 @@ impl App {
         if self.layout_config.is_search && Self::rect_contains(self.layout.pane_search, column, row) {
             return Some(Focus::Search);
         }
-        if self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts) && Self::rect_contains(self.layout.pane_inspector, column, row) {
+        if self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts) && Self::rect_contains(self.layout.pane_inspector, column, row) {
             return Some(Focus::Inspector);
         }
-        if self.layout_config.is_status && self.graph_selected == 0 && Self::rect_contains(self.layout.pane_status_bottom, column, row) {
+        if self.layout_config.is_status && self.tab.graph_selected == 0 && Self::rect_contains(self.layout.pane_status_bottom, column, row) {
             return Some(Focus::StatusBottom);
         }
         if self.layout_config.is_status && Self::rect_contains(self.layout.pane_status_top, column, row) {
@@ -1102,7 +1163,7 @@ impl App {
     }
 
     fn layout_drag_at(&self, column: u16, row: u16) -> Option<LayoutDrag> {
-        if matches!(self.viewport, Viewport::Splash | Viewport::Settings) || self.is_modal_focus() {
+        if matches!(self.tab.viewport, Viewport::Splash | Viewport::Settings) || self.is_modal_focus() {
             return None;
         }
 
@@ -1190,12 +1251,19 @@ impl App {
 
     pub(crate) fn is_modal_focus(&self) -> bool {
         matches!(
-            self.focus,
+            self.tab.focus,
             Focus::ModalCheckout
                 | Focus::ModalSolo
                 | Focus::ModalCommit
                 | Focus::ModalCherrypick
                 | Focus::ModalRevert
+                | Focus::ModalMainline
+                | Focus::ModalJournal
+                | Focus::ModalReset
+                | Focus::ModalExportGraph
+                | Focus::ModalExportPatches
+                | Focus::ModalApplyPatchPath
+                | Focus::ModalApplyPatch
                 | Focus::ModalCreateBranch
                 | Focus::ModalRenameBranch
                 | Focus::ModalCreateWorktreeName
@@ -1203,19 +1271,26 @@ impl App {
                 | Focus::ModalDeleteBranch
                 | Focus::ModalWorktreeChooser
                 | Focus::ModalRemoveWorktree
+                | Focus::ModalBatch
                 | Focus::ModalLockWorktree
                 | Focus::ModalRemoteAction
                 | Focus::ModalRemoteDelete
                 | Focus::ModalRemoteName
                 | Focus::ModalRemoteUrl
                 | Focus::ModalGraphLaneLimit
+                | Focus::ModalViewerFileSizeLimit
+                | Focus::ModalDeepen
                 | Focus::ModalGrep
                 | Focus::ModalFileSearch
+                | Focus::ModalContentSearch
+                | Focus::ModalCommandPalette
                 | Focus::ModalTag
                 | Focus::ModalDeleteTag
                 | Focus::ModalKeyCapture
                 | Focus::ModalAuth
                 | Focus::ModalNetworkProgress
+                | Focus::ModalHookProgress
+                | Focus::ModalCustomCommand
                 | Focus::ModalOperationProgress
                 | Focus::ModalOperationConflict
                 | Focus::ModalOperationSuccess
@@ -1244,7 +1319,7 @@ impl App {
     }
 
     fn resize_pane_keyboard(&mut self, direction: ResizeDirection) {
-        if matches!(self.viewport, Viewport::Splash | Viewport::Settings) || self.is_modal_focus() {
+        if matches!(self.tab.viewport, Viewport::Splash | Viewport::Settings) || self.is_modal_focus() {
             return;
         }
 
@@ -1253,7 +1328,7 @@ impl App {
                 if self.layout_config.is_zen {
                     false
                 } else {
-                    match self.focus {
+                    match self.tab.focus {
                         Focus::Branches | Focus::Tags | Focus::Stashes | Focus::Reflogs | Focus::Worktrees | Focus::Submodules | Focus::Search | Focus::Viewport => self.resize_left_column_by(-1),
                         Focus::Inspector | Focus::StatusTop | Focus::StatusBottom => self.resize_right_column_by(1),
                         _ => false,
@@ -1264,7 +1339,7 @@ impl App {
                 if self.layout_config.is_zen {
                     false
                 } else {
-                    match self.focus {
+                    match self.tab.focus {
                         Focus::Branches | Focus::Tags | Focus::Stashes | Focus::Reflogs | Focus::Worktrees | Focus::Submodules | Focus::Search => self.resize_left_column_by(1),
                         Focus::Viewport | Focus::Inspector | Focus::StatusTop | Focus::StatusBottom => self.resize_right_column_by(-1),
                         _ => false,
@@ -1358,9 +1433,9 @@ impl App {
     }
 
     fn resize_right_stack_pane(&mut self, focused: StackPane, direction: Direction) -> bool {
-        let has_inspector = self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts);
+        let has_inspector = self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts);
         let has_status = self.layout_config.is_status;
-        let has_status_bottom = has_status && self.graph_selected == 0;
+        let has_status_bottom = has_status && self.tab.graph_selected == 0;
 
         match (focused, direction) {
             (StackPane::Inspector, Direction::Up) if has_inspector && has_status => self.adjust_stack_pair(StackPane::Inspector, StackPane::Status, -1),
@@ -1413,7 +1488,7 @@ impl App {
     }
 
     fn focus_stack_pane(&self) -> Option<StackPane> {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => Some(StackPane::Branches),
             Focus::Tags => Some(StackPane::Tags),
             Focus::Stashes => Some(StackPane::Stashes),
@@ -0,0 +1,69 @@
+use crate::{
+    app::app::{App, Focus},
+    core::{
+        chunk::NONE,
+        export::{ExportFormat, ExportWindow, export_window},
+    },
+    helpers::localisation::errors,
+};
+use std::{fs, path::PathBuf};
+
+const EXPORT_GRAPH_FILE_NAME: &str = "guitar-graph.svg";
+
+impl App {
+    pub fn on_export_graph(&mut self) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let directory = repo.workdir().map(PathBuf::from).unwrap_or_else(|| PathBuf::from(self.tab.path.as_deref().unwrap_or(".")));
+        self.modal_input.set_value(directory.join(EXPORT_GRAPH_FILE_NAME).display().to_string());
+        self.tab.focus = Focus::ModalExportGraph;
+    }
+
+    pub(crate) fn confirm_export_graph(&mut self) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let path = PathBuf::from(self.modal_input.value().trim());
+        if path.as_os_str().is_empty() {
+            self.show_error(errors::EXPORT_GRAPH_EMPTY_PATH());
+            return;
+        }
+        let Some(window) = self.visible_export_window() else {
+            self.show_error(errors::EXPORT_GRAPH_NOT_LOADED());
+            return;
+        };
+
+        let contents = match export_window(&self.theme, &self.symbols, &repo, &window, ExportFormat::from_path(&path)) {
+            Ok(contents) => contents,
+            Err(error) => {
+                self.show_error(errors::with_error(errors::EXPORT_GRAPH(), error));
+                return;
+            },
+        };
+        match fs::write(&path, contents) {
+            Ok(()) => self.close_export_graph_modal(),
+            Err(error) => self.show_error(errors::with_error(errors::EXPORT_GRAPH(), error)),
+        }
+    }
+
+    pub(crate) fn close_export_graph_modal(&mut self) {
+        self.modal_input.clear();
+        self.tab.focus = Focus::Viewport;
+    }
+
+    // The rows on screen, taken from the cached window the graph was drawn from.
+    fn visible_export_window(&self) -> Option<ExportWindow> {
+        let window = self.tab.graph.graph_window.as_ref()?;
+        let total_lines = self.graph_commit_count();
+        let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(2) as usize } else { self.layout.graph.height as usize };
+        let start = self.tab.graph_scroll.get().min(total_lines.saturating_sub(visible_height));
+        let end = (start + visible_height).min(total_lines);
+
+        let rows: Vec<_> = window.rows.iter().filter(|row| row.alias != NONE && (start..end).contains(&row.index)).cloned().collect();
+        if rows.is_empty() {
+            return None;
+        }
+        Some(ExportWindow { rows, history: window.history.clone(), head_alias: window.head_alias, end: window.end })
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/export.rs"]
+mod tests;
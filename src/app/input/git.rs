@@ -1,36 +1,50 @@
 use crate::{
-    app::app::{App, AuthInputField, BranchModalAction, Focus, OperationKind, PendingOperationAction, Viewport},
-    core::graph_service::GraphPaneRow,
+    app::app::{App, AuthInputField, BatchAction, BranchModalAction, Focus, OperationKind, PendingOperationAction, Viewport},
+    core::{
+        commit_graph::CommitGraph,
+        graph_service::GraphPaneRow,
+        walk_cache::{walk_cache_dir, walk_cache_path},
+    },
     git::{
         actions::{
             branching::delete_branch,
             checkout::{checkout_branch, checkout_head},
-            cherrypicking::{CherrypickOutcome, abort_cherrypick, continue_cherrypick, is_cherrypick_in_progress},
-            merging::{MergeOutcome, abort_merge, continue_merge, is_merge_in_progress, start_merge},
+            cherrypicking::{CherrypickOutcome, abort_cherrypick, continue_cherrypick},
+            merging::{MergeOutcome, abort_merge, continue_merge},
             network::NetworkRequest,
-            rebasing::{RebaseOutcome, abort_rebase, continue_rebase, is_rebase_in_progress, start_rebase},
-            resetting::{reset_file, reset_to_commit},
-            reverting::{RevertOutcome, abort_revert, continue_revert, is_revert_in_progress},
+            rebasing::{RebaseOutcome, abort_rebase, continue_rebase},
+            resetting::{ResetPreview, reset_branch, reset_branch_preview, reset_file, reset_preview, reset_to_commit},
+            reverting::{RevertOutcome, abort_revert, continue_revert},
+            sequencing::{SequenceKind, SequenceOutcome, SequenceStep, abort_sequence, continue_sequence, is_sequence_in_progress, order_sequence, range_oids, sequence_kind, start_sequence},
             staging::{stage_all, stage_file, unstage_all, unstage_file},
             stashing::{pop, stash},
             submodules::{stage_submodule_head, unstage_submodule},
             tagging::untag,
         },
         auth::{AuthRequired, AuthSecret, NetworkResult},
-        queries::{commits::get_current_branch, remotes::effective_default_remote},
+        os::path::open_repo,
+        queries::{
+            commits::{get_current_branch, is_descendant_of},
+            diffs::get_filenames_diff_against_parent,
+            remotes::effective_default_remote,
+        },
     },
     helpers::{
         branch_visibility::save_branch_visibility,
+        keymap::Command,
         localisation::{errors, network, operations},
     },
 };
-use git2::{BranchType, Repository, RepositoryState};
-use std::path::Path;
+use git2::{BranchType, Oid, Repository, RepositoryState, ResetType};
+use std::{fs, path::Path, rc::Rc};
+
+// Commits the deepen prompt offers to fetch below the shallow boundary.
+const DEEPEN_DEFAULT: usize = 50;
 
 impl App {
     const MAX_AUTH_ATTEMPTS: usize = 3;
 
-    fn submodule_name_for_status_path(repo: &Repository, path: &str) -> Option<String> {
+    pub(crate) fn submodule_name_for_status_path(repo: &Repository, path: &str) -> Option<String> {
         let target = Path::new(path);
         repo.submodules().ok()?.into_iter().find(|submodule| submodule.path() == target).map(|submodule| submodule.name().map(str::to_string).unwrap_or_else(|| path.to_string()))
     }
@@ -57,8 +71,8 @@ impl App {
         };
         self.modal_network_title = request.label().to_string();
         self.modal_network_message = request.progress_message();
-        self.focus = Focus::ModalNetworkProgress;
-        self.network_handle = Some(request.spawn(self.auth_session.clone()));
+        self.tab.focus = Focus::ModalNetworkProgress;
+        self.network_handle = Some(request.spawn(self.tab.work_tree.clone(), self.auth_session.clone()));
     }
 
     pub fn poll_network_request(&mut self) {
@@ -87,16 +101,24 @@ impl App {
                 self.auth_secret_input.clear();
                 self.modal_network_title.clear();
                 self.modal_network_message.clear();
-                if let Some(NetworkRequest::DeleteRemoteBranch { remote_name, branch, .. }) = completed_request {
-                    let hidden_name = format!("{remote_name}/{branch}");
-                    if self.branches.hidden_branch_names.contains(hidden_name.as_str()) {
-                        self.branches.hidden_branch_names.remove(hidden_name.as_str());
-                        if let Some(path) = &self.path {
-                            save_branch_visibility(path, &self.branches.hidden_branch_names);
+                match completed_request {
+                    Some(NetworkRequest::DeleteRemoteBranches { remote_name, branches, .. }) => {
+                        let mut was_hidden = false;
+                        for branch in branches {
+                            was_hidden |= self.tab.branches.hidden_branch_names.remove(format!("{remote_name}/{branch}").as_str()).is_some();
                         }
-                    }
+                        if was_hidden && let Some(path) = &self.tab.path {
+                            save_branch_visibility(path, &self.tab.branches.hidden_branch_names);
+                        }
+                    },
+                    // Deepening adds history below tips that did not move, so the stored walk is thrown away.
+                    Some(NetworkRequest::Deepen { repo_path, .. }) => {
+                        let cache_dir = self.walk_cache_dir.clone().unwrap_or_else(walk_cache_dir);
+                        let _ = fs::remove_file(walk_cache_path(&cache_dir, &repo_path));
+                    },
+                    _ => {},
                 }
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.reload(None);
             },
             NetworkResult::AuthRequired(AuthRequired { challenge, rejected }) => {
@@ -113,7 +135,7 @@ impl App {
                     self.auth_username_input.set_value(username);
                 }
                 self.auth_input_field = if challenge.protocol.is_http() && self.auth_username_input.value().is_empty() { AuthInputField::Username } else { AuthInputField::Secret };
-                self.focus = Focus::ModalAuth;
+                self.tab.focus = Focus::ModalAuth;
             },
             NetworkResult::Failure(message) => self.finish_network_failure(message),
         }
@@ -127,7 +149,7 @@ impl App {
         self.auth_secret_input.clear();
         self.modal_network_title.clear();
         self.modal_network_message.clear();
-        self.focus = Focus::Viewport;
+        self.tab.focus = Focus::Viewport;
         self.show_error(message);
     }
 
@@ -140,7 +162,7 @@ impl App {
         self.auth_secret_input.clear();
         self.modal_network_title.clear();
         self.modal_network_message.clear();
-        self.focus = Focus::Viewport;
+        self.tab.focus = Focus::Viewport;
         self.show_error(errors::auth_cancelled(&operation));
     }
 
@@ -175,43 +197,58 @@ impl App {
         let Some(action) = self.pending_operation_action.take() else {
             return;
         };
-        let Some(path) = self.repo.as_ref().map(|repo| repo.path().to_path_buf()) else {
-            self.focus = Focus::Viewport;
-            self.show_error(errors::GIT_OPERATION_NO_REPOSITORY());
+        let Some(repo) = self.open_operation_repo() else {
             return;
         };
 
-        let repo = match Repository::open(path) {
-            Ok(repo) => repo,
-            Err(error) => {
-                self.focus = Focus::Viewport;
-                self.show_error(errors::with_error(errors::OPEN_REPOSITORY(), error));
-                return;
-            },
-        };
-
+        self.begin_journal(action.command());
         match action {
-            PendingOperationAction::Start { kind: OperationKind::Rebase, oid } => self.handle_rebase_result(start_rebase(&repo, oid)),
-            PendingOperationAction::Start { kind: OperationKind::Merge, oid } => self.handle_merge_result(start_merge(&repo, oid)),
+            PendingOperationAction::Start { kind: OperationKind::Rebase, oid } => self.start_rebase_with_hooks(&repo, oid),
+            PendingOperationAction::Start { kind: OperationKind::Merge, oid } => self.start_merge_with_hooks(&repo, oid),
             PendingOperationAction::Start { kind: OperationKind::Cherrypick, .. } => {
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.show_error(errors::CHERRYPICK_NO_MESSAGE());
             },
             PendingOperationAction::Start { kind: OperationKind::Revert, .. } => {
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.show_error(errors::REVERT_NO_MESSAGE());
             },
+            PendingOperationAction::StartSequence { kind } => {
+                let result = self.marked_sequence_steps(&repo, kind).and_then(|steps| start_sequence(&repo, Self::sequence_kind_for(kind), steps));
+                if result.is_ok() {
+                    self.tab.graph_marked_oids.clear();
+                }
+                self.handle_sequence_result(kind, result);
+            },
             PendingOperationAction::Continue => self.continue_active_operation(&repo),
             PendingOperationAction::Abort => self.abort_active_operation(&repo),
         }
     }
 
-    fn handle_rebase_result(&mut self, result: Result<RebaseOutcome, git2::Error>) {
+    // Operations run on a fresh handle so libgit2 sees the on-disk state the last reload missed.
+    pub(crate) fn open_operation_repo(&mut self) -> Option<Repository> {
+        let Some(path) = self.tab.repo.as_ref().map(|repo| repo.path().to_path_buf()) else {
+            self.tab.focus = Focus::Viewport;
+            self.show_error(errors::GIT_OPERATION_NO_REPOSITORY());
+            return None;
+        };
+
+        match open_repo(path, self.tab.work_tree.as_deref()) {
+            Ok(repo) => Some(repo),
+            Err(error) => {
+                self.tab.focus = Focus::Viewport;
+                self.show_error(errors::with_error(errors::OPEN_REPOSITORY(), error));
+                None
+            },
+        }
+    }
+
+    pub(crate) fn handle_rebase_result(&mut self, result: Result<RebaseOutcome, git2::Error>) {
         self.modal_operation_kind = OperationKind::Rebase;
         match result {
             Ok(RebaseOutcome::Completed { applied }) => {
                 self.modal_operation_message = operations::rebase_completed(applied);
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Ok(RebaseOutcome::Conflict) => {
@@ -219,12 +256,12 @@ impl App {
             },
             Ok(RebaseOutcome::Aborted) => {
                 self.modal_operation_message = operations::REBASE_ABORTED().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Err(error) => {
                 self.modal_operation_message.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.show_error(errors::with_error(errors::REBASE(), error));
                 self.reload(None);
             },
@@ -236,7 +273,7 @@ impl App {
         match result {
             Ok(CherrypickOutcome::Committed { .. }) => {
                 self.modal_operation_message = operations::CHERRYPICK_COMPLETED().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Ok(CherrypickOutcome::Conflict) => {
@@ -244,12 +281,12 @@ impl App {
             },
             Ok(CherrypickOutcome::Aborted) => {
                 self.modal_operation_message = operations::CHERRYPICK_ABORTED().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Err(error) => {
                 self.modal_operation_message.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.show_error(errors::with_error(errors::CHERRYPICK(), error));
                 self.reload(None);
             },
@@ -261,7 +298,7 @@ impl App {
         match result {
             Ok(RevertOutcome::Committed { .. }) => {
                 self.modal_operation_message = operations::REVERT_COMPLETED().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Ok(RevertOutcome::Conflict) => {
@@ -269,34 +306,65 @@ impl App {
             },
             Ok(RevertOutcome::Aborted) => {
                 self.modal_operation_message = operations::REVERT_ABORTED().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Err(error) => {
                 self.modal_operation_message.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.show_error(errors::with_error(errors::REVERT(), error));
                 self.reload(None);
             },
         }
     }
 
-    fn handle_merge_result(&mut self, result: Result<MergeOutcome, git2::Error>) {
+    fn handle_sequence_result(&mut self, kind: OperationKind, result: Result<SequenceOutcome, git2::Error>) {
+        self.modal_operation_kind = kind;
+        match result {
+            Ok(SequenceOutcome::Completed { applied }) => {
+                self.modal_operation_message = operations::sequence_completed(applied);
+                self.tab.focus = Focus::ModalOperationSuccess;
+                self.reload(None);
+            },
+            Ok(SequenceOutcome::Conflict { remaining, .. }) => {
+                self.show_operation_conflict(kind, operations::sequence_conflict(remaining));
+            },
+            Ok(SequenceOutcome::Aborted) => {
+                self.modal_operation_message = operations::SEQUENCE_ABORTED().to_string();
+                self.tab.focus = Focus::ModalOperationSuccess;
+                self.reload(None);
+            },
+            Err(error) => {
+                self.modal_operation_message.clear();
+                self.tab.focus = Focus::Viewport;
+                let prefix = if kind == OperationKind::Revert { errors::REVERT() } else { errors::CHERRYPICK() };
+                self.show_error(errors::with_error(prefix, error));
+                self.reload(None);
+            },
+        }
+    }
+
+    pub(crate) fn handle_merge_result(&mut self, result: Result<MergeOutcome, git2::Error>) {
         self.modal_operation_kind = OperationKind::Merge;
         match result {
             Ok(MergeOutcome::Completed { .. }) => {
                 self.modal_operation_message = operations::MERGE_COMPLETED().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Ok(MergeOutcome::FastForward { .. }) => {
                 self.modal_operation_message = operations::MERGE_FAST_FORWARDED().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Ok(MergeOutcome::UpToDate) => {
                 self.modal_operation_message = operations::MERGE_ALREADY_UP_TO_DATE().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
+                self.reload(None);
+            },
+            Ok(MergeOutcome::Staged) => {
+                self.modal_operation_message = operations::MERGE_STAGED().to_string();
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Ok(MergeOutcome::Conflict) => {
@@ -304,12 +372,12 @@ impl App {
             },
             Ok(MergeOutcome::Aborted) => {
                 self.modal_operation_message = operations::MERGE_ABORTED().to_string();
-                self.focus = Focus::ModalOperationSuccess;
+                self.tab.focus = Focus::ModalOperationSuccess;
                 self.reload(None);
             },
             Err(error) => {
                 self.modal_operation_message.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.show_error(errors::with_error(errors::MERGE(), error));
                 self.reload(None);
             },
@@ -319,11 +387,15 @@ impl App {
     pub fn show_operation_conflict(&mut self, kind: OperationKind, message: impl Into<String>) {
         self.modal_operation_kind = kind;
         self.modal_operation_message = message.into();
-        self.focus = Focus::ModalOperationConflict;
+        self.tab.focus = Focus::ModalOperationConflict;
         self.reload(None);
     }
 
     pub(crate) fn active_operation_kind(repo: &Repository) -> Option<OperationKind> {
+        if let Some(kind) = sequence_kind(repo) {
+            return Some(Self::operation_kind_for(kind));
+        }
+
         match repo.state() {
             RepositoryState::Rebase | RepositoryState::RebaseInteractive | RepositoryState::RebaseMerge | RepositoryState::ApplyMailboxOrRebase => Some(OperationKind::Rebase),
             RepositoryState::CherryPick | RepositoryState::CherryPickSequence => Some(OperationKind::Cherrypick),
@@ -333,40 +405,83 @@ impl App {
         }
     }
 
+    fn sequence_kind_for(kind: OperationKind) -> SequenceKind {
+        if kind == OperationKind::Revert { SequenceKind::Revert } else { SequenceKind::Cherrypick }
+    }
+
+    fn operation_kind_for(kind: SequenceKind) -> OperationKind {
+        match kind {
+            SequenceKind::Cherrypick => OperationKind::Cherrypick,
+            SequenceKind::Revert => OperationKind::Revert,
+        }
+    }
+
+    // Marked commits are replayed in topological order with the same messages the single-commit modals prefill.
+    fn marked_sequence_steps(&self, repo: &Repository, kind: OperationKind) -> Result<Vec<SequenceStep>, git2::Error> {
+        let ordered = order_sequence(repo, Self::sequence_kind_for(kind), &self.tab.graph_marked_oids)?;
+        ordered
+            .into_iter()
+            .map(|oid| {
+                let commit = repo.find_commit(oid)?;
+                let message = if kind == OperationKind::Revert {
+                    operations::reverted(commit.summary().unwrap_or(operations::REVERT_COMMIT_FALLBACK()))
+                } else {
+                    operations::cherrypicked(commit.summary().unwrap_or(operations::CHERRYPICK_COMMIT_FALLBACK()))
+                };
+                Ok(SequenceStep { oid, message })
+            })
+            .collect()
+    }
+
     fn continue_active_operation(&mut self, repo: &Repository) {
+        if is_sequence_in_progress(repo) {
+            let kind = Self::active_operation_kind(repo).unwrap_or(OperationKind::Cherrypick);
+            self.handle_sequence_result(kind, continue_sequence(repo));
+            return;
+        }
+
         match Self::active_operation_kind(repo) {
             Some(OperationKind::Rebase) => self.handle_rebase_result(continue_rebase(repo)),
             Some(OperationKind::Cherrypick) => self.handle_cherrypick_result(continue_cherrypick(repo)),
             Some(OperationKind::Revert) => self.handle_revert_result(continue_revert(repo)),
             Some(OperationKind::Merge) => self.handle_merge_result(continue_merge(repo)),
             None => {
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.show_error(errors::CONTINUE_NO_OPERATION());
             },
         }
     }
 
     fn abort_active_operation(&mut self, repo: &Repository) {
+        if is_sequence_in_progress(repo) {
+            let kind = Self::active_operation_kind(repo).unwrap_or(OperationKind::Cherrypick);
+            self.handle_sequence_result(kind, abort_sequence(repo));
+            return;
+        }
+
         match Self::active_operation_kind(repo) {
             Some(OperationKind::Rebase) => self.handle_rebase_result(abort_rebase(repo)),
             Some(OperationKind::Cherrypick) => self.handle_cherrypick_result(abort_cherrypick(repo)),
             Some(OperationKind::Revert) => self.handle_revert_result(abort_revert(repo)),
             Some(OperationKind::Merge) => self.handle_merge_result(abort_merge(repo)),
             None => {
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.show_error(errors::ABORT_NO_OPERATION());
             },
         }
     }
 
     pub fn on_drop(&mut self) {
-        if self.repo.is_none() || self.viewport != Viewport::Graph {
+        if self.open_batch_modal(BatchAction::DropStashes) {
+            return;
+        }
+        if self.tab.repo.is_none() || self.tab.viewport != Viewport::Graph {
             return;
         }
 
-        let oid = match self.focus {
+        let oid = match self.tab.focus {
             Focus::Viewport => {
-                let Some(row) = self.graph_row_at(self.graph_selected) else {
+                let Some(row) = self.graph_row_at(self.tab.graph_selected) else {
                     return;
                 };
                 if !row.is_stash {
@@ -378,21 +493,22 @@ impl App {
                 let Some(alias) = self.stash_alias_at_pane_selection() else {
                     return;
                 };
-                *self.oids.get_oid_by_alias(alias)
+                *self.tab.oids.get_oid_by_alias(alias)
             },
             _ => return,
         };
 
-        let Some(path) = self.repo.as_ref().map(|repo| repo.path().to_path_buf()) else {
+        let Some(path) = self.tab.repo.as_ref().map(|repo| repo.path().to_path_buf()) else {
             return;
         };
-        let mut repo = match Repository::open(path) {
+        let mut repo = match open_repo(path, self.tab.work_tree.as_deref()) {
             Ok(repo) => repo,
             Err(error) => {
                 self.show_error(errors::with_error(errors::OPEN_REPOSITORY(), error));
                 return;
             },
         };
+        self.begin_journal(Command::Drop);
         match pop(&mut repo, &oid, false) {
             Ok(_) => self.reload(None),
             Err(error) => self.show_error(errors::with_error(errors::DROP_STASH(), error)),
@@ -400,13 +516,13 @@ impl App {
     }
 
     pub fn on_pop(&mut self) {
-        if self.repo.is_none() || self.viewport != Viewport::Graph {
+        if self.tab.repo.is_none() || self.tab.viewport != Viewport::Graph {
             return;
         }
 
-        let oid = match self.focus {
+        let oid = match self.tab.focus {
             Focus::Viewport => {
-                let Some(row) = self.graph_row_at(self.graph_selected) else {
+                let Some(row) = self.graph_row_at(self.tab.graph_selected) else {
                     return;
                 };
                 if !row.is_stash {
@@ -418,21 +534,22 @@ impl App {
                 let Some(alias) = self.stash_alias_at_pane_selection() else {
                     return;
                 };
-                *self.oids.get_oid_by_alias(alias)
+                *self.tab.oids.get_oid_by_alias(alias)
             },
             _ => return,
         };
 
-        let Some(path) = self.repo.as_ref().map(|repo| repo.path().to_path_buf()) else {
+        let Some(path) = self.tab.repo.as_ref().map(|repo| repo.path().to_path_buf()) else {
             return;
         };
-        let mut repo = match Repository::open(path) {
+        let mut repo = match open_repo(path, self.tab.work_tree.as_deref()) {
             Ok(repo) => repo,
             Err(error) => {
                 self.show_error(errors::with_error(errors::OPEN_REPOSITORY(), error));
                 return;
             },
         };
+        self.begin_journal(Command::Pop);
         match pop(&mut repo, &oid, true) {
             Ok(_) => self.reload(None),
             Err(error) => self.show_error(errors::with_error(errors::POP_STASH(), error)),
@@ -440,11 +557,11 @@ impl App {
     }
 
     pub fn on_stash(&mut self) {
-        if self.repo.is_some() && self.viewport == Viewport::Graph && self.focus == Focus::Viewport {
-            let Some(path) = self.repo.as_ref().map(|repo| repo.path().to_path_buf()) else {
+        if self.tab.repo.is_some() && self.tab.viewport == Viewport::Graph && self.tab.focus == Focus::Viewport {
+            let Some(path) = self.tab.repo.as_ref().map(|repo| repo.path().to_path_buf()) else {
                 return;
             };
-            let mut repo = match Repository::open(path) {
+            let mut repo = match open_repo(path, self.tab.work_tree.as_deref()) {
                 Ok(repo) => repo,
                 Err(error) => {
                     self.show_error(errors::with_error(errors::OPEN_REPOSITORY(), error));
@@ -460,18 +577,18 @@ impl App {
     }
 
     pub fn on_find(&mut self) {
-        if self.viewport == Viewport::Graph && self.focus == Focus::Viewport {
-            self.focus = Focus::ModalGrep;
+        if self.tab.viewport == Viewport::Graph && self.tab.focus == Focus::Viewport {
+            self.tab.focus = Focus::ModalGrep;
         }
     }
 
     pub fn on_find_file(&mut self) {
-        if self.repo.is_none() || matches!(self.viewport, Viewport::Splash | Viewport::Settings) {
+        if self.tab.repo.is_none() || matches!(self.tab.viewport, Viewport::Splash | Viewport::Settings) {
             return;
         }
 
         if !matches!(
-            self.focus,
+            self.tab.focus,
             Focus::Viewport
                 | Focus::Inspector
                 | Focus::StatusTop
@@ -487,53 +604,80 @@ impl App {
             return;
         }
 
-        self.modal_file_search_return_focus = self.focus;
+        self.modal_file_search_return_focus = self.tab.focus;
         self.modal_input.clear();
         self.modal_file_search_results.clear();
         self.modal_file_search_selected = 0;
         self.modal_file_search_scroll.set(0);
-        self.focus = Focus::ModalFileSearch;
+        self.tab.focus = Focus::ModalFileSearch;
     }
 
     pub fn on_fetch_all(&mut self) {
-        if self.viewport != Viewport::Settings {
+        if self.tab.viewport != Viewport::Settings {
             let Some(remote_name) = self.default_remote_for_network(network::FETCH()) else {
                 return;
             };
-            let repo_path = self.path.as_deref().unwrap_or(".");
+            let repo_path = self.tab.path.as_deref().unwrap_or(".");
             self.start_network_request(NetworkRequest::Fetch { repo_path: repo_path.to_string(), remote_name });
         }
     }
 
+    // Only shallow clones have history left to fetch below their boundary commits.
+    pub fn on_deepen_history(&mut self) {
+        if self.tab.viewport == Viewport::Settings || !self.tab.repo.as_ref().is_some_and(|repo| repo.is_shallow()) {
+            return;
+        }
+        self.modal_input.set_value(DEEPEN_DEFAULT.to_string());
+        self.tab.focus = Focus::ModalDeepen;
+    }
+
+    pub(crate) fn confirm_deepen_input(&mut self) {
+        let Ok(by) = self.modal_input.value().trim().parse::<usize>() else {
+            return;
+        };
+        if by == 0 {
+            return;
+        }
+
+        self.modal_input.clear();
+        self.tab.focus = Focus::Viewport;
+        let Some(remote_name) = self.default_remote_for_network(network::DEEPEN()) else {
+            return;
+        };
+        let repo_path = self.tab.path.as_deref().unwrap_or(".");
+        self.start_network_request(NetworkRequest::Deepen { repo_path: repo_path.to_string(), remote_name, by });
+    }
+
     pub fn on_checkout(&mut self) {
-        let Some(repo) = &self.repo else { return };
+        let Some(repo) = self.tab.repo.clone() else { return };
 
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
                 // Branch pane checkout uses the selected row directly.
-                let projected = self.graph.branches_window.as_ref().and_then(|window| {
-                    if self.branches_selected >= window.start
-                        && self.branches_selected < window.end
-                        && let Some(GraphPaneRow::Branch { alias, name, graph_index, .. }) = window.rows.get(self.branches_selected - window.start)
+                let projected = self.tab.graph.branches_window.as_ref().and_then(|window| {
+                    if self.tab.branches_selected >= window.start
+                        && self.tab.branches_selected < window.end
+                        && let Some(GraphPaneRow::Branch { alias, name, graph_index, .. }) = window.rows.get(self.tab.branches_selected - window.start)
                     {
                         Some((*alias, name.clone(), *graph_index))
                     } else {
                         None
                     }
                 });
-                let Some((alias, branch, graph_index)) = projected.or_else(|| self.branches.sorted.get(self.branches_selected).cloned().map(|(alias, branch)| (alias, branch, None))) else {
+                let Some((alias, branch, graph_index)) = projected.or_else(|| self.tab.branches.sorted.get(self.tab.branches_selected).cloned().map(|(alias, branch)| (alias, branch, None))) else {
                     return;
                 };
 
-                match checkout_branch(repo, &mut self.branches.hidden_branch_names, &mut self.branches.local, alias, &branch) {
+                self.begin_journal(Command::Checkout);
+                match checkout_branch(&repo, &mut self.tab.branches.hidden_branch_names, &mut self.tab.branches.local, alias, &branch) {
                     Ok(_) => {
                         // Keep graph selection on the commit that owns the checked-out branch.
-                        self.graph_selected = graph_index.or_else(|| self.oids.get_sorted_aliases().iter().position(|o| o == &alias)).unwrap_or(0);
+                        self.tab.graph_selected = graph_index.or_else(|| self.tab.oids.get_sorted_aliases().iter().position(|o| o == &alias)).unwrap_or(0);
 
-                        if let Some(path) = &self.path {
-                            save_branch_visibility(path, &self.branches.hidden_branch_names);
+                        if let Some(path) = &self.tab.path {
+                            save_branch_visibility(path, &self.tab.branches.hidden_branch_names);
                         }
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.reload(None);
                     },
                     Err(error) => self.show_error(errors::with_error(errors::CHECKOUT(), error)),
@@ -542,14 +686,14 @@ impl App {
 
             Focus::Viewport => {
                 // The uncommitted pseudo-row has no standalone commit to checkout.
-                if self.viewport != Viewport::Graph || self.graph_selected == 0 {
+                if self.tab.viewport != Viewport::Graph || self.tab.graph_selected == 0 {
                     return;
                 }
 
-                let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+                let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
                     return;
                 };
-                let Some(oid) = self.graph_oid_at(self.graph_selected) else {
+                let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
                     return;
                 };
 
@@ -559,9 +703,10 @@ impl App {
                 match branches_for_alias.len() {
                     0 => {
                         // No branch label means detached checkout is the only option.
-                        match checkout_head(repo, oid) {
+                        self.begin_journal(Command::Checkout);
+                        match checkout_head(&repo, oid) {
                             Ok(_) => {
-                                self.focus = Focus::Viewport;
+                                self.tab.focus = Focus::Viewport;
                                 self.reload(None);
                             },
                             Err(error) => self.show_error(errors::with_error(errors::CHECKOUT(), error)),
@@ -569,19 +714,20 @@ impl App {
                     },
                     1 => {
                         // A single label can be checked out without another prompt.
-                        match checkout_branch(repo, &mut self.branches.hidden_branch_names, &mut self.branches.local, alias, &branches_for_alias[0]) {
+                        self.begin_journal(Command::Checkout);
+                        match checkout_branch(&repo, &mut self.tab.branches.hidden_branch_names, &mut self.tab.branches.local, alias, &branches_for_alias[0]) {
                             Ok(_) => {
-                                if let Some(path) = &self.path {
-                                    save_branch_visibility(path, &self.branches.hidden_branch_names);
+                                if let Some(path) = &self.tab.path {
+                                    save_branch_visibility(path, &self.tab.branches.hidden_branch_names);
                                 }
-                                self.focus = Focus::Viewport;
+                                self.tab.focus = Focus::Viewport;
                                 self.reload(None);
                             },
                             Err(error) => self.show_error(errors::with_error(errors::CHECKOUT(), error)),
                         }
                     },
                     _ => {
-                        self.focus = Focus::ModalCheckout;
+                        self.tab.focus = Focus::ModalCheckout;
                     },
                 }
             },
@@ -591,64 +737,156 @@ impl App {
     }
 
     pub fn on_hard_reset(&mut self) {
-        if let Some(repo) = &self.repo {
-            match self.focus {
-                Focus::Viewport => {
-                    if self.viewport != Viewport::Graph {
-                        return;
-                    }
-                    let Some(oid) = self.graph_oid_at(self.graph_selected) else {
-                        return;
-                    };
-                    match reset_to_commit(repo, oid, git2::ResetType::Hard) {
-                        Ok(_) => {
-                            self.reload(None);
-                            self.focus = Focus::Viewport;
-                        },
-                        Err(error) => self.show_error(errors::with_error(errors::HARD_RESET(), error)),
-                    }
-                },
-                Focus::StatusTop | Focus::StatusBottom => {
-                    if let Some(file_name) = self.get_selected_file_name() {
-                        let path = Path::new(&file_name);
-                        match reset_file(repo, path) {
-                            Ok(_) => self.reload(None),
-                            Err(error) => self.show_error(errors::with_error(errors::RESET_FILE(), error)),
-                        }
-                    }
-                },
-                _ => {},
+        let discard = if self.tab.focus == Focus::StatusTop { BatchAction::DiscardStaged } else { BatchAction::DiscardUnstaged };
+        if self.open_batch_modal(discard) {
+            return;
+        }
+        if let Some(repo) = self.tab.repo.clone()
+            && matches!(self.tab.focus, Focus::StatusTop | Focus::StatusBottom)
+        {
+            if let Some(file_name) = self.get_selected_file_name() {
+                let path = Path::new(&file_name);
+                match reset_file(&repo, path) {
+                    Ok(_) => self.reload(None),
+                    Err(error) => self.show_error(errors::with_error(errors::RESET_FILE(), error)),
+                }
             }
+            return;
         }
+        self.open_reset_modal(Command::HardReset);
     }
 
     pub fn on_mixed_reset(&mut self) {
-        if let Some(repo) = &self.repo
-            && self.focus == Focus::Viewport
-        {
-            if self.focus == Focus::Viewport && self.viewport != Viewport::Graph {
-                return;
-            }
-            let Some(oid) = self.graph_oid_at(self.graph_selected) else {
-                return;
-            };
-            match reset_to_commit(repo, oid, git2::ResetType::Mixed) {
-                Ok(_) => {
-                    self.reload(None);
-                    self.focus = Focus::Viewport;
-                },
-                Err(error) => self.show_error(errors::with_error(errors::MIXED_RESET(), error)),
+        self.open_reset_modal(Command::MixedReset);
+    }
+
+    pub fn on_soft_reset(&mut self) {
+        self.open_reset_modal(Command::SoftReset);
+    }
+
+    // Moves the branch selected in the branches pane to the commit selected in the graph.
+    pub fn on_reset_branch(&mut self) {
+        if self.tab.viewport != Viewport::Graph || self.tab.focus != Focus::Viewport {
+            return;
+        }
+        let Some(branch) = self.branch_name_at_pane_selection() else { return };
+        let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else { return };
+        self.show_branch_reset_modal(branch, oid);
+    }
+
+    // The checked-out branch can only move through HEAD, so it gets the regular mixed reset instead.
+    pub(crate) fn show_branch_reset_modal(&mut self, branch: String, oid: Oid) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let label = oid.to_string()[..7].to_string();
+        if get_current_branch(&repo).as_deref() == Some(branch.as_str()) {
+            self.show_reset_modal(Command::MixedReset, oid, label, None);
+        } else {
+            self.show_reset_modal(Command::ResetBranch, oid, label, Some(branch));
+        }
+    }
+
+    // The commit a reset moves the current branch to, taken from whichever pane has focus.
+    fn reset_target(&self, repo: &Repository) -> Option<(Oid, String)> {
+        match self.tab.focus {
+            Focus::Viewport if self.tab.viewport == Viewport::Graph => {
+                let oid = self.graph_oid_at(self.tab.graph_selected)?;
+                Some((oid, oid.to_string()[..7].to_string()))
+            },
+            Focus::Reflogs => self.tab.reflogs.entries.get(self.tab.reflogs_selected).map(|entry| (entry.new_oid, entry.selector.clone())),
+            Focus::Tags => {
+                let name = self.tag_name_at_pane_selection()?;
+                let oid = repo.find_reference(&format!("refs/tags/{name}")).ok()?.peel_to_commit().ok()?.id();
+                Some((oid, name))
+            },
+            Focus::Branches => {
+                let name = self.branch_name_at_pane_selection()?;
+                let oid = repo.resolve_reference_from_short_name(&name).ok()?.peel_to_commit().ok()?.id();
+                Some((oid, name))
+            },
+            _ => None,
+        }
+    }
+
+    fn open_reset_modal(&mut self, kind: Command) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        if self.tab.viewport == Viewport::Settings {
+            return;
+        }
+        let Some((oid, label)) = self.reset_target(&repo) else {
+            return;
+        };
+        self.show_reset_modal(kind, oid, label, None);
+    }
+
+    // `branch` names a branch other than the checked-out one; only its ref moves on confirm.
+    pub(crate) fn show_reset_modal(&mut self, kind: Command, oid: Oid, label: String, branch: Option<String>) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let preview = match &branch {
+            Some(name) => reset_branch_preview(&repo, name, oid),
+            None => reset_preview(&repo, oid),
+        };
+
+        match preview {
+            Ok(preview) => {
+                self.modal_reset_kind = kind;
+                self.modal_reset_target = Some(oid);
+                self.modal_reset_label = label;
+                self.modal_reset_branch = branch;
+                self.modal_reset_preview = preview;
+                self.tab.focus = Focus::ModalReset;
+            },
+            Err(error) => self.show_error(errors::with_error(errors::RESET_PREVIEW(), error)),
+        }
+    }
+
+    pub(crate) fn confirm_reset(&mut self) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let Some(oid) = self.modal_reset_target else {
+            self.close_reset_modal();
+            return;
+        };
+        let kind = self.modal_reset_kind.clone();
+        if let Some(branch) = self.modal_reset_branch.clone() {
+            self.close_reset_modal();
+            self.begin_journal(kind);
+            match reset_branch(&repo, &branch, oid) {
+                Ok(_) => self.reload(None),
+                Err(error) => self.show_error(errors::with_error(errors::RESET_BRANCH(), error)),
             }
+            return;
+        }
+        let (reset_type, error_prefix) = match kind {
+            Command::SoftReset => (ResetType::Soft, errors::SOFT_RESET()),
+            Command::MixedReset => (ResetType::Mixed, errors::MIXED_RESET()),
+            _ => (ResetType::Hard, errors::HARD_RESET()),
+        };
+
+        self.close_reset_modal();
+        self.begin_journal(kind);
+        match reset_to_commit(&repo, oid, reset_type) {
+            Ok(_) => self.reload(None),
+            Err(error) => self.show_error(errors::with_error(error_prefix, error)),
         }
     }
 
+    pub(crate) fn close_reset_modal(&mut self) {
+        self.modal_reset_target = None;
+        self.modal_reset_label.clear();
+        self.modal_reset_branch = None;
+        self.modal_reset_preview = ResetPreview::default();
+        self.tab.focus = Focus::Viewport;
+    }
+
     pub fn on_unstage(&mut self) {
-        if let Some(repo) = &self.repo {
-            match self.viewport {
+        if self.tab.viewport != Viewport::Settings && self.open_batch_modal(BatchAction::Unstage) {
+            return;
+        }
+        if let Some(repo) = &self.tab.repo {
+            match self.tab.viewport {
                 Viewport::Settings => {},
-                _ => match self.focus {
+                _ => match self.tab.focus {
                     Focus::Viewport => {
-                        if self.uncommitted.is_staged {
+                        if self.tab.uncommitted.is_staged {
                             match unstage_all(repo) {
                                 Ok(_) => self.reload(None),
                                 Err(error) => self.show_error(errors::with_error(errors::UNSTAGE_ALL(), error)),
@@ -681,7 +919,7 @@ impl App {
                         };
                         match unstage_submodule(repo, &name) {
                             Ok(_) => {
-                                self.focus = Focus::Submodules;
+                                self.tab.focus = Focus::Submodules;
                                 self.reload(None);
                             },
                             Err(error) => self.show_error(errors::with_error(errors::UNSTAGE_SUBMODULE(), error)),
@@ -694,12 +932,15 @@ impl App {
     }
 
     pub fn on_stage(&mut self) {
-        if let Some(repo) = &self.repo {
-            match self.viewport {
+        if self.tab.viewport != Viewport::Settings && self.open_batch_modal(BatchAction::Stage) {
+            return;
+        }
+        if let Some(repo) = &self.tab.repo {
+            match self.tab.viewport {
                 Viewport::Settings => {},
-                _ => match self.focus {
+                _ => match self.tab.focus {
                     Focus::Viewport => {
-                        if self.uncommitted.is_unstaged {
+                        if self.tab.uncommitted.is_unstaged {
                             match stage_all(repo) {
                                 Ok(_) => self.reload(None),
                                 Err(error) => self.show_error(errors::with_error(errors::STAGE_ALL(), error)),
@@ -732,7 +973,7 @@ impl App {
                         };
                         match stage_submodule_head(repo, &name) {
                             Ok(_) => {
-                                self.focus = Focus::Submodules;
+                                self.tab.focus = Focus::Submodules;
                                 self.reload(None);
                             },
                             Err(error) => self.show_error(errors::with_error(errors::STAGE_SUBMODULE(), error)),
@@ -745,22 +986,22 @@ impl App {
     }
 
     pub fn on_commit(&mut self) {
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Settings | Viewport::Viewer => {},
             _ => {
-                if self.uncommitted.is_staged {
-                    self.focus = Focus::ModalCommit;
+                if self.tab.uncommitted.is_staged {
+                    self.tab.focus = Focus::ModalCommit;
                 }
             },
         }
     }
 
     pub fn on_force_push(&mut self) {
-        if let Some(repo) = self.repo.clone() {
-            match self.viewport {
+        if let Some(repo) = self.tab.repo.clone() {
+            match self.tab.viewport {
                 Viewport::Settings | Viewport::Viewer => {},
                 _ => {
-                    let repo_path = self.path.as_deref().unwrap_or(".").to_string();
+                    let repo_path = self.tab.path.as_deref().unwrap_or(".").to_string();
                     let Some(branch) = get_current_branch(&repo) else {
                         self.show_error(errors::PUSH_DETACHED_HEAD());
                         return;
@@ -768,29 +1009,29 @@ impl App {
                     let Some(remote_name) = self.default_remote_for_network(network::PUSH()) else {
                         return;
                     };
-                    self.start_network_request(NetworkRequest::PushBranch { repo_path, remote_name, branch, force: true });
+                    self.start_push_request(NetworkRequest::PushBranch { repo_path, remote_name, branch, force: true }, Some(Command::ForcePush));
                 },
             }
         }
     }
 
     pub fn on_push_tags(&mut self) {
-        if self.repo.is_some() {
-            match self.viewport {
+        if self.tab.repo.is_some() {
+            match self.tab.viewport {
                 Viewport::Settings | Viewport::Viewer => {},
                 _ => {
                     let Some(remote_name) = self.default_remote_for_network(network::PUSH_TAGS()) else {
                         return;
                     };
-                    let repo_path = self.path.as_deref().unwrap_or(".");
-                    self.start_network_request(NetworkRequest::PushTags { repo_path: repo_path.to_string(), remote_name });
+                    let repo_path = self.tab.path.as_deref().unwrap_or(".");
+                    self.start_push_request(NetworkRequest::PushTags { repo_path: repo_path.to_string(), remote_name }, None);
                 },
             }
         }
     }
 
     fn default_remote_for_network(&mut self, operation: &str) -> Option<String> {
-        let Some(repo) = self.repo.clone() else {
+        let Some(repo) = self.tab.repo.clone() else {
             return None;
         };
 
@@ -804,19 +1045,19 @@ impl App {
     }
 
     pub fn on_create_branch(&mut self) {
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Settings | Viewport::Viewer => {},
-            _ => match self.focus {
+            _ => match self.tab.focus {
                 Focus::Reflogs => {
-                    if let Some(entry) = self.reflogs.entries.get(self.reflogs_selected) {
+                    if let Some(entry) = self.tab.reflogs.entries.get(self.tab.reflogs_selected) {
                         self.pending_branch_target_oid = Some(entry.new_oid);
-                        self.focus = Focus::ModalCreateBranch;
+                        self.tab.focus = Focus::ModalCreateBranch;
                     }
                 },
                 _ => {
-                    if self.graph_selected != 0 {
+                    if self.tab.graph_selected != 0 {
                         self.pending_branch_target_oid = None;
-                        self.focus = Focus::ModalCreateBranch;
+                        self.tab.focus = Focus::ModalCreateBranch;
                     }
                 },
             },
@@ -828,7 +1069,7 @@ impl App {
             return Some(oid);
         }
 
-        if self.graph_selected != 0 { self.graph_oid_at(self.graph_selected) } else { None }
+        if self.tab.graph_selected != 0 { self.graph_oid_at(self.tab.graph_selected) } else { None }
     }
 
     pub fn clear_pending_branch_target(&mut self) {
@@ -838,18 +1079,18 @@ impl App {
     pub(crate) fn open_branch_rename_modal(&mut self, branch: String) {
         self.modal_input.set_value(branch.clone());
         self.modal_rename_branch_source = Some(branch);
-        self.focus = Focus::ModalRenameBranch;
+        self.tab.focus = Focus::ModalRenameBranch;
     }
 
     pub fn on_rename_branch(&mut self) {
-        let Some(repo) = self.repo.clone() else { return };
+        let Some(repo) = self.tab.repo.clone() else { return };
 
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Settings | Viewport::Viewer => return,
             _ => {},
         }
 
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
                 let Some(branch) = self.branch_name_at_pane_selection() else {
                     return;
@@ -862,11 +1103,11 @@ impl App {
                 }
             },
             Focus::Viewport => {
-                if self.viewport != Viewport::Graph || self.graph_selected == 0 {
+                if self.tab.viewport != Viewport::Graph || self.tab.graph_selected == 0 {
                     return;
                 }
 
-                let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+                let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
                     return;
                 };
                 let branch_names = self.graph_branch_choices(alias);
@@ -881,7 +1122,7 @@ impl App {
                     _ => {
                         self.modal_branch_action = BranchModalAction::Rename;
                         self.modal_solo_selected = 0;
-                        self.focus = Focus::ModalSolo;
+                        self.tab.focus = Focus::ModalSolo;
                     },
                 }
             },
@@ -890,21 +1131,22 @@ impl App {
     }
 
     pub(crate) fn delete_branch_from_ui(&mut self, branch: &str) {
-        let Some(repo) = self.repo.clone() else {
+        let Some(repo) = self.tab.repo.clone() else {
             return;
         };
 
         if repo.find_branch(branch, BranchType::Local).is_ok() {
+            self.begin_journal(Command::DeleteBranch);
             match delete_branch(&repo, branch) {
                 Ok(_) => {
-                    if self.branches.hidden_branch_names.contains(branch) {
-                        self.branches.hidden_branch_names.remove(branch);
-                        if let Some(path) = &self.path {
-                            save_branch_visibility(path, &self.branches.hidden_branch_names);
+                    if self.tab.branches.hidden_branch_names.contains(branch) {
+                        self.tab.branches.hidden_branch_names.remove(branch);
+                        if let Some(path) = &self.tab.path {
+                            save_branch_visibility(path, &self.tab.branches.hidden_branch_names);
                         }
                     }
                     self.modal_delete_branch_selected = 0;
-                    self.focus = Focus::Viewport;
+                    self.tab.focus = Focus::Viewport;
                     self.reload(None);
                 },
                 Err(error) => self.show_error(errors::with_error(errors::DELETE_BRANCH(), error)),
@@ -918,32 +1160,38 @@ impl App {
             return;
         }
 
-        let repo_path = self.path.as_deref().unwrap_or(".");
         self.modal_delete_branch_selected = 0;
-        self.start_network_request(NetworkRequest::DeleteRemoteBranch { repo_path: repo_path.to_string(), remote_name: remote_name.to_string(), branch: remote_branch.to_string() });
+        let repo_path = self.tab.path.as_deref().unwrap_or(".");
+        self.start_push_request(
+            NetworkRequest::DeleteRemoteBranches { repo_path: repo_path.to_string(), remote_name: remote_name.to_string(), branches: vec![remote_branch.to_string()] },
+            Some(Command::DeleteBranch),
+        );
     }
 
     pub fn on_delete_branch(&mut self) {
-        let Some(repo) = &self.repo else { return };
+        if !matches!(self.tab.viewport, Viewport::Settings | Viewport::Viewer) && self.open_batch_modal(BatchAction::DeleteBranches) {
+            return;
+        }
+        let Some(repo) = &self.tab.repo else { return };
 
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Settings | Viewport::Viewer => return,
             _ => {},
         }
 
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
-                let projected = self.graph.branches_window.as_ref().and_then(|window| {
-                    if self.branches_selected >= window.start
-                        && self.branches_selected < window.end
-                        && let Some(GraphPaneRow::Branch { name, .. }) = window.rows.get(self.branches_selected - window.start)
+                let projected = self.tab.graph.branches_window.as_ref().and_then(|window| {
+                    if self.tab.branches_selected >= window.start
+                        && self.tab.branches_selected < window.end
+                        && let Some(GraphPaneRow::Branch { name, .. }) = window.rows.get(self.tab.branches_selected - window.start)
                     {
                         Some(name.clone())
                     } else {
                         None
                     }
                 });
-                let Some(branch) = projected.or_else(|| self.branches.sorted.get(self.branches_selected).map(|(_, branch)| branch.clone())) else {
+                let Some(branch) = projected.or_else(|| self.tab.branches.sorted.get(self.tab.branches_selected).map(|(_, branch)| branch.clone())) else {
                     return;
                 };
 
@@ -961,11 +1209,11 @@ impl App {
             },
 
             Focus::Viewport => {
-                if self.graph_selected == 0 {
+                if self.tab.graph_selected == 0 {
                     return;
                 }
 
-                let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+                let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
                     return;
                 };
                 let current = get_current_branch(repo);
@@ -977,7 +1225,7 @@ impl App {
                     0 => {},
                     1 => self.delete_branch_from_ui(&branch_names[0]),
                     _ => {
-                        self.focus = Focus::ModalDeleteBranch;
+                        self.tab.focus = Focus::ModalDeleteBranch;
                     },
                 }
             },
@@ -987,55 +1235,55 @@ impl App {
     }
 
     pub fn on_tag(&mut self) {
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Settings | Viewport::Viewer => {},
             _ => {
-                if self.focus == Focus::Viewport && self.graph_selected != 0 {
-                    self.focus = Focus::ModalTag;
+                if self.tab.focus == Focus::Viewport && self.tab.graph_selected != 0 {
+                    self.tab.focus = Focus::ModalTag;
                 }
             },
         }
     }
 
     pub fn on_untag(&mut self) {
-        if let Some(repo) = &self.repo {
-            match self.viewport {
+        if !matches!(self.tab.viewport, Viewport::Settings | Viewport::Viewer) && self.open_batch_modal(BatchAction::DeleteTags) {
+            return;
+        }
+        if let Some(repo) = self.tab.repo.clone() {
+            match self.tab.viewport {
                 Viewport::Settings | Viewport::Viewer => {},
-                _ => match self.focus {
+                _ => match self.tab.focus {
                     Focus::Tags => {
-                        let projected = self.graph.tags_window.as_ref().and_then(|window| {
-                            if self.tags_selected >= window.start
-                                && self.tags_selected < window.end
-                                && let Some(GraphPaneRow::Tag { name, .. }) = window.rows.get(self.tags_selected - window.start)
-                            {
-                                Some(name.clone())
-                            } else {
-                                None
-                            }
-                        });
-                        let Some(tag) = projected.or_else(|| self.tags.sorted.get(self.tags_selected).map(|(_, tag)| tag.clone())) else {
+                        let Some(tag) = self.tag_name_at_pane_selection() else {
                             return;
                         };
-                        match untag(repo, &tag) {
+                        self.begin_journal(Command::Untag);
+                        match untag(&repo, &tag) {
                             Ok(_) => self.reload(None),
                             Err(error) => self.show_error(errors::with_error(errors::DELETE_TAG(), error)),
                         }
                     },
                     Focus::Viewport => {
-                        if self.graph_selected != 0 {
+                        if self.tab.graph_selected != 0 {
                             let tag_names: Vec<String> = self
-                                .graph_row_at(if self.graph_selected == 0 { 1 } else { self.graph_selected })
+                                .graph_row_at(if self.tab.graph_selected == 0 { 1 } else { self.tab.graph_selected })
                                 .map(|row| row.tags.iter().map(|tag| tag.name.clone()).collect())
-                                .or_else(|| self.graph_alias_at(if self.graph_selected == 0 { 1 } else { self.graph_selected }).map(|alias| self.tags.local.get(&alias).cloned().unwrap_or_default()))
+                                .or_else(|| {
+                                    self.graph_alias_at(if self.tab.graph_selected == 0 { 1 } else { self.tab.graph_selected })
+                                        .map(|alias| self.tab.tags.local.get(&alias).cloned().unwrap_or_default())
+                                })
                                 .unwrap_or_default();
                             match tag_names.len() {
                                 0 => {},
-                                1 => match untag(repo, tag_names[0].as_str()) {
-                                    Ok(_) => self.reload(None),
-                                    Err(error) => self.show_error(errors::with_error(errors::DELETE_TAG(), error)),
+                                1 => {
+                                    self.begin_journal(Command::Untag);
+                                    match untag(&repo, tag_names[0].as_str()) {
+                                        Ok(_) => self.reload(None),
+                                        Err(error) => self.show_error(errors::with_error(errors::DELETE_TAG(), error)),
+                                    }
                                 },
                                 _ => {
-                                    self.focus = Focus::ModalDeleteTag;
+                                    self.tab.focus = Focus::ModalDeleteTag;
                                 },
                             }
                         }
@@ -1047,35 +1295,180 @@ impl App {
     }
 
     pub fn on_cherrypick(&mut self) {
-        if self.viewport == Viewport::Graph
-            && self.focus == Focus::Viewport
-            && self.graph_selected != 0
-            && let Some(repo) = &self.repo
+        if self.tab.viewport == Viewport::Graph
+            && self.tab.focus == Focus::Viewport
+            && self.tab.graph_selected != 0
+            && let Some(repo) = self.tab.repo.clone()
         {
-            let idx = if self.graph_selected == 0 { 1 } else { self.graph_selected };
+            // A sequence has no step that asks for a mainline, so marked merges are refused like reverts.
+            if self.tab.graph_marked_oids.iter().any(|oid| repo.find_commit(*oid).is_ok_and(|commit| commit.parent_count() > 1)) {
+                self.show_error(errors::CHERRYPICK_MERGE_UNSUPPORTED());
+                return;
+            }
+            if self.open_batch_modal(BatchAction::Cherrypick) {
+                return;
+            }
+
+            let idx = if self.tab.graph_selected == 0 { 1 } else { self.tab.graph_selected };
             let Some(oid) = self.graph_oid_at(idx) else {
                 return;
             };
 
             let original_message = match repo.find_commit(oid) {
-                Ok(commit) => Ok(commit.summary().unwrap_or(operations::CHERRYPICK_COMMIT_FALLBACK()).to_string()),
-                Err(error) => Err(error),
+                Ok(commit) if commit.parent_count() > 1 => None,
+                Ok(commit) => Some(Ok(commit.summary().unwrap_or(operations::CHERRYPICK_COMMIT_FALLBACK()).to_string())),
+                Err(error) => Some(Err(error)),
             };
 
             match original_message {
-                Ok(original_message) => {
+                None => self.open_mainline_modal(oid, OperationKind::Cherrypick),
+                Some(Ok(original_message)) => {
                     self.pending_cherrypick_oid = Some(oid);
                     self.modal_input.set_value(operations::cherrypicked(&original_message));
-                    self.focus = Focus::ModalCherrypick;
+                    self.tab.focus = Focus::ModalCherrypick;
                 },
-                Err(error) => self.show_error(errors::with_error(errors::CHERRYPICK(), error)),
+                Some(Err(error)) => self.show_error(errors::with_error(errors::CHERRYPICK(), error)),
             }
         }
     }
 
+    // Merge commits need a parent to diff against before they can be replayed or undone.
+    fn open_mainline_modal(&mut self, oid: Oid, kind: OperationKind) {
+        match kind {
+            OperationKind::Revert => self.pending_revert_oid = Some(oid),
+            _ => self.pending_cherrypick_oid = Some(oid),
+        }
+        self.pending_mainline = None;
+        self.modal_mainline_kind = kind;
+        self.modal_mainline_selected = 0;
+        self.refresh_mainline_preview();
+        self.tab.focus = Focus::ModalMainline;
+    }
+
+    fn mainline_target_oid(&self) -> Option<Oid> {
+        match self.modal_mainline_kind {
+            OperationKind::Revert => self.pending_revert_oid,
+            _ => self.pending_cherrypick_oid,
+        }
+    }
+
+    pub(crate) fn mainline_parents(&self) -> Vec<Oid> {
+        let (Some(repo), Some(oid)) = (&self.tab.repo, self.mainline_target_oid()) else {
+            return Vec::new();
+        };
+        repo.find_commit(oid).map(|commit| commit.parent_ids().collect()).unwrap_or_default()
+    }
+
+    pub(crate) fn refresh_mainline_preview(&mut self) {
+        self.modal_mainline_preview = match (&self.tab.repo, self.mainline_target_oid()) {
+            (Some(repo), Some(oid)) => get_filenames_diff_against_parent(repo, oid, self.modal_mainline_selected.max(0) as usize),
+            _ => Vec::new(),
+        };
+    }
+
+    pub(crate) fn confirm_mainline(&mut self) {
+        let Some(repo) = &self.tab.repo else { return };
+        let Some(oid) = self.mainline_target_oid() else {
+            return;
+        };
+
+        let summary = match repo.find_commit(oid).map(|commit| commit.summary().map(str::to_string)) {
+            Ok(summary) => summary,
+            Err(error) => {
+                let context = if self.modal_mainline_kind == OperationKind::Revert { errors::REVERT() } else { errors::CHERRYPICK() };
+                self.close_mainline_modal();
+                self.show_error(errors::with_error(context, error));
+                return;
+            },
+        };
+
+        // git numbers parents from one, the modal lists them from zero.
+        self.pending_mainline = Some(self.modal_mainline_selected.max(0) as u32 + 1);
+        self.modal_mainline_preview.clear();
+        self.modal_mainline_selected = 0;
+        match self.modal_mainline_kind {
+            OperationKind::Revert => {
+                self.modal_input.set_value(operations::reverted(summary.as_deref().unwrap_or(operations::REVERT_COMMIT_FALLBACK())));
+                self.tab.focus = Focus::ModalRevert;
+            },
+            _ => {
+                self.modal_input.set_value(operations::cherrypicked(summary.as_deref().unwrap_or(operations::CHERRYPICK_COMMIT_FALLBACK())));
+                self.tab.focus = Focus::ModalCherrypick;
+            },
+        }
+    }
+
+    pub(crate) fn close_mainline_modal(&mut self) {
+        self.pending_cherrypick_oid = None;
+        self.pending_revert_oid = None;
+        self.pending_mainline = None;
+        self.modal_mainline_selected = 0;
+        self.modal_mainline_preview.clear();
+        self.tab.focus = Focus::Viewport;
+    }
+
+    pub fn on_mark_commit(&mut self) {
+        if self.tab.viewport != Viewport::Graph || self.tab.focus != Focus::Viewport || self.tab.graph_selected == 0 {
+            return;
+        }
+        let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
+            return;
+        };
+
+        if let Some(index) = self.tab.graph_marked_oids.iter().position(|marked| *marked == oid) {
+            self.tab.graph_marked_oids.remove(index);
+        } else {
+            self.tab.graph_marked_oids.push(oid);
+        }
+    }
+
+    pub fn on_mark_range(&mut self) {
+        let Some(repo) = &self.tab.repo else { return };
+        if self.tab.viewport != Viewport::Graph || self.tab.focus != Focus::Viewport || self.tab.graph_selected == 0 {
+            return;
+        }
+        let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
+            return;
+        };
+        let Some(anchor) = self.tab.graph_marked_oids.last().copied() else {
+            self.show_error(errors::MARK_RANGE_NO_ANCHOR());
+            return;
+        };
+
+        // The range runs from the anchor towards the selection, whichever side of it the selection sits on.
+        let commit_graph = self.tab.commit_graph.get_or_init(|| CommitGraph::open(repo).map(Rc::new));
+        let range = match is_descendant_of(repo, commit_graph.as_deref(), anchor, oid) {
+            Ok(true) => range_oids(repo, oid, anchor).map(|mut range| {
+                range.push(oid);
+                range
+            }),
+            Ok(false) => range_oids(repo, anchor, oid),
+            Err(error) => Err(error),
+        };
+
+        match range {
+            Ok(range) => {
+                let range: Vec<Oid> = range.into_iter().filter(|oid| !self.tab.graph_marked_oids.contains(oid)).collect();
+                self.tab.graph_marked_oids.extend(range);
+            },
+            Err(error) => self.show_error(errors::with_error(errors::MARK_RANGE(), error)),
+        }
+    }
+
+    pub fn on_clear_marks(&mut self) {
+        self.tab.graph_marked_oids.clear();
+    }
+
+    pub(crate) fn start_marked_sequence(&mut self, kind: OperationKind) {
+        self.pending_operation_action = Some(PendingOperationAction::StartSequence { kind });
+        self.modal_operation_kind = kind;
+        self.modal_operation_message = operations::applying_marked_commits(self.tab.graph_marked_oids.len());
+        self.tab.focus = Focus::ModalOperationProgress;
+    }
+
     pub fn on_revert(&mut self) {
-        let Some(repo) = &self.repo else { return };
-        if matches!(self.viewport, Viewport::Settings | Viewport::Viewer) || self.focus != Focus::Viewport {
+        let Some(repo) = &self.tab.repo else { return };
+        if matches!(self.tab.viewport, Viewport::Settings | Viewport::Viewer) || self.tab.focus != Focus::Viewport {
             return;
         }
 
@@ -1084,11 +1477,20 @@ impl App {
             return;
         }
 
-        if self.viewport != Viewport::Graph || self.graph_selected == 0 {
+        if self.tab.viewport != Viewport::Graph || self.tab.graph_selected == 0 {
             return;
         }
 
-        let Some(oid) = self.graph_oid_at(self.graph_selected) else {
+        if !self.tab.graph_marked_oids.is_empty() {
+            if self.tab.graph_marked_oids.iter().any(|oid| repo.find_commit(*oid).is_ok_and(|commit| commit.parent_count() > 1)) {
+                self.show_error(errors::REVERT_MERGE_UNSUPPORTED());
+            } else {
+                self.open_batch_modal(BatchAction::Revert);
+            }
+            return;
+        }
+
+        let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
             return;
         };
 
@@ -1099,67 +1501,69 @@ impl App {
         };
 
         match original_message {
-            None => self.show_error(errors::REVERT_MERGE_UNSUPPORTED()),
+            None => self.open_mainline_modal(oid, OperationKind::Revert),
             Some(Ok(original_message)) => {
                 self.pending_revert_oid = Some(oid);
                 self.modal_input.set_value(operations::reverted(&original_message));
-                self.focus = Focus::ModalRevert;
+                self.tab.focus = Focus::ModalRevert;
             },
             Some(Err(error)) => self.show_error(errors::with_error(errors::REVERT(), error)),
         }
     }
 
     pub fn on_rebase(&mut self) {
-        let Some(repo) = &self.repo else { return };
-        if matches!(self.viewport, Viewport::Settings | Viewport::Viewer) || self.focus != Focus::Viewport {
+        let Some(repo) = &self.tab.repo else { return };
+        if matches!(self.tab.viewport, Viewport::Settings | Viewport::Viewer) || self.tab.focus != Focus::Viewport {
             return;
         }
 
-        if is_rebase_in_progress(repo) || is_cherrypick_in_progress(repo) || is_revert_in_progress(repo) || is_merge_in_progress(repo) {
+        if Self::active_operation_kind(repo).is_some() {
             self.on_continue_operation();
             return;
         }
 
-        if self.viewport != Viewport::Graph || self.graph_selected == 0 {
+        if self.tab.viewport != Viewport::Graph || self.tab.graph_selected == 0 {
             return;
         }
 
-        let Some(oid) = self.graph_oid_at(self.graph_selected) else {
+        let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
             return;
         };
-        self.pending_operation_action = Some(PendingOperationAction::Start { kind: OperationKind::Rebase, oid });
-        self.modal_operation_kind = OperationKind::Rebase;
-        self.modal_operation_message = operations::rebasing_selected_commit();
-        self.focus = Focus::ModalOperationProgress;
+        self.open_operation_progress(OperationKind::Rebase, oid, operations::rebasing_selected_commit());
     }
 
     pub fn on_merge(&mut self) {
-        let Some(repo) = &self.repo else { return };
-        if matches!(self.viewport, Viewport::Settings | Viewport::Viewer) || self.focus != Focus::Viewport {
+        let Some(repo) = &self.tab.repo else { return };
+        if matches!(self.tab.viewport, Viewport::Settings | Viewport::Viewer) || self.tab.focus != Focus::Viewport {
             return;
         }
 
-        if is_rebase_in_progress(repo) || is_cherrypick_in_progress(repo) || is_revert_in_progress(repo) || is_merge_in_progress(repo) {
+        if Self::active_operation_kind(repo).is_some() {
             self.on_continue_operation();
             return;
         }
 
-        if self.viewport != Viewport::Graph || self.graph_selected == 0 {
+        if self.tab.viewport != Viewport::Graph || self.tab.graph_selected == 0 {
             return;
         }
 
-        let Some(oid) = self.graph_oid_at(self.graph_selected) else {
+        let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
             return;
         };
-        self.pending_operation_action = Some(PendingOperationAction::Start { kind: OperationKind::Merge, oid });
-        self.modal_operation_kind = OperationKind::Merge;
-        self.modal_operation_message = operations::merging_selected_commit();
-        self.focus = Focus::ModalOperationProgress;
+        self.open_operation_progress(OperationKind::Merge, oid, operations::merging_selected_commit());
+    }
+
+    // The action runs right after the next draw, so the progress message paints first.
+    pub(crate) fn open_operation_progress(&mut self, kind: OperationKind, oid: Oid, message: String) {
+        self.pending_operation_action = Some(PendingOperationAction::Start { kind, oid });
+        self.modal_operation_kind = kind;
+        self.modal_operation_message = message;
+        self.tab.focus = Focus::ModalOperationProgress;
     }
 
     pub fn on_continue_operation(&mut self) {
-        let Some(repo) = &self.repo else { return };
-        if matches!(self.viewport, Viewport::Settings | Viewport::Viewer) || self.focus != Focus::Viewport || Self::active_operation_kind(repo).is_none() {
+        let Some(repo) = &self.tab.repo else { return };
+        if matches!(self.tab.viewport, Viewport::Settings | Viewport::Viewer) || self.tab.focus != Focus::Viewport || Self::active_operation_kind(repo).is_none() {
             return;
         }
 
@@ -1167,12 +1571,12 @@ impl App {
         self.pending_operation_action = Some(PendingOperationAction::Continue);
         self.modal_operation_kind = kind;
         self.modal_operation_message = operations::continuing(kind.label());
-        self.focus = Focus::ModalOperationProgress;
+        self.tab.focus = Focus::ModalOperationProgress;
     }
 
     pub fn on_abort_operation(&mut self) {
-        let Some(repo) = &self.repo else { return };
-        if matches!(self.viewport, Viewport::Settings | Viewport::Viewer) || self.focus != Focus::Viewport || Self::active_operation_kind(repo).is_none() {
+        let Some(repo) = &self.tab.repo else { return };
+        if matches!(self.tab.viewport, Viewport::Settings | Viewport::Viewer) || self.tab.focus != Focus::Viewport || Self::active_operation_kind(repo).is_none() {
             return;
         }
 
@@ -1180,7 +1584,7 @@ impl App {
         self.pending_operation_action = Some(PendingOperationAction::Abort);
         self.modal_operation_kind = kind;
         self.modal_operation_message = operations::aborting(kind.label());
-        self.focus = Focus::ModalOperationProgress;
+        self.tab.focus = Focus::ModalOperationProgress;
     }
 }
 
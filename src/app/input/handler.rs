@@ -1,8 +1,15 @@
 use crate::{
     app::app::{App, Focus, Viewport},
-    helpers::keymap::{Command, InputMode, KeyBinding, command_for_key_binding, load_or_init_keymaps},
+    helpers::keymap::{Command, InputMode, KeyBinding, KeySequenceMatch, load_or_init_keymaps, match_key_sequence},
 };
 use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::time::{Duration, Instant};
+
+// How long a partial key sequence waits for its next key.
+pub const KEY_SEQUENCE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+// Large enough to cross any history, small enough that repeated motions stay quick.
+const MAX_COUNT: usize = 99_999;
 
 impl App {
     pub fn load_keymap(&mut self) {
@@ -13,7 +20,7 @@ impl App {
         let key_binding = KeyBinding::new(key_event.code, key_event.modifiers);
         let current_mode = self.mode;
 
-        if key_event.code == KeyCode::Esc && key_event.modifiers == KeyModifiers::NONE && self.viewport == Viewport::Settings && self.focus == Focus::Viewport {
+        if key_event.code == KeyCode::Esc && key_event.modifiers == KeyModifiers::NONE && self.tab.viewport == Viewport::Settings && self.tab.focus == Focus::Viewport {
             self.on_back();
             self.mode = InputMode::Normal;
             return;
@@ -29,21 +36,173 @@ impl App {
             return;
         }
 
-        let command = self.keymaps.get(&self.mode).and_then(|mode_map| command_for_key_binding(mode_map, &key_binding));
-        if let Some(command) = command {
-            if self.viewport == Viewport::Splash && self.focus == Focus::Viewport {
-                self.dispatch_splash_command(&command);
-            } else {
-                self.dispatch_command(&command);
+        // A new key interrupts a counted motion still waiting on lookups.
+        self.tab.graph_repeat = None;
+
+        if let Some(command) = self.pending_mark.take() {
+            let name = match key_event.code {
+                KeyCode::Char(name) if !key_event.modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) => Some(name),
+                _ => None,
+            };
+            self.handle_pending_mark(command, name);
+            self.mode = InputMode::Normal;
+            return;
+        }
+
+        // Esc abandons a partial sequence or count instead of running Back.
+        if (!self.pending_keys.is_empty() || self.pending_count.is_some()) && key_event.code == KeyCode::Esc && key_event.modifiers == KeyModifiers::NONE {
+            self.clear_pending_keys();
+            self.pending_count = None;
+            self.mode = InputMode::Normal;
+            return;
+        }
+
+        if let Some(digit) = self.count_digit(key_event) {
+            self.pending_count = Some(self.pending_count.unwrap_or(0).saturating_mul(10).saturating_add(digit).min(MAX_COUNT));
+            return;
+        }
+
+        let mut matched = self.match_pending_keys(&key_binding);
+        if matched == KeySequenceMatch::None && !self.pending_keys.is_empty() {
+            // A key that does not continue the sequence counts on its own.
+            self.clear_pending_keys();
+            matched = self.match_pending_keys(&key_binding);
+        }
+
+        match matched {
+            KeySequenceMatch::Prefix(_) => {
+                // Action mode holds until the sequence completes.
+                self.pending_keys.push(key_binding);
+                self.pending_keys_at = Some(Instant::now());
+                return;
+            },
+            KeySequenceMatch::Command(command) => {
+                self.clear_pending_keys();
+                // Any other command drops the count; the prefix with no digits counts as one.
+                match self.pending_count.take() {
+                    Some(count) if Self::is_countable_command(&command) => self.run_counted_command(&command, count.max(1)),
+                    _ => self.run_key_command(&command),
+                }
+            },
+            KeySequenceMatch::None => self.pending_count = None,
+        }
+
+        if current_mode == InputMode::Action {
+            self.mode = InputMode::Normal;
+        }
+    }
+
+    fn match_pending_keys(&self, key_binding: &KeyBinding) -> KeySequenceMatch {
+        self.keymaps.get(&self.mode).map(|mode_map| match_key_sequence(mode_map, &self.pending_keys, key_binding)).unwrap_or(KeySequenceMatch::None)
+    }
+
+    fn run_key_command(&mut self, command: &Command) {
+        if self.tab.viewport == Viewport::Splash && self.tab.focus == Focus::Viewport {
+            self.dispatch_splash_command(command);
+        } else {
+            self.dispatch_command(command);
+        }
+    }
+
+    // Counts are only typed in the graph, and only between sequences. Digits start one after the count prefix,
+    // or on their own when the mode leaves them unbound; 0 cannot start one.
+    fn count_digit(&self, key_event: KeyEvent) -> Option<usize> {
+        if self.mode != InputMode::Normal || self.tab.focus != Focus::Viewport || self.tab.viewport != Viewport::Graph || !self.pending_keys.is_empty() || key_event.modifiers != KeyModifiers::NONE {
+            return None;
+        }
+        let KeyCode::Char(ch) = key_event.code else { return None };
+        let digit = ch.to_digit(10)? as usize;
+        let is_unbound = self.match_pending_keys(&KeyBinding::new(key_event.code, key_event.modifiers)) == KeySequenceMatch::None;
+        (self.pending_count.is_some() || (digit != 0 && is_unbound)).then_some(digit)
+    }
+
+    pub(crate) fn on_count(&mut self) {
+        if self.tab.focus == Focus::Viewport && self.tab.viewport == Viewport::Graph {
+            self.pending_count = Some(0);
+        }
+    }
+
+    pub(crate) fn is_countable_command(command: &Command) -> bool {
+        matches!(
+            command,
+            Command::ScrollUp
+                | Command::ScrollDown
+                | Command::ScrollUpHalf
+                | Command::ScrollDownHalf
+                | Command::ScrollPageUp
+                | Command::ScrollPageDown
+                | Command::ScrollHalfPageUp
+                | Command::ScrollHalfPageDown
+                | Command::ScrollUpBranch
+                | Command::ScrollDownBranch
+                | Command::ScrollUpCommit
+                | Command::ScrollDownCommit
+        )
+    }
+
+    fn run_counted_command(&mut self, command: &Command, count: usize) {
+        match command {
+            Command::ScrollUp => return self.select_graph_index(self.tab.graph_selected.saturating_sub(count)),
+            Command::ScrollDown => return self.select_graph_index(self.tab.graph_selected.saturating_add(count)),
+            _ => {},
+        }
+
+        // Every step moves at least one row, so more steps than rows would be wasted.
+        let count = count.min(self.graph_commit_count().max(1));
+        for step in 0..count {
+            let lookup = self.tab.graph.pending_lookup.as_ref().map(|(request_id, _)| *request_id);
+            self.run_key_command(command);
+            if self.tab.graph.pending_lookup.as_ref().map(|(request_id, _)| *request_id) != lookup {
+                // Lookups answer asynchronously, so each further step waits for the previous result.
+                self.tab.graph_repeat = Some((command.clone(), count - step - 1));
+                return;
             }
         }
+    }
+
+    pub(crate) fn continue_graph_repeat(&mut self, command: Command, remaining: usize) {
+        if remaining == 0 {
+            return;
+        }
+        self.tab.graph_repeat = Some((command.clone(), remaining - 1));
+        let lookup = self.tab.graph.pending_lookup.as_ref().map(|(request_id, _)| *request_id);
+        self.dispatch_command(&command);
+        if self.tab.graph.pending_lookup.as_ref().map(|(request_id, _)| *request_id) == lookup {
+            self.tab.graph_repeat = None;
+        }
+    }
+
+    pub(crate) fn clear_pending_keys(&mut self) {
+        self.pending_keys.clear();
+        self.pending_keys_at = None;
+    }
+
+    // A sequence left hanging runs whatever its keys are bound to on their own, if anything.
+    pub fn expire_pending_keys(&mut self) {
+        if self.pending_keys_at.is_none_or(|at| at.elapsed() < KEY_SEQUENCE_TIMEOUT) {
+            return;
+        }
+
+        let mut pending = std::mem::take(&mut self.pending_keys);
+        self.clear_pending_keys();
+        let Some(last) = pending.pop() else { return };
+        let fallback = self.keymaps.get(&self.mode).map(|mode_map| match_key_sequence(mode_map, &pending, &last));
+        let current_mode = self.mode;
 
+        if let Some(KeySequenceMatch::Prefix(Some(command))) = fallback
+            && command != Command::Leader
+        {
+            self.run_key_command(&command);
+        }
         if current_mode == InputMode::Action {
             self.mode = InputMode::Normal;
         }
     }
 
     pub(crate) fn dispatch_command(&mut self, command: &Command) {
+        if !self.prepare_selection(command) {
+            return;
+        }
         match command {
             Command::WidenScope => self.on_widen_scope(),
             Command::NarrowScope => self.on_narrow_scope(),
@@ -72,6 +231,8 @@ impl App {
             Command::ToggleGraphDates => self.on_toggle_graph_dates(),
             Command::ToggleGraphCommitters => self.on_toggle_graph_committers(),
             Command::ToggleGraphRefs => self.on_toggle_graph_refs(),
+            Command::ToggleGraphSignatures => self.on_toggle_graph_signatures(),
+            Command::ToggleOriginalParents => self.on_toggle_original_parents(),
             Command::ToggleWorktrees => self.on_toggle_worktrees(),
             Command::ToggleSubmodules => self.on_toggle_submodules(),
             Command::ToggleSearch => self.on_toggle_search(),
@@ -79,12 +240,18 @@ impl App {
             Command::ToggleInspector => self.on_toggle_inspector(),
             Command::ToggleShas => self.on_toggle_shas(),
             Command::ToggleHelp => self.on_toggle_help(),
+            Command::CommandPalette => self.on_command_palette(),
             Command::ActionMode => self.on_action_mode(),
+            Command::Leader => self.on_leader(),
             Command::Exit => self.on_exit(),
             Command::RemoveRecentRepository => self.on_remove_recent_repository(),
             Command::MoveRecentRepositoryUp => self.on_move_recent_repository_up(),
             Command::MoveRecentRepositoryDown => self.on_move_recent_repository_down(),
             Command::ReturnToParentRepository => self.on_return_to_parent_repository(),
+            Command::NewTab => self.on_new_tab(),
+            Command::CloseTab => self.on_close_tab(),
+            Command::NextTab => self.on_next_tab(),
+            Command::PreviousTab => self.on_previous_tab(),
             Command::ScrollPageUp => self.on_scroll_page_up(),
             Command::ScrollPageDown => self.on_scroll_page_down(),
             Command::ScrollHalfPageUp => self.on_scroll_half_page_up(),
@@ -101,22 +268,40 @@ impl App {
             Command::ScrollDownCommit => self.on_scroll_down_commit(),
             Command::Find => self.on_find(),
             Command::FindFile => self.on_find_file(),
+            Command::SearchContents => self.on_search_contents(),
+            Command::MarkCommit => self.on_mark_row(),
+            Command::MarkRange => self.on_mark_range(),
+            Command::ClearMarks => self.on_clear_pane_marks(),
+            Command::VisualMode => self.on_visual_mode(),
+            Command::Count => self.on_count(),
+            Command::SetMark => self.on_set_mark(),
+            Command::JumpToMark => self.on_jump_to_mark(),
+            Command::JumpBack => self.on_jump_back(),
+            Command::JumpForward => self.on_jump_forward(),
             Command::SoloBranch => self.on_solo_branch(),
             Command::ToggleBranch => self.on_toggle_branch(),
             Command::ToggleHunkMode => self.on_toggle_hunk_mode(),
             Command::ToggleSplitDiffMode => self.on_toggle_split_diff_mode(),
+            Command::LoadFullDiff => self.on_load_full_diff(),
             Command::Drop => self.on_drop(),
             Command::Pop => self.on_pop(),
             Command::Stash => self.on_stash(),
             Command::FetchAll => self.on_fetch_all(),
+            Command::DeepenHistory => self.on_deepen_history(),
             Command::Checkout => self.on_checkout(),
             Command::HardReset => self.on_hard_reset(),
             Command::MixedReset => self.on_mixed_reset(),
+            Command::SoftReset => self.on_soft_reset(),
+            Command::ResetBranch => self.on_reset_branch(),
             Command::Unstage => self.on_unstage(),
             Command::Stage => self.on_stage(),
             Command::Commit => self.on_commit(),
             Command::ForcePush => self.on_force_push(),
             Command::PushTags => self.on_push_tags(),
+            Command::SkipHooks => self.on_skip_hooks(),
+            Command::ExportGraph => self.on_export_graph(),
+            Command::ExportPatches => self.on_export_patches(),
+            Command::ApplyPatch => self.on_apply_patch(),
             Command::CreateBranch => self.on_create_branch(),
             Command::DeleteBranch => self.on_delete_branch(),
             Command::RenameBranch => self.on_rename_branch(),
@@ -133,12 +318,19 @@ impl App {
             Command::Merge => self.on_merge(),
             Command::ContinueOperation => self.on_continue_operation(),
             Command::AbortOperation => self.on_abort_operation(),
+            Command::Undo => self.on_undo(),
+            Command::ToggleJournal => self.on_toggle_journal(),
             Command::Reload => self.on_reload(),
             Command::ReloadAllBranches => self.on_reload_all_branches(),
+            Command::Custom(name) => self.on_custom_command(name),
+        }
+        // Moving to another pane ends visual mode rather than leaving it to come back later.
+        if self.tab.visual.is_some_and(|visual| visual.focus != self.tab.focus) {
+            self.tab.visual = None;
         }
     }
 
-    fn dispatch_splash_command(&mut self, command: &Command) {
+    pub(crate) fn dispatch_splash_command(&mut self, command: &Command) {
         match command {
             Command::NarrowScope => self.on_narrow_scope(),
             Command::Select => self.on_select(),
@@ -148,6 +340,10 @@ impl App {
             Command::MoveRecentRepositoryUp => self.on_move_recent_repository_up(),
             Command::MoveRecentRepositoryDown => self.on_move_recent_repository_down(),
             Command::ReturnToParentRepository => self.on_return_to_parent_repository(),
+            Command::NewTab => self.on_new_tab(),
+            Command::CloseTab => self.on_close_tab(),
+            Command::NextTab => self.on_next_tab(),
+            Command::PreviousTab => self.on_previous_tab(),
             Command::ScrollPageUp => self.on_scroll_page_up(),
             Command::ScrollPageDown => self.on_scroll_page_down(),
             Command::ScrollHalfPageUp => self.on_scroll_half_page_up(),
@@ -158,7 +354,44 @@ impl App {
             Command::ScrollDownHalf => self.on_scroll_down_half(),
             Command::GoToBeginning => self.on_scroll_to_beginning(),
             Command::GoToEnd => self.on_scroll_to_end(),
+            Command::CommandPalette => self.on_command_palette(),
+            Command::Leader => self.on_leader(),
             _ => {},
         }
     }
+
+    // The commands the start screen reacts to; everything else needs an open repository.
+    pub(crate) fn is_splash_command(command: &Command) -> bool {
+        matches!(
+            command,
+            Command::NarrowScope
+                | Command::Select
+                | Command::Back
+                | Command::Exit
+                | Command::RemoveRecentRepository
+                | Command::MoveRecentRepositoryUp
+                | Command::MoveRecentRepositoryDown
+                | Command::ReturnToParentRepository
+                | Command::NewTab
+                | Command::CloseTab
+                | Command::NextTab
+                | Command::PreviousTab
+                | Command::ScrollPageUp
+                | Command::ScrollPageDown
+                | Command::ScrollHalfPageUp
+                | Command::ScrollHalfPageDown
+                | Command::ScrollUp
+                | Command::ScrollDown
+                | Command::ScrollUpHalf
+                | Command::ScrollDownHalf
+                | Command::GoToBeginning
+                | Command::GoToEnd
+                | Command::CommandPalette
+                | Command::Leader
+        )
+    }
 }
+
+#[cfg(test)]
+#[path = "../../tests/app/input/handler.rs"]
+mod tests;
@@ -0,0 +1,264 @@
+use crate::{
+    app::app::{App, Focus, HookFollowUp, HookTask, Viewport},
+    git::actions::{
+        committing::commit_staged,
+        hooks::{Hook, HookRun, PushedRef, commit_hook_runs, hook_run, pre_push_run, read_commit_message, run_hooks},
+        merging::{MergeOutcome, continue_merge, stage_merge, start_merge},
+        network::NetworkRequest,
+        rebasing::start_rebase,
+        signing::signing_config,
+    },
+    helpers::{
+        keymap::Command,
+        localisation::{errors, operations},
+        process::ProcessLog,
+    },
+};
+use git2::{Oid, Repository};
+use std::{mem, sync::Arc, thread, time::Instant};
+
+// A failed hook usually explains itself in its last few lines.
+const HOOK_ERROR_LINES: usize = 12;
+
+impl App {
+    pub fn on_skip_hooks(&mut self) {
+        if self.tab.repo.is_some() && self.tab.viewport != Viewport::Splash {
+            self.skip_hooks_once = !self.skip_hooks_once;
+        }
+    }
+
+    // Skipping applies to the next hooked operation only.
+    pub(crate) fn take_skip_hooks(&mut self) -> bool {
+        mem::take(&mut self.skip_hooks_once)
+    }
+
+    pub(crate) fn start_hook_task(&mut self, runs: Vec<HookRun>, follow_up: HookFollowUp) {
+        if runs.is_empty() {
+            self.run_hook_follow_up(follow_up);
+            return;
+        }
+
+        let title = runs.iter().map(|run| run.hook.name()).collect::<Vec<_>>().join(", ");
+        let log: ProcessLog = Arc::default();
+        let task_log = log.clone();
+        let handle = thread::spawn(move || run_hooks(&runs, &task_log));
+        self.hook_task = Some(HookTask { title, log, handle, follow_up });
+        self.tab.focus = Focus::ModalHookProgress;
+    }
+
+    pub fn poll_hook_task(&mut self) {
+        if !self.hook_task.as_ref().is_some_and(|task| task.handle.is_finished()) {
+            return;
+        }
+        let Some(task) = self.hook_task.take() else {
+            return;
+        };
+        let result = task.handle.join().unwrap_or_else(|_| Err(git2::Error::from_str(errors::HOOK_PANICKED())));
+
+        // Post hooks cannot undo what already happened, so their exit code is ignored like git does.
+        if let HookFollowUp::Return { focus } = task.follow_up {
+            self.tab.focus = focus;
+            return;
+        }
+
+        let error = match result {
+            Ok(()) => {
+                self.tab.focus = Focus::Viewport;
+                self.run_hook_follow_up(task.follow_up);
+                return;
+            },
+            Err(error) => error,
+        };
+
+        let mut message = errors::with_error(errors::HOOK(), error);
+        if let Ok(lines) = task.log.lock() {
+            let start = lines.len().saturating_sub(HOOK_ERROR_LINES);
+            for line in lines[start..].iter().filter(|line| !line.trim().is_empty()) {
+                message.push('\n');
+                message.push_str(line);
+            }
+        }
+        match task.follow_up {
+            // The prompt keeps its message so it can be fixed and confirmed again.
+            HookFollowUp::Commit { .. } => self.tab.focus = Focus::ModalCommit,
+            HookFollowUp::CommitMerge => {
+                message.push_str("\n\n");
+                message.push_str(errors::HOOK_MERGE_STAGED());
+                self.tab.focus = Focus::Viewport;
+            },
+            _ => self.tab.focus = Focus::Viewport,
+        }
+        self.show_error(message);
+        self.reload(None);
+    }
+
+    fn run_hook_follow_up(&mut self, follow_up: HookFollowUp) {
+        match follow_up {
+            HookFollowUp::Commit { message } => self.commit_after_hooks(message),
+            HookFollowUp::Rebase { oid } => {
+                let Some(repo) = self.open_operation_repo() else {
+                    return;
+                };
+                self.begin_journal(Command::Rebase);
+                self.handle_rebase_result(start_rebase(&repo, oid));
+            },
+            HookFollowUp::CommitMerge => {
+                let Some(repo) = self.open_operation_repo() else {
+                    return;
+                };
+                self.begin_journal(Command::Merge);
+                self.finish_merge(continue_merge(&repo));
+            },
+            HookFollowUp::Network { request, journal } => {
+                if let Some(command) = journal {
+                    self.begin_journal(command);
+                }
+                self.start_network_request(request);
+            },
+            HookFollowUp::Return { focus } => self.tab.focus = focus,
+        }
+    }
+
+    pub(crate) fn confirm_commit(&mut self) {
+        let Some(repo) = self.tab.repo.clone() else {
+            return;
+        };
+        let message = self.modal_input.value().to_string();
+        let is_verifying = !self.take_skip_hooks();
+        match commit_hook_runs(&repo, &message, is_verifying) {
+            Ok(runs) => {
+                // Message hooks may rewrite COMMIT_EDITMSG, so it is read back once they pass.
+                let message = if runs.iter().any(|run| run.hook != Hook::PreCommit) { None } else { Some(message) };
+                self.start_hook_task(runs, HookFollowUp::Commit { message });
+            },
+            Err(error) => self.show_error(errors::with_error(errors::HOOK(), error)),
+        }
+    }
+
+    fn commit_after_hooks(&mut self, message: Option<String>) {
+        let Some(repo) = self.tab.repo.clone() else {
+            return;
+        };
+        let message = match message.map_or_else(|| read_commit_message(&repo), Ok) {
+            Ok(message) => message,
+            Err(error) => {
+                self.tab.focus = Focus::ModalCommit;
+                self.show_error(errors::with_error(errors::COMMIT(), error));
+                return;
+            },
+        };
+        if message.trim().is_empty() {
+            self.tab.focus = Focus::ModalCommit;
+            self.show_error(errors::COMMIT_EMPTY_MESSAGE());
+            return;
+        }
+
+        self.begin_journal(Command::Commit);
+        // Signing may wait on gpg or ssh-keygen, so signed commits are made on a worker.
+        if matches!(signing_config(&repo), Ok(Some(_))) {
+            let (path, name, email) = (repo.path().to_path_buf(), self.tab.name.clone(), self.tab.email.clone());
+            self.commit_task = Some(thread::spawn(move || commit_staged(&Repository::open(path)?, &message, &name, &email)));
+            self.status_notice = Some((operations::COMMIT_SIGNING().to_string(), Instant::now()));
+            self.tab.focus = Focus::Viewport;
+            return;
+        }
+        let result = commit_staged(&repo, &message, &self.tab.name, &self.tab.email);
+        self.finish_commit(result);
+    }
+
+    pub fn poll_commit_task(&mut self) {
+        if !self.commit_task.as_ref().is_some_and(|handle| handle.is_finished()) {
+            return;
+        }
+        let Some(handle) = self.commit_task.take() else {
+            return;
+        };
+        self.status_notice = None;
+        self.finish_commit(handle.join().unwrap_or_else(|_| Err(git2::Error::from_str(errors::COMMIT_PANICKED()))));
+    }
+
+    fn finish_commit(&mut self, result: Result<Oid, git2::Error>) {
+        match result {
+            Ok(_) => {
+                self.modal_input.clear();
+                self.reload(None);
+                self.tab.focus = Focus::Viewport;
+                let runs = self.tab.repo.clone().and_then(|repo| hook_run(&repo, Hook::PostCommit, Vec::new(), Vec::new())).into_iter().collect();
+                self.start_hook_task(runs, HookFollowUp::Return { focus: Focus::Viewport });
+            },
+            Err(error) => {
+                // The prompt keeps its message so it can be confirmed again.
+                self.tab.focus = Focus::ModalCommit;
+                self.show_error(errors::with_error(errors::COMMIT(), error));
+            },
+        }
+    }
+
+    // `pre-rebase` gets the upstream; a rejection leaves the branch untouched.
+    pub(crate) fn start_rebase_with_hooks(&mut self, repo: &Repository, oid: Oid) {
+        let is_verifying = !self.take_skip_hooks();
+        match hook_run(repo, Hook::PreRebase, vec![oid.to_string()], Vec::new()).filter(|_| is_verifying) {
+            Some(run) => {
+                // The journal entry starts once the hook lets the rebase through.
+                self.tab.pending_journal.pop_back();
+                self.start_hook_task(vec![run], HookFollowUp::Rebase { oid });
+            },
+            None => self.handle_rebase_result(start_rebase(repo, oid)),
+        }
+    }
+
+    // With a `pre-merge-commit` hook, a clean merge is staged first and committed once the hook passes.
+    pub(crate) fn start_merge_with_hooks(&mut self, repo: &Repository, oid: Oid) {
+        let is_verifying = !self.take_skip_hooks();
+        let Some(run) = hook_run(repo, Hook::PreMergeCommit, Vec::new(), Vec::new()).filter(|_| is_verifying) else {
+            self.finish_merge(start_merge(repo, oid));
+            return;
+        };
+        match stage_merge(repo, oid) {
+            Ok(MergeOutcome::Staged) => {
+                self.modal_operation_message.clear();
+                self.start_hook_task(vec![run], HookFollowUp::CommitMerge);
+            },
+            result => self.finish_merge(result),
+        }
+    }
+
+    // `post-merge` follows merges that moved the branch; its argument flags a squash, which ours never is.
+    fn finish_merge(&mut self, result: Result<MergeOutcome, git2::Error>) {
+        let is_merged = matches!(result, Ok(MergeOutcome::Completed { .. } | MergeOutcome::FastForward { .. }));
+        self.handle_merge_result(result);
+        let Some(repo) = self.tab.repo.clone().filter(|_| is_merged) else {
+            return;
+        };
+        let runs = hook_run(&repo, Hook::PostMerge, vec!["0".to_string()], Vec::new()).into_iter().collect();
+        let focus = self.tab.focus;
+        self.start_hook_task(runs, HookFollowUp::Return { focus });
+    }
+
+    // Pushes go through `pre-push` first unless hooks are skipped for this push.
+    pub(crate) fn start_push_request(&mut self, request: NetworkRequest, journal: Option<Command>) {
+        let is_verifying = !self.take_skip_hooks();
+        let Some(repo) = self.tab.repo.clone().filter(|_| is_verifying) else {
+            self.run_hook_follow_up(HookFollowUp::Network { request, journal });
+            return;
+        };
+        let pushed = match &request {
+            NetworkRequest::PushBranch { remote_name, branch, .. } => Some((remote_name.clone(), PushedRef::Branch { branch: branch.clone() })),
+            NetworkRequest::PushTags { remote_name, .. } => Some((remote_name.clone(), PushedRef::Tags)),
+            NetworkRequest::DeleteRemoteBranches { remote_name, branches, .. } => Some((remote_name.clone(), PushedRef::Delete { branches: branches.clone() })),
+            NetworkRequest::Fetch { .. } | NetworkRequest::Deepen { .. } | NetworkRequest::UpdateSubmodule { .. } => None,
+        };
+        let run = match pushed.map(|(remote_name, pushed)| pre_push_run(&repo, &remote_name, pushed)).transpose() {
+            Ok(run) => run.flatten(),
+            Err(error) => {
+                self.show_error(errors::with_error(errors::HOOK(), error));
+                return;
+            },
+        };
+        self.start_hook_task(run.into_iter().collect(), HookFollowUp::Network { request, journal });
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/hooks.rs"]
+mod tests;
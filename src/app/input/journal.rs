@@ -0,0 +1,109 @@
+use crate::{
+    app::app::{App, Focus, Viewport},
+    git::actions::journal::{JournalEntry, RefState, capture_ref_state, journal_entry, undo_journal_entry},
+    helpers::{journal::MAX_JOURNAL_ENTRIES, keymap::Command, localisation::errors},
+};
+
+impl App {
+    // Snapshots refs before an action; the entry is completed on the next reload or error.
+    pub(crate) fn begin_journal(&mut self, operation: Command) {
+        if let Some(state) = self.tab.repo.as_ref().and_then(|repo| capture_ref_state(repo).ok()) {
+            self.tab.pending_journal.push_back((operation, state));
+        }
+    }
+
+    // Each queued action owns the changes made before the next one started.
+    pub(crate) fn finish_journal(&mut self) {
+        if self.tab.pending_journal.is_empty() {
+            return;
+        }
+        let Some(after) = self.tab.repo.as_ref().and_then(|repo| capture_ref_state(repo).ok()) else {
+            self.tab.pending_journal.clear();
+            return;
+        };
+
+        let pending: Vec<(Command, RefState)> = self.tab.pending_journal.drain(..).collect();
+        let entries: Vec<JournalEntry> = pending
+            .iter()
+            .enumerate()
+            .filter_map(|(index, (operation, before))| {
+                let next = pending.get(index + 1).map(|(_, state)| state).unwrap_or(&after);
+                journal_entry(operation.clone(), before, next)
+            })
+            .collect();
+        if entries.is_empty() {
+            return;
+        }
+
+        self.tab.journal.extend(entries);
+        if self.tab.journal.len() > MAX_JOURNAL_ENTRIES {
+            let overflow = self.tab.journal.len() - MAX_JOURNAL_ENTRIES;
+            self.tab.journal.drain(..overflow);
+        }
+        self.save_journal();
+    }
+
+    pub fn on_undo(&mut self) {
+        if self.tab.repo.is_none() || self.is_modal_focus() {
+            return;
+        }
+        if self.tab.journal.is_empty() {
+            self.show_error(errors::UNDO_EMPTY());
+            return;
+        }
+        self.undo_journal_entries(1);
+    }
+
+    pub fn on_toggle_journal(&mut self) {
+        if self.tab.focus == Focus::ModalJournal {
+            self.modal_journal_selected = 0;
+            self.tab.focus = Focus::Viewport;
+            return;
+        }
+        if self.tab.repo.is_none() || self.is_modal_focus() || self.tab.viewport == Viewport::Splash || self.tab.viewport == Viewport::Settings {
+            return;
+        }
+
+        self.modal_journal_selected = 0;
+        self.tab.focus = Focus::ModalJournal;
+    }
+
+    // Undoes the newest `count` entries, stopping at the first one that can no longer be restored.
+    pub(crate) fn undo_journal_entries(&mut self, count: usize) {
+        let Some(repo) = self.tab.repo.clone() else {
+            return;
+        };
+
+        let mut undone = 0;
+        let mut failure = None;
+        while undone < count {
+            let Some(entry) = self.tab.journal.last() else {
+                break;
+            };
+            match undo_journal_entry(&repo, entry) {
+                Ok(()) => {
+                    self.tab.journal.pop();
+                    undone += 1;
+                },
+                Err(error) => {
+                    failure = Some(error);
+                    break;
+                },
+            }
+        }
+
+        if undone > 0 {
+            self.save_journal();
+        }
+        self.modal_journal_selected = 0;
+        self.tab.focus = Focus::Viewport;
+        self.reload(None);
+        if let Some(error) = failure {
+            self.show_error(errors::with_error(errors::UNDO(), error));
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/journal.rs"]
+mod tests;
@@ -0,0 +1,136 @@
+use crate::{
+    app::app::{App, Focus, PendingGraphLookup, Viewport},
+    core::graph_service::GraphLookupKind,
+    helpers::{
+        keymap::Command,
+        localisation::errors,
+        marks::{is_mark_name, save_marks, save_marks_to_path},
+    },
+};
+use git2::Oid;
+
+// Like vim, the oldest jumps fall off once the list is full.
+pub const MAX_JUMPS: usize = 100;
+
+impl App {
+    fn is_graph_focus(&self) -> bool {
+        self.tab.focus == Focus::Viewport && self.tab.viewport == Viewport::Graph
+    }
+
+    fn selected_commit_oid(&self) -> Option<Oid> {
+        if self.tab.graph_selected == 0 {
+            return None;
+        }
+        self.graph_oid_at(self.tab.graph_selected).filter(|oid| !oid.is_zero())
+    }
+
+    // The next key names the mark, see `handle_pending_mark`.
+    pub fn on_set_mark(&mut self) {
+        if self.is_graph_focus() {
+            self.pending_mark = Some(Command::SetMark);
+        }
+    }
+
+    pub fn on_jump_to_mark(&mut self) {
+        if self.is_graph_focus() {
+            self.pending_mark = Some(Command::JumpToMark);
+        }
+    }
+
+    // Any key that is not a letter cancels.
+    pub(crate) fn handle_pending_mark(&mut self, command: Command, name: Option<char>) {
+        let Some(name) = name.filter(|name| is_mark_name(*name)) else { return };
+        match command {
+            Command::SetMark => self.set_mark(name),
+            Command::JumpToMark => self.jump_to_mark(name),
+            _ => {},
+        }
+    }
+
+    pub(crate) fn set_mark(&mut self, name: char) {
+        let Some(oid) = self.selected_commit_oid() else { return };
+        self.tab.graph_marks.insert(name, oid);
+        self.save_marks();
+    }
+
+    pub(crate) fn jump_to_mark(&mut self, name: char) {
+        let Some(oid) = self.tab.graph_marks.get(&name).copied() else {
+            self.show_error(errors::with_error(errors::MARK_NOT_SET(), name));
+            return;
+        };
+        self.record_jump();
+        self.jump_to_oid(oid);
+    }
+
+    pub fn save_marks(&self) {
+        let Some(repo_path) = &self.tab.path else { return };
+        if let Some(path) = &self.marks_save_path {
+            save_marks_to_path(path.as_path(), repo_path, &self.tab.graph_marks);
+        } else {
+            save_marks(repo_path, &self.tab.graph_marks);
+        }
+    }
+
+    // Rows of mark name and commit for the which-key popup.
+    pub(crate) fn mark_rows(&self) -> Vec<(String, String)> {
+        self.tab
+            .graph_marks
+            .iter()
+            .map(|(name, oid)| {
+                let summary = self.tab.repo.as_ref().and_then(|repo| repo.find_commit(*oid).ok()).and_then(|commit| commit.summary().map(str::to_string)).unwrap_or_default();
+                (name.to_string(), format!("{:.7} {summary}", oid.to_string()).trim_end().to_string())
+            })
+            .collect()
+    }
+
+    // Remembers the selected commit as the place to come back to; false when nothing is selected.
+    pub(crate) fn record_jump(&mut self) -> bool {
+        let Some(oid) = self.selected_commit_oid() else { return false };
+        self.tab.jump_list.retain(|entry| *entry != oid);
+        self.tab.jump_list.push(oid);
+        if self.tab.jump_list.len() > MAX_JUMPS {
+            self.tab.jump_list.remove(0);
+        }
+        self.tab.jump_index = self.tab.jump_list.len();
+        true
+    }
+
+    pub fn on_jump_back(&mut self) {
+        if !self.is_graph_focus() {
+            return;
+        }
+        // Leaving the newest end records where we are, so jumping forward can return to it.
+        if self.tab.jump_index >= self.tab.jump_list.len() && self.record_jump() {
+            self.tab.jump_index = self.tab.jump_list.len() - 1;
+        }
+        let Some(target) = self.tab.jump_index.checked_sub(1) else { return };
+        self.tab.jump_index = target;
+        self.jump_to_oid(self.tab.jump_list[target]);
+    }
+
+    pub fn on_jump_forward(&mut self) {
+        if !self.is_graph_focus() || self.tab.jump_index + 1 >= self.tab.jump_list.len() {
+            return;
+        }
+        self.tab.jump_index += 1;
+        self.jump_to_oid(self.tab.jump_list[self.tab.jump_index]);
+    }
+
+    // Jumps go by commit rather than row, so they survive reloads that shift the graph.
+    fn jump_to_oid(&mut self, oid: Oid) {
+        if self.tab.graph_tx.is_some() {
+            self.request_graph_lookup(GraphLookupKind::Oid { oid }, PendingGraphLookup::Jump);
+            return;
+        }
+
+        let index = self.tab.oids.aliases.get(&oid).and_then(|alias| self.tab.oids.get_sorted_aliases().iter().position(|sorted| sorted == alias));
+        match index {
+            Some(index) => self.select_graph_index(index),
+            None => self.show_error(errors::JUMP_TARGET_NOT_IN_GRAPH()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/jumps.rs"]
+mod tests;
@@ -3,16 +3,15 @@ use crate::{
     core::graph_service::GraphLookupKind,
     git::actions::{
         branching::{create_branch, rename_branch},
-        cherrypicking::{CherrypickOutcome, start_cherrypick},
-        committing::commit_staged,
-        reverting::{RevertOutcome, start_revert},
+        cherrypicking::{CherrypickOutcome, start_cherrypick_with_mainline},
+        reverting::{RevertOutcome, start_revert_with_mainline},
         tagging::tag,
         worktrees::{create_worktree, is_valid_worktree_name, lock_worktree},
     },
-    git::queries::{diffs::get_filenames_diff_at_oid, files::search_tracked_files},
+    git::queries::files::search_tracked_files,
     helpers::{
         branch_visibility::save_branch_visibility,
-        keymap::{KeyBinding, rebind_keymap_selection, save_keymaps, save_keymaps_to_path},
+        keymap::{Command, KeyBinding, KeymapEditError, rebind_keymap_selection, save_keymaps, save_keymaps_to_path},
         localisation::{errors, operations},
     },
 };
@@ -21,18 +20,21 @@ use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::path::PathBuf;
 
 const FILE_SEARCH_RESULT_LIMIT: usize = 50;
+const KEY_CAPTURE_SEQUENCE_LIMIT: usize = 4;
 
 impl App {
     pub fn show_error(&mut self, message: impl Into<String>) {
-        if self.focus != Focus::ModalError {
-            self.modal_error_return_focus = self.focus;
+        // A failed action may still have moved refs part way through.
+        self.finish_journal();
+        if self.tab.focus != Focus::ModalError {
+            self.modal_error_return_focus = self.tab.focus;
         }
         self.modal_error_message = message.into();
-        self.focus = Focus::ModalError;
+        self.tab.focus = Focus::ModalError;
     }
 
     fn close_error_modal(&mut self) {
-        self.focus = self.modal_error_return_focus;
+        self.tab.focus = self.modal_error_return_focus;
         self.modal_error_message.clear();
     }
 
@@ -40,7 +42,7 @@ impl App {
         self.modal_key_capture_selection = None;
         self.modal_key_capture_candidate = None;
         self.modal_key_capture_error = None;
-        self.focus = Focus::Viewport;
+        self.tab.focus = Focus::Viewport;
     }
 
     fn is_key_capture_cancel(key_event: &KeyEvent) -> bool {
@@ -97,7 +99,31 @@ impl App {
             return true;
         }
 
-        self.preview_key_capture_candidate(KeyBinding::new(key_event.code, key_event.modifiers));
+        // Backspace takes back the last key of the shortcut being recorded.
+        if key_event.code == KeyCode::Backspace
+            && key_event.modifiers == KeyModifiers::NONE
+            && let Some(candidate) = &self.modal_key_capture_candidate
+        {
+            let mut keys = candidate.keys();
+            keys.pop();
+            match KeyBinding::sequence(&keys) {
+                Some(shorter) => self.preview_key_capture_candidate(shorter),
+                None => {
+                    self.modal_key_capture_candidate = None;
+                    self.modal_key_capture_error = None;
+                },
+            }
+            return true;
+        }
+
+        // Further keys grow the candidate into a sequence, unless it clashed in a way more keys cannot fix.
+        let key_binding = KeyBinding::new(key_event.code, key_event.modifiers);
+        let extends = matches!(self.modal_key_capture_error, None | Some(KeymapEditError::PrefixConflict { .. }));
+        let candidate = match &self.modal_key_capture_candidate {
+            Some(candidate) if extends && candidate.keys().len() < KEY_CAPTURE_SEQUENCE_LIMIT => KeyBinding::sequence(&[candidate.keys(), vec![key_binding.clone()]].concat()).unwrap_or(key_binding),
+            _ => key_binding,
+        };
+        self.preview_key_capture_candidate(candidate);
         true
     }
 
@@ -133,7 +159,7 @@ impl App {
     }
 
     fn refresh_file_search_results(&mut self) {
-        let Some(repo) = &self.repo else {
+        let Some(repo) = &self.tab.repo else {
             self.modal_file_search_results.clear();
             self.modal_file_search_selected = 0;
             self.modal_file_search_scroll.set(0);
@@ -150,7 +176,7 @@ impl App {
         self.modal_file_search_results.clear();
         self.modal_file_search_selected = 0;
         self.modal_file_search_scroll.set(0);
-        self.focus = self.modal_file_search_return_focus;
+        self.tab.focus = self.modal_file_search_return_focus;
         self.modal_file_search_return_focus = Focus::Viewport;
     }
 
@@ -183,7 +209,7 @@ impl App {
         self.layout_config.is_search = true;
         self.mark_viewer_layout_dirty();
         self.save_layout();
-        self.focus = Focus::Search;
+        self.tab.focus = Focus::Search;
         self.request_file_history_search(path);
     }
 
@@ -226,63 +252,93 @@ impl App {
 
         let selected = self.settings_selected;
         let scroll = self.settings_scroll.get();
-        let should_reload = self.repo.is_some() && self.layout_config.graph_lane_limit != limit;
+        let should_reload = self.tab.repo.is_some() && self.layout_config.graph_lane_limit != limit;
         self.layout_config.graph_lane_limit = limit;
         self.save_layout();
         self.modal_input.clear();
-        self.focus = Focus::Viewport;
+        self.tab.focus = Focus::Viewport;
         if should_reload {
             self.reload(None);
-            self.viewport = Viewport::Settings;
-            self.focus = Focus::Viewport;
+            self.tab.viewport = Viewport::Settings;
+            self.tab.focus = Focus::Viewport;
             self.settings_selected = selected;
             self.settings_scroll.set(scroll);
         }
     }
 
+    // Only files opened from now on use the new limit; reloading the graph is not needed.
+    fn confirm_viewer_file_size_limit_input(&mut self) {
+        let Ok(limit) = self.modal_input.value().trim().parse::<u64>() else {
+            return;
+        };
+        if limit == 0 {
+            return;
+        }
+
+        self.layout_config.viewer_file_size_limit = limit;
+        self.save_layout();
+        self.modal_input.clear();
+        self.tab.focus = Focus::Viewport;
+    }
+
     pub(super) fn handle_modal_key_event(&mut self, key_event: KeyEvent) -> bool {
         if key_event.code == KeyCode::Esc && key_event.modifiers == KeyModifiers::NONE && self.is_dismissible_modal_focus() {
             self.on_back();
             return true;
         }
 
-        if self.focus == Focus::ModalKeyCapture {
+        if self.tab.focus == Focus::ModalKeyCapture {
             return self.handle_key_capture_event(key_event);
         }
 
-        if self.focus == Focus::ModalAuth {
+        if self.tab.focus == Focus::ModalAuth {
             return self.handle_auth_event(key_event);
         }
 
-        if self.focus == Focus::ModalError {
+        if self.tab.focus == Focus::ModalError {
             if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
                 self.close_error_modal();
             }
             return true;
         }
 
-        if matches!(self.focus, Focus::ModalOperationConflict | Focus::ModalOperationSuccess) {
+        if matches!(self.tab.focus, Focus::ModalOperationConflict | Focus::ModalOperationSuccess) {
             if matches!(key_event.code, KeyCode::Enter | KeyCode::Esc) {
                 self.modal_operation_message.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 self.reload(None);
             }
             return true;
         }
 
-        if self.focus == Focus::ModalOperationProgress {
+        if self.tab.focus == Focus::ModalOperationProgress {
             return true;
         }
 
-        if self.focus == Focus::ModalNetworkProgress {
+        if self.tab.focus == Focus::ModalNetworkProgress || self.tab.focus == Focus::ModalHookProgress {
             return true;
         }
 
-        if self.focus == Focus::ModalFileSearch {
+        if self.tab.focus == Focus::ModalCustomCommand {
+            if matches!(key_event.code, KeyCode::Esc | KeyCode::Enter) {
+                self.close_custom_command_modal();
+            }
+            return true;
+        }
+
+        if self.tab.focus == Focus::ModalFileSearch {
             return self.handle_file_search_event(key_event);
         }
 
-        if self.focus == Focus::ModalGraphLaneLimit {
+        if self.tab.focus == Focus::ModalContentSearch {
+            return self.handle_content_search_event(key_event);
+        }
+
+        if self.tab.focus == Focus::ModalCommandPalette {
+            return self.handle_palette_event(key_event);
+        }
+
+        if self.tab.focus == Focus::ModalGraphLaneLimit {
             match key_event.code {
                 KeyCode::Enter => self.confirm_graph_lane_limit_input(),
                 _ => self.modal_input.on_key(key_event),
@@ -290,7 +346,41 @@ impl App {
             return true;
         }
 
-        if self.focus == Focus::ModalRemoveWorktree {
+        if self.tab.focus == Focus::ModalViewerFileSizeLimit {
+            match key_event.code {
+                KeyCode::Enter => self.confirm_viewer_file_size_limit_input(),
+                _ => self.modal_input.on_key(key_event),
+            }
+            return true;
+        }
+
+        if self.tab.focus == Focus::ModalDeepen {
+            match key_event.code {
+                KeyCode::Enter => self.confirm_deepen_input(),
+                _ => self.modal_input.on_key(key_event),
+            }
+            return true;
+        }
+
+        if self.tab.focus == Focus::ModalReset {
+            match key_event.code {
+                KeyCode::Esc => self.close_reset_modal(),
+                KeyCode::Enter => self.confirm_reset(),
+                _ => {},
+            }
+            return true;
+        }
+
+        if self.tab.focus == Focus::ModalBatch {
+            match key_event.code {
+                KeyCode::Esc => self.close_batch_modal(),
+                KeyCode::Enter => self.confirm_batch(),
+                _ => {},
+            }
+            return true;
+        }
+
+        if self.tab.focus == Focus::ModalRemoveWorktree {
             match key_event.code {
                 KeyCode::Esc => {
                     self.close_worktree_modal();
@@ -301,25 +391,15 @@ impl App {
             return true;
         }
 
-        match self.focus {
+        match self.tab.focus {
             Focus::ModalCommit => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.modal_input.clear();
                     },
-                    KeyCode::Enter => {
-                        if let Some(repo) = &self.repo {
-                            match commit_staged(repo, self.modal_input.value(), &self.name, &self.email) {
-                                Ok(_) => {
-                                    self.modal_input.clear();
-                                    self.reload(None);
-                                    self.focus = Focus::Viewport;
-                                },
-                                Err(error) => self.show_error(errors::with_error(errors::COMMIT(), error)),
-                            }
-                        }
-                    },
+                    KeyCode::Enter => self.confirm_commit(),
+                    KeyCode::Tab => self.skip_hooks_once = !self.skip_hooks_once,
                     _ => {
                         self.modal_input.on_key(key_event);
                     },
@@ -329,12 +409,13 @@ impl App {
             Focus::ModalCherrypick => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.modal_input.clear();
                         self.pending_cherrypick_oid = None;
+                        self.pending_mainline = None;
                     },
                     KeyCode::Enter => {
-                        let Some(repo) = &self.repo else {
+                        let Some(repo) = self.tab.repo.clone() else {
                             return true;
                         };
                         let Some(oid) = self.pending_cherrypick_oid else {
@@ -346,16 +427,19 @@ impl App {
                             return true;
                         }
 
-                        match start_cherrypick(repo, oid, &message) {
+                        self.begin_journal(Command::Cherrypick);
+                        match start_cherrypick_with_mainline(&repo, oid, self.pending_mainline, &message) {
                             Ok(CherrypickOutcome::Committed { .. }) => {
                                 self.modal_input.clear();
                                 self.pending_cherrypick_oid = None;
+                                self.pending_mainline = None;
                                 self.reload(None);
-                                self.focus = Focus::Viewport;
+                                self.tab.focus = Focus::Viewport;
                             },
                             Ok(CherrypickOutcome::Conflict) => {
                                 self.modal_input.clear();
                                 self.pending_cherrypick_oid = None;
+                                self.pending_mainline = None;
                                 self.show_operation_conflict(crate::app::app::OperationKind::Cherrypick, operations::CHERRYPICK_CONFLICT());
                             },
                             Ok(CherrypickOutcome::Aborted) => {},
@@ -371,12 +455,13 @@ impl App {
             Focus::ModalRevert => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.modal_input.clear();
                         self.pending_revert_oid = None;
+                        self.pending_mainline = None;
                     },
                     KeyCode::Enter => {
-                        let Some(repo) = &self.repo else {
+                        let Some(repo) = self.tab.repo.clone() else {
                             return true;
                         };
                         let Some(oid) = self.pending_revert_oid else {
@@ -388,16 +473,19 @@ impl App {
                             return true;
                         }
 
-                        match start_revert(repo, oid, &message) {
+                        self.begin_journal(Command::Revert);
+                        match start_revert_with_mainline(&repo, oid, self.pending_mainline, &message) {
                             Ok(RevertOutcome::Committed { .. }) => {
                                 self.modal_input.clear();
                                 self.pending_revert_oid = None;
+                                self.pending_mainline = None;
                                 self.reload(None);
-                                self.focus = Focus::Viewport;
+                                self.tab.focus = Focus::Viewport;
                             },
                             Ok(RevertOutcome::Conflict) => {
                                 self.modal_input.clear();
                                 self.pending_revert_oid = None;
+                                self.pending_mainline = None;
                                 self.show_operation_conflict(OperationKind::Revert, operations::REVERT_CONFLICT());
                             },
                             Ok(RevertOutcome::Aborted) => {},
@@ -413,22 +501,23 @@ impl App {
             Focus::ModalCreateBranch => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.clear_pending_branch_target();
                         self.modal_input.clear();
                     },
                     KeyCode::Enter => {
-                        if let Some(repo) = &self.repo {
+                        if let Some(repo) = self.tab.repo.clone() {
                             let Some(oid) = self.selected_branch_target_oid() else {
                                 self.show_error(errors::CREATE_BRANCH_NO_COMMIT());
                                 return true;
                             };
-                            match create_branch(repo, self.modal_input.value(), oid) {
+                            self.begin_journal(Command::CreateBranch);
+                            match create_branch(&repo, self.modal_input.value(), oid) {
                                 Ok(_) => {
                                     self.modal_input.clear();
                                     self.clear_pending_branch_target();
                                     self.reload(None);
-                                    self.focus = Focus::Viewport;
+                                    self.tab.focus = Focus::Viewport;
                                 },
                                 Err(error) => self.show_error(errors::with_error(errors::CREATE_BRANCH(), error)),
                             }
@@ -443,30 +532,31 @@ impl App {
             Focus::ModalRenameBranch => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.modal_rename_branch_source = None;
                         self.modal_input.clear();
                     },
                     KeyCode::Enter => {
-                        if let Some(repo) = self.repo.clone() {
+                        if let Some(repo) = self.tab.repo.clone() {
                             let Some(source) = self.modal_rename_branch_source.clone() else {
                                 self.show_error(errors::RENAME_BRANCH_NO_PENDING());
                                 return true;
                             };
                             let new_name = self.modal_input.value().trim().to_string();
+                            self.begin_journal(Command::RenameBranch);
                             match rename_branch(&repo, &source, &new_name) {
                                 Ok(_) => {
-                                    if self.branches.hidden_branch_names.contains(source.as_str()) {
-                                        self.branches.hidden_branch_names.remove(source.as_str());
-                                        self.branches.hidden_branch_names.insert(new_name);
-                                        if let Some(path) = &self.path {
-                                            save_branch_visibility(path, &self.branches.hidden_branch_names);
+                                    if self.tab.branches.hidden_branch_names.contains(source.as_str()) {
+                                        self.tab.branches.hidden_branch_names.remove(source.as_str());
+                                        self.tab.branches.hidden_branch_names.insert(new_name);
+                                        if let Some(path) = &self.tab.path {
+                                            save_branch_visibility(path, &self.tab.branches.hidden_branch_names);
                                         }
                                     }
                                     self.modal_input.clear();
                                     self.modal_rename_branch_source = None;
                                     self.reload(None);
-                                    self.focus = Focus::Viewport;
+                                    self.tab.focus = Focus::Viewport;
                                 },
                                 Err(error) => self.show_error(errors::with_error(errors::RENAME_BRANCH(), error)),
                             }
@@ -481,7 +571,7 @@ impl App {
             Focus::ModalCreateWorktreeName => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.modal_input.clear();
                         self.modal_worktree_name.clear();
                     },
@@ -495,7 +585,7 @@ impl App {
                         let path = self.default_worktree_path(&name);
                         self.modal_worktree_name = name;
                         self.modal_input.set_value(path);
-                        self.focus = Focus::ModalCreateWorktreePath;
+                        self.tab.focus = Focus::ModalCreateWorktreePath;
                     },
                     _ => {
                         self.modal_input.on_key(key_event);
@@ -506,12 +596,12 @@ impl App {
             Focus::ModalCreateWorktreePath => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.modal_input.clear();
                         self.modal_worktree_name.clear();
                     },
                     KeyCode::Enter => {
-                        let Some(repo) = self.repo.clone() else {
+                        let Some(repo) = self.tab.repo.clone() else {
                             return true;
                         };
                         let name = self.modal_worktree_name.clone();
@@ -521,7 +611,7 @@ impl App {
                             return true;
                         }
 
-                        let Some(oid) = self.graph_oid_at(self.graph_selected) else {
+                        let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
                             self.show_error(errors::CREATE_WORKTREE_NO_COMMIT());
                             return true;
                         };
@@ -529,7 +619,7 @@ impl App {
                             Ok(_) => {
                                 self.modal_input.clear();
                                 self.modal_worktree_name.clear();
-                                self.focus = Focus::Viewport;
+                                self.tab.focus = Focus::Viewport;
                                 self.reload(None);
                             },
                             Err(error) => self.show_error(errors::with_error(errors::CREATE_WORKTREE(), error)),
@@ -544,21 +634,21 @@ impl App {
             Focus::ModalLockWorktree => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Worktrees;
+                        self.tab.focus = Focus::Worktrees;
                         self.modal_input.clear();
                     },
                     KeyCode::Enter => {
-                        let Some(repo) = self.repo.clone() else {
+                        let Some(repo) = self.tab.repo.clone() else {
                             return true;
                         };
-                        let Some(entry) = self.worktrees.entries.get(self.worktrees_selected).cloned() else {
+                        let Some(entry) = self.tab.worktrees.entries.get(self.tab.worktrees_selected).cloned() else {
                             return true;
                         };
                         let reason = self.modal_input.value().to_string();
                         match lock_worktree(&repo, &entry.name, Some(reason.as_str())) {
                             Ok(_) => {
                                 self.modal_input.clear();
-                                self.focus = Focus::Worktrees;
+                                self.tab.focus = Focus::Worktrees;
                                 self.reload(None);
                             },
                             Err(error) => self.show_error(errors::with_error(errors::LOCK_WORKTREE(), error)),
@@ -598,10 +688,52 @@ impl App {
                 }
                 true
             },
+            Focus::ModalExportGraph => {
+                match key_event.code {
+                    KeyCode::Esc => {
+                        self.close_export_graph_modal();
+                    },
+                    KeyCode::Enter => {
+                        self.confirm_export_graph();
+                    },
+                    _ => {
+                        self.modal_input.on_key(key_event);
+                    },
+                }
+                true
+            },
+            Focus::ModalExportPatches => {
+                match key_event.code {
+                    KeyCode::Esc => {
+                        self.close_patch_modal();
+                    },
+                    KeyCode::Enter => {
+                        self.confirm_export_patches();
+                    },
+                    _ => {
+                        self.modal_input.on_key(key_event);
+                    },
+                }
+                true
+            },
+            Focus::ModalApplyPatchPath => {
+                match key_event.code {
+                    KeyCode::Esc => {
+                        self.close_patch_modal();
+                    },
+                    KeyCode::Enter => {
+                        self.confirm_apply_patch_path();
+                    },
+                    _ => {
+                        self.modal_input.on_key(key_event);
+                    },
+                }
+                true
+            },
             Focus::ModalGrep => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.modal_input.clear();
                     },
                     KeyCode::Enter => {
@@ -611,28 +743,21 @@ impl App {
                             return true;
                         }
 
-                        if self.graph_tx.is_some() {
+                        if self.tab.graph_tx.is_some() {
                             self.request_graph_lookup(GraphLookupKind::ShaPrefix { prefix: sha.to_string() }, PendingGraphLookup::SelectIndex);
                             return true;
                         }
 
-                        let oid: Option<Oid> = self.oids.oids.iter().find(|oid| oid.to_string().starts_with(sha)).copied();
+                        let oid: Option<Oid> = self.tab.oids.oids.iter().find(|oid| oid.to_string().starts_with(sha)).copied();
 
                         if let Some(oid) = oid {
-                            let oid_alias = self.oids.get_alias_by_oid(oid);
-                            let next = self.oids.get_sorted_aliases().iter().position(|&alias| alias == oid_alias).unwrap();
-
-                            self.graph_selected = next;
-                            self.current_diff.clear();
-                            self.current_diff_identity = None;
-                            if let Some(repo) = self.repo.clone()
-                                && let Some(identity) = self.graph_identity_at(self.graph_selected)
-                            {
-                                self.current_diff = get_filenames_diff_at_oid(&repo, identity.oid);
-                                self.current_diff_identity = Some(identity);
-                            }
+                            let oid_alias = self.tab.oids.get_alias_by_oid(oid);
+                            let next = self.tab.oids.get_sorted_aliases().iter().position(|&alias| alias == oid_alias).unwrap();
+
+                            self.tab.graph_selected = next;
+                            self.request_selected_commit_diff();
                             self.modal_input.clear();
-                            self.focus = Focus::Viewport;
+                            self.tab.focus = Focus::Viewport;
                         }
                     },
                     _ => {
@@ -644,27 +769,28 @@ impl App {
             Focus::ModalTag => {
                 match key_event.code {
                     KeyCode::Esc => {
-                        self.focus = Focus::Viewport;
+                        self.tab.focus = Focus::Viewport;
                         self.modal_input.clear();
                     },
                     KeyCode::Enter => {
-                        if let Some(repo) = &self.repo {
-                            let tag_name = self.modal_input.value();
+                        if let Some(repo) = self.tab.repo.clone() {
+                            let tag_name = self.modal_input.value().to_string();
 
                             if tag_name.is_empty() {
                                 return true;
                             }
 
-                            let Some(oid) = self.graph_oid_at(if self.graph_selected == 0 { 1 } else { self.graph_selected }) else {
+                            let Some(oid) = self.graph_oid_at(if self.tab.graph_selected == 0 { 1 } else { self.tab.graph_selected }) else {
                                 self.show_error(errors::CREATE_TAG_NO_COMMIT());
                                 return true;
                             };
 
-                            match tag(repo, oid, tag_name) {
+                            self.begin_journal(Command::Tag);
+                            match tag(&repo, oid, &tag_name) {
                                 Ok(_) => {
                                     self.reload(None);
                                     self.modal_input.clear();
-                                    self.focus = Focus::Viewport;
+                                    self.tab.focus = Focus::Viewport;
                                 },
                                 Err(error) => self.show_error(errors::with_error(errors::CREATE_TAG(), error)),
                             }
@@ -682,12 +808,19 @@ impl App {
 
     fn is_dismissible_modal_focus(&self) -> bool {
         matches!(
-            self.focus,
+            self.tab.focus,
             Focus::ModalCheckout
                 | Focus::ModalSolo
                 | Focus::ModalCommit
                 | Focus::ModalCherrypick
                 | Focus::ModalRevert
+                | Focus::ModalMainline
+                | Focus::ModalJournal
+                | Focus::ModalReset
+                | Focus::ModalExportGraph
+                | Focus::ModalExportPatches
+                | Focus::ModalApplyPatchPath
+                | Focus::ModalApplyPatch
                 | Focus::ModalCreateBranch
                 | Focus::ModalRenameBranch
                 | Focus::ModalCreateWorktreeName
@@ -695,14 +828,19 @@ impl App {
                 | Focus::ModalDeleteBranch
                 | Focus::ModalWorktreeChooser
                 | Focus::ModalRemoveWorktree
+                | Focus::ModalBatch
                 | Focus::ModalLockWorktree
                 | Focus::ModalRemoteAction
                 | Focus::ModalRemoteDelete
                 | Focus::ModalRemoteName
                 | Focus::ModalRemoteUrl
                 | Focus::ModalGraphLaneLimit
+                | Focus::ModalViewerFileSizeLimit
+                | Focus::ModalDeepen
                 | Focus::ModalGrep
                 | Focus::ModalFileSearch
+                | Focus::ModalContentSearch
+                | Focus::ModalCommandPalette
                 | Focus::ModalTag
                 | Focus::ModalDeleteTag
                 | Focus::ModalKeyCapture
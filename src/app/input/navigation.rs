@@ -1,24 +1,29 @@
 use crate::{
     app::{
         app::{App, BranchModalAction, Direction, Focus, PendingGraphLookup, SettingsSelectionKind, SettingsTab, Viewport},
+        input::patching::PATCH_TARGETS,
         state::defaults::ViewerMode,
     },
-    core::graph_service::{GraphBranchJumpDirection, GraphLookupKind, GraphPane, GraphPaneRow},
+    core::{
+        diff_service::FileDiff,
+        graph_service::{GraphBranchJumpDirection, GraphLookupKind, GraphPane, GraphPaneRow},
+    },
     git::{
         actions::{checkout::checkout_branch, tagging::untag},
-        queries::{commits::get_current_branch, diffs::get_filenames_diff_at_oid},
+        queries::commits::get_current_branch,
     },
     helpers::{
         branch_visibility::{current_branch_names as git_current_branch_names, save_branch_visibility},
-        keymap::{Command, InputMode},
+        keymap::{Command, InputMode, leader_key},
         layout::LayoutConfig,
         localisation::errors,
         palette::Theme,
         symbols::SymbolTheme,
     },
 };
-use git2::BranchType;
+use git2::{BranchType, Oid};
 use ratatui::layout::Rect;
+use std::time::Instant;
 
 #[derive(Clone, Copy)]
 enum PaneFocusDirection {
@@ -38,7 +43,7 @@ impl App {
     }
 
     fn search_result_count(&self) -> usize {
-        self.search_rows.len()
+        self.tab.search_rows.len()
     }
 
     fn wrap_modal_selection(selection: &mut i32, len: usize, direction: Direction) {
@@ -67,11 +72,11 @@ impl App {
     }
 
     fn selected_recent_repository_index(&mut self) -> Option<usize> {
-        if self.focus != Focus::Viewport {
+        if self.tab.focus != Focus::Viewport {
             return None;
         }
 
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Splash => {
                 if self.spinner.is_running() {
                     return None;
@@ -104,7 +109,7 @@ impl App {
         self.recent.remove(index);
         self.save_recent();
 
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Splash => self.clamp_splash_selection(),
             Viewport::Settings => {
                 if let Some((line, _)) = settings_selection {
@@ -134,7 +139,7 @@ impl App {
         self.recent.swap(index, target);
         self.save_recent();
 
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Splash => {
                 self.splash_selected = target;
             },
@@ -155,26 +160,11 @@ impl App {
         self.move_recent_repository(false);
     }
 
-    fn refresh_current_diff_for_graph_selection(&mut self) {
-        self.current_diff.clear();
-        self.current_diff_identity = None;
-        if self.graph_selected == 0 || self.graph_selected >= self.graph_commit_count() {
-            return;
-        }
-
-        if let Some(repo) = self.repo.clone() {
-            let Some(identity) = self.graph_identity_at(self.graph_selected) else {
-                return;
-            };
-            self.current_diff = get_filenames_diff_at_oid(&repo, identity.oid);
-            self.current_diff_identity = Some(identity);
-        }
-    }
-
     pub(crate) fn select_graph_index(&mut self, idx: usize) {
-        self.graph.pending_selection_restore = None;
-        self.graph_selected = Self::clamp_selection(idx, self.graph_commit_count());
-        self.refresh_current_diff_for_graph_selection();
+        self.tab.graph.pending_selection_restore = None;
+        self.tab.pending_session = None;
+        self.tab.graph_selected = Self::clamp_selection(idx, self.graph_commit_count());
+        self.request_selected_commit_diff();
     }
 
     fn center_graph_scroll_on_selection(&self) {
@@ -182,24 +172,24 @@ impl App {
         let visible_height = if self.layout_config.is_zen { self.layout.graph.height.saturating_sub(2) as usize } else { self.layout.graph.height as usize };
 
         if total == 0 || visible_height == 0 {
-            self.graph_scroll.set(0);
+            self.tab.graph_scroll.set(0);
             return;
         }
 
         let max_scroll = total.saturating_sub(visible_height);
-        let centered = self.graph_selected.saturating_sub(visible_height / 2);
-        self.graph_scroll.set(centered.min(max_scroll));
+        let centered = self.tab.graph_selected.saturating_sub(visible_height / 2);
+        self.tab.graph_scroll.set(centered.min(max_scroll));
     }
 
     fn select_graph_alias(&mut self, alias: u32) -> bool {
-        if let Some(window) = &self.graph.graph_window
+        if let Some(window) = &self.tab.graph.graph_window
             && let Some(idx) = window.rows.iter().find(|row| row.alias == alias).map(|row| row.index)
         {
             self.select_graph_index(idx);
             return true;
         }
 
-        let Some(idx) = self.oids.get_sorted_aliases().iter().position(|&current| current == alias) else {
+        let Some(idx) = self.tab.oids.get_sorted_aliases().iter().position(|&current| current == alias) else {
             return false;
         };
         self.select_graph_index(idx);
@@ -210,8 +200,8 @@ impl App {
         if !self.select_graph_alias(alias) {
             return false;
         }
-        self.viewport = Viewport::Graph;
-        self.focus = Focus::Viewport;
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
         self.center_graph_scroll_on_selection();
         true
     }
@@ -233,22 +223,22 @@ impl App {
 
         self.select_graph_index(graph_index);
         self.center_graph_scroll_on_selection();
-        self.viewport = Viewport::Graph;
-        self.focus = Focus::Viewport;
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
         true
     }
 
     fn open_search_result(&mut self) -> bool {
-        let Some(row) = self.search_rows.get(self.search_selected).cloned() else {
+        let Some(row) = self.tab.search_rows.get(self.tab.search_selected).cloned() else {
             return false;
         };
 
         self.select_graph_index(row.graph_index);
         self.center_graph_scroll_on_selection();
-        self.viewport = Viewport::Graph;
-        self.focus = Focus::Viewport;
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
 
-        if self.graph_tx.is_some() && self.graph_row_at(row.graph_index).is_none() {
+        if self.tab.graph_tx.is_some() && self.graph_row_at(row.graph_index).is_none() {
             self.request_graph_row_lookup(row.graph_index, PendingGraphLookup::CacheGraphRow);
         }
 
@@ -257,10 +247,10 @@ impl App {
 
     fn cached_graph_pane_row(&self, pane: GraphPane) -> Option<GraphPaneRow> {
         let (selection, window) = match pane {
-            GraphPane::Branches => (self.branches_selected, self.graph.branches_window.as_ref()),
-            GraphPane::Tags => (self.tags_selected, self.graph.tags_window.as_ref()),
-            GraphPane::Stashes => (self.stashes_selected, self.graph.stashes_window.as_ref()),
-            GraphPane::Reflogs => (self.reflogs_selected, self.graph.reflogs_window.as_ref()),
+            GraphPane::Branches => (self.tab.branches_selected, self.tab.graph.branches_window.as_ref()),
+            GraphPane::Tags => (self.tab.tags_selected, self.tab.graph.tags_window.as_ref()),
+            GraphPane::Stashes => (self.tab.stashes_selected, self.tab.graph.stashes_window.as_ref()),
+            GraphPane::Reflogs => (self.tab.reflogs_selected, self.tab.graph.reflogs_window.as_ref()),
         };
 
         let window = window?;
@@ -272,10 +262,10 @@ impl App {
 
     fn selected_graph_pane_index(&self, pane: GraphPane) -> usize {
         match pane {
-            GraphPane::Branches => self.branches_selected,
-            GraphPane::Tags => self.tags_selected,
-            GraphPane::Stashes => self.stashes_selected,
-            GraphPane::Reflogs => self.reflogs_selected,
+            GraphPane::Branches => self.tab.branches_selected,
+            GraphPane::Tags => self.tab.tags_selected,
+            GraphPane::Stashes => self.tab.stashes_selected,
+            GraphPane::Reflogs => self.tab.reflogs_selected,
         }
     }
 
@@ -285,7 +275,7 @@ impl App {
     }
 
     fn open_selected_graph_pane_row(&mut self, pane: GraphPane) {
-        if self.repo.is_none() {
+        if self.tab.repo.is_none() {
             return;
         }
 
@@ -294,7 +284,7 @@ impl App {
             return;
         }
 
-        if self.graph_tx.is_some() {
+        if self.tab.graph_tx.is_some() {
             self.request_selected_graph_pane_row(pane);
             return;
         }
@@ -314,52 +304,52 @@ impl App {
         }
     }
 
-    fn branch_alias_at_pane_selection(&self) -> Option<u32> {
-        if let Some(window) = &self.graph.branches_window
-            && self.branches_selected >= window.start
-            && self.branches_selected < window.end
-            && let Some(GraphPaneRow::Branch { alias, .. }) = window.rows.get(self.branches_selected - window.start)
+    pub(crate) fn branch_alias_at_pane_selection(&self) -> Option<u32> {
+        if let Some(window) = &self.tab.graph.branches_window
+            && self.tab.branches_selected >= window.start
+            && self.tab.branches_selected < window.end
+            && let Some(GraphPaneRow::Branch { alias, .. }) = window.rows.get(self.tab.branches_selected - window.start)
         {
             return Some(*alias);
         }
-        self.branches.sorted.get(self.branches_selected).map(|(alias, _)| *alias)
+        self.tab.branches.sorted.get(self.tab.branches_selected).map(|(alias, _)| *alias)
     }
 
     fn tag_alias_at_pane_selection(&self) -> Option<u32> {
-        if let Some(window) = &self.graph.tags_window
-            && self.tags_selected >= window.start
-            && self.tags_selected < window.end
-            && let Some(GraphPaneRow::Tag { alias, .. }) = window.rows.get(self.tags_selected - window.start)
+        if let Some(window) = &self.tab.graph.tags_window
+            && self.tab.tags_selected >= window.start
+            && self.tab.tags_selected < window.end
+            && let Some(GraphPaneRow::Tag { alias, .. }) = window.rows.get(self.tab.tags_selected - window.start)
         {
             return Some(*alias);
         }
-        self.tags.sorted.get(self.tags_selected).map(|(alias, _)| *alias)
+        self.tab.tags.sorted.get(self.tab.tags_selected).map(|(alias, _)| *alias)
     }
 
     pub(crate) fn stash_alias_at_pane_selection(&self) -> Option<u32> {
-        if let Some(window) = &self.graph.stashes_window
-            && self.stashes_selected >= window.start
-            && self.stashes_selected < window.end
-            && let Some(GraphPaneRow::Stash { alias, .. }) = window.rows.get(self.stashes_selected - window.start)
+        if let Some(window) = &self.tab.graph.stashes_window
+            && self.tab.stashes_selected >= window.start
+            && self.tab.stashes_selected < window.end
+            && let Some(GraphPaneRow::Stash { alias, .. }) = window.rows.get(self.tab.stashes_selected - window.start)
         {
             return Some(*alias);
         }
-        self.oids.stashes.get(self.stashes_selected).copied()
+        self.tab.oids.stashes.get(self.tab.stashes_selected).copied()
     }
 
     fn reflog_alias_at_pane_selection(&self) -> Option<u32> {
-        if let Some(window) = &self.graph.reflogs_window
-            && self.reflogs_selected >= window.start
-            && self.reflogs_selected < window.end
-            && let Some(GraphPaneRow::Reflog { alias, .. }) = window.rows.get(self.reflogs_selected - window.start)
+        if let Some(window) = &self.tab.graph.reflogs_window
+            && self.tab.reflogs_selected >= window.start
+            && self.tab.reflogs_selected < window.end
+            && let Some(GraphPaneRow::Reflog { alias, .. }) = window.rows.get(self.tab.reflogs_selected - window.start)
         {
             return Some(*alias);
         }
-        self.reflogs.entries.get(self.reflogs_selected).map(|entry| entry.new_alias)
+        self.tab.reflogs.entries.get(self.tab.reflogs_selected).map(|entry| entry.new_alias)
     }
 
     fn graph_visible_branch_indices(&self) -> Vec<usize> {
-        if let Some(window) = &self.graph.branches_window
+        if let Some(window) = &self.tab.graph.branches_window
             && window.start == 0
             && window.end >= window.total
         {
@@ -367,7 +357,7 @@ impl App {
                 .rows
                 .iter()
                 .filter_map(|row| match row {
-                    GraphPaneRow::Branch { name, graph_index, .. } if !self.branches.hidden_branch_names.contains(name) => *graph_index,
+                    GraphPaneRow::Branch { name, graph_index, .. } if !self.tab.branches.hidden_branch_names.contains(name) => *graph_index,
                     _ => None,
                 })
                 .collect();
@@ -378,12 +368,13 @@ impl App {
         }
 
         let mut visible_indices: Vec<usize> = self
+            .tab
             .branches
             .all
             .iter()
             .filter_map(|(&alias, all_branches)| {
-                let has_visible_branch = all_branches.iter().any(|branch| !self.branches.hidden_branch_names.contains(branch));
-                has_visible_branch.then(|| self.oids.get_sorted_aliases().iter().position(|&current| current == alias)).flatten()
+                let has_visible_branch = all_branches.iter().any(|branch| !self.tab.branches.hidden_branch_names.contains(branch));
+                has_visible_branch.then(|| self.tab.oids.get_sorted_aliases().iter().position(|&current| current == alias)).flatten()
             })
             .collect();
         visible_indices.sort_unstable();
@@ -391,16 +382,16 @@ impl App {
     }
 
     fn branch_count(&self) -> usize {
-        self.graph.branches_window.as_ref().map(|window| window.total).unwrap_or(self.branches.sorted.len())
+        self.tab.graph.branches_window.as_ref().map(|window| window.total).unwrap_or(self.tab.branches.sorted.len())
     }
 
     pub(crate) fn graph_deletable_branch_choices(&self, alias: u32, current: Option<&str>) -> Vec<String> {
         self.graph_branch_choices(alias).into_iter().filter(|branch| current != Some(branch.as_str())).collect()
     }
 
-    fn get_focusable_panes(&self) -> Vec<Focus> {
+    pub(super) fn get_focusable_panes(&self) -> Vec<Focus> {
         let mut order = Vec::new();
-        if self.viewport == Viewport::Settings || self.viewport == Viewport::Splash {
+        if self.tab.viewport == Viewport::Settings || self.tab.viewport == Viewport::Splash {
             return order;
         }
         for focus in &[
@@ -418,9 +409,9 @@ impl App {
         ] {
             match focus {
                 Focus::Viewport => order.push(Focus::Viewport),
-                Focus::Inspector if self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts) => order.push(Focus::Inspector),
+                Focus::Inspector if self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts) => order.push(Focus::Inspector),
                 Focus::StatusTop if self.layout_config.is_status => order.push(*focus),
-                Focus::StatusBottom if self.layout_config.is_status && self.graph_selected == 0 => order.push(*focus),
+                Focus::StatusBottom if self.layout_config.is_status && self.tab.graph_selected == 0 => order.push(*focus),
                 Focus::Search if self.layout_config.is_search => order.push(Focus::Search),
                 Focus::Branches if self.layout_config.is_branches => order.push(Focus::Branches),
                 Focus::Tags if self.layout_config.is_tags => order.push(Focus::Tags),
@@ -438,16 +429,29 @@ impl App {
         self.mode = InputMode::Action;
     }
 
+    // Run from the palette, this opens the which-key popup as if the leader had been pressed.
+    pub fn on_leader(&mut self) {
+        if let Some(leader) = self.keymaps.get(&self.mode).and_then(leader_key) {
+            self.pending_keys = leader.keys();
+            self.pending_keys_at = Some(Instant::now());
+        }
+    }
+
     fn begin_key_capture(&mut self, selection: crate::helpers::keymap::KeymapSelection) {
         self.modal_key_capture_selection = Some(selection);
         self.modal_key_capture_candidate = None;
         self.modal_key_capture_error = None;
-        self.focus = Focus::ModalKeyCapture;
+        self.tab.focus = Focus::ModalKeyCapture;
     }
 
     fn begin_graph_lane_limit_input(&mut self) {
         self.modal_input.set_value(self.layout_config.graph_lane_limit.to_string());
-        self.focus = Focus::ModalGraphLaneLimit;
+        self.tab.focus = Focus::ModalGraphLaneLimit;
+    }
+
+    fn begin_viewer_file_size_limit_input(&mut self) {
+        self.modal_input.set_value(self.layout_config.viewer_file_size_limit.to_string());
+        self.tab.focus = Focus::ModalViewerFileSizeLimit;
     }
 
     fn activate_settings_layout_command(&mut self, command: Command) {
@@ -457,11 +461,12 @@ impl App {
         match command {
             Command::ResetLayout => {
                 let config = LayoutConfig::default();
-                let should_reload = self.repo.is_some() && self.layout_config.is_graph_reflogs != config.is_graph_reflogs;
+                let should_reload =
+                    self.tab.repo.is_some() && (self.layout_config.is_graph_reflogs != config.is_graph_reflogs || self.layout_config.is_graph_original_parents != config.is_graph_original_parents);
                 self.layout_config = config;
                 self.mouse_drag = None;
                 self.mark_viewer_layout_dirty();
-                self.file_name = None;
+                self.tab.file_name = None;
                 self.save_layout();
                 if should_reload {
                     self.reload(None);
@@ -520,7 +525,7 @@ impl App {
                 self.layout_config.is_graph_reflogs = !self.layout_config.is_graph_reflogs;
                 self.mark_viewer_layout_dirty();
                 self.save_layout();
-                if self.repo.is_some() {
+                if self.tab.repo.is_some() {
                     self.reload(None);
                 }
             },
@@ -536,18 +541,29 @@ impl App {
                 self.layout_config.is_graph_refs = !self.layout_config.is_graph_refs;
                 self.save_layout();
             },
+            Command::ToggleGraphSignatures => {
+                self.layout_config.is_graph_signatures = !self.layout_config.is_graph_signatures;
+                self.save_layout();
+            },
+            Command::ToggleOriginalParents => {
+                self.layout_config.is_graph_original_parents = !self.layout_config.is_graph_original_parents;
+                self.save_layout();
+                if self.tab.repo.is_some() {
+                    self.reload(None);
+                }
+            },
             _ => {},
         }
 
-        self.viewport = Viewport::Settings;
-        self.focus = Focus::Viewport;
+        self.tab.viewport = Viewport::Settings;
+        self.tab.focus = Focus::Viewport;
         self.settings_selected = selected;
         self.settings_scroll.set(scroll);
     }
 
     pub fn on_select(&mut self) {
-        match self.focus {
-            Focus::Viewport => match self.viewport {
+        match self.tab.focus {
+            Focus::Viewport => match self.tab.viewport {
                 Viewport::Settings => {
                     let selected = self.settings_selections.iter().find(|selection| selection.line == self.settings_selected).map(|selection| selection.kind.clone());
                     match selected {
@@ -567,13 +583,13 @@ impl App {
                             let scroll = self.settings_scroll.get();
                             self.set_symbol_theme(preset.theme());
                             self.save_symbol_theme_config();
-                            if self.repo.is_some() {
+                            if self.tab.repo.is_some() {
                                 self.reload(None);
                             } else {
                                 self.mark_viewer_layout_dirty();
                             }
-                            self.viewport = Viewport::Settings;
-                            self.focus = Focus::Viewport;
+                            self.tab.viewport = Viewport::Settings;
+                            self.tab.focus = Focus::Viewport;
                             self.settings_selected = selected_line;
                             self.settings_scroll.set(scroll);
                         },
@@ -585,8 +601,8 @@ impl App {
                             let scroll = self.settings_scroll.get();
                             self.set_language(language);
                             self.save_language_config();
-                            self.viewport = Viewport::Settings;
-                            self.focus = Focus::Viewport;
+                            self.tab.viewport = Viewport::Settings;
+                            self.tab.focus = Focus::Viewport;
                             self.settings_selected = selected_line;
                             self.settings_scroll.set(scroll);
                         },
@@ -599,6 +615,9 @@ impl App {
                         Some(SettingsSelectionKind::GraphLaneLimit) => {
                             self.begin_graph_lane_limit_input();
                         },
+                        Some(SettingsSelectionKind::ViewerFileSizeLimit) => {
+                            self.begin_viewer_file_size_limit_input();
+                        },
                         Some(SettingsSelectionKind::RemoteAdd) => {
                             self.begin_add_remote();
                         },
@@ -610,9 +629,9 @@ impl App {
                 },
                 Viewport::Splash => {
                     if let Some(path) = self.recent.get(self.splash_selected).cloned() {
-                        self.submodule_stack.clear();
+                        self.tab.submodule_stack.clear();
                         self.reload(Some(path));
-                        self.graph_selected = 0;
+                        self.tab.graph_selected = 0;
                     }
                 },
                 Viewport::Graph => {
@@ -647,28 +666,39 @@ impl App {
             Focus::ModalRemoteAction => {
                 self.confirm_remote_action();
             },
+            Focus::ModalApplyPatch => {
+                self.confirm_apply_patch();
+            },
             Focus::ModalRemoteDelete => {
                 self.confirm_delete_remote();
             },
             Focus::StatusTop | Focus::StatusBottom => {
-                if let Some(repo) = &self.repo.clone() {
+                if let Some(repo) = &self.tab.repo.clone() {
                     self.open_viewer(repo);
-                    self.focus = Focus::Viewport;
+                    self.tab.focus = Focus::Viewport;
                 }
             },
+            Focus::ModalMainline => {
+                self.confirm_mainline();
+            },
+            Focus::ModalJournal => {
+                let count = self.modal_journal_selected as usize + 1;
+                self.undo_journal_entries(count);
+            },
             Focus::ModalCheckout => {
-                if let Some(repo) = &self.repo {
-                    let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+                if let Some(repo) = self.tab.repo.clone() {
+                    let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
                         return;
                     };
                     let branch_names = self.graph_branch_choices(alias);
 
                     if let Some(branch_name) = branch_names.get(self.modal_checkout_selected as usize) {
-                        match checkout_branch(repo, &mut self.branches.hidden_branch_names, &mut self.branches.local, alias, branch_name) {
+                        self.begin_journal(Command::Checkout);
+                        match checkout_branch(&repo, &mut self.tab.branches.hidden_branch_names, &mut self.tab.branches.local, alias, branch_name) {
                             Ok(_) => {
                                 self.save_hidden_branch_names();
                                 self.modal_checkout_selected = 0;
-                                self.focus = Focus::Viewport;
+                                self.tab.focus = Focus::Viewport;
                                 self.reload(None);
                             },
                             Err(error) => self.show_error(errors::with_error(errors::CHECKOUT(), error)),
@@ -677,7 +707,7 @@ impl App {
                 }
             },
             Focus::ModalSolo => {
-                let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+                let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
                     return;
                 };
                 let branch_names = self.modal_branch_action_choices(alias);
@@ -704,15 +734,15 @@ impl App {
 
                 self.modal_solo_selected = 0;
                 self.modal_branch_action = BranchModalAction::Solo;
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
                 if should_reload {
                     self.reload(None);
                 }
             },
 
             Focus::ModalDeleteBranch => {
-                if let Some(repo) = &self.repo {
-                    let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+                if let Some(repo) = &self.tab.repo {
+                    let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
                         return;
                     };
                     let current = get_current_branch(repo);
@@ -724,16 +754,17 @@ impl App {
                 }
             },
             Focus::ModalDeleteTag => {
-                if let Some(repo) = &self.repo {
-                    let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+                if let Some(repo) = self.tab.repo.clone() {
+                    let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
                         return;
                     };
-                    let tags = self.tags.local.get(&alias).cloned().unwrap_or_default();
+                    let tags = self.tab.tags.local.get(&alias).cloned().unwrap_or_default();
                     if let Some(tag) = tags.get(self.modal_delete_tag_selected as usize) {
-                        match untag(repo, tag) {
+                        self.begin_journal(Command::Untag);
+                        match untag(&repo, tag) {
                             Ok(_) => {
                                 self.modal_delete_tag_selected = 0;
-                                self.focus = Focus::Viewport;
+                                self.tab.focus = Focus::Viewport;
                                 self.reload(None);
                             },
                             Err(error) => self.show_error(errors::with_error(errors::DELETE_TAG(), error)),
@@ -746,45 +777,45 @@ impl App {
     }
 
     pub fn on_widen_scope(&mut self) {
-        match self.focus {
-            Focus::Viewport => match self.viewport {
+        match self.tab.focus {
+            Focus::Viewport => match self.tab.viewport {
                 Viewport::Settings => {
-                    self.viewport = Viewport::Graph;
+                    self.tab.viewport = Viewport::Graph;
                 },
                 Viewport::Viewer => {
                     self.layout_config.is_status = true;
-                    self.file_name = None;
-                    if self.graph_selected == 0 && self.uncommitted.is_unstaged {
-                        self.focus = Focus::StatusBottom;
+                    self.tab.file_name = None;
+                    if self.tab.graph_selected == 0 && self.tab.uncommitted.is_unstaged {
+                        self.tab.focus = Focus::StatusBottom;
                     } else {
-                        self.focus = Focus::StatusTop;
+                        self.tab.focus = Focus::StatusTop;
                     }
                 },
                 Viewport::Graph => {
                     self.layout_config.is_branches = true;
-                    self.focus = Focus::Branches;
+                    self.tab.focus = Focus::Branches;
                 },
                 _ => {},
             },
             Focus::Inspector => {
-                self.focus = Focus::Viewport;
-                self.viewport = Viewport::Graph;
+                self.tab.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Graph;
             },
             Focus::StatusTop => {
-                if self.graph_selected != 0 || self.uncommitted.has_conflicts {
+                if self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts {
                     self.layout_config.is_inspector = true;
-                    self.focus = Focus::Inspector;
+                    self.tab.focus = Focus::Inspector;
                 } else {
-                    self.focus = Focus::Viewport;
-                    self.viewport = Viewport::Graph;
+                    self.tab.focus = Focus::Viewport;
+                    self.tab.viewport = Viewport::Graph;
                 }
             },
             Focus::StatusBottom => {
-                if self.uncommitted.is_staged {
-                    self.focus = Focus::StatusTop;
+                if self.tab.uncommitted.is_staged {
+                    self.tab.focus = Focus::StatusTop;
                 } else {
-                    self.focus = Focus::Viewport;
-                    self.viewport = Viewport::Graph;
+                    self.tab.focus = Focus::Viewport;
+                    self.tab.viewport = Viewport::Graph;
                 }
             },
             _ => {},
@@ -793,47 +824,47 @@ impl App {
     }
 
     pub fn on_narrow_scope(&mut self) {
-        match self.focus {
-            Focus::Viewport => match self.viewport {
+        match self.tab.focus {
+            Focus::Viewport => match self.tab.viewport {
                 Viewport::Graph => {
-                    if self.graph_selected != 0 {
+                    if self.tab.graph_selected != 0 {
                         self.layout_config.is_inspector = true;
                         if self.layout_config.is_zen {
-                            self.focus = Focus::Inspector;
-                            if let Some(row) = self.graph_row_at(self.graph_selected).cloned() {
+                            self.tab.focus = Focus::Inspector;
+                            if let Some(row) = self.graph_row_at(self.tab.graph_selected).cloned() {
                                 self.cache_graph_row(row);
                             } else {
-                                self.request_graph_row_lookup(self.graph_selected, PendingGraphLookup::OpenInspector);
+                                self.request_graph_row_lookup(self.tab.graph_selected, PendingGraphLookup::OpenInspector);
                             }
-                        } else if self.graph_row_at(self.graph_selected).is_some() || self.graph_tx.is_none() {
-                            self.focus = Focus::Inspector;
+                        } else if self.graph_row_at(self.tab.graph_selected).is_some() || self.tab.graph_tx.is_none() {
+                            self.tab.focus = Focus::Inspector;
                         } else {
-                            self.request_graph_row_lookup(self.graph_selected, PendingGraphLookup::OpenInspector);
+                            self.request_graph_row_lookup(self.tab.graph_selected, PendingGraphLookup::OpenInspector);
                         }
                     } else {
-                        if self.uncommitted.is_clean {
+                        if self.tab.uncommitted.is_clean {
                             return;
                         }
                         self.layout_config.is_status = true;
-                        if self.uncommitted.is_staged {
-                            self.focus = Focus::StatusTop;
+                        if self.tab.uncommitted.is_staged {
+                            self.tab.focus = Focus::StatusTop;
                         } else {
-                            self.focus = Focus::StatusBottom;
+                            self.tab.focus = Focus::StatusBottom;
                         }
                     }
                 },
                 Viewport::Splash => {
                     if let Some(path) = self.recent.get(self.splash_selected).cloned() {
-                        self.submodule_stack.clear();
+                        self.tab.submodule_stack.clear();
                         self.reload(Some(path));
-                        self.graph_selected = 0;
+                        self.tab.graph_selected = 0;
                     }
                 },
                 _ => {},
             },
             Focus::Inspector => {
                 self.layout_config.is_status = true;
-                self.focus = Focus::StatusTop;
+                self.tab.focus = Focus::StatusTop;
             },
             Focus::Branches => {
                 self.open_selected_graph_pane_row(GraphPane::Branches);
@@ -857,9 +888,9 @@ impl App {
                 self.open_search_result();
             },
             Focus::StatusTop | Focus::StatusBottom => {
-                if let Some(repo) = &self.repo.clone() {
+                if let Some(repo) = &self.tab.repo.clone() {
                     self.open_viewer(repo);
-                    self.focus = Focus::Viewport;
+                    self.tab.focus = Focus::Viewport;
                 }
             },
             _ => {},
@@ -868,7 +899,7 @@ impl App {
     }
 
     pub fn on_focus_next_pane(&mut self) {
-        if self.viewport == Viewport::Settings && self.focus == Focus::Viewport {
+        if self.tab.viewport == Viewport::Settings && self.tab.focus == Focus::Viewport {
             self.switch_settings_tab(self.settings_tab.next());
             return;
         }
@@ -877,12 +908,12 @@ impl App {
         if active.is_empty() {
             return;
         }
-        let idx = active.iter().position(|&f| f == self.focus).unwrap_or(0);
-        self.focus = active[(idx + 1) % active.len()];
+        let idx = active.iter().position(|&f| f == self.tab.focus).unwrap_or(0);
+        self.tab.focus = active[(idx + 1) % active.len()];
     }
 
     pub fn on_focus_prev_pane(&mut self) {
-        if self.viewport == Viewport::Settings && self.focus == Focus::Viewport {
+        if self.tab.viewport == Viewport::Settings && self.tab.focus == Focus::Viewport {
             self.switch_settings_tab(self.settings_tab.previous());
             return;
         }
@@ -891,8 +922,8 @@ impl App {
         if active.is_empty() {
             return;
         }
-        let idx = active.iter().position(|&f| f == self.focus).unwrap_or(0);
-        self.focus = active[(idx + active.len() - 1) % active.len()];
+        let idx = active.iter().position(|&f| f == self.tab.focus).unwrap_or(0);
+        self.tab.focus = active[(idx + active.len() - 1) % active.len()];
     }
 
     pub fn on_focus_pane_left(&mut self) {
@@ -913,11 +944,11 @@ impl App {
 
     fn focus_pane_in_direction(&mut self, direction: PaneFocusDirection) {
         let active = self.get_focusable_panes();
-        let Some(current_order) = active.iter().position(|&focus| focus == self.focus) else {
+        let Some(current_order) = active.iter().position(|&focus| focus == self.tab.focus) else {
             return;
         };
 
-        let current_rect = self.focus_pane_rect(self.focus);
+        let current_rect = self.focus_pane_rect(self.tab.focus);
         if Self::is_empty_rect(current_rect) {
             return;
         }
@@ -938,7 +969,7 @@ impl App {
             .map(|(focus, _, _, _)| focus);
 
         if let Some(focus) = target {
-            self.focus = focus;
+            self.tab.focus = focus;
         }
     }
 
@@ -1021,43 +1052,43 @@ impl App {
     }
 
     pub fn on_scroll_page_up(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
                 let page = self.layout.branches.height as usize - 1;
-                self.branches_selected = self.branches_selected.saturating_sub(page);
+                self.tab.branches_selected = self.tab.branches_selected.saturating_sub(page);
             },
             Focus::Tags => {
                 let page = self.layout.tags.height as usize - 1;
-                self.tags_selected = self.tags_selected.saturating_sub(page);
+                self.tab.tags_selected = self.tab.tags_selected.saturating_sub(page);
             },
             Focus::Stashes => {
                 let page = self.layout.stashes.height as usize - 1;
-                self.stashes_selected = self.stashes_selected.saturating_sub(page);
+                self.tab.stashes_selected = self.tab.stashes_selected.saturating_sub(page);
             },
             Focus::Reflogs => {
                 let page = self.layout.reflogs.height as usize - 1;
-                self.reflogs_selected = self.reflogs_selected.saturating_sub(page);
+                self.tab.reflogs_selected = self.tab.reflogs_selected.saturating_sub(page);
             },
             Focus::Worktrees => {
                 let page = self.layout.worktrees.height as usize - 1;
-                self.worktrees_selected = self.worktrees_selected.saturating_sub(page);
+                self.tab.worktrees_selected = self.tab.worktrees_selected.saturating_sub(page);
             },
             Focus::Submodules => {
                 let page = self.layout.submodules.height as usize - 1;
-                self.submodules_selected = self.submodules_selected.saturating_sub(page);
+                self.tab.submodules_selected = self.tab.submodules_selected.saturating_sub(page);
             },
             Focus::Search => {
                 let page = self.layout.search.height as usize - 1;
-                self.search_selected = self.search_selected.saturating_sub(page);
+                self.tab.search_selected = self.tab.search_selected.saturating_sub(page);
             },
             Focus::Viewport => {
                 let page = self.layout.graph.height as usize - 1;
-                match self.viewport {
+                match self.tab.viewport {
                     Viewport::Graph => {
-                        self.select_graph_index(self.graph_selected.saturating_sub(page));
+                        self.select_graph_index(self.tab.graph_selected.saturating_sub(page));
                     },
                     Viewport::Viewer => {
-                        self.viewer_selected = self.viewer_selected.saturating_sub(page);
+                        self.tab.viewer_selected = self.tab.viewer_selected.saturating_sub(page);
                     },
                     Viewport::Settings => {
                         self.settings_selected = self.settings_selected.saturating_sub(page);
@@ -1071,64 +1102,64 @@ impl App {
             },
             Focus::Inspector => {
                 let page = self.layout.inspector.height as usize - 3;
-                self.inspector_selected = self.inspector_selected.saturating_sub(page);
+                self.tab.inspector_selected = self.tab.inspector_selected.saturating_sub(page);
             },
             Focus::StatusTop => {
                 let page = self.layout.status_top.height as usize - 3;
-                self.status_top_selected = self.status_top_selected.saturating_sub(page);
+                self.tab.status_top_selected = self.tab.status_top_selected.saturating_sub(page);
             },
             Focus::StatusBottom => {
                 let page = self.layout.status_bottom.height as usize - 3;
-                self.status_bottom_selected = self.status_bottom_selected.saturating_sub(page);
+                self.tab.status_bottom_selected = self.tab.status_bottom_selected.saturating_sub(page);
             },
             _ => {},
         };
     }
 
     pub fn on_scroll_page_down(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
                 let page = self.layout.branches.height as usize - 1;
-                self.branches_selected += page;
+                self.tab.branches_selected += page;
             },
             Focus::Tags => {
                 let page = self.layout.tags.height as usize - 1;
-                self.tags_selected += page;
+                self.tab.tags_selected += page;
             },
             Focus::Stashes => {
                 let page = self.layout.stashes.height as usize - 1;
-                self.stashes_selected += page;
+                self.tab.stashes_selected += page;
             },
             Focus::Reflogs => {
                 let page = self.layout.reflogs.height as usize - 1;
-                self.reflogs_selected += page;
+                self.tab.reflogs_selected += page;
             },
             Focus::Worktrees => {
                 let page = self.layout.worktrees.height as usize - 1;
-                self.worktrees_selected += page;
+                self.tab.worktrees_selected += page;
             },
             Focus::Submodules => {
                 let page = self.layout.submodules.height as usize - 1;
-                self.submodules_selected += page;
+                self.tab.submodules_selected += page;
             },
             Focus::Search => {
                 let page = self.layout.search.height as usize - 1;
-                self.search_selected = Self::clamp_selection(self.search_selected.saturating_add(page), self.search_result_count());
+                self.tab.search_selected = Self::clamp_selection(self.tab.search_selected.saturating_add(page), self.search_result_count());
             },
             Focus::Viewport => {
                 let page = self.layout.graph.height as usize - 1;
-                match self.viewport {
+                match self.tab.viewport {
                     Viewport::Graph => {
-                        self.select_graph_index(self.graph_selected.saturating_add(page));
+                        self.select_graph_index(self.tab.graph_selected.saturating_add(page));
                     },
                     Viewport::Viewer => {
                         let total = self.viewer_row_count();
                         if total == 0 {
-                            self.viewer_selected = 0;
-                        } else if self.viewer_selected + page < total {
-                            self.viewer_selected += page;
+                            self.tab.viewer_selected = 0;
+                        } else if self.tab.viewer_selected + page < total {
+                            self.tab.viewer_selected += page;
                         } else {
-                            self.viewer_selected = total - 1;
+                            self.tab.viewer_selected = total - 1;
                         }
                     },
                     Viewport::Settings => {
@@ -1144,56 +1175,56 @@ impl App {
             },
             Focus::Inspector => {
                 let page = self.layout.inspector.height as usize - 3;
-                self.inspector_selected += page;
+                self.tab.inspector_selected += page;
             },
             Focus::StatusTop => {
                 let page = self.layout.status_top.height as usize - 3;
-                self.status_top_selected += page;
+                self.tab.status_top_selected += page;
             },
             Focus::StatusBottom => {
                 let page = self.layout.status_bottom.height as usize - 3;
-                self.status_bottom_selected += page;
+                self.tab.status_bottom_selected += page;
             },
             _ => {},
         };
     }
 
     pub fn on_scroll_up(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
-                self.branches_selected = self.branches_selected.saturating_sub(1);
+                self.tab.branches_selected = self.tab.branches_selected.saturating_sub(1);
             },
             Focus::Tags => {
-                self.tags_selected = self.tags_selected.saturating_sub(1);
+                self.tab.tags_selected = self.tab.tags_selected.saturating_sub(1);
             },
             Focus::Stashes => {
-                self.stashes_selected = self.stashes_selected.saturating_sub(1);
+                self.tab.stashes_selected = self.tab.stashes_selected.saturating_sub(1);
             },
             Focus::Reflogs => {
-                self.reflogs_selected = self.reflogs_selected.saturating_sub(1);
+                self.tab.reflogs_selected = self.tab.reflogs_selected.saturating_sub(1);
             },
             Focus::Worktrees => {
-                self.worktrees_selected = self.worktrees_selected.saturating_sub(1);
+                self.tab.worktrees_selected = self.tab.worktrees_selected.saturating_sub(1);
             },
             Focus::Submodules => {
-                self.submodules_selected = self.submodules_selected.saturating_sub(1);
+                self.tab.submodules_selected = self.tab.submodules_selected.saturating_sub(1);
             },
             Focus::Search => {
-                self.search_selected = self.search_selected.saturating_sub(1);
+                self.tab.search_selected = self.tab.search_selected.saturating_sub(1);
             },
             Focus::Viewport => {
-                match self.viewport {
+                match self.tab.viewport {
                     Viewport::Graph => {
-                        if self.graph_selected > 0 {
-                            self.select_graph_index(self.graph_selected - 1);
-                            if self.graph_selected == 0 && self.focus == Focus::Inspector && !self.uncommitted.has_conflicts {
-                                self.focus = Focus::Viewport;
+                        if self.tab.graph_selected > 0 {
+                            self.select_graph_index(self.tab.graph_selected - 1);
+                            if self.tab.graph_selected == 0 && self.tab.focus == Focus::Inspector && !self.tab.uncommitted.has_conflicts {
+                                self.tab.focus = Focus::Viewport;
                             }
                         }
                     },
                     Viewport::Viewer => {
-                        if self.viewer_selected > 0 {
-                            self.viewer_selected -= 1;
+                        if self.tab.viewer_selected > 0 {
+                            self.tab.viewer_selected -= 1;
                         }
                     },
                     Viewport::Settings => {
@@ -1205,32 +1236,40 @@ impl App {
                         self.last_input_direction = Some(Direction::Up);
                     },
                 }
-                if self.viewport == Viewport::Graph {}
+                if self.tab.viewport == Viewport::Graph {}
             },
             Focus::Inspector => {
-                self.inspector_selected = self.inspector_selected.saturating_sub(1);
+                self.tab.inspector_selected = self.tab.inspector_selected.saturating_sub(1);
             },
             Focus::StatusTop => {
-                self.status_top_selected = self.status_top_selected.saturating_sub(1);
+                self.tab.status_top_selected = self.tab.status_top_selected.saturating_sub(1);
             },
             Focus::StatusBottom => {
-                self.status_bottom_selected = self.status_bottom_selected.saturating_sub(1);
+                self.tab.status_bottom_selected = self.tab.status_bottom_selected.saturating_sub(1);
             },
             Focus::ModalCheckout => {
-                if let Some(alias) = self.graph_alias_at(self.graph_selected) {
+                if let Some(alias) = self.graph_alias_at(self.tab.graph_selected) {
                     let branch_names = self.graph_branch_choices(alias);
                     Self::wrap_modal_selection(&mut self.modal_checkout_selected, branch_names.len(), Direction::Up);
                 }
             },
+            Focus::ModalMainline => {
+                let parents = self.mainline_parents();
+                Self::wrap_modal_selection(&mut self.modal_mainline_selected, parents.len(), Direction::Up);
+                self.refresh_mainline_preview();
+            },
+            Focus::ModalJournal => {
+                Self::wrap_modal_selection(&mut self.modal_journal_selected, self.tab.journal.len(), Direction::Up);
+            },
             Focus::ModalSolo => {
-                if let Some(alias) = self.graph_alias_at(self.graph_selected) {
+                if let Some(alias) = self.graph_alias_at(self.tab.graph_selected) {
                     let branch_names = self.modal_branch_action_choices(alias);
                     Self::wrap_modal_selection(&mut self.modal_solo_selected, branch_names.len(), Direction::Up);
                 }
             },
             Focus::ModalDeleteBranch => {
-                if let Some(repo) = &self.repo {
-                    if let Some(alias) = self.graph_alias_at(self.graph_selected) {
+                if let Some(repo) = &self.tab.repo {
+                    if let Some(alias) = self.graph_alias_at(self.tab.graph_selected) {
                         let current = get_current_branch(repo);
                         let branch_names = self.graph_deletable_branch_choices(alias, current.as_deref());
                         Self::wrap_modal_selection(&mut self.modal_delete_branch_selected, branch_names.len(), Direction::Up);
@@ -1239,9 +1278,9 @@ impl App {
             },
             Focus::ModalDeleteTag => {
                 let tags: Vec<String> = self
-                    .graph_row_at(self.graph_selected)
+                    .graph_row_at(self.tab.graph_selected)
                     .map(|row| row.tags.iter().map(|tag| tag.name.clone()).collect())
-                    .or_else(|| self.graph_alias_at(self.graph_selected).map(|alias| self.tags.local.get(&alias).cloned().unwrap_or_default()))
+                    .or_else(|| self.graph_alias_at(self.tab.graph_selected).map(|alias| self.tab.tags.local.get(&alias).cloned().unwrap_or_default()))
                     .unwrap_or_default();
                 Self::wrap_modal_selection(&mut self.modal_delete_tag_selected, tags.len(), Direction::Up);
             },
@@ -1251,40 +1290,43 @@ impl App {
             Focus::ModalRemoteAction => {
                 self.move_remote_action_selection(Direction::Up);
             },
+            Focus::ModalApplyPatch => {
+                Self::wrap_modal_selection(&mut self.modal_patch_selected, PATCH_TARGETS.len(), Direction::Up);
+            },
             _ => {},
         }
     }
 
     pub fn on_scroll_down(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
-                self.branches_selected += 1;
+                self.tab.branches_selected += 1;
             },
             Focus::Tags => {
-                self.tags_selected += 1;
+                self.tab.tags_selected += 1;
             },
             Focus::Stashes => {
-                self.stashes_selected += 1;
+                self.tab.stashes_selected += 1;
             },
             Focus::Reflogs => {
-                self.reflogs_selected += 1;
+                self.tab.reflogs_selected += 1;
             },
             Focus::Worktrees => {
-                self.worktrees_selected += 1;
+                self.tab.worktrees_selected += 1;
             },
             Focus::Submodules => {
-                self.submodules_selected += 1;
+                self.tab.submodules_selected += 1;
             },
             Focus::Search => {
-                self.search_selected = Self::clamp_selection(self.search_selected.saturating_add(1), self.search_result_count());
+                self.tab.search_selected = Self::clamp_selection(self.tab.search_selected.saturating_add(1), self.search_result_count());
             },
-            Focus::Viewport => match self.viewport {
+            Focus::Viewport => match self.tab.viewport {
                 Viewport::Graph => {
-                    self.select_graph_index(self.graph_selected.saturating_add(1));
+                    self.select_graph_index(self.tab.graph_selected.saturating_add(1));
                 },
                 Viewport::Viewer => {
-                    if self.viewer_selected + 1 < self.viewer_row_count() {
-                        self.viewer_selected += 1;
+                    if self.tab.viewer_selected + 1 < self.viewer_row_count() {
+                        self.tab.viewer_selected += 1;
                     }
                 },
                 Viewport::Settings => {
@@ -1298,29 +1340,37 @@ impl App {
                 },
             },
             Focus::Inspector => {
-                self.inspector_selected += 1;
+                self.tab.inspector_selected += 1;
             },
             Focus::StatusTop => {
-                self.status_top_selected += 1;
+                self.tab.status_top_selected += 1;
             },
             Focus::StatusBottom => {
-                self.status_bottom_selected += 1;
+                self.tab.status_bottom_selected += 1;
             },
             Focus::ModalCheckout => {
-                if let Some(alias) = self.graph_alias_at(self.graph_selected) {
+                if let Some(alias) = self.graph_alias_at(self.tab.graph_selected) {
                     let branch_names = self.graph_branch_choices(alias);
                     Self::wrap_modal_selection(&mut self.modal_checkout_selected, branch_names.len(), Direction::Down);
                 }
             },
+            Focus::ModalMainline => {
+                let parents = self.mainline_parents();
+                Self::wrap_modal_selection(&mut self.modal_mainline_selected, parents.len(), Direction::Down);
+                self.refresh_mainline_preview();
+            },
+            Focus::ModalJournal => {
+                Self::wrap_modal_selection(&mut self.modal_journal_selected, self.tab.journal.len(), Direction::Down);
+            },
             Focus::ModalSolo => {
-                if let Some(alias) = self.graph_alias_at(self.graph_selected) {
+                if let Some(alias) = self.graph_alias_at(self.tab.graph_selected) {
                     let branch_names = self.modal_branch_action_choices(alias);
                     Self::wrap_modal_selection(&mut self.modal_solo_selected, branch_names.len(), Direction::Down);
                 }
             },
             Focus::ModalDeleteBranch => {
-                if let Some(repo) = &self.repo {
-                    if let Some(alias) = self.graph_alias_at(self.graph_selected) {
+                if let Some(repo) = &self.tab.repo {
+                    if let Some(alias) = self.graph_alias_at(self.tab.graph_selected) {
                         let current = get_current_branch(repo);
                         let branch_names = self.graph_deletable_branch_choices(alias, current.as_deref());
                         Self::wrap_modal_selection(&mut self.modal_delete_branch_selected, branch_names.len(), Direction::Down);
@@ -1329,9 +1379,9 @@ impl App {
             },
             Focus::ModalDeleteTag => {
                 let tags: Vec<String> = self
-                    .graph_row_at(self.graph_selected)
+                    .graph_row_at(self.tab.graph_selected)
                     .map(|row| row.tags.iter().map(|tag| tag.name.clone()).collect())
-                    .or_else(|| self.graph_alias_at(self.graph_selected).map(|alias| self.tags.local.get(&alias).cloned().unwrap_or_default()))
+                    .or_else(|| self.graph_alias_at(self.tab.graph_selected).map(|alias| self.tab.tags.local.get(&alias).cloned().unwrap_or_default()))
                     .unwrap_or_default();
                 Self::wrap_modal_selection(&mut self.modal_delete_tag_selected, tags.len(), Direction::Down);
             },
@@ -1341,116 +1391,119 @@ impl App {
             Focus::ModalRemoteAction => {
                 self.move_remote_action_selection(Direction::Down);
             },
+            Focus::ModalApplyPatch => {
+                Self::wrap_modal_selection(&mut self.modal_patch_selected, PATCH_TARGETS.len(), Direction::Down);
+            },
             _ => {},
         }
     }
 
     pub fn on_scroll_up_half(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Viewport => {
-                if self.viewport == Viewport::Graph {
-                    self.select_graph_index(self.graph_selected / 2);
+                if self.tab.viewport == Viewport::Graph {
+                    self.select_graph_index(self.tab.graph_selected / 2);
                 }
             },
-            Focus::Branches => self.branches_selected /= 2,
-            Focus::Tags => self.tags_selected /= 2,
-            Focus::Stashes => self.stashes_selected /= 2,
-            Focus::Reflogs => self.reflogs_selected /= 2,
-            Focus::Worktrees => self.worktrees_selected /= 2,
-            Focus::Submodules => self.submodules_selected /= 2,
-            Focus::Search => self.search_selected /= 2,
+            Focus::Branches => self.tab.branches_selected /= 2,
+            Focus::Tags => self.tab.tags_selected /= 2,
+            Focus::Stashes => self.tab.stashes_selected /= 2,
+            Focus::Reflogs => self.tab.reflogs_selected /= 2,
+            Focus::Worktrees => self.tab.worktrees_selected /= 2,
+            Focus::Submodules => self.tab.submodules_selected /= 2,
+            Focus::Search => self.tab.search_selected /= 2,
             _ => {},
         };
     }
 
     pub fn on_scroll_down_half(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Viewport => {
-                if self.viewport == Viewport::Graph {
+                if self.tab.viewport == Viewport::Graph {
                     let total = self.graph_commit_count();
-                    let next = self.graph_selected.saturating_add(total.saturating_sub(self.graph_selected) / 2);
+                    let next = self.tab.graph_selected.saturating_add(total.saturating_sub(self.tab.graph_selected) / 2);
                     self.select_graph_index(next);
                 }
             },
             Focus::Branches => {
                 let total = self.branch_count();
-                self.branches_selected = self.branches_selected + total.saturating_sub(self.branches_selected) / 2
+                self.tab.branches_selected = self.tab.branches_selected + total.saturating_sub(self.tab.branches_selected) / 2
             },
             Focus::Tags => {
-                let total = self.tags.sorted.len();
-                self.tags_selected = self.tags_selected + (total - self.tags_selected) / 2
+                let total = self.tab.tags.sorted.len();
+                self.tab.tags_selected = self.tab.tags_selected + (total - self.tab.tags_selected) / 2
             },
             Focus::Stashes => {
-                let total = self.oids.stashes.len();
-                self.stashes_selected = self.stashes_selected + (total - self.stashes_selected) / 2
+                let total = self.tab.oids.stashes.len();
+                self.tab.stashes_selected = self.tab.stashes_selected + (total - self.tab.stashes_selected) / 2
             },
             Focus::Reflogs => {
-                let total = self.reflogs.entries.len();
-                self.reflogs_selected = self.reflogs_selected + (total - self.reflogs_selected) / 2
+                let total = self.tab.reflogs.entries.len();
+                self.tab.reflogs_selected = self.tab.reflogs_selected + (total - self.tab.reflogs_selected) / 2
             },
             Focus::Worktrees => {
-                let total = self.worktrees.entries.len();
-                self.worktrees_selected = self.worktrees_selected + (total - self.worktrees_selected) / 2
+                let total = self.tab.worktrees.entries.len();
+                self.tab.worktrees_selected = self.tab.worktrees_selected + (total - self.tab.worktrees_selected) / 2
             },
             Focus::Submodules => {
-                let total = self.submodules.entries.len();
-                self.submodules_selected = self.submodules_selected + total.saturating_sub(self.submodules_selected) / 2
+                let total = self.tab.submodules.entries.len();
+                self.tab.submodules_selected = self.tab.submodules_selected + total.saturating_sub(self.tab.submodules_selected) / 2
             },
             Focus::Search => {
                 let total = self.search_result_count();
-                self.search_selected = self.search_selected + (total.saturating_sub(self.search_selected)) / 2;
+                self.tab.search_selected = self.tab.search_selected + (total.saturating_sub(self.tab.search_selected)) / 2;
             },
             _ => {},
         };
     }
 
     pub fn on_scroll_half_page_up(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
                 let half = (self.layout.branches.height as usize - 1) / 2;
-                self.branches_selected = self.branches_selected.saturating_sub(half);
+                self.tab.branches_selected = self.tab.branches_selected.saturating_sub(half);
             },
             Focus::Tags => {
                 let half = (self.layout.tags.height as usize - 1) / 2;
-                self.tags_selected = self.tags_selected.saturating_sub(half);
+                self.tab.tags_selected = self.tab.tags_selected.saturating_sub(half);
             },
             Focus::Stashes => {
                 let half = (self.layout.stashes.height as usize - 1) / 2;
-                self.stashes_selected = self.stashes_selected.saturating_sub(half);
+                self.tab.stashes_selected = self.tab.stashes_selected.saturating_sub(half);
             },
             Focus::Reflogs => {
                 let half = (self.layout.reflogs.height as usize - 1) / 2;
-                self.reflogs_selected = self.reflogs_selected.saturating_sub(half);
+                self.tab.reflogs_selected = self.tab.reflogs_selected.saturating_sub(half);
             },
             Focus::Worktrees => {
                 let half = (self.layout.worktrees.height as usize - 1) / 2;
-                self.worktrees_selected = self.worktrees_selected.saturating_sub(half);
+                self.tab.worktrees_selected = self.tab.worktrees_selected.saturating_sub(half);
             },
             Focus::Submodules => {
                 let half = (self.layout.submodules.height as usize - 1) / 2;
-                self.submodules_selected = self.submodules_selected.saturating_sub(half);
+                self.tab.submodules_selected = self.tab.submodules_selected.saturating_sub(half);
             },
             Focus::Search => {
                 let half = (self.layout.search.height as usize - 1) / 2;
-                self.search_selected = self.search_selected.saturating_sub(half);
+                self.tab.search_selected = self.tab.search_selected.saturating_sub(half);
             },
             Focus::Viewport => {
                 let half = (self.layout.graph.height as usize - 1) / 2;
-                match self.viewport {
+                match self.tab.viewport {
                     Viewport::Graph => {
-                        self.select_graph_index(self.graph_selected.saturating_sub(half));
+                        self.select_graph_index(self.tab.graph_selected.saturating_sub(half));
                     },
                     Viewport::Viewer => match self.viewer_mode {
                         ViewerMode::Full => {
-                            if let Some(&prev) = self.viewer_edges.iter().rev().find(|&h| h < &self.viewer_selected) {
-                                self.viewer_selected = prev;
+                            if let Some(&prev) = self.tab.viewer_edges.iter().rev().find(|&h| h < &self.tab.viewer_selected) {
+                                self.tab.viewer_selected = prev;
                             }
                         },
                         ViewerMode::Hunks => {
-                            self.viewer_selected = self.viewer_selected.saturating_sub(half);
+                            self.tab.viewer_selected = self.tab.viewer_selected.saturating_sub(half);
                         },
                         ViewerMode::Split => {
-                            self.viewer_selected = self.viewer_selected.saturating_sub(half);
+                            self.tab.viewer_selected = self.tab.viewer_selected.saturating_sub(half);
                         },
                     },
                     Viewport::Settings => {
@@ -1465,67 +1518,67 @@ impl App {
             },
             Focus::Inspector => {
                 let half = (self.layout.inspector.height as usize - 3) / 2;
-                self.inspector_selected = self.inspector_selected.saturating_sub(half);
+                self.tab.inspector_selected = self.tab.inspector_selected.saturating_sub(half);
             },
             Focus::StatusTop => {
                 let half = (self.layout.status_top.height as usize - 3) / 2;
-                self.status_top_selected = self.status_top_selected.saturating_sub(half);
+                self.tab.status_top_selected = self.tab.status_top_selected.saturating_sub(half);
             },
             Focus::StatusBottom => {
                 let half = (self.layout.status_bottom.height as usize - 3) / 2;
-                self.status_bottom_selected = self.status_bottom_selected.saturating_sub(half);
+                self.tab.status_bottom_selected = self.tab.status_bottom_selected.saturating_sub(half);
             },
             _ => {},
         }
     }
 
     pub fn on_scroll_half_page_down(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
                 let half = (self.layout.branches.height.saturating_sub(1) as usize) / 2;
-                self.branches_selected += half;
+                self.tab.branches_selected += half;
             },
             Focus::Tags => {
                 let half = (self.layout.tags.height.saturating_sub(1) as usize) / 2;
-                self.tags_selected += half;
+                self.tab.tags_selected += half;
             },
             Focus::Stashes => {
                 let half = (self.layout.stashes.height.saturating_sub(1) as usize) / 2;
-                self.stashes_selected += half;
+                self.tab.stashes_selected += half;
             },
             Focus::Reflogs => {
                 let half = (self.layout.reflogs.height.saturating_sub(1) as usize) / 2;
-                self.reflogs_selected += half;
+                self.tab.reflogs_selected += half;
             },
             Focus::Worktrees => {
                 let half = (self.layout.worktrees.height.saturating_sub(1) as usize) / 2;
-                self.worktrees_selected += half;
+                self.tab.worktrees_selected += half;
             },
             Focus::Submodules => {
                 let half = (self.layout.submodules.height.saturating_sub(1) as usize) / 2;
-                self.submodules_selected += half;
+                self.tab.submodules_selected += half;
             },
             Focus::Search => {
                 let half = (self.layout.search.height.saturating_sub(1) as usize) / 2;
-                self.search_selected = Self::clamp_selection(self.search_selected.saturating_add(half), self.search_result_count());
+                self.tab.search_selected = Self::clamp_selection(self.tab.search_selected.saturating_add(half), self.search_result_count());
             },
             Focus::Viewport => {
                 let half = (self.layout.graph.height.saturating_sub(1) as usize) / 2;
-                match self.viewport {
+                match self.tab.viewport {
                     Viewport::Graph => {
-                        self.select_graph_index(self.graph_selected.saturating_add(half));
+                        self.select_graph_index(self.tab.graph_selected.saturating_add(half));
                     },
                     Viewport::Viewer => match self.viewer_mode {
                         ViewerMode::Full => {
-                            if let Some(&next) = self.viewer_edges.iter().find(|&h| h > &self.viewer_selected) {
-                                self.viewer_selected = next;
+                            if let Some(&next) = self.tab.viewer_edges.iter().find(|&h| h > &self.tab.viewer_selected) {
+                                self.tab.viewer_selected = next;
                             }
                         },
                         ViewerMode::Hunks => {
-                            self.viewer_selected += half;
+                            self.tab.viewer_selected += half;
                         },
                         ViewerMode::Split => {
-                            self.viewer_selected += half;
+                            self.tab.viewer_selected += half;
                         },
                     },
                     Viewport::Settings => {
@@ -1541,61 +1594,61 @@ impl App {
             },
             Focus::Inspector => {
                 let half = (self.layout.inspector.height.saturating_sub(3) as usize) / 2;
-                self.inspector_selected += half;
+                self.tab.inspector_selected += half;
             },
             Focus::StatusTop => {
                 let half = (self.layout.status_top.height.saturating_sub(3) as usize) / 2;
-                self.status_top_selected += half;
+                self.tab.status_top_selected += half;
             },
             Focus::StatusBottom => {
                 let half = (self.layout.status_bottom.height.saturating_sub(3) as usize) / 2;
-                self.status_bottom_selected += half;
+                self.tab.status_bottom_selected += half;
             },
             _ => {},
         }
     }
 
     pub fn on_scroll_up_branch(&mut self) {
-        if self.focus != Focus::Viewport || self.viewport != Viewport::Graph {
+        if self.tab.focus != Focus::Viewport || self.tab.viewport != Viewport::Graph {
             return;
         }
 
-        if self.graph_tx.is_some() {
-            self.request_graph_lookup(GraphLookupKind::BranchIndex { from: self.graph_selected, direction: GraphBranchJumpDirection::Previous }, PendingGraphLookup::SelectIndex);
+        if self.tab.graph_tx.is_some() {
+            self.request_graph_lookup(GraphLookupKind::BranchIndex { from: self.tab.graph_selected, direction: GraphBranchJumpDirection::Previous }, PendingGraphLookup::SelectIndex);
             return;
         }
 
-        if let Some(&next) = self.graph_visible_branch_indices().iter().rev().find(|&&idx| idx < self.graph_selected) {
+        if let Some(&next) = self.graph_visible_branch_indices().iter().rev().find(|&&idx| idx < self.tab.graph_selected) {
             self.select_graph_index(next);
         }
     }
 
     pub fn on_scroll_down_branch(&mut self) {
-        if self.focus != Focus::Viewport || self.viewport != Viewport::Graph {
+        if self.tab.focus != Focus::Viewport || self.tab.viewport != Viewport::Graph {
             return;
         }
 
-        if self.graph_tx.is_some() {
-            self.request_graph_lookup(GraphLookupKind::BranchIndex { from: self.graph_selected, direction: GraphBranchJumpDirection::Next }, PendingGraphLookup::SelectIndex);
+        if self.tab.graph_tx.is_some() {
+            self.request_graph_lookup(GraphLookupKind::BranchIndex { from: self.tab.graph_selected, direction: GraphBranchJumpDirection::Next }, PendingGraphLookup::SelectIndex);
             return;
         }
 
-        if let Some(&next) = self.graph_visible_branch_indices().iter().find(|&&idx| idx > self.graph_selected) {
+        if let Some(&next) = self.graph_visible_branch_indices().iter().find(|&&idx| idx > self.tab.graph_selected) {
             self.select_graph_index(next);
         }
     }
 
     pub fn on_scroll_up_commit(&mut self) {
-        if let Some(repo) = &self.repo
-            && self.focus == Focus::Viewport
-            && self.viewport == Viewport::Graph
+        if let Some(repo) = &self.tab.repo
+            && self.tab.focus == Focus::Viewport
+            && self.tab.viewport == Viewport::Graph
         {
-            if self.graph_tx.is_some() {
-                self.request_graph_lookup(GraphLookupKind::ChildIndex { index: self.graph_selected }, PendingGraphLookup::SelectIndex);
+            if self.tab.graph_tx.is_some() {
+                self.request_graph_lookup(GraphLookupKind::ChildIndex { index: self.tab.graph_selected }, PendingGraphLookup::SelectIndex);
                 return;
             }
 
-            let Some(oid) = self.graph_oid_at(self.graph_selected) else {
+            let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
                 return;
             };
 
@@ -1604,6 +1657,7 @@ impl App {
             }
 
             let mut child_positions: Vec<usize> = self
+                .tab
                 .graph
                 .graph_window
                 .as_ref()
@@ -1617,12 +1671,13 @@ impl App {
 
             if child_positions.is_empty() {
                 child_positions = self
+                    .tab
                     .oids
                     .get_sorted_aliases()
                     .iter()
                     .enumerate()
                     .filter_map(|(idx, &alias)| {
-                        let child_oid = self.oids.get_oid_by_alias(alias);
+                        let child_oid = self.tab.oids.get_oid_by_alias(alias);
                         let commit = repo.find_commit(*child_oid).ok()?;
                         if commit.parent_ids().any(|parent_oid| parent_oid == oid) { Some(idx) } else { None }
                     })
@@ -1636,16 +1691,16 @@ impl App {
     }
 
     pub fn on_scroll_down_commit(&mut self) {
-        if let Some(repo) = &self.repo
-            && self.focus == Focus::Viewport
-            && self.viewport == Viewport::Graph
+        if let Some(repo) = &self.tab.repo
+            && self.tab.focus == Focus::Viewport
+            && self.tab.viewport == Viewport::Graph
         {
-            if self.graph_tx.is_some() {
-                self.request_graph_lookup(GraphLookupKind::ParentIndex { index: self.graph_selected }, PendingGraphLookup::SelectIndex);
+            if self.tab.graph_tx.is_some() {
+                self.request_graph_lookup(GraphLookupKind::ParentIndex { index: self.tab.graph_selected }, PendingGraphLookup::SelectIndex);
                 return;
             }
 
-            let Some(oid) = self.graph_oid_at(self.graph_selected) else {
+            let Some(oid) = self.graph_oid_at(self.tab.graph_selected) else {
                 return;
             };
 
@@ -1661,9 +1716,9 @@ impl App {
                 let Some(parent_oid) = commit.parent_ids().next() else {
                     return;
                 };
-                self.graph.graph_window.as_ref().and_then(|window| window.rows.iter().find(|row| row.oid == parent_oid).map(|row| row.index)).or_else(|| {
-                    let parent_alias = self.oids.aliases.get(&parent_oid).copied()?;
-                    self.oids.get_sorted_aliases().iter().position(|&alias| alias == parent_alias)
+                self.tab.graph.graph_window.as_ref().and_then(|window| window.rows.iter().find(|row| row.oid == parent_oid).map(|row| row.index)).or_else(|| {
+                    let parent_alias = self.tab.oids.aliases.get(&parent_oid).copied()?;
+                    self.tab.oids.get_sorted_aliases().iter().position(|&alias| alias == parent_alias)
                 })
             };
 
@@ -1677,85 +1732,99 @@ impl App {
         // Preserve the closest logical diff location when switching viewer modes.
         match self.viewer_mode {
             ViewerMode::Full => {
-                let full_idx = self.viewer_selected;
-                let hunk_view_idx = self.viewer_hunks.iter().enumerate().min_by_key(|(_, h)| h.abs_diff(full_idx)).map(|(i, _)| i).unwrap_or(0);
+                let full_idx = self.tab.viewer_selected;
+                let hunk_view_idx = self.tab.viewer_hunks.iter().enumerate().min_by_key(|(_, h)| h.abs_diff(full_idx)).map(|(i, _)| i).unwrap_or(0);
                 self.viewer_mode = ViewerMode::Hunks;
-                self.viewer_selected = hunk_view_idx;
+                self.tab.viewer_selected = hunk_view_idx;
             },
             ViewerMode::Hunks => {
-                let hunk_view_idx = self.viewer_selected;
-                if let Some(&full_idx) = self.viewer_hunks.get(hunk_view_idx) {
+                let hunk_view_idx = self.tab.viewer_selected;
+                if let Some(&full_idx) = self.tab.viewer_hunks.get(hunk_view_idx) {
                     self.viewer_mode = ViewerMode::Full;
-                    self.viewer_selected = full_idx;
+                    self.tab.viewer_selected = full_idx;
                 } else {
                     self.viewer_mode = ViewerMode::Full;
-                    self.viewer_selected = 0;
+                    self.tab.viewer_selected = 0;
                 }
             },
             ViewerMode::Split => {
-                let full_idx = self.split_unified_index(self.viewer_selected);
-                let hunk_view_idx = self.viewer_hunks.iter().enumerate().min_by_key(|(_, h)| h.abs_diff(full_idx)).map(|(i, _)| i).unwrap_or(0);
+                let full_idx = self.split_unified_index(self.tab.viewer_selected);
+                let hunk_view_idx = self.tab.viewer_hunks.iter().enumerate().min_by_key(|(_, h)| h.abs_diff(full_idx)).map(|(i, _)| i).unwrap_or(0);
                 self.viewer_mode = ViewerMode::Hunks;
-                self.viewer_selected = hunk_view_idx;
+                self.tab.viewer_selected = hunk_view_idx;
             },
         }
 
-        self.viewer_scroll.set(self.viewer_selected);
+        self.tab.viewer_scroll.set(self.tab.viewer_selected);
     }
 
     pub fn on_toggle_split_diff_mode(&mut self) {
         match self.viewer_mode {
             ViewerMode::Split => {
-                let full_idx = self.split_unified_index(self.viewer_selected);
+                let full_idx = self.split_unified_index(self.tab.viewer_selected);
                 self.viewer_mode = ViewerMode::Full;
-                self.viewer_selected = full_idx.min(self.viewer_lines.len().saturating_sub(1));
+                self.tab.viewer_selected = full_idx.min(self.tab.viewer_lines.len().saturating_sub(1));
             },
             ViewerMode::Full => {
-                let full_idx = self.viewer_selected;
+                let full_idx = self.tab.viewer_selected;
                 self.viewer_mode = ViewerMode::Split;
-                self.viewer_selected = self.closest_split_row_for_unified(full_idx);
+                self.tab.viewer_selected = self.closest_split_row_for_unified(full_idx);
             },
             ViewerMode::Hunks => {
-                let full_idx = self.viewer_hunks.get(self.viewer_selected).copied().unwrap_or(0);
+                let full_idx = self.tab.viewer_hunks.get(self.tab.viewer_selected).copied().unwrap_or(0);
                 self.viewer_mode = ViewerMode::Split;
-                self.viewer_selected = self.closest_split_row_for_unified(full_idx);
+                self.tab.viewer_selected = self.closest_split_row_for_unified(full_idx);
             },
         }
 
         self.mark_viewer_layout_dirty();
-        self.viewer_scroll.set(self.viewer_selected);
+        self.tab.viewer_scroll.set(self.tab.viewer_selected);
         self.save_layout();
     }
 
+    // Re-reads a file the viewer truncated, this time without the size limit.
+    pub fn on_load_full_diff(&mut self) {
+        if self.tab.viewport != Viewport::Viewer || !matches!(self.tab.viewer_diff, Some(FileDiff::Truncated { .. })) {
+            return;
+        }
+        let Some(repo) = self.tab.repo.clone() else {
+            return;
+        };
+        let oid = if self.tab.graph_selected != 0 { self.graph_oid_at(self.tab.graph_selected).unwrap_or_else(Oid::zero) } else { Oid::zero() };
+        self.load_viewer_file(oid, &repo, None);
+        self.tab.viewer_scroll.set(self.tab.viewer_selected);
+    }
+
     pub fn on_scroll_to_beginning(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
-                self.branches_selected = 0;
+                self.tab.branches_selected = 0;
             },
             Focus::Tags => {
-                self.tags_selected = 0;
+                self.tab.tags_selected = 0;
             },
             Focus::Stashes => {
-                self.stashes_selected = 0;
+                self.tab.stashes_selected = 0;
             },
             Focus::Reflogs => {
-                self.reflogs_selected = 0;
+                self.tab.reflogs_selected = 0;
             },
             Focus::Worktrees => {
-                self.worktrees_selected = 0;
+                self.tab.worktrees_selected = 0;
             },
             Focus::Submodules => {
-                self.submodules_selected = 0;
+                self.tab.submodules_selected = 0;
             },
             Focus::Search => {
-                self.search_selected = 0;
+                self.tab.search_selected = 0;
             },
-            Focus::Viewport => match self.viewport {
+            Focus::Viewport => match self.tab.viewport {
                 Viewport::Graph => {
+                    self.record_jump();
                     self.select_graph_index(0);
                 },
                 Viewport::Viewer => {
-                    self.viewer_selected = 0;
+                    self.tab.viewer_selected = 0;
                 },
                 Viewport::Settings => {
                     self.settings_selected = 0;
@@ -1765,47 +1834,48 @@ impl App {
                 },
             },
             Focus::Inspector => {
-                self.inspector_selected = 0;
+                self.tab.inspector_selected = 0;
             },
             Focus::StatusTop => {
-                self.status_top_selected = 0;
+                self.tab.status_top_selected = 0;
             },
             Focus::StatusBottom => {
-                self.status_bottom_selected = 0;
+                self.tab.status_bottom_selected = 0;
             },
             _ => {},
         };
     }
 
     pub fn on_scroll_to_end(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
-                self.branches_selected = usize::MAX;
+                self.tab.branches_selected = usize::MAX;
             },
             Focus::Tags => {
-                self.tags_selected = usize::MAX;
+                self.tab.tags_selected = usize::MAX;
             },
             Focus::Stashes => {
-                self.stashes_selected = usize::MAX;
+                self.tab.stashes_selected = usize::MAX;
             },
             Focus::Reflogs => {
-                self.reflogs_selected = usize::MAX;
+                self.tab.reflogs_selected = usize::MAX;
             },
             Focus::Worktrees => {
-                self.worktrees_selected = usize::MAX;
+                self.tab.worktrees_selected = usize::MAX;
             },
             Focus::Submodules => {
-                self.submodules_selected = usize::MAX;
+                self.tab.submodules_selected = usize::MAX;
             },
             Focus::Search => {
-                self.search_selected = Self::last_index(self.search_result_count());
+                self.tab.search_selected = Self::last_index(self.search_result_count());
             },
-            Focus::Viewport => match self.viewport {
+            Focus::Viewport => match self.tab.viewport {
                 Viewport::Graph => {
+                    self.record_jump();
                     self.select_graph_index(Self::last_index(self.graph_commit_count()));
                 },
                 Viewport::Viewer => {
-                    self.viewer_selected = usize::MAX;
+                    self.tab.viewer_selected = usize::MAX;
                 },
                 Viewport::Settings => {
                     self.settings_selected = usize::MAX;
@@ -1815,39 +1885,50 @@ impl App {
                 },
             },
             Focus::Inspector => {
-                self.inspector_selected = usize::MAX;
+                self.tab.inspector_selected = usize::MAX;
             },
             Focus::StatusTop => {
-                self.status_top_selected = usize::MAX;
+                self.tab.status_top_selected = usize::MAX;
             },
             Focus::StatusBottom => {
-                self.status_bottom_selected = usize::MAX;
+                self.tab.status_bottom_selected = usize::MAX;
             },
             _ => {},
         };
     }
 
     pub(crate) fn branch_name_at_pane_selection(&self) -> Option<String> {
-        if let Some(window) = &self.graph.branches_window
-            && self.branches_selected >= window.start
-            && self.branches_selected < window.end
-            && let Some(GraphPaneRow::Branch { name, .. }) = window.rows.get(self.branches_selected - window.start)
+        if let Some(window) = &self.tab.graph.branches_window
+            && self.tab.branches_selected >= window.start
+            && self.tab.branches_selected < window.end
+            && let Some(GraphPaneRow::Branch { name, .. }) = window.rows.get(self.tab.branches_selected - window.start)
         {
             return Some(name.clone());
         }
-        self.branches.sorted.get(self.branches_selected).map(|(_, branch)| branch.clone())
+        self.tab.branches.sorted.get(self.tab.branches_selected).map(|(_, branch)| branch.clone())
+    }
+
+    pub(crate) fn tag_name_at_pane_selection(&self) -> Option<String> {
+        if let Some(window) = &self.tab.graph.tags_window
+            && self.tab.tags_selected >= window.start
+            && self.tab.tags_selected < window.end
+            && let Some(GraphPaneRow::Tag { name, .. }) = window.rows.get(self.tab.tags_selected - window.start)
+        {
+            return Some(name.clone());
+        }
+        self.tab.tags.sorted.get(self.tab.tags_selected).map(|(_, tag)| tag.clone())
     }
 
     fn all_branch_names(&self) -> im::HashSet<String> {
-        if let Some(repo) = &self.repo {
+        if let Some(repo) = &self.tab.repo {
             let names = git_current_branch_names(repo);
             if !names.is_empty() {
                 return names;
             }
         }
 
-        let mut names: im::HashSet<String> = self.branches.sorted.iter().map(|(_, branch)| branch.clone()).collect();
-        if let Some(window) = &self.graph.branches_window {
+        let mut names: im::HashSet<String> = self.tab.branches.sorted.iter().map(|(_, branch)| branch.clone()).collect();
+        if let Some(window) = &self.tab.graph.branches_window {
             for row in &window.rows {
                 if let GraphPaneRow::Branch { name, .. } = row {
                     names.insert(name.clone());
@@ -1858,41 +1939,42 @@ impl App {
     }
 
     fn save_hidden_branch_names(&self) {
-        if self.repo.is_some()
-            && let Some(path) = &self.path
+        if self.tab.repo.is_some()
+            && let Some(path) = &self.tab.path
         {
-            save_branch_visibility(path, &self.branches.hidden_branch_names);
+            save_branch_visibility(path, &self.tab.branches.hidden_branch_names);
         }
     }
 
     fn solo_branch_name(&mut self, branch: &str) {
         let all_branches = self.all_branch_names();
         if all_branches.len() <= 1 || !all_branches.contains(branch) {
-            self.branches.hidden_branch_names.clear();
+            self.tab.branches.hidden_branch_names.clear();
         } else {
-            self.branches.hidden_branch_names = all_branches;
-            self.branches.hidden_branch_names.remove(branch);
+            self.tab.branches.hidden_branch_names = all_branches;
+            self.tab.branches.hidden_branch_names.remove(branch);
         }
         self.save_hidden_branch_names();
     }
 
     fn toggle_branch_name(&mut self, branch: &str) {
-        if self.branches.hidden_branch_names.contains(branch) {
-            self.branches.hidden_branch_names.remove(branch);
+        if self.tab.branches.hidden_branch_names.contains(branch) {
+            self.tab.branches.hidden_branch_names.remove(branch);
         } else {
-            self.branches.hidden_branch_names.insert(branch.to_string());
+            self.tab.branches.hidden_branch_names.insert(branch.to_string());
         }
 
         let all_branches = self.all_branch_names();
-        let has_visible_branch = all_branches.iter().any(|branch| !self.branches.hidden_branch_names.contains(branch));
+        let has_visible_branch = all_branches.iter().any(|branch| !self.tab.branches.hidden_branch_names.contains(branch));
         if !all_branches.is_empty() && !has_visible_branch {
-            self.branches.hidden_branch_names.clear();
+            self.tab.branches.hidden_branch_names.clear();
         }
         self.save_hidden_branch_names();
     }
 
     pub(crate) fn graph_branch_choices(&self, alias: u32) -> Vec<String> {
         let mut choices: Vec<String> = self
+            .tab
             .graph
             .graph_window
             .as_ref()
@@ -1906,18 +1988,24 @@ impl App {
             return choices;
         }
 
-        choices =
-            self.branches.sorted.iter().filter(|(branch_alias, branch)| *branch_alias == alias && !self.branches.hidden_branch_names.contains(branch)).map(|(_, branch)| branch.clone()).collect();
+        choices = self
+            .tab
+            .branches
+            .sorted
+            .iter()
+            .filter(|(branch_alias, branch)| *branch_alias == alias && !self.tab.branches.hidden_branch_names.contains(branch))
+            .map(|(_, branch)| branch.clone())
+            .collect();
 
         choices
     }
 
     pub(crate) fn is_local_branch_name(&self, branch: &str) -> bool {
-        if let Some(repo) = &self.repo {
+        if let Some(repo) = &self.tab.repo {
             return repo.find_branch(branch, BranchType::Local).is_ok();
         }
 
-        self.branches.local.values().any(|branches| branches.iter().any(|local| local.as_str() == branch))
+        self.tab.branches.local.values().any(|branches| branches.iter().any(|local| local.as_str() == branch))
     }
 
     pub(crate) fn graph_local_branch_choices(&self, alias: u32) -> Vec<String> {
@@ -1932,11 +2020,11 @@ impl App {
     }
 
     fn apply_graph_branch_action(&mut self, action: BranchModalAction) {
-        if self.viewport != Viewport::Graph || self.graph_selected == 0 {
+        if self.tab.viewport != Viewport::Graph || self.tab.graph_selected == 0 {
             return;
         }
 
-        let Some(alias) = self.graph_alias_at(self.graph_selected) else {
+        let Some(alias) = self.graph_alias_at(self.tab.graph_selected) else {
             return;
         };
         let branch_names = self.graph_branch_choices(alias);
@@ -1960,13 +2048,13 @@ impl App {
             _ => {
                 self.modal_branch_action = action;
                 self.modal_solo_selected = 0;
-                self.focus = Focus::ModalSolo;
+                self.tab.focus = Focus::ModalSolo;
             },
         }
     }
 
     pub fn on_toggle_branch(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
                 if let Some(branch) = self.branch_name_at_pane_selection() {
                     self.toggle_branch_name(&branch);
@@ -1979,7 +2067,7 @@ impl App {
     }
 
     pub fn on_solo_branch(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::Branches => {
                 if let Some(branch) = self.branch_name_at_pane_selection() {
                     self.solo_branch_name(&branch);
@@ -1992,98 +2080,133 @@ impl App {
     }
 
     pub fn on_back(&mut self) {
-        match self.focus {
+        // Like vim, the first escape only leaves visual mode.
+        if self.visual_range().is_some() {
+            self.tab.visual = None;
+            return;
+        }
+        match self.tab.focus {
             Focus::ModalCommit => {
                 self.modal_input.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalCherrypick => {
                 self.modal_input.clear();
                 self.pending_cherrypick_oid = None;
-                self.focus = Focus::Viewport;
+                self.pending_mainline = None;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalRevert => {
                 self.modal_input.clear();
                 self.pending_revert_oid = None;
-                self.focus = Focus::Viewport;
+                self.pending_mainline = None;
+                self.tab.focus = Focus::Viewport;
+            },
+            Focus::ModalMainline => {
+                self.close_mainline_modal();
+            },
+            Focus::ModalJournal => {
+                self.modal_journal_selected = 0;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalCreateBranch => {
                 self.modal_input.clear();
                 self.clear_pending_branch_target();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalRenameBranch => {
                 self.modal_input.clear();
                 self.modal_rename_branch_source = None;
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalCreateWorktreeName | Focus::ModalCreateWorktreePath => {
                 self.modal_input.clear();
                 self.modal_worktree_name.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalWorktreeChooser | Focus::ModalRemoveWorktree => {
                 self.close_worktree_modal();
             },
+            Focus::ModalBatch => {
+                self.close_batch_modal();
+            },
+            Focus::ModalReset => {
+                self.close_reset_modal();
+            },
+            Focus::ModalExportPatches | Focus::ModalApplyPatchPath | Focus::ModalApplyPatch => {
+                self.close_patch_modal();
+            },
+            Focus::ModalExportGraph => {
+                self.close_export_graph_modal();
+            },
             Focus::ModalSolo => {
                 self.modal_solo_selected = 0;
                 self.modal_branch_action = BranchModalAction::Solo;
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalDeleteBranch => {
                 self.modal_delete_branch_selected = 0;
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalDeleteTag => {
                 self.modal_delete_tag_selected = 0;
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalLockWorktree => {
                 self.modal_input.clear();
-                self.focus = Focus::Worktrees;
+                self.tab.focus = Focus::Worktrees;
             },
             Focus::ModalRemoteAction | Focus::ModalRemoteDelete | Focus::ModalRemoteName | Focus::ModalRemoteUrl => {
                 self.close_remote_modal();
             },
-            Focus::ModalGraphLaneLimit => {
+            Focus::ModalGraphLaneLimit | Focus::ModalViewerFileSizeLimit | Focus::ModalDeepen => {
                 self.modal_input.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalFileSearch => {
                 self.modal_input.clear();
                 self.modal_file_search_results.clear();
                 self.modal_file_search_selected = 0;
                 self.modal_file_search_scroll.set(0);
-                self.focus = self.modal_file_search_return_focus;
+                self.tab.focus = self.modal_file_search_return_focus;
                 self.modal_file_search_return_focus = Focus::Viewport;
             },
+            Focus::ModalContentSearch => {
+                self.close_content_search_modal();
+            },
+            Focus::ModalCommandPalette => {
+                self.close_palette();
+            },
             Focus::ModalKeyCapture => {
                 self.close_key_capture();
             },
             Focus::ModalAuth => {
                 self.cancel_auth_prompt();
             },
-            Focus::ModalNetworkProgress => {},
+            Focus::ModalNetworkProgress | Focus::ModalHookProgress => {},
+            Focus::ModalCustomCommand => {
+                self.close_custom_command_modal();
+            },
             Focus::ModalCheckout => {
                 self.modal_checkout_selected = 0;
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalGrep | Focus::ModalTag => {
                 self.modal_input.clear();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
-            Focus::Viewport => match self.viewport {
+            Focus::Viewport => match self.tab.viewport {
                 Viewport::Graph => {
                     if self.spinner.is_running() {
                         return;
                     }
-                    self.viewport = Viewport::Splash;
-                    self.focus = Focus::Viewport;
+                    self.tab.viewport = Viewport::Splash;
+                    self.tab.focus = Focus::Viewport;
 
                     // Highlight the current repository in the recent list when possible.
                     let mut selected = 0;
 
-                    if let Some(path) = &self.path
+                    if let Some(path) = &self.tab.path
                         && let Some(pos) = self.recent.iter().position(|p| p == path)
                     {
                         selected = pos;
@@ -2095,23 +2218,23 @@ impl App {
                     if self.spinner.is_running() {
                         return;
                     }
-                    self.viewer_selected = 0;
-                    self.viewport = Viewport::Graph;
-                    self.focus = Focus::Viewport;
-                    self.file_name = None;
+                    self.tab.viewer_selected = 0;
+                    self.tab.viewport = Viewport::Graph;
+                    self.tab.focus = Focus::Viewport;
+                    self.tab.file_name = None;
                 },
                 _ => {
-                    self.viewer_selected = 0;
-                    self.viewport = Viewport::Graph;
-                    self.focus = Focus::Viewport;
-                    self.file_name = None;
+                    self.tab.viewer_selected = 0;
+                    self.tab.viewport = Viewport::Graph;
+                    self.tab.focus = Focus::Viewport;
+                    self.tab.file_name = None;
                 },
             },
             _ => {
-                self.viewer_selected = 0;
-                self.viewport = Viewport::Graph;
-                self.focus = Focus::Viewport;
-                self.file_name = None;
+                self.tab.viewer_selected = 0;
+                self.tab.viewport = Viewport::Graph;
+                self.tab.focus = Focus::Viewport;
+                self.tab.file_name = None;
             },
         };
     }
@@ -2122,27 +2245,48 @@ impl App {
     }
 
     pub fn on_reload_all_branches(&mut self) {
-        self.branches.hidden_branch_names.clear();
+        self.tab.branches.hidden_branch_names.clear();
         self.save_hidden_branch_names();
         self.reload(None);
         self.cleanup_after_reload();
     }
 
     fn cleanup_after_reload(&mut self) {
-        match self.focus {
+        match self.tab.focus {
             Focus::ModalWorktreeChooser | Focus::ModalRemoveWorktree => {
                 self.clear_worktree_modal_state();
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
+            },
+            Focus::ModalBatch => {
+                self.close_batch_modal();
+            },
+            Focus::ModalReset => {
+                self.close_reset_modal();
+            },
+            Focus::ModalExportPatches | Focus::ModalApplyPatchPath | Focus::ModalApplyPatch => {
+                self.close_patch_modal();
+            },
+            Focus::ModalExportGraph => {
+                self.close_export_graph_modal();
             },
             Focus::ModalCherrypick => {
                 self.modal_input.clear();
                 self.pending_cherrypick_oid = None;
-                self.focus = Focus::Viewport;
+                self.pending_mainline = None;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalRevert => {
                 self.modal_input.clear();
                 self.pending_revert_oid = None;
-                self.focus = Focus::Viewport;
+                self.pending_mainline = None;
+                self.tab.focus = Focus::Viewport;
+            },
+            Focus::ModalMainline => {
+                self.close_mainline_modal();
+            },
+            Focus::ModalJournal => {
+                self.modal_journal_selected = 0;
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalCommit
             | Focus::ModalCreateBranch
@@ -2153,6 +2297,8 @@ impl App {
             | Focus::ModalRemoteName
             | Focus::ModalRemoteUrl
             | Focus::ModalGraphLaneLimit
+            | Focus::ModalViewerFileSizeLimit
+            | Focus::ModalDeepen
             | Focus::ModalFileSearch => {
                 self.modal_input.clear();
                 self.modal_file_search_results.clear();
@@ -2160,7 +2306,15 @@ impl App {
                 self.modal_file_search_scroll.set(0);
                 self.clear_pending_branch_target();
                 self.modal_rename_branch_source = None;
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
+            },
+            Focus::ModalContentSearch => {
+                self.close_content_search_modal();
+                self.tab.focus = Focus::Viewport;
+            },
+            Focus::ModalCommandPalette => {
+                self.close_palette();
+                self.tab.focus = Focus::Viewport;
             },
             Focus::ModalRemoteAction | Focus::ModalRemoteDelete => {
                 self.close_remote_modal();
@@ -2172,10 +2326,15 @@ impl App {
                 self.pending_network_request = None;
                 self.network_handle = None;
                 self.network_auth_attempts = 0;
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
+            },
+            // A running hook keeps its modal; dropping the task would orphan the process and its follow-up.
+            Focus::ModalHookProgress => {},
+            Focus::ModalCustomCommand => {
+                self.close_custom_command_modal();
             },
             Focus::ModalCheckout => {
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             },
             _ => {},
         }
@@ -2189,13 +2348,14 @@ impl App {
 
     pub fn on_reset_layout(&mut self) {
         let config = LayoutConfig::default();
-        let should_reload = self.repo.is_some() && self.layout_config.is_graph_reflogs != config.is_graph_reflogs;
+        let should_reload =
+            self.tab.repo.is_some() && (self.layout_config.is_graph_reflogs != config.is_graph_reflogs || self.layout_config.is_graph_original_parents != config.is_graph_original_parents);
         self.layout_config = config;
         self.mouse_drag = None;
         self.mark_viewer_layout_dirty();
-        self.viewport = Viewport::Graph;
-        self.focus = Focus::Viewport;
-        self.file_name = None;
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
+        self.tab.file_name = None;
         self.save_layout();
         if should_reload {
             self.reload(None);
@@ -2208,20 +2368,20 @@ impl App {
             return;
         }
 
-        let was_settings = self.viewport == Viewport::Settings;
+        let was_settings = self.tab.viewport == Viewport::Settings;
         let settings_selected = self.settings_selected;
         let settings_scroll = self.settings_scroll.get();
 
         self.layout_config.graph_lane_limit = limit;
         self.save_layout();
 
-        if self.repo.is_some() {
+        if self.tab.repo.is_some() {
             self.reload(None);
         }
 
         if was_settings {
-            self.viewport = Viewport::Settings;
-            self.focus = Focus::Viewport;
+            self.tab.viewport = Viewport::Settings;
+            self.tab.focus = Focus::Viewport;
             self.settings_selected = settings_selected;
             self.settings_scroll.set(settings_scroll);
         }
@@ -2238,7 +2398,7 @@ impl App {
     }
 
     pub fn on_toggle_shas(&mut self) {
-        if self.viewport != Viewport::Splash {
+        if self.tab.viewport != Viewport::Splash {
             self.layout_config.is_shas = !self.layout_config.is_shas;
             self.save_layout();
         }
@@ -2253,13 +2413,13 @@ impl App {
     pub fn on_toggle_branches(&mut self) {
         self.layout_config.is_branches = !self.layout_config.is_branches;
         self.mark_viewer_layout_dirty();
-        if self.viewport == Viewport::Settings {
+        if self.tab.viewport == Viewport::Settings {
             return;
         }
         if self.layout_config.is_branches {
-            self.focus = Focus::Branches;
+            self.tab.focus = Focus::Branches;
         } else {
-            self.focus = Focus::Viewport;
+            self.tab.focus = Focus::Viewport;
         }
         self.save_layout();
     }
@@ -2267,13 +2427,13 @@ impl App {
     pub fn on_toggle_tags(&mut self) {
         self.layout_config.is_tags = !self.layout_config.is_tags;
         self.mark_viewer_layout_dirty();
-        if self.viewport == Viewport::Settings {
+        if self.tab.viewport == Viewport::Settings {
             return;
         }
         if self.layout_config.is_tags {
-            self.focus = Focus::Tags;
+            self.tab.focus = Focus::Tags;
         } else {
-            self.focus = Focus::Viewport;
+            self.tab.focus = Focus::Viewport;
         }
         self.save_layout();
     }
@@ -2281,13 +2441,13 @@ impl App {
     pub fn on_toggle_stashes(&mut self) {
         self.layout_config.is_stashes = !self.layout_config.is_stashes;
         self.mark_viewer_layout_dirty();
-        if self.viewport == Viewport::Settings {
+        if self.tab.viewport == Viewport::Settings {
             return;
         }
         if self.layout_config.is_stashes {
-            self.focus = Focus::Stashes;
+            self.tab.focus = Focus::Stashes;
         } else {
-            self.focus = Focus::Viewport;
+            self.tab.focus = Focus::Viewport;
         }
         self.save_layout();
     }
@@ -2295,13 +2455,13 @@ impl App {
     pub fn on_toggle_reflogs(&mut self) {
         self.layout_config.is_reflogs = !self.layout_config.is_reflogs;
         self.mark_viewer_layout_dirty();
-        if self.viewport == Viewport::Settings {
+        if self.tab.viewport == Viewport::Settings {
             return;
         }
         if self.layout_config.is_reflogs {
-            self.focus = Focus::Reflogs;
+            self.tab.focus = Focus::Reflogs;
         } else {
-            self.focus = Focus::Viewport;
+            self.tab.focus = Focus::Viewport;
         }
         self.save_layout();
     }
@@ -2310,44 +2470,62 @@ impl App {
         self.layout_config.is_graph_reflogs = !self.layout_config.is_graph_reflogs;
         self.mark_viewer_layout_dirty();
         self.save_layout();
-        if self.repo.is_some() {
+        if self.tab.repo.is_some() {
             self.reload(None);
-            self.focus = Focus::Viewport;
-            self.viewport = Viewport::Graph;
+            self.tab.focus = Focus::Viewport;
+            self.tab.viewport = Viewport::Graph;
+        }
+    }
+
+    // Replace refs change which commits are reachable, so the walk starts over.
+    pub fn on_toggle_original_parents(&mut self) {
+        self.layout_config.is_graph_original_parents = !self.layout_config.is_graph_original_parents;
+        self.save_layout();
+        if self.tab.repo.is_some() {
+            self.reload(None);
+            self.tab.focus = Focus::Viewport;
+            self.tab.viewport = Viewport::Graph;
         }
     }
 
     pub fn on_toggle_graph_dates(&mut self) {
-        if self.viewport != Viewport::Splash {
+        if self.tab.viewport != Viewport::Splash {
             self.layout_config.is_graph_dates = !self.layout_config.is_graph_dates;
             self.save_layout();
         }
     }
 
     pub fn on_toggle_graph_committers(&mut self) {
-        if self.viewport != Viewport::Splash {
+        if self.tab.viewport != Viewport::Splash {
             self.layout_config.is_graph_committers = !self.layout_config.is_graph_committers;
             self.save_layout();
         }
     }
 
     pub fn on_toggle_graph_refs(&mut self) {
-        if self.viewport != Viewport::Splash {
+        if self.tab.viewport != Viewport::Splash {
             self.layout_config.is_graph_refs = !self.layout_config.is_graph_refs;
             self.save_layout();
         }
     }
 
+    pub fn on_toggle_graph_signatures(&mut self) {
+        if self.tab.viewport != Viewport::Splash {
+            self.layout_config.is_graph_signatures = !self.layout_config.is_graph_signatures;
+            self.save_layout();
+        }
+    }
+
     pub fn on_toggle_worktrees(&mut self) {
         self.layout_config.is_worktrees = !self.layout_config.is_worktrees;
         self.mark_viewer_layout_dirty();
-        if self.viewport == Viewport::Settings {
+        if self.tab.viewport == Viewport::Settings {
             return;
         }
         if self.layout_config.is_worktrees {
-            self.focus = Focus::Worktrees;
+            self.tab.focus = Focus::Worktrees;
         } else {
-            self.focus = Focus::Viewport;
+            self.tab.focus = Focus::Viewport;
         }
         self.save_layout();
     }
@@ -2355,13 +2533,13 @@ impl App {
     pub fn on_toggle_submodules(&mut self) {
         self.layout_config.is_submodules = !self.layout_config.is_submodules;
         self.mark_viewer_layout_dirty();
-        if self.viewport == Viewport::Settings {
+        if self.tab.viewport == Viewport::Settings {
             return;
         }
         if self.layout_config.is_submodules {
-            self.focus = Focus::Submodules;
+            self.tab.focus = Focus::Submodules;
         } else {
-            self.focus = Focus::Viewport;
+            self.tab.focus = Focus::Viewport;
         }
         self.save_layout();
     }
@@ -2369,13 +2547,13 @@ impl App {
     pub fn on_toggle_search(&mut self) {
         self.layout_config.is_search = !self.layout_config.is_search;
         self.mark_viewer_layout_dirty();
-        if self.viewport == Viewport::Settings {
+        if self.tab.viewport == Viewport::Settings {
             return;
         }
         if self.layout_config.is_search {
-            self.focus = Focus::Search;
+            self.tab.focus = Focus::Search;
         } else {
-            self.focus = Focus::Viewport;
+            self.tab.focus = Focus::Viewport;
         }
         self.save_layout();
     }
@@ -2383,8 +2561,8 @@ impl App {
     pub fn on_toggle_status(&mut self) {
         self.layout_config.is_status = !self.layout_config.is_status;
         self.mark_viewer_layout_dirty();
-        if !self.layout_config.is_status && (self.focus == Focus::StatusTop || self.focus == Focus::StatusBottom) {
-            self.focus = Focus::Viewport;
+        if !self.layout_config.is_status && (self.tab.focus == Focus::StatusTop || self.tab.focus == Focus::StatusBottom) {
+            self.tab.focus = Focus::Viewport;
         }
         self.save_layout();
     }
@@ -2392,29 +2570,29 @@ impl App {
     pub fn on_toggle_inspector(&mut self) {
         self.layout_config.is_inspector = !self.layout_config.is_inspector;
         self.mark_viewer_layout_dirty();
-        if !self.layout_config.is_inspector && self.focus == Focus::Inspector {
+        if !self.layout_config.is_inspector && self.tab.focus == Focus::Inspector {
             if self.layout_config.is_status {
-                self.focus = Focus::StatusTop;
+                self.tab.focus = Focus::StatusTop;
             } else {
-                self.focus = Focus::Viewport;
+                self.tab.focus = Focus::Viewport;
             }
         }
         self.save_layout();
     }
 
     pub fn on_toggle_help(&mut self) {
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Graph => {
-                self.viewport = Viewport::Settings;
-                self.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Settings;
+                self.tab.focus = Focus::Viewport;
                 self.settings_tab = SettingsTab::General;
                 self.settings_selected = 0;
                 self.settings_scroll.set(0);
                 self.last_input_direction = None;
             },
             _ => {
-                self.viewport = Viewport::Graph;
-                self.focus = Focus::Viewport;
+                self.tab.viewport = Viewport::Graph;
+                self.tab.focus = Focus::Viewport;
             },
         };
     }
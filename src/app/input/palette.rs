@@ -0,0 +1,178 @@
+use crate::{
+    app::app::{App, Direction, Focus, PaneMarks, Viewport},
+    core::diff_service::FileDiff,
+    git::queries::files::rank_file_path_indices,
+    helpers::{
+        keymap::{COMMANDS, Command, InputMode, action_keymap_visible_entries, command_to_visual_string, keybinding_to_visual_string},
+        localisation::palette,
+    },
+};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaletteEntry {
+    pub command: Command,
+    pub label: String,
+    pub keys: String,
+    pub disabled: Option<&'static str>,
+    pub matched_indices: Vec<usize>,
+}
+
+impl App {
+    pub fn on_command_palette(&mut self) {
+        if self.is_modal_focus() {
+            return;
+        }
+        self.close_context_menu();
+        self.modal_palette_return_focus = self.tab.focus;
+        self.modal_palette_selected = 0;
+        self.modal_palette_scroll.set(0);
+        self.modal_input.clear();
+        self.tab.focus = Focus::ModalCommandPalette;
+    }
+
+    // Every command with its current key, ranked like file search once something is typed.
+    pub(crate) fn palette_entries(&self) -> Vec<PaletteEntry> {
+        let custom = self.custom_commands.iter().map(|command| Command::Custom(command.name.clone()));
+        let entries: Vec<PaletteEntry> = COMMANDS
+            .iter()
+            .filter(|command| **command != Command::CommandPalette)
+            .cloned()
+            .chain(custom)
+            .map(|command| PaletteEntry {
+                label: command_to_visual_string(&command),
+                keys: self.palette_keys(&command),
+                disabled: self.command_disabled_reason(&command),
+                matched_indices: Vec::new(),
+                command,
+            })
+            .collect();
+
+        let query = self.modal_input.value();
+        if query.trim().is_empty() {
+            return entries;
+        }
+
+        // Matches are keyed by entry, so a custom command named like a built-in one keeps its own row.
+        let labels: Vec<String> = entries.iter().map(|entry| entry.label.clone()).collect();
+        let mut entries: Vec<Option<PaletteEntry>> = entries.into_iter().map(Some).collect();
+        rank_file_path_indices(&labels, query, labels.len())
+            .into_iter()
+            .filter_map(|(index, result)| {
+                let mut entry = entries.get_mut(index)?.take()?;
+                entry.matched_indices = result.matched_indices;
+                Some(entry)
+            })
+            .collect()
+    }
+
+    // Normal mode keys are shown as is; action mode keys are prefixed with the action mode key.
+    pub(crate) fn palette_keys(&self, command: &Command) -> String {
+        let normal = self.keymaps.get(&InputMode::Normal);
+        if let Some(binding) = normal.and_then(|normal| normal.iter().find(|(_, mapped)| *mapped == command).map(|(binding, _)| binding)) {
+            return keybinding_to_visual_string(binding);
+        }
+
+        let action = self.keymaps.get(&InputMode::Action).map(|action| action_keymap_visible_entries(normal, action)).unwrap_or_default();
+        let Some(binding) = action.iter().find(|(_, mapped)| *mapped == command).map(|(binding, _)| binding) else {
+            return String::new();
+        };
+        let leader = normal.and_then(|normal| normal.iter().find(|(_, mapped)| **mapped == Command::ActionMode).map(|(binding, _)| keybinding_to_visual_string(binding)));
+        match leader {
+            Some(leader) => format!("{leader} {}", keybinding_to_visual_string(binding)),
+            None => keybinding_to_visual_string(binding),
+        }
+    }
+
+    // Commands that would do nothing right now, and why.
+    pub(crate) fn command_disabled_reason(&self, command: &Command) -> Option<&'static str> {
+        let is_splash = self.tab.viewport == Viewport::Splash;
+        match command {
+            Command::RemoveRecentRepository | Command::MoveRecentRepositoryUp | Command::MoveRecentRepositoryDown => (!is_splash).then(palette::START_SCREEN_ONLY),
+            Command::ReturnToParentRepository => self.tab.submodule_stack.is_empty().then(palette::NOT_IN_SUBMODULE),
+            Command::CloseTab | Command::NextTab | Command::PreviousTab => (self.tabs.len() < 2).then(palette::ONE_TAB),
+            _ if is_splash => (!Self::is_splash_command(command)).then(palette::NO_REPOSITORY),
+            _ => {
+                let repo = self.tab.repo.as_ref()?;
+                match command {
+                    Command::ContinueOperation | Command::AbortOperation => Self::active_operation_kind(repo).is_none().then(palette::NO_OPERATION),
+                    Command::Undo => self.tab.journal.is_empty().then(palette::NOTHING_TO_UNDO),
+                    Command::MarkRange => self.tab.graph_marked_oids.is_empty().then(palette::NO_MARKED_COMMITS),
+                    Command::ClearMarks => (self.tab.graph_marked_oids.is_empty() && self.tab.pane_marks == PaneMarks::default()).then(palette::NO_MARKED_ROWS),
+                    Command::JumpToMark => self.tab.graph_marks.is_empty().then(palette::NO_NAMED_MARKS),
+                    Command::JumpBack | Command::JumpForward => self.tab.jump_list.is_empty().then(palette::NO_JUMPS),
+                    Command::Drop | Command::Pop => self.tab.oids.stashes.is_empty().then(palette::NO_STASHES),
+                    Command::RemoveWorktree | Command::ToggleWorktreeLock => (!self.tab.worktrees.entries.iter().any(|entry| entry.is_linked())).then(palette::NO_LINKED_WORKTREES),
+                    Command::UpdateSubmodule | Command::SyncSubmodule => self.tab.submodules.entries.is_empty().then(palette::NO_SUBMODULES),
+                    Command::DeepenHistory => (!repo.is_shallow()).then(palette::NOT_SHALLOW),
+                    Command::LoadFullDiff => (!matches!(self.tab.viewer_diff, Some(FileDiff::Truncated { .. }))).then(palette::NOTHING_TRUNCATED),
+                    Command::Custom(name) => {
+                        // The palette itself has focus, so the context is checked against the pane it was opened from.
+                        let focus = if self.tab.focus == Focus::ModalCommandPalette { self.modal_palette_return_focus } else { self.tab.focus };
+                        let command = self.custom_command(name)?;
+                        (!self.custom_command_in_context(command, focus)).then(palette::NOT_IN_CONTEXT)
+                    },
+                    _ => None,
+                }
+            },
+        }
+    }
+
+    pub(crate) fn handle_palette_event(&mut self, key_event: KeyEvent) -> bool {
+        match key_event.code {
+            KeyCode::Esc => self.close_palette(),
+            KeyCode::Enter => self.run_palette_selection(),
+            KeyCode::Down => self.move_palette_selection(Direction::Down),
+            KeyCode::Up => self.move_palette_selection(Direction::Up),
+            KeyCode::Char('j') | KeyCode::Char('J') if key_event.modifiers.contains(KeyModifiers::CONTROL) => self.move_palette_selection(Direction::Down),
+            KeyCode::Char('k') | KeyCode::Char('K') if key_event.modifiers.contains(KeyModifiers::CONTROL) => self.move_palette_selection(Direction::Up),
+            _ => {
+                self.modal_input.on_key(key_event);
+                self.modal_palette_selected = 0;
+                self.modal_palette_scroll.set(0);
+            },
+        }
+        true
+    }
+
+    fn move_palette_selection(&mut self, direction: Direction) {
+        let len = self.palette_entries().len();
+        if len == 0 {
+            self.modal_palette_selected = 0;
+            return;
+        }
+        self.modal_palette_selected = match direction {
+            Direction::Up => (self.modal_palette_selected + len - 1) % len,
+            Direction::Down => (self.modal_palette_selected + 1) % len,
+        };
+    }
+
+    // The command runs against the pane the palette was opened from; disabled ones keep the palette open.
+    fn run_palette_selection(&mut self) {
+        let Some(entry) = self.palette_entries().into_iter().nth(self.modal_palette_selected) else {
+            return;
+        };
+        if entry.disabled.is_some() {
+            return;
+        }
+
+        self.close_palette();
+        if self.tab.viewport == Viewport::Splash && self.tab.focus == Focus::Viewport {
+            self.dispatch_splash_command(&entry.command);
+        } else {
+            self.dispatch_command(&entry.command);
+        }
+    }
+
+    pub(crate) fn close_palette(&mut self) {
+        self.modal_input.clear();
+        self.modal_palette_selected = 0;
+        self.modal_palette_scroll.set(0);
+        self.tab.focus = self.modal_palette_return_focus;
+        self.modal_palette_return_focus = Focus::Viewport;
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/palette.rs"]
+mod tests;
@@ -0,0 +1,141 @@
+use crate::{
+    app::app::{App, Focus},
+    git::actions::{
+        patching::{PatchTarget, apply_patches, split_mbox, write_patches},
+        sequencing::{SequenceKind, order_sequence},
+    },
+    helpers::{
+        keymap::Command,
+        localisation::{errors, menu},
+    },
+};
+use std::{fs, path::PathBuf};
+
+pub(crate) const PATCH_TARGETS: [PatchTarget; 3] = [PatchTarget::Workdir, PatchTarget::Index, PatchTarget::Commit];
+
+pub(crate) fn patch_target_label(target: PatchTarget) -> &'static str {
+    match target {
+        PatchTarget::Workdir => menu::APPLY_TO_WORKTREE(),
+        PatchTarget::Index => menu::APPLY_TO_INDEX(),
+        PatchTarget::Commit => menu::COMMIT_PATCHES(),
+    }
+}
+
+impl App {
+    pub fn on_export_patches(&mut self) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        // Marked commits export as a series; otherwise the selected commit on its own.
+        let oids = if self.tab.graph_marked_oids.is_empty() { self.graph_oid_at(self.tab.graph_selected).into_iter().collect() } else { self.tab.graph_marked_oids.clone() };
+        let ordered = match order_sequence(&repo, SequenceKind::Cherrypick, &oids) {
+            Ok(ordered) if !ordered.is_empty() => ordered,
+            Ok(_) => {
+                self.show_error(errors::EXPORT_PATCHES_NO_COMMIT());
+                return;
+            },
+            Err(error) => {
+                self.show_error(errors::with_error(errors::EXPORT_PATCHES(), error));
+                return;
+            },
+        };
+
+        let name = match ordered.as_slice() {
+            [oid] => format!("{}.patch", &oid.to_string()[..7]),
+            [first, .., last] => format!("{}-{}.mbox", &first.to_string()[..7], &last.to_string()[..7]),
+            [] => return,
+        };
+        // Outside the worktree, so an export never shows up as an untracked file.
+        let directory = std::env::temp_dir().join("guitar-patches");
+        self.modal_patch_oids = ordered;
+        self.modal_input.set_value(directory.join(name).display().to_string());
+        self.tab.focus = Focus::ModalExportPatches;
+    }
+
+    pub(crate) fn confirm_export_patches(&mut self) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let path = PathBuf::from(self.modal_input.value().trim());
+        if path.as_os_str().is_empty() {
+            self.show_error(errors::EXPORT_PATCHES_EMPTY_PATH());
+            return;
+        }
+
+        match write_patches(&repo, &self.modal_patch_oids, &path) {
+            Ok(_) => {
+                self.close_patch_modal();
+                self.reload(None);
+            },
+            Err(error) => self.show_error(errors::with_error(errors::EXPORT_PATCHES(), error)),
+        }
+    }
+
+    pub fn on_apply_patch(&mut self) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        // Start from the last file used, or the worktree root.
+        let path = if self.modal_patch_path.as_os_str().is_empty() {
+            repo.workdir().map(PathBuf::from).unwrap_or_else(|| PathBuf::from(self.tab.path.as_deref().unwrap_or("."))).join("").display().to_string()
+        } else {
+            self.modal_patch_path.display().to_string()
+        };
+        self.modal_input.set_value(path);
+        self.tab.focus = Focus::ModalApplyPatchPath;
+    }
+
+    pub(crate) fn confirm_apply_patch_path(&mut self) {
+        let path = PathBuf::from(self.modal_input.value().trim());
+        if path.as_os_str().is_empty() {
+            self.show_error(errors::APPLY_PATCH_EMPTY_PATH());
+            return;
+        }
+
+        let mails = match fs::read(&path) {
+            Ok(contents) => split_mbox(&contents),
+            Err(error) => {
+                self.show_error(errors::with_error(errors::APPLY_PATCH(), error));
+                return;
+            },
+        };
+        if mails.is_empty() {
+            self.show_error(errors::APPLY_PATCH_NO_PATCHES());
+            return;
+        }
+
+        self.modal_patch_path = path;
+        self.modal_patch_mails = mails;
+        self.modal_patch_selected = 0;
+        self.modal_input.clear();
+        self.tab.focus = Focus::ModalApplyPatch;
+    }
+
+    pub(crate) fn confirm_apply_patch(&mut self) {
+        let Some(repo) = self.tab.repo.clone() else { return };
+        let target = PATCH_TARGETS[self.modal_patch_selected.rem_euclid(PATCH_TARGETS.len() as i32) as usize];
+        if target == PatchTarget::Commit {
+            self.begin_journal(Command::ApplyPatch);
+        }
+
+        match apply_patches(&repo, &self.modal_patch_path, target, &self.tab.name, &self.tab.email) {
+            Ok(_) => {
+                self.close_patch_modal();
+                self.reload(None);
+            },
+            Err(error) => {
+                // Patches committed before the failing one stay, like an interrupted `git am`.
+                self.close_patch_modal();
+                self.reload(None);
+                self.show_error(errors::with_error(errors::APPLY_PATCH(), error));
+            },
+        }
+    }
+
+    // The last applied path is kept so a fixed-up mailbox can be retried quickly.
+    pub(crate) fn close_patch_modal(&mut self) {
+        self.modal_patch_oids.clear();
+        self.modal_patch_mails.clear();
+        self.modal_patch_selected = 0;
+        self.modal_input.clear();
+        self.tab.focus = Focus::Viewport;
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/patching.rs"]
+mod tests;
@@ -45,14 +45,14 @@ impl App {
         self.modal_remote_name.clear();
         self.modal_remote_input_action = RemoteInputAction::AddName;
         self.modal_input.clear();
-        self.focus = Focus::ModalRemoteName;
+        self.tab.focus = Focus::ModalRemoteName;
     }
 
     pub(crate) fn begin_remote_action(&mut self, remote_name: String) {
         self.modal_remote_selected = 0;
         self.modal_remote_target = Some(remote_name);
         self.modal_input.clear();
-        self.focus = Focus::ModalRemoteAction;
+        self.tab.focus = Focus::ModalRemoteAction;
     }
 
     pub(crate) fn close_remote_modal(&mut self) {
@@ -60,7 +60,7 @@ impl App {
         self.modal_remote_target = None;
         self.modal_remote_name.clear();
         self.modal_input.clear();
-        self.focus = Focus::Viewport;
+        self.tab.focus = Focus::Viewport;
     }
 
     pub(crate) fn remote_input_title(&self) -> &'static str {
@@ -96,20 +96,20 @@ impl App {
         let selected = self.modal_remote_selected.rem_euclid(REMOTE_ACTIONS.len() as i32) as usize;
         match REMOTE_ACTIONS[selected] {
             RemoteAction::Fetch => {
-                let repo_path = self.path.as_deref().unwrap_or(".");
+                let repo_path = self.tab.path.as_deref().unwrap_or(".");
                 self.modal_remote_selected = 0;
                 self.modal_remote_target = None;
                 self.start_network_request(NetworkRequest::Fetch { repo_path: repo_path.to_string(), remote_name });
             },
             RemoteAction::SetDefault => {
-                let Some(repo) = self.repo.clone() else {
+                let Some(repo) = self.tab.repo.clone() else {
                     self.close_remote_modal();
                     return;
                 };
                 match set_default_remote(&repo, &remote_name) {
                     Ok(_) => {
                         self.close_remote_modal();
-                        self.viewport = crate::app::app::Viewport::Settings;
+                        self.tab.viewport = crate::app::app::Viewport::Settings;
                         self.reload(None);
                     },
                     Err(error) => self.show_error(errors::with_error(errors::SET_DEFAULT_REMOTE(), error)),
@@ -118,26 +118,26 @@ impl App {
             RemoteAction::Rename => {
                 self.modal_remote_input_action = RemoteInputAction::Rename;
                 self.modal_input.set_value(remote_name);
-                self.focus = Focus::ModalRemoteName;
+                self.tab.focus = Focus::ModalRemoteName;
             },
             RemoteAction::EditFetchUrl => {
                 self.modal_remote_input_action = RemoteInputAction::EditUrl;
                 self.prefill_remote_url(false);
-                self.focus = Focus::ModalRemoteUrl;
+                self.tab.focus = Focus::ModalRemoteUrl;
             },
             RemoteAction::EditPushUrl => {
                 self.modal_remote_input_action = RemoteInputAction::EditPushUrl;
                 self.prefill_remote_url(true);
-                self.focus = Focus::ModalRemoteUrl;
+                self.tab.focus = Focus::ModalRemoteUrl;
             },
             RemoteAction::Delete => {
-                self.focus = Focus::ModalRemoteDelete;
+                self.tab.focus = Focus::ModalRemoteDelete;
             },
         }
     }
 
     fn prefill_remote_url(&mut self, push_url: bool) {
-        let Some(repo) = self.repo.clone() else {
+        let Some(repo) = self.tab.repo.clone() else {
             self.modal_input.clear();
             return;
         };
@@ -168,10 +168,10 @@ impl App {
                 self.modal_remote_name = name;
                 self.modal_input.clear();
                 self.modal_remote_input_action = RemoteInputAction::AddUrl;
-                self.focus = Focus::ModalRemoteUrl;
+                self.tab.focus = Focus::ModalRemoteUrl;
             },
             RemoteInputAction::Rename => {
-                let Some(repo) = self.repo.clone() else {
+                let Some(repo) = self.tab.repo.clone() else {
                     self.close_remote_modal();
                     return;
                 };
@@ -184,7 +184,7 @@ impl App {
                     Ok(_) => {
                         self.rewrite_hidden_remote_prefix(&old_name, Some(&new_name));
                         self.close_remote_modal();
-                        self.viewport = crate::app::app::Viewport::Settings;
+                        self.tab.viewport = crate::app::app::Viewport::Settings;
                         self.reload(None);
                     },
                     Err(error) => self.show_error(errors::with_error(errors::RENAME_REMOTE(), error)),
@@ -195,7 +195,7 @@ impl App {
     }
 
     pub(crate) fn confirm_remote_url_input(&mut self) {
-        let Some(repo) = self.repo.clone() else {
+        let Some(repo) = self.tab.repo.clone() else {
             self.close_remote_modal();
             return;
         };
@@ -207,7 +207,7 @@ impl App {
                 match add_remote(&repo, &name, &url) {
                     Ok(_) => {
                         self.close_remote_modal();
-                        self.viewport = crate::app::app::Viewport::Settings;
+                        self.tab.viewport = crate::app::app::Viewport::Settings;
                         self.reload(None);
                     },
                     Err(error) => self.show_error(errors::with_error(errors::ADD_REMOTE(), error)),
@@ -222,7 +222,7 @@ impl App {
                 match set_remote_url(&repo, &remote_name, &url) {
                     Ok(_) => {
                         self.close_remote_modal();
-                        self.viewport = crate::app::app::Viewport::Settings;
+                        self.tab.viewport = crate::app::app::Viewport::Settings;
                         self.reload(None);
                     },
                     Err(error) => self.show_error(errors::with_error(errors::EDIT_REMOTE(), error)),
@@ -237,7 +237,7 @@ impl App {
                 match set_remote_push_url(&repo, &remote_name, Some(push_url.as_str())) {
                     Ok(_) => {
                         self.close_remote_modal();
-                        self.viewport = crate::app::app::Viewport::Settings;
+                        self.tab.viewport = crate::app::app::Viewport::Settings;
                         self.reload(None);
                     },
                     Err(error) => self.show_error(errors::with_error(errors::EDIT_REMOTE(), error)),
@@ -248,7 +248,7 @@ impl App {
     }
 
     pub(crate) fn confirm_delete_remote(&mut self) {
-        let Some(repo) = self.repo.clone() else {
+        let Some(repo) = self.tab.repo.clone() else {
             self.close_remote_modal();
             return;
         };
@@ -261,7 +261,7 @@ impl App {
             Ok(_) => {
                 self.rewrite_hidden_remote_prefix(&remote_name, None);
                 self.close_remote_modal();
-                self.viewport = crate::app::app::Viewport::Settings;
+                self.tab.viewport = crate::app::app::Viewport::Settings;
                 self.reload(None);
             },
             Err(error) => self.show_error(errors::with_error(errors::DELETE_REMOTE(), error)),
@@ -271,15 +271,16 @@ impl App {
     fn rewrite_hidden_remote_prefix(&mut self, old_remote: &str, new_remote: Option<&str>) {
         let prefix = format!("{old_remote}/");
         let updated = self
+            .tab
             .branches
             .hidden_branch_names
             .iter()
             .filter_map(|name| if let Some(suffix) = name.strip_prefix(&prefix) { new_remote.map(|remote| format!("{remote}/{suffix}")) } else { Some(name.clone()) })
             .collect();
 
-        self.branches.hidden_branch_names = updated;
-        if let Some(path) = &self.path {
-            save_branch_visibility(path, &self.branches.hidden_branch_names);
+        self.tab.branches.hidden_branch_names = updated;
+        if let Some(path) = &self.tab.path {
+            save_branch_visibility(path, &self.tab.branches.hidden_branch_names);
         }
     }
 }
@@ -0,0 +1,457 @@
+use crate::{
+    app::app::{App, BatchAction, Focus, OperationKind, PendingGraphLookup, Viewport, VisualSelection},
+    core::graph_service::{GraphLookupKind, GraphPane, GraphPaneRow},
+    git::{
+        actions::{
+            branching::delete_branch,
+            network::NetworkRequest,
+            resetting::reset_file,
+            staging::{stage_file, unstage_file},
+            stashing::pop,
+            submodules::{stage_submodule_head, unstage_submodule},
+            tagging::untag,
+        },
+        os::path::open_repo,
+        queries::{commits::get_current_branch, helpers::FileChanges},
+    },
+    helpers::{
+        branch_visibility::save_branch_visibility,
+        keymap::Command,
+        localisation::{empty, errors},
+    },
+};
+use git2::{BranchType, Oid};
+use std::{ops::RangeInclusive, path::Path};
+
+// Commands that act on every marked row instead of just the cursor.
+const SELECTION_COMMANDS: [Command; 10] =
+    [Command::MarkCommit, Command::Stage, Command::Unstage, Command::HardReset, Command::DeleteBranch, Command::Untag, Command::Drop, Command::Cherrypick, Command::Revert, Command::ExportPatches];
+
+// One row of a pane, by what it points at rather than where it sits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum MarkedRow {
+    Commit(Oid),
+    Branch(String),
+    Tag(String),
+    Stash(Oid),
+    Staged(String),
+    Unstaged(String),
+}
+
+impl BatchAction {
+    // The pane a batch comes from, which keeps focus once it is done.
+    pub fn pane(self) -> Focus {
+        match self {
+            BatchAction::Stage | BatchAction::DiscardUnstaged => Focus::StatusBottom,
+            BatchAction::Unstage | BatchAction::DiscardStaged => Focus::StatusTop,
+            BatchAction::DeleteBranches => Focus::Branches,
+            BatchAction::DeleteTags => Focus::Tags,
+            BatchAction::DropStashes => Focus::Stashes,
+            BatchAction::Cherrypick | BatchAction::Revert => Focus::Viewport,
+        }
+    }
+}
+
+impl App {
+    // The row under the cursor in panes that support marking; status panes only list files on the uncommitted row.
+    fn selection_cursor(&self) -> Option<usize> {
+        match self.tab.focus {
+            Focus::Viewport if self.tab.viewport == Viewport::Graph => Some(self.tab.graph_selected),
+            Focus::Branches => Some(self.tab.branches_selected),
+            Focus::Tags => Some(self.tab.tags_selected),
+            Focus::Stashes => Some(self.tab.stashes_selected),
+            Focus::StatusTop | Focus::StatusBottom if self.tab.graph_selected == 0 => {
+                Some(if self.tab.focus == Focus::StatusTop { self.tab.status_top_selected } else { self.tab.status_bottom_selected })
+            },
+            _ => None,
+        }
+    }
+
+    pub fn on_visual_mode(&mut self) {
+        if self.visual_range().is_some() {
+            self.tab.visual = None;
+            return;
+        }
+        self.tab.visual = self.selection_cursor().map(|anchor| VisualSelection { focus: self.tab.focus, anchor });
+    }
+
+    // Visual mode only applies while the pane it started in keeps focus.
+    pub(crate) fn visual_range(&self) -> Option<RangeInclusive<usize>> {
+        let visual = self.tab.visual.filter(|visual| visual.focus == self.tab.focus)?;
+        let cursor = self.selection_cursor()?;
+        Some(visual.anchor.min(cursor)..=visual.anchor.max(cursor))
+    }
+
+    pub(crate) fn is_in_visual_range(&self, focus: Focus, index: usize) -> bool {
+        self.tab.focus == focus && self.visual_range().is_some_and(|range| range.contains(&index))
+    }
+
+    pub(crate) fn marked_row_at(&self, focus: Focus, index: usize) -> Option<MarkedRow> {
+        match focus {
+            Focus::Viewport => (index != 0).then(|| self.graph_oid_at(index)).flatten().map(MarkedRow::Commit),
+            Focus::Branches => self.pane_row_at(GraphPane::Branches, index).or_else(|| self.tab.branches.sorted.get(index).map(|(_, name)| MarkedRow::Branch(name.clone()))),
+            Focus::Tags => self.pane_row_at(GraphPane::Tags, index).or_else(|| self.tab.tags.sorted.get(index).map(|(_, name)| MarkedRow::Tag(name.clone()))),
+            Focus::Stashes => self.pane_row_at(GraphPane::Stashes, index).or_else(|| self.tab.oids.stashes.get(index).map(|alias| MarkedRow::Stash(*self.tab.oids.get_oid_by_alias(*alias)))),
+            Focus::StatusTop => Self::selected_uncommitted_file_name(&self.tab.uncommitted.conflicts, &self.tab.uncommitted.staged, index).map(MarkedRow::Staged),
+            Focus::StatusBottom => Self::selected_uncommitted_file_name(&self.tab.uncommitted.conflicts, &self.tab.uncommitted.unstaged, index).map(MarkedRow::Unstaged),
+            _ => None,
+        }
+    }
+
+    // Pane rows come from the worker's window; the local lists are only a fallback without a worker.
+    pub(crate) fn pane_row_at(&self, pane: GraphPane, index: usize) -> Option<MarkedRow> {
+        let window = match pane {
+            GraphPane::Branches => self.tab.graph.branches_window.as_ref(),
+            GraphPane::Tags => self.tab.graph.tags_window.as_ref(),
+            GraphPane::Stashes => self.tab.graph.stashes_window.as_ref(),
+            GraphPane::Reflogs => None,
+        }?;
+        index.checked_sub(window.start).and_then(|offset| window.rows.get(offset)).and_then(|row| self.marked_row_from_pane_row(row))
+    }
+
+    fn marked_row_from_pane_row(&self, row: &GraphPaneRow) -> Option<MarkedRow> {
+        match row {
+            GraphPaneRow::Branch { name, .. } => Some(MarkedRow::Branch(name.clone())),
+            GraphPaneRow::Tag { name, .. } => Some(MarkedRow::Tag(name.clone())),
+            GraphPaneRow::Stash { alias, .. } => Some(MarkedRow::Stash(*self.tab.oids.get_oid_by_alias(*alias))),
+            GraphPaneRow::Reflog { .. } => None,
+        }
+    }
+
+    pub(crate) fn is_row_marked(&self, row: &MarkedRow) -> bool {
+        match row {
+            MarkedRow::Commit(oid) => self.tab.graph_marked_oids.contains(oid),
+            MarkedRow::Branch(name) => self.tab.pane_marks.branches.contains(name),
+            MarkedRow::Tag(name) => self.tab.pane_marks.tags.contains(name),
+            MarkedRow::Stash(oid) => self.tab.pane_marks.stashes.contains(oid),
+            MarkedRow::Staged(path) => self.tab.pane_marks.staged.contains(path),
+            MarkedRow::Unstaged(path) => self.tab.pane_marks.unstaged.contains(path),
+        }
+    }
+
+    // Marked or inside the live visual range, which is what the panes highlight.
+    pub(crate) fn is_pane_row_selected(&self, focus: Focus, index: usize) -> bool {
+        self.is_in_visual_range(focus, index) || self.marked_row_at(focus, index).is_some_and(|row| self.is_row_marked(&row))
+    }
+
+    fn add_mark(&mut self, row: MarkedRow) {
+        if self.is_row_marked(&row) {
+            return;
+        }
+        match row {
+            MarkedRow::Commit(oid) => self.tab.graph_marked_oids.push(oid),
+            MarkedRow::Branch(name) => self.tab.pane_marks.branches.push(name),
+            MarkedRow::Tag(name) => self.tab.pane_marks.tags.push(name),
+            MarkedRow::Stash(oid) => self.tab.pane_marks.stashes.push(oid),
+            MarkedRow::Staged(path) => self.tab.pane_marks.staged.push(path),
+            MarkedRow::Unstaged(path) => self.tab.pane_marks.unstaged.push(path),
+        }
+    }
+
+    fn remove_mark(&mut self, row: &MarkedRow) {
+        match row {
+            MarkedRow::Commit(oid) => self.tab.graph_marked_oids.retain(|marked| marked != oid),
+            MarkedRow::Branch(name) => self.tab.pane_marks.branches.retain(|marked| marked != name),
+            MarkedRow::Tag(name) => self.tab.pane_marks.tags.retain(|marked| marked != name),
+            MarkedRow::Stash(oid) => self.tab.pane_marks.stashes.retain(|marked| marked != oid),
+            MarkedRow::Staged(path) => self.tab.pane_marks.staged.retain(|marked| marked != path),
+            MarkedRow::Unstaged(path) => self.tab.pane_marks.unstaged.retain(|marked| marked != path),
+        }
+    }
+
+    // The graph keeps its own toggle; other panes toggle the row under the cursor.
+    pub fn on_mark_row(&mut self) {
+        if self.tab.focus == Focus::Viewport {
+            self.on_mark_commit();
+            return;
+        }
+        let Some(row) = self.selection_cursor().and_then(|index| self.marked_row_at(self.tab.focus, index)) else {
+            return;
+        };
+        if self.is_row_marked(&row) {
+            self.remove_mark(&row);
+        } else {
+            self.add_mark(row);
+        }
+    }
+
+    pub fn on_clear_pane_marks(&mut self) {
+        self.tab.visual = None;
+        match self.tab.focus {
+            Focus::Branches => self.tab.pane_marks.branches.clear(),
+            Focus::Tags => self.tab.pane_marks.tags.clear(),
+            Focus::Stashes => self.tab.pane_marks.stashes.clear(),
+            Focus::StatusTop => self.tab.pane_marks.staged.clear(),
+            Focus::StatusBottom => self.tab.pane_marks.unstaged.clear(),
+            _ => self.on_clear_marks(),
+        }
+    }
+
+    // Runs before every command; false when the command has to wait for a lookup or was only folding the range.
+    pub(crate) fn prepare_selection(&mut self, command: &Command) -> bool {
+        if !SELECTION_COMMANDS.contains(command) || self.visual_range().is_none() {
+            return true;
+        }
+        self.fold_visual_range(command) && *command != Command::MarkCommit
+    }
+
+    // Turns the visual range into marks so commands only ever look at marks.
+    fn fold_visual_range(&mut self, command: &Command) -> bool {
+        let Some(range) = self.visual_range() else { return true };
+        let focus = self.tab.focus;
+        let rows: Vec<Option<MarkedRow>> = range.clone().filter(|index| focus != Focus::Viewport || *index != 0).map(|index| self.marked_row_at(focus, index)).collect();
+
+        // Long ranges reach past the cached window, so the worker supplies the rows.
+        if rows.iter().any(Option::is_none) && self.tab.graph_tx.is_some() {
+            let (start, end) = (*range.start(), range.end() + 1);
+            let kind = match focus {
+                Focus::Viewport => Some(GraphLookupKind::GraphOidRange { start, end }),
+                Focus::Branches => Some(GraphLookupKind::PaneRowRange { pane: GraphPane::Branches, start, end }),
+                Focus::Tags => Some(GraphLookupKind::PaneRowRange { pane: GraphPane::Tags, start, end }),
+                Focus::Stashes => Some(GraphLookupKind::PaneRowRange { pane: GraphPane::Stashes, start, end }),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                self.tab.pending_visual_command = Some(command.clone());
+                self.request_graph_lookup(kind, PendingGraphLookup::VisualRange);
+                return false;
+            }
+        }
+
+        for row in rows.into_iter().flatten() {
+            self.add_mark(row);
+        }
+        self.tab.visual = None;
+        true
+    }
+
+    // Finishes `fold_visual_range` with the rows the worker found, then runs the command that asked for them.
+    pub(crate) fn finish_visual_range(&mut self, rows: Vec<MarkedRow>) {
+        for row in rows {
+            self.add_mark(row);
+        }
+        self.tab.visual = None;
+        if let Some(command) = self.tab.pending_visual_command.take()
+            && command != Command::MarkCommit
+        {
+            self.dispatch_command(&command);
+        }
+    }
+
+    pub(crate) fn visual_rows_from_oids(oids: Vec<Oid>) -> Vec<MarkedRow> {
+        oids.into_iter().map(MarkedRow::Commit).collect()
+    }
+
+    pub(crate) fn visual_rows_from_pane_rows(&self, rows: &[GraphPaneRow]) -> Vec<MarkedRow> {
+        rows.iter().filter_map(|row| self.marked_row_from_pane_row(row)).collect()
+    }
+
+    // Marks outlive reloads, so rows that have since moved or gone are dropped before a batch runs.
+    fn prune_pane_marks(&mut self) {
+        let uncommitted = &self.tab.uncommitted;
+        let lists = |changes: &FileChanges, path: &String| uncommitted.conflicts.contains(path) || changes.modified.contains(path) || changes.added.contains(path) || changes.deleted.contains(path);
+        self.tab.pane_marks.staged.retain(|path| lists(&uncommitted.staged, path));
+        self.tab.pane_marks.unstaged.retain(|path| lists(&uncommitted.unstaged, path));
+
+        if let Some(repo) = &self.tab.repo {
+            let stashes: Vec<Oid> = repo.reflog("refs/stash").map(|reflog| reflog.iter().map(|entry| entry.id_new()).collect()).unwrap_or_default();
+            self.tab.pane_marks.stashes.retain(|oid| stashes.contains(oid));
+            self.tab.pane_marks.tags.retain(|tag| repo.find_reference(&format!("refs/tags/{tag}")).is_ok());
+            self.tab.pane_marks.branches.retain(|branch| repo.resolve_reference_from_short_name(branch).is_ok());
+        }
+    }
+
+    // Entry point for the per-pane commands; false leaves the single-row behaviour alone.
+    pub(crate) fn open_batch_modal(&mut self, action: BatchAction) -> bool {
+        self.prune_pane_marks();
+        let is_status = matches!(action.pane(), Focus::StatusTop | Focus::StatusBottom);
+        if self.tab.focus != action.pane() || (is_status && self.tab.graph_selected != 0) || self.batch_items(action).is_empty() {
+            return false;
+        }
+
+        let error = match action {
+            BatchAction::Stage if self.tab.pane_marks.unstaged.iter().any(|path| self.tab.uncommitted.conflicts.contains(path)) => Some(errors::STAGE_FILE_CONFLICT()),
+            BatchAction::Unstage if self.tab.pane_marks.staged.iter().any(|path| self.tab.uncommitted.conflicts.contains(path)) => Some(errors::UNSTAGE_FILE_CONFLICT()),
+            BatchAction::DeleteBranches => {
+                let current = self.tab.repo.as_ref().and_then(|repo| get_current_branch(repo));
+                if self.tab.pane_marks.branches.iter().any(|name| Some(name) == current.as_ref()) { Some(errors::DELETE_BRANCH_CURRENT()) } else { self.marked_remote_branches().err() }
+            },
+            _ => None,
+        };
+        if let Some(error) = error {
+            self.show_error(error);
+            return true;
+        }
+
+        self.modal_batch_action = Some(action);
+        self.tab.focus = Focus::ModalBatch;
+        true
+    }
+
+    // What the confirmation lists, in the order the rows were marked.
+    pub(crate) fn batch_items(&self, action: BatchAction) -> Vec<String> {
+        match action {
+            BatchAction::Stage | BatchAction::DiscardUnstaged => self.tab.pane_marks.unstaged.clone(),
+            BatchAction::Unstage | BatchAction::DiscardStaged => self.tab.pane_marks.staged.clone(),
+            BatchAction::DeleteBranches => self.tab.pane_marks.branches.clone(),
+            BatchAction::DeleteTags => self.tab.pane_marks.tags.clone(),
+            BatchAction::DropStashes => self.commit_summaries(&self.tab.pane_marks.stashes),
+            BatchAction::Cherrypick | BatchAction::Revert => self.commit_summaries(&self.tab.graph_marked_oids),
+        }
+    }
+
+    fn commit_summaries(&self, oids: &[Oid]) -> Vec<String> {
+        oids.iter()
+            .map(|oid| {
+                let summary = self.tab.repo.as_ref().and_then(|repo| repo.find_commit(*oid).ok()).and_then(|commit| commit.summary().map(str::to_string));
+                summary.unwrap_or_else(|| empty::NO_MESSAGE().to_string())
+            })
+            .collect()
+    }
+
+    // Marked remote branches go out in one push, so they have to share a remote.
+    fn marked_remote_branches(&self) -> Result<Option<(String, Vec<String>)>, &'static str> {
+        let mut remote: Option<(String, Vec<String>)> = None;
+        for name in self.tab.pane_marks.branches.iter().filter(|name| !self.is_local_branch_name(name)) {
+            let Some((remote_name, branch)) = name.split_once('/').filter(|(remote_name, branch)| !remote_name.is_empty() && !branch.is_empty()) else {
+                return Err(errors::DELETE_BRANCH_INVALID_REMOTE());
+            };
+            match &mut remote {
+                Some((name, branches)) if name == remote_name => branches.push(branch.to_string()),
+                Some(_) => return Err(errors::DELETE_BRANCH_REMOTE_BATCH()),
+                None => remote = Some((remote_name.to_string(), vec![branch.to_string()])),
+            }
+        }
+        Ok(remote)
+    }
+
+    pub(crate) fn close_batch_modal(&mut self) {
+        self.tab.focus = self.modal_batch_action.take().map(BatchAction::pane).unwrap_or(Focus::Viewport);
+    }
+
+    pub(crate) fn confirm_batch(&mut self) {
+        let Some(action) = self.modal_batch_action else {
+            self.close_batch_modal();
+            return;
+        };
+        self.close_batch_modal();
+
+        // Replaying commits runs through the operation modal, which keeps the marks until the sequence starts.
+        if let Some(kind) = match action {
+            BatchAction::Cherrypick => Some(OperationKind::Cherrypick),
+            BatchAction::Revert => Some(OperationKind::Revert),
+            _ => None,
+        } {
+            self.start_marked_sequence(kind);
+            return;
+        }
+
+        // Stops at the first failure; whatever already ran stays applied and shows after the reload.
+        let result = match action {
+            BatchAction::Stage => self.stage_marked_files(),
+            BatchAction::Unstage => self.unstage_marked_files(),
+            BatchAction::DiscardStaged | BatchAction::DiscardUnstaged => self.discard_marked_files(action),
+            BatchAction::DeleteBranches => self.delete_marked_branches(),
+            BatchAction::DeleteTags => self.delete_marked_tags(),
+            BatchAction::DropStashes => self.drop_marked_stashes(),
+            BatchAction::Cherrypick | BatchAction::Revert => Ok(()),
+        };
+
+        match action {
+            BatchAction::Stage | BatchAction::DiscardUnstaged => self.tab.pane_marks.unstaged.clear(),
+            BatchAction::Unstage | BatchAction::DiscardStaged => self.tab.pane_marks.staged.clear(),
+            BatchAction::DeleteBranches => self.tab.pane_marks.branches.clear(),
+            BatchAction::DeleteTags => self.tab.pane_marks.tags.clear(),
+            BatchAction::DropStashes => self.tab.pane_marks.stashes.clear(),
+            BatchAction::Cherrypick | BatchAction::Revert => {},
+        }
+        self.reload(None);
+        if let Err(error) = result {
+            self.show_error(error);
+        }
+    }
+
+    fn stage_marked_files(&mut self) -> Result<(), String> {
+        let Some(repo) = self.tab.repo.clone() else { return Ok(()) };
+        for file in &self.tab.pane_marks.unstaged {
+            if let Some(name) = Self::submodule_name_for_status_path(&repo, file) {
+                stage_submodule_head(&repo, &name).map_err(|error| errors::with_error(errors::STAGE_SUBMODULE(), error))?;
+            } else {
+                stage_file(&repo, Path::new(file)).map_err(|error| errors::with_error(errors::STAGE_FILE(), error))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn unstage_marked_files(&mut self) -> Result<(), String> {
+        let Some(repo) = self.tab.repo.clone() else { return Ok(()) };
+        for file in &self.tab.pane_marks.staged {
+            if let Some(name) = Self::submodule_name_for_status_path(&repo, file) {
+                unstage_submodule(&repo, &name).map_err(|error| errors::with_error(errors::UNSTAGE_SUBMODULE(), error))?;
+            } else {
+                unstage_file(&repo, Path::new(file)).map_err(|error| errors::with_error(errors::UNSTAGE_FILE(), error))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn discard_marked_files(&mut self, action: BatchAction) -> Result<(), String> {
+        let Some(repo) = self.tab.repo.clone() else { return Ok(()) };
+        for file in self.batch_items(action) {
+            reset_file(&repo, Path::new(&file)).map_err(|error| errors::with_error(errors::RESET_FILE(), error))?;
+        }
+        Ok(())
+    }
+
+    // Local branches go first; the remote ones follow in a single push once they are done.
+    fn delete_marked_branches(&mut self) -> Result<(), String> {
+        let Some(repo) = self.tab.repo.clone() else { return Ok(()) };
+        let remote = self.marked_remote_branches().map_err(str::to_string)?;
+        self.begin_journal(Command::DeleteBranch);
+        let mut result = Ok(());
+        let mut was_hidden = false;
+        for branch in self.tab.pane_marks.branches.clone() {
+            if repo.find_branch(&branch, BranchType::Local).is_err() {
+                continue;
+            }
+            if let Err(error) = delete_branch(&repo, &branch) {
+                result = Err(errors::with_error(errors::DELETE_BRANCH(), error));
+                break;
+            }
+            was_hidden |= self.tab.branches.hidden_branch_names.remove(&branch).is_some();
+        }
+        if was_hidden && let Some(path) = &self.tab.path {
+            save_branch_visibility(path, &self.tab.branches.hidden_branch_names);
+        }
+        if result.is_ok()
+            && let Some((remote_name, branches)) = remote
+        {
+            let repo_path = self.tab.path.clone().unwrap_or_else(|| ".".to_string());
+            self.start_push_request(NetworkRequest::DeleteRemoteBranches { repo_path, remote_name, branches }, Some(Command::DeleteBranch));
+        }
+        result
+    }
+
+    fn delete_marked_tags(&mut self) -> Result<(), String> {
+        let Some(repo) = self.tab.repo.clone() else { return Ok(()) };
+        self.begin_journal(Command::Untag);
+        for tag in &self.tab.pane_marks.tags {
+            untag(&repo, tag).map_err(|error| errors::with_error(errors::DELETE_TAG(), error))?;
+        }
+        Ok(())
+    }
+
+    fn drop_marked_stashes(&mut self) -> Result<(), String> {
+        let Some(path) = self.tab.repo.as_ref().map(|repo| repo.path().to_path_buf()) else { return Ok(()) };
+        let mut repo = open_repo(path, self.tab.work_tree.as_deref()).map_err(|error| errors::with_error(errors::OPEN_REPOSITORY(), error))?;
+        self.begin_journal(Command::Drop);
+        for oid in &self.tab.pane_marks.stashes {
+            pop(&mut repo, oid, false).map_err(|error| errors::with_error(errors::DROP_STASH(), error))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/selection.rs"]
+mod tests;
@@ -0,0 +1,215 @@
+use crate::{
+    app::{
+        app::{App, Focus, GraphSelectionRestore, PendingGraphLookup, Viewport},
+        input::selection::MarkedRow,
+        state::defaults::ViewerMode,
+    },
+    core::graph_service::{GraphLookupKind, GraphPane},
+    helpers::session::{RepositorySession, SessionPane, SessionViewerMode, load_session, load_session_from_path, save_session, save_session_to_path},
+};
+use git2::Oid;
+
+impl App {
+    fn session_pane(&self) -> SessionPane {
+        match self.tab.focus {
+            Focus::Viewport if self.tab.viewport == Viewport::Viewer => SessionPane::Viewer,
+            Focus::Branches => SessionPane::Branches,
+            Focus::Tags => SessionPane::Tags,
+            Focus::Stashes => SessionPane::Stashes,
+            Focus::Reflogs => SessionPane::Reflogs,
+            Focus::Worktrees => SessionPane::Worktrees,
+            Focus::Submodules => SessionPane::Submodules,
+            Focus::Search => SessionPane::Search,
+            Focus::Inspector => SessionPane::Inspector,
+            Focus::StatusTop => SessionPane::Staged,
+            Focus::StatusBottom => SessionPane::Unstaged,
+            _ => SessionPane::Graph,
+        }
+    }
+
+    fn session_focus(pane: SessionPane) -> Focus {
+        match pane {
+            SessionPane::Graph | SessionPane::Viewer => Focus::Viewport,
+            SessionPane::Branches => Focus::Branches,
+            SessionPane::Tags => Focus::Tags,
+            SessionPane::Stashes => Focus::Stashes,
+            SessionPane::Reflogs => Focus::Reflogs,
+            SessionPane::Worktrees => Focus::Worktrees,
+            SessionPane::Submodules => Focus::Submodules,
+            SessionPane::Search => Focus::Search,
+            SessionPane::Inspector => Focus::Inspector,
+            SessionPane::Staged => Focus::StatusTop,
+            SessionPane::Unstaged => Focus::StatusBottom,
+        }
+    }
+
+    // Where the open repository stands right now; `None` before a repository has loaded.
+    pub(crate) fn session_snapshot(&self) -> Option<RepositorySession> {
+        self.tab.repo.as_ref()?;
+        if self.tab.viewport == Viewport::Splash {
+            return None;
+        }
+        let path = self.tab.path.clone()?;
+        // A restore that has not landed yet still knows which commit it is after.
+        let oid = if self.tab.graph_selected != 0 { self.graph_oid_at(self.tab.graph_selected) } else { None }.or(self.tab.graph.pending_selection_restore.map(|restore| restore.oid));
+        let pane = self.session_pane();
+        let name_at = |pane, index| match self.pane_row_at(pane, index) {
+            Some(MarkedRow::Branch(name) | MarkedRow::Tag(name)) => Some(name),
+            Some(MarkedRow::Stash(oid)) => Some(oid.to_string()),
+            _ => None,
+        };
+
+        Some(RepositorySession {
+            path,
+            oid: oid.map(|oid| oid.to_string()),
+            offset: self.tab.graph_selected.saturating_sub(self.tab.graph_scroll.get()),
+            pane,
+            file_name: if pane == SessionPane::Viewer { self.tab.file_name.clone() } else { None },
+            viewer_mode: match self.viewer_mode {
+                ViewerMode::Full => SessionViewerMode::Full,
+                ViewerMode::Hunks => SessionViewerMode::Hunks,
+                ViewerMode::Split => SessionViewerMode::Split,
+            },
+            branches_selected: self.tab.branches_selected,
+            tags_selected: self.tab.tags_selected,
+            stashes_selected: self.tab.stashes_selected,
+            branch_name: name_at(GraphPane::Branches, self.tab.branches_selected),
+            tag_name: name_at(GraphPane::Tags, self.tab.tags_selected),
+            stash_oid: name_at(GraphPane::Stashes, self.tab.stashes_selected),
+        })
+    }
+
+    pub fn save_session(&self) {
+        let Some(session) = self.session_snapshot() else {
+            return;
+        };
+        if let Some(path) = &self.session_save_path {
+            save_session_to_path(path.as_path(), &session);
+        } else {
+            save_session(&session);
+        }
+    }
+
+    // Loads the saved session for a repository that is being opened and returns the commit to select.
+    pub(crate) fn begin_session_restore(&mut self, repo_path: &str) -> Option<GraphSelectionRestore> {
+        let session = if let Some(path) = &self.session_save_path { load_session_from_path(path.as_path(), repo_path) } else { load_session(repo_path) };
+        self.tab.pending_session_rows = session
+            .as_ref()
+            .map(|session| {
+                vec![
+                    (GraphPane::Branches, session.branch_name.clone(), session.branches_selected),
+                    (GraphPane::Tags, session.tag_name.clone(), session.tags_selected),
+                    (GraphPane::Stashes, session.stash_oid.clone(), session.stashes_selected),
+                ]
+            })
+            .unwrap_or_default();
+        self.tab.pending_session = session;
+
+        let session = self.tab.pending_session.as_ref()?;
+        let oid = Oid::from_str(session.oid.as_deref()?).ok()?;
+        Some(GraphSelectionRestore { oid, selected_offset: session.offset })
+    }
+
+    // Pane rows are only known once the worker sends their window, so each is restored on its first one.
+    // Named rows are looked up by the worker, one at a time since a lookup may already be in flight.
+    pub(crate) fn restore_session_pane_row(&mut self, pane: GraphPane, total: usize) {
+        let Some(position) = self.tab.pending_session_rows.iter().position(|(pending, _, _)| *pending == pane) else {
+            return;
+        };
+        if self.tab.pending_session_rows[position].1.is_some() {
+            self.request_pending_session_row_lookup();
+            return;
+        }
+        let (_, _, selected) = self.tab.pending_session_rows.remove(position);
+        self.set_pane_selected(pane, selected.min(total.saturating_sub(1)));
+    }
+
+    pub(crate) fn request_pending_session_row_lookup(&mut self) {
+        if self.tab.graph.pending_lookup.is_some() {
+            return;
+        }
+        let is_loaded = |pane| match pane {
+            GraphPane::Branches => self.tab.graph.branches_window.is_some(),
+            GraphPane::Tags => self.tab.graph.tags_window.is_some(),
+            GraphPane::Stashes => self.tab.graph.stashes_window.is_some(),
+            GraphPane::Reflogs => false,
+        };
+        let Some(position) = self.tab.pending_session_rows.iter().position(|(pane, name, _)| name.is_some() && is_loaded(*pane)) else {
+            return;
+        };
+        let (pane, name, _) = self.tab.pending_session_rows.remove(position);
+        self.request_graph_lookup(GraphLookupKind::PaneRowByName { pane, name: name.unwrap_or_default() }, PendingGraphLookup::RestorePaneRow(pane));
+    }
+
+    pub(crate) fn set_pane_selected(&mut self, pane: GraphPane, selected: usize) {
+        match pane {
+            GraphPane::Branches => self.tab.branches_selected = selected,
+            GraphPane::Tags => self.tab.tags_selected = selected,
+            GraphPane::Stashes => self.tab.stashes_selected = selected,
+            GraphPane::Reflogs => {},
+        }
+    }
+
+    // Runs once the saved commit is selected, or straight away when the session was on the uncommitted row.
+    pub(crate) fn finish_session_restore(&mut self, repo: &git2::Repository) {
+        let Some(session) = self.tab.pending_session.take() else {
+            return;
+        };
+
+        // The selected row may not be cached yet, but the saved sha is the commit it was restored to.
+        let oid = if self.tab.graph_selected != 0 { session.oid.as_deref().and_then(|oid| Oid::from_str(oid).ok()).unwrap_or_else(Oid::zero) } else { Oid::zero() };
+        let has_file = match (session.pane, &session.file_name) {
+            (SessionPane::Viewer, Some(file_name)) => self.has_file_at(oid, file_name),
+            _ => Some(false),
+        };
+        // The commit's files come from the diff service; its answer finishes the restore.
+        let Some(has_file) = has_file else {
+            self.tab.pending_session = Some(session);
+            return;
+        };
+        if has_file && let Some(file_name) = session.file_name {
+            self.tab.file_name = Some(file_name);
+            self.update_viewer(oid, repo);
+            self.viewer_mode = match session.viewer_mode {
+                SessionViewerMode::Full => ViewerMode::Full,
+                SessionViewerMode::Hunks => ViewerMode::Hunks,
+                SessionViewerMode::Split => ViewerMode::Split,
+            };
+            self.tab.viewer_selected = 0;
+            self.tab.viewer_scroll.set(0);
+            self.tab.viewport = Viewport::Viewer;
+            self.tab.focus = Focus::Viewport;
+            return;
+        }
+
+        let focus = Self::session_focus(session.pane);
+        if self.get_focusable_panes().contains(&focus) {
+            self.tab.focus = focus;
+        }
+    }
+
+    // A commit that is gone leaves the graph at the top; the viewer is skipped but the pane still gets focus.
+    pub(crate) fn abandon_session_restore(&mut self, repo: &git2::Repository) {
+        if let Some(session) = &mut self.tab.pending_session {
+            session.file_name = None;
+        }
+        self.finish_session_restore(repo);
+    }
+
+    // `None` until the diff service has listed the selected commit's files.
+    fn has_file_at(&self, oid: Oid, file_name: &str) -> Option<bool> {
+        if oid != Oid::zero() {
+            self.tab.current_diff_identity.filter(|identity| identity.index == self.tab.graph_selected)?;
+            return Some(self.tab.current_diff.iter().any(|change| change.filename == file_name));
+        }
+        let changes = [&self.tab.uncommitted.staged, &self.tab.uncommitted.unstaged];
+        Some(
+            self.tab.uncommitted.conflicts.iter().any(|path| path == file_name)
+                || changes.iter().any(|changes| [&changes.modified, &changes.added, &changes.deleted].iter().any(|paths| paths.iter().any(|path| path == file_name))),
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/session.rs"]
+mod tests;
@@ -8,11 +8,11 @@ use std::path::PathBuf;
 
 impl App {
     pub(crate) fn selected_submodule_name(&self) -> Option<String> {
-        self.submodules.entries.get(self.submodules_selected).map(|entry| entry.name.clone())
+        self.tab.submodules.entries.get(self.tab.submodules_selected).map(|entry| entry.name.clone())
     }
 
     pub(super) fn open_selected_submodule(&mut self) {
-        let Some(entry) = self.submodules.entries.get(self.submodules_selected).cloned() else {
+        let Some(entry) = self.tab.submodules.entries.get(self.tab.submodules_selected).cloned() else {
             return;
         };
 
@@ -21,12 +21,12 @@ impl App {
             return;
         }
 
-        let parent_path = PathBuf::from(self.path.as_deref().unwrap_or("."));
-        self.submodule_stack.push(SubmoduleStackEntry::new(parent_path, entry.path.clone(), entry.name.clone()));
+        let parent_path = PathBuf::from(self.tab.path.as_deref().unwrap_or("."));
+        self.tab.submodule_stack.push(SubmoduleStackEntry::new(parent_path, entry.path.clone(), entry.name.clone()));
         self.reload(Some(entry.absolute_path.display().to_string()));
-        self.viewport = Viewport::Graph;
-        self.focus = Focus::Viewport;
-        self.graph_selected = 0;
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
+        self.tab.graph_selected = 0;
     }
 
     pub fn on_return_to_parent_repository(&mut self) {
@@ -34,36 +34,36 @@ impl App {
             return;
         }
 
-        let Some(entry) = self.submodule_stack.pop() else {
+        let Some(entry) = self.tab.submodule_stack.pop() else {
             return;
         };
 
         self.reload(Some(entry.parent_path.display().to_string()));
-        self.viewport = Viewport::Graph;
-        self.focus = Focus::Viewport;
-        self.graph_selected = 0;
-        self.viewer_selected = 0;
-        self.file_name = None;
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
+        self.tab.graph_selected = 0;
+        self.tab.viewer_selected = 0;
+        self.tab.file_name = None;
     }
 
     pub fn on_update_submodule(&mut self) {
-        if self.viewport == Viewport::Settings || self.viewport == Viewport::Viewer || self.focus != Focus::Submodules {
+        if self.tab.viewport == Viewport::Settings || self.tab.viewport == Viewport::Viewer || self.tab.focus != Focus::Submodules {
             return;
         }
 
         let Some(name) = self.selected_submodule_name() else {
             return;
         };
-        let repo_path = self.path.as_deref().unwrap_or(".");
+        let repo_path = self.tab.path.as_deref().unwrap_or(".");
         self.start_network_request(NetworkRequest::UpdateSubmodule { repo_path: repo_path.to_string(), name });
     }
 
     pub fn on_sync_submodule(&mut self) {
-        if self.viewport == Viewport::Settings || self.viewport == Viewport::Viewer || self.focus != Focus::Submodules {
+        if self.tab.viewport == Viewport::Settings || self.tab.viewport == Viewport::Viewer || self.tab.focus != Focus::Submodules {
             return;
         }
 
-        let Some(repo) = self.repo.clone() else {
+        let Some(repo) = self.tab.repo.clone() else {
             return;
         };
         let Some(name) = self.selected_submodule_name() else {
@@ -72,7 +72,7 @@ impl App {
 
         match sync_submodule(&repo, &name) {
             Ok(_) => {
-                self.focus = Focus::Submodules;
+                self.tab.focus = Focus::Submodules;
                 self.reload(None);
             },
             Err(error) => self.show_error(errors::with_error(errors::SYNC_SUBMODULE(), error)),
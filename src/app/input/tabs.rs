@@ -0,0 +1,125 @@
+use crate::{
+    app::app::{App, RepoTab},
+    core::graph_service::GraphCommand,
+    helpers::localisation::errors,
+};
+use std::mem::swap;
+
+impl App {
+    // Background work reports back into whichever repository is active when it finishes.
+    fn is_tab_switch_blocked(&self) -> bool {
+        self.network_handle.is_some() || self.hook_task.is_some() || self.commit_task.is_some() || self.custom_command_task.is_some() || self.pending_operation_action.is_some()
+    }
+
+    fn pause_graph_service(&self, is_paused: bool) {
+        if let Some(tx) = &self.tab.graph_tx {
+            let _ = tx.send(GraphCommand::Pause { is_paused });
+        }
+    }
+
+    pub(crate) fn switch_tab(&mut self, index: usize) {
+        if index == self.active_tab || index >= self.tabs.len() {
+            return;
+        }
+        if self.is_tab_switch_blocked() {
+            self.show_error(errors::SWITCH_TAB_BUSY());
+            return;
+        }
+
+        // A parked tab does not change, so its session is saved as it leaves.
+        self.save_session();
+
+        // The incoming slot becomes the empty placeholder and the outgoing state is parked.
+        // A parked walker is paused, since nothing reads its events until the tab comes back.
+        self.pause_graph_service(true);
+        let mut parked = std::mem::take(&mut self.tabs[index]);
+        swap(&mut self.tab, &mut parked);
+        self.tabs[self.active_tab] = parked;
+        self.active_tab = index;
+        self.pause_graph_service(false);
+
+        // Transient input belongs to the screen that was just left.
+        self.context_menu = None;
+        self.mouse_drag = None;
+        self.last_mouse_click = None;
+        self.modal_area = None;
+        self.pending_keys.clear();
+        self.pending_keys_at = None;
+        self.pending_count = None;
+        self.pending_mark = None;
+        self.is_viewer_layout_dirty = true;
+
+        // A resumed walker picks up where it stopped, so the spinner follows the tab.
+        if self.tab.repo.is_some() && !self.tab.graph.is_complete {
+            self.spinner.start();
+        } else {
+            self.spinner.stop();
+        }
+    }
+
+    pub fn on_new_tab(&mut self) {
+        if self.is_modal_focus() {
+            return;
+        }
+        if self.is_tab_switch_blocked() {
+            self.show_error(errors::SWITCH_TAB_BUSY());
+            return;
+        }
+
+        self.tabs.insert(self.active_tab + 1, RepoTab::default());
+        self.switch_tab(self.active_tab + 1);
+        self.splash_selected = 0;
+    }
+
+    pub fn on_close_tab(&mut self) {
+        if self.is_modal_focus() || self.tabs.len() < 2 {
+            return;
+        }
+        if self.is_tab_switch_blocked() {
+            self.show_error(errors::SWITCH_TAB_BUSY());
+            return;
+        }
+
+        self.finish_journal();
+        self.stop_graph_service();
+        self.stop_diff_service();
+
+        let closing = self.active_tab;
+        let next = if closing + 1 < self.tabs.len() { closing + 1 } else { closing - 1 };
+        self.switch_tab(next);
+        self.tabs.remove(closing);
+        if closing < self.active_tab {
+            self.active_tab -= 1;
+        }
+    }
+
+    pub fn on_next_tab(&mut self) {
+        if self.is_modal_focus() || self.tabs.len() < 2 {
+            return;
+        }
+        self.switch_tab((self.active_tab + 1) % self.tabs.len());
+    }
+
+    pub fn on_previous_tab(&mut self) {
+        if self.is_modal_focus() || self.tabs.len() < 2 {
+            return;
+        }
+        self.switch_tab((self.active_tab + self.tabs.len() - 1) % self.tabs.len());
+    }
+
+    // Folder names for the tab bar, with `None` for tabs still on the splash screen.
+    pub(crate) fn tab_names(&self) -> Vec<Option<String>> {
+        self.tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| {
+                let path = if index == self.active_tab { self.tab.path.as_deref() } else { tab.path.as_deref() };
+                path.map(|path| std::path::Path::new(path).file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_else(|| path.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/app/input/tabs.rs"]
+mod tests;
@@ -7,32 +7,33 @@ use std::path::{Path, PathBuf};
 
 impl App {
     pub(super) fn default_worktree_path(&self, name: &str) -> String {
-        let current = PathBuf::from(self.path.as_deref().unwrap_or("."));
+        let current = PathBuf::from(self.tab.path.as_deref().unwrap_or("."));
         let parent = current.parent().unwrap_or_else(|| Path::new("."));
         let repo_name = current.file_name().and_then(|value| value.to_str()).unwrap_or("worktree");
         parent.join(format!("{repo_name}-{name}")).display().to_string()
     }
 
     fn graph_worktree_indices(&self) -> Vec<usize> {
-        if self.viewport != Viewport::Graph || self.graph_selected == 0 {
+        if self.tab.viewport != Viewport::Graph || self.tab.graph_selected == 0 {
             return Vec::new();
         }
 
-        if let Some(alias) = self.graph_alias_at(self.graph_selected) {
-            if let Some(row) = self.graph_row_at(self.graph_selected) {
+        if let Some(alias) = self.graph_alias_at(self.tab.graph_selected) {
+            if let Some(row) = self.graph_row_at(self.tab.graph_selected) {
                 let indices: Vec<usize> =
-                    row.worktrees.iter().filter_map(|projected| self.worktrees.entries.iter().position(|entry| entry.path == projected.path && entry.name == projected.name)).collect();
+                    row.worktrees.iter().filter_map(|projected| self.tab.worktrees.entries.iter().position(|entry| entry.path == projected.path && entry.name == projected.name)).collect();
                 if !indices.is_empty() {
                     return indices;
                 }
             }
 
             return self
+                .tab
                 .worktrees
                 .entries
                 .iter()
                 .enumerate()
-                .filter_map(|(idx, entry)| entry.head.and_then(|oid| self.oids.aliases.get(&oid)).is_some_and(|current| *current == alias).then_some(idx))
+                .filter_map(|(idx, entry)| entry.head.and_then(|oid| self.tab.oids.aliases.get(&oid)).is_some_and(|current| *current == alias).then_some(idx))
                 .collect();
         }
 
@@ -40,11 +41,11 @@ impl App {
     }
 
     pub(crate) fn graph_open_worktree_indices(&self) -> Vec<usize> {
-        self.graph_worktree_indices().into_iter().filter(|idx| self.worktrees.entries.get(*idx).is_some_and(|entry| entry.is_valid)).collect()
+        self.graph_worktree_indices().into_iter().filter(|idx| self.tab.worktrees.entries.get(*idx).is_some_and(|entry| entry.is_valid)).collect()
     }
 
     fn graph_remove_worktree_indices(&self) -> Vec<usize> {
-        self.graph_worktree_indices().into_iter().filter(|idx| self.worktrees.entries.get(*idx).is_some_and(|entry| entry.can_remove())).collect()
+        self.graph_worktree_indices().into_iter().filter(|idx| self.tab.worktrees.entries.get(*idx).is_some_and(|entry| entry.can_remove())).collect()
     }
 
     pub(super) fn clear_worktree_modal_state(&mut self) {
@@ -58,7 +59,7 @@ impl App {
     pub(super) fn close_worktree_modal(&mut self) {
         let return_focus = self.modal_worktree_return_focus;
         self.clear_worktree_modal_state();
-        self.focus = return_focus;
+        self.tab.focus = return_focus;
     }
 
     fn open_worktree_chooser(&mut self, action: WorktreeModalAction, candidates: Vec<usize>, return_focus: Focus) {
@@ -67,11 +68,11 @@ impl App {
         self.modal_worktree_target = None;
         self.modal_worktree_action = action;
         self.modal_worktree_return_focus = return_focus;
-        self.focus = Focus::ModalWorktreeChooser;
+        self.tab.focus = Focus::ModalWorktreeChooser;
     }
 
     fn open_worktree_by_index(&mut self, index: usize) {
-        let Some(entry) = self.worktrees.entries.get(index).cloned() else {
+        let Some(entry) = self.tab.worktrees.entries.get(index).cloned() else {
             return;
         };
 
@@ -81,15 +82,15 @@ impl App {
         }
 
         self.clear_worktree_modal_state();
-        self.submodule_stack.clear();
+        self.tab.submodule_stack.clear();
         self.reload(Some(entry.path.display().to_string()));
-        self.viewport = Viewport::Graph;
-        self.focus = Focus::Viewport;
-        self.graph_selected = 0;
+        self.tab.viewport = Viewport::Graph;
+        self.tab.focus = Focus::Viewport;
+        self.tab.graph_selected = 0;
     }
 
     pub(super) fn open_selected_worktree(&mut self) {
-        self.open_worktree_by_index(self.worktrees_selected);
+        self.open_worktree_by_index(self.tab.worktrees_selected);
     }
 
     pub(super) fn open_graph_worktree(&mut self) {
@@ -120,7 +121,7 @@ impl App {
                 self.modal_worktree_candidates.clear();
                 self.modal_worktree_selected = 0;
                 self.modal_worktree_target = Some(index);
-                self.focus = Focus::ModalRemoveWorktree;
+                self.tab.focus = Focus::ModalRemoveWorktree;
             },
         }
     }
@@ -131,17 +132,17 @@ impl App {
         self.modal_worktree_target = Some(index);
         self.modal_worktree_action = WorktreeModalAction::Remove;
         self.modal_worktree_return_focus = return_focus;
-        self.focus = Focus::ModalRemoveWorktree;
+        self.tab.focus = Focus::ModalRemoveWorktree;
     }
 
     pub(super) fn confirm_remove_worktree(&mut self) {
-        let Some(repo) = self.repo.clone() else {
+        let Some(repo) = self.tab.repo.clone() else {
             return;
         };
         let Some(index) = self.modal_worktree_target else {
             return;
         };
-        let Some(entry) = self.worktrees.entries.get(index).cloned() else {
+        let Some(entry) = self.tab.worktrees.entries.get(index).cloned() else {
             return;
         };
 
@@ -154,7 +155,7 @@ impl App {
         match remove_worktree(&repo, &entry.name) {
             Ok(_) => {
                 self.clear_worktree_modal_state();
-                self.focus = return_focus;
+                self.tab.focus = return_focus;
                 self.reload(None);
             },
             Err(error) => self.show_error(errors::with_error(errors::REMOVE_WORKTREE(), error)),
@@ -162,26 +163,26 @@ impl App {
     }
 
     pub fn on_create_worktree(&mut self) {
-        match self.viewport {
+        match self.tab.viewport {
             Viewport::Settings | Viewport::Viewer => {},
             _ => {
-                if self.focus == Focus::Viewport && self.viewport == Viewport::Graph && self.graph_selected != 0 {
+                if self.tab.focus == Focus::Viewport && self.tab.viewport == Viewport::Graph && self.tab.graph_selected != 0 {
                     self.modal_input.clear();
                     self.modal_worktree_name.clear();
-                    self.focus = Focus::ModalCreateWorktreeName;
+                    self.tab.focus = Focus::ModalCreateWorktreeName;
                 }
             },
         }
     }
 
     pub fn on_remove_worktree(&mut self) {
-        if self.viewport == Viewport::Settings || self.viewport == Viewport::Viewer {
+        if self.tab.viewport == Viewport::Settings || self.tab.viewport == Viewport::Viewer {
             return;
         }
 
-        match self.focus {
+        match self.tab.focus {
             Focus::Worktrees => {
-                let Some(entry) = self.worktrees.entries.get(self.worktrees_selected) else {
+                let Some(entry) = self.tab.worktrees.entries.get(self.tab.worktrees_selected) else {
                     return;
                 };
 
@@ -190,9 +191,9 @@ impl App {
                     return;
                 }
 
-                self.open_remove_worktree_confirmation(self.worktrees_selected, Focus::Worktrees);
+                self.open_remove_worktree_confirmation(self.tab.worktrees_selected, Focus::Worktrees);
             },
-            Focus::Viewport if self.viewport == Viewport::Graph => {
+            Focus::Viewport if self.tab.viewport == Viewport::Graph => {
                 let all = self.graph_worktree_indices();
                 let removable = self.graph_remove_worktree_indices();
 
@@ -208,14 +209,14 @@ impl App {
     }
 
     pub fn on_toggle_worktree_lock(&mut self) {
-        if self.viewport == Viewport::Settings || self.viewport == Viewport::Viewer || self.focus != Focus::Worktrees {
+        if self.tab.viewport == Viewport::Settings || self.tab.viewport == Viewport::Viewer || self.tab.focus != Focus::Worktrees {
             return;
         }
 
-        let Some(repo) = self.repo.clone() else {
+        let Some(repo) = self.tab.repo.clone() else {
             return;
         };
-        let Some(entry) = self.worktrees.entries.get(self.worktrees_selected).cloned() else {
+        let Some(entry) = self.tab.worktrees.entries.get(self.tab.worktrees_selected).cloned() else {
             return;
         };
 
@@ -227,7 +228,7 @@ impl App {
         if entry.locked_reason.is_some() {
             match unlock_worktree(&repo, &entry.name) {
                 Ok(_) => {
-                    self.focus = Focus::Worktrees;
+                    self.tab.focus = Focus::Worktrees;
                     self.reload(None);
                 },
                 Err(error) => self.show_error(errors::with_error(errors::UNLOCK_WORKTREE(), error)),
@@ -236,7 +237,7 @@ impl App {
         }
 
         self.modal_input.clear();
-        self.focus = Focus::ModalLockWorktree;
+        self.tab.focus = Focus::ModalLockWorktree;
     }
 }
 
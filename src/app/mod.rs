@@ -7,17 +7,26 @@ pub mod draw {
     pub mod graph;
     pub mod inspector;
     pub mod modals {
+        pub mod apply_patch;
         pub mod auth;
+        pub mod batch;
         pub mod checkout;
+        pub mod content_search;
+        pub mod custom_commands;
         pub mod delete_branch;
         pub mod delete_tag;
         pub mod error;
         pub mod file_search;
+        pub mod hooks;
         pub mod input;
+        pub mod journal;
         pub mod key_capture;
+        pub mod mainline;
+        pub mod palette;
         pub mod rebase;
         pub mod remotes;
         pub mod remove_worktree;
+        pub mod reset;
         pub(crate) mod shared;
         pub mod solo;
         pub mod worktree_chooser;
@@ -34,18 +43,30 @@ pub mod draw {
     pub mod tags;
     pub mod title;
     pub mod viewer;
+    pub mod which_key;
     pub mod worktrees;
 }
 
 pub mod input {
+    pub mod content_search;
     pub mod context_menu;
+    pub mod custom_commands;
     pub mod events;
+    pub mod export;
     pub mod git;
     pub mod handler;
+    pub mod hooks;
+    pub mod journal;
+    pub mod jumps;
     pub mod modals;
     pub mod navigation;
+    pub mod palette;
+    pub mod patching;
     pub mod remotes;
+    pub mod selection;
+    pub mod session;
     pub mod submodules;
+    pub mod tabs;
     pub mod text;
     pub mod worktrees;
 
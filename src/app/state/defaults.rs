@@ -1,5 +1,5 @@
 use crate::helpers::heatmap::empty_heatmap;
-use crate::helpers::keymap::InputMode;
+use crate::helpers::keymap::{Command, InputMode};
 use crate::helpers::layout::load_layout_config;
 use crate::{
     app::input::TextInput,
@@ -7,14 +7,18 @@ use crate::{
 };
 use crate::{
     app::{
-        app::{App, AuthInputField, BranchModalAction, Focus, OperationKind, RemoteInputAction, SettingsTab, Viewport, WorktreeModalAction},
+        app::{App, AuthInputField, BranchModalAction, Focus, OperationKind, PaneMarks, RemoteInputAction, RepoTab, SettingsTab, Viewport, WorktreeModalAction},
         state::layout::Layout,
     },
     core::{branches::Branches, oids::Oids, tags::Tags},
-    git::queries::helpers::UncommittedChanges,
+    git::{
+        actions::resetting::ResetPreview,
+        queries::{grep::GrepScope, helpers::UncommittedChanges},
+    },
     helpers::{
         colors::ColorPicker,
         localisation::{Language, set_active_language},
+        marks::Marks,
         palette::*,
         spinner::Spinner,
         symbols::SymbolTheme,
@@ -22,7 +26,12 @@ use crate::{
 };
 use indexmap::IndexMap;
 use ratatui::{style::Style, text::Span, widgets::ListItem};
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::{OnceCell, RefCell},
+    collections::{HashMap, VecDeque},
+    path::PathBuf,
+    rc::Rc,
+};
 
 #[derive(Clone)]
 pub struct SplitViewerRow {
@@ -45,7 +54,6 @@ impl Default for App {
         let language = Language::English;
         set_active_language(language);
         let color = Rc::new(RefCell::new(ColorPicker::from_theme(&theme)));
-        let heatmap = empty_heatmap();
         let logo = vec![
             Span::styled(symbols.splash.logo_word_prefix.clone(), Style::default().fg(theme.COLOR_GRASS)),
             Span::styled(symbols.splash.logo_corner.clone(), Style::default().fg(theme.COLOR_GREEN)),
@@ -54,53 +62,28 @@ impl Default for App {
         App {
             // General
             logo,
-            path: None,
             recent: Vec::new(),
-            repo: None,
             spinner: Spinner::new(),
             keymaps: IndexMap::new(),
             mode: InputMode::Normal,
+            pending_keys: Vec::new(),
+            pending_keys_at: None,
+            pending_count: None,
+            pending_mark: None,
             last_input_direction: None,
             theme,
             symbols,
             language,
-            heatmap,
 
-            // User
-            name: String::new(),
-            email: String::new(),
+            // Active repository
+            tab: RepoTab::default(),
 
             // Walker utilities
             color,
-            graph: Default::default(),
-            graph_tx: None,
-            graph_rx: None,
-            walker_cancel: None,
-            walker_handle: None,
-
-            // Walker data
-            oids: Oids::default(),
-            branches: Branches::default(),
-            tags: Tags::default(),
-            stashes: Stashes::default(),
-            reflogs: HeadReflogs::default(),
-            worktrees: Worktrees::default(),
-            submodules: Submodules::default(),
-            submodule_stack: Vec::new(),
-            uncommitted: UncommittedChanges::default(),
 
             // Cache
-            current_diff: Vec::new(),
-            current_diff_identity: None,
-            is_uncommitted_loaded: false,
-            file_name: None,
-            viewer_lines: Vec::new(),
-            viewer_split_rows: Vec::new(),
-            viewer_edges: Vec::new(),      // line numbers where hunks start and end
-            viewer_hunks: Vec::new(),      // indices of changed lines the belong to hunks
             viewer_mode: ViewerMode::Full, // Viewer mode: Full, Hunks, or Split
             is_viewer_layout_dirty: false,
-            viewer_layout_signature: None,
 
             // Interface
             layout: Layout::default(),
@@ -108,48 +91,15 @@ impl Default for App {
             // Focus
             layout_config: load_layout_config(),
             mouse_drag: None,
+            graph_message_x: 0,
             last_mouse_click: None,
             context_menu: None,
             modal_area: None,
-            viewport: Viewport::Splash,
-            focus: Focus::Viewport,
-
-            // Branches
-            branches_selected: 0,
-            branches_scroll: 0.into(),
-
-            // Tags
-            tags_selected: 0,
-            tags_scroll: 0.into(),
-
-            // Stashes
-            stashes_selected: 0,
-            stashes_scroll: 0.into(),
-
-            // Reflogs
-            reflogs_selected: 0,
-            reflogs_scroll: 0.into(),
-
-            // Worktrees
-            worktrees_selected: 0,
-            worktrees_scroll: 0.into(),
-
-            // Submodules
-            submodules_selected: 0,
-            submodules_scroll: 0.into(),
-
-            // Search
-            search_path: None,
-            search_rows: Vec::new(),
-            search_is_loading: false,
-            search_error: None,
-            search_request_id: None,
-            search_selected: 0,
-            search_scroll: 0.into(),
 
             // Graph
-            graph_selected: 0,
-            graph_scroll: 0.into(),
+            marks_save_path: None,
+            session_save_path: None,
+            walk_cache_dir: None,
 
             // Splash
             splash_selected: 0,
@@ -169,20 +119,7 @@ impl Default for App {
             language_save_path: None,
 
             // Viewer
-            viewer_selected: 0,
-            viewer_scroll: 0.into(),
-
-            // Inspector
-            inspector_selected: 0,
-            inspector_scroll: 0.into(),
-
-            // Status top
-            status_top_selected: 0,
-            status_top_scroll: 0.into(),
-
-            // Status bottom
-            status_bottom_selected: 0,
-            status_bottom_scroll: 0.into(),
+            viewer_pending_line: None,
 
             // Modal checkout
             modal_checkout_selected: 0,
@@ -191,10 +128,30 @@ impl Default for App {
             modal_solo_selected: 0,
             modal_branch_action: BranchModalAction::Solo,
 
+            // Modal mainline
+            modal_mainline_selected: 0,
+            modal_mainline_kind: OperationKind::Cherrypick,
+            modal_mainline_preview: Vec::new(),
+            modal_reset_kind: Command::HardReset,
+            modal_reset_target: None,
+            modal_reset_label: String::new(),
+            modal_reset_branch: None,
+            modal_reset_preview: ResetPreview::default(),
+            modal_batch_action: None,
+            modal_patch_oids: Vec::new(),
+            modal_patch_path: PathBuf::new(),
+            modal_patch_mails: Vec::new(),
+            modal_patch_selected: 0,
+
+            // Journal
+            journal_save_path: None,
+            modal_journal_selected: 0,
+
             // Modal editor
             modal_input: TextInput::default(),
             pending_cherrypick_oid: None,
             pending_revert_oid: None,
+            pending_mainline: None,
             pending_branch_target_oid: None,
             modal_rename_branch_source: None,
             modal_worktree_name: String::new(),
@@ -211,6 +168,20 @@ impl Default for App {
             modal_file_search_selected: 0,
             modal_file_search_scroll: 0.into(),
             modal_file_search_return_focus: Focus::Viewport,
+            modal_content_search_results: Vec::new(),
+            modal_content_search_selected: 0,
+            modal_content_search_scroll: 0.into(),
+            modal_content_search_return_focus: Focus::Viewport,
+            modal_content_search_scope: GrepScope::Workdir,
+            modal_content_search_is_regex: false,
+            modal_content_search_task: None,
+            modal_content_search_query: String::new(),
+            modal_content_search_due: None,
+            modal_content_search_error: None,
+            modal_content_search_is_truncated: false,
+            modal_palette_selected: 0,
+            modal_palette_scroll: 0.into(),
+            modal_palette_return_focus: Focus::Viewport,
 
             // Modal delete branch
             modal_delete_branch_selected: 0,
@@ -237,8 +208,109 @@ impl Default for App {
             modal_network_title: String::new(),
             modal_network_message: String::new(),
 
+            // Git hooks and the one-shot flag that skips them for the next operation.
+            hook_task: None,
+            skip_hooks_once: false,
+            commit_task: None,
+
+            // Custom commands
+            custom_commands: Vec::new(),
+            custom_command_task: None,
+            background_custom_commands: Vec::new(),
+            status_notice: None,
+            pending_suspended_command: None,
+
+            // Tabs
+            tabs: vec![RepoTab::default()],
+            active_tab: 0,
+
             // Exit
             is_exit: false,
         }
     }
 }
+
+impl Default for RepoTab {
+    fn default() -> Self {
+        RepoTab {
+            path: None,
+            repo: None,
+            work_tree: None,
+            heatmap: empty_heatmap(),
+            name: String::new(),
+            email: String::new(),
+            graph: Default::default(),
+            graph_tx: None,
+            graph_rx: None,
+            walker_cancel: None,
+            walker_handle: None,
+            diff_tx: None,
+            diff_rx: None,
+            oids: Oids::default(),
+            branches: Branches::default(),
+            tags: Tags::default(),
+            stashes: Stashes::default(),
+            reflogs: HeadReflogs::default(),
+            worktrees: Worktrees::default(),
+            submodules: Submodules::default(),
+            submodule_stack: Vec::new(),
+            uncommitted: UncommittedChanges::default(),
+            commit_graph: OnceCell::new(),
+            replacements: HashMap::new(),
+            current_diff: Vec::new(),
+            current_diff_identity: None,
+            is_uncommitted_loaded: false,
+            file_name: None,
+            viewer_diff: None,
+            viewer_lines: Vec::new(),
+            viewer_split_rows: Vec::new(),
+            viewer_edges: Vec::new(),     // line numbers where hunks start and end
+            viewer_hunks: Vec::new(),     // indices of changed lines the belong to hunks
+            viewer_line_rows: Vec::new(), // first row of each line of the shown file
+            viewer_layout_signature: None,
+            viewport: Viewport::Splash,
+            focus: Focus::Viewport,
+            branches_selected: 0,
+            branches_scroll: 0.into(),
+            tags_selected: 0,
+            tags_scroll: 0.into(),
+            stashes_selected: 0,
+            stashes_scroll: 0.into(),
+            reflogs_selected: 0,
+            reflogs_scroll: 0.into(),
+            worktrees_selected: 0,
+            worktrees_scroll: 0.into(),
+            submodules_selected: 0,
+            submodules_scroll: 0.into(),
+            search_path: None,
+            search_rows: Vec::new(),
+            search_is_loading: false,
+            search_error: None,
+            search_request_id: None,
+            search_selected: 0,
+            search_scroll: 0.into(),
+            graph_selected: 0,
+            graph_scroll: 0.into(),
+            graph_marked_oids: Vec::new(),
+            graph_marks: Marks::new(),
+            graph_repeat: None,
+            jump_list: Vec::new(),
+            jump_index: 0,
+            pane_marks: PaneMarks::default(),
+            visual: None,
+            pending_visual_command: None,
+            pending_session: None,
+            pending_session_rows: Vec::new(),
+            viewer_selected: 0,
+            viewer_scroll: 0.into(),
+            inspector_selected: 0,
+            inspector_scroll: 0.into(),
+            status_top_selected: 0,
+            status_top_scroll: 0.into(),
+            status_bottom_selected: 0,
+            status_bottom_scroll: 0.into(),
+            journal: Vec::new(),
+            pending_journal: VecDeque::new(),
+        }
+    }
+}
@@ -129,8 +129,8 @@ pub struct Layout {
 impl App {
     pub fn layout(&mut self, frame: &mut Frame) {
         let is_zen = self.layout_config.is_zen;
-        let is_settings = self.viewport == Viewport::Splash || self.viewport == Viewport::Settings;
-        let is_inspector = !is_settings && self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts);
+        let is_settings = self.tab.viewport == Viewport::Splash || self.tab.viewport == Viewport::Settings;
+        let is_inspector = !is_settings && self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts);
         let is_status = !is_settings && self.layout_config.is_status;
         let is_right_pane = is_inspector || is_status;
         let is_left_pane = (self.layout_config.is_branches
@@ -141,7 +141,7 @@ impl App {
             || self.layout_config.is_submodules
             || self.layout_config.is_search)
             && !is_settings;
-        let is_viewer_split = self.viewport == Viewport::Viewer && self.viewer_mode == ViewerMode::Split;
+        let is_viewer_split = self.tab.viewport == Viewport::Viewer && self.viewer_mode == ViewerMode::Split;
 
         // Split title, main area, and status bar before pane-specific decisions.
         let chunks_vertical = RatatuiLayout::default()
@@ -214,7 +214,7 @@ impl App {
             .split(chunks_horizontal[2]);
 
         // The bottom status pane is only needed for unstaged changes on the pseudo-row.
-        let status_sections = [true, self.graph_selected == 0];
+        let status_sections = [true, self.tab.graph_selected == 0];
         let status_weights = [self.layout_config.weight_status_top, self.layout_config.weight_status_bottom];
         let status_weight_total = total_active_weight(&status_sections.into_iter().zip(status_weights).collect::<Vec<_>>());
         let chunks_status = RatatuiLayout::default()
@@ -258,7 +258,7 @@ impl App {
         // Status can merge with inspector to avoid double borders between stacked panes.
         let mut status_top_scrollbar = chunks_status[0];
         let mut status_top = chunks_status[0];
-        let merge_with_inspector = self.layout_config.is_inspector && (self.graph_selected != 0 || self.uncommitted.has_conflicts);
+        let merge_with_inspector = self.layout_config.is_inspector && (self.tab.graph_selected != 0 || self.tab.uncommitted.has_conflicts);
         if merge_with_inspector {
             status_top_scrollbar = extend_up(status_top_scrollbar, 1);
             status_top = extend_up(status_top, 1);
@@ -392,20 +392,27 @@ impl App {
         let divider_submodules_search =
             divider_rect(self.layout_config.is_submodules && self.layout_config.is_search, chunks_pane_left[6].x, chunks_pane_left[6].y.saturating_sub(1), chunks_pane_left[6].width);
         let divider_inspector_status = divider_rect(is_inspector && is_status, chunks_pane_right[1].x, chunks_pane_right[1].y.saturating_sub(1), chunks_pane_right[1].width);
-        let divider_status_files = divider_rect(is_status && self.graph_selected == 0, chunks_status[1].x, chunks_status[1].y.saturating_sub(1), chunks_status[1].width);
+        let divider_status_files = divider_rect(is_status && self.tab.graph_selected == 0, chunks_status[1].x, chunks_status[1].y.saturating_sub(1), chunks_status[1].width);
 
         if is_zen {
             let zen = chunks_vertical[1];
             let zero = zero_rect();
 
             let graph = if matches!(
-                self.focus,
+                self.tab.focus,
                 Focus::Viewport
                     | Focus::ModalCheckout
                     | Focus::ModalSolo
                     | Focus::ModalCommit
                     | Focus::ModalCherrypick
                     | Focus::ModalRevert
+                    | Focus::ModalMainline
+                    | Focus::ModalJournal
+                    | Focus::ModalReset
+                    | Focus::ModalExportGraph
+                    | Focus::ModalExportPatches
+                    | Focus::ModalApplyPatchPath
+                    | Focus::ModalApplyPatch
                     | Focus::ModalCreateBranch
                     | Focus::ModalRenameBranch
                     | Focus::ModalCreateWorktreeName
@@ -413,19 +420,26 @@ impl App {
                     | Focus::ModalDeleteBranch
                     | Focus::ModalWorktreeChooser
                     | Focus::ModalRemoveWorktree
+                    | Focus::ModalBatch
                     | Focus::ModalLockWorktree
                     | Focus::ModalRemoteAction
                     | Focus::ModalRemoteDelete
                     | Focus::ModalRemoteName
                     | Focus::ModalRemoteUrl
                     | Focus::ModalGraphLaneLimit
+                    | Focus::ModalViewerFileSizeLimit
+                    | Focus::ModalDeepen
                     | Focus::ModalGrep
                     | Focus::ModalFileSearch
+                    | Focus::ModalContentSearch
+                    | Focus::ModalCommandPalette
                     | Focus::ModalTag
                     | Focus::ModalDeleteTag
                     | Focus::ModalKeyCapture
                     | Focus::ModalAuth
                     | Focus::ModalNetworkProgress
+                    | Focus::ModalHookProgress
+                    | Focus::ModalCustomCommand
                     | Focus::ModalOperationProgress
                     | Focus::ModalOperationConflict
                     | Focus::ModalOperationSuccess
@@ -446,49 +460,56 @@ impl App {
                 statusbar_right: chunks_status_bar[1],
                 pane_left: zero,
                 pane_right: zero,
-                pane_branches: if matches!(self.focus, Focus::Branches) { zen } else { zero },
-                pane_tags: if matches!(self.focus, Focus::Tags) { zen } else { zero },
-                pane_stashes: if matches!(self.focus, Focus::Stashes) { zen } else { zero },
-                pane_reflogs: if matches!(self.focus, Focus::Reflogs) { zen } else { zero },
-                pane_worktrees: if matches!(self.focus, Focus::Worktrees) { zen } else { zero },
-                pane_submodules: if matches!(self.focus, Focus::Submodules) { zen } else { zero },
-                pane_search: if matches!(self.focus, Focus::Search) { zen } else { zero },
-                pane_inspector: if matches!(self.focus, Focus::Inspector) { zen } else { zero },
+                pane_branches: if matches!(self.tab.focus, Focus::Branches) { zen } else { zero },
+                pane_tags: if matches!(self.tab.focus, Focus::Tags) { zen } else { zero },
+                pane_stashes: if matches!(self.tab.focus, Focus::Stashes) { zen } else { zero },
+                pane_reflogs: if matches!(self.tab.focus, Focus::Reflogs) { zen } else { zero },
+                pane_worktrees: if matches!(self.tab.focus, Focus::Worktrees) { zen } else { zero },
+                pane_submodules: if matches!(self.tab.focus, Focus::Submodules) { zen } else { zero },
+                pane_search: if matches!(self.tab.focus, Focus::Search) { zen } else { zero },
+                pane_inspector: if matches!(self.tab.focus, Focus::Inspector) { zen } else { zero },
                 pane_status: zero,
-                pane_status_top: if matches!(self.focus, Focus::StatusTop) { zen } else { zero },
-                pane_status_bottom: if matches!(self.focus, Focus::StatusBottom) { zen } else { zero },
+                pane_status_top: if matches!(self.tab.focus, Focus::StatusTop) { zen } else { zero },
+                pane_status_bottom: if matches!(self.tab.focus, Focus::StatusBottom) { zen } else { zero },
 
                 // Only the focused pane receives the entire main rectangle.
-                branches: if matches!(self.focus, Focus::Branches) { zen } else { zero },
-                tags: if matches!(self.focus, Focus::Tags) { zen } else { zero },
-                stashes: if matches!(self.focus, Focus::Stashes) { zen } else { zero },
-                reflogs: if matches!(self.focus, Focus::Reflogs) { zen } else { zero },
-                worktrees: if matches!(self.focus, Focus::Worktrees) { zen } else { zero },
-                submodules: if matches!(self.focus, Focus::Submodules) { zen } else { zero },
-                search: if matches!(self.focus, Focus::Search) { zen } else { zero },
+                branches: if matches!(self.tab.focus, Focus::Branches) { zen } else { zero },
+                tags: if matches!(self.tab.focus, Focus::Tags) { zen } else { zero },
+                stashes: if matches!(self.tab.focus, Focus::Stashes) { zen } else { zero },
+                reflogs: if matches!(self.tab.focus, Focus::Reflogs) { zen } else { zero },
+                worktrees: if matches!(self.tab.focus, Focus::Worktrees) { zen } else { zero },
+                submodules: if matches!(self.tab.focus, Focus::Submodules) { zen } else { zero },
+                search: if matches!(self.tab.focus, Focus::Search) { zen } else { zero },
                 graph,
                 viewer_split_left,
                 viewer_split_right,
-                inspector: if matches!(self.focus, Focus::Inspector) { zen } else { zero },
-                status_top: if matches!(self.focus, Focus::StatusTop) { zen } else { zero },
-                status_bottom: if matches!(self.focus, Focus::StatusBottom) { zen } else { zero },
+                inspector: if matches!(self.tab.focus, Focus::Inspector) { zen } else { zero },
+                status_top: if matches!(self.tab.focus, Focus::StatusTop) { zen } else { zero },
+                status_bottom: if matches!(self.tab.focus, Focus::StatusBottom) { zen } else { zero },
 
                 // Scrollbar rectangles mirror their pane visibility in zen mode.
-                branches_scrollbar: if matches!(self.focus, Focus::Branches) { zen } else { zero },
-                tags_scrollbar: if matches!(self.focus, Focus::Tags) { zen } else { zero },
-                stashes_scrollbar: if matches!(self.focus, Focus::Stashes) { zen } else { zero },
-                reflogs_scrollbar: if matches!(self.focus, Focus::Reflogs) { zen } else { zero },
-                worktrees_scrollbar: if matches!(self.focus, Focus::Worktrees) { zen } else { zero },
-                submodules_scrollbar: if matches!(self.focus, Focus::Submodules) { zen } else { zero },
-                search_scrollbar: if matches!(self.focus, Focus::Search) { zen } else { zero },
+                branches_scrollbar: if matches!(self.tab.focus, Focus::Branches) { zen } else { zero },
+                tags_scrollbar: if matches!(self.tab.focus, Focus::Tags) { zen } else { zero },
+                stashes_scrollbar: if matches!(self.tab.focus, Focus::Stashes) { zen } else { zero },
+                reflogs_scrollbar: if matches!(self.tab.focus, Focus::Reflogs) { zen } else { zero },
+                worktrees_scrollbar: if matches!(self.tab.focus, Focus::Worktrees) { zen } else { zero },
+                submodules_scrollbar: if matches!(self.tab.focus, Focus::Submodules) { zen } else { zero },
+                search_scrollbar: if matches!(self.tab.focus, Focus::Search) { zen } else { zero },
                 graph_scrollbar: if matches!(
-                    self.focus,
+                    self.tab.focus,
                     Focus::Viewport
                         | Focus::ModalCheckout
                         | Focus::ModalSolo
                         | Focus::ModalCommit
                         | Focus::ModalCherrypick
                         | Focus::ModalRevert
+                        | Focus::ModalMainline
+                        | Focus::ModalJournal
+                        | Focus::ModalReset
+                        | Focus::ModalExportGraph
+                        | Focus::ModalExportPatches
+                        | Focus::ModalApplyPatchPath
+                        | Focus::ModalApplyPatch
                         | Focus::ModalCreateBranch
                         | Focus::ModalRenameBranch
                         | Focus::ModalCreateWorktreeName
@@ -496,19 +517,26 @@ impl App {
                         | Focus::ModalDeleteBranch
                         | Focus::ModalWorktreeChooser
                         | Focus::ModalRemoveWorktree
+                        | Focus::ModalBatch
                         | Focus::ModalLockWorktree
                         | Focus::ModalRemoteAction
                         | Focus::ModalRemoteDelete
                         | Focus::ModalRemoteName
                         | Focus::ModalRemoteUrl
                         | Focus::ModalGraphLaneLimit
+                        | Focus::ModalViewerFileSizeLimit
+                        | Focus::ModalDeepen
                         | Focus::ModalGrep
                         | Focus::ModalFileSearch
+                        | Focus::ModalContentSearch
+                        | Focus::ModalCommandPalette
                         | Focus::ModalTag
                         | Focus::ModalDeleteTag
                         | Focus::ModalKeyCapture
                         | Focus::ModalAuth
                         | Focus::ModalNetworkProgress
+                        | Focus::ModalHookProgress
+                        | Focus::ModalCustomCommand
                         | Focus::ModalOperationProgress
                         | Focus::ModalOperationConflict
                         | Focus::ModalOperationSuccess
@@ -518,9 +546,9 @@ impl App {
                 } else {
                     zero
                 },
-                inspector_scrollbar: if matches!(self.focus, Focus::Inspector) { zen } else { zero },
-                status_top_scrollbar: if matches!(self.focus, Focus::StatusTop) { zen } else { zero },
-                status_bottom_scrollbar: if matches!(self.focus, Focus::StatusBottom) { zen } else { zero },
+                inspector_scrollbar: if matches!(self.tab.focus, Focus::Inspector) { zen } else { zero },
+                status_top_scrollbar: if matches!(self.tab.focus, Focus::StatusTop) { zen } else { zero },
+                status_bottom_scrollbar: if matches!(self.tab.focus, Focus::StatusBottom) { zen } else { zero },
 
                 divider_left: zero,
                 divider_right: zero,
@@ -1,41 +1,67 @@
+use crate::core::commit_graph::{CommitGraph, GENERATION_INFINITY};
 use git2::{BranchType, Oid, Repository, Revwalk};
 use im::HashSet;
 use std::cell::RefCell;
-use std::collections::HashSet as StdHashSet;
+use std::collections::{BinaryHeap, HashMap, HashSet as StdHashSet};
 use std::{rc::Rc, sync::Mutex};
 
 // Own the revwalk cursor so commit history can be loaded in pages.
 pub struct Batcher {
-    revwalk: Mutex<Revwalk<'static>>,
+    cursor: Mutex<Cursor>,
+    // Tips the current cursor was started from.
+    roots: Mutex<Vec<Oid>>,
+}
+
+// libgit2 sorts the whole history before its first commit comes out; with a commit graph the
+// order is produced as the pages are pulled.
+enum Cursor {
+    Revwalk(Revwalk<'static>),
+    Graph(Box<TopoWalk>),
 }
 
 impl Batcher {
     // Build the initial revwalk from all visible local and remote branch tips.
-    pub fn new(repo: Rc<RefCell<Repository>>, hidden_branch_names: &HashSet<String>, extra_roots: &[Oid]) -> Result<Self, git2::Error> {
-        let revwalk = Self::build(&repo.borrow(), hidden_branch_names, extra_roots)?;
-        Ok(Self { revwalk: Mutex::new(revwalk) })
+    pub fn new(
+        repo: Rc<RefCell<Repository>>, commit_graph: Option<Rc<CommitGraph>>, hidden_branch_names: &HashSet<String>, extra_roots: &[Oid], replacements: &HashMap<Oid, Oid>,
+    ) -> Result<Self, git2::Error> {
+        let (cursor, roots) = Self::build(&repo.borrow(), commit_graph, hidden_branch_names, extra_roots, replacements)?;
+        Ok(Self { cursor: Mutex::new(cursor), roots: Mutex::new(roots) })
     }
 
     // Recreate the cursor after branch filters, fetches, or repository state changes.
-    pub fn reset(&self, repo: Rc<RefCell<Repository>>, hidden_branch_names: &HashSet<String>, extra_roots: &[Oid]) -> Result<(), git2::Error> {
-        let revwalk = Self::build(&repo.borrow(), hidden_branch_names, extra_roots)?;
-        let mut guard = self.revwalk.lock().unwrap();
-        *guard = revwalk;
+    pub fn reset(
+        &self, repo: Rc<RefCell<Repository>>, commit_graph: Option<Rc<CommitGraph>>, hidden_branch_names: &HashSet<String>, extra_roots: &[Oid], replacements: &HashMap<Oid, Oid>,
+    ) -> Result<(), git2::Error> {
+        let (cursor, roots) = Self::build(&repo.borrow(), commit_graph, hidden_branch_names, extra_roots, replacements)?;
+        let mut guard = self.cursor.lock().unwrap();
+        *guard = cursor;
+        *self.roots.lock().unwrap() = roots;
         Ok(())
     }
 
     // Pull the next page, dropping commits libgit2 cannot resolve.
     pub fn next(&self, count: usize) -> Vec<Oid> {
-        let mut revwalk = self.revwalk.lock().unwrap();
-        revwalk.by_ref().take(count).filter_map(Result::ok).collect()
+        match &mut *self.cursor.lock().unwrap() {
+            Cursor::Revwalk(revwalk) => revwalk.by_ref().take(count).filter_map(Result::ok).collect(),
+            Cursor::Graph(walk) => std::iter::from_fn(|| walk.next()).take(count).collect(),
+        }
+    }
+
+    // The tips this walk started from.
+    pub fn roots(&self) -> Vec<Oid> {
+        self.roots.lock().unwrap().clone()
     }
 
-    fn build(repo: &Repository, hidden_branch_names: &HashSet<String>, extra_roots: &[Oid]) -> Result<Revwalk<'static>, git2::Error> {
+    fn build(
+        repo: &Repository, commit_graph: Option<Rc<CommitGraph>>, hidden_branch_names: &HashSet<String>, extra_roots: &[Oid], replacements: &HashMap<Oid, Oid>,
+    ) -> Result<(Cursor, Vec<Oid>), git2::Error> {
         // The repository outlives the revwalk in App state; this keeps libgit2's lifetime usable here.
         let repo_ref: &'static Repository = unsafe { std::mem::transmute::<&Repository, &'static Repository>(repo) };
 
         let mut revwalk = repo_ref.revwalk()?;
         let mut pushed = StdHashSet::new();
+        // Every push in order, repeats included; libgit2 starts from the last push of each tip.
+        let mut pushes = Vec::new();
 
         for branch_type in [BranchType::Local, BranchType::Remote] {
             for branch_result in repo.branches(Some(branch_type))? {
@@ -49,6 +75,7 @@ impl Batcher {
                 if !hidden_branch_names.contains(&name) {
                     revwalk.push(oid)?;
                     pushed.insert(oid);
+                    pushes.push(oid);
                 }
             }
         }
@@ -56,10 +83,200 @@ impl Batcher {
         for oid in extra_roots {
             if pushed.insert(*oid) {
                 revwalk.push(*oid)?;
+                pushes.push(*oid);
             }
         }
 
+        // Newest push first, the order libgit2 hands its tips to the topological sort.
+        let mut seen = StdHashSet::new();
+        let tips: Vec<Oid> = pushes.into_iter().rev().filter(|oid| seen.insert(*oid)).collect();
+
+        // Sorted so two walks over the same tips compare equal.
+        let mut roots: Vec<Oid> = pushed.into_iter().collect();
+        roots.sort();
+        // libgit2 walks the original parents, so replaced history is ordered here with every commit read.
+        if !replacements.is_empty() {
+            return Ok((Cursor::Graph(Box::new(TopoWalk::new(repo_ref, Rc::new(CommitGraph::empty()), &tips, replacements.clone()))), roots));
+        }
+        if let Some(commit_graph) = commit_graph {
+            return Ok((Cursor::Graph(Box::new(TopoWalk::new(repo_ref, commit_graph, &tips, HashMap::new()))), roots));
+        }
+
         revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
-        Ok(revwalk)
+        Ok((Cursor::Revwalk(revwalk), roots))
+    }
+}
+
+// Children before parents and newest first among the commits that are ready, like libgit2's
+// TOPOLOGICAL | TIME. A commit is ready once every child is out; generation numbers tell how
+// deep history has to be counted before that is known, so only that much is read.
+struct TopoWalk {
+    repo: &'static Repository,
+    graph: Rc<CommitGraph>,
+    // Commits written after the commit-graph file, numbered after its positions.
+    extras: Vec<ExtraCommit>,
+    extra_nodes: HashMap<Oid, u32>,
+    // Replace refs; a replaced commit keeps its id but takes the replacement's time and parents.
+    replacements: HashMap<Oid, Oid>,
+    // Children not yet emitted, for every commit counted so far.
+    indegrees: HashMap<u32, u32>,
+    // Counted commits whose parents are not counted yet, highest generation first.
+    explore: BinaryHeap<(u64, u32)>,
+    // Commits with no children left, newest first.
+    ready: ReadyQueue,
+}
+
+struct ExtraCommit {
+    oid: Oid,
+    time: i64,
+    parents: Vec<Oid>,
+}
+
+impl TopoWalk {
+    fn new(repo: &'static Repository, graph: Rc<CommitGraph>, tips: &[Oid], replacements: HashMap<Oid, Oid>) -> Self {
+        let mut walk = Self { repo, graph, extras: Vec::new(), extra_nodes: HashMap::new(), replacements, indegrees: HashMap::new(), explore: BinaryHeap::new(), ready: ReadyQueue::default() };
+
+        let nodes: Vec<u32> = tips.iter().filter_map(|&oid| walk.node(oid)).collect();
+        for &node in &nodes {
+            if walk.indegrees.insert(node, 0).is_none() {
+                walk.explore.push((walk.generation(node), node));
+            }
+        }
+
+        // A tip in another tip's history waits for the commits above it like any other commit.
+        let floor = nodes.iter().map(|&node| walk.generation(node)).min().unwrap_or(GENERATION_INFINITY);
+        walk.count_to(floor);
+        for node in nodes {
+            if walk.indegrees[&node] == 0 {
+                walk.push_ready(node);
+            }
+        }
+        walk
+    }
+
+    fn next(&mut self) -> Option<Oid> {
+        let node = self.ready.pop()?;
+        for parent in self.parents(node) {
+            // Every child of the parent sits above its generation, so counting that deep settles it.
+            self.count_to(self.generation(parent));
+            if let Some(indegree) = self.indegrees.get_mut(&parent)
+                && *indegree > 0
+            {
+                *indegree -= 1;
+                if *indegree == 0 {
+                    self.push_ready(parent);
+                }
+            }
+        }
+        Some(self.oid(node))
+    }
+
+    // Count the children of every commit at or above `floor`.
+    fn count_to(&mut self, floor: u64) {
+        while let Some(&(generation, node)) = self.explore.peek()
+            && generation >= floor
+        {
+            self.explore.pop();
+            for parent in self.parents(node) {
+                match self.indegrees.get_mut(&parent) {
+                    Some(indegree) => *indegree += 1,
+                    None => {
+                        self.indegrees.insert(parent, 1);
+                        self.explore.push((self.generation(parent), parent));
+                    },
+                }
+            }
+        }
+    }
+
+    fn push_ready(&mut self, node: u32) {
+        self.ready.push(self.time(node), node);
+    }
+
+    // Graph positions first; anything newer is read from the object database once.
+    fn node(&mut self, oid: Oid) -> Option<u32> {
+        if let Some(position) = self.graph.position(oid) {
+            return Some(position);
+        }
+        if let Some(&node) = self.extra_nodes.get(&oid) {
+            return Some(node);
+        }
+        let commit = self.repo.find_commit(self.replacements.get(&oid).copied().unwrap_or(oid)).ok()?;
+        let node = (self.graph.len() + self.extras.len()) as u32;
+        self.extras.push(ExtraCommit { oid, time: commit.time().seconds(), parents: commit.parent_ids().collect() });
+        self.extra_nodes.insert(oid, node);
+        Some(node)
+    }
+
+    fn extra(&self, node: u32) -> Option<&ExtraCommit> {
+        self.extras.get((node as usize).checked_sub(self.graph.len())?)
+    }
+
+    fn parents(&mut self, node: u32) -> Vec<u32> {
+        match self.extra(node) {
+            Some(extra) => extra.parents.clone().into_iter().filter_map(|oid| self.node(oid)).collect(),
+            None => self.graph.parents(node).collect(),
+        }
+    }
+
+    fn generation(&self, node: u32) -> u64 {
+        if self.extra(node).is_some() { GENERATION_INFINITY } else { self.graph.generation(node) }
+    }
+
+    fn time(&self, node: u32) -> i64 {
+        self.extra(node).map_or_else(|| self.graph.commit_time(node), |extra| extra.time)
+    }
+
+    fn oid(&self, node: u32) -> Oid {
+        self.extra(node).map_or_else(|| self.graph.oid(node), |extra| extra.oid)
+    }
+}
+
+// libgit2's pqueue, sift for sift. Its heap is not stable, so commits from the same second only
+// come out in libgit2's order when they are inserted and removed exactly the way it does.
+#[derive(Default)]
+struct ReadyQueue {
+    heap: Vec<(i64, u32)>,
+}
+
+impl ReadyQueue {
+    fn push(&mut self, time: i64, node: u32) {
+        let mut index = self.heap.len();
+        self.heap.push((time, node));
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.heap[parent].0 >= time {
+                break;
+            }
+            self.heap[index] = self.heap[parent];
+            index = parent;
+        }
+        self.heap[index] = (time, node);
+    }
+
+    fn pop(&mut self) -> Option<u32> {
+        let (_, node) = *self.heap.first()?;
+        let last = self.heap.pop()?;
+        if self.heap.is_empty() {
+            return Some(node);
+        }
+
+        let mut index = 0;
+        loop {
+            let mut kid = index * 2 + 1;
+            if kid >= self.heap.len() {
+                break;
+            }
+            if kid + 1 < self.heap.len() && self.heap[kid].0 < self.heap[kid + 1].0 {
+                kid += 1;
+            }
+            if last.0 >= self.heap[kid].0 {
+                break;
+            }
+            self.heap[index] = self.heap[kid];
+            index = kid;
+        }
+        self.heap[index] = last;
+        Some(node)
     }
 }
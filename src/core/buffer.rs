@@ -2,7 +2,7 @@ use crate::core::chunk::{Chunk, LaneRef, NONE};
 use im::{OrdMap, Vector};
 use std::ops::Deref;
 
-#[derive(Default, Clone)]
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
 pub struct Delta {
     pub ops: Vec<DeltaOp>,
 }
@@ -359,34 +359,73 @@ impl Buffer {
         let end = end.min(self.deltas.len());
 
         for delta in self.deltas.iter().skip(begin).take(end - begin) {
-            for op in delta.ops.iter() {
-                match op {
-                    DeltaOp::Insert { index, item } => {
-                        snapshot.lanes.insert(*index, item.clone());
-                    },
-                    DeltaOp::Remove { index } => {
-                        snapshot.lanes.remove(*index);
-                    },
-                    DeltaOp::Replace { index, new } => {
-                        snapshot.lanes[*index] = new.clone();
-                    },
-                    DeltaOp::CompressedParentInsert { parent } => {
-                        if *parent != NONE && !snapshot.compressed_parents.iter().any(|candidate| candidate == parent) {
-                            snapshot.compressed_parents.push_back(*parent);
-                        }
-                    },
-                    DeltaOp::CompressedParentRemove { parent } => {
-                        if let Some(index) = snapshot.compressed_parents.iter().position(|candidate| candidate == parent) {
-                            snapshot.compressed_parents.remove(index);
-                        }
-                    },
-                }
-            }
+            apply_delta(&mut snapshot, delta);
             history.push_back(snapshot.clone());
         }
 
         history
     }
+
+    // Lane holding the commit drawn at a row, read back from the recorded history.
+    pub fn lane_at(&self, row: usize, alias: u32) -> Option<LaneRef> {
+        let snapshot = self.window(row + 1, row + 2).last().cloned()?;
+        snapshot.lanes.iter().position(|chunk| !chunk.is_dummy() && chunk.alias == alias).map(|index| LaneRef::new(index, snapshot.lanes[index].is_flattened))
+    }
+
+    // Append stored deltas after the current row, then rebuild lanes and checkpoints from the whole history.
+    pub fn graft(&mut self, tail: impl IntoIterator<Item = Delta>) {
+        let delta = std::mem::take(&mut self.delta);
+        self.deltas.push_back(delta);
+        self.deltas.extend(tail);
+        self.mergers.clear();
+        self.transient_lanes.clear();
+
+        let mut snapshot = GraphSnapshot::default();
+        self.checkpoints = OrdMap::new();
+        for (idx, delta) in self.deltas.iter().enumerate() {
+            apply_delta(&mut snapshot, delta);
+            if idx.is_multiple_of(100) {
+                self.checkpoints.insert(idx, snapshot.clone());
+            }
+        }
+        self.curr = snapshot.lanes;
+        self.compressed_parents = snapshot.compressed_parents;
+    }
+
+    // Current lanes and compressed parents, for comparing against a replayed history.
+    pub fn matches(&self, snapshot: &GraphSnapshot) -> bool {
+        self.curr == snapshot.lanes && self.compressed_parents == snapshot.compressed_parents
+    }
+}
+
+pub fn apply_delta(snapshot: &mut GraphSnapshot, delta: &Delta) {
+    for op in delta.ops.iter() {
+        apply_op(snapshot, op);
+    }
+}
+
+pub fn apply_op(snapshot: &mut GraphSnapshot, op: &DeltaOp) {
+    match op {
+        DeltaOp::Insert { index, item } => {
+            snapshot.lanes.insert(*index, item.clone());
+        },
+        DeltaOp::Remove { index } => {
+            snapshot.lanes.remove(*index);
+        },
+        DeltaOp::Replace { index, new } => {
+            snapshot.lanes[*index] = new.clone();
+        },
+        DeltaOp::CompressedParentInsert { parent } => {
+            if *parent != NONE && !snapshot.compressed_parents.iter().any(|candidate| candidate == parent) {
+                snapshot.compressed_parents.push_back(*parent);
+            }
+        },
+        DeltaOp::CompressedParentRemove { parent } => {
+            if let Some(index) = snapshot.compressed_parents.iter().position(|candidate| candidate == parent) {
+                snapshot.compressed_parents.remove(index);
+            }
+        },
+    }
 }
 
 fn append_unique_parents(parents: &mut Vec<u32>, chunk: &Chunk) {
@@ -0,0 +1,262 @@
+use git2::{Oid, Repository};
+use std::{collections::HashSet, fs, path::Path};
+
+const SIGNATURE: &[u8; 4] = b"CGPH";
+const HASH_LEN: usize = 20;
+// Tree id, two parent words, generation and the low word of the commit time.
+const COMMIT_DATA_LEN: usize = HASH_LEN + 16;
+const NO_PARENT: u32 = 0x7000_0000;
+// Set on the second parent word when the parents continue in the extra edge list.
+const EXTRA_EDGES: u32 = 0x8000_0000;
+const LAST_EDGE: u32 = 0x8000_0000;
+// Set on a generation data entry whose offset did not fit and lives in the overflow chunk.
+const GENERATION_OVERFLOW: u32 = 0x8000_0000;
+
+// Reported for commits the file does not know; they sort above every commit it does.
+pub const GENERATION_INFINITY: u64 = u64::MAX;
+
+// Read-only view of `objects/info/commit-graph` or of a split commit-graph chain.
+// Positions run across the whole chain, base layer first, the way parent entries count them.
+pub struct CommitGraph {
+    layers: Vec<Layer>,
+    // Corrected commit dates are used only when every layer carries them.
+    has_corrected_dates: bool,
+}
+
+struct Layer {
+    data: Vec<u8>,
+    // Commits in the layers below this one.
+    base: u32,
+    count: u32,
+    fanout: usize,
+    lookup: usize,
+    commit_data: usize,
+    extra_edges: Option<(usize, usize)>,
+    generation_data: Option<usize>,
+    generation_overflow: Option<(usize, usize)>,
+}
+
+impl CommitGraph {
+    // None when the repository has no usable commit-graph file, or when git would not trust one.
+    pub fn open(repo: &Repository) -> Option<Self> {
+        if !repo.config().ok().and_then(|config| config.get_bool("core.commitGraph").ok()).unwrap_or(true) {
+            return None;
+        }
+        // Grafted and replaced parents differ from the ones written into the file.
+        if repo.is_shallow() || repo.references_glob("refs/replace/*").ok()?.next().is_some() {
+            return None;
+        }
+
+        let info = repo.commondir().join("objects").join("info");
+        Self::from_chain(&info.join("commit-graphs")).or_else(|| Self::from_files(vec![fs::read(info.join("commit-graph")).ok()?]))
+    }
+
+    // No commits at all; walks over it read every commit from the object database.
+    pub fn empty() -> Self {
+        Self { layers: Vec::new(), has_corrected_dates: false }
+    }
+
+    fn from_chain(dir: &Path) -> Option<Self> {
+        let chain = fs::read_to_string(dir.join("commit-graph-chain")).ok()?;
+        let files = chain.lines().filter(|line| !line.is_empty()).map(|hash| fs::read(dir.join(format!("graph-{hash}.graph"))).ok()).collect::<Option<Vec<_>>>()?;
+        Self::from_files(files)
+    }
+
+    // Layers are given base first; any malformed layer rejects the whole graph.
+    pub fn from_files(files: Vec<Vec<u8>>) -> Option<Self> {
+        let mut layers: Vec<Layer> = Vec::with_capacity(files.len());
+        for (index, data) in files.into_iter().enumerate() {
+            let base = layers.last().map_or(0, |layer| layer.base + layer.count);
+            layers.push(Layer::parse(data, index, base)?);
+        }
+        if layers.is_empty() {
+            return None;
+        }
+
+        let has_corrected_dates = layers.iter().all(|layer| layer.generation_data.is_some());
+        let graph = Self { layers, has_corrected_dates };
+        graph.is_consistent().then_some(graph)
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.last().map_or(0, |layer| (layer.base + layer.count) as usize)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn position(&self, oid: Oid) -> Option<u32> {
+        self.layers.iter().rev().find_map(|layer| layer.find(oid.as_bytes()).map(|index| layer.base + index))
+    }
+
+    pub fn oid(&self, position: u32) -> Oid {
+        let (layer, index) = self.locate(position);
+        let start = layer.lookup + index * HASH_LEN;
+        Oid::from_bytes(&layer.data[start..start + HASH_LEN]).unwrap()
+    }
+
+    // Every parent in order, including the third and later parents of octopus merges.
+    pub fn parents(&self, position: u32) -> impl Iterator<Item = u32> + '_ {
+        let (layer, index) = self.locate(position);
+        let record = layer.commit_data + index * COMMIT_DATA_LEN + HASH_LEN;
+        let first = read_u32(&layer.data, record);
+        let second = read_u32(&layer.data, record + 4);
+
+        let mut direct = [first, second].into_iter().filter(|&parent| parent != NO_PARENT && parent & EXTRA_EDGES == 0);
+        let mut edge = (second != NO_PARENT && second & EXTRA_EDGES != 0).then(|| layer.extra_edges.map_or(0, |(start, _)| start) + (second & !EXTRA_EDGES) as usize * 4);
+        std::iter::from_fn(move || {
+            if let Some(parent) = direct.next() {
+                return Some(parent);
+            }
+            let offset = edge?;
+            let value = read_u32(&layer.data, offset);
+            edge = (value & LAST_EDGE == 0).then_some(offset + 4);
+            Some(value & !LAST_EDGE)
+        })
+    }
+
+    pub fn commit_time(&self, position: u32) -> i64 {
+        let (layer, index) = self.locate(position);
+        let record = layer.commit_data + index * COMMIT_DATA_LEN + HASH_LEN + 8;
+        let high = (read_u32(&layer.data, record) & 0b11) as i64;
+        (high << 32) | read_u32(&layer.data, record + 4) as i64
+    }
+
+    // Strictly greater than the generation of every parent, so a walk looking for a commit can
+    // stop at anything not above it.
+    pub fn generation(&self, position: u32) -> u64 {
+        let (layer, index) = self.locate(position);
+        if !self.has_corrected_dates {
+            return (read_u32(&layer.data, layer.commit_data + index * COMMIT_DATA_LEN + HASH_LEN + 8) >> 2) as u64;
+        }
+
+        let offset = read_u32(&layer.data, layer.generation_data.unwrap() + index * 4);
+        let offset = if offset & GENERATION_OVERFLOW == 0 {
+            offset as u64
+        } else {
+            let at = (offset & !GENERATION_OVERFLOW) as usize * 8;
+            layer.generation_overflow.filter(|&(_, len)| at + 8 <= len).map_or(0, |(start, _)| read_u64(&layer.data, start + at))
+        };
+        (self.commit_time(position) as u64).saturating_add(offset)
+    }
+
+    // Whether `ancestor` is in the history of `commit`; a commit does not descend from itself.
+    pub fn is_descendant_of(&self, commit: u32, ancestor: u32) -> bool {
+        let floor = self.generation(ancestor);
+        let mut seen = HashSet::from([commit]);
+        let mut stack = vec![commit];
+        while let Some(position) = stack.pop() {
+            for parent in self.parents(position) {
+                if parent == ancestor {
+                    return true;
+                }
+                if self.generation(parent) > floor && seen.insert(parent) {
+                    stack.push(parent);
+                }
+            }
+        }
+        false
+    }
+
+    fn locate(&self, position: u32) -> (&Layer, usize) {
+        let layer = self.layers.iter().rev().find(|layer| position >= layer.base).unwrap();
+        (layer, (position - layer.base) as usize)
+    }
+
+    // Parent entries must point into this layer or the ones below it, and edge lists must end
+    // inside their chunk, so the accessors above never read out of bounds.
+    fn is_consistent(&self) -> bool {
+        self.layers.iter().all(|layer| {
+            let limit = layer.base + layer.count;
+            let edges = layer.extra_edges.map_or(0, |(_, len)| len / 4);
+            let edge_at = |index: usize| read_u32(&layer.data, layer.extra_edges.unwrap().0 + index * 4);
+            (0..layer.count as usize).all(|index| {
+                let record = layer.commit_data + index * COMMIT_DATA_LEN + HASH_LEN;
+                let first = read_u32(&layer.data, record);
+                let second = read_u32(&layer.data, record + 4);
+                let is_valid = |parent: u32| parent == NO_PARENT || parent < limit;
+                if second == NO_PARENT || second & EXTRA_EDGES == 0 {
+                    return is_valid(first) && is_valid(second);
+                }
+                let start = (second & !EXTRA_EDGES) as usize;
+                let Some(end) = (start..edges).find(|&edge| edge_at(edge) & LAST_EDGE != 0) else {
+                    return false;
+                };
+                is_valid(first) && (start..=end).all(|edge| edge_at(edge) & !LAST_EDGE < limit)
+            })
+        })
+    }
+}
+
+impl Layer {
+    fn parse(data: Vec<u8>, index: usize, base: u32) -> Option<Self> {
+        // Version 1 with SHA-1 ids, and exactly as many base layers as sit below this one.
+        let header = data.get(..8)?;
+        if &header[..4] != SIGNATURE || header[4] != 1 || header[5] != 1 || header[7] as usize != index {
+            return None;
+        }
+
+        let chunk_count = header[6] as usize;
+        let mut table = Vec::with_capacity(chunk_count + 1);
+        for entry in 0..=chunk_count {
+            let start = 8 + entry * 12;
+            let id: [u8; 4] = data.get(start..start + 4)?.try_into().ok()?;
+            let offset = usize::try_from(read_u64(&data, start + 4)).ok()?;
+            table.push((id, offset));
+        }
+        // The trailing checksum follows the last chunk.
+        if table.windows(2).any(|pair| pair[0].1 > pair[1].1) || table.last()?.1 > data.len().checked_sub(HASH_LEN)? {
+            return None;
+        }
+        let chunk = |id: &[u8; 4]| table.windows(2).find(|pair| &pair[0].0 == id).map(|pair| (pair[0].1, pair[1].1 - pair[0].1));
+
+        let (fanout, fanout_len) = chunk(b"OIDF")?;
+        if fanout_len != 256 * 4 {
+            return None;
+        }
+        let count = read_u32(&data, fanout + 255 * 4);
+        if (0..255).any(|byte| read_u32(&data, fanout + byte * 4) > read_u32(&data, fanout + (byte + 1) * 4)) {
+            return None;
+        }
+
+        let (lookup, lookup_len) = chunk(b"OIDL")?;
+        let (commit_data, commit_data_len) = chunk(b"CDAT")?;
+        let generation_data = chunk(b"GDA2");
+        if lookup_len != count as usize * HASH_LEN || commit_data_len != count as usize * COMMIT_DATA_LEN || generation_data.is_some_and(|(_, len)| len != count as usize * 4) {
+            return None;
+        }
+        base.checked_add(count)?;
+
+        Some(Self { data, base, count, fanout, lookup, commit_data, extra_edges: chunk(b"EDGE"), generation_data: generation_data.map(|(start, _)| start), generation_overflow: chunk(b"GDO2") })
+    }
+
+    // The fanout narrows the sorted id table to ids sharing the first byte.
+    fn find(&self, oid: &[u8]) -> Option<u32> {
+        let first = oid[0] as usize;
+        let mut low = if first == 0 { 0 } else { read_u32(&self.data, self.fanout + (first - 1) * 4) as usize };
+        let mut high = read_u32(&self.data, self.fanout + first * 4) as usize;
+        while low < high {
+            let middle = (low + high) / 2;
+            let start = self.lookup + middle * HASH_LEN;
+            match self.data[start..start + HASH_LEN].cmp(oid) {
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+                std::cmp::Ordering::Equal => return Some(middle as u32),
+            }
+        }
+        None
+    }
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_be_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
+#[cfg(test)]
+#[path = "../tests/core/commit_graph.rs"]
+mod tests;
@@ -0,0 +1,72 @@
+use crate::git::{
+    os::path::open_repo,
+    queries::grep::{GrepMatch, GrepPattern, GrepScope, grep_files},
+};
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::{Receiver, Sender, channel},
+    },
+    thread,
+};
+
+// Enough to browse; past this the query is too broad to be useful.
+pub const CONTENT_SEARCH_RESULT_LIMIT: usize = 2000;
+
+#[derive(Debug)]
+pub enum ContentSearchEvent {
+    Match(GrepMatch),
+    Done { is_truncated: bool },
+    Failed(String),
+}
+
+// One running search. Dropping it cancels the worker, so replacing it with a new query stops the old one.
+pub struct ContentSearchTask {
+    pub events: Receiver<ContentSearchEvent>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Drop for ContentSearchTask {
+    fn drop(&mut self) {
+        self.cancel.store(true, Ordering::Relaxed);
+    }
+}
+
+// `size_limit` is the viewer's file size limit in bytes; larger files are not searched.
+pub fn spawn_content_search(path: String, work_tree: Option<PathBuf>, scope: GrepScope, pattern: GrepPattern, size_limit: u64) -> ContentSearchTask {
+    let (tx, events) = channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    let worker_cancel = cancel.clone();
+    thread::spawn(move || run_content_search(&path, work_tree, scope, &pattern, size_limit, &worker_cancel, &tx));
+    ContentSearchTask { events, cancel }
+}
+
+fn run_content_search(path: &str, work_tree: Option<PathBuf>, scope: GrepScope, pattern: &GrepPattern, size_limit: u64, cancel: &AtomicBool, tx: &Sender<ContentSearchEvent>) {
+    let repo = match open_repo(path, work_tree.as_deref()) {
+        Ok(repo) => repo,
+        Err(error) => {
+            let _ = tx.send(ContentSearchEvent::Failed(error.message().to_string()));
+            return;
+        },
+    };
+
+    let mut found = 0;
+    let result = grep_files(&repo, scope, pattern, size_limit, cancel, |result| {
+        found += 1;
+        // A closed receiver means the modal moved on, which stops the walk like a cancel.
+        found <= CONTENT_SEARCH_RESULT_LIMIT && tx.send(ContentSearchEvent::Match(result)).is_ok()
+    });
+    if cancel.load(Ordering::Relaxed) {
+        return;
+    }
+    let _ = tx.send(match result {
+        Ok(()) => ContentSearchEvent::Done { is_truncated: found > CONTENT_SEARCH_RESULT_LIMIT },
+        Err(error) => ContentSearchEvent::Failed(error.message().to_string()),
+    });
+}
+
+#[cfg(test)]
+#[path = "../tests/core/content_search.rs"]
+mod tests;
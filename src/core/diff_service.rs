@@ -0,0 +1,225 @@
+use crate::{
+    core::graph_service::{Generation, RequestId},
+    git::{
+        os::path::open_repo,
+        queries::{
+            blobs::{BINARY_SNIFF_LEN, FileSides, HEX_PREVIEW_LEN, get_file_sides_at_oid, get_file_sides_at_workdir, is_binary_file, parse_lfs_pointer},
+            diffs::{get_conflict_file, get_file_at_oid, get_file_at_workdir, get_file_diff_at_oid, get_file_diff_at_workdir, get_filenames_diff_at_oid_cancellable},
+            helpers::{ConflictFile, FileChange, Hunk, LfsPointer},
+        },
+    },
+    helpers::text::{decode, sanitize},
+};
+use git2::{Oid, Repository};
+use std::{
+    path::PathBuf,
+    sync::mpsc::{Receiver, Sender},
+    thread,
+};
+
+#[derive(Clone, Debug)]
+pub enum DiffCommand {
+    // Files touched by a commit, for the status pane.
+    QueryFileList { generation: Generation, request_id: RequestId, oid: Oid },
+    // One file's contents and hunks for the viewer; Oid::zero reads the working tree.
+    // Files larger than `size_limit` bytes come back truncated; None loads them whole.
+    QueryFile { generation: Generation, request_id: RequestId, oid: Oid, filename: String, is_conflict: bool, size_limit: Option<u64> },
+    Shutdown,
+}
+
+#[derive(Debug)]
+pub enum DiffEvent {
+    FileList { generation: Generation, request_id: RequestId, oid: Oid, changes: Vec<FileChange> },
+    File { generation: Generation, request_id: RequestId, oid: Oid, filename: String, diff: FileDiff },
+}
+
+// Everything the viewer lays out for one file, kept so layout changes can rewrap it without going back to git.
+#[derive(Debug)]
+pub enum FileDiff {
+    Patch { original_lines: Vec<String>, hunks: Vec<Hunk> },
+    Conflict(ConflictFile),
+    // Sizes are None for the side where the file does not exist; the preview is the leading bytes of the shown side.
+    Binary { old_size: Option<u64>, new_size: Option<u64>, preview: Vec<u8> },
+    LfsPointer { old: Option<LfsPointer>, new: Option<LfsPointer> },
+    // A file over the size limit: the first `limit` bytes of it and of its hunks.
+    Truncated { lines: Vec<String>, hunks: Vec<Hunk>, size: u64, limit: u64 },
+}
+
+pub struct DiffServiceConfig {
+    pub generation: Generation,
+    pub path: String,
+    pub work_tree: Option<PathBuf>,
+}
+
+// Reads one file the way the viewer shows it: conflicts from the index stages, LFS pointers and
+// binary files as summaries, oversized files truncated, everything else as a patch.
+// `is_cancelled` can stop the patch halfway, which leaves it without hunks; callers drop that result.
+pub fn load_file_diff(repo: &Repository, oid: Oid, filename: &str, is_conflict: bool, size_limit: Option<u64>, is_cancelled: &mut dyn FnMut() -> bool) -> FileDiff {
+    let is_workdir = oid == Oid::zero();
+    if is_workdir
+        && is_conflict
+        && let Ok(Some(conflict)) = get_conflict_file(repo, filename)
+    {
+        return FileDiff::Conflict(conflict);
+    }
+
+    // Only as much is read as the summaries and the truncated view need; sizes come from the headers.
+    let read_limit = size_limit.map(|limit| limit.max(BINARY_SNIFF_LEN as u64));
+    let sides = if is_workdir { get_file_sides_at_workdir(repo, filename, read_limit) } else { get_file_sides_at_oid(repo, oid, filename, read_limit).unwrap_or_default() };
+    if let Some(summary) = summarize_file(repo, filename, (!is_workdir).then_some(oid), &sides) {
+        return summary;
+    }
+
+    let hunks = if is_workdir { get_file_diff_at_workdir(repo, filename, is_cancelled) } else { get_file_diff_at_oid(repo, oid, filename, is_cancelled) }.unwrap_or_default();
+    let size = sides.shown().map_or(0, |side| side.size);
+    match size_limit {
+        Some(limit) if size > limit => {
+            let lines = sides.new.as_ref().map(|side| sanitize(decode(&side.head[..side.head.len().min(limit as usize)])).lines().map(str::to_string).collect()).unwrap_or_default();
+            FileDiff::Truncated { lines, hunks: truncate_hunks(hunks, limit), size, limit }
+        },
+        _ if is_workdir => FileDiff::Patch { original_lines: get_file_at_workdir(repo, filename), hunks },
+        _ => FileDiff::Patch { original_lines: get_file_at_oid(repo, oid, filename), hunks },
+    }
+}
+
+// LFS pointers and binary files, which the viewer shows as a summary, or None for text.
+fn summarize_file(repo: &Repository, filename: &str, commit: Option<Oid>, sides: &FileSides) -> Option<FileDiff> {
+    let old = sides.old.as_ref().map(|side| parse_lfs_pointer(&side.head));
+    let new = sides.new.as_ref().map(|side| parse_lfs_pointer(&side.head));
+    if old.as_ref().is_some_and(Option::is_some) || new.as_ref().is_some_and(Option::is_some) {
+        return Some(FileDiff::LfsPointer { old: old.flatten(), new: new.flatten() });
+    }
+
+    if is_binary_file(repo, filename, commit, sides) {
+        let preview = sides.shown().map(|side| side.head[..side.head.len().min(HEX_PREVIEW_LEN)].to_vec()).unwrap_or_default();
+        return Some(FileDiff::Binary { old_size: sides.old.as_ref().map(|side| side.size), new_size: sides.new.as_ref().map(|side| side.size), preview });
+    }
+    None
+}
+
+// A rewritten file can change every line, so the hunks of a truncated file stop at the same byte budget.
+fn truncate_hunks(hunks: Vec<Hunk>, limit: u64) -> Vec<Hunk> {
+    let mut budget = limit as usize;
+    let mut kept = Vec::new();
+    for mut hunk in hunks {
+        let mut count = 0;
+        for line in &hunk.lines {
+            // Header rows are not file content and always fit.
+            let cost = if matches!(line.origin, '+' | '-' | ' ') { line.content.len() } else { 0 };
+            if cost > budget {
+                break;
+            }
+            budget -= cost;
+            count += 1;
+        }
+        let is_cut = count < hunk.lines.len();
+        hunk.lines.truncate(count);
+        if !hunk.lines.is_empty() {
+            kept.push(hunk);
+        }
+        if is_cut {
+            break;
+        }
+    }
+    kept
+}
+
+pub fn spawn_diff_service(config: DiffServiceConfig, rx: Receiver<DiffCommand>, tx: Sender<DiffEvent>) -> thread::JoinHandle<()> {
+    thread::spawn(move || run_diff_service(config, rx, tx))
+}
+
+fn run_diff_service(config: DiffServiceConfig, rx: Receiver<DiffCommand>, tx: Sender<DiffEvent>) {
+    let Ok(mut repo) = open_repo(&config.path, config.work_tree.as_deref()) else {
+        return;
+    };
+    let mut generation = config.generation;
+    let mut pending_file_list: Option<DiffCommand> = None;
+    let mut pending_file: Option<DiffCommand> = None;
+
+    loop {
+        // Sleep until there is work; otherwise only pick up whatever has queued meanwhile.
+        if pending_file_list.is_none() && pending_file.is_none() {
+            match rx.recv() {
+                Ok(command) => {
+                    if !handle_command(command, &mut pending_file_list, &mut pending_file) {
+                        break;
+                    }
+                },
+                Err(_) => break,
+            }
+        }
+        if !drain_commands(&rx, &mut pending_file_list, &mut pending_file) {
+            break;
+        }
+
+        let mut is_shutdown = false;
+        if let Some(DiffCommand::QueryFileList { generation: request_generation, request_id, oid }) = pending_file_list.take() {
+            reopen_for_generation(&config, &mut repo, &mut generation, request_generation);
+            let changes = get_filenames_diff_at_oid_cancellable(&repo, oid, &mut || {
+                is_shutdown |= !drain_commands(&rx, &mut pending_file_list, &mut pending_file);
+                is_shutdown || pending_file_list.is_some()
+            });
+            if is_shutdown {
+                break;
+            }
+            // A newer list took this one's place and runs on the next pass.
+            let Some(changes) = changes else { continue };
+            if tx.send(DiffEvent::FileList { generation, request_id, oid, changes }).is_err() {
+                break;
+            }
+            // A file list can take a while; requests made meanwhile replace the queued file.
+            continue;
+        }
+
+        if let Some(DiffCommand::QueryFile { generation: request_generation, request_id, oid, filename, is_conflict, size_limit }) = pending_file.take() {
+            reopen_for_generation(&config, &mut repo, &mut generation, request_generation);
+            let diff = load_file_diff(&repo, oid, &filename, is_conflict, size_limit, &mut || {
+                is_shutdown |= !drain_commands(&rx, &mut pending_file_list, &mut pending_file);
+                is_shutdown || pending_file.is_some()
+            });
+            if is_shutdown {
+                break;
+            }
+            if pending_file.is_some() {
+                continue;
+            }
+            if tx.send(DiffEvent::File { generation, request_id, oid, filename, diff }).is_err() {
+                break;
+            }
+        }
+    }
+}
+
+// A new generation means the repository was reloaded, so the index and refs are read afresh.
+fn reopen_for_generation(config: &DiffServiceConfig, repo: &mut Repository, generation: &mut Generation, request_generation: Generation) {
+    if request_generation == *generation {
+        return;
+    }
+    if let Ok(reopened) = open_repo(&config.path, config.work_tree.as_deref()) {
+        *repo = reopened;
+    }
+    *generation = request_generation;
+}
+
+fn drain_commands(rx: &Receiver<DiffCommand>, pending_file_list: &mut Option<DiffCommand>, pending_file: &mut Option<DiffCommand>) -> bool {
+    while let Ok(command) = rx.try_recv() {
+        if !handle_command(command, pending_file_list, pending_file) {
+            return false;
+        }
+    }
+    true
+}
+
+// Only the newest request of each kind is kept; the selection has already moved past the older ones.
+fn handle_command(command: DiffCommand, pending_file_list: &mut Option<DiffCommand>, pending_file: &mut Option<DiffCommand>) -> bool {
+    match command {
+        DiffCommand::QueryFileList { .. } => *pending_file_list = Some(command),
+        DiffCommand::QueryFile { .. } => *pending_file = Some(command),
+        DiffCommand::Shutdown => return false,
+    }
+    true
+}
+
+#[cfg(test)]
+#[path = "../tests/core/diff_service.rs"]
+mod tests;
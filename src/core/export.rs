@@ -0,0 +1,591 @@
+use crate::{
+    core::{
+        graph_service::{GraphHistory, GraphRow, graph_rows_with, head_alias},
+        renderers::{GRAPH_COMMITTER_WIDTH, render_graph_projection, render_message_projection, render_sha_projection},
+        walker::Walker,
+        worktrees::Worktrees,
+    },
+    git::queries::helpers::UncommittedChanges,
+    helpers::{
+        layout::GRAPH_LANE_LIMIT_DEFAULT,
+        palette::Theme,
+        symbols::SymbolTheme,
+        text::truncate_with_ellipsis,
+        time::{timestamp_to_iso8601, timestamp_to_utc_date_time},
+    },
+};
+use facet::Facet;
+use git2::{Commit, Oid, Repository};
+use im::HashSet;
+use ratatui::{
+    style::{Color, Style},
+    text::{Line, Span},
+};
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    io::{self, Write},
+    path::Path,
+};
+
+// Same page size as the app, so exports walk history the way the graph does.
+const EXPORT_WALK_AMOUNT: usize = 1000;
+// Rows rendered and written at a time by `guitar log`.
+const EXPORT_CHUNK_ROWS: usize = 1000;
+const ANSI_RESET: &str = "\x1b[0m";
+// Image metrics assume a monospace font where a cell is 0.6em wide.
+const IMAGE_FONT_SIZE: f32 = 14.0;
+const IMAGE_CELL_WIDTH: f32 = IMAGE_FONT_SIZE * 0.6;
+const IMAGE_LINE_HEIGHT: f32 = IMAGE_FONT_SIZE * 1.4;
+const IMAGE_PADDING: f32 = 16.0;
+const IMAGE_FONT_FAMILY: &str = "'JetBrains Mono', 'DejaVu Sans Mono', Menlo, Consolas, monospace";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExportFormat {
+    #[default]
+    Text,
+    Ansi,
+    Json,
+    Svg,
+    Html,
+}
+
+impl ExportFormat {
+    pub fn from_label(label: &str) -> Option<Self> {
+        match label {
+            "text" => Some(ExportFormat::Text),
+            "ansi" => Some(ExportFormat::Ansi),
+            "json" => Some(ExportFormat::Json),
+            "svg" => Some(ExportFormat::Svg),
+            "html" => Some(ExportFormat::Html),
+            _ => None,
+        }
+    }
+
+    // Image exports pick their format from the file name, defaulting to SVG.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|extension| extension.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("html" | "htm") => ExportFormat::Html,
+            _ => ExportFormat::Svg,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExportOptions {
+    pub format: ExportFormat,
+    // `None` exports the whole history.
+    pub limit: Option<usize>,
+    pub symbols: Option<String>,
+}
+
+// The document a JSON export writes, one row at a time.
+#[derive(Facet)]
+struct ExportGraph {
+    head: Option<String>,
+    rows: Vec<ExportRow>,
+}
+
+#[derive(Facet)]
+struct ExportRow {
+    index: usize,
+    sha: String,
+    lane: Option<usize>,
+    parents: Vec<ExportEdge>,
+    author_name: String,
+    author_email: String,
+    committer_name: String,
+    date: String,
+    branches: Vec<String>,
+    remote_branches: Vec<String>,
+    tags: Vec<String>,
+    is_stash: bool,
+    message: String,
+    graph: String,
+}
+
+// Parents outside the exported rows keep their sha but have no index or lane.
+#[derive(Facet)]
+struct ExportEdge {
+    sha: String,
+    index: Option<usize>,
+    lane: Option<usize>,
+}
+
+// What exported rows need from a commit beyond its graph row, read in the same lookup.
+// Rows show who wrote a commit and when; `short_date` is the text column, `date` the JSON field.
+#[derive(Default)]
+struct CommitDetails {
+    author_name: String,
+    author_email: String,
+    short_date: String,
+    date: String,
+    parents: Vec<Oid>,
+}
+
+impl CommitDetails {
+    fn new(commit: &Commit<'_>) -> Self {
+        let author = commit.author();
+        CommitDetails {
+            author_name: author.name().unwrap_or_default().to_string(),
+            author_email: author.email().unwrap_or_default().to_string(),
+            short_date: timestamp_to_utc_date_time(author.when()),
+            date: timestamp_to_iso8601(author.when()),
+            parents: commit.parent_ids().collect(),
+        }
+    }
+}
+
+// Graph rows to export, without the uncommitted pseudo-row, plus the lane history they were projected from.
+// `end` is the end of the history window, which may reach past the last exported row.
+pub struct ExportWindow {
+    pub rows: Vec<GraphRow>,
+    pub history: GraphHistory,
+    pub head_alias: u32,
+    pub end: usize,
+}
+
+// One step of a streamed export: a window of rows and the details of each.
+struct ExportChunk {
+    window: ExportWindow,
+    details: Vec<CommitDetails>,
+}
+
+// Walks history just far enough for each chunk: one row past it, so its last row can close its lanes,
+// and, for JSON, on to the parents of its rows, so edges can point at the rows they join.
+struct ExportChunks {
+    start: usize,
+    // Row 0 is the uncommitted pseudo-row, so the limit ends one row later; `usize::MAX` without one.
+    end: usize,
+    rows: usize,
+    is_walking: bool,
+    is_tracking_parents: bool,
+    positions: HashMap<u32, usize>,
+}
+
+impl ExportChunks {
+    fn new(options: &ExportOptions, rows: usize) -> Self {
+        ExportChunks {
+            start: 1,
+            end: options.limit.map_or(usize::MAX, |limit| limit.saturating_add(1)),
+            rows,
+            is_walking: true,
+            is_tracking_parents: options.format == ExportFormat::Json,
+            positions: HashMap::new(),
+        }
+    }
+
+    fn walk(&mut self, walker: &mut Walker) {
+        self.is_walking = walker.walk();
+        if self.is_tracking_parents {
+            // Rows are only ever appended, so positions already seen stay put.
+            let placed = self.positions.len();
+            self.positions.extend(walker.oids.get_sorted_aliases().iter().enumerate().skip(placed + 1).map(|(index, &alias)| (alias, index)));
+        }
+    }
+
+    fn position(&self, walker: &Walker, oid: &Oid) -> Option<usize> {
+        walker.oids.aliases.get(oid).and_then(|alias| self.positions.get(alias)).copied()
+    }
+
+    // A parent's lane is read from the snapshot after its row, so that has to be walked too.
+    fn is_placed(&self, walker: &Walker, oid: &Oid) -> bool {
+        self.position(walker, oid).is_some_and(|position| walker.buffer.borrow().deltas.len() > position + 1)
+    }
+
+    fn next(&mut self, walker: &mut Walker, symbols: &SymbolTheme) -> Option<ExportChunk> {
+        let end = self.start.saturating_add(self.rows).min(self.end);
+        while self.is_walking && walker.oids.get_commit_count() <= end {
+            self.walk(walker);
+        }
+        let end = end.min(walker.oids.get_commit_count());
+        if self.start >= end {
+            return None;
+        }
+
+        let mut details: HashMap<usize, CommitDetails> = HashMap::new();
+        let rows = graph_rows_with(walker, &Worktrees::default(), &HashSet::new(), symbols, self.start, end, |index, commit| {
+            details.insert(index, CommitDetails::new(commit));
+        });
+        while self.is_walking && self.is_tracking_parents && details.values().flat_map(|details| &details.parents).any(|parent| !self.is_placed(walker, parent)) {
+            self.walk(walker);
+        }
+
+        let details = rows.iter().map(|row| details.remove(&row.index).unwrap_or_default()).collect();
+        let history = walker.buffer.borrow().window(self.start, end.saturating_add(1));
+        self.start = end;
+        Some(ExportChunk { window: ExportWindow { rows, history, head_alias: head_alias(walker), end }, details })
+    }
+}
+
+// Walks history for the headless `guitar log` and writes it a chunk at a time, so no more than one chunk of rows is held.
+pub fn export_graph(path: String, options: &ExportOptions, theme: &Theme, out: &mut impl Write) -> io::Result<()> {
+    let symbols = match &options.symbols {
+        Some(label) => SymbolTheme::from_label(label).ok_or_else(|| io::Error::other(format!("unknown symbol preset: {label}")))?,
+        None => SymbolTheme::main(),
+    };
+    let mut walker = Walker::new(path, EXPORT_WALK_AMOUNT, HashSet::new(), false, true, GRAPH_LANE_LIMIT_DEFAULT).map_err(|error| io::Error::other(error.message().to_string()))?;
+    write_export(&mut walker, options, theme, &symbols, out, EXPORT_CHUNK_ROWS)
+}
+
+fn write_export(walker: &mut Walker, options: &ExportOptions, theme: &Theme, symbols: &SymbolTheme, out: &mut impl Write, chunk_rows: usize) -> io::Result<()> {
+    let head = walker.repo.borrow().head().ok().and_then(|head| head.target());
+    // An SVG states its size up front, so its rows are rendered once to measure and again to write.
+    let size = if options.format == ExportFormat::Svg {
+        let mut chunks = ExportChunks::new(options, chunk_rows);
+        let (mut rows, mut columns) = (0, 0);
+        while let Some(chunk) = chunks.next(walker, symbols) {
+            let (lines, _) = render_chunk(theme, symbols, &chunk.window, &chunk.details);
+            rows += lines.len();
+            columns = columns.max(max_columns(&lines));
+        }
+        (rows, columns)
+    } else {
+        (0, 0)
+    };
+
+    let mut writer = ExportWriter { theme, format: options.format, out, rows: 0 };
+    writer.begin(head, size)?;
+    let mut chunks = ExportChunks::new(options, chunk_rows);
+    while let Some(chunk) = chunks.next(walker, symbols) {
+        let (lines, graph) = render_chunk(theme, symbols, &chunk.window, &chunk.details);
+        let rows = if options.format == ExportFormat::Json {
+            json_rows(&chunk.window, chunk.details, &graph, writer.rows, |oid| {
+                let position = chunks.position(walker, oid).filter(|&position| position < chunks.end)?;
+                Some((position - 1, lane_at(walker, position)))
+            })
+        } else {
+            Vec::new()
+        };
+        writer.write_rows(&lines, rows)?;
+    }
+    writer.finish()
+}
+
+// Exports rows the app already has on screen.
+pub fn export_window(theme: &Theme, symbols: &SymbolTheme, repo: &Repository, window: &ExportWindow, format: ExportFormat) -> Result<String, git2::Error> {
+    let details: Vec<CommitDetails> = window.rows.iter().map(|row| repo.find_commit(row.oid).map(|commit| CommitDetails::new(&commit)).unwrap_or_default()).collect();
+    let (lines, graph) = render_chunk(theme, symbols, window, &details);
+    let rows = if format == ExportFormat::Json {
+        let positions: HashMap<Oid, usize> = window.rows.iter().enumerate().map(|(position, row)| (row.oid, position)).collect();
+        json_rows(window, details, &graph, 0, |oid| positions.get(oid).map(|&position| (position, row_lane(window, &window.rows[position]))))
+    } else {
+        Vec::new()
+    };
+
+    let head = repo.head().ok().and_then(|head| head.target());
+    let mut writer = ExportWriter { theme, format, out: Vec::new(), rows: 0 };
+    let written = writer.begin(head, (lines.len(), max_columns(&lines))).and_then(|()| writer.write_rows(&lines, rows)).and_then(|()| writer.finish());
+    written.map_err(|error| git2::Error::from_str(&error.to_string()))?;
+    String::from_utf8(writer.out).map_err(|error| git2::Error::from_str(&error.to_string()))
+}
+
+// Writes one export document, opened once, then a chunk of rows at a time.
+struct ExportWriter<'a, W: Write> {
+    theme: &'a Theme,
+    format: ExportFormat,
+    out: W,
+    rows: usize,
+}
+
+impl<W: Write> ExportWriter<'_, W> {
+    // `size` is the rows and columns an SVG covers; the other formats ignore it.
+    fn begin(&mut self, head: Option<Oid>, size: (usize, usize)) -> io::Result<()> {
+        match self.format {
+            ExportFormat::Text | ExportFormat::Ansi => Ok(()),
+            ExportFormat::Json => {
+                let head = facet_json::to_string(&head.map(|oid| oid.to_string())).map_err(|error| io::Error::other(error.to_string()))?;
+                write!(self.out, "{{\n  \"head\": {head},\n  \"rows\": [")
+            },
+            ExportFormat::Svg => self.out.write_all(svg_header(self.theme, size.0, size.1).as_bytes()),
+            ExportFormat::Html => self.out.write_all(html_header(self.theme).as_bytes()),
+        }
+    }
+
+    fn write_rows(&mut self, lines: &[Line<'static>], rows: Vec<ExportRow>) -> io::Result<()> {
+        match self.format {
+            ExportFormat::Text => lines.iter().try_for_each(|line| writeln!(self.out, "{}", plain_text(line)))?,
+            ExportFormat::Ansi => lines.iter().try_for_each(|line| writeln!(self.out, "{}", ansi_text(line)))?,
+            ExportFormat::Json => {
+                for (index, row) in rows.iter().enumerate() {
+                    let json = facet_json::to_string_pretty(row).map_err(|error| io::Error::other(error.to_string()))?;
+                    let separator = if self.rows + index == 0 { "" } else { "," };
+                    write!(self.out, "{separator}\n    {}", json.replace('\n', "\n    "))?;
+                }
+            },
+            ExportFormat::Svg => lines.iter().enumerate().try_for_each(|(index, line)| self.out.write_all(svg_line(self.rows + index, line).as_bytes()))?,
+            ExportFormat::Html => lines.iter().enumerate().try_for_each(|(index, line)| self.out.write_all(html_line(self.rows + index, line).as_bytes()))?,
+        }
+        self.rows += lines.len();
+        Ok(())
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        match self.format {
+            ExportFormat::Text | ExportFormat::Ansi => Ok(()),
+            ExportFormat::Json => self.out.write_all(if self.rows == 0 { "]\n}\n" } else { "\n  ]\n}\n" }.as_bytes()),
+            ExportFormat::Svg => self.out.write_all(b"</g>\n</svg>\n"),
+            ExportFormat::Html => self.out.write_all(b"</pre>\n</body>\n</html>\n"),
+        }
+    }
+}
+
+// The rendered rows and, apart, their graph cells. Messages line up within a chunk, so a chunk with wider lanes
+// starts its messages further right.
+fn render_chunk(theme: &Theme, symbols: &SymbolTheme, window: &ExportWindow, details: &[CommitDetails]) -> (Vec<Line<'static>>, Vec<Line<'static>>) {
+    let rows = &window.rows;
+    let graph = render_graph_projection(theme, symbols, rows, &window.history, window.head_alias, 0, window.end, false);
+    let graph_width = graph.iter().map(Line::width).max().unwrap_or(0);
+    let shas = render_sha_projection(theme, rows, usize::MAX);
+    let messages = render_message_projection(theme, symbols, rows, false, true, usize::MAX, &UncommittedChanges::default(), false);
+
+    let text = Style::default().fg(theme.COLOR_TEXT);
+    let lines = details
+        .iter()
+        .zip(shas)
+        .zip(graph.iter().cloned())
+        .zip(messages)
+        .map(|(((details, sha), graph), message)| {
+            let padding = graph_width.saturating_sub(graph.width());
+            let mut spans = sha.spans;
+            spans.push(Span::styled(details.short_date.clone(), text));
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(format!("{:<width$} ", truncate_with_ellipsis(&details.author_name, GRAPH_COMMITTER_WIDTH), width = GRAPH_COMMITTER_WIDTH), text));
+            spans.extend(graph.spans);
+            spans.push(Span::raw(format!("{} ", " ".repeat(padding))));
+            spans.extend(message.spans);
+            Line::from(spans)
+        })
+        .collect();
+    (lines, graph)
+}
+
+// `parent` finds where a parent row sits in the export and its lane, or `None` when it is not exported.
+fn json_rows(window: &ExportWindow, details: Vec<CommitDetails>, graph: &[Line<'static>], first: usize, parent: impl Fn(&Oid) -> Option<(usize, Option<usize>)>) -> Vec<ExportRow> {
+    window
+        .rows
+        .iter()
+        .zip(details)
+        .zip(graph)
+        .enumerate()
+        .map(|(position, ((row, details), line))| {
+            let parents = details
+                .parents
+                .iter()
+                .map(|oid| {
+                    let found = parent(oid);
+                    ExportEdge { sha: oid.to_string(), index: found.map(|(index, _)| index), lane: found.and_then(|(_, lane)| lane) }
+                })
+                .collect();
+            let (branches, remote_branches) = row.branches.iter().partition::<Vec<_>, _>(|branch| branch.is_local);
+            ExportRow {
+                index: first + position,
+                sha: row.oid.to_string(),
+                lane: row_lane(window, row),
+                parents,
+                author_name: details.author_name,
+                author_email: details.author_email,
+                committer_name: row.committer_name.clone(),
+                date: details.date,
+                branches: branches.into_iter().map(|branch| branch.name.clone()).collect(),
+                remote_branches: remote_branches.into_iter().map(|branch| branch.name.clone()).collect(),
+                tags: row.tags.iter().map(|tag| tag.name.clone()).collect(),
+                is_stash: row.is_stash,
+                message: row.summary.clone(),
+                graph: plain_text(line),
+            }
+        })
+        .collect()
+}
+
+// The lane a commit sits in, counted in the walker's snapshot before empty columns are collapsed.
+fn row_lane(window: &ExportWindow, row: &GraphRow) -> Option<usize> {
+    let delta = (window.history.len() + row.index).checked_sub(window.end)?;
+    let snapshot = window.history.get(delta)?;
+    snapshot.lanes.iter().position(|chunk| !chunk.is_dummy() && chunk.alias == row.alias)
+}
+
+// The same for a row in another chunk, replayed from the nearest checkpoint.
+fn lane_at(walker: &Walker, index: usize) -> Option<usize> {
+    let alias = *walker.oids.get_sorted_aliases().get(index)?;
+    let history = walker.buffer.borrow().window(index, index.saturating_add(2));
+    history.last()?.lanes.iter().position(|chunk| !chunk.is_dummy() && chunk.alias == alias)
+}
+
+fn plain_text(line: &Line<'_>) -> String {
+    line.spans.iter().map(|span| span.content.as_ref()).collect::<String>().trim_end().to_string()
+}
+
+fn max_columns(lines: &[Line<'static>]) -> usize {
+    lines.iter().map(|line| plain_text(line).chars().count()).max().unwrap_or(0)
+}
+
+fn ansi_text(line: &Line<'_>) -> String {
+    let mut text = String::new();
+    for span in &line.spans {
+        match span.style.fg.and_then(ansi_foreground) {
+            Some(code) => {
+                let _ = write!(text, "\x1b[{code}m{}{ANSI_RESET}", span.content);
+            },
+            None => text.push_str(&span.content),
+        }
+    }
+    text.trim_end().to_string()
+}
+
+fn svg_header(theme: &Theme, rows: usize, columns: usize) -> String {
+    let width = (columns as f32 * IMAGE_CELL_WIDTH + IMAGE_PADDING * 2.0).ceil();
+    let height = (rows as f32 * IMAGE_LINE_HEIGHT + IMAGE_PADDING * 2.0).ceil();
+    let foreground = css_color(theme.COLOR_TEXT).unwrap_or_default();
+    let mut svg = format!("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n");
+    let _ = writeln!(svg, "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>", css_color(theme.background_color()).unwrap_or_else(|| "#000000".to_string()));
+    let _ = writeln!(svg, "<g font-family=\"{IMAGE_FONT_FAMILY}\" font-size=\"{IMAGE_FONT_SIZE}\" fill=\"{foreground}\" xml:space=\"preserve\">");
+    svg
+}
+
+// One `<text>` per row keeps the SVG editable, with a `<tspan>` per coloured span.
+fn svg_line(index: usize, line: &Line<'static>) -> String {
+    let y = IMAGE_PADDING + (index as f32 + 0.75) * IMAGE_LINE_HEIGHT;
+    let mut svg = format!("<text x=\"{IMAGE_PADDING}\" y=\"{y}\">");
+    for span in line.spans.iter().filter(|span| !span.content.is_empty()) {
+        match span.style.fg.and_then(css_color) {
+            Some(color) => {
+                let _ = write!(svg, "<tspan fill=\"{color}\">{}</tspan>", escape_markup(&span.content));
+            },
+            None => svg.push_str(&escape_markup(&span.content)),
+        }
+    }
+    svg.push_str("</text>\n");
+    svg
+}
+
+fn html_header(theme: &Theme) -> String {
+    let background = css_color(theme.background_color()).unwrap_or_else(|| "#000000".to_string());
+    let foreground = css_color(theme.COLOR_TEXT).unwrap_or_default();
+    let mut html = String::from("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>guitar graph</title>\n");
+    let _ = writeln!(
+        html,
+        "<style>body {{ margin: 0; background: {background}; }} pre {{ margin: 0; padding: {IMAGE_PADDING}px; color: {foreground}; font: {IMAGE_FONT_SIZE}px/1.4 {IMAGE_FONT_FAMILY}; }}</style>"
+    );
+    html.push_str("</head>\n<body>\n<pre>");
+    html
+}
+
+fn html_line(index: usize, line: &Line<'static>) -> String {
+    let mut html = String::new();
+    if index > 0 {
+        html.push('\n');
+    }
+    for span in line.spans.iter().filter(|span| !span.content.is_empty()) {
+        match span.style.fg.and_then(css_color) {
+            Some(color) => {
+                let _ = write!(html, "<span style=\"color: {color}\">{}</span>", escape_markup(&span.content));
+            },
+            None => html.push_str(&escape_markup(&span.content)),
+        }
+    }
+    html
+}
+
+fn escape_markup(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for character in text.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+// Named colours use the xterm defaults, since a file has no terminal palette to inherit.
+fn css_color(color: Color) -> Option<String> {
+    let (r, g, b) = match color {
+        Color::Reset => return None,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) => indexed_rgb(index),
+        Color::Black => indexed_rgb(0),
+        Color::Red => indexed_rgb(1),
+        Color::Green => indexed_rgb(2),
+        Color::Yellow => indexed_rgb(3),
+        Color::Blue => indexed_rgb(4),
+        Color::Magenta => indexed_rgb(5),
+        Color::Cyan => indexed_rgb(6),
+        Color::Gray => indexed_rgb(7),
+        Color::DarkGray => indexed_rgb(8),
+        Color::LightRed => indexed_rgb(9),
+        Color::LightGreen => indexed_rgb(10),
+        Color::LightYellow => indexed_rgb(11),
+        Color::LightBlue => indexed_rgb(12),
+        Color::LightMagenta => indexed_rgb(13),
+        Color::LightCyan => indexed_rgb(14),
+        Color::White => indexed_rgb(15),
+    };
+    Some(format!("#{r:02x}{g:02x}{b:02x}"))
+}
+
+fn indexed_rgb(index: u8) -> (u8, u8, u8) {
+    const BASIC: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    match index {
+        0..=15 => BASIC[index as usize],
+        16..=231 => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let cube = index - 16;
+            (level(cube / 36), level(cube / 6 % 6), level(cube % 6))
+        },
+        _ => {
+            let grey = 8 + (index - 232) * 10;
+            (grey, grey, grey)
+        },
+    }
+}
+
+fn ansi_foreground(color: Color) -> Option<String> {
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => "30".to_string(),
+        Color::Red => "31".to_string(),
+        Color::Green => "32".to_string(),
+        Color::Yellow => "33".to_string(),
+        Color::Blue => "34".to_string(),
+        Color::Magenta => "35".to_string(),
+        Color::Cyan => "36".to_string(),
+        Color::Gray => "37".to_string(),
+        Color::DarkGray => "90".to_string(),
+        Color::LightRed => "91".to_string(),
+        Color::LightGreen => "92".to_string(),
+        Color::LightYellow => "93".to_string(),
+        Color::LightBlue => "94".to_string(),
+        Color::LightMagenta => "95".to_string(),
+        Color::LightCyan => "96".to_string(),
+        Color::White => "97".to_string(),
+        Color::Indexed(index) => format!("38;5;{index}"),
+        Color::Rgb(r, g, b) => format!("38;2;{r};{g};{b}"),
+    };
+    Some(code)
+}
+
+#[cfg(test)]
+#[path = "../tests/core/export.rs"]
+mod tests;
@@ -2,10 +2,16 @@ use crate::{
     core::{
         chunk::{LaneRef, NONE},
         reflogs::HeadReflogAliasEntry,
+        walk_cache::{load_walk_cache, save_walk_cache},
         walker::Walker,
         worktrees::{WorktreeEntry, Worktrees},
     },
-    git::queries::{file_history::changed_file_status_at_commit, helpers::FileStatus, reflogs::HeadReflogEntry},
+    git::queries::{
+        file_history::changed_file_status_at_commit,
+        helpers::FileStatus,
+        reflogs::HeadReflogEntry,
+        signatures::{SignatureStatus, verify_commit},
+    },
     helpers::{
         heatmap::{DAYS, WEEKS, build_heatmap},
         localisation::{common, empty, errors, status as status_text},
@@ -13,14 +19,15 @@ use crate::{
         time::timestamp_to_utc_date_time,
     },
 };
-use git2::Oid;
+use git2::{Oid, Repository};
 use im::HashSet;
 use std::{
     collections::HashMap,
+    path::PathBuf,
     sync::{
         Arc,
         atomic::{AtomicBool, Ordering},
-        mpsc::{Receiver, Sender},
+        mpsc::{self, Receiver, Sender},
     },
     thread,
     time::Duration,
@@ -54,14 +61,54 @@ pub enum GraphLookupKind {
     Oid { oid: Oid },
     ParentIndex { index: usize },
     ChildIndex { index: usize },
+    GraphOidRange { start: usize, end: usize },
+    PaneRowRange { pane: GraphPane, start: usize, end: usize },
+    // Branches and tags by name, stashes by sha.
+    PaneRowByName { pane: GraphPane, name: String },
 }
 
 #[derive(Clone, Debug)]
 pub enum GraphCommand {
-    QueryGraphWindow { generation: Generation, request_id: RequestId, start: usize, end: usize },
-    QueryPaneWindow { generation: Generation, pane: GraphPane, start: usize, end: usize },
-    QueryFileHistory { generation: Generation, request_id: RequestId, path: String },
-    Lookup { generation: Generation, request_id: RequestId, kind: GraphLookupKind },
+    QueryGraphWindow {
+        generation: Generation,
+        request_id: RequestId,
+        start: usize,
+        end: usize,
+    },
+    QueryPaneWindow {
+        generation: Generation,
+        pane: GraphPane,
+        start: usize,
+        end: usize,
+    },
+    QueryFileHistory {
+        generation: Generation,
+        request_id: RequestId,
+        path: String,
+    },
+    Lookup {
+        generation: Generation,
+        request_id: RequestId,
+        kind: GraphLookupKind,
+    },
+    VerifySignatures {
+        generation: Generation,
+        oids: Vec<Oid>,
+    },
+    // Re-read refs after an action; the finished walk is extended instead of walked again.
+    Refresh {
+        generation: Generation,
+        hidden_branch_names: HashSet<String>,
+        include_head_reflog_roots: bool,
+        use_replace_refs: bool,
+        graph_lane_limit: usize,
+        worktrees: Vec<WorktreeEntry>,
+        symbols: Box<SymbolTheme>,
+    },
+    // A parked tab stops walking so its events do not pile up unread.
+    Pause {
+        is_paused: bool,
+    },
     Shutdown,
 }
 
@@ -98,6 +145,8 @@ pub struct GraphRow {
     pub branches: Vec<GraphBranchLabel>,
     pub tags: Vec<GraphTagLabel>,
     pub is_stash: bool,
+    // Parents were cut off by a shallow clone.
+    pub is_shallow: bool,
     pub stash_lane: Option<LaneRef>,
     pub worktrees: Vec<WorktreeEntry>,
     pub reflog: Option<GraphReflogLabel>,
@@ -132,6 +181,8 @@ pub enum GraphLookupResult {
     GraphRow(Option<GraphRow>),
     Index(Option<usize>),
     PaneRow(Option<GraphPaneRow>),
+    Oids(Vec<Oid>),
+    PaneRows(Vec<GraphPaneRow>),
 }
 
 #[derive(Clone, Debug)]
@@ -142,6 +193,7 @@ pub enum GraphEvent {
     FileHistory { generation: Generation, request_id: RequestId, path: String, rows: Vec<GraphFileHistoryRow>, error: Option<String> },
     LookupResult { generation: Generation, request_id: RequestId, result: GraphLookupResult },
     Heatmap { generation: Generation, heatmap: [[usize; WEEKS]; DAYS] },
+    Signatures { generation: Generation, statuses: Vec<(Oid, SignatureStatus)> },
     Error { generation: Generation, message: String },
 }
 
@@ -151,54 +203,129 @@ pub struct GraphServiceConfig {
     pub amount: usize,
     pub hidden_branch_names: HashSet<String>,
     pub include_head_reflog_roots: bool,
+    // Off when the graph shows original parents instead of the ones from `refs/replace/*`.
+    pub use_replace_refs: bool,
     pub graph_lane_limit: usize,
     pub worktrees: Vec<WorktreeEntry>,
     pub symbols: SymbolTheme,
+    // Where finished walks are stored and resumed from; None always walks from scratch.
+    pub walk_cache_path: Option<PathBuf>,
 }
 
 pub fn spawn_graph_service(config: GraphServiceConfig, rx: Receiver<GraphCommand>, tx: Sender<GraphEvent>, cancel: Arc<AtomicBool>) -> thread::JoinHandle<()> {
     thread::spawn(move || run_graph_service(config, rx, tx, cancel))
 }
 
-fn run_graph_service(config: GraphServiceConfig, rx: Receiver<GraphCommand>, tx: Sender<GraphEvent>, cancel: Arc<AtomicBool>) {
-    let generation = config.generation;
-    let mut walk_ctx = match Walker::new(config.path, config.amount, config.hidden_branch_names.clone(), config.include_head_reflog_roots, config.graph_lane_limit) {
-        Ok(walker) => walker,
+// What the service carries between commands, handed to the helpers below as one value.
+struct ServiceState {
+    config: GraphServiceConfig,
+    generation: Generation,
+    version: GraphVersion,
+    tx: Sender<GraphEvent>,
+    signatures: Sender<(Generation, Vec<Oid>)>,
+    worktrees: Worktrees,
+    pending_graph: Option<(RequestId, usize, usize)>,
+    pending_file_history: Option<(RequestId, String)>,
+    pending_refresh: Option<GraphCommand>,
+    is_paused: bool,
+}
+
+fn run_graph_service(mut config: GraphServiceConfig, rx: Receiver<GraphCommand>, tx: Sender<GraphEvent>, cancel: Arc<AtomicBool>) {
+    let (mut walk_ctx, mut is_resumed) = match open_walker(&config) {
+        Ok(opened) => opened,
         Err(error) => {
-            let _ = tx.send(GraphEvent::Error { generation, message: errors::walker_failed(error) });
+            let _ = tx.send(GraphEvent::Error { generation: config.generation, message: errors::walker_failed(error) });
             return;
         },
     };
 
-    let mut worktrees = Worktrees::from_entries(config.worktrees);
-    let mut version: GraphVersion = 0;
+    let mut state = ServiceState {
+        generation: config.generation,
+        version: 0,
+        signatures: spawn_signature_worker(config.path.clone(), tx.clone()),
+        tx,
+        worktrees: Worktrees::from_entries(std::mem::take(&mut config.worktrees)),
+        pending_graph: None,
+        pending_file_history: None,
+        pending_refresh: None,
+        is_paused: false,
+        config,
+    };
     let mut is_first = true;
     let mut is_complete = false;
-    let mut pending_graph: Option<(RequestId, usize, usize)> = None;
-    let mut pending_file_history: Option<(RequestId, String)> = None;
+    // Walks extended by refreshes are saved once, as the service stops, instead of after every action.
+    let mut is_refreshed = false;
+    let mut is_cache_dirty = false;
 
     loop {
         if cancel.load(Ordering::SeqCst) {
             break;
         }
 
-        if !drain_commands(generation, version, &rx, &tx, &walk_ctx, &mut worktrees, &mut pending_graph, &mut pending_file_history, &config.hidden_branch_names, &config.symbols) {
+        if let Some(GraphCommand::Refresh {
+            generation: refresh_generation,
+            hidden_branch_names,
+            include_head_reflog_roots,
+            use_replace_refs,
+            graph_lane_limit,
+            worktrees: refresh_worktrees,
+            symbols,
+        }) = state.pending_refresh.take()
+        {
+            let config = &mut state.config;
+            state.generation = refresh_generation;
+            config.hidden_branch_names = hidden_branch_names;
+            config.include_head_reflog_roots = include_head_reflog_roots;
+            config.use_replace_refs = use_replace_refs;
+            config.graph_lane_limit = graph_lane_limit;
+            config.symbols = *symbols;
+
+            // Only a finished walk is a complete description of history worth extending.
+            let is_extended = is_complete && walk_ctx.refresh(config.hidden_branch_names.clone(), config.include_head_reflog_roots, config.use_replace_refs, config.graph_lane_limit).unwrap_or(false);
+            if is_extended {
+                is_resumed = true;
+            } else {
+                (walk_ctx, is_resumed) = match open_walker(config) {
+                    Ok(opened) => opened,
+                    Err(error) => {
+                        let _ = state.tx.send(GraphEvent::Error { generation: state.generation, message: errors::walker_failed(error) });
+                        return;
+                    },
+                };
+            }
+            is_refreshed = true;
+            is_cache_dirty &= is_extended;
+            state.worktrees = Worktrees::from_entries(refresh_worktrees);
+            is_first = true;
+            is_complete = false;
+            state.pending_graph = None;
+            state.pending_file_history = None;
+        }
+
+        if !drain_commands(&mut state, &rx, &walk_ctx) {
             break;
         }
 
-        if let Some((request_id, start, end)) = pending_graph.take() {
-            send_graph_window(generation, request_id, version, start, end, &tx, &walk_ctx, &worktrees, &config.hidden_branch_names, &config.symbols);
+        // Commands queued behind a refresh belong to its generation, so apply it first.
+        if state.pending_refresh.is_some() {
+            continue;
         }
 
-        if is_complete && let Some((request_id, path)) = pending_file_history.take() {
-            send_file_history(generation, request_id, path, &tx, &walk_ctx, &config.symbols);
+        if let Some((request_id, start, end)) = state.pending_graph.take() {
+            send_graph_window(&state, request_id, start, end, &walk_ctx);
         }
 
-        if is_complete {
+        if is_complete && let Some((request_id, path)) = state.pending_file_history.take() {
+            send_file_history(&state, request_id, path, &walk_ctx);
+        }
+
+        if is_complete || state.is_paused {
             match rx.recv_timeout(Duration::from_millis(50)) {
                 Ok(GraphCommand::Shutdown) => break,
+                Ok(command @ GraphCommand::Refresh { .. }) => state.pending_refresh = Some(command),
+                Ok(GraphCommand::Pause { is_paused }) => state.is_paused = is_paused,
                 Ok(command) => {
-                    if !handle_command(generation, version, command, &tx, &walk_ctx, &mut worktrees, &mut pending_graph, &mut pending_file_history, &config.hidden_branch_names, &config.symbols) {
+                    if !handle_command(&mut state, command, &walk_ctx) {
                         break;
                     }
                 },
@@ -208,84 +335,138 @@ fn run_graph_service(config: GraphServiceConfig, rx: Receiver<GraphCommand>, tx:
             continue;
         }
 
-        let is_again = walk_ctx.walk();
-        version = version.saturating_add(1);
+        // A resumed walk is already complete and only needs announcing once.
+        let is_again = !is_resumed && walk_ctx.walk();
+        state.version = state.version.saturating_add(1);
         is_complete = !is_again;
         let total = walk_ctx.oids.get_commit_count();
 
-        let _ = tx.send(GraphEvent::Progress { generation, version, total, is_first, is_complete });
+        let _ = state.tx.send(GraphEvent::Progress { generation: state.generation, version: state.version, total, is_first, is_complete });
         is_first = false;
 
         if is_complete {
+            // Saved before the heatmap goes out, so the stored walk is on disk once it arrives.
+            if !walk_ctx.is_cache_current && walk_ctx.is_cacheable() {
+                if is_refreshed {
+                    is_cache_dirty = true;
+                } else if let Some(path) = &state.config.walk_cache_path {
+                    save_walk_cache(path, &walk_ctx.walk_cache());
+                }
+            }
+
             let repo = walk_ctx.repo.borrow();
             let heatmap = build_heatmap(&repo, &walk_ctx.oids.oids);
-            let _ = tx.send(GraphEvent::Heatmap { generation, heatmap });
+            let _ = state.tx.send(GraphEvent::Heatmap { generation: state.generation, heatmap });
+            drop(repo);
 
-            if let Some((request_id, path)) = pending_file_history.take() {
-                send_file_history(generation, request_id, path, &tx, &walk_ctx, &config.symbols);
+            if let Some((request_id, path)) = state.pending_file_history.take() {
+                send_file_history(&state, request_id, path, &walk_ctx);
             }
         }
     }
+
+    if is_cache_dirty && let Some(path) = &state.config.walk_cache_path {
+        save_walk_cache(path, &walk_ctx.walk_cache());
+    }
+}
+
+// Resume from the walk cache when it still matches, otherwise start a fresh walk.
+fn open_walker(config: &GraphServiceConfig) -> Result<(Walker, bool), git2::Error> {
+    if let Some(cache) = config.walk_cache_path.as_deref().and_then(load_walk_cache)
+        && let Ok(Some(walker)) =
+            Walker::resume(config.path.clone(), config.amount, config.hidden_branch_names.clone(), config.include_head_reflog_roots, config.use_replace_refs, config.graph_lane_limit, cache)
+    {
+        return Ok((walker, true));
+    }
+
+    let walker = Walker::new(config.path.clone(), config.amount, config.hidden_branch_names.clone(), config.include_head_reflog_roots, config.use_replace_refs, config.graph_lane_limit)?;
+    Ok((walker, false))
 }
 
-fn drain_commands(
-    generation: Generation, version: GraphVersion, rx: &Receiver<GraphCommand>, tx: &Sender<GraphEvent>, walk_ctx: &Walker, worktrees: &mut Worktrees,
-    pending_graph: &mut Option<(RequestId, usize, usize)>, pending_file_history: &mut Option<(RequestId, String)>, hidden_branch_names: &HashSet<String>, symbols: &SymbolTheme,
-) -> bool {
+fn drain_commands(state: &mut ServiceState, rx: &Receiver<GraphCommand>, walk_ctx: &Walker) -> bool {
     while let Ok(command) = rx.try_recv() {
-        if !handle_command(generation, version, command, tx, walk_ctx, worktrees, pending_graph, pending_file_history, hidden_branch_names, symbols) {
+        if let GraphCommand::Refresh { .. } = command {
+            state.pending_refresh = Some(command);
+            return true;
+        }
+        if let GraphCommand::Pause { is_paused } = command {
+            state.is_paused = is_paused;
+            continue;
+        }
+        if !handle_command(state, command, walk_ctx) {
             return false;
         }
     }
     true
 }
 
-fn handle_command(
-    generation: Generation, version: GraphVersion, command: GraphCommand, tx: &Sender<GraphEvent>, walk_ctx: &Walker, worktrees: &mut Worktrees, pending_graph: &mut Option<(RequestId, usize, usize)>,
-    pending_file_history: &mut Option<(RequestId, String)>, hidden_branch_names: &HashSet<String>, symbols: &SymbolTheme,
-) -> bool {
+fn handle_command(state: &mut ServiceState, command: GraphCommand, walk_ctx: &Walker) -> bool {
+    let generation = state.generation;
     match command {
         GraphCommand::Shutdown => false,
+        // Refreshes replace the walker and pauses stop it, so the service loop applies them itself.
+        GraphCommand::Refresh { .. } | GraphCommand::Pause { .. } => true,
         GraphCommand::QueryGraphWindow { generation: cmd_generation, request_id, start, end } => {
             if cmd_generation == generation {
-                *pending_graph = Some((request_id, start, end));
+                state.pending_graph = Some((request_id, start, end));
             }
             true
         },
         GraphCommand::QueryPaneWindow { generation: cmd_generation, pane, start, end } => {
             if cmd_generation == generation {
-                send_pane_window(generation, version, pane, start, end, tx, walk_ctx);
+                send_pane_window(generation, state.version, pane, start, end, &state.tx, walk_ctx);
             }
             true
         },
         GraphCommand::QueryFileHistory { generation: cmd_generation, request_id, path } => {
             if cmd_generation == generation {
-                *pending_file_history = Some((request_id, path));
+                state.pending_file_history = Some((request_id, path));
             }
             true
         },
         GraphCommand::Lookup { generation: cmd_generation, request_id, kind } => {
             if cmd_generation == generation {
-                let result = lookup(kind, walk_ctx, worktrees, hidden_branch_names, symbols);
-                let _ = tx.send(GraphEvent::LookupResult { generation, request_id, result });
+                let result = lookup(kind, walk_ctx, &state.worktrees, &state.config.hidden_branch_names, &state.config.symbols);
+                let _ = state.tx.send(GraphEvent::LookupResult { generation, request_id, result });
+            }
+            true
+        },
+        GraphCommand::VerifySignatures { generation: cmd_generation, oids } => {
+            if cmd_generation == generation {
+                let _ = state.signatures.send((generation, oids));
             }
             true
         },
     }
 }
 
-fn send_graph_window(
-    generation: Generation, request_id: RequestId, version: GraphVersion, start: usize, end: usize, tx: &Sender<GraphEvent>, walk_ctx: &Walker, worktrees: &Worktrees,
-    hidden_branch_names: &HashSet<String>, symbols: &SymbolTheme,
-) {
+// Verifiers are external programs, so they run on their own thread and never stall graph queries.
+// The worker exits once the service drops its sender.
+fn spawn_signature_worker(path: String, tx: Sender<GraphEvent>) -> Sender<(Generation, Vec<Oid>)> {
+    let (command_tx, command_rx) = mpsc::channel::<(Generation, Vec<Oid>)>();
+    thread::spawn(move || {
+        let Ok(repo) = Repository::open(&path) else {
+            return;
+        };
+        for (generation, oids) in command_rx {
+            let statuses = oids.into_iter().map(|oid| (oid, verify_commit(&repo, oid))).collect();
+            if tx.send(GraphEvent::Signatures { generation, statuses }).is_err() {
+                break;
+            }
+        }
+    });
+    command_tx
+}
+
+fn send_graph_window(state: &ServiceState, request_id: RequestId, start: usize, end: usize, walk_ctx: &Walker) {
     let total = walk_ctx.oids.get_commit_count();
     let start = start.min(total);
     let end = end.min(total);
     let history = walk_ctx.buffer.borrow().window(start, end.saturating_add(1));
-    let rows = graph_rows(walk_ctx, worktrees, hidden_branch_names, symbols, start, end);
+    let rows = graph_rows(walk_ctx, &state.worktrees, &state.config.hidden_branch_names, &state.config.symbols, start, end);
     let head_alias = head_alias(walk_ctx);
 
-    let _ = tx.send(GraphEvent::GraphWindow { generation, request_id, version, start, end, total, head_alias, rows, history });
+    let _ = state.tx.send(GraphEvent::GraphWindow { generation: state.generation, request_id, version: state.version, start, end, total, head_alias, rows, history });
 }
 
 fn send_pane_window(generation: Generation, version: GraphVersion, pane: GraphPane, start: usize, end: usize, tx: &Sender<GraphEvent>, walk_ctx: &Walker) {
@@ -298,8 +479,9 @@ fn send_pane_window(generation: Generation, version: GraphVersion, pane: GraphPa
     let _ = tx.send(GraphEvent::PaneWindow { generation, version, pane, start, end, total, rows });
 }
 
-fn send_file_history(generation: Generation, request_id: RequestId, path: String, tx: &Sender<GraphEvent>, walk_ctx: &Walker, symbols: &SymbolTheme) {
-    let result = file_history_rows(walk_ctx, &path, symbols);
+fn send_file_history(state: &ServiceState, request_id: RequestId, path: String, walk_ctx: &Walker) {
+    let (generation, tx) = (state.generation, &state.tx);
+    let result = file_history_rows(walk_ctx, &path, &state.config.symbols);
     match result {
         Ok(rows) => {
             let _ = tx.send(GraphEvent::FileHistory { generation, request_id, path, rows, error: None });
@@ -336,7 +518,14 @@ fn no_message(symbols: &SymbolTheme) -> String {
     format!("{} {}", symbols.empty_state.mark, empty::NO_MESSAGE())
 }
 
-fn graph_rows(walk_ctx: &Walker, worktrees: &Worktrees, hidden_branch_names: &HashSet<String>, symbols: &SymbolTheme, start: usize, end: usize) -> Vec<GraphRow> {
+pub(crate) fn graph_rows(walk_ctx: &Walker, worktrees: &Worktrees, hidden_branch_names: &HashSet<String>, symbols: &SymbolTheme, start: usize, end: usize) -> Vec<GraphRow> {
+    graph_rows_with(walk_ctx, worktrees, hidden_branch_names, symbols, start, end, |_, _| {})
+}
+
+// Hands each row's commit to `inspect`, for callers that need more of it than a row carries without looking it up again.
+pub(crate) fn graph_rows_with(
+    walk_ctx: &Walker, worktrees: &Worktrees, hidden_branch_names: &HashSet<String>, symbols: &SymbolTheme, start: usize, end: usize, mut inspect: impl FnMut(usize, &git2::Commit<'_>),
+) -> Vec<GraphRow> {
     let repo = walk_ctx.repo.borrow();
     let latest_reflogs = latest_reflogs_by_alias(walk_ctx);
     let mut rows = Vec::with_capacity(end.saturating_sub(start));
@@ -347,11 +536,12 @@ fn graph_rows(walk_ctx: &Walker, worktrees: &Worktrees, hidden_branch_names: &Ha
         let is_uncommitted = alias == NONE || walk_ctx.oids.is_zero(&oid);
         let (summary, committer_date, committer_name, is_merge_commit) = if is_uncommitted {
             (String::new(), String::new(), String::new(), false)
-        } else if let Ok(commit) = repo.find_commit(oid) {
+        } else if let Ok(commit) = repo.find_commit(walk_ctx.commit_oid(oid)) {
             let summary = commit.summary().map(str::to_string).unwrap_or_else(|| no_message(symbols));
             let committer = commit.committer();
             let committer_date = timestamp_to_utc_date_time(committer.when());
             let committer_name = committer.name().unwrap_or(common::UNKNOWN()).to_string();
+            inspect(index, &commit);
             (summary, committer_date, committer_name, commit.parent_count() > 1)
         } else {
             (no_message(symbols), String::new(), String::new(), false)
@@ -374,11 +564,12 @@ fn graph_rows(walk_ctx: &Walker, worktrees: &Worktrees, hidden_branch_names: &Ha
 
         let is_stash = walk_ctx.oids.stashes.contains(&alias);
         let is_merge = is_merge_commit && !is_stash;
+        let is_shallow = walk_ctx.shallow_boundaries.contains(&oid);
         let stash_lane = walk_ctx.stashes_lanes.get(&alias).copied();
         let worktrees = worktrees_for_alias(worktrees, walk_ctx, alias);
         let reflog = latest_reflogs.get(&alias).map(|entry| GraphReflogLabel { selector: entry.selector.clone(), message: entry.message.clone(), lane: walk_ctx.reflogs_lanes.get(&alias).copied() });
 
-        rows.push(GraphRow { index, alias, oid, summary, committer_date, committer_name, is_merge, has_any_branch, branches, tags, is_stash, stash_lane, worktrees, reflog });
+        rows.push(GraphRow { index, alias, oid, summary, committer_date, committer_name, is_merge, has_any_branch, branches, tags, is_stash, is_shallow, stash_lane, worktrees, reflog });
     }
 
     rows
@@ -457,6 +648,17 @@ fn lookup(kind: GraphLookupKind, walk_ctx: &Walker, worktrees: &Worktrees, hidde
         },
         GraphLookupKind::ParentIndex { index } => GraphLookupResult::Index(parent_index(walk_ctx, index)),
         GraphLookupKind::ChildIndex { index } => GraphLookupResult::Index(child_index(walk_ctx, index)),
+        GraphLookupKind::GraphOidRange { start, end } => {
+            let aliases = walk_ctx.oids.get_sorted_aliases();
+            let aliases = &aliases[start.min(aliases.len())..end.min(aliases.len())];
+            GraphLookupResult::Oids(aliases.iter().map(|&alias| *walk_ctx.oids.get_oid_by_alias(alias)).filter(|oid| !oid.is_zero()).collect())
+        },
+        GraphLookupKind::PaneRowRange { pane, start, end } => GraphLookupResult::PaneRows(pane_rows(pane, walk_ctx).into_iter().skip(start).take(end.saturating_sub(start)).collect()),
+        GraphLookupKind::PaneRowByName { pane, name } => GraphLookupResult::Index(pane_rows(pane, walk_ctx).iter().position(|row| match row {
+            GraphPaneRow::Branch { name: current, .. } | GraphPaneRow::Tag { name: current, .. } => *current == name,
+            GraphPaneRow::Stash { alias, .. } => walk_ctx.oids.get_oid_by_alias(*alias).to_string() == name,
+            GraphPaneRow::Reflog { .. } => false,
+        })),
     }
 }
 
@@ -504,7 +706,7 @@ fn child_index(walk_ctx: &Walker, index: usize) -> Option<usize> {
     })
 }
 
-fn head_alias(walk_ctx: &Walker) -> u32 {
+pub(crate) fn head_alias(walk_ctx: &Walker) -> u32 {
     let repo = walk_ctx.repo.borrow();
     repo.head().ok().and_then(|head| head.target()).and_then(|oid| walk_ctx.oids.aliases.get(&oid).copied()).unwrap_or(NONE)
 }
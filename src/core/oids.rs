@@ -19,6 +19,12 @@ impl Default for Oids {
 }
 
 impl Oids {
+    // Restore a stored alias table so cached rows and lanes keep pointing at the same commits.
+    pub fn from_oids(oids: Vec<Oid>) -> Self {
+        let aliases = oids.iter().enumerate().map(|(alias, oid)| (*oid, alias as u32)).collect();
+        Oids { oids, aliases, ..Oids::default() }
+    }
+
     pub fn get_alias_by_oid(&mut self, oid: Oid) -> u32 {
         // Assign aliases lazily so refs, commits, tags, and stashes share one namespace.
         *self.aliases.entry(oid).or_insert_with(|| {
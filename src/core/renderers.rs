@@ -1,15 +1,16 @@
 use crate::core::{
-    graph_service::{GraphHistory, GraphRow, GraphSnapshot},
+    graph_service::{GraphBranchLabel, GraphHistory, GraphRow, GraphSnapshot},
     layers::LayersContext,
+    worktrees::WorktreeEntry,
 };
 use crate::helpers::text::truncate_with_ellipsis;
 use crate::helpers::{
-    keymap::{Command, KeyBinding, keycode_to_visual_string},
+    keymap::{Command, KeyBinding, keybinding_to_visual_string, keycode_to_visual_string},
     localisation::status as status_text,
 };
 use crate::{
     core::chunk::{Chunk, LaneRef, NONE},
-    git::queries::helpers::UncommittedChanges,
+    git::queries::{helpers::UncommittedChanges, signatures::SignatureStatus},
     helpers::{
         colors::ColorPicker,
         palette::*,
@@ -17,14 +18,17 @@ use crate::{
         text::{modifiers_to_string, pascal_to_spaced},
     },
 };
+use git2::Oid;
 use im::Vector;
 use indexmap::IndexMap;
 use ratatui::{
     style::Style,
     text::{Line, Span},
 };
+use std::collections::HashMap;
 
 pub const GRAPH_COMMITTER_WIDTH: usize = 18;
+pub const GRAPH_SIGNATURE_WIDTH: usize = 8;
 
 // Render graph symbols from worker-projected rows. The lane history is still
 // precomputed by Buffer, but only for the requested visible range.
@@ -158,6 +162,9 @@ pub fn render_graph_projection(
                 let is_two_parents = !chunk.is_flattened && chunk.parent_a != NONE && chunk.parent_b != NONE;
                 if row.is_merge && !row.has_any_branch {
                     layers.commit(&graph.merge, lane_idx);
+                } else if row.is_shallow {
+                    // Wins over the branch mark so a depth-one clone still shows where history stops.
+                    layers.commit(&graph.commit_shallow, lane_idx);
                 } else if row.has_any_branch {
                     layers.commit(&graph.commit_branch, lane_idx);
                 } else if row.worktrees.iter().any(|entry| entry.branch.is_none() || !row.has_any_branch) {
@@ -613,15 +620,28 @@ pub fn render_committer_projection(theme: &Theme, rows: &[GraphRow], selected: u
         .collect()
 }
 
+// Unsigned and not yet verified commits stay blank so signed ones stand out.
+pub fn render_signature_projection(theme: &Theme, rows: &[GraphRow], signatures: &HashMap<Oid, SignatureStatus>) -> Vec<Line<'static>> {
+    rows.iter()
+        .map(|row| {
+            let (text, color) = match signatures.get(&row.oid).filter(|_| row.alias != NONE) {
+                Some(SignatureStatus::Good { .. }) => (status_text::SIGNATURE_GOOD(), theme.COLOR_GRASS),
+                Some(SignatureStatus::Bad) => (status_text::SIGNATURE_BAD(), theme.COLOR_RED),
+                Some(SignatureStatus::UnknownKey) => (status_text::SIGNATURE_UNKNOWN(), theme.COLOR_YELLOW),
+                Some(SignatureStatus::Unsigned) | None => return Line::from(""),
+            };
+            Line::from(Span::styled(truncate_with_ellipsis(text, GRAPH_SIGNATURE_WIDTH), Style::default().fg(color)))
+        })
+        .collect()
+}
+
 pub fn render_message_projection(
     theme: &Theme, symbols: &SymbolTheme, rows: &[GraphRow], show_reflog_labels: bool, show_ref_labels: bool, selected: usize, uncommitted: &UncommittedChanges, render_uncommitted_row: bool,
 ) -> Vec<Line<'static>> {
     let color_picker = ColorPicker::from_theme(theme);
-    let branch_symbols = &symbols.branch;
     let entity = &symbols.entity;
     let graph = &symbols.graph;
     let status = &symbols.status;
-    let worktree_symbols = &symbols.worktree;
     let mut lines = Vec::new();
 
     for row in rows {
@@ -639,7 +659,7 @@ pub fn render_message_projection(
                     } else {
                         theme.COLOR_TEAL
                     };
-                    spans.push(Span::styled(format!("{} {} ", worktree_symbols.current, worktree.name), Style::default().fg(color)));
+                    spans.push(Span::styled(worktree_label(symbols, worktree), Style::default().fg(color)));
                 }
             }
             let has_worktree_label = show_ref_labels && !row.worktrees.is_empty();
@@ -647,10 +667,7 @@ pub fn render_message_projection(
             if show_ref_labels {
                 for branch in &row.branches {
                     let color = branch.lane.map(|lane| color_picker.get_lane_ref(lane)).unwrap_or(theme.COLOR_TEXT);
-                    spans.push(Span::styled(
-                        format!("{} {} ", if branch.is_local { branch_symbols.local_visible.as_str() } else { branch_symbols.remote_visible.as_str() }, branch.name),
-                        Style::default().fg(color),
-                    ));
+                    spans.push(Span::styled(branch_label(symbols, branch), Style::default().fg(color)));
                 }
             }
             let has_visible_branch_label = show_ref_labels && !row.branches.is_empty();
@@ -707,6 +724,27 @@ pub fn render_message_projection(
     lines
 }
 
+fn worktree_label(symbols: &SymbolTheme, worktree: &WorktreeEntry) -> String {
+    format!("{} {} ", symbols.worktree.current, worktree.name)
+}
+
+fn branch_label(symbols: &SymbolTheme, branch: &GraphBranchLabel) -> String {
+    format!("{} {} ", if branch.is_local { symbols.branch.local_visible.as_str() } else { symbols.branch.remote_visible.as_str() }, branch.name)
+}
+
+// The branch label under `column`, counted from the start of the message column with ref labels shown.
+pub fn branch_label_at<'a>(symbols: &SymbolTheme, row: &'a GraphRow, column: usize) -> Option<&'a GraphBranchLabel> {
+    let mut start = row.worktrees.iter().map(|worktree| Span::raw(worktree_label(symbols, worktree)).width()).sum::<usize>();
+    for branch in &row.branches {
+        let end = start + Span::raw(branch_label(symbols, branch)).width();
+        if (start..end).contains(&column) {
+            return Some(branch);
+        }
+        start = end;
+    }
+    None
+}
+
 pub fn render_keybindings(theme: &Theme, keymap: &IndexMap<KeyBinding, Command>, width: usize) -> Vec<Line<'static>> {
     keymap
         .iter()
@@ -717,6 +755,10 @@ pub fn render_keybindings(theme: &Theme, keymap: &IndexMap<KeyBinding, Command>,
                 key_string = format!("{} + ", key_string);
             }
             key_string.push_str(&keycode_to_visual_string(kb.code));
+            if kb.is_sequence() {
+                let prefix: Vec<String> = kb.prefix.iter().map(keybinding_to_visual_string).collect();
+                key_string = format!("{} {}", prefix.join(" "), key_string);
+            }
 
             // Command enum names double as display labels after spacing.
             let mut cmd_string = format!("{:?}", cmd);
@@ -0,0 +1,291 @@
+use crate::core::{
+    buffer::{Delta, DeltaOp, GraphSnapshot, apply_op},
+    chunk::{Chunk, Markers, NONE},
+};
+use git2::Oid;
+use im::HashSet;
+use std::{
+    collections::HashSet as StdHashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Bumped whenever the layout below changes so older files are ignored instead of misread.
+const MAGIC: &[u8; 4] = b"GTWC";
+const VERSION: u64 = 2;
+
+// Settings a stored walk was produced with; any difference changes the lanes it would draw.
+// The shallow boundaries are part of it because deepening adds history below unchanged tips.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalkKey {
+    pub path: String,
+    pub hidden_branch_names: Vec<String>,
+    pub include_head_reflog_roots: bool,
+    pub graph_lane_limit: usize,
+    pub shallow_boundaries: Vec<Oid>,
+}
+
+impl WalkKey {
+    pub fn new(path: &str, hidden_branch_names: &HashSet<String>, include_head_reflog_roots: bool, graph_lane_limit: usize, shallow_boundaries: &StdHashSet<Oid>) -> Self {
+        let mut hidden_branch_names: Vec<String> = hidden_branch_names.iter().cloned().collect();
+        hidden_branch_names.sort();
+        let mut shallow_boundaries: Vec<Oid> = shallow_boundaries.iter().copied().collect();
+        shallow_boundaries.sort();
+        Self { path: path.to_string(), hidden_branch_names, include_head_reflog_roots, graph_lane_limit, shallow_boundaries }
+    }
+}
+
+// A finished walk: the tips it started from, its alias table, row order and lane deltas.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalkCache {
+    pub key: WalkKey,
+    pub tips: Vec<Oid>,
+    pub stashes: Vec<Oid>,
+    pub oids: Vec<Oid>,
+    pub sorted_aliases: Vec<u32>,
+    pub deltas: Vec<Delta>,
+}
+
+pub fn walk_cache_dir() -> PathBuf {
+    let mut path = dirs::cache_dir().unwrap();
+    path.push("guitar");
+    path.push("walks");
+    path
+}
+
+// One file per repository, named after a stable hash of its path.
+pub fn walk_cache_path(dir: &Path, repo_path: &str) -> PathBuf {
+    let hash = repo_path.bytes().fold(0xcbf29ce484222325u64, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x100000001b3));
+    dir.join(format!("{hash:016x}.bin"))
+}
+
+pub fn load_walk_cache(path: &Path) -> Option<WalkCache> {
+    let bytes = fs::read(path).ok()?;
+    decode(&bytes)
+}
+
+// Written next to the target and renamed so an interrupted save never leaves a torn file.
+pub fn save_walk_cache(path: &Path, cache: &WalkCache) {
+    if let Some(parent) = path.parent()
+        && !parent.exists()
+    {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    let temp_path = path.with_extension("tmp");
+    if fs::write(&temp_path, encode(cache)).is_ok() {
+        let _ = fs::rename(&temp_path, path);
+    }
+}
+
+fn encode(cache: &WalkCache) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(cache.oids.len() * 20 + cache.deltas.len() * 16);
+    bytes.extend_from_slice(MAGIC);
+    put_varint(&mut bytes, VERSION);
+
+    put_string(&mut bytes, &cache.key.path);
+    put_varint(&mut bytes, cache.key.hidden_branch_names.len() as u64);
+    for name in &cache.key.hidden_branch_names {
+        put_string(&mut bytes, name);
+    }
+    bytes.push(cache.key.include_head_reflog_roots as u8);
+    put_varint(&mut bytes, cache.key.graph_lane_limit as u64);
+
+    for oids in [&cache.key.shallow_boundaries, &cache.tips, &cache.stashes, &cache.oids] {
+        put_varint(&mut bytes, oids.len() as u64);
+        for oid in oids {
+            bytes.extend_from_slice(oid.as_bytes());
+        }
+    }
+
+    put_varint(&mut bytes, cache.sorted_aliases.len() as u64);
+    for &alias in &cache.sorted_aliases {
+        put_alias(&mut bytes, alias);
+    }
+
+    put_varint(&mut bytes, cache.deltas.len() as u64);
+    for delta in &cache.deltas {
+        put_varint(&mut bytes, delta.ops.len() as u64);
+        for op in &delta.ops {
+            match op {
+                DeltaOp::Insert { index, item } => {
+                    bytes.push(0);
+                    put_varint(&mut bytes, *index as u64);
+                    put_chunk(&mut bytes, item);
+                },
+                DeltaOp::Remove { index } => {
+                    bytes.push(1);
+                    put_varint(&mut bytes, *index as u64);
+                },
+                DeltaOp::Replace { index, new } => {
+                    bytes.push(2);
+                    put_varint(&mut bytes, *index as u64);
+                    put_chunk(&mut bytes, new);
+                },
+                DeltaOp::CompressedParentInsert { parent } => {
+                    bytes.push(3);
+                    put_alias(&mut bytes, *parent);
+                },
+                DeltaOp::CompressedParentRemove { parent } => {
+                    bytes.push(4);
+                    put_alias(&mut bytes, *parent);
+                },
+            }
+        }
+    }
+
+    bytes
+}
+
+fn decode(bytes: &[u8]) -> Option<WalkCache> {
+    let mut reader = Reader { bytes, pos: 0 };
+    if reader.take(MAGIC.len())? != MAGIC || reader.varint()? != VERSION {
+        return None;
+    }
+
+    let path = reader.string()?;
+    let hidden_branch_names = (0..reader.len()?).map(|_| reader.string()).collect::<Option<Vec<_>>>()?;
+    let include_head_reflog_roots = reader.byte()? != 0;
+    let graph_lane_limit = reader.varint()? as usize;
+    let shallow_boundaries = reader.oids()?;
+    let key = WalkKey { path, hidden_branch_names, include_head_reflog_roots, graph_lane_limit, shallow_boundaries };
+
+    let tips = reader.oids()?;
+    let stashes = reader.oids()?;
+    let oids = reader.oids()?;
+
+    let sorted_aliases = (0..reader.len()?).map(|_| reader.alias()).collect::<Option<Vec<_>>>()?;
+    // Row aliases index the table above; anything else means the file is not ours to trust.
+    if sorted_aliases.first() != Some(&NONE) || sorted_aliases.iter().skip(1).any(|&alias| alias as usize >= oids.len()) {
+        return None;
+    }
+
+    // Replaying the ops checks every lane index before `apply_delta` would panic on it later.
+    let known = |alias: u32| alias == NONE || (alias as usize) < oids.len();
+    let mut scratch = GraphSnapshot::default();
+    let mut deltas = Vec::with_capacity(sorted_aliases.len() + 1);
+    for _ in 0..reader.len()? {
+        let mut ops = Vec::new();
+        for _ in 0..reader.len()? {
+            let op = match reader.byte()? {
+                0 => DeltaOp::Insert { index: reader.varint()? as usize, item: reader.chunk()? },
+                1 => DeltaOp::Remove { index: reader.varint()? as usize },
+                2 => DeltaOp::Replace { index: reader.varint()? as usize, new: reader.chunk()? },
+                3 => DeltaOp::CompressedParentInsert { parent: reader.alias()? },
+                4 => DeltaOp::CompressedParentRemove { parent: reader.alias()? },
+                _ => return None,
+            };
+            let lanes = scratch.lanes.len();
+            let valid = match &op {
+                DeltaOp::Insert { index, item } => *index <= lanes && [item.alias, item.parent_a, item.parent_b].into_iter().all(known),
+                DeltaOp::Remove { index } => *index < lanes,
+                DeltaOp::Replace { index, new } => *index < lanes && [new.alias, new.parent_a, new.parent_b].into_iter().all(known),
+                DeltaOp::CompressedParentInsert { parent } | DeltaOp::CompressedParentRemove { parent } => known(*parent),
+            };
+            if !valid {
+                return None;
+            }
+            apply_op(&mut scratch, &op);
+            ops.push(op);
+        }
+        deltas.push(Delta { ops });
+    }
+
+    (reader.pos == bytes.len()).then_some(WalkCache { key, tips, stashes, oids, sorted_aliases, deltas })
+}
+
+fn put_varint(bytes: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        bytes.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+// Shifted by one so NONE, the most common parent, takes a single byte.
+fn put_alias(bytes: &mut Vec<u8>, alias: u32) {
+    put_varint(bytes, alias.wrapping_add(1) as u64);
+}
+
+fn put_string(bytes: &mut Vec<u8>, value: &str) {
+    put_varint(bytes, value.len() as u64);
+    bytes.extend_from_slice(value.as_bytes());
+}
+
+fn put_chunk(bytes: &mut Vec<u8>, chunk: &Chunk) {
+    put_alias(bytes, chunk.alias);
+    put_alias(bytes, chunk.parent_a);
+    put_alias(bytes, chunk.parent_b);
+    let marker = match chunk.marker {
+        Markers::Uncommitted => 0,
+        Markers::Commit => 1,
+        Markers::Dummy => 2,
+    };
+    bytes.push(marker | ((chunk.is_flattened as u8) << 2));
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, count: usize) -> Option<&[u8]> {
+        let slice = self.bytes.get(self.pos..self.pos.checked_add(count)?)?;
+        self.pos += count;
+        Some(slice)
+    }
+
+    fn byte(&mut self) -> Option<u8> {
+        self.take(1).map(|slice| slice[0])
+    }
+
+    fn varint(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    // Every counted item takes at least one byte, which bounds allocations on a corrupt length.
+    fn len(&mut self) -> Option<usize> {
+        let len = self.varint()? as usize;
+        (len <= self.bytes.len() - self.pos).then_some(len)
+    }
+
+    fn alias(&mut self) -> Option<u32> {
+        u32::try_from(self.varint()?).ok().map(|alias| alias.wrapping_sub(1))
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let len = self.len()?;
+        String::from_utf8(self.take(len)?.to_vec()).ok()
+    }
+
+    fn oids(&mut self) -> Option<Vec<Oid>> {
+        (0..self.len()?).map(|_| Oid::from_bytes(self.take(20)?).ok()).collect()
+    }
+
+    fn chunk(&mut self) -> Option<Chunk> {
+        let alias = self.alias()?;
+        let parent_a = self.alias()?;
+        let parent_b = self.alias()?;
+        let flags = self.byte()?;
+        let marker = match flags & 0b11 {
+            0 => Markers::Uncommitted,
+            1 => Markers::Commit,
+            2 => Markers::Dummy,
+            _ => return None,
+        };
+        Some(Chunk { alias, parent_a, parent_b, marker, is_flattened: flags & 0b100 != 0 })
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/core/walk_cache.rs"]
+mod tests;
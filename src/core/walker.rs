@@ -2,14 +2,17 @@ use crate::git::queries::{commits::get_stashed_commits, reflogs::HeadReflogEntry
 use crate::{
     core::{
         batcher::Batcher,
-        buffer::Buffer,
+        buffer::{Buffer, Delta, GraphSnapshot, apply_delta},
         chunk::{Chunk, LaneRef, NONE},
+        commit_graph::CommitGraph,
         oids::Oids,
+        walk_cache::{WalkCache, WalkKey},
     },
     git::queries::commits::{get_sorted_oids, get_tag_oids, get_tip_oids},
+    git::queries::grafts::{get_replacements, get_shallow_boundaries},
     git::queries::reflogs::get_head_reflog_entries,
 };
-use git2::Repository;
+use git2::{Oid, Repository};
 use im::{HashSet, Vector};
 use std::{
     cell::RefCell,
@@ -25,9 +28,18 @@ pub struct Walker {
     // Revwalk cursor for incremental history loading.
     pub batcher: Batcher,
 
+    // Parents and generation numbers from `objects/info/commit-graph`, when git wrote one.
+    pub commit_graph: Option<Rc<CommitGraph>>,
+
     // Mutable lane buffer that records topology deltas.
     pub buffer: RefCell<Buffer>,
 
+    // Replace refs honoured by this walk; empty when original parents are shown.
+    pub replacements: HashMap<Oid, Oid>,
+
+    // Commits a shallow clone was cut at, drawn with their own marker instead of as roots.
+    pub shallow_boundaries: StdHashSet<Oid>,
+
     // Alias and ref metadata accumulated during the walk.
     pub oids: Oids,
 
@@ -47,17 +59,71 @@ pub struct Walker {
 
     // Number of commits requested per walk iteration.
     pub amount: usize,
+
+    // Settings this walk was started with, stored alongside it in the walk cache.
+    pub key: WalkKey,
+
+    // Set when the walk was restored unchanged from the cache and needs no saving.
+    pub is_cache_current: bool,
 }
 
 impl Walker {
     // Open the repository and seed all metadata that does not depend on walking commits.
-    pub fn new(path: String, amount: usize, hidden_branch_names: HashSet<String>, include_head_reflog_roots: bool, graph_lane_limit: usize) -> Result<Self, git2::Error> {
-        let path = path.clone();
-        let repo = Rc::new(RefCell::new(Repository::open(path).expect("Failed to open repo")));
+    pub fn new(path: String, amount: usize, hidden_branch_names: HashSet<String>, include_head_reflog_roots: bool, use_replace_refs: bool, graph_lane_limit: usize) -> Result<Self, git2::Error> {
+        Self::with_oids(path, amount, hidden_branch_names, include_head_reflog_roots, use_replace_refs, graph_lane_limit, Oids::default())
+    }
 
-        let buffer = RefCell::new(Buffer::with_lane_limit(graph_lane_limit));
+    // Rebuild a finished walk from the cache, walking only commits added since it was stored.
+    // Returns None when the cache no longer describes this history and a full walk is needed.
+    pub fn resume(
+        path: String, amount: usize, hidden_branch_names: HashSet<String>, include_head_reflog_roots: bool, use_replace_refs: bool, graph_lane_limit: usize, cache: WalkCache,
+    ) -> Result<Option<Self>, git2::Error> {
+        let shallow_boundaries = Repository::open(&path).map(|repo| get_shallow_boundaries(&repo)).unwrap_or_default();
+        if cache.key != WalkKey::new(&path, &hidden_branch_names, include_head_reflog_roots, graph_lane_limit, &shallow_boundaries) {
+            return Ok(None);
+        }
+
+        let oids = Oids::from_oids(cache.oids);
+        let mut walker = Self::with_oids(path, amount, hidden_branch_names, include_head_reflog_roots, use_replace_refs, graph_lane_limit, oids)?;
+        if walker.key != cache.key || !walker.is_cacheable() || !walker.replay(cache.tips, cache.stashes, cache.sorted_aliases, cache.deltas)? {
+            return Ok(None);
+        }
+
+        walker.refresh_ref_lanes();
+        Ok(Some(walker))
+    }
 
-        let mut oids = Oids::default();
+    // Re-read refs after an action and place new commits above the rows this finished walk holds.
+    // The alias table, rows and lane deltas are moved into the refreshed walk rather than copied.
+    // Returns false when the settings or history changed under the walk, which then needs a fresh one.
+    pub fn refresh(&mut self, hidden_branch_names: HashSet<String>, include_head_reflog_roots: bool, use_replace_refs: bool, graph_lane_limit: usize) -> Result<bool, git2::Error> {
+        let shallow_boundaries = get_shallow_boundaries(&self.repo.borrow());
+        if !self.is_cacheable() || self.key != WalkKey::new(&self.key.path, &hidden_branch_names, include_head_reflog_roots, graph_lane_limit, &shallow_boundaries) {
+            return Ok(false);
+        }
+
+        let tips = self.batcher.roots();
+        let stashes: Vec<Oid> = self.oids.stashes.iter().map(|&alias| *self.oids.get_oid_by_alias(alias)).collect();
+        let mut oids = std::mem::take(&mut self.oids);
+        let sorted_aliases = std::mem::replace(&mut oids.sorted_aliases, vec![NONE]);
+        let refreshed = Self::with_oids(self.key.path.clone(), self.amount, hidden_branch_names, include_head_reflog_roots, use_replace_refs, graph_lane_limit, oids)?;
+        let previous = std::mem::replace(self, refreshed);
+        let deltas = std::mem::take(&mut previous.buffer.borrow_mut().deltas);
+
+        if self.key != previous.key || !self.is_cacheable() || !self.replay(tips, stashes, sorted_aliases, deltas)? {
+            return Ok(false);
+        }
+
+        self.refresh_ref_lanes();
+        Ok(true)
+    }
+
+    fn with_oids(
+        path: String, amount: usize, hidden_branch_names: HashSet<String>, include_head_reflog_roots: bool, use_replace_refs: bool, graph_lane_limit: usize, mut oids: Oids,
+    ) -> Result<Self, git2::Error> {
+        let repo = Rc::new(RefCell::new(Repository::open(&path).expect("Failed to open repo")));
+
+        let buffer = RefCell::new(Buffer::with_lane_limit(graph_lane_limit));
 
         // Branch and tag tips are registered before walking so aliases are stable.
         let branches_lanes = HashMap::new();
@@ -95,12 +161,20 @@ impl Walker {
             }
         }
 
-        let batcher = Batcher::new(repo.clone(), &hidden_branch_names, &head_reflog_roots).expect("Error");
+        let replacements = if use_replace_refs { get_replacements(&repo.borrow()) } else { HashMap::new() };
+        let shallow_boundaries = get_shallow_boundaries(&repo.borrow());
+        let key = WalkKey::new(&path, &hidden_branch_names, include_head_reflog_roots, graph_lane_limit, &shallow_boundaries);
+
+        let commit_graph = CommitGraph::open(&repo.borrow()).map(Rc::new);
+        let batcher = Batcher::new(repo.clone(), commit_graph.clone(), &hidden_branch_names, &head_reflog_roots, &replacements).expect("Error");
 
         Ok(Self {
             repo,
             batcher,
+            commit_graph,
             buffer,
+            replacements,
+            shallow_boundaries,
             oids,
             branches_lanes,
             branches_local,
@@ -114,12 +188,37 @@ impl Walker {
             reflog_aliases,
             stash_parent_aliases,
             amount,
+            key,
+            is_cache_current: false,
         })
     }
 
+    // The commit whose contents stand for `oid`: its replacement, or the commit itself.
+    pub fn commit_oid(&self, oid: Oid) -> Oid {
+        self.replacements.get(&oid).copied().unwrap_or(oid)
+    }
+
+    // Replaying a cache relies on libgit2's walk, which knows nothing of replace refs.
+    pub fn is_cacheable(&self) -> bool {
+        self.replacements.is_empty()
+    }
+
+    // Snapshot of a finished walk for the walk cache.
+    pub fn walk_cache(&self) -> WalkCache {
+        WalkCache {
+            key: self.key.clone(),
+            tips: self.batcher.roots(),
+            stashes: self.oids.stashes.iter().map(|&alias| *self.oids.get_oid_by_alias(alias)).collect(),
+            oids: self.oids.oids.clone(),
+            sorted_aliases: self.oids.sorted_aliases.clone(),
+            deltas: self.buffer.borrow().deltas.iter().cloned().collect(),
+        }
+    }
+
     // Process one revwalk page and update lane snapshots for the renderer.
     pub fn walk(&mut self) -> bool {
-        let repo = self.repo.borrow();
+        let repo_rc = self.repo.clone();
+        let repo = repo_rc.borrow();
 
         // Without HEAD there is no stable parent for the uncommitted pseudo-row.
         let head_oid = match repo.head().ok().and_then(|h| h.target()) {
@@ -146,89 +245,211 @@ impl Walker {
             }
         }
 
-        // Hold one mutable buffer borrow while the page updates topology.
-        let mut buffer = self.buffer.borrow_mut();
-
         for &alias in sorted_batch.iter() {
-            let mut merger_alias: u32 = NONE;
-            let mut transient_lane: Option<usize> = None;
-            let oid = self.oids.get_oid_by_alias(alias);
-            let commit = repo.find_commit(*oid).unwrap();
-
-            // Only two parents are modeled because the renderer draws one merge edge.
-            let mut parents_iter = commit.parent_ids();
-            let parent_a_oid = parents_iter.next();
-            let parent_b_oid = parents_iter.next();
-
-            // Stashes should point only to their base commit, not the index/worktree parents.
-            let (parent_a, parent_b) = if self.stash_aliases.contains(&alias) {
-                (parent_a_oid.map(|p| self.oids.get_alias_by_oid(p)).unwrap_or(NONE), NONE)
-            } else {
-                (parent_a_oid.map(|p| self.oids.get_alias_by_oid(p)).unwrap_or(NONE), parent_b_oid.map(|p| self.oids.get_alias_by_oid(p)).unwrap_or(NONE))
-            };
+            self.place(&repo, alias);
+        }
 
-            let chunk = Chunk::commit(alias, parent_a, parent_b);
+        // Empty pages mean the worker is done; emit one backup so lane-window reconstruction has a final delta.
+        if sorted_batch.is_empty() {
+            self.buffer.borrow_mut().backup();
+            return false;
+        }
 
-            let update = buffer.update(chunk);
+        true
+    }
 
-            if let Some(chunk) = buffer.curr.get(update.lane.index)
-                && !chunk.is_dummy()
-                && alias == chunk.alias
-            {
-                let lane = update.lane;
-                let lane_idx = lane.index;
+    // Feed one commit through the lane buffer and record where its refs landed.
+    fn place(&mut self, repo: &Repository, alias: u32) {
+        let mut buffer = self.buffer.borrow_mut();
+        let mut merger_alias: u32 = NONE;
+        let mut transient_lane: Option<usize> = None;
+        let oid = *self.oids.get_oid_by_alias(alias);
+
+        // Only two parents are modeled because the renderer draws one merge edge.
+        let (parent_a_oid, parent_b_oid) = self.parent_ids(repo, oid);
+
+        // Stashes should point only to their base commit, not the index/worktree parents.
+        let (parent_a, parent_b) = if self.stash_aliases.contains(&alias) {
+            (parent_a_oid.map(|p| self.oids.get_alias_by_oid(p)).unwrap_or(NONE), NONE)
+        } else {
+            (parent_a_oid.map(|p| self.oids.get_alias_by_oid(p)).unwrap_or(NONE), parent_b_oid.map(|p| self.oids.get_alias_by_oid(p)).unwrap_or(NONE))
+        };
 
-                // Ref lanes are captured after the buffer decides where this alias sits.
-                if self.branches_local.contains_key(&alias) || self.branches_remote.contains_key(&alias) {
-                    self.branches_lanes.insert(alias, lane);
-                }
+        let chunk = Chunk::commit(alias, parent_a, parent_b);
 
-                if self.tags_local.contains_key(&alias) {
-                    self.tags_lanes.insert(alias, lane);
-                }
+        let update = buffer.update(chunk);
 
-                if self.stash_aliases.contains(&alias) {
-                    self.stashes_lanes.insert(alias, lane);
-                }
+        if let Some(chunk) = buffer.curr.get(update.lane.index)
+            && !chunk.is_dummy()
+            && alias == chunk.alias
+        {
+            let lane = update.lane;
+            let lane_idx = lane.index;
 
-                if self.reflog_aliases.contains(&alias) {
-                    self.reflogs_lanes.insert(alias, lane);
-                }
+            // Ref lanes are captured after the buffer decides where this alias sits.
+            if self.branches_local.contains_key(&alias) || self.branches_remote.contains_key(&alias) {
+                self.branches_lanes.insert(alias, lane);
+            }
 
-                if !chunk.is_flattened && chunk.parent_a != NONE && chunk.parent_b != NONE {
-                    // If the second parent is not already visible as a lane, mark a deferred merge.
-                    let is_merger_found = buffer.curr.iter().enumerate().any(|(idx, chunk_nested)| idx != lane_idx && chunk_nested.has_parent(chunk.parent_b));
-                    if !is_merger_found {
-                        merger_alias = chunk.alias;
-                    } else if update.started_lane
-                        && !lane.is_flattened
-                        && lane_idx + 1 == buffer.curr.len()
-                        && parent_is_on_prior_lane(&buffer.curr, chunk.parent_a, lane_idx)
-                        && parent_is_on_prior_lane(&buffer.curr, chunk.parent_b, lane_idx)
-                    {
-                        transient_lane = Some(lane_idx);
-                    }
-                }
+            if self.tags_local.contains_key(&alias) {
+                self.tags_lanes.insert(alias, lane);
             }
 
-            if merger_alias != NONE {
-                buffer.merger(merger_alias);
-            } else if let Some(lane_idx) = transient_lane {
-                buffer.expire_lane_after_snapshot(lane_idx);
+            if self.stash_aliases.contains(&alias) {
+                self.stashes_lanes.insert(alias, lane);
             }
 
-            // Preserve the rendered order separately from first-seen alias assignment.
-            self.oids.append_sorted_alias(alias);
+            if self.reflog_aliases.contains(&alias) {
+                self.reflogs_lanes.insert(alias, lane);
+            }
+
+            if !chunk.is_flattened && chunk.parent_a != NONE && chunk.parent_b != NONE {
+                // If the second parent is not already visible as a lane, mark a deferred merge.
+                let is_merger_found = buffer.curr.iter().enumerate().any(|(idx, chunk_nested)| idx != lane_idx && chunk_nested.has_parent(chunk.parent_b));
+                if !is_merger_found {
+                    merger_alias = chunk.alias;
+                } else if update.started_lane
+                    && !lane.is_flattened
+                    && lane_idx + 1 == buffer.curr.len()
+                    && parent_is_on_prior_lane(&buffer.curr, chunk.parent_a, lane_idx)
+                    && parent_is_on_prior_lane(&buffer.curr, chunk.parent_b, lane_idx)
+                {
+                    transient_lane = Some(lane_idx);
+                }
+            }
         }
 
-        // Empty pages mean the worker is done; emit one backup so lane-window reconstruction has a final delta.
-        if sorted_batch.is_empty() {
-            buffer.backup();
-            return false;
+        if merger_alias != NONE {
+            buffer.merger(merger_alias);
+        } else if let Some(lane_idx) = transient_lane {
+            buffer.expire_lane_after_snapshot(lane_idx);
         }
 
-        true
+        // Preserve the rendered order separately from first-seen alias assignment.
+        self.oids.append_sorted_alias(alias);
+    }
+
+    // The commit graph answers without reading the commit; commits newer than the file are read.
+    fn parent_ids(&self, repo: &Repository, oid: Oid) -> (Option<Oid>, Option<Oid>) {
+        if let Some(graph) = &self.commit_graph
+            && let Some(position) = graph.position(oid)
+        {
+            let mut parents = graph.parents(position).map(|parent| graph.oid(parent));
+            return (parents.next(), parents.next());
+        }
+        let commit = repo.find_commit(self.commit_oid(oid)).unwrap();
+        let mut parents = commit.parent_ids();
+        (parents.next(), parents.next())
+    }
+
+    // Place new commits above the stored rows, then replay stored rows only until the lanes
+    // match what was recorded; from there on the stored deltas are reused as they are.
+    fn replay(&mut self, tips: Vec<Oid>, stashes: Vec<Oid>, sorted_aliases: Vec<u32>, deltas: impl IntoIterator<Item = Delta>) -> Result<bool, git2::Error> {
+        let repo_rc = self.repo.clone();
+        let repo = repo_rc.borrow();
+
+        let Some(head_oid) = repo.head().ok().and_then(|head| head.target()) else {
+            return Ok(false);
+        };
+
+        // Stashes were slotted next to their parents when the rows were walked.
+        let current_stashes: Vec<Oid> = self.oids.stashes.iter().map(|&alias| *self.oids.get_oid_by_alias(alias)).collect();
+        if current_stashes != stashes || sorted_aliases.first() != Some(&NONE) {
+            return Ok(false);
+        }
+
+        // Every stored tip must still be reachable, otherwise rows below it may be gone.
+        let roots = self.batcher.roots();
+        if has_unreachable_commits(&repo, &tips, &roots)? {
+            return Ok(false);
+        }
+
+        let fresh: Vec<u32> = new_commits(&repo, &roots, &tips)?.into_iter().map(|oid| self.oids.get_alias_by_oid(oid)).collect();
+        let fresh_set: StdHashSet<u32> = fresh.iter().copied().collect();
+        if self.stash_parent_aliases.iter().any(|(_, parent)| fresh_set.contains(parent)) {
+            return Ok(false);
+        }
+
+        let head_alias = self.oids.get_alias_by_oid(head_oid);
+        self.buffer.borrow_mut().update(Chunk::uncommitted(head_alias, NONE));
+
+        // The first stored delta is the initial backup and the second holds the uncommitted row.
+        let mut stored = GraphSnapshot::default();
+        let mut deltas = deltas.into_iter();
+        for delta in deltas.by_ref().take(2) {
+            apply_delta(&mut stored, &delta);
+        }
+
+        let mut rows = sorted_aliases.into_iter().skip(1);
+        if fresh.is_empty() && self.buffer.borrow().matches(&stored) {
+            self.buffer.borrow_mut().graft(deltas);
+            self.oids.sorted_aliases.extend(rows);
+            self.is_cache_current = tips == roots;
+            return Ok(true);
+        }
+
+        for alias in fresh {
+            self.place(&repo, alias);
+        }
+
+        while let Some(alias) = rows.next() {
+            self.place(&repo, alias);
+            let Some(delta) = deltas.next() else {
+                return Ok(false);
+            };
+            apply_delta(&mut stored, &delta);
+
+            if self.buffer.borrow().matches(&stored) {
+                self.buffer.borrow_mut().graft(deltas);
+                self.oids.sorted_aliases.extend(rows);
+                return Ok(true);
+            }
+        }
+
+        // Lanes never settled back onto the stored ones, so every row was walked again.
+        self.buffer.borrow_mut().backup();
+        Ok(true)
+    }
+
+    // Grafted rows were never placed here, so ref lanes are read back from the lane history.
+    fn refresh_ref_lanes(&mut self) {
+        let rows: HashMap<u32, usize> = self.oids.get_sorted_aliases().iter().enumerate().map(|(index, &alias)| (alias, index)).collect();
+        let buffer = self.buffer.borrow();
+        let lanes_for = |aliases: Vec<u32>| -> HashMap<u32, LaneRef> { aliases.into_iter().filter_map(|alias| Some((alias, buffer.lane_at(*rows.get(&alias)?, alias)?))).collect() };
+
+        self.branches_lanes = lanes_for(self.branches_local.keys().chain(self.branches_remote.keys()).copied().collect());
+        self.tags_lanes = lanes_for(self.tags_local.keys().copied().collect());
+        self.stashes_lanes = lanes_for(self.stash_aliases.iter().copied().collect());
+        self.reflogs_lanes = lanes_for(self.reflog_aliases.iter().copied().collect());
+    }
+}
+
+// One walk from the stored tips with the current ones hidden; anything it yields was dropped from history.
+fn has_unreachable_commits(repo: &Repository, stored_tips: &[Oid], roots: &[Oid]) -> Result<bool, git2::Error> {
+    let mut revwalk = repo.revwalk()?;
+    for tip in stored_tips {
+        // A tip that can no longer be read is gone from history too.
+        if revwalk.push(*tip).is_err() {
+            return Ok(true);
+        }
+    }
+    for root in roots {
+        revwalk.hide(*root)?;
+    }
+    Ok(revwalk.next().is_some())
+}
+
+// Commits reachable from the current tips but not from the stored ones, in walk order.
+fn new_commits(repo: &Repository, roots: &[Oid], stored_tips: &[Oid]) -> Result<Vec<Oid>, git2::Error> {
+    let mut revwalk = repo.revwalk()?;
+    for root in roots {
+        revwalk.push(*root)?;
+    }
+    for tip in stored_tips {
+        revwalk.hide(*tip)?;
     }
+    revwalk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::TIME)?;
+    revwalk.collect()
 }
 
 fn parent_is_on_prior_lane(lanes: &Vector<Chunk>, parent: u32, before_lane: usize) -> bool {
@@ -1,4 +1,8 @@
-use crate::git::actions::conflicts::{ensure_clean_workdir, mark_conflicts_resolved_from_workdir};
+use crate::git::actions::{
+    conflicts::{ensure_clean_workdir, mark_conflicts_resolved_from_workdir},
+    sequencing::{SequenceKind, validate_mainline},
+    signing::commit_to_head,
+};
 use git2::{CherrypickOptions, Error, Oid, Repository, RepositoryState, build::CheckoutBuilder};
 use std::{fs, path::PathBuf};
 
@@ -36,12 +40,15 @@ fn read_message(repo: &Repository) -> String {
         .unwrap_or_else(|| "cherrypicked: Cherry-pick commit".to_string())
 }
 
-fn cherrypick_options<'a>() -> CherrypickOptions<'a> {
+fn cherrypick_options<'a>(mainline: Option<u32>) -> CherrypickOptions<'a> {
     let mut checkout = CheckoutBuilder::new();
     checkout.allow_conflicts(true).conflict_style_merge(true);
 
     let mut opts = CherrypickOptions::new();
     opts.checkout_builder(checkout);
+    if let Some(mainline) = mainline {
+        opts.mainline(mainline);
+    }
     opts
 }
 
@@ -52,7 +59,7 @@ fn commit_index(repo: &Repository, message: &str) -> Result<Oid, Error> {
     let sig = repo.signature()?;
     let head_commit = repo.head()?.peel_to_commit()?;
     let parents = [&head_commit];
-    let oid = repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)?;
+    let oid = commit_to_head(repo, "cherry-pick", &sig, &sig, message, &tree, &parents)?;
     repo.cleanup_state()?;
     cleanup_message(repo);
     repo.checkout_head(Some(CheckoutBuilder::default().force()))?;
@@ -60,14 +67,21 @@ fn commit_index(repo: &Repository, message: &str) -> Result<Oid, Error> {
 }
 
 pub fn start_cherrypick(repo: &Repository, commit_oid: Oid, message: &str) -> Result<CherrypickOutcome, Error> {
+    start_cherrypick_with_mainline(repo, commit_oid, None, message)
+}
+
+pub fn start_cherrypick_with_mainline(repo: &Repository, commit_oid: Oid, mainline: Option<u32>, message: &str) -> Result<CherrypickOutcome, Error> {
     if is_cherrypick_in_progress(repo) {
         return Err(Error::from_str("cherry-pick already in progress"));
     }
     ensure_clean_workdir(repo, "cherry-picking")?;
-    persist_message(repo, message)?;
 
     let commit = repo.find_commit(commit_oid)?;
-    let mut opts = cherrypick_options();
+    validate_mainline(SequenceKind::Cherrypick, &commit, mainline)?;
+
+    persist_message(repo, message)?;
+
+    let mut opts = cherrypick_options(mainline);
     if let Err(error) = repo.cherrypick(&commit, Some(&mut opts)) {
         cleanup_message(repo);
         return Err(error);
@@ -1,3 +1,4 @@
+use crate::git::actions::signing::commit_to_head;
 use git2::{Error, ErrorCode, Oid, Repository, Signature};
 
 pub fn commit_staged(repo: &Repository, message: &str, name: &str, email: &str) -> Result<Oid, Error> {
@@ -20,9 +21,9 @@ pub fn commit_staged(repo: &Repository, message: &str, name: &str, email: &str)
     let signature = Signature::now(name, email)?;
 
     let commit_oid = if let Some(parent) = parent_commit {
-        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &[&parent])?
+        commit_to_head(repo, "commit", &signature, &signature, message, &tree, &[&parent])?
     } else {
-        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &[])?
+        commit_to_head(repo, "commit", &signature, &signature, message, &tree, &[])?
     };
 
     Ok(commit_oid)
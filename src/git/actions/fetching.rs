@@ -1,21 +1,35 @@
 use crate::{
-    git::auth::{AuthAttempt, AuthSession, NetworkResult, network_result},
+    git::{
+        auth::{AuthAttempt, AuthSession, NetworkResult, network_result},
+        os::path::open_repo,
+        queries::grafts::{get_shallow_boundaries, get_shallow_depth},
+    },
     helpers::localisation::network,
 };
 use git2::FetchPrune;
-use git2::{FetchOptions, RemoteCallbacks, Repository};
-use std::thread;
+use git2::{FetchOptions, Oid, RemoteCallbacks, Repository};
+use std::{path::PathBuf, thread};
 
 // Run fetch on a worker thread so auth prompts and network latency stay outside the draw loop.
-pub fn fetch_remote(repo_path: &str, remote_name: &str, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
+pub fn fetch_remote(repo_path: &str, work_tree: Option<PathBuf>, remote_name: &str, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
+    spawn_fetch(repo_path, work_tree, remote_name, None, auth_session)
+}
+
+// Fetch `by` more commits below the current shallow boundary, like `git fetch --deepen`.
+pub fn deepen_remote(repo_path: &str, work_tree: Option<PathBuf>, remote_name: &str, by: usize, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
+    spawn_fetch(repo_path, work_tree, remote_name, Some(by), auth_session)
+}
+
+fn spawn_fetch(repo_path: &str, work_tree: Option<PathBuf>, remote_name: &str, deepen_by: Option<usize>, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
     // Own the inputs before crossing the thread boundary.
     let repo_path = repo_path.to_string();
     let remote_name = remote_name.to_string();
+    let label = if deepen_by.is_some() { network::DEEPEN() } else { network::FETCH() };
 
     thread::spawn(move || {
-        let attempt = AuthAttempt::new(auth_session, network::FETCH());
+        let attempt = AuthAttempt::new(auth_session, label);
         let result = (|| -> Result<(), git2::Error> {
-            let repo = Repository::open(repo_path)?;
+            let repo = open_repo(&repo_path, work_tree.as_deref())?;
             let mut remote = repo.find_remote(&remote_name)?;
             let config = repo.config()?;
 
@@ -34,10 +48,29 @@ pub fn fetch_remote(repo_path: &str, remote_name: &str, auth_session: AuthSessio
 
             // Fetch heads and tags explicitly because libgit2 does not expand all refspecs by default.
             let heads = format!("refs/heads/*:refs/remotes/{remote_name}/*");
-            remote.fetch(&[heads.as_str(), "refs/tags/*:refs/tags/*"], Some(&mut fetch_options), None)?;
+            let refspecs = [heads.as_str(), "refs/tags/*:refs/tags/*"];
+            remote.fetch(&refspecs, Some(&mut fetch_options), None)?;
+
+            // libgit2 only takes a depth counted from the remote tips. The plain fetch above has
+            // brought those up to date, so the depth below them is measured before asking for more.
+            if let Some(by) = deepen_by {
+                fetch_options.depth(deepen_depth(&repo, &remote_name, by));
+                remote.fetch(&refspecs, Some(&mut fetch_options), None)?;
+            }
             Ok(())
         })();
 
-        network_result(network::FETCH(), &attempt, result)
+        network_result(label, &attempt, result)
     })
 }
+
+// Deepest the remote's branches currently reach, plus `by`.
+fn deepen_depth(repo: &Repository, remote_name: &str, by: usize) -> i32 {
+    let tips: Vec<Oid> = repo.references_glob(&format!("refs/remotes/{remote_name}/*")).map(|references| references.flatten().filter_map(|reference| reference.target()).collect()).unwrap_or_default();
+    let current = get_shallow_depth(repo, &get_shallow_boundaries(repo), &tips);
+    i32::try_from(current.saturating_add(by)).unwrap_or(i32::MAX)
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/actions/fetching.rs"]
+mod tests;
@@ -0,0 +1,201 @@
+use crate::helpers::process::{ProcessLog, push_line, run_logged};
+use git2::{Error, Repository};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+const COMMIT_EDITMSG: &str = "COMMIT_EDITMSG";
+const ZERO_OID: &str = "0000000000000000000000000000000000000000";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hook {
+    PreCommit,
+    PrepareCommitMsg,
+    CommitMsg,
+    PostCommit,
+    PreMergeCommit,
+    PostMerge,
+    PreRebase,
+    PrePush,
+}
+
+impl Hook {
+    pub fn name(self) -> &'static str {
+        match self {
+            Hook::PreCommit => "pre-commit",
+            Hook::PrepareCommitMsg => "prepare-commit-msg",
+            Hook::CommitMsg => "commit-msg",
+            Hook::PostCommit => "post-commit",
+            Hook::PreMergeCommit => "pre-merge-commit",
+            Hook::PostMerge => "post-merge",
+            Hook::PreRebase => "pre-rebase",
+            Hook::PrePush => "pre-push",
+        }
+    }
+
+    // Commit hooks see the index git is about to commit, like `git commit` exports it.
+    fn uses_index(self) -> bool {
+        matches!(self, Hook::PreCommit | Hook::PrepareCommitMsg | Hook::CommitMsg | Hook::PostCommit | Hook::PreMergeCommit)
+    }
+
+    // The hooks `--no-verify` bypasses; the rest run even when hooks are skipped.
+    pub fn is_verification(self) -> bool {
+        matches!(self, Hook::PreCommit | Hook::CommitMsg | Hook::PreMergeCommit | Hook::PreRebase | Hook::PrePush)
+    }
+}
+
+// A resolved hook invocation that can run on another thread without the repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HookRun {
+    pub hook: Hook,
+    pub program: PathBuf,
+    pub args: Vec<String>,
+    pub stdin: Vec<u8>,
+    pub cwd: PathBuf,
+    pub env: Vec<(String, String)>,
+}
+
+// `core.hooksPath` wins; relative paths resolve from the worktree root like git does.
+pub fn hooks_dir(repo: &Repository) -> PathBuf {
+    let base = repo.workdir().unwrap_or_else(|| repo.path()).to_path_buf();
+    match repo.config().ok().and_then(|config| config.get_path("core.hooksPath").ok()) {
+        Some(path) if path.is_absolute() => path,
+        Some(path) => base.join(path),
+        None => repo.commondir().join("hooks"),
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+// Missing or non-executable hooks are skipped, matching git.
+pub fn hook_run(repo: &Repository, hook: Hook, args: Vec<String>, stdin: Vec<u8>) -> Option<HookRun> {
+    let program = hooks_dir(repo).join(hook.name());
+    if !is_executable(&program) {
+        return None;
+    }
+
+    let cwd = repo.workdir().unwrap_or_else(|| repo.path()).to_path_buf();
+    let mut env = Vec::new();
+    if hook.uses_index() {
+        env.push(("GIT_INDEX_FILE".to_string(), repo.path().join("index").display().to_string()));
+        env.push(("GIT_EDITOR".to_string(), ":".to_string()));
+    }
+    Some(HookRun { hook, program, args, stdin, cwd, env })
+}
+
+impl HookRun {
+    // Runs the hook with stdout and stderr streamed into the log; a non-zero exit is an error.
+    pub fn run(&self, log: &ProcessLog) -> Result<(), Error> {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args).current_dir(&self.cwd).envs(self.env.iter().map(|(key, value)| (key, value)));
+        run_logged(command, &format!("the {} hook", self.hook.name()), Some(self.stdin.clone()), log)
+    }
+}
+
+// Runs hooks in order and stops at the first failure.
+pub fn run_hooks(runs: &[HookRun], log: &ProcessLog) -> Result<(), Error> {
+    for run in runs {
+        push_line(log, format!("> {}", run.hook.name()));
+        run.run(log)?;
+    }
+    Ok(())
+}
+
+pub fn commit_message_path(repo: &Repository) -> PathBuf {
+    repo.path().join(COMMIT_EDITMSG)
+}
+
+// Hooks that gate a commit; the message goes through COMMIT_EDITMSG when a message hook exists.
+pub fn commit_hook_runs(repo: &Repository, message: &str, is_verifying: bool) -> Result<Vec<HookRun>, Error> {
+    let message_path = commit_message_path(repo).display().to_string();
+    let runs: Vec<HookRun> = [
+        hook_run(repo, Hook::PreCommit, Vec::new(), Vec::new()),
+        hook_run(repo, Hook::PrepareCommitMsg, vec![message_path.clone(), "message".to_string()], Vec::new()),
+        hook_run(repo, Hook::CommitMsg, vec![message_path], Vec::new()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|run| is_verifying || !run.hook.is_verification())
+    .collect();
+
+    if runs.iter().any(|run| run.hook != Hook::PreCommit) {
+        fs::write(commit_message_path(repo), format!("{message}\n")).map_err(|error| Error::from_str(&format!("write {COMMIT_EDITMSG} failed: {error}")))?;
+    }
+    Ok(runs)
+}
+
+// Reads the message back after the hooks had their say, with git's whitespace cleanup.
+pub fn read_commit_message(repo: &Repository) -> Result<String, Error> {
+    let message = fs::read_to_string(commit_message_path(repo)).map_err(|error| Error::from_str(&format!("read {COMMIT_EDITMSG} failed: {error}")))?;
+    let lines: Vec<&str> = message.lines().map(str::trim_end).collect();
+    let mut cleaned: Vec<&str> = Vec::with_capacity(lines.len());
+    for line in lines {
+        if line.is_empty() && cleaned.last().is_none_or(|last| last.is_empty()) {
+            continue;
+        }
+        cleaned.push(line);
+    }
+    while cleaned.last().is_some_and(|line| line.is_empty()) {
+        cleaned.pop();
+    }
+    Ok(cleaned.join("\n"))
+}
+
+fn remote_tracking_oid(repo: &Repository, remote_name: &str, branch: &str) -> String {
+    repo.refname_to_id(&format!("refs/remotes/{remote_name}/{branch}")).map(|oid| oid.to_string()).unwrap_or_else(|_| ZERO_OID.to_string())
+}
+
+// What a push is about to update on the remote.
+pub enum PushedRef {
+    Branch { branch: String },
+    Tags,
+    Delete { branches: Vec<String> },
+}
+
+// `pre-push` gets the remote name and url as arguments and one line per updated ref on stdin.
+pub fn pre_push_run(repo: &Repository, remote_name: &str, pushed: PushedRef) -> Result<Option<HookRun>, Error> {
+    if !is_executable(&hooks_dir(repo).join(Hook::PrePush.name())) {
+        return Ok(None);
+    }
+
+    let remote = repo.find_remote(remote_name)?;
+    let url = remote.pushurl().or_else(|| remote.url()).unwrap_or_default().to_string();
+    let mut lines = Vec::new();
+    match pushed {
+        PushedRef::Branch { branch } => {
+            let refname = format!("refs/heads/{branch}");
+            let oid = repo.refname_to_id(&refname)?;
+            lines.push(format!("{refname} {oid} {refname} {}", remote_tracking_oid(repo, remote_name, &branch)));
+        },
+        PushedRef::Tags => {
+            for name in repo.tag_names(None)?.iter().flatten() {
+                let refname = format!("refs/tags/{name}");
+                // Annotated tags push the tag object itself, so its id is reported rather than the commit.
+                let oid = repo.refname_to_id(&refname)?;
+                lines.push(format!("{refname} {oid} {refname} {ZERO_OID}"));
+            }
+        },
+        PushedRef::Delete { branches } => {
+            for branch in branches {
+                lines.push(format!("(delete) {ZERO_OID} refs/heads/{branch} {}", remote_tracking_oid(repo, remote_name, &branch)));
+            }
+        },
+    }
+    let stdin = lines.iter().map(|line| format!("{line}\n")).collect::<String>().into_bytes();
+    Ok(hook_run(repo, Hook::PrePush, vec![remote_name.to_string(), url], stdin))
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/actions/hooks.rs"]
+mod tests;
@@ -0,0 +1,194 @@
+use crate::{git::actions::conflicts::ensure_clean_workdir, helpers::keymap::Command};
+use facet::Facet;
+use git2::{Error, Oid, Repository, build::CheckoutBuilder};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const STASH_REF: &str = "refs/stash";
+const REMOTES_PREFIX: &str = "refs/remotes/";
+
+#[derive(Facet, Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalRef {
+    pub name: String,
+    #[facet(default)]
+    pub before: Option<String>,
+    #[facet(default)]
+    pub after: Option<String>,
+}
+
+impl JournalRef {
+    pub fn is_deleted(&self) -> bool {
+        self.before.is_some() && self.after.is_none()
+    }
+
+    pub fn is_remote(&self) -> bool {
+        self.name.starts_with(REMOTES_PREFIX)
+    }
+}
+
+#[derive(Facet, Debug, Clone, Default, PartialEq, Eq)]
+pub struct JournalStash {
+    pub oid: String,
+    #[facet(default)]
+    pub message: String,
+}
+
+#[derive(Facet, Debug, Clone, PartialEq, Eq)]
+pub struct JournalEntry {
+    pub operation: Command,
+    pub time: i64,
+    #[facet(default)]
+    pub head_before: String,
+    #[facet(default)]
+    pub head_after: String,
+    #[facet(default)]
+    pub refs: Vec<JournalRef>,
+    #[facet(default)]
+    pub dropped_stashes: Vec<JournalStash>,
+}
+
+// Everything an undo might need to put back, captured around a single action.
+// HEAD is kept as either a symbolic ref name or a detached commit id.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RefState {
+    head: String,
+    refs: BTreeMap<String, Oid>,
+    stashes: Vec<(Oid, String)>,
+}
+
+pub fn capture_ref_state(repo: &Repository) -> Result<RefState, Error> {
+    let head = repo.find_reference("HEAD")?;
+    let head = match head.symbolic_target() {
+        Some(target) => target.to_string(),
+        None => head.target().map(|oid| oid.to_string()).unwrap_or_default(),
+    };
+
+    let mut refs = BTreeMap::new();
+    for reference in repo.references()? {
+        let reference = reference?;
+        let (Some(name), Some(oid)) = (reference.name(), reference.target()) else {
+            continue;
+        };
+        if name != STASH_REF {
+            refs.insert(name.to_string(), oid);
+        }
+    }
+
+    // The stash stack lives in the refs/stash reflog, newest entry first.
+    let stashes = match repo.reflog(STASH_REF) {
+        Ok(reflog) => reflog.iter().map(|entry| (entry.id_new(), entry.message().unwrap_or_default().to_string())).collect(),
+        Err(_) => Vec::new(),
+    };
+
+    Ok(RefState { head, refs, stashes })
+}
+
+// Returns None when the action left every ref and stash untouched.
+pub fn journal_entry(operation: Command, before: &RefState, after: &RefState) -> Option<JournalEntry> {
+    let names: BTreeSet<&String> = before.refs.keys().chain(after.refs.keys()).collect();
+    let refs: Vec<JournalRef> = names
+        .into_iter()
+        .filter_map(|name| {
+            let old = before.refs.get(name);
+            let new = after.refs.get(name);
+            (old != new).then(|| JournalRef { name: name.clone(), before: old.map(Oid::to_string), after: new.map(Oid::to_string) })
+        })
+        .collect();
+
+    let dropped_stashes: Vec<JournalStash> =
+        before.stashes.iter().filter(|(oid, _)| !after.stashes.iter().any(|(kept, _)| kept == oid)).map(|(oid, message)| JournalStash { oid: oid.to_string(), message: message.clone() }).collect();
+
+    if refs.is_empty() && dropped_stashes.is_empty() && before.head == after.head {
+        return None;
+    }
+
+    let time = SystemTime::now().duration_since(UNIX_EPOCH).map(|duration| duration.as_secs() as i64).unwrap_or_default();
+    Some(JournalEntry { operation, time, head_before: before.head.clone(), head_after: after.head.clone(), refs, dropped_stashes })
+}
+
+fn parse_oid(value: &Option<String>) -> Result<Option<Oid>, Error> {
+    value.as_deref().map(Oid::from_str).transpose()
+}
+
+fn resolve_head(repo: &Repository, head: &str) -> Option<Oid> {
+    if head.starts_with("refs/") { repo.refname_to_id(head).ok() } else { Oid::from_str(head).ok() }
+}
+
+// Puts local refs, HEAD and dropped stashes back to how they were before the entry.
+// Remote-tracking refs are left alone: the remote itself has already moved, so only a
+// new push can restore it.
+pub fn undo_journal_entry(repo: &Repository, entry: &JournalEntry) -> Result<(), Error> {
+    let state = capture_ref_state(repo)?;
+    let local_refs: Vec<&JournalRef> = entry.refs.iter().filter(|journal_ref| !journal_ref.is_remote()).collect();
+
+    // Refuse to clobber anything that moved after the entry was recorded.
+    if state.head != entry.head_after {
+        return Err(Error::from_str("HEAD has moved since this operation"));
+    }
+    for journal_ref in &local_refs {
+        if state.refs.get(&journal_ref.name).copied() != parse_oid(&journal_ref.after)? {
+            return Err(Error::from_str(&format!("{} has moved since this operation", journal_ref.name)));
+        }
+    }
+
+    // Pushes and remote deletions only moved the remote, which only a new push can put back.
+    if local_refs.is_empty() && entry.dropped_stashes.is_empty() && entry.head_before == entry.head_after {
+        return Err(Error::from_str("this operation only changed the remote and cannot be undone"));
+    }
+
+    let current_commit = repo.head().ok().and_then(|head| head.target());
+    let restored_refs: BTreeMap<&str, Option<Oid>> = local_refs.iter().map(|journal_ref| Ok((journal_ref.name.as_str(), parse_oid(&journal_ref.before)?))).collect::<Result<_, Error>>()?;
+    let restored_commit = if entry.head_before.starts_with("refs/") {
+        restored_refs.get(entry.head_before.as_str()).copied().unwrap_or_else(|| resolve_head(repo, &entry.head_before))
+    } else {
+        resolve_head(repo, &entry.head_before)
+    };
+    // A safe checkout happens before any ref moves, so a conflict leaves everything as it was.
+    if current_commit != restored_commit
+        && let Some(oid) = restored_commit
+    {
+        ensure_clean_workdir(repo, "undoing")?;
+        repo.checkout_tree(repo.find_commit(oid)?.as_object(), Some(CheckoutBuilder::default().safe()))?;
+    }
+
+    let message = "guitar: undo";
+    for (name, oid) in &restored_refs {
+        if let Some(oid) = oid {
+            repo.reference(name, *oid, true, message)?;
+        }
+    }
+
+    if entry.head_before.starts_with("refs/") {
+        repo.set_head(&entry.head_before)?;
+    } else if let Some(oid) = restored_commit {
+        repo.set_head_detached(oid)?;
+    }
+
+    // Deletions wait until HEAD no longer points at the ref being removed.
+    for (name, oid) in &restored_refs {
+        if oid.is_none()
+            && let Ok(mut reference) = repo.find_reference(name)
+        {
+            reference.delete()?;
+        }
+    }
+
+    // Re-pushing onto refs/stash with a reflog entry is what `git stash store` does.
+    if !entry.dropped_stashes.is_empty() {
+        repo.reference_ensure_log(STASH_REF)?;
+    }
+    for stash in entry.dropped_stashes.iter().rev() {
+        let oid = Oid::from_str(&stash.oid)?;
+        if !state.stashes.iter().any(|(existing, _)| *existing == oid) {
+            repo.reference(STASH_REF, oid, true, &stash.message)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/actions/journal.rs"]
+mod tests;
@@ -1,5 +1,8 @@
 use crate::git::{
-    actions::conflicts::{ensure_clean_workdir, mark_conflicts_resolved_from_workdir},
+    actions::{
+        conflicts::{ensure_clean_workdir, mark_conflicts_resolved_from_workdir},
+        signing::commit_to_head,
+    },
     queries::commits::get_current_branch,
 };
 use git2::{Error, MergeAnalysis, MergeOptions, Oid, Repository, RepositoryState, build::CheckoutBuilder};
@@ -10,6 +13,7 @@ pub enum MergeOutcome {
     Completed { oid: Oid },
     FastForward { oid: Oid },
     UpToDate,
+    Staged,
     Conflict,
     Aborted,
 }
@@ -55,7 +59,7 @@ fn commit_merge(repo: &Repository, target_oid: Oid) -> Result<Oid, Error> {
     let head_commit = repo.head()?.peel_to_commit()?;
     let target_commit = repo.find_commit(target_oid)?;
     let message = merge_message(repo, target_oid);
-    let oid = repo.commit(Some("HEAD"), &signature, &signature, &message, &tree, &[&head_commit, &target_commit])?;
+    let oid = commit_to_head(repo, "merge", &signature, &signature, &message, &tree, &[&head_commit, &target_commit])?;
 
     repo.cleanup_state()?;
     let mut checkout = force_checkout_options();
@@ -75,7 +79,7 @@ fn fast_forward(repo: &Repository, target_oid: Oid) -> Result<MergeOutcome, Erro
     Ok(MergeOutcome::FastForward { oid: target_oid })
 }
 
-fn normal_merge(repo: &Repository, target_oid: Oid) -> Result<MergeOutcome, Error> {
+fn normal_merge(repo: &Repository, target_oid: Oid, is_committing: bool) -> Result<MergeOutcome, Error> {
     let target = repo.find_annotated_commit(target_oid)?;
     let mut merge_opts = merge_options();
     let mut checkout_opts = checkout_options();
@@ -84,6 +88,9 @@ fn normal_merge(repo: &Repository, target_oid: Oid) -> Result<MergeOutcome, Erro
     if repo.index()?.has_conflicts() {
         return Ok(MergeOutcome::Conflict);
     }
+    if !is_committing {
+        return Ok(MergeOutcome::Staged);
+    }
 
     commit_merge(repo, target_oid).map(|oid| MergeOutcome::Completed { oid })
 }
@@ -93,6 +100,15 @@ fn merge_is_possible(analysis: MergeAnalysis) -> bool {
 }
 
 pub fn start_merge(repo: &Repository, target_oid: Oid) -> Result<MergeOutcome, Error> {
+    merge(repo, target_oid, true)
+}
+
+// Leaves a clean merge staged with MERGE_HEAD in place, so a hook can vet it before `continue_merge` commits.
+pub fn stage_merge(repo: &Repository, target_oid: Oid) -> Result<MergeOutcome, Error> {
+    merge(repo, target_oid, false)
+}
+
+fn merge(repo: &Repository, target_oid: Oid, is_committing: bool) -> Result<MergeOutcome, Error> {
     if get_current_branch(repo).is_none() {
         return Err(Error::from_str("merging requires a checked-out local branch"));
     }
@@ -118,7 +134,7 @@ pub fn start_merge(repo: &Repository, target_oid: Oid) -> Result<MergeOutcome, E
         return fast_forward(repo, target_oid);
     }
 
-    normal_merge(repo, target_oid)
+    normal_merge(repo, target_oid, is_committing)
 }
 
 pub fn continue_merge(repo: &Repository) -> Result<MergeOutcome, Error> {
@@ -1,20 +1,21 @@
 use crate::git::{
     actions::{
-        fetching::fetch_remote,
-        pushing::{delete_remote_branch, push_branch, push_tags},
+        fetching::{deepen_remote, fetch_remote},
+        pushing::{delete_remote_branches, push_branch, push_tags},
         submodules::update_submodule,
     },
     auth::{AuthSession, NetworkResult},
 };
 use crate::helpers::localisation::network;
-use std::thread;
+use std::{path::PathBuf, thread};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum NetworkRequest {
     Fetch { repo_path: String, remote_name: String },
+    Deepen { repo_path: String, remote_name: String, by: usize },
     PushBranch { repo_path: String, remote_name: String, branch: String, force: bool },
     PushTags { repo_path: String, remote_name: String },
-    DeleteRemoteBranch { repo_path: String, remote_name: String, branch: String },
+    DeleteRemoteBranches { repo_path: String, remote_name: String, branches: Vec<String> },
     UpdateSubmodule { repo_path: String, name: String },
 }
 
@@ -22,9 +23,10 @@ impl NetworkRequest {
     pub fn label(&self) -> &'static str {
         match self {
             NetworkRequest::Fetch { .. } => network::FETCH(),
+            NetworkRequest::Deepen { .. } => network::DEEPEN(),
             NetworkRequest::PushBranch { .. } => network::PUSH(),
             NetworkRequest::PushTags { .. } => network::PUSH_TAGS(),
-            NetworkRequest::DeleteRemoteBranch { .. } => network::DELETE_REMOTE_BRANCH(),
+            NetworkRequest::DeleteRemoteBranches { .. } => network::DELETE_REMOTE_BRANCH(),
             NetworkRequest::UpdateSubmodule { .. } => network::UPDATE_SUBMODULE(),
         }
     }
@@ -32,6 +34,7 @@ impl NetworkRequest {
     pub fn progress_message(&self) -> String {
         match self {
             NetworkRequest::Fetch { remote_name, .. } => network::fetching(remote_name),
+            NetworkRequest::Deepen { remote_name, by, .. } => network::deepening(remote_name, *by),
             NetworkRequest::PushBranch { remote_name, branch, force, .. } => {
                 if *force {
                     network::force_pushing(branch, remote_name)
@@ -40,18 +43,20 @@ impl NetworkRequest {
                 }
             },
             NetworkRequest::PushTags { remote_name, .. } => network::pushing_tags(remote_name),
-            NetworkRequest::DeleteRemoteBranch { remote_name, branch, .. } => network::deleting_remote_branch(remote_name, branch),
+            NetworkRequest::DeleteRemoteBranches { remote_name, branches, .. } => network::deleting_remote_branches(remote_name, branches),
             NetworkRequest::UpdateSubmodule { name, .. } => network::updating_submodule(name),
         }
     }
 
-    pub fn spawn(&self, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
+    // `work_tree` is the one the repository was opened with, for `--work-tree` and bare repositories.
+    pub fn spawn(&self, work_tree: Option<PathBuf>, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
         match self {
-            NetworkRequest::Fetch { repo_path, remote_name } => fetch_remote(repo_path, remote_name, auth_session),
-            NetworkRequest::PushBranch { repo_path, remote_name, branch, force } => push_branch(repo_path, remote_name, branch, *force, auth_session),
-            NetworkRequest::PushTags { repo_path, remote_name } => push_tags(repo_path, remote_name, auth_session),
-            NetworkRequest::DeleteRemoteBranch { repo_path, remote_name, branch } => delete_remote_branch(repo_path, remote_name, branch, auth_session),
-            NetworkRequest::UpdateSubmodule { repo_path, name } => update_submodule(repo_path, name, auth_session),
+            NetworkRequest::Fetch { repo_path, remote_name } => fetch_remote(repo_path, work_tree, remote_name, auth_session),
+            NetworkRequest::Deepen { repo_path, remote_name, by } => deepen_remote(repo_path, work_tree, remote_name, *by, auth_session),
+            NetworkRequest::PushBranch { repo_path, remote_name, branch, force } => push_branch(repo_path, work_tree, remote_name, branch, *force, auth_session),
+            NetworkRequest::PushTags { repo_path, remote_name } => push_tags(repo_path, work_tree, remote_name, auth_session),
+            NetworkRequest::DeleteRemoteBranches { repo_path, remote_name, branches } => delete_remote_branches(repo_path, work_tree, remote_name, branches, auth_session),
+            NetworkRequest::UpdateSubmodule { repo_path, name } => update_submodule(repo_path, work_tree, name, auth_session),
         }
     }
 }
@@ -0,0 +1,301 @@
+use crate::git::actions::{
+    conflicts::ensure_clean_workdir,
+    sequencing::{SequenceKind, order_sequence},
+    signing::commit_to_head,
+};
+use chrono::DateTime;
+use git2::{ApplyLocation, Diff, DiffOptions, Email, EmailCreateOptions, Error, Oid, Repository, Signature, Time};
+use std::{fs, path::Path};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatchTarget {
+    Workdir,
+    Index,
+    Commit,
+}
+
+// One message of a mailbox, or the whole file for a plain `git diff` patch.
+// The diff stays raw bytes so binary patches and non-UTF-8 content apply unchanged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchMail {
+    pub author: Option<(String, String)>,
+    pub time: Option<Time>,
+    pub subject: String,
+    pub body: String,
+    pub diff: Vec<u8>,
+}
+
+impl PatchMail {
+    pub fn message(&self) -> String {
+        if self.body.is_empty() { format!("{}\n", self.subject) } else { format!("{}\n\n{}\n", self.subject, self.body) }
+    }
+}
+
+// Renders commits as a `git format-patch --stdout` series, oldest first and numbered.
+pub fn export_patches(repo: &Repository, oids: &[Oid]) -> Result<Vec<u8>, Error> {
+    let ordered = order_sequence(repo, SequenceKind::Cherrypick, oids)?;
+    if ordered.is_empty() {
+        return Err(Error::from_str("no commits to export"));
+    }
+
+    let mut mbox = Vec::new();
+    for (idx, oid) in ordered.iter().enumerate() {
+        let commit = repo.find_commit(*oid)?;
+        if commit.parent_count() > 1 {
+            return Err(Error::from_str(&format!("{} is a merge commit and has no single patch", &oid.to_string()[..7])));
+        }
+
+        let parent_tree = commit.parents().next().map(|parent| parent.tree()).transpose()?;
+        // Binary files go out as `GIT binary patch` hunks instead of "Binary files differ".
+        let mut diff_options = DiffOptions::new();
+        diff_options.show_binary(true);
+        let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&commit.tree()?), Some(&mut diff_options))?;
+        let summary = commit.summary().unwrap_or_default().to_string();
+        // Quoted like mboxrd so `>From ` lines read back unchanged.
+        let body = commit.body().unwrap_or_default().trim().lines().map(escape_mboxrd).collect::<Vec<_>>().join("\n");
+        let mut options = EmailCreateOptions::new();
+        let email = Email::from_diff(&diff, idx + 1, ordered.len(), oid, summary.as_str(), body.as_str(), &commit.author(), &mut options)?;
+        mbox.extend_from_slice(email.as_slice());
+    }
+    Ok(mbox)
+}
+
+pub fn write_patches(repo: &Repository, oids: &[Oid], path: &Path) -> Result<usize, Error> {
+    let mbox = export_patches(repo, oids)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|error| Error::from_str(&format!("create {} failed: {error}", parent.display())))?;
+    }
+    fs::write(path, &mbox).map_err(|error| Error::from_str(&format!("write {} failed: {error}", path.display())))?;
+    Ok(split_mbox(&mbox).len())
+}
+
+// Mailbox messages start with a `From <sha> <date>` line after a blank one; anything else is
+// a single patch.
+pub fn split_mbox(contents: &[u8]) -> Vec<PatchMail> {
+    let mut messages: Vec<Vec<&[u8]>> = Vec::new();
+    let mut previous: &[u8] = b"";
+    for line in lines(contents) {
+        let sender = text(line).strip_prefix(b"From ").and_then(|rest| rest.split(u8::is_ascii_whitespace).find(|word| !word.is_empty())).unwrap_or_default();
+        let is_separator = previous.is_empty() && (sender.len() == 40 && sender.iter().all(u8::is_ascii_hexdigit) || sender.contains(&b'@'));
+        previous = text(line);
+        if is_separator || messages.is_empty() {
+            messages.push(Vec::new());
+        }
+        if let Some(message) = messages.last_mut() {
+            message.push(line);
+        }
+    }
+
+    messages.into_iter().map(|lines| parse_mail(&lines)).filter(|mail| !mail.diff.trim_ascii().is_empty()).collect()
+}
+
+// Lines without their `\n`, like `str::lines`, over bytes that need not be UTF-8.
+fn lines(contents: &[u8]) -> Vec<&[u8]> {
+    let contents = contents.strip_suffix(b"\n").unwrap_or(contents);
+    if contents.is_empty() { Vec::new() } else { contents.split(|&byte| byte == b'\n').collect() }
+}
+
+// A line as mail headers and separators see it, without a trailing `\r`.
+fn text(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+fn parse_mail(lines: &[&[u8]]) -> PatchMail {
+    let mut mail = PatchMail { author: None, time: None, subject: String::new(), body: String::new(), diff: Vec::new() };
+    let diff_start = lines.iter().position(|line| line.starts_with(b"diff --git ") || line.starts_with(b"--- ")).unwrap_or(lines.len());
+    let mut idx = usize::from(lines.first().is_some_and(|line| line.starts_with(b"From ")));
+
+    // Headers run until the first blank line; folded headers continue on indented lines.
+    let mut headers: Vec<String> = Vec::new();
+    while idx < diff_start && !text(lines[idx]).is_empty() {
+        let line = String::from_utf8_lossy(text(lines[idx]));
+        match headers.last_mut() {
+            Some(header) if line.starts_with([' ', '\t']) => header.push_str(&format!(" {}", line.trim())),
+            _ => headers.push(line.into_owned()),
+        }
+        idx += 1;
+    }
+    for header in &headers {
+        if let Some(value) = header.strip_prefix("From: ") {
+            mail.author = parse_author(&decode_encoded_words(value));
+        } else if let Some(value) = header.strip_prefix("Date: ") {
+            mail.time = DateTime::parse_from_rfc2822(value.trim()).ok().map(|date| Time::new(date.timestamp(), date.offset().local_minus_utc() / 60));
+        } else if let Some(value) = header.strip_prefix("Subject: ") {
+            mail.subject = strip_patch_prefix(decode_encoded_words(value.trim()).as_str()).to_string();
+        }
+    }
+
+    // The message ends at the `---` line that introduces the diffstat.
+    let body_end = lines[idx.min(diff_start)..diff_start].iter().position(|line| text(line) == b"---").map_or(diff_start, |end| idx + end);
+    let body: Vec<&[u8]> = lines[idx.min(body_end)..body_end].iter().map(|line| unescape_mboxrd(line)).collect();
+    mail.body = String::from_utf8_lossy(&body.join(&b'\n')).trim().to_string();
+
+    let mut diff_end = lines.len();
+    if let Some(signature) = lines[diff_start..].iter().rposition(|line| matches!(text(line), b"--" | b"-- "))
+        && lines[diff_start + signature + 1..].iter().filter(|line| !text(line).is_empty()).count() <= 1
+    {
+        diff_end = diff_start + signature;
+    }
+    if diff_start < diff_end {
+        mail.diff = lines[diff_start..diff_end].join(&b'\n');
+        mail.diff.push(b'\n');
+    }
+    mail
+}
+
+fn escape_mboxrd(line: &str) -> String {
+    if line.trim_start_matches('>').starts_with("From ") { format!(">{line}") } else { line.to_string() }
+}
+
+// mboxrd quotes body lines matching `>*From ` with one more `>`, so one comes off again.
+fn unescape_mboxrd(line: &[u8]) -> &[u8] {
+    let quotes = line.iter().take_while(|&&byte| byte == b'>').count();
+    if quotes > 0 && line[quotes..].starts_with(b"From ") { &line[1..] } else { line }
+}
+
+// `git format-patch` writes non-ASCII names and subjects as RFC 2047 encoded-words, such as
+// `=?UTF-8?q?J=C3=B6rg?=`. Whitespace between two encoded-words is not part of the text.
+fn decode_encoded_words(value: &str) -> String {
+    let mut decoded = String::new();
+    let mut rest = value;
+    let mut follows_word = false;
+    while let Some(start) = rest.find("=?") {
+        let before = &rest[..start];
+        match parse_encoded_word(&rest[start..]) {
+            Some((word, after)) => {
+                if !(follows_word && before.trim().is_empty()) {
+                    decoded.push_str(before);
+                }
+                decoded.push_str(&word);
+                follows_word = true;
+                rest = after;
+            },
+            None => {
+                decoded.push_str(&rest[..start + 2]);
+                follows_word = false;
+                rest = &rest[start + 2..];
+            },
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+// Splits one `=?charset?encoding?text?=` word off the front; malformed words stay raw.
+fn parse_encoded_word(value: &str) -> Option<(String, &str)> {
+    let inner = value.strip_prefix("=?")?;
+    let (charset, inner) = inner.split_once('?')?;
+    let (encoding, inner) = inner.split_once('?')?;
+    let (text, after) = inner.split_once("?=")?;
+    if text.contains(char::is_whitespace) {
+        return None;
+    }
+    let bytes = match encoding {
+        "Q" | "q" => decode_q(text)?,
+        "B" | "b" => decode_base64(text)?,
+        _ => return None,
+    };
+    // RFC 2231 allows a language after the charset, as in `UTF-8*en`.
+    let charset = charset.split('*').next().unwrap_or(charset);
+    let decoded = match encoding_rs::Encoding::for_label(charset.as_bytes()) {
+        Some(encoding) => encoding.decode_without_bom_handling(&bytes).0.into_owned(),
+        None => String::from_utf8_lossy(&bytes).into_owned(),
+    };
+    Some((decoded, after))
+}
+
+fn decode_q(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut input = text.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'_' => bytes.push(b' '),
+            b'=' => {
+                let hex = [input.next()?, input.next()?];
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            },
+            _ => bytes.push(byte),
+        }
+    }
+    Some(bytes)
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let (mut buffer, mut bits) = (0u32, 0);
+    for byte in text.bytes().take_while(|&byte| byte != b'=') {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return None,
+        };
+        buffer = (buffer << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+fn parse_author(value: &str) -> Option<(String, String)> {
+    let (name, rest) = value.split_once('<')?;
+    let email = rest.split_once('>')?.0;
+    Some((name.trim().trim_matches('"').to_string(), email.trim().to_string()))
+}
+
+fn strip_patch_prefix(subject: &str) -> &str {
+    match subject.strip_prefix('[').and_then(|rest| rest.split_once(']')) {
+        Some((prefix, rest)) if prefix.contains("PATCH") => rest.trim_start(),
+        _ => subject,
+    }
+}
+
+// Applies every patch of a file in order; `Commit` records each one like `git am`.
+pub fn apply_patches(repo: &Repository, path: &Path, target: PatchTarget, name: &str, email: &str) -> Result<usize, Error> {
+    let contents = fs::read(path).map_err(|error| Error::from_str(&format!("read {} failed: {error}", path.display())))?;
+    let mails = split_mbox(&contents);
+    if mails.is_empty() {
+        return Err(Error::from_str("file contains no patches"));
+    }
+    if target == PatchTarget::Commit {
+        ensure_clean_workdir(repo, "applying patches")?;
+    }
+
+    for (idx, mail) in mails.iter().enumerate() {
+        let diff = Diff::from_buffer(&mail.diff)?;
+        let location = match target {
+            PatchTarget::Workdir => ApplyLocation::WorkDir,
+            PatchTarget::Index => ApplyLocation::Index,
+            PatchTarget::Commit => ApplyLocation::Both,
+        };
+        repo.apply(&diff, location, None).map_err(|error| Error::from_str(&format!("patch {}/{} \"{}\" does not apply: {}", idx + 1, mails.len(), mail.subject, error.message())))?;
+        if target == PatchTarget::Commit {
+            commit_mail(repo, mail, name, email)?;
+        }
+    }
+    Ok(mails.len())
+}
+
+fn commit_mail(repo: &Repository, mail: &PatchMail, name: &str, email: &str) -> Result<Oid, Error> {
+    let (author_name, author_email) = mail.author.clone().ok_or_else(|| Error::from_str("patch has no author, apply it to the index instead"))?;
+    let author = match mail.time {
+        Some(time) => Signature::new(&author_name, &author_email, &time)?,
+        None => Signature::now(&author_name, &author_email)?,
+    };
+    let committer = Signature::now(name, email)?;
+
+    let mut index = repo.index()?;
+    let tree = repo.find_tree(index.write_tree()?)?;
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    commit_to_head(repo, "am", &author, &committer, &mail.message(), &tree, &parents)
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/actions/patching.rs"]
+mod tests;
@@ -1,9 +1,12 @@
 use crate::{
-    git::auth::{AuthAttempt, AuthSession, NetworkResult, network_result},
+    git::{
+        auth::{AuthAttempt, AuthSession, NetworkResult, network_result},
+        os::path::open_repo,
+    },
     helpers::localisation::network,
 };
-use git2::{PushOptions, RemoteCallbacks, Repository};
-use std::thread;
+use git2::{PushOptions, RemoteCallbacks};
+use std::{path::PathBuf, thread};
 
 fn auth_callbacks<'a>(attempt: AuthAttempt, config: git2::Config) -> RemoteCallbacks<'a> {
     let mut callbacks = RemoteCallbacks::new();
@@ -23,7 +26,7 @@ fn auth_push_callbacks<'a>(attempt: AuthAttempt, config: git2::Config) -> Remote
 }
 
 // Pushes are threaded so network latency does not have to live inside command handlers.
-pub fn push_branch(repo_path: &str, remote_name: &str, branch: &str, force: bool, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
+pub fn push_branch(repo_path: &str, work_tree: Option<PathBuf>, remote_name: &str, branch: &str, force: bool, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
     // Own the inputs before crossing the thread boundary.
     let repo_path = repo_path.to_string();
     let remote_name = remote_name.to_string();
@@ -32,7 +35,7 @@ pub fn push_branch(repo_path: &str, remote_name: &str, branch: &str, force: bool
     thread::spawn(move || {
         let attempt = AuthAttempt::new(auth_session, network::PUSH());
         let result = (|| -> Result<(), git2::Error> {
-            let repo = Repository::open(&repo_path)?;
+            let repo = open_repo(&repo_path, work_tree.as_deref())?;
             let mut remote = repo.find_remote(&remote_name)?;
             let config = repo.config()?;
 
@@ -54,14 +57,14 @@ pub fn push_branch(repo_path: &str, remote_name: &str, branch: &str, force: bool
     })
 }
 
-pub fn push_tags(repo_path: &str, remote_name: &str, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
+pub fn push_tags(repo_path: &str, work_tree: Option<PathBuf>, remote_name: &str, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
     let repo_path = repo_path.to_string();
     let remote_name = remote_name.to_string();
 
     thread::spawn(move || {
         let attempt = AuthAttempt::new(auth_session, network::PUSH_TAGS());
         let result = (|| -> Result<(), git2::Error> {
-            let repo = Repository::open(&repo_path)?;
+            let repo = open_repo(&repo_path, work_tree.as_deref())?;
             let mut remote = repo.find_remote(&remote_name)?;
             let config = repo.config()?;
 
@@ -85,25 +88,25 @@ pub fn push_tags(repo_path: &str, remote_name: &str, auth_session: AuthSession)
     })
 }
 
-pub fn delete_remote_branch(repo_path: &str, remote_name: &str, branch: &str, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
+pub fn delete_remote_branches(repo_path: &str, work_tree: Option<PathBuf>, remote_name: &str, branches: &[String], auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
     let repo_path = repo_path.to_string();
     let remote_name = remote_name.to_string();
-    let branch = branch.to_string();
+    let branches = branches.to_vec();
 
     thread::spawn(move || {
         let attempt = AuthAttempt::new(auth_session, network::DELETE_REMOTE_BRANCH());
         let result = (|| -> Result<(), git2::Error> {
-            let repo = Repository::open(&repo_path)?;
+            let repo = open_repo(&repo_path, work_tree.as_deref())?;
             let mut remote = repo.find_remote(&remote_name)?;
             let config = repo.config()?;
 
             let mut push_options = PushOptions::new();
             push_options.remote_callbacks(auth_push_callbacks(attempt.clone(), config));
 
-            // An empty source refspec asks the remote to delete the destination branch.
-            let refspec = format!(":refs/heads/{}", branch);
+            // An empty source refspec asks the remote to delete the destination branch; every branch goes in one push.
+            let refspecs: Vec<String> = branches.iter().map(|branch| format!(":refs/heads/{branch}")).collect();
 
-            remote.push(&[&refspec], Some(&mut push_options))?;
+            remote.push(&refspecs, Some(&mut push_options))?;
 
             Ok(())
         })();
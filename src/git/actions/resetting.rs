@@ -1,6 +1,12 @@
 use std::path::Path;
 
-use git2::{Error, Oid, Repository, ResetType};
+use git2::{BranchType, Error, Oid, Repository, ResetType, Status, StatusOptions};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResetPreview {
+    pub unreachable: Vec<Oid>,
+    pub overwritten: Vec<String>,
+}
 
 pub fn reset_to_commit(repo: &Repository, target: Oid, reset_type: ResetType) -> Result<(), Error> {
     let target_commit = repo.find_commit(target)?;
@@ -25,6 +31,77 @@ pub fn reset_to_commit(repo: &Repository, target: Oid, reset_type: ResetType) ->
     Ok(())
 }
 
+// What resetting the current branch to `target` would lose: commits only reachable from
+// HEAD, and local changes a hard reset rewrites, including untracked files in the way.
+pub fn reset_preview(repo: &Repository, target: Oid) -> Result<ResetPreview, Error> {
+    let target_tree = repo.find_commit(target)?.tree()?;
+
+    let mut walk = repo.revwalk()?;
+    walk.push_head()?;
+    walk.hide(target)?;
+    let unreachable = walk.collect::<Result<Vec<_>, _>>()?;
+
+    let mut options = StatusOptions::new();
+    options.include_untracked(true).recurse_untracked_dirs(true).renames_head_to_index(false).renames_index_to_workdir(false);
+    let mut overwritten = Vec::new();
+    for entry in repo.statuses(Some(&mut options))?.iter() {
+        let Some(path) = entry.path() else { continue };
+        let status = entry.status();
+        let is_overwritten = if status == Status::WT_NEW { target_tree.get_path(Path::new(path)).is_ok() } else { !status.is_ignored() };
+        if is_overwritten {
+            overwritten.push(path.to_string());
+        }
+    }
+    overwritten.sort();
+
+    Ok(ResetPreview { unreachable, overwritten })
+}
+
+// Whether HEAD of the main worktree or any linked one points at `ref_name`.
+fn is_checked_out_anywhere(repo: &Repository, ref_name: &str) -> Result<bool, Error> {
+    let owner = Repository::open(repo.commondir())?;
+    let points_at = |repo: &Repository| repo.find_reference("HEAD").ok().and_then(|head| head.symbolic_target().map(|target| target == ref_name)).unwrap_or(false);
+    if !owner.is_bare() && points_at(&owner) {
+        return Ok(true);
+    }
+    for name in owner.worktrees()?.iter().flatten() {
+        let Ok(worktree) = owner.find_worktree(name) else { continue };
+        if Repository::open_from_worktree(&worktree).is_ok_and(|linked| points_at(&linked)) {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+// Moves a branch that is not checked out; the index and worktree belong to HEAD, so only the ref changes.
+pub fn reset_branch(repo: &Repository, branch_name: &str, target: Oid) -> Result<(), Error> {
+    let branch = repo.find_branch(branch_name, BranchType::Local)?;
+    if branch.is_head() {
+        return Err(Error::from_str("the checked-out branch is reset through HEAD"));
+    }
+    // Another worktree's index and files follow its HEAD, so moving the ref under it would leave them stale.
+    if is_checked_out_anywhere(repo, branch.get().name().unwrap_or_default())? {
+        return Err(Error::from_str("the branch is checked out in another worktree"));
+    }
+    repo.find_commit(target)?;
+    let mut reference = branch.into_reference();
+    reference.set_target(target, "reset branch to commit")?;
+    Ok(())
+}
+
+// Commits only `branch_name` reaches that moving it to `target` would leave behind.
+pub fn reset_branch_preview(repo: &Repository, branch_name: &str, target: Oid) -> Result<ResetPreview, Error> {
+    let tip = repo.find_branch(branch_name, BranchType::Local)?.get().peel_to_commit()?.id();
+    repo.find_commit(target)?;
+
+    let mut walk = repo.revwalk()?;
+    walk.push(tip)?;
+    walk.hide(target)?;
+    let unreachable = walk.collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ResetPreview { unreachable, overwritten: Vec::new() })
+}
+
 // Reset one path to HEAD, removing both staged and working tree changes for that file.
 pub fn reset_file(repo: &Repository, path: &Path) -> Result<(), Error> {
     // Remove any staged entry first so checkout_tree can restore a clean copy from HEAD.
@@ -40,3 +117,7 @@ pub fn reset_file(repo: &Repository, path: &Path) -> Result<(), Error> {
 
     Ok(())
 }
+
+#[cfg(test)]
+#[path = "../../tests/git/actions/resetting.rs"]
+mod tests;
@@ -1,4 +1,8 @@
-use crate::git::actions::conflicts::{ensure_clean_workdir, mark_conflicts_resolved_from_workdir};
+use crate::git::actions::{
+    conflicts::{ensure_clean_workdir, mark_conflicts_resolved_from_workdir},
+    sequencing::{SequenceKind, validate_mainline},
+    signing::commit_to_head,
+};
 use git2::{Error, Oid, Repository, RepositoryState, RevertOptions, build::CheckoutBuilder};
 use std::{fs, path::PathBuf};
 
@@ -36,12 +40,15 @@ fn read_message(repo: &Repository) -> String {
         .unwrap_or_else(|| "reverted: Revert commit".to_string())
 }
 
-fn revert_options<'a>() -> RevertOptions<'a> {
+fn revert_options<'a>(mainline: Option<u32>) -> RevertOptions<'a> {
     let mut checkout = CheckoutBuilder::new();
     checkout.allow_conflicts(true).conflict_style_merge(true);
 
     let mut opts = RevertOptions::new();
     opts.checkout_builder(checkout);
+    if let Some(mainline) = mainline {
+        opts.mainline(mainline);
+    }
     opts
 }
 
@@ -52,7 +59,7 @@ fn commit_index(repo: &Repository, message: &str) -> Result<Oid, Error> {
     let sig = repo.signature()?;
     let head_commit = repo.head()?.peel_to_commit()?;
     let parents = [&head_commit];
-    let oid = repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents)?;
+    let oid = commit_to_head(repo, "revert", &sig, &sig, message, &tree, &parents)?;
     repo.cleanup_state()?;
     cleanup_message(repo);
     repo.checkout_head(Some(CheckoutBuilder::default().force()))?;
@@ -60,19 +67,21 @@ fn commit_index(repo: &Repository, message: &str) -> Result<Oid, Error> {
 }
 
 pub fn start_revert(repo: &Repository, commit_oid: Oid, message: &str) -> Result<RevertOutcome, Error> {
+    start_revert_with_mainline(repo, commit_oid, None, message)
+}
+
+pub fn start_revert_with_mainline(repo: &Repository, commit_oid: Oid, mainline: Option<u32>, message: &str) -> Result<RevertOutcome, Error> {
     if is_revert_in_progress(repo) {
         return Err(Error::from_str("revert already in progress"));
     }
     ensure_clean_workdir(repo, "reverting")?;
 
     let commit = repo.find_commit(commit_oid)?;
-    if commit.parent_count() > 1 {
-        return Err(Error::from_str("reverting merge commits is not supported"));
-    }
+    validate_mainline(SequenceKind::Revert, &commit, mainline)?;
 
     persist_message(repo, message)?;
 
-    let mut opts = revert_options();
+    let mut opts = revert_options(mainline);
     if let Err(error) = repo.revert(&commit, Some(&mut opts)) {
         cleanup_message(repo);
         return Err(error);
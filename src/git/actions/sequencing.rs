@@ -0,0 +1,284 @@
+use crate::git::actions::{
+    cherrypicking::{CherrypickOutcome, abort_cherrypick, continue_cherrypick, is_cherrypick_in_progress, start_cherrypick},
+    conflicts::ensure_clean_workdir,
+    reverting::{RevertOutcome, abort_revert, continue_revert, is_revert_in_progress, start_revert},
+};
+use git2::{Error, Oid, Repository, RepositoryState, ResetType, Sort, build::CheckoutBuilder};
+use std::{collections::HashSet, fs, path::PathBuf};
+
+const GUITAR_SEQUENCE: &str = "GUITAR_SEQUENCE";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceKind {
+    Cherrypick,
+    Revert,
+}
+
+impl SequenceKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            SequenceKind::Cherrypick => "cherrypick",
+            SequenceKind::Revert => "revert",
+        }
+    }
+
+    fn verb(self) -> &'static str {
+        match self {
+            SequenceKind::Cherrypick => "cherry-picking",
+            SequenceKind::Revert => "reverting",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "cherrypick" => Some(SequenceKind::Cherrypick),
+            "revert" => Some(SequenceKind::Revert),
+            _ => None,
+        }
+    }
+}
+
+// Merge commits need a mainline: the 1-based parent whose side of the merge is kept.
+pub fn validate_mainline(kind: SequenceKind, commit: &git2::Commit<'_>, mainline: Option<u32>) -> Result<(), Error> {
+    match mainline {
+        None if commit.parent_count() > 1 => Err(Error::from_str(&format!("{} merge commits requires choosing a parent", kind.verb()))),
+        Some(mainline) if commit.parent_count() < 2 => Err(Error::from_str(&format!("parent {mainline} was given but the commit is not a merge"))),
+        Some(mainline) if mainline == 0 || mainline as usize > commit.parent_count() => Err(Error::from_str(&format!("commit has no parent {mainline}"))),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequenceStep {
+    pub oid: Oid,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceOutcome {
+    Completed { applied: usize },
+    Conflict { oid: Oid, remaining: usize },
+    Aborted,
+}
+
+// State persisted under .git so a paused sequence survives restarting guitar.
+// `head` and `branch` are where the sequence started; `last` is the latest commit it made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SequenceState {
+    kind: SequenceKind,
+    head: Oid,
+    branch: Option<String>,
+    last: Oid,
+    applied: usize,
+    current: Option<Oid>,
+    todo: Vec<SequenceStep>,
+}
+
+fn state_path(repo: &Repository) -> PathBuf {
+    repo.path().join(GUITAR_SEQUENCE)
+}
+
+fn write_state(repo: &Repository, state: &SequenceState) -> Result<(), Error> {
+    let mut contents = format!("kind {}\nhead {}\nlast {}\napplied {}\n", state.kind.as_str(), state.head, state.last, state.applied);
+    if let Some(branch) = &state.branch {
+        contents.push_str(&format!("branch {branch}\n"));
+    }
+    if let Some(current) = state.current {
+        contents.push_str(&format!("current {current}\n"));
+    }
+    for step in &state.todo {
+        let message = step.message.replace(['\n', '\r'], " ");
+        contents.push_str(&format!("pick {} {}\n", step.oid, message));
+    }
+    fs::write(state_path(repo), contents).map_err(|error| Error::from_str(&format!("write sequence state failed: {error}")))
+}
+
+fn read_state(repo: &Repository) -> Result<SequenceState, Error> {
+    let contents = fs::read_to_string(state_path(repo)).map_err(|_| Error::from_str("no sequence in progress"))?;
+    let invalid = || Error::from_str("sequence state is corrupt");
+
+    let mut kind = None;
+    let mut head = None;
+    let mut branch = None;
+    let mut last = None;
+    let mut applied = 0;
+    let mut current = None;
+    let mut todo = Vec::new();
+
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let (key, value) = line.split_once(' ').ok_or_else(invalid)?;
+        match key {
+            "kind" => kind = SequenceKind::parse(value),
+            "head" => head = Some(Oid::from_str(value)?),
+            "branch" => branch = Some(value.to_string()),
+            "last" => last = Some(Oid::from_str(value)?),
+            "applied" => applied = value.parse().map_err(|_| invalid())?,
+            "current" => current = Some(Oid::from_str(value)?),
+            "pick" => {
+                let (oid, message) = value.split_once(' ').unwrap_or((value, ""));
+                todo.push(SequenceStep { oid: Oid::from_str(oid)?, message: message.to_string() });
+            },
+            _ => return Err(invalid()),
+        }
+    }
+
+    Ok(SequenceState { kind: kind.ok_or_else(invalid)?, head: head.ok_or_else(invalid)?, branch, last: last.ok_or_else(invalid)?, applied, current, todo })
+}
+
+// The full name of the branch HEAD is on, or None when it is detached.
+fn head_branch(repo: &Repository) -> Result<Option<String>, Error> {
+    if repo.head_detached()? {
+        return Ok(None);
+    }
+    Ok(repo.head()?.name().map(str::to_string))
+}
+
+fn cleanup_state(repo: &Repository) {
+    let _ = fs::remove_file(state_path(repo));
+}
+
+pub fn is_sequence_in_progress(repo: &Repository) -> bool {
+    state_path(repo).is_file()
+}
+
+pub fn sequence_kind(repo: &Repository) -> Option<SequenceKind> {
+    read_state(repo).ok().map(|state| state.kind)
+}
+
+// Orders commits so each one is applied after the commits it builds on:
+// cherry-picks replay oldest first, reverts undo newest first.
+pub fn order_sequence(repo: &Repository, kind: SequenceKind, oids: &[Oid]) -> Result<Vec<Oid>, Error> {
+    let wanted: HashSet<Oid> = oids.iter().copied().collect();
+    let mut walk = repo.revwalk()?;
+    let sorting = match kind {
+        SequenceKind::Cherrypick => Sort::TOPOLOGICAL | Sort::REVERSE,
+        SequenceKind::Revert => Sort::TOPOLOGICAL,
+    };
+    walk.set_sorting(sorting)?;
+    for oid in &wanted {
+        walk.push(*oid)?;
+    }
+    // Everything below the marks' common ancestor is left out, so the walk stays as short as the selection.
+    let base = if let [only] = oids { Ok(*only) } else { repo.merge_base_octopus(oids) };
+    if let Ok(base) = base {
+        for parent in repo.find_commit(base)?.parent_ids() {
+            walk.hide(parent)?;
+        }
+    }
+
+    let mut ordered = Vec::with_capacity(wanted.len());
+    for oid in walk {
+        let oid = oid?;
+        if wanted.contains(&oid) {
+            ordered.push(oid);
+        }
+    }
+    Ok(ordered)
+}
+
+// Commits reachable from `to` but not from `from`, like `git rev-list from..to`.
+pub fn range_oids(repo: &Repository, from: Oid, to: Oid) -> Result<Vec<Oid>, Error> {
+    let mut walk = repo.revwalk()?;
+    walk.set_sorting(Sort::TOPOLOGICAL)?;
+    walk.push(to)?;
+    walk.hide(from)?;
+    walk.collect()
+}
+
+pub fn start_sequence(repo: &Repository, kind: SequenceKind, steps: Vec<SequenceStep>) -> Result<SequenceOutcome, Error> {
+    if is_sequence_in_progress(repo) {
+        return Err(Error::from_str("sequence already in progress"));
+    }
+    if repo.state() != RepositoryState::Clean {
+        return Err(Error::from_str("another operation is already in progress"));
+    }
+    if steps.is_empty() {
+        return Err(Error::from_str("no commits selected"));
+    }
+    ensure_clean_workdir(repo, if kind == SequenceKind::Cherrypick { "cherry-picking" } else { "reverting" })?;
+
+    let head = repo.head()?.peel_to_commit()?.id();
+    let state = SequenceState { kind, head, branch: head_branch(repo)?, last: head, applied: 0, current: None, todo: steps };
+    write_state(repo, &state)?;
+    run_sequence(repo, state)
+}
+
+pub fn continue_sequence(repo: &Repository) -> Result<SequenceOutcome, Error> {
+    let mut state = read_state(repo)?;
+
+    if let Some(oid) = state.current {
+        let resolved = match state.kind {
+            SequenceKind::Cherrypick if is_cherrypick_in_progress(repo) => Some(matches!(continue_cherrypick(repo)?, CherrypickOutcome::Committed { .. })),
+            SequenceKind::Revert if is_revert_in_progress(repo) => Some(matches!(continue_revert(repo)?, RevertOutcome::Committed { .. })),
+            // The paused step was finished outside guitar, so only the remaining steps are left.
+            _ => None,
+        };
+        if resolved == Some(false) {
+            return Ok(SequenceOutcome::Conflict { oid, remaining: state.todo.len() });
+        }
+        state.applied += 1;
+        state.current = None;
+        state.last = repo.head()?.peel_to_commit()?.id();
+        write_state(repo, &state)?;
+    }
+
+    run_sequence(repo, state)
+}
+
+pub fn abort_sequence(repo: &Repository) -> Result<SequenceOutcome, Error> {
+    let state = read_state(repo)?;
+
+    // Like `git cherry-pick --abort`, a HEAD that moved elsewhere is left alone and only the sequence is dropped.
+    if head_branch(repo)? != state.branch || repo.head()?.peel_to_commit()?.id() != state.last {
+        cleanup_state(repo);
+        return Err(Error::from_str("HEAD moved since the sequence ran; the sequence was dropped without rewinding, check HEAD"));
+    }
+
+    if is_cherrypick_in_progress(repo) {
+        abort_cherrypick(repo)?;
+    } else if is_revert_in_progress(repo) {
+        abort_revert(repo)?;
+    }
+
+    let head = repo.find_commit(state.head)?;
+    repo.reset(head.as_object(), ResetType::Hard, Some(CheckoutBuilder::default().force()))?;
+    repo.cleanup_state()?;
+    cleanup_state(repo);
+    Ok(SequenceOutcome::Aborted)
+}
+
+fn run_sequence(repo: &Repository, mut state: SequenceState) -> Result<SequenceOutcome, Error> {
+    while !state.todo.is_empty() {
+        let step = state.todo.remove(0);
+        let result = match state.kind {
+            SequenceKind::Cherrypick => start_cherrypick(repo, step.oid, &step.message).map(|outcome| matches!(outcome, CherrypickOutcome::Committed { .. })),
+            SequenceKind::Revert => start_revert(repo, step.oid, &step.message).map(|outcome| matches!(outcome, RevertOutcome::Committed { .. })),
+        };
+
+        match result {
+            Ok(true) => {
+                state.applied += 1;
+                state.last = repo.head()?.peel_to_commit()?.id();
+                write_state(repo, &state)?;
+            },
+            Ok(false) => {
+                state.current = Some(step.oid);
+                write_state(repo, &state)?;
+                return Ok(SequenceOutcome::Conflict { oid: step.oid, remaining: state.todo.len() });
+            },
+            Err(error) => {
+                // Keep the failed step queued so the sequence can be continued or aborted as a whole.
+                state.todo.insert(0, step);
+                write_state(repo, &state)?;
+                return Err(error);
+            },
+        }
+    }
+
+    cleanup_state(repo);
+    Ok(SequenceOutcome::Completed { applied: state.applied })
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/actions/sequencing.rs"]
+mod tests;
@@ -0,0 +1,216 @@
+use git2::{Commit, Config, Error, Oid, Repository, Signature, Tree};
+use std::{
+    fs,
+    io::{Read, Write},
+    path::PathBuf,
+    process::{Command, Output, Stdio},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+// Long enough to type a passphrase into a pinentry dialog, short enough that a stuck agent gives up.
+const SIGN_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignFormat {
+    OpenPgp,
+    Ssh,
+    X509,
+}
+
+impl SignFormat {
+    // `gpg.format` names; anything unknown falls back to OpenPGP like git does.
+    fn parse(value: &str) -> Self {
+        match value {
+            "ssh" => SignFormat::Ssh,
+            "x509" => SignFormat::X509,
+            _ => SignFormat::OpenPgp,
+        }
+    }
+
+    // Signature armor tells which tool can verify an existing signature.
+    pub fn from_signature(signature: &str) -> Option<Self> {
+        let signature = signature.trim_start();
+        if signature.starts_with("-----BEGIN PGP SIGNATURE-----") {
+            Some(SignFormat::OpenPgp)
+        } else if signature.starts_with("-----BEGIN SSH SIGNATURE-----") {
+            Some(SignFormat::Ssh)
+        } else if signature.starts_with("-----BEGIN SIGNED MESSAGE-----") {
+            Some(SignFormat::X509)
+        } else {
+            None
+        }
+    }
+
+    pub fn program(self, config: &Config) -> String {
+        let (keys, fallback): (&[&str], &str) = match self {
+            SignFormat::OpenPgp => (&["gpg.openpgp.program", "gpg.program"], "gpg"),
+            SignFormat::Ssh => (&["gpg.ssh.program"], "ssh-keygen"),
+            SignFormat::X509 => (&["gpg.x509.program"], "gpgsm"),
+        };
+        keys.iter().find_map(|key| config.get_string(key).ok()).unwrap_or_else(|| fallback.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SigningConfig {
+    pub format: SignFormat,
+    pub key: Option<String>,
+    pub program: String,
+}
+
+// Reads `commit.gpgsign`, `gpg.format` and `user.signingkey`; `None` means commits stay unsigned.
+pub fn signing_config(repo: &Repository) -> Result<Option<SigningConfig>, Error> {
+    let config = repo.config()?;
+    if !config.get_bool("commit.gpgsign").unwrap_or(false) {
+        return Ok(None);
+    }
+
+    let format = SignFormat::parse(&config.get_string("gpg.format").unwrap_or_default());
+    let key = config.get_string("user.signingkey").ok().filter(|key| !key.trim().is_empty());
+    Ok(Some(SigningConfig { format, key, program: format.program(&config) }))
+}
+
+// A scratch file under the temp dir for tools that only read or write files.
+pub(crate) fn scratch_path(label: &str) -> PathBuf {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos()).unwrap_or_default();
+    std::env::temp_dir().join(format!("guitar-{label}-{}-{id}", std::process::id()))
+}
+
+// Runs a program with `input` on stdin and kills it when it has not finished within `timeout`.
+pub(crate) fn run_program_with_timeout(program: &str, args: &[&str], input: &[u8], timeout: Duration) -> Result<Output, Error> {
+    let mut child =
+        Command::new(program).args(args).stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped()).spawn().map_err(|error| Error::from_str(&format!("could not run {program}: {error}")))?;
+
+    // The pipes are fed and drained on their own threads so a stuck program cannot block the caller.
+    let stdin = child.stdin.take();
+    let input = input.to_vec();
+    let writer = thread::spawn(move || {
+        if let Some(mut stdin) = stdin {
+            let _ = stdin.write_all(&input);
+        }
+    });
+    let stdout = child.stdout.take().map(read_pipe);
+    let stderr = child.stderr.take().map(read_pipe);
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) if Instant::now() < deadline => thread::sleep(Duration::from_millis(10)),
+            Ok(None) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                return Err(Error::from_str(&format!("{program} did not finish within {} seconds", timeout.as_secs())));
+            },
+            Err(error) => return Err(Error::from_str(&format!("{program} failed: {error}"))),
+        }
+    };
+    let _ = writer.join();
+    let collect = |reader: Option<thread::JoinHandle<Vec<u8>>>| reader.and_then(|reader| reader.join().ok()).unwrap_or_default();
+    Ok(Output { status, stdout: collect(stdout), stderr: collect(stderr) })
+}
+
+fn read_pipe(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = pipe.read_to_end(&mut bytes);
+        bytes
+    })
+}
+
+fn program_error(program: &str, output: &Output) -> Error {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let detail = stderr.lines().rfind(|line| !line.trim().is_empty() && !line.starts_with("[GNUPG:]")).unwrap_or("no signature was produced");
+    Error::from_str(&format!("{program} failed to sign the commit: {}", detail.trim()))
+}
+
+// Signs a commit buffer with the configured tool and returns the armored signature.
+pub fn sign_buffer(signing: &SigningConfig, buffer: &str, committer: &Signature<'_>) -> Result<String, Error> {
+    match signing.format {
+        SignFormat::OpenPgp | SignFormat::X509 => {
+            // Without a configured key, gpg picks the key matching the committer like git does.
+            let key = signing.key.clone().unwrap_or_else(|| format!("{} <{}>", committer.name().unwrap_or_default(), committer.email().unwrap_or_default()));
+            let output = run_program_with_timeout(&signing.program, &["--status-fd=2", "-bsau", &key], buffer.as_bytes(), SIGN_TIMEOUT)?;
+            if !output.status.success() || output.stdout.is_empty() {
+                return Err(program_error(&signing.program, &output));
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+        },
+        SignFormat::Ssh => sign_buffer_ssh(signing, buffer),
+    }
+}
+
+fn sign_buffer_ssh(signing: &SigningConfig, buffer: &str) -> Result<String, Error> {
+    let key = signing.key.as_deref().ok_or_else(|| Error::from_str("user.signingkey is required for ssh signing"))?;
+    let buffer_path = scratch_path("ssh-buffer");
+    let signature_path = buffer_path.with_extension("sig");
+    let literal_path = scratch_path("ssh-key");
+
+    // Literal public keys are handed over as a file and signed through the ssh agent.
+    let literal = key.strip_prefix("key::").or_else(|| key.starts_with("ssh-").then_some(key));
+    let mut args = vec!["-Y".to_string(), "sign".to_string(), "-n".to_string(), "git".to_string(), "-f".to_string()];
+    if let Some(literal) = literal {
+        fs::write(&literal_path, literal).map_err(|error| Error::from_str(&format!("write signing key failed: {error}")))?;
+        args.push(literal_path.display().to_string());
+        args.push("-U".to_string());
+    } else {
+        let expanded = match key.strip_prefix("~/") {
+            Some(rest) => dirs::home_dir().map(|home| home.join(rest).display().to_string()).unwrap_or_else(|| key.to_string()),
+            None => key.to_string(),
+        };
+        args.push(expanded);
+    }
+    args.push(buffer_path.display().to_string());
+
+    let result = fs::write(&buffer_path, buffer).map_err(|error| Error::from_str(&format!("write commit buffer failed: {error}"))).and_then(|_| {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        let output = run_program_with_timeout(&signing.program, &args, &[], SIGN_TIMEOUT)?;
+        if !output.status.success() {
+            return Err(program_error(&signing.program, &output));
+        }
+        fs::read_to_string(&signature_path).map_err(|error| Error::from_str(&format!("read ssh signature failed: {error}")))
+    });
+
+    let _ = fs::remove_file(&buffer_path);
+    let _ = fs::remove_file(&signature_path);
+    let _ = fs::remove_file(&literal_path);
+    result
+}
+
+// Creates a commit on HEAD, signing it when `commit.gpgsign` asks for it.
+// `operation` prefixes the reflog entry the way git does, e.g. "cherry-pick" or "revert".
+pub fn commit_to_head(repo: &Repository, operation: &str, author: &Signature<'_>, committer: &Signature<'_>, message: &str, tree: &Tree<'_>, parents: &[&Commit<'_>]) -> Result<Oid, Error> {
+    let oid = match signing_config(repo)? {
+        Some(signing) => {
+            let buffer = repo.commit_create_buffer(author, committer, message, tree, parents)?;
+            let buffer = buffer.as_str().ok_or_else(|| Error::from_str("commit buffer is not valid UTF-8"))?;
+            let signature = sign_buffer(&signing, buffer, committer)?;
+            repo.commit_signed(buffer, &signature, None)?
+        },
+        None => repo.commit(None, author, committer, message, tree, parents)?,
+    };
+
+    // Neither call above moves HEAD, so update it with a reflog entry naming the operation.
+    let summary = message.lines().next().unwrap_or_default();
+    let reflog = match (operation, parents.len()) {
+        ("commit", 0) => format!("commit (initial): {summary}"),
+        ("commit", 1) => format!("commit: {summary}"),
+        ("commit", _) => format!("commit (merge): {summary}"),
+        _ => format!("{operation}: {summary}"),
+    };
+    let head = repo.find_reference("HEAD")?;
+    match head.symbolic_target() {
+        Some(target) => {
+            repo.reference(target, oid, true, &reflog)?;
+        },
+        None => {
+            repo.reference("HEAD", oid, true, &reflog)?;
+        },
+    }
+    Ok(oid)
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/actions/signing.rs"]
+mod tests;
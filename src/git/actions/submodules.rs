@@ -1,9 +1,12 @@
 use crate::{
-    git::auth::{AuthAttempt, AuthSession, NetworkResult, network_result},
+    git::{
+        auth::{AuthAttempt, AuthSession, NetworkResult, network_result},
+        os::path::open_repo,
+    },
     helpers::localisation::network,
 };
 use git2::{FetchOptions, RemoteCallbacks, Repository, SubmoduleUpdateOptions};
-use std::thread;
+use std::{path::PathBuf, thread};
 
 pub fn sync_submodule(repo: &Repository, name: &str) -> Result<(), git2::Error> {
     let mut submodule = repo.find_submodule(name)?;
@@ -32,14 +35,14 @@ pub fn unstage_submodule(repo: &Repository, name: &str) -> Result<(), git2::Erro
     Ok(())
 }
 
-pub fn update_submodule(repo_path: &str, name: &str, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
+pub fn update_submodule(repo_path: &str, work_tree: Option<PathBuf>, name: &str, auth_session: AuthSession) -> thread::JoinHandle<NetworkResult> {
     let repo_path = repo_path.to_string();
     let name = name.to_string();
 
     thread::spawn(move || {
         let attempt = AuthAttempt::new(auth_session, network::UPDATE_SUBMODULE());
         let result = (|| -> Result<(), git2::Error> {
-            let repo = Repository::open(&repo_path)?;
+            let repo = open_repo(&repo_path, work_tree.as_deref())?;
             let config = repo.config()?;
             let mut submodule = repo.find_submodule(&name)?;
 
@@ -1,3 +1,4 @@
+use git2::{Error, Repository};
 use std::path::{Path, PathBuf};
 
 // Walk upward from any path until a non-bare repository root is found.
@@ -21,6 +22,22 @@ pub fn try_into_git_repo_root(start_path: impl AsRef<Path>) -> Option<PathBuf> {
     }
 }
 
+// Git's own discovery: honours `.git` files, `core.worktree`, and returns the git dir of bare repositories.
+pub fn discover_repo_root(start_path: impl AsRef<Path>) -> Option<PathBuf> {
+    let repo = Repository::discover(start_path).ok()?;
+    let root = repo.workdir().unwrap_or_else(|| repo.path());
+    Some(root.canonicalize().unwrap_or_else(|_| root.to_path_buf()))
+}
+
+// Opens a repository, pointing it at an explicit work tree for `--work-tree` style setups.
+pub fn open_repo(path: impl AsRef<Path>, work_tree: Option<&Path>) -> Result<Repository, Error> {
+    let repo = Repository::open(path)?;
+    if let Some(work_tree) = work_tree {
+        repo.set_workdir(work_tree, false)?;
+    }
+    Ok(repo)
+}
+
 #[cfg(test)]
 #[path = "../../tests/git/os/path.rs"]
 mod tests;
@@ -0,0 +1,210 @@
+use crate::git::queries::helpers::LfsPointer;
+use git2::{AttrCheckFlags, AttrValue, Oid, Repository};
+use regex::Regex;
+use std::{
+    fs::{self, File},
+    io::Read,
+    path::Path,
+};
+
+// Git only looks this far into a file when deciding whether it is binary.
+pub const BINARY_SNIFF_LEN: usize = 8000;
+// Bytes shown in a binary file's hex preview.
+pub const HEX_PREVIEW_LEN: usize = 256;
+// The LFS spec caps pointer files at 1024 bytes, so anything larger is real content.
+const LFS_POINTER_MAX_LEN: usize = 1024;
+const LFS_POINTER_VERSION: &str = "version https://git-lfs.github.com/spec/v1";
+
+// One side of a file: its full size and its leading bytes, which are the whole file when no read limit applies.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FileSide {
+    pub size: u64,
+    pub head: Vec<u8>,
+}
+
+impl FileSide {
+    pub fn is_partial(&self) -> bool {
+        (self.head.len() as u64) < self.size
+    }
+}
+
+// Both sides of one file: before (first parent or HEAD) and after (commit or working tree).
+#[derive(Debug, Default)]
+pub struct FileSides {
+    pub old: Option<FileSide>,
+    pub new: Option<FileSide>,
+}
+
+impl FileSides {
+    // The side the viewer shows: the new content, or what was there before a deletion.
+    pub fn shown(&self) -> Option<&FileSide> {
+        self.new.as_ref().or(self.old.as_ref())
+    }
+}
+
+// `read_limit` caps how many bytes of each side are read; sizes always come from the object headers.
+pub fn get_file_sides_at_oid(repo: &Repository, commit_oid: Oid, filename: &str, read_limit: Option<u64>) -> Result<FileSides, git2::Error> {
+    let commit = repo.find_commit(commit_oid)?;
+    let parent_tree = if commit.parent_count() > 0 { Some(commit.parent(0)?.tree()?) } else { None };
+    Ok(FileSides { old: parent_tree.and_then(|tree| read_tree_blob(repo, &tree, filename, read_limit)), new: read_tree_blob(repo, &commit.tree()?, filename, read_limit) })
+}
+
+pub fn get_file_sides_at_workdir(repo: &Repository, filename: &str, read_limit: Option<u64>) -> FileSides {
+    let head_tree = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+    let full_path = repo.workdir().map(|root| root.join(filename)).unwrap_or_else(|| Path::new(filename).to_path_buf());
+    FileSides { old: head_tree.and_then(|tree| read_tree_blob(repo, &tree, filename, read_limit)), new: read_workdir_file(&full_path, read_limit) }
+}
+
+fn read_tree_blob(repo: &Repository, tree: &git2::Tree, filename: &str, read_limit: Option<u64>) -> Option<FileSide> {
+    let id = tree.get_path(Path::new(filename)).ok()?.id();
+    let odb = repo.odb().ok()?;
+    let size = odb.read_header(id).ok()?.0 as u64;
+    let limit = read_limit.filter(|limit| *limit < size);
+
+    // Loose objects stream, so only the head is inflated; packed ones have to be read whole.
+    if let Some(limit) = limit
+        && let Ok((reader, _, _)) = odb.reader(id)
+    {
+        let mut head = Vec::with_capacity(limit as usize);
+        if reader.take(limit).read_to_end(&mut head).is_ok() {
+            return Some(FileSide { size, head });
+        }
+    }
+    let blob = repo.find_blob(id).ok()?;
+    let content = blob.content();
+    Some(FileSide { size, head: content[..limit.map_or(content.len(), |limit| limit as usize)].to_vec() })
+}
+
+fn read_workdir_file(path: &Path, read_limit: Option<u64>) -> Option<FileSide> {
+    let file = File::open(path).ok()?;
+    let size = file.metadata().ok()?.len();
+    let mut head = Vec::new();
+    file.take(read_limit.unwrap_or(u64::MAX)).read_to_end(&mut head).ok()?;
+    Some(FileSide { size, head })
+}
+
+// Binary per `.gitattributes` (`binary`, or `-diff`) or, as git decides it, a NUL in the sniffed prefix.
+// Attributes come from the working tree, or from `commit`'s own tree when looking at history.
+pub fn is_binary_file(repo: &Repository, filename: &str, commit: Option<Oid>, sides: &FileSides) -> bool {
+    let by_attributes = match commit {
+        Some(oid) => repo.find_commit(oid).and_then(|commit| commit.tree()).is_ok_and(|tree| is_binary_by_committed_attributes(repo, &tree, filename)),
+        None => is_binary_by_attributes(repo, filename),
+    };
+    by_attributes || [&sides.old, &sides.new].iter().any(|side| side.as_ref().is_some_and(|side| has_nul_byte(&side.head)))
+}
+
+pub fn is_binary_by_attributes(repo: &Repository, filename: &str) -> bool {
+    let attr = |name| AttrValue::from_string(repo.get_attr(Path::new(filename), name, AttrCheckFlags::FILE_THEN_INDEX).ok().flatten());
+    matches!(attr("binary"), AttrValue::True) || matches!(attr("diff"), AttrValue::False)
+}
+
+// libgit2 only reads attributes from the working tree and index, so a commit's `.gitattributes` files are
+// read from its tree here: root first, then each directory towards the file, then `.git/info/attributes`.
+fn is_binary_by_committed_attributes(repo: &Repository, tree: &git2::Tree, filename: &str) -> bool {
+    let mut sources = Vec::new();
+    let mut dir = String::new();
+    for component in Path::new(filename).parent().into_iter().flat_map(Path::components) {
+        sources.push(dir.clone());
+        dir = format!("{dir}{}/", component.as_os_str().to_string_lossy());
+    }
+    sources.push(dir);
+
+    let mut files: Vec<(String, String)> = sources
+        .into_iter()
+        .filter_map(|dir| {
+            let entry = tree.get_path(Path::new(&format!("{dir}.gitattributes"))).ok()?;
+            let blob = repo.find_blob(entry.id()).ok()?;
+            Some((String::from_utf8_lossy(blob.content()).into_owned(), dir))
+        })
+        .collect();
+    if let Ok(info) = fs::read_to_string(repo.path().join("info").join("attributes")) {
+        files.push((info, String::new()));
+    }
+
+    // Later lines win, as in git; `binary` is a macro for `-diff -merge -text`.
+    let (mut binary, mut diff) = (None, None);
+    for (contents, dir) in &files {
+        let Some(relative) = filename.strip_prefix(dir.as_str()) else { continue };
+        for line in contents.lines().map(str::trim).filter(|line| !line.is_empty() && !line.starts_with('#')) {
+            let mut fields = line.split_whitespace();
+            if !fields.next().is_some_and(|pattern| attribute_pattern_matches(pattern, relative)) {
+                continue;
+            }
+            for field in fields {
+                match field {
+                    "binary" => (binary, diff) = (Some(true), Some(false)),
+                    "-binary" => binary = Some(false),
+                    "!binary" => binary = None,
+                    "-diff" => diff = Some(false),
+                    "!diff" => diff = None,
+                    _ if field == "diff" || field.starts_with("diff=") => diff = Some(true),
+                    _ => {},
+                }
+            }
+        }
+    }
+    binary == Some(true) || diff == Some(false)
+}
+
+// Patterns without a slash match the file name at any depth; the rest match from the attributes file's directory.
+fn attribute_pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, target) = match pattern.contains('/') {
+        true => (pattern.trim_start_matches('/'), path),
+        false => (pattern, path.rsplit('/').next().unwrap_or(path)),
+    };
+    let mut expression = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    expression.push_str("(?:.*/)?");
+                } else {
+                    expression.push_str(".*");
+                }
+            },
+            '*' => expression.push_str("[^/]*"),
+            '?' => expression.push_str("[^/]"),
+            '[' => {
+                let class: String = chars.by_ref().take_while(|char| *char != ']').collect();
+                let class = class.strip_prefix('!').map(|rest| format!("^{rest}")).unwrap_or(class);
+                expression.push_str(&format!("[{class}]"));
+            },
+            _ => expression.push_str(&regex::escape(&char.to_string())),
+        }
+    }
+    expression.push('$');
+    Regex::new(&expression).is_ok_and(|regex| regex.is_match(target))
+}
+
+pub fn has_nul_byte(bytes: &[u8]) -> bool {
+    bytes[..bytes.len().min(BINARY_SNIFF_LEN)].contains(&0)
+}
+
+// Reads a Git LFS pointer file; anything else, including malformed pointers, is ordinary content.
+pub fn parse_lfs_pointer(bytes: &[u8]) -> Option<LfsPointer> {
+    if bytes.len() > LFS_POINTER_MAX_LEN {
+        return None;
+    }
+    let text = std::str::from_utf8(bytes).ok()?;
+    let mut lines = text.lines();
+    if lines.next()? != LFS_POINTER_VERSION {
+        return None;
+    }
+
+    let (mut oid, mut size) = (None, None);
+    for line in lines {
+        match line.split_once(' ')? {
+            ("oid", value) => oid = Some(value.to_string()),
+            ("size", value) => size = Some(value.parse().ok()?),
+            _ => {},
+        }
+    }
+    Some(LfsPointer { oid: oid?, size: size? })
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/queries/blobs.rs"]
+mod tests;
@@ -1,4 +1,4 @@
-use crate::core::{batcher::Batcher, oids::Oids};
+use crate::core::{batcher::Batcher, commit_graph::CommitGraph, oids::Oids};
 use git2::ObjectType;
 use git2::{Oid, Repository, Time};
 use std::collections::HashMap;
@@ -68,6 +68,16 @@ pub fn get_sorted_oids(batcher: &Batcher, oids: &mut Oids, sorted: &mut Vec<u32>
     }
 }
 
+// Whether `ancestor` is in the history of `commit`, from the commit graph when it knows both.
+pub fn is_descendant_of(repo: &Repository, commit_graph: Option<&CommitGraph>, commit: Oid, ancestor: Oid) -> Result<bool, git2::Error> {
+    if let Some(graph) = commit_graph
+        && let (Some(commit), Some(ancestor)) = (graph.position(commit), graph.position(ancestor))
+    {
+        return Ok(graph.is_descendant_of(commit, ancestor));
+    }
+    repo.graph_descendant_of(commit, ancestor)
+}
+
 // Return the current branch name, or None when HEAD is detached.
 pub fn get_current_branch(repo: &Repository) -> Option<String> {
     let head = repo.head().ok()?;
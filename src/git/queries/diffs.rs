@@ -7,12 +7,15 @@ use std::path::Path;
 
 // Collect staged and unstaged changes separately so the status panes can act on each side.
 pub fn get_filenames_diff_at_workdir(repo: &Repository) -> Result<UncommittedChanges, Error> {
+    // Bare repositories opened without a work tree have nothing uncommitted to show.
+    let Some(workdir) = repo.workdir() else {
+        return Ok(UncommittedChanges::default());
+    };
     let mut options = StatusOptions::new();
     options.include_untracked(true).exclude_submodules(true).show(git2::StatusShow::IndexAndWorkdir).renames_head_to_index(false).renames_index_to_workdir(false);
 
     let statuses = repo.statuses(Some(&mut options))?;
     let mut changes = UncommittedChanges::default();
-    let workdir = repo.workdir().expect("Bare repo not supported");
     let submodules = repo.submodules().unwrap_or_default();
     let submodule_paths = submodules.iter().map(|entry| entry.path().to_path_buf()).collect::<Vec<_>>();
 
@@ -179,6 +182,21 @@ fn collect_files_for_status(repo: &Repository, workdir: &Path, rel_path: &str) -
 
 // List files changed by a commit compared with its first parent.
 pub fn get_filenames_diff_at_oid(repo: &Repository, oid: Oid) -> Vec<FileChange> {
+    // Compare against the first parent, matching the normal `git show` view of merges.
+    get_filenames_diff_against_parent(repo, oid, 0)
+}
+
+// Same file list as `get_filenames_diff_at_oid`, but against any parent of a merge commit.
+pub fn get_filenames_diff_against_parent(repo: &Repository, oid: Oid, parent: usize) -> Vec<FileChange> {
+    filenames_diff(repo, oid, parent, &mut || false).unwrap_or_default()
+}
+
+// The first-parent file list for workers that can drop it halfway; None once `is_cancelled` says so.
+pub fn get_filenames_diff_at_oid_cancellable(repo: &Repository, oid: Oid, is_cancelled: &mut dyn FnMut() -> bool) -> Option<Vec<FileChange>> {
+    filenames_diff(repo, oid, 0, is_cancelled)
+}
+
+fn filenames_diff(repo: &Repository, oid: Oid, parent: usize, is_cancelled: &mut dyn FnMut() -> bool) -> Option<Vec<FileChange>> {
     let commit = repo.find_commit(oid).unwrap();
     let tree = commit.tree().unwrap();
     let mut changes = Vec::new();
@@ -186,17 +204,19 @@ pub fn get_filenames_diff_at_oid(repo: &Repository, oid: Oid) -> Vec<FileChange>
     // The root commit has no parent, so every tree entry appears as added.
     if commit.parent_count() == 0 {
         walk_tree(repo, &tree, "", &mut changes);
-        return changes;
+        return Some(changes);
     }
 
-    // Compare against the first parent, matching the normal `git show` view of merges.
-    let parent_tree = commit.parent(0).unwrap().tree().unwrap();
+    let parent_tree = commit.parent(parent.min(commit.parent_count() - 1)).unwrap().tree().unwrap();
     let mut opts = DiffOptions::new();
     opts.include_untracked(false).recurse_untracked_dirs(false).include_typechange(false).ignore_submodules(false).show_binary(false).minimal(false).skip_binary_check(true);
 
     let diff = repo.diff_tree_to_tree(Some(&parent_tree), Some(&tree), Some(&mut opts)).unwrap();
 
     for delta in diff.deltas() {
+        if is_cancelled() {
+            return None;
+        }
         let path = delta.new_file().path().or_else(|| delta.old_file().path()).unwrap().display().to_string();
 
         // Tree deltas can represent directories; expand them so the list stays file-oriented.
@@ -219,11 +239,11 @@ pub fn get_filenames_diff_at_oid(repo: &Repository, oid: Oid) -> Vec<FileChange>
         });
     }
 
-    changes
+    Some(changes)
 }
 
 // Build structured hunks for a working tree file against HEAD and the index.
-pub fn get_file_diff_at_workdir(repo: &Repository, filename: &str) -> Result<Vec<Hunk>, git2::Error> {
+pub fn get_file_diff_at_workdir(repo: &Repository, filename: &str, is_cancelled: &mut dyn FnMut() -> bool) -> Result<Vec<Hunk>, git2::Error> {
     // HEAD can be absent in a fresh repository, so the diff may be against an empty tree.
     let head_tree = repo.head().ok().and_then(|h| h.peel_to_tree().ok());
 
@@ -231,11 +251,11 @@ pub fn get_file_diff_at_workdir(repo: &Repository, filename: &str) -> Result<Vec
     let mut diff_options = DiffOptions::new();
     diff_options.pathspec(filename).show_untracked_content(true);
 
-    diff_to_hunks(repo.diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut diff_options))?)
+    diff_to_hunks(repo.diff_tree_to_workdir_with_index(head_tree.as_ref(), Some(&mut diff_options))?, is_cancelled)
 }
 
 // Build structured hunks for one file in a commit against its first parent.
-pub fn get_file_diff_at_oid(repo: &Repository, commit_oid: Oid, filename: &str) -> std::result::Result<Vec<Hunk>, git2::Error> {
+pub fn get_file_diff_at_oid(repo: &Repository, commit_oid: Oid, filename: &str, is_cancelled: &mut dyn FnMut() -> bool) -> std::result::Result<Vec<Hunk>, git2::Error> {
     let commit = repo.find_commit(commit_oid)?;
     let tree = commit.tree()?;
     let parent_tree = if commit.parent_count() > 0 { Some(commit.parent(0)?.tree()?) } else { None };
@@ -244,7 +264,7 @@ pub fn get_file_diff_at_oid(repo: &Repository, commit_oid: Oid, filename: &str)
     let mut diff_options = DiffOptions::new();
     diff_options.pathspec(filename);
 
-    diff_to_hunks(repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut diff_options))?)
+    diff_to_hunks(repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut diff_options))?, is_cancelled)
 }
 
 // Read file contents from a commit, returning sanitized display lines.
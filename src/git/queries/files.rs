@@ -56,25 +56,35 @@ pub fn search_tracked_files(repo: &Repository, query: &str, limit: usize) -> Res
 }
 
 pub fn rank_file_paths(paths: &[String], query: &str, limit: usize) -> Vec<FileSearchResult> {
+    let mut seen = HashSet::new();
+    rank_paths(paths, query, limit, |path| seen.insert(path.to_string())).into_iter().map(|(_, result)| result).collect()
+}
+
+// Keeps every match, equal ones included, with its index into `paths`.
+pub fn rank_file_path_indices(paths: &[String], query: &str, limit: usize) -> Vec<(usize, FileSearchResult)> {
+    rank_paths(paths, query, limit, |_| true)
+}
+
+fn rank_paths(paths: &[String], query: &str, limit: usize, mut keep: impl FnMut(&str) -> bool) -> Vec<(usize, FileSearchResult)> {
     let terms = normalize_query(query);
     if terms.is_empty() || limit == 0 {
         return Vec::new();
     }
 
-    let mut seen = HashSet::new();
-    let mut results: Vec<FileSearchResult> = paths
+    let mut results: Vec<(usize, FileSearchResult)> = paths
         .iter()
-        .filter_map(|path| {
+        .enumerate()
+        .filter_map(|(index, path)| {
             let path = normalize_path(path);
-            if path.is_empty() || is_git_internal_path(&path) || !seen.insert(path.clone()) {
+            if path.is_empty() || is_git_internal_path(&path) || !keep(&path) {
                 return None;
             }
 
-            score_path(&path, &terms).map(|(score, matched_indices)| FileSearchResult { path, score, matched_indices })
+            score_path(&path, &terms).map(|(score, matched_indices)| (index, FileSearchResult { path, score, matched_indices }))
         })
         .collect();
 
-    results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.path.chars().count().cmp(&b.path.chars().count())).then_with(|| a.path.cmp(&b.path)));
+    results.sort_by(|(_, a), (_, b)| b.score.cmp(&a.score).then_with(|| a.path.chars().count().cmp(&b.path.chars().count())).then_with(|| a.path.cmp(&b.path)));
     results.truncate(limit);
     results
 }
@@ -0,0 +1,72 @@
+use git2::{ObjectType, Oid, Repository};
+use std::collections::{HashMap, HashSet, VecDeque, hash_map::Entry};
+use std::fs;
+
+// Commits a shallow clone was cut at; libgit2 reports them without parents.
+pub fn get_shallow_boundaries(repo: &Repository) -> HashSet<Oid> {
+    let Ok(contents) = fs::read_to_string(repo.commondir().join("shallow")) else {
+        return HashSet::new();
+    };
+    contents.lines().filter_map(|line| Oid::from_str(line.trim()).ok()).collect()
+}
+
+// Commits listed under `refs/replace/*`, keyed by the commit they stand in for.
+// libgit2 ignores replace refs, so walks look the replacement up themselves.
+pub fn get_replacements(repo: &Repository) -> HashMap<Oid, Oid> {
+    let mut replacements = HashMap::new();
+    if !repo.config().ok().and_then(|config| config.get_bool("core.useReplaceRefs").ok()).unwrap_or(true) {
+        return replacements;
+    }
+    let Ok(references) = repo.references_glob("refs/replace/*") else {
+        return replacements;
+    };
+
+    for reference in references.flatten() {
+        let Some(original) = reference.name().and_then(|name| name.strip_prefix("refs/replace/")).and_then(|sha| Oid::from_str(sha).ok()) else {
+            continue;
+        };
+        // Only commit replacements change the graph; trees and blobs are left to git.
+        let Some(replacement) = reference.peel(ObjectType::Commit).ok().map(|object| object.id()) else {
+            continue;
+        };
+        if replacement != original && repo.find_commit(original).is_ok() {
+            replacements.insert(original, replacement);
+        }
+    }
+
+    replacements
+}
+
+// How many commits deep the shallow history reaches from `tips`, counted the way
+// `git fetch --depth` counts them. Zero when nothing is cut off below them.
+pub fn get_shallow_depth(repo: &Repository, boundaries: &HashSet<Oid>, tips: &[Oid]) -> usize {
+    if boundaries.is_empty() {
+        return 0;
+    }
+
+    let mut distances: HashMap<Oid, usize> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for &oid in tips {
+        if distances.insert(oid, 0).is_none() {
+            queue.push_back(oid);
+        }
+    }
+
+    // Breadth first, so each commit keeps its shortest distance from a tip.
+    while let Some(oid) = queue.pop_front() {
+        let distance = distances[&oid];
+        let Ok(commit) = repo.find_commit(oid) else { continue };
+        for parent in commit.parent_ids() {
+            if let Entry::Vacant(entry) = distances.entry(parent) {
+                entry.insert(distance + 1);
+                queue.push_back(parent);
+            }
+        }
+    }
+
+    boundaries.iter().filter_map(|oid| distances.get(oid)).map(|distance| distance + 1).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/queries/grafts.rs"]
+mod tests;
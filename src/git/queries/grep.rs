@@ -0,0 +1,139 @@
+use crate::git::queries::blobs::has_nul_byte;
+use git2::{ErrorCode, ObjectType, Oid, Repository, TreeWalkMode, TreeWalkResult};
+use regex::{Regex, RegexBuilder};
+use std::{
+    fs,
+    path::Path,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+// Snippets are cut around the match so long minified lines stay readable.
+const SNIPPET_CONTEXT: usize = 80;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrepMatch {
+    pub path: String,
+    pub line: usize,             // 1-based, as in the viewer's line numbers.
+    pub snippet: String,         // The matching line, cut down to the match and some context.
+    pub matched: (usize, usize), // Char range of the match inside the snippet.
+}
+
+// Where to look: tracked files as they are on disk, or the tree of one commit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrepScope {
+    Workdir,
+    Tree(Oid),
+}
+
+pub enum GrepPattern {
+    Literal(String),
+    Regex(Regex),
+}
+
+impl GrepPattern {
+    // Lines are matched one at a time, so multi-line regexes never match.
+    pub fn new(query: &str, is_regex: bool) -> Result<Self, regex::Error> {
+        if is_regex { RegexBuilder::new(query).size_limit(1 << 20).build().map(Self::Regex) } else { Ok(Self::Literal(query.to_string())) }
+    }
+
+    fn find(&self, line: &str) -> Option<(usize, usize)> {
+        match self {
+            Self::Literal(needle) => line.find(needle.as_str()).map(|start| (start, start + needle.len())),
+            // Patterns like `a*` match the empty string everywhere, which would flag every line.
+            Self::Regex(regex) => regex.find_iter(line).find(|found| !found.is_empty()).map(|found| (found.start(), found.end())),
+        }
+    }
+}
+
+// Calls `on_match` for every matching line in path order until it returns false or `cancel` is set.
+// Binary files are skipped, as `git grep` does without `--text`, and so are files over `size_limit` bytes.
+pub fn grep_files(repo: &Repository, scope: GrepScope, pattern: &GrepPattern, size_limit: u64, cancel: &AtomicBool, mut on_match: impl FnMut(GrepMatch) -> bool) -> Result<(), git2::Error> {
+    let mut keep_going = true;
+    let mut search = |path: &str, bytes: &[u8]| {
+        if !keep_going || has_nul_byte(bytes) {
+            return keep_going;
+        }
+        for (idx, line) in String::from_utf8_lossy(bytes).lines().enumerate() {
+            // Checked per line so one large file does not hold up a newer query.
+            if cancel.load(Ordering::Relaxed) {
+                keep_going = false;
+                break;
+            }
+            let Some(found) = pattern.find(line) else {
+                continue;
+            };
+            let (snippet, matched) = snippet_around(line, found);
+            if !on_match(GrepMatch { path: path.to_string(), line: idx + 1, snippet, matched }) {
+                keep_going = false;
+                break;
+            }
+        }
+        keep_going
+    };
+
+    match scope {
+        GrepScope::Workdir => {
+            let Some(workdir) = repo.workdir() else {
+                return Ok(());
+            };
+            let index = repo.index()?;
+            let mut paths: Vec<String> = index.iter().filter_map(|entry| String::from_utf8(entry.path).ok()).collect();
+            paths.dedup();
+            for path in paths {
+                if cancel.load(Ordering::Relaxed) {
+                    break;
+                }
+                let file_path = workdir.join(Path::new(&path));
+                // Deleted and unreadable files have nothing to match.
+                if fs::metadata(&file_path).map_or(true, |metadata| metadata.len() > size_limit) {
+                    continue;
+                }
+                let Ok(bytes) = fs::read(&file_path) else {
+                    continue;
+                };
+                if !search(&path, &bytes) {
+                    break;
+                }
+            }
+        },
+        GrepScope::Tree(oid) => {
+            let tree = repo.find_commit(oid)?.tree()?;
+            let odb = repo.odb()?;
+            // Stopping early surfaces as a user error from libgit2, which is not a failure here.
+            let walked = tree.walk(TreeWalkMode::PreOrder, |root, entry| {
+                if cancel.load(Ordering::Relaxed) {
+                    return TreeWalkResult::Abort;
+                }
+                // The header gives the size without inflating the blob.
+                if entry.kind() != Some(ObjectType::Blob) || odb.read_header(entry.id()).map_or(true, |(size, _)| size as u64 > size_limit) {
+                    return TreeWalkResult::Ok;
+                }
+                let Ok(blob) = repo.find_blob(entry.id()) else {
+                    return TreeWalkResult::Ok;
+                };
+                let path = format!("{root}{}", entry.name().unwrap_or_default());
+                if search(&path, blob.content()) { TreeWalkResult::Ok } else { TreeWalkResult::Abort }
+            });
+            if let Err(error) = walked
+                && error.code() != ErrorCode::User
+            {
+                return Err(error);
+            }
+        },
+    }
+    Ok(())
+}
+
+fn snippet_around(line: &str, (start, end): (usize, usize)) -> (String, (usize, usize)) {
+    let from = line[..start].char_indices().rev().nth(SNIPPET_CONTEXT.saturating_sub(1)).map(|(idx, _)| idx).unwrap_or(0);
+    let to = line[end..].char_indices().nth(SNIPPET_CONTEXT).map(|(idx, _)| end + idx).unwrap_or(line.len());
+    // Offsets are counted on the cleaned snippet so the match still lines up after tab expansion.
+    let clean = |text: &str| text.replace('\t', "    ").replace('\r', "");
+    let prefix = clean(&line[from..start]).chars().count();
+    let matched = clean(&line[start..end]).chars().count();
+    (clean(&line[from..to]), (prefix, prefix + matched))
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/queries/grep.rs"]
+mod tests;
@@ -76,6 +76,13 @@ pub struct ConflictFile {
     pub workdir: Vec<String>,
 }
 
+// Metadata from a Git LFS pointer file; the content itself lives in LFS storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LfsPointer {
+    pub oid: String, // Including the hash method, e.g. `sha256:…`.
+    pub size: u64,   // Size of the stored object, not of the pointer.
+}
+
 // Count unique filenames across staged and unstaged buckets.
 pub fn deduplicate(a: &[String], b: &[String]) -> usize {
     a.iter().chain(b).collect::<HashSet<_>>().len()
@@ -102,12 +109,15 @@ pub fn walk_tree(repo: &Repository, tree: &git2::Tree, base: &str, changes: &mut
     }
 }
 
-// Convert libgit2 patch callbacks into the viewer's hunk model.
-pub fn diff_to_hunks(diff: Diff) -> Result<Vec<Hunk>, git2::Error> {
+// Convert libgit2 patch callbacks into the viewer's hunk model; `is_cancelled` is asked before every line and stops the patch with an error.
+pub fn diff_to_hunks(diff: Diff, is_cancelled: &mut dyn FnMut() -> bool) -> Result<Vec<Hunk>, git2::Error> {
     let mut hunks = Vec::new();
 
     // Patch format gives both hunk headers and individual lines in one pass.
     diff.print(Patch, |_, hunk_opt, line| {
+        if is_cancelled() {
+            return false;
+        }
         if let Some(hunk) = hunk_opt {
             hunks.push(Hunk {
                 header: HunkHeader {
@@ -0,0 +1,100 @@
+use crate::git::actions::signing::{SignFormat, run_program_with_timeout, scratch_path};
+use git2::{Oid, Repository};
+use std::{fs, time::Duration};
+
+// A verifier waiting on a pinentry or an unreachable keyserver must not hold up the next commit.
+const VERIFY_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureStatus {
+    Good { signer: String },
+    Bad,
+    UnknownKey,
+    Unsigned,
+}
+
+// Verifies a commit signature with the same tool that would have created it.
+pub fn verify_commit(repo: &Repository, oid: Oid) -> SignatureStatus {
+    let Ok((signature, data)) = repo.extract_signature(&oid, None) else {
+        return SignatureStatus::Unsigned;
+    };
+    let signature = String::from_utf8_lossy(&signature).into_owned();
+    let Some(format) = SignFormat::from_signature(&signature) else {
+        return SignatureStatus::UnknownKey;
+    };
+    let Ok(config) = repo.config() else {
+        return SignatureStatus::UnknownKey;
+    };
+
+    let signature_path = scratch_path("verify-sig");
+    if fs::write(&signature_path, &signature).is_err() {
+        return SignatureStatus::UnknownKey;
+    }
+    let program = format.program(&config);
+    let signature_file = signature_path.display().to_string();
+    let status = match format {
+        SignFormat::OpenPgp | SignFormat::X509 => verify_gpg(&program, &signature_file, &data),
+        SignFormat::Ssh => {
+            let allowed = config.get_path("gpg.ssh.allowedSignersFile").ok().map(|path| path.display().to_string());
+            verify_ssh(&program, allowed.as_deref(), &signature_file, &data)
+        },
+    };
+    let _ = fs::remove_file(&signature_path);
+    status
+}
+
+fn run(program: &str, args: &[&str], input: &[u8]) -> Result<std::process::Output, git2::Error> {
+    run_program_with_timeout(program, args, input, VERIFY_TIMEOUT)
+}
+
+fn verify_gpg(program: &str, signature_file: &str, data: &[u8]) -> SignatureStatus {
+    let Ok(output) = run(program, &["--status-fd=1", "--verify", signature_file, "-"], data) else {
+        return SignatureStatus::UnknownKey;
+    };
+
+    // Status lines are stable across gpg versions, unlike the human readable output.
+    let status = String::from_utf8_lossy(&output.stdout);
+    for line in status.lines() {
+        let mut fields = line.split_whitespace();
+        if fields.next() != Some("[GNUPG:]") {
+            continue;
+        }
+        match fields.next() {
+            Some("GOODSIG") => {
+                let signer = fields.skip(1).collect::<Vec<_>>().join(" ");
+                return SignatureStatus::Good { signer };
+            },
+            Some("BADSIG") => return SignatureStatus::Bad,
+            _ => {},
+        }
+    }
+    SignatureStatus::UnknownKey
+}
+
+fn verify_ssh(program: &str, allowed_signers: Option<&str>, signature_file: &str, data: &[u8]) -> SignatureStatus {
+    if let Some(allowed) = allowed_signers {
+        let principal = run(program, &["-Y", "find-principals", "-f", allowed, "-s", signature_file], &[])
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8_lossy(&output.stdout).lines().next().map(|line| line.trim().to_string()))
+            .filter(|principal| !principal.is_empty());
+        if let Some(principal) = principal {
+            return match run(program, &["-Y", "verify", "-f", allowed, "-I", &principal, "-n", "git", "-s", signature_file], data) {
+                Ok(output) if output.status.success() => SignatureStatus::Good { signer: principal },
+                Ok(_) => SignatureStatus::Bad,
+                Err(_) => SignatureStatus::UnknownKey,
+            };
+        }
+    }
+
+    // Without a trusted principal the signature can still be checked for integrity.
+    match run(program, &["-Y", "check-novalidate", "-n", "git", "-s", signature_file], data) {
+        Ok(output) if output.status.success() => SignatureStatus::UnknownKey,
+        Ok(_) => SignatureStatus::Bad,
+        Err(_) => SignatureStatus::UnknownKey,
+    }
+}
+
+#[cfg(test)]
+#[path = "../../tests/git/queries/signatures.rs"]
+mod tests;
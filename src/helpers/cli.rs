@@ -0,0 +1,150 @@
+use crate::{
+    core::export::{ExportFormat, ExportOptions},
+    git::os::path::{discover_repo_root, open_repo},
+    helpers::symbols::SymbolTheme,
+};
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+};
+
+const FORMAT_FLAG: &str = "--format";
+const GIT_DIR_FLAG: &str = "--git-dir";
+const HELP_LONG: &str = "--help";
+const HELP_SHORT: &str = "-h";
+const LIMIT_FLAG: &str = "--limit";
+const LOG_COMMAND: &str = "log";
+const RESET_CONFIG: &str = "--reset";
+const SYMBOLS_FLAG: &str = "--symbols";
+const VERSION_LONG: &str = "--version";
+const VERSION_SHORT: &str = "-v";
+const WORK_TREE_FLAG: &str = "--work-tree";
+
+// Git exits with 128 when it cannot find a repository, so scripts can treat both alike.
+pub const EXIT_NOT_A_REPOSITORY: i32 = 128;
+pub const EXIT_USAGE: i32 = 2;
+
+pub const USAGE: &str = "usage: guitar [<path>] [--git-dir <dir>] [--work-tree <dir>] [--reset] [--version] [--help]
+       guitar log [<path>] [--git-dir <dir>] [--format text|ansi|json|svg|html] [--limit <n>] [--symbols <preset>]";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CliArgs {
+    pub path: Option<String>,
+    pub git_dir: Option<String>,
+    pub work_tree: Option<String>,
+    pub is_help: bool,
+    pub is_reset: bool,
+    pub is_version: bool,
+    // Set by the `log` subcommand, which prints the graph instead of starting the app.
+    // Its flags are only accepted after `log`, so `guitar --limit 5` stays an unknown option.
+    pub log: Option<ExportOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliError {
+    Usage(String),
+    NotARepository(String),
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Usage(_) => EXIT_USAGE,
+            CliError::NotARepository(_) => EXIT_NOT_A_REPOSITORY,
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{message}\n{USAGE}"),
+            CliError::NotARepository(path) => write!(f, "not a git repository (or any of the parent directories): {path}"),
+        }
+    }
+}
+
+// Where the app opens its repository; the work tree is only set for explicit overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoTarget {
+    pub path: PathBuf,
+    pub work_tree: Option<PathBuf>,
+}
+
+// Accepts `--flag value` and `--flag=value`, like git.
+fn flag_value(flag: &str, arg: &str, rest: &mut impl Iterator<Item = String>) -> Result<Option<String>, CliError> {
+    if arg == flag {
+        return rest.next().map(Some).ok_or_else(|| CliError::Usage(format!("{flag} needs a value")));
+    }
+    Ok(arg.strip_prefix(flag).and_then(|value| value.strip_prefix('=')).map(str::to_string))
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<CliArgs, CliError> {
+    let mut parsed = CliArgs::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            VERSION_LONG | VERSION_SHORT => parsed.is_version = true,
+            HELP_LONG | HELP_SHORT => parsed.is_help = true,
+            RESET_CONFIG => parsed.is_reset = true,
+            _ => {
+                if let Some(value) = flag_value(GIT_DIR_FLAG, &arg, &mut args)? {
+                    parsed.git_dir = Some(value);
+                } else if let Some(value) = flag_value(WORK_TREE_FLAG, &arg, &mut args)? {
+                    parsed.work_tree = Some(value);
+                } else if let Some(log) = parsed.log.as_mut()
+                    && let Some(value) = flag_value(FORMAT_FLAG, &arg, &mut args)?
+                {
+                    log.format = ExportFormat::from_label(&value).ok_or_else(|| CliError::Usage(format!("unknown format: {value}")))?;
+                } else if let Some(log) = parsed.log.as_mut()
+                    && let Some(value) = flag_value(LIMIT_FLAG, &arg, &mut args)?
+                {
+                    log.limit = Some(value.parse().map_err(|_| CliError::Usage(format!("{LIMIT_FLAG} needs a number: {value}")))?);
+                } else if let Some(log) = parsed.log.as_mut()
+                    && let Some(value) = flag_value(SYMBOLS_FLAG, &arg, &mut args)?
+                {
+                    SymbolTheme::from_label(&value).ok_or_else(|| CliError::Usage(format!("unknown symbol preset: {value}")))?;
+                    log.symbols = Some(value);
+                } else if arg == LOG_COMMAND && parsed.log.is_none() && parsed.path.is_none() {
+                    parsed.log = Some(ExportOptions::default());
+                } else if arg.starts_with('-') && arg != "-" {
+                    return Err(CliError::Usage(format!("unknown option: {arg}")));
+                } else if parsed.path.replace(arg.clone()).is_some() {
+                    return Err(CliError::Usage(format!("unexpected argument: {arg}")));
+                }
+            },
+        }
+    }
+    Ok(parsed)
+}
+
+fn absolute(path: &str) -> PathBuf {
+    let path = Path::new(path);
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+// Flags win over `GIT_DIR`/`GIT_WORK_TREE`; `None` keeps the splash fallback for a bare `guitar`.
+pub fn resolve_repository(args: &CliArgs, env: impl Fn(&str) -> Option<String>) -> Result<Option<RepoTarget>, CliError> {
+    let git_dir = args.git_dir.clone().or_else(|| env("GIT_DIR")).filter(|value| !value.is_empty());
+    let work_tree = args.work_tree.clone().or_else(|| env("GIT_WORK_TREE")).filter(|value| !value.is_empty()).map(|value| absolute(&value));
+
+    if let Some(git_dir) = git_dir {
+        if let Some(path) = &args.path {
+            return Err(CliError::Usage(format!("a path cannot be combined with {GIT_DIR_FLAG}: {path}")));
+        }
+        let path = absolute(&git_dir);
+        open_repo(&path, work_tree.as_deref()).map_err(|_| CliError::NotARepository(git_dir))?;
+        return Ok(Some(RepoTarget { path, work_tree }));
+    }
+
+    // Without a git dir, `--work-tree` and `log` still discover the repository from the current directory.
+    let Some(start) = args.path.clone().or_else(|| (work_tree.is_some() || args.log.is_some()).then(|| ".".to_string())) else {
+        return Ok(None);
+    };
+    let path = discover_repo_root(absolute(&start)).ok_or(CliError::NotARepository(start))?;
+    Ok(Some(RepoTarget { path, work_tree }))
+}
+
+#[cfg(test)]
+#[path = "../tests/helpers/cli.rs"]
+mod tests;
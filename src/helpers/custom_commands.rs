@@ -0,0 +1,161 @@
+use crate::helpers::process::{ProcessLog, exit_result, run_logged};
+use facet::Facet;
+use git2::Error;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+pub const PLACEHOLDERS: [&str; 6] = ["sha", "short_sha", "branch", "path", "repo", "remote_url"];
+
+// Where a custom command may run; Any ignores the focused pane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Facet)]
+#[facet(default)]
+#[repr(C)]
+pub enum CustomCommandContext {
+    #[default]
+    Any,
+    Graph,
+    Branches,
+    Status,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Facet)]
+#[facet(default)]
+#[repr(C)]
+pub enum CustomCommandOutput {
+    #[default]
+    Modal,
+    Background,
+    Suspend,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Facet)]
+pub struct CustomCommand {
+    pub name: String,
+    pub command: String,
+    #[facet(default)]
+    pub context: CustomCommandContext,
+    #[facet(default)]
+    pub output: CustomCommandOutput,
+}
+
+#[derive(Facet, Clone, Default)]
+pub struct CustomCommandsConfig {
+    #[facet(default)]
+    pub commands: Vec<CustomCommand>,
+}
+
+// Values for the template placeholders; None when nothing suitable is selected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomCommandValues {
+    pub sha: Option<String>,
+    pub branch: Option<String>,
+    pub path: Option<String>,
+    pub repo: Option<String>,
+    pub remote_url: Option<String>,
+}
+
+impl CustomCommandValues {
+    fn get(&self, placeholder: &str) -> Option<String> {
+        match placeholder {
+            "sha" => self.sha.clone(),
+            "short_sha" => self.sha.as_ref().map(|sha| sha.chars().take(7).collect()),
+            "branch" => self.branch.clone(),
+            "path" => self.path.clone(),
+            "repo" => self.repo.clone(),
+            "remote_url" => self.remote_url.clone(),
+            _ => None,
+        }
+    }
+}
+
+// A resolved command line, ready to hand to the shell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomCommandRun {
+    pub name: String,
+    pub script: String,
+    pub cwd: PathBuf,
+}
+
+pub fn custom_commands_config_path() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap();
+    path.push("guitar");
+    path.push("custom_commands.json");
+    path
+}
+
+pub fn load_custom_commands() -> Vec<CustomCommand> {
+    load_custom_commands_from_path(&custom_commands_config_path())
+}
+
+// A missing or malformed file means no custom commands, never a startup failure.
+pub fn load_custom_commands_from_path(path: &Path) -> Vec<CustomCommand> {
+    let Ok(contents) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    facet_json::from_str::<CustomCommandsConfig>(&contents).map(|config| config.commands).unwrap_or_default()
+}
+
+// Substituted values are shell-quoted; an unknown placeholder is left as written.
+pub fn expand_template(template: &str, values: &CustomCommandValues) -> Result<String, String> {
+    let mut expanded = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(open) = rest.find('{') {
+        expanded.push_str(&rest[..open]);
+        let after = &rest[open + 1..];
+        let Some(close) = after.find('}') else {
+            expanded.push_str(&rest[open..]);
+            return Ok(expanded);
+        };
+
+        let placeholder = &after[..close];
+        if PLACEHOLDERS.contains(&placeholder) {
+            let value = values.get(placeholder).ok_or_else(|| format!("{{{placeholder}}}"))?;
+            expanded.push_str(&shell_quote(&value));
+        } else {
+            expanded.push_str(&rest[open..open + close + 2]);
+        }
+        rest = &after[close + 1..];
+    }
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+#[cfg(not(windows))]
+pub fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+// cmd expands `%` even inside double quotes, so each one is closed off and escaped with `^`.
+#[cfg(windows)]
+pub fn shell_quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\"").replace('%', "\"^%\""))
+}
+
+fn shell(script: &str) -> Command {
+    let mut command = if cfg!(windows) { Command::new("cmd") } else { Command::new("sh") };
+    command.arg(if cfg!(windows) { "/C" } else { "-c" }).arg(script);
+    command
+}
+
+impl CustomCommandRun {
+    // Runs with stdout and stderr streamed into the log; a non-zero exit is an error.
+    pub fn run(&self, log: &ProcessLog) -> Result<(), Error> {
+        let mut command = shell(&self.script);
+        command.current_dir(&self.cwd);
+        run_logged(command, &self.name, None, log)
+    }
+
+    // Runs on the real terminal, for commands that need it such as editors and pagers.
+    pub fn run_attached(&self) -> Result<(), Error> {
+        let status = shell(&self.script).current_dir(&self.cwd).status().map_err(|error| Error::from_str(&format!("could not run {}: {error}", self.name)))?;
+        exit_result(&self.name, status)
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/helpers/custom_commands.rs"]
+mod tests;
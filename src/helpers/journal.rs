@@ -0,0 +1,78 @@
+use crate::git::actions::journal::JournalEntry;
+use facet::Facet;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Older entries are dropped so the file stays small for long-lived repositories.
+pub const MAX_JOURNAL_ENTRIES: usize = 100;
+
+#[derive(Facet, Clone)]
+pub struct RepositoryJournal {
+    pub path: String,
+    #[facet(default)]
+    pub entries: Vec<JournalEntry>,
+}
+
+#[derive(Facet, Clone, Default)]
+pub struct JournalConfig {
+    #[facet(default)]
+    pub repositories: Vec<RepositoryJournal>,
+}
+
+pub fn journal_config_path() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap();
+    path.push("guitar");
+    path.push("journal.json");
+    path
+}
+
+pub fn load_journal(repo_path: &str) -> Vec<JournalEntry> {
+    load_journal_from_path(&journal_config_path(), repo_path)
+}
+
+pub fn save_journal(repo_path: &str, entries: &[JournalEntry]) {
+    save_journal_to_path(&journal_config_path(), repo_path, entries);
+}
+
+pub fn load_journal_from_path(path: &Path, repo_path: &str) -> Vec<JournalEntry> {
+    let config = load_config_from_path(path);
+    config.repositories.into_iter().find(|entry| entry.path == repo_path).map(|entry| entry.entries).unwrap_or_default()
+}
+
+pub fn save_journal_to_path(path: &Path, repo_path: &str, entries: &[JournalEntry]) {
+    let mut config = load_config_from_path(path);
+    let entries = entries[entries.len().saturating_sub(MAX_JOURNAL_ENTRIES)..].to_vec();
+
+    config.repositories.retain(|entry| entry.path != repo_path);
+    if !entries.is_empty() {
+        config.repositories.push(RepositoryJournal { path: repo_path.to_string(), entries });
+    }
+    config.repositories.sort_by(|a, b| a.path.cmp(&b.path));
+
+    if let Some(parent) = path.parent()
+        && !parent.exists()
+    {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    if let Ok(config_string) = facet_json::to_string_pretty(&config) {
+        let _ = fs::write(path, config_string);
+    }
+}
+
+fn load_config_from_path(path: &Path) -> JournalConfig {
+    if !path.exists() {
+        return JournalConfig::default();
+    }
+
+    let Ok(contents) = fs::read_to_string(path) else {
+        return JournalConfig::default();
+    };
+    facet_json::from_str::<JournalConfig>(&contents).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "../tests/helpers/journal.rs"]
+mod tests;
@@ -46,6 +46,8 @@ pub enum Command {
     ToggleGraphDates,
     ToggleGraphCommitters,
     ToggleGraphRefs,
+    ToggleGraphSignatures,
+    ToggleOriginalParents,
     ToggleWorktrees,
     ToggleSubmodules,
     ToggleSearch,
@@ -53,12 +55,18 @@ pub enum Command {
     ToggleInspector,
     ToggleShas,
     ToggleHelp,
+    CommandPalette,
     ActionMode,
+    Leader,
     Exit,
     RemoveRecentRepository,
     MoveRecentRepositoryUp,
     MoveRecentRepositoryDown,
     ReturnToParentRepository,
+    NewTab,
+    CloseTab,
+    NextTab,
+    PreviousTab,
 
     // Lists
     ScrollPageUp,
@@ -79,24 +87,42 @@ pub enum Command {
     ScrollDownCommit,
     Find,
     FindFile,
+    SearchContents,
+    MarkCommit,
+    MarkRange,
+    ClearMarks,
+    VisualMode,
+    Count,
+    SetMark,
+    JumpToMark,
+    JumpBack,
+    JumpForward,
 
     // Viewer
     ToggleHunkMode,
     ToggleSplitDiffMode,
+    LoadFullDiff,
 
     // Git
     Drop,
     Pop,
     Stash,
     FetchAll,
+    DeepenHistory,
     Checkout,
     HardReset,
     MixedReset,
+    SoftReset,
+    ResetBranch,
     Unstage,
     Stage,
     Commit,
     ForcePush,
     PushTags,
+    SkipHooks,
+    ExportGraph,
+    ExportPatches,
+    ApplyPatch,
     SoloBranch,
     ToggleBranch,
     CreateBranch,
@@ -110,6 +136,8 @@ pub enum Command {
     Merge,
     ContinueOperation,
     AbortOperation,
+    Undo,
+    ToggleJournal,
     CreateWorktree,
     RemoveWorktree,
     ToggleWorktreeLock,
@@ -117,8 +145,133 @@ pub enum Command {
     SyncSubmodule,
     Reload,
     ReloadAllBranches,
+
+    // A shell command from custom_commands.json, bound by its name
+    Custom(String),
 }
 
+// Every built-in command in declaration order, for the command palette.
+pub const COMMANDS: [Command; 118] = [
+    Command::WidenScope,
+    Command::NarrowScope,
+    Command::FocusNextPane,
+    Command::FocusPreviousPane,
+    Command::FocusPaneLeft,
+    Command::FocusPaneDown,
+    Command::FocusPaneUp,
+    Command::FocusPaneRight,
+    Command::Select,
+    Command::Back,
+    Command::Minimize,
+    Command::ResetLayout,
+    Command::ShrinkGraphLaneLimit,
+    Command::GrowGraphLaneLimit,
+    Command::ResizePaneLeft,
+    Command::ResizePaneDown,
+    Command::ResizePaneUp,
+    Command::ResizePaneRight,
+    Command::ToggleZenMode,
+    Command::ToggleBranches,
+    Command::ToggleTags,
+    Command::ToggleStashes,
+    Command::ToggleReflogs,
+    Command::ToggleGraphReflogs,
+    Command::ToggleGraphDates,
+    Command::ToggleGraphCommitters,
+    Command::ToggleGraphRefs,
+    Command::ToggleGraphSignatures,
+    Command::ToggleOriginalParents,
+    Command::ToggleWorktrees,
+    Command::ToggleSubmodules,
+    Command::ToggleSearch,
+    Command::ToggleStatus,
+    Command::ToggleInspector,
+    Command::ToggleShas,
+    Command::ToggleHelp,
+    Command::CommandPalette,
+    Command::ActionMode,
+    Command::Leader,
+    Command::Exit,
+    Command::RemoveRecentRepository,
+    Command::MoveRecentRepositoryUp,
+    Command::MoveRecentRepositoryDown,
+    Command::ReturnToParentRepository,
+    Command::NewTab,
+    Command::CloseTab,
+    Command::NextTab,
+    Command::PreviousTab,
+    Command::ScrollPageUp,
+    Command::ScrollPageDown,
+    Command::ScrollHalfPageUp,
+    Command::ScrollHalfPageDown,
+    Command::ScrollUp,
+    Command::ScrollDown,
+    Command::ScrollUpHalf,
+    Command::ScrollDownHalf,
+    Command::GoToBeginning,
+    Command::GoToEnd,
+    Command::ScrollUpBranch,
+    Command::ScrollDownBranch,
+    Command::ScrollUpCommit,
+    Command::ScrollDownCommit,
+    Command::Find,
+    Command::FindFile,
+    Command::SearchContents,
+    Command::MarkCommit,
+    Command::MarkRange,
+    Command::ClearMarks,
+    Command::VisualMode,
+    Command::Count,
+    Command::SetMark,
+    Command::JumpToMark,
+    Command::JumpBack,
+    Command::JumpForward,
+    Command::ToggleHunkMode,
+    Command::ToggleSplitDiffMode,
+    Command::LoadFullDiff,
+    Command::Drop,
+    Command::Pop,
+    Command::Stash,
+    Command::FetchAll,
+    Command::DeepenHistory,
+    Command::Checkout,
+    Command::HardReset,
+    Command::MixedReset,
+    Command::SoftReset,
+    Command::ResetBranch,
+    Command::Unstage,
+    Command::Stage,
+    Command::Commit,
+    Command::ForcePush,
+    Command::PushTags,
+    Command::SkipHooks,
+    Command::ExportGraph,
+    Command::ExportPatches,
+    Command::ApplyPatch,
+    Command::SoloBranch,
+    Command::ToggleBranch,
+    Command::CreateBranch,
+    Command::DeleteBranch,
+    Command::RenameBranch,
+    Command::Tag,
+    Command::Untag,
+    Command::Cherrypick,
+    Command::Revert,
+    Command::Rebase,
+    Command::Merge,
+    Command::ContinueOperation,
+    Command::AbortOperation,
+    Command::Undo,
+    Command::ToggleJournal,
+    Command::CreateWorktree,
+    Command::RemoveWorktree,
+    Command::ToggleWorktreeLock,
+    Command::UpdateSubmodule,
+    Command::SyncSubmodule,
+    Command::Reload,
+    Command::ReloadAllBranches,
+];
+
 pub type ModeKeymap = IndexMap<KeyBinding, Command>;
 pub type Keymaps = IndexMap<InputMode, ModeKeymap>;
 
@@ -141,6 +294,8 @@ pub enum KeymapEditError {
     MissingBinding { mode: InputMode, key: KeyBinding },
     CommandChanged { mode: InputMode, key: KeyBinding, expected: Command, actual: Command },
     Conflict { mode: InputMode, key: KeyBinding, command: Command },
+    // One binding would start the other, so the shorter one could never run.
+    PrefixConflict { mode: InputMode, key: KeyBinding, command: Command },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -148,6 +303,23 @@ pub struct KeymapEditOutcome {
     pub synced_action: bool,
 }
 
+// What a key means after the keys already pressed in a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeySequenceMatch {
+    Command(Command),
+    // Longer sequences continue here; the command is what the keys so far are bound to on their own.
+    Prefix(Option<Command>),
+    None,
+}
+
+// A key that can follow the pending ones, for the which-key popup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyContinuation {
+    pub key: KeyBinding,
+    pub command: Option<Command>,
+    pub sequences: usize,
+}
+
 pub fn action_keymap_visible_entries(normal: Option<&ModeKeymap>, action: &ModeKeymap) -> ModeKeymap {
     action.iter().filter(|(kb, cmd)| normal.and_then(|normal| normal.get(*kb)) != Some(*cmd)).map(|(kb, cmd)| (kb.clone(), cmd.clone())).collect()
 }
@@ -188,6 +360,8 @@ pub fn command_to_visual_string(command: &Command) -> String {
         Command::ToggleGraphDates => "Toggle graph dates",
         Command::ToggleGraphCommitters => "Toggle graph committers",
         Command::ToggleGraphRefs => "Toggle graph refs",
+        Command::ToggleGraphSignatures => "Toggle graph signatures",
+        Command::ToggleOriginalParents => "Toggle original parents",
         Command::ToggleWorktrees => "Toggle worktrees",
         Command::ToggleSubmodules => "Toggle submodules",
         Command::ToggleSearch => "Toggle search",
@@ -195,12 +369,18 @@ pub fn command_to_visual_string(command: &Command) -> String {
         Command::ToggleInspector => "Toggle inspector",
         Command::ToggleShas => "Toggle SHAs",
         Command::ToggleHelp => "Toggle help",
+        Command::CommandPalette => "Command palette",
         Command::ActionMode => "Action mode",
+        Command::Leader => "Leader key",
         Command::Exit => "Exit",
         Command::RemoveRecentRepository => "Remove recent repository",
         Command::MoveRecentRepositoryUp => "Move recent repository up",
         Command::MoveRecentRepositoryDown => "Move recent repository down",
         Command::ReturnToParentRepository => "Return to parent repository",
+        Command::NewTab => "New tab",
+        Command::CloseTab => "Close tab",
+        Command::NextTab => "Next tab",
+        Command::PreviousTab => "Previous tab",
         Command::ScrollPageUp => "Scroll page up",
         Command::ScrollPageDown => "Scroll page down",
         Command::ScrollHalfPageUp => "Scroll half page up",
@@ -217,20 +397,38 @@ pub fn command_to_visual_string(command: &Command) -> String {
         Command::ScrollDownCommit => "Scroll down commit",
         Command::Find => "Find",
         Command::FindFile => "Find file",
+        Command::SearchContents => "Search file contents",
+        Command::MarkCommit => "Mark commit",
+        Command::MarkRange => "Mark range",
+        Command::ClearMarks => "Clear marks",
+        Command::VisualMode => "Visual mode",
+        Command::Count => "Start count",
+        Command::SetMark => "Set mark",
+        Command::JumpToMark => "Jump to mark",
+        Command::JumpBack => "Jump back",
+        Command::JumpForward => "Jump forward",
         Command::ToggleHunkMode => "Toggle hunk mode",
         Command::ToggleSplitDiffMode => "Toggle split diff mode",
+        Command::LoadFullDiff => "Load full diff",
         Command::Drop => "Drop stash",
         Command::Pop => "Pop stash",
         Command::Stash => "Stash changes",
         Command::FetchAll => "Fetch all",
+        Command::DeepenHistory => "Deepen shallow history",
         Command::Checkout => "Checkout",
         Command::HardReset => "Hard reset",
         Command::MixedReset => "Mixed reset",
+        Command::SoftReset => "Soft reset",
+        Command::ResetBranch => "Reset branch",
         Command::Unstage => "Unstage file",
         Command::Stage => "Stage file",
         Command::Commit => "Commit",
         Command::ForcePush => "Push",
         Command::PushTags => "Push tags",
+        Command::SkipHooks => "Skip hooks once",
+        Command::ExportGraph => "Export graph",
+        Command::ExportPatches => "Export patches",
+        Command::ApplyPatch => "Apply patch",
         Command::SoloBranch => "Solo branch",
         Command::ToggleBranch => "Toggle branch",
         Command::CreateBranch => "Create branch",
@@ -244,6 +442,8 @@ pub fn command_to_visual_string(command: &Command) -> String {
         Command::Merge => "Merge",
         Command::ContinueOperation => "Continue operation",
         Command::AbortOperation => "Abort operation",
+        Command::Undo => "Undo",
+        Command::ToggleJournal => "Operation journal",
         Command::CreateWorktree => "Create worktree",
         Command::RemoveWorktree => "Remove worktree",
         Command::ToggleWorktreeLock => "Toggle worktree lock",
@@ -251,6 +451,7 @@ pub fn command_to_visual_string(command: &Command) -> String {
         Command::SyncSubmodule => "Sync URL",
         Command::Reload => "Reload",
         Command::ReloadAllBranches => "Reload all branches",
+        Command::Custom(name) => return name.clone(),
     };
     localisation::command_label(label).to_string()
 }
@@ -286,6 +487,9 @@ fn shifted_digit_visual_binding(binding: &KeyBinding) -> Option<KeyBinding> {
 }
 
 pub fn keybinding_to_visual_string(binding: &KeyBinding) -> String {
+    if binding.is_sequence() {
+        return binding.keys().iter().map(keybinding_to_visual_string).collect::<Vec<_>>().join(" ");
+    }
     if let Some(visual_binding) = shifted_digit_visual_binding(binding) {
         return keybinding_to_visual_string(&visual_binding);
     }
@@ -309,6 +513,8 @@ fn shifted_digit_alias(binding: &KeyBinding) -> Option<KeyBinding> {
         '@' | '"' => '2',
         '#' | '§' => '3',
         '$' => '4',
+        '%' => '5',
+        '^' => '6',
         _ => return None,
     };
 
@@ -319,14 +525,14 @@ fn shifted_digit_alias(binding: &KeyBinding) -> Option<KeyBinding> {
 
 fn legacy_ctrl_digit_alias(binding: &KeyBinding) -> Option<KeyBinding> {
     match binding {
-        KeyBinding { code: KeyCode::Char('0'..='4'), modifiers } if *modifiers == KeyModifiers::CONTROL => Some(KeyBinding::new(binding.code, KeyModifiers::SHIFT)),
+        KeyBinding { code: KeyCode::Char('0'..='4'), modifiers, .. } if *modifiers == KeyModifiers::CONTROL => Some(KeyBinding::new(binding.code, KeyModifiers::SHIFT)),
         _ => None,
     }
 }
 
 fn legacy_ctrl_binding_for_shift_digit(binding: &KeyBinding) -> Option<KeyBinding> {
     match binding {
-        KeyBinding { code: KeyCode::Char('0'..='4'), modifiers } if *modifiers == KeyModifiers::SHIFT => Some(KeyBinding::new(binding.code, KeyModifiers::CONTROL)),
+        KeyBinding { code: KeyCode::Char('0'..='4'), modifiers, .. } if *modifiers == KeyModifiers::SHIFT => Some(KeyBinding::new(binding.code, KeyModifiers::CONTROL)),
         _ => None,
     }
 }
@@ -341,6 +547,51 @@ pub fn command_for_key_binding(map: &ModeKeymap, binding: &KeyBinding) -> Option
         .or_else(|| legacy_ctrl_binding_for_shift_digit(binding).and_then(|alias| map.get(&alias).cloned()))
 }
 
+pub fn match_key_sequence(map: &ModeKeymap, pending: &[KeyBinding], key: &KeyBinding) -> KeySequenceMatch {
+    let candidate = KeyBinding { prefix: pending.to_vec(), ..KeyBinding::new(key.code, key.modifiers) };
+    let exact = if pending.is_empty() { command_for_key_binding(map, key) } else { map.get(&candidate).cloned() };
+    let keys = candidate.keys();
+
+    if map.keys().any(|binding| binding.extends(&keys)) {
+        KeySequenceMatch::Prefix(exact)
+    } else if let Some(command) = exact {
+        KeySequenceMatch::Command(command)
+    } else {
+        KeySequenceMatch::None
+    }
+}
+
+pub fn key_sequence_continuations(map: &ModeKeymap, pending: &[KeyBinding]) -> Vec<KeyContinuation> {
+    let mut continuations: IndexMap<KeyBinding, KeyContinuation> = IndexMap::new();
+    for (binding, command) in map.iter().filter(|(binding, _)| binding.extends(pending)) {
+        let keys = binding.keys();
+        let key = keys[pending.len()].clone();
+        let continuation = continuations.entry(key.clone()).or_insert(KeyContinuation { key, command: None, sequences: 0 });
+        if keys.len() == pending.len() + 1 {
+            continuation.command = Some(command.clone());
+        } else {
+            continuation.sequences += 1;
+        }
+    }
+    continuations.into_values().collect()
+}
+
+pub fn leader_key(map: &ModeKeymap) -> Option<KeyBinding> {
+    map.iter().find(|(binding, command)| **command == Command::Leader && !binding.is_sequence()).map(|(binding, _)| binding.clone())
+}
+
+// The leader only ever starts sequences, so it never conflicts with them.
+fn prefix_conflict(map: &ModeKeymap, old_key: &KeyBinding, command: &Command, new_key: &KeyBinding) -> Option<(KeyBinding, Command)> {
+    if *command == Command::Leader {
+        return None;
+    }
+    let new_keys = new_key.keys();
+    map.iter()
+        .filter(|(key, existing)| *key != old_key && **existing != Command::Leader)
+        .find(|(key, _)| key.extends(&new_keys) || new_key.extends(&key.keys()))
+        .map(|(key, existing)| (key.clone(), existing.clone()))
+}
+
 fn rebind_key_in_mode(maps: &mut Keymaps, mode: InputMode, old_key: &KeyBinding, command: &Command, new_key: &KeyBinding) -> Result<(), KeymapEditError> {
     let Some(mode_map) = maps.get_mut(&mode) else {
         return Err(KeymapEditError::MissingMode(mode));
@@ -375,13 +626,27 @@ fn rebind_key_in_mode(maps: &mut Keymaps, mode: InputMode, old_key: &KeyBinding,
         None => {},
     }
 
+    if let Some((key, existing)) = prefix_conflict(mode_map, old_key, command, new_key) {
+        return Err(KeymapEditError::PrefixConflict { mode, key, command: existing });
+    }
+
+    // Moving the leader takes the sequences it starts along with it.
+    let moves_leader = *command == Command::Leader && !old_key.is_sequence();
     let mut updated = IndexMap::with_capacity(mode_map.len());
     for (key, value) in mode_map.iter() {
-        if key == old_key {
-            updated.insert(new_key.clone(), value.clone());
+        let key = if key == old_key {
+            new_key.clone()
+        } else if moves_leader && key.prefix.first() == Some(old_key) {
+            let mut moved = key.clone();
+            moved.prefix.splice(0..1, new_key.keys());
+            if let Some(existing) = mode_map.get(&moved) {
+                return Err(KeymapEditError::Conflict { mode, key: moved.clone(), command: existing.clone() });
+            }
+            moved
         } else {
-            updated.insert(key.clone(), value.clone());
-        }
+            key.clone()
+        };
+        updated.insert(key, value.clone());
     }
     *mode_map = updated;
     Ok(())
@@ -508,8 +773,8 @@ fn default_navigation_keymap() -> IndexMap<KeyBinding, Command> {
 
     // Full-page scrolling (Vim-style)
 
-    // 'g' goes to beginning because single-key handling cannot distinguish vim's 'gg' yet.
-    map.insert(KeyBinding::new(Char('g'), KeyModifiers::NONE), Command::GoToBeginning);
+    // 'g g' goes to beginning (vim's 'gg')
+    map.insert(key_sequence("gg"), Command::GoToBeginning);
 
     // 'G' for end
     map.insert(KeyBinding::new(Char('G'), KeyModifiers::SHIFT), Command::GoToEnd);
@@ -544,14 +809,42 @@ fn default_navigation_keymap() -> IndexMap<KeyBinding, Command> {
     // ']' for commit navigation, smaller jumps to an older commit in the topology
     map.insert(KeyBinding::new(Char(']'), KeyModifiers::NONE), Command::ScrollDownCommit);
 
+    // 'x' marks commits for a multi-commit cherry-pick or revert, like ticking a checkbox
+    map.insert(KeyBinding::new(Char('x'), KeyModifiers::NONE), Command::MarkCommit);
+
+    // 'X' marks every commit between the last mark and the selected commit
+    map.insert(KeyBinding::new(Char('X'), KeyModifiers::SHIFT), Command::MarkRange);
+
+    // Ctrl-X clears all marks
+    map.insert(KeyBinding::new(Char('x'), KeyModifiers::CONTROL), Command::ClearMarks);
+
+    // 'V' starts a visual range in the focused pane, like vim's line-wise visual mode
+    map.insert(KeyBinding::new(Char('V'), KeyModifiers::SHIFT), Command::VisualMode);
+
+    // ';' then digits repeats the next graph motion, so lone digits keep toggling panes
+    map.insert(KeyBinding::new(Char(';'), KeyModifiers::NONE), Command::Count);
+
+    // 'm' then a letter names the selected commit, as in vim
+    map.insert(KeyBinding::new(Char('m'), KeyModifiers::NONE), Command::SetMark);
+
+    // Apostrophe then a letter jumps back to a named commit, as in vim
+    map.insert(KeyBinding::new(Char('\''), KeyModifiers::NONE), Command::JumpToMark);
+
+    // Ctrl-O and Ctrl-F walk the jump list of lookups and jumps; Ctrl-I would arrive as Tab on most terminals
+    map.insert(KeyBinding::new(Char('o'), KeyModifiers::CONTROL), Command::JumpBack);
+    map.insert(KeyBinding::new(Char('f'), KeyModifiers::CONTROL), Command::JumpForward);
+
     // Viewer specific navigation
 
-    // 'm' to toggle viewer mode, between full and hunks only view
-    map.insert(KeyBinding::new(Char('m'), KeyModifiers::NONE), Command::ToggleHunkMode);
+    // 'M' to toggle viewer mode, between full and hunks only view
+    map.insert(KeyBinding::new(Char('M'), KeyModifiers::SHIFT), Command::ToggleHunkMode);
 
     // 'v' toggles the side-by-side diff viewer
     map.insert(KeyBinding::new(Char('v'), KeyModifiers::NONE), Command::ToggleSplitDiffMode);
 
+    // 'L' loads the rest of a file the viewer truncated at its size limit
+    map.insert(KeyBinding::new(Char('L'), KeyModifiers::SHIFT), Command::LoadFullDiff);
+
     // UI toggles
 
     // 'z' for zen mode
@@ -571,6 +864,10 @@ fn default_navigation_keymap() -> IndexMap<KeyBinding, Command> {
     map.insert(KeyBinding::new(Char('2'), KeyModifiers::SHIFT), Command::ToggleGraphDates);
     map.insert(KeyBinding::new(Char('3'), KeyModifiers::SHIFT), Command::ToggleGraphCommitters);
     map.insert(KeyBinding::new(Char('4'), KeyModifiers::SHIFT), Command::ToggleGraphRefs);
+    // '%' shows signature status, verified with gpg or ssh-keygen for visible rows only.
+    map.insert(KeyBinding::new(Char('5'), KeyModifiers::SHIFT), Command::ToggleGraphSignatures);
+    // '^' walks the parents commits were written with instead of those from refs/replace/*.
+    map.insert(KeyBinding::new(Char('6'), KeyModifiers::SHIFT), Command::ToggleOriginalParents);
 
     // Help and settings
     map.insert(KeyBinding::new(Char('?'), KeyModifiers::NONE), Command::ToggleHelp);
@@ -579,6 +876,10 @@ fn default_navigation_keymap() -> IndexMap<KeyBinding, Command> {
     // This is where dangerous/destructive operations live
     map.insert(KeyBinding::new(Char('a'), KeyModifiers::CONTROL), Command::ActionMode);
 
+    // '\' is the leader key; the sequences it starts are listed in a popup while typing them
+    map.insert(KeyBinding::new(Char('\\'), KeyModifiers::NONE), Command::Leader);
+    map.extend(leader_sequence_defaults());
+
     // '.' minimizes panels, trading vim repeat behavior for a compact UI command.
     map.insert(KeyBinding::new(Char('.'), KeyModifiers::NONE), Command::Minimize);
 
@@ -620,6 +921,21 @@ fn default_normal_keymap() -> IndexMap<KeyBinding, Command> {
     // 'w' for worktree creation
     map.insert(KeyBinding::new(Char('w'), KeyModifiers::NONE), Command::CreateWorktree);
 
+    // 'e' exports the selected or marked commits as a patch series
+    map.insert(KeyBinding::new(Char('e'), KeyModifiers::NONE), Command::ExportPatches);
+
+    // 'E' exports the visible graph as an SVG or HTML image
+    map.insert(KeyBinding::new(Char('E'), KeyModifiers::SHIFT), Command::ExportGraph);
+
+    // 'n' skips verification hooks for the next commit, merge, rebase or push ("no verify")
+    map.insert(KeyBinding::new(Char('n'), KeyModifiers::NONE), Command::SkipHooks);
+
+    // ':' opens the command palette, like vim's command line
+    map.insert(KeyBinding::new(Char(':'), KeyModifiers::NONE), Command::CommandPalette);
+
+    // 'Z' opens the operation journal, the history that undo walks back through
+    map.insert(KeyBinding::new(Char('Z'), KeyModifiers::SHIFT), Command::ToggleJournal);
+
     // 'T' for toggling selected branch visibility.
     map.insert(KeyBinding::new(Char('T'), KeyModifiers::SHIFT), Command::ToggleBranch);
 
@@ -636,6 +952,11 @@ fn default_normal_keymap() -> IndexMap<KeyBinding, Command> {
     // Backspace returns from a submodule to its parent repository when there is a session stack.
     map.insert(KeyBinding::new(Backspace, KeyModifiers::NONE), Command::ReturnToParentRepository);
 
+    // 'g t' and 'g T' cycle repository tabs (vim's gt/gT).
+    for (key, command) in tab_sequence_defaults() {
+        map.insert(key, command);
+    }
+
     // '-' and '+' adjust the graph lane cap without entering settings.
     map.insert(KeyBinding::new(Char('-'), KeyModifiers::NONE), Command::ShrinkGraphLaneLimit);
     map.insert(KeyBinding::new(Char('+'), KeyModifiers::NONE), Command::GrowGraphLaneLimit);
@@ -648,6 +969,7 @@ fn default_action_keymap() -> IndexMap<KeyBinding, Command> {
 
     let mut map = default_normal_keymap();
     map.retain(|_, command| !matches!(command, Command::ShrinkGraphLaneLimit | Command::GrowGraphLaneLimit));
+    remove_leader_bindings(&mut map);
 
     // Dangerous/destructive git operations (action mode only)
 
@@ -669,6 +991,9 @@ fn default_action_keymap() -> IndexMap<KeyBinding, Command> {
     // 'M' for mixed reset (capital to indicate caution)
     map.insert(KeyBinding::new(Char('M'), KeyModifiers::SHIFT), Command::MixedReset);
 
+    // 'O' for soft reset, which only moves the branch and keeps index and worktree
+    map.insert(KeyBinding::new(Char('O'), KeyModifiers::SHIFT), Command::SoftReset);
+
     // 'P' for force push (capital P to indicate DANGER)
     map.insert(KeyBinding::new(Char('P'), KeyModifiers::SHIFT), Command::ForcePush);
 
@@ -702,6 +1027,12 @@ fn default_action_keymap() -> IndexMap<KeyBinding, Command> {
     // 'A' aborts an in-progress rebase, cherry-pick, or merge from action mode.
     map.insert(KeyBinding::new(Char('A'), KeyModifiers::SHIFT), Command::AbortOperation);
 
+    // 'a' applies a patch or mailbox to the worktree, index, or as commits like `git am`
+    map.insert(KeyBinding::new(Char('a'), KeyModifiers::NONE), Command::ApplyPatch);
+
+    // Ctrl-Z undoes the newest journal entry, restoring refs and dropped stashes.
+    map.insert(KeyBinding::new(Char('z'), KeyModifiers::CONTROL), Command::Undo);
+
     // 'W' removes/prunes a selected worktree.
     map.insert(KeyBinding::new(Char('W'), KeyModifiers::SHIFT), Command::RemoveWorktree);
 
@@ -717,6 +1048,30 @@ fn default_action_keymap() -> IndexMap<KeyBinding, Command> {
     map
 }
 
+// Plain keys typed one after another, such as "gg".
+fn key_sequence(keys: &str) -> KeyBinding {
+    let keys: Vec<KeyBinding> = keys.chars().map(|key| KeyBinding::new(Char(key), KeyModifiers::NONE)).collect();
+    KeyBinding::sequence(&keys).unwrap()
+}
+
+fn leader_sequence_defaults() -> Vec<(KeyBinding, Command)> {
+    vec![
+        (key_sequence("\\ff"), Command::FindFile),
+        (key_sequence("\\fc"), Command::Find),
+        (key_sequence("\\fg"), Command::SearchContents),
+        (key_sequence("\\p"), Command::CommandPalette),
+        (key_sequence("\\j"), Command::ToggleJournal),
+        (key_sequence("\\e"), Command::ExportGraph),
+        (key_sequence("\\tn"), Command::NewTab),
+        (key_sequence("\\tc"), Command::CloseTab),
+    ]
+}
+
+fn tab_sequence_defaults() -> Vec<(KeyBinding, Command)> {
+    let previous = KeyBinding::sequence(&[KeyBinding::new(Char('g'), KeyModifiers::NONE), KeyBinding::new(Char('T'), KeyModifiers::SHIFT)]).unwrap();
+    vec![(key_sequence("gt"), Command::NextTab), (previous, Command::PreviousTab)]
+}
+
 fn default_keymaps() -> Keymaps {
     let mut maps = IndexMap::new();
 
@@ -828,6 +1183,22 @@ fn insert_default_binding_if_available(map: &mut ModeKeymap, key: KeyBinding, co
     }
 }
 
+// Sequences also need every longer or shorter binding they would shadow to be free.
+fn insert_default_sequence_if_available(map: &mut ModeKeymap, key: KeyBinding, command: Command) -> bool {
+    if prefix_conflict(map, &key, &command, &key).is_some() {
+        return false;
+    }
+    insert_default_binding_if_available(map, key, command)
+}
+
+// Leader sequences are normal mode only; action mode keys are single presses that leave the mode.
+fn remove_leader_bindings(map: &mut ModeKeymap) -> bool {
+    let leader = leader_key(map);
+    let before = map.len();
+    map.retain(|key, command| *command != Command::Leader && (leader.is_none() || key.prefix.first() != leader.as_ref()));
+    map.len() != before
+}
+
 fn ensure_default_keymap_bindings(maps: &mut Keymaps) -> bool {
     let mut changed = false;
     let shared_defaults = [
@@ -843,6 +1214,8 @@ fn ensure_default_keymap_bindings(maps: &mut Keymaps) -> bool {
         (KeyBinding::new(Char('2'), KeyModifiers::SHIFT), Command::ToggleGraphDates),
         (KeyBinding::new(Char('3'), KeyModifiers::SHIFT), Command::ToggleGraphCommitters),
         (KeyBinding::new(Char('4'), KeyModifiers::SHIFT), Command::ToggleGraphRefs),
+        (KeyBinding::new(Char('5'), KeyModifiers::SHIFT), Command::ToggleGraphSignatures),
+        (KeyBinding::new(Char('6'), KeyModifiers::SHIFT), Command::ToggleOriginalParents),
         (KeyBinding::new(Left, KeyModifiers::CONTROL), Command::FocusPaneLeft),
         (KeyBinding::new(Down, KeyModifiers::CONTROL), Command::FocusPaneDown),
         (KeyBinding::new(Up, KeyModifiers::CONTROL), Command::FocusPaneUp),
@@ -851,6 +1224,14 @@ fn ensure_default_keymap_bindings(maps: &mut Keymaps) -> bool {
         (KeyBinding::new(Down, KeyModifiers::CONTROL | KeyModifiers::ALT), Command::ResizePaneDown),
         (KeyBinding::new(Up, KeyModifiers::CONTROL | KeyModifiers::ALT), Command::ResizePaneUp),
         (KeyBinding::new(Right, KeyModifiers::CONTROL | KeyModifiers::ALT), Command::ResizePaneRight),
+        (KeyBinding::new(Char('Z'), KeyModifiers::SHIFT), Command::ToggleJournal),
+        (KeyBinding::new(Char('e'), KeyModifiers::NONE), Command::ExportPatches),
+        (KeyBinding::new(Char('E'), KeyModifiers::SHIFT), Command::ExportGraph),
+        (KeyBinding::new(Char(':'), KeyModifiers::NONE), Command::CommandPalette),
+        (KeyBinding::new(Char('n'), KeyModifiers::NONE), Command::SkipHooks),
+        (KeyBinding::new(Char('\''), KeyModifiers::NONE), Command::JumpToMark),
+        (KeyBinding::new(Char('o'), KeyModifiers::CONTROL), Command::JumpBack),
+        (KeyBinding::new(Char('f'), KeyModifiers::CONTROL), Command::JumpForward),
     ];
     for mode in [InputMode::Normal, InputMode::Action] {
         let mode_map = maps.entry(mode).or_default();
@@ -860,11 +1241,42 @@ fn ensure_default_keymap_bindings(maps: &mut Keymaps) -> bool {
         if rewrite_untouched_ctrl_graph_metadata_defaults(mode_map) {
             changed = true;
         }
+        if rewrite_default_group(mode_map, &[(KeyBinding::new(Char('i'), KeyModifiers::CONTROL), Command::JumpForward)], &[(KeyBinding::new(Char('f'), KeyModifiers::CONTROL), Command::JumpForward)]) {
+            changed = true;
+        }
+        // 'g' alone went to the beginning until sequences existed; 'g g' frees 'g' for the tab sequences.
+        if rewrite_default_group(mode_map, &[(KeyBinding::new(Char('g'), KeyModifiers::NONE), Command::GoToBeginning)], &[(key_sequence("gg"), Command::GoToBeginning)]) {
+            changed = true;
+        }
         for (key, command) in shared_defaults.iter() {
             if insert_default_binding_if_available(mode_map, key.clone(), command.clone()) {
                 changed = true;
             }
         }
+        if mode == InputMode::Action {
+            if remove_leader_bindings(mode_map) {
+                changed = true;
+            }
+            continue;
+        }
+        // Leader sequences arrive with the leader, so rebinding one away later does not bring it back.
+        if insert_default_binding_if_available(mode_map, KeyBinding::new(Char('\\'), KeyModifiers::NONE), Command::Leader) {
+            for (key, command) in leader_sequence_defaults() {
+                if !mode_map.contains_key(&key) && prefix_conflict(mode_map, &key, &command, &key).is_none() {
+                    mode_map.insert(key, command);
+                }
+            }
+            changed = true;
+        }
+        // Tab and content search commands came after the leader, so older keymaps pick up their leader sequences here.
+        if let Some(leader) = leader_key(mode_map) {
+            for (key, command) in leader_sequence_defaults().into_iter().filter(|(_, command)| matches!(command, Command::NewTab | Command::CloseTab | Command::SearchContents)) {
+                let key = KeyBinding::sequence(&[vec![leader.clone()], key.keys().split_off(1)].concat()).unwrap();
+                if insert_default_sequence_if_available(mode_map, key, command) {
+                    changed = true;
+                }
+            }
+        }
     }
     let normal_map = maps.entry(InputMode::Normal).or_default();
     let reload_all_key = KeyBinding::new(Char('R'), KeyModifiers::SHIFT);
@@ -877,12 +1289,36 @@ fn ensure_default_keymap_bindings(maps: &mut Keymaps) -> bool {
         normal_map.insert(return_parent_key, Command::ReturnToParentRepository);
         changed = true;
     }
-    let normal_only_defaults = [(KeyBinding::new(Char('-'), KeyModifiers::NONE), Command::ShrinkGraphLaneLimit), (KeyBinding::new(Char('+'), KeyModifiers::NONE), Command::GrowGraphLaneLimit)];
+    // 'm' moved from hunk mode to marks, so untouched keymaps swap the two; older ones never had marks.
+    let hunk_mode = (KeyBinding::new(Char('m'), KeyModifiers::NONE), Command::ToggleHunkMode);
+    let swapped = [(KeyBinding::new(Char('m'), KeyModifiers::NONE), Command::SetMark), (KeyBinding::new(Char('M'), KeyModifiers::SHIFT), Command::ToggleHunkMode)];
+    let had_marks = normal_map.values().any(|existing| existing == &Command::SetMark);
+    if rewrite_default_group(normal_map, &[hunk_mode.clone(), (KeyBinding::new(Char('M'), KeyModifiers::SHIFT), Command::SetMark)], &swapped)
+        || (!had_marks && rewrite_default_group(normal_map, &[hunk_mode], &swapped))
+    {
+        changed = true;
+    }
+    let normal_only_defaults = [
+        (KeyBinding::new(Char('-'), KeyModifiers::NONE), Command::ShrinkGraphLaneLimit),
+        (KeyBinding::new(Char('+'), KeyModifiers::NONE), Command::GrowGraphLaneLimit),
+        (KeyBinding::new(Char('x'), KeyModifiers::NONE), Command::MarkCommit),
+        (KeyBinding::new(Char('X'), KeyModifiers::SHIFT), Command::MarkRange),
+        (KeyBinding::new(Char('x'), KeyModifiers::CONTROL), Command::ClearMarks),
+        (KeyBinding::new(Char('V'), KeyModifiers::SHIFT), Command::VisualMode),
+        (KeyBinding::new(Char(';'), KeyModifiers::NONE), Command::Count),
+        (KeyBinding::new(Char('m'), KeyModifiers::NONE), Command::SetMark),
+        (KeyBinding::new(Char('L'), KeyModifiers::SHIFT), Command::LoadFullDiff),
+    ];
     for (key, command) in normal_only_defaults {
         if insert_default_binding_if_available(normal_map, key, command) {
             changed = true;
         }
     }
+    for (key, command) in tab_sequence_defaults() {
+        if insert_default_sequence_if_available(normal_map, key, command) {
+            changed = true;
+        }
+    }
     let action_map = maps.entry(InputMode::Action).or_default();
     let rename_branch_key = KeyBinding::new(Char('B'), KeyModifiers::SHIFT);
     if !action_map.values().any(|existing| existing == &Command::RenameBranch) && !action_map.contains_key(&rename_branch_key) {
@@ -894,6 +1330,15 @@ fn ensure_default_keymap_bindings(maps: &mut Keymaps) -> bool {
         action_map.insert(update_submodule_key, Command::UpdateSubmodule);
         changed = true;
     }
+    if insert_default_binding_if_available(action_map, KeyBinding::new(Char('z'), KeyModifiers::CONTROL), Command::Undo) {
+        changed = true;
+    }
+    if insert_default_binding_if_available(action_map, KeyBinding::new(Char('O'), KeyModifiers::SHIFT), Command::SoftReset) {
+        changed = true;
+    }
+    if insert_default_binding_if_available(action_map, KeyBinding::new(Char('a'), KeyModifiers::NONE), Command::ApplyPatch) {
+        changed = true;
+    }
     let sync_submodule_key = KeyBinding::new(Char('I'), KeyModifiers::SHIFT);
     if !action_map.values().any(|existing| existing == &Command::SyncSubmodule) && !action_map.contains_key(&sync_submodule_key) {
         action_map.insert(sync_submodule_key, Command::SyncSubmodule);
@@ -902,15 +1347,39 @@ fn ensure_default_keymap_bindings(maps: &mut Keymaps) -> bool {
     changed
 }
 
+// The last key of a binding; `prefix` holds the keys pressed before it in a sequence such as `g g`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct KeyBinding {
     pub code: KeyCode,
     pub modifiers: KeyModifiers,
+    pub prefix: Vec<KeyBinding>,
 }
 
 impl KeyBinding {
     pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
-        Self { code, modifiers }
+        Self { code, modifiers, prefix: Vec::new() }
+    }
+
+    // None for an empty list of keys.
+    pub fn sequence(keys: &[KeyBinding]) -> Option<Self> {
+        let (last, prefix) = keys.split_last()?;
+        Some(Self { prefix: prefix.to_vec(), ..Self::new(last.code, last.modifiers) })
+    }
+
+    pub fn is_sequence(&self) -> bool {
+        !self.prefix.is_empty()
+    }
+
+    // Every key in press order, each as a single-key binding.
+    pub fn keys(&self) -> Vec<KeyBinding> {
+        let mut keys = self.prefix.clone();
+        keys.push(Self::new(self.code, self.modifiers));
+        keys
+    }
+
+    // True when this is a longer sequence that begins with `keys`.
+    pub fn extends(&self, keys: &[KeyBinding]) -> bool {
+        self.prefix.len() >= keys.len() && self.prefix[..keys.len()] == *keys
     }
 }
 
@@ -924,9 +1393,19 @@ struct KeymapConfig {
 struct KeyBindingEntry {
     key: String,
     modifiers: Vec<String>,
+    // Keys pressed before `key`; "Leader" stands for the key bound to the Leader command.
+    #[facet(default, skip_serializing_if = Vec::is_empty)]
+    prefix: Vec<KeyStrokeEntry>,
     command: Command,
 }
 
+#[derive(Facet)]
+struct KeyStrokeEntry {
+    key: String,
+    #[facet(default)]
+    modifiers: Vec<String>,
+}
+
 pub fn keycode_to_string(code: KeyCode) -> String {
     match code {
         KeyCode::Backspace => "Backspace".into(),
@@ -1059,8 +1538,29 @@ pub fn parse_modifiers(mods: &[String]) -> Result<KeyModifiers, String> {
     Ok(km)
 }
 
+const LEADER_KEY: &str = "Leader";
+
 fn keymap_entries(map: &ModeKeymap) -> Vec<KeyBindingEntry> {
-    map.iter().map(|(kb, cmd)| KeyBindingEntry { key: keycode_to_string(kb.code), modifiers: modifiers_to_vec(kb.modifiers), command: cmd.clone() }).collect()
+    let leader = leader_key(map);
+    map.iter()
+        .map(|(kb, cmd)| KeyBindingEntry {
+            key: keycode_to_string(kb.code),
+            modifiers: modifiers_to_vec(kb.modifiers),
+            prefix: kb
+                .prefix
+                .iter()
+                .enumerate()
+                .map(|(idx, stroke)| {
+                    if idx == 0 && leader.as_ref() == Some(stroke) {
+                        KeyStrokeEntry { key: LEADER_KEY.to_string(), modifiers: Vec::new() }
+                    } else {
+                        KeyStrokeEntry { key: keycode_to_string(stroke.code), modifiers: modifiers_to_vec(stroke.modifiers) }
+                    }
+                })
+                .collect(),
+            command: cmd.clone(),
+        })
+        .collect()
 }
 
 fn keymaps_to_config(maps: &Keymaps) -> KeymapConfig {
@@ -1071,13 +1571,27 @@ fn keymaps_to_config(maps: &Keymaps) -> KeymapConfig {
     KeymapConfig { normal, action }
 }
 
+fn parse_stroke(stroke: &KeyStrokeEntry, leader: Option<&KeyBinding>) -> Result<KeyBinding, String> {
+    if stroke.key == LEADER_KEY {
+        return leader.cloned().ok_or_else(|| "Leader used in a sequence without a Leader binding".to_string());
+    }
+    Ok(KeyBinding::new(parse_key(&stroke.key)?, parse_modifiers(&stroke.modifiers)?))
+}
+
 fn entries_to_keymap(entries: Vec<KeyBindingEntry>) -> Result<ModeKeymap, String> {
     let mut map = IndexMap::new();
 
+    // The leader has to be known before the sequences that refer to it.
+    let leader = match entries.iter().find(|entry| entry.command == Command::Leader && entry.prefix.is_empty()) {
+        Some(entry) => Some(KeyBinding::new(parse_key(&entry.key)?, parse_modifiers(&entry.modifiers)?)),
+        None => None,
+    };
+
     for entry in entries {
         let key = parse_key(&entry.key)?;
         let mods = parse_modifiers(&entry.modifiers)?;
-        map.insert(KeyBinding::new(key, mods), entry.command);
+        let prefix = entry.prefix.iter().map(|stroke| parse_stroke(stroke, leader.as_ref())).collect::<Result<Vec<_>, _>>()?;
+        map.insert(KeyBinding { prefix, ..KeyBinding::new(key, mods) }, entry.command);
     }
 
     Ok(map)
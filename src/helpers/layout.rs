@@ -12,6 +12,8 @@ pub const LAYOUT_WIDTH_MIN_SIDE_PANE: u16 = 16;
 pub const LAYOUT_HEIGHT_MIN_STACKED_PANE: u16 = 3;
 pub const LAYOUT_WEIGHT_DEFAULT: u16 = 100;
 pub const GRAPH_LANE_LIMIT_DEFAULT: usize = 50;
+// KiB; larger files open truncated in the viewer until the full diff is requested.
+pub const VIEWER_FILE_SIZE_LIMIT_DEFAULT: u64 = 1024;
 
 pub fn inset_top(mut r: Rect, n: u16) -> Rect {
     r.y += n;
@@ -71,6 +73,11 @@ pub struct LayoutConfig {
     #[facet(default = true)]
     pub is_graph_refs: bool,
     #[facet(default = false)]
+    pub is_graph_signatures: bool,
+    // Walk the parents commits were written with, ignoring `refs/replace/*`.
+    #[facet(default = false)]
+    pub is_graph_original_parents: bool,
+    #[facet(default = false)]
     pub is_worktrees: bool,
     #[facet(default = false)]
     pub is_submodules: bool,
@@ -109,6 +116,8 @@ pub struct LayoutConfig {
     pub weight_viewer_split_right: u16,
     #[facet(default = GRAPH_LANE_LIMIT_DEFAULT)]
     pub graph_lane_limit: usize,
+    #[facet(default = VIEWER_FILE_SIZE_LIMIT_DEFAULT)]
+    pub viewer_file_size_limit: u64,
 }
 
 impl Default for LayoutConfig {
@@ -125,6 +134,8 @@ impl Default for LayoutConfig {
             is_graph_dates: false,
             is_graph_committers: false,
             is_graph_refs: true,
+            is_graph_signatures: false,
+            is_graph_original_parents: false,
             is_worktrees: false,
             is_submodules: false,
             is_status: true,
@@ -146,6 +157,7 @@ impl Default for LayoutConfig {
             weight_viewer_split_left: LAYOUT_WEIGHT_DEFAULT,
             weight_viewer_split_right: LAYOUT_WEIGHT_DEFAULT,
             graph_lane_limit: GRAPH_LANE_LIMIT_DEFAULT,
+            viewer_file_size_limit: VIEWER_FILE_SIZE_LIMIT_DEFAULT,
         }
     }
 }
@@ -168,6 +180,7 @@ impl LayoutConfig {
         self.weight_viewer_split_left = self.weight_viewer_split_left.max(1);
         self.weight_viewer_split_right = self.weight_viewer_split_right.max(1);
         self.graph_lane_limit = self.graph_lane_limit.max(1);
+        self.viewer_file_size_limit = self.viewer_file_size_limit.max(1);
         self
     }
 }
@@ -203,6 +203,7 @@ fn es(en: &'static str) -> &'static str {
         "Reflog commit is hidden from the graph. Press 9 to show graph reflogs." => "El commit del reflog está oculto en el grafo. Pulsa 9 para mostrar reflogs.",
         "Reset file failed" => "Restablecer archivo falló",
         "Revert failed" => "Revert falló",
+        "Cherry-pick failed: cherry-picking marked merge commits is not supported" => "Cherry-pick falló: hacer cherry-pick de commits de merge marcados no está soportado",
         "Revert failed: reverting merge commits is not supported" => "Revert falló: revertir commits de merge no está soportado",
         "Revert failed: no commit message was provided" => "Revert falló: no se proporcionó mensaje de commit",
         "Revert failed: no commit is pending" => "Revert falló: no hay ningún commit pendiente",
@@ -1046,6 +1047,152 @@ fn es_extra(en: &'static str) -> &'static str {
     match en {
         " settings" => " configuración",
         " status" => " estado",
+        "Mark commit" => "Marcar commit",
+        "Mark range" => "Marcar rango",
+        "Clear marks" => "Borrar marcas",
+        "Unmark commit" => "Desmarcar commit",
+        "Cherry-pick marked commits" => "Cherry-pick de commits marcados",
+        "Revert marked commits" => "Revert de commits marcados",
+        "Sequence aborted and HEAD restored." => "Secuencia abortada y HEAD restaurado.",
+        "Commit failed: worker thread panicked" => "Error del commit: el hilo de trabajo falló",
+        "Signing the commit..." => "Firmando el commit...",
+        "Mark range failed: mark a commit first" => "Marcar rango falló: marca un commit primero",
+        "Mark range failed" => "Marcar rango falló",
+        "select the parent to keep" => "selecciona el padre a conservar",
+        "diff against selected parent:" => "diff contra el padre seleccionado:",
+        "no changes" => "sin cambios",
+        "Undo failed" => "Error al deshacer",
+        "Undo failed: the journal is empty" => "Error al deshacer: el historial está vacío",
+        "no journal entries" => "sin entradas en el historial",
+        "select an entry to undo back to" => "selecciona hasta qué entrada deshacer",
+        "Undo" => "Deshacer",
+        "Operation journal" => "Historial de operaciones",
+        "Reset preview failed" => "Error en la vista previa del reset",
+        "Soft reset failed" => "Soft reset falló",
+        "Soft reset" => "Soft reset",
+        "Reset branch" => "Restablecer rama",
+        "branch:" => "rama:",
+        "target:" => "destino:",
+        "local changes a hard reset overwrites:" => "cambios locales que sobrescribe un hard reset:",
+        "commits leaving the branch:" => "commits que salen de la rama:",
+        "no local changes are overwritten" => "no se sobrescriben cambios locales",
+        "no commits leave the branch" => "ningún commit sale de la rama",
+        "Apply patch" => "Aplicar parche",
+        "Apply to index" => "Aplicar al índice",
+        "Apply to worktree" => "Aplicar al worktree",
+        "Commit with original authors" => "Hacer commit con los autores originales",
+        "Export marked commits as patches" => "Exportar commits marcados como parches",
+        "Export as patch" => "Exportar como parche",
+        "Export patches" => "Exportar parches",
+        "patches in file:" => "parches en el archivo:",
+        "patch without subject" => "parche sin asunto",
+        "Enter patch or mailbox path" => "Introduce la ruta del parche o mailbox",
+        "Enter patch file path" => "Introduce la ruta del archivo de parches",
+        "Apply patch failed" => "Aplicar parche falló",
+        "Apply patch failed: no path was provided" => "Aplicar parche falló: no se indicó ninguna ruta",
+        "Apply patch failed: the file contains no patches" => "Aplicar parche falló: el archivo no contiene parches",
+        "Export patches failed" => "Exportar parches falló",
+        "Export patches failed: no path was provided" => "Exportar parches falló: no se indicó ninguna ruta",
+        "Export patches failed: no commit is selected" => "Exportar parches falló: no hay ningún commit seleccionado",
+        "signature:" => "firma:",
+        "bad signature" => "firma no válida",
+        "checking..." => "comprobando...",
+        "good signature by" => "firma válida de",
+        "signed with an unknown key" => "firmado con una clave desconocida",
+        "unsigned" => "sin firmar",
+        "bad" => "no válida",
+        "good" => "válida",
+        "unknown" => "desconocida",
+        "signatures" => "firmas",
+        "Commit failed: the commit message is empty" => "Error al confirmar: el mensaje de confirmación está vacío",
+        "Hook failed" => "Error del hook",
+        "The merge is left staged; continue it to commit anyway or abort it." => "La fusión queda preparada; continúala para confirmarla de todos modos o abórtala.",
+        "Hook failed: worker thread panicked" => "Error del hook: el hilo de trabajo falló",
+        "Merge staged and waiting to be committed." => "Fusión preparada y pendiente de confirmar.",
+        "running hooks" => "ejecutando hooks",
+        "skip hooks" => "omitir hooks",
+        "Skip hooks once" => "Omitir hooks una vez",
+        "hooks skipped" => "hooks omitidos",
+        "Export graph failed" => "Error al exportar el grafo",
+        "Export graph failed: no path was provided" => "Error al exportar el grafo: no se indicó ninguna ruta",
+        "Export graph failed: the graph is still loading" => "Error al exportar el grafo: el grafo aún se está cargando",
+        "Enter graph image path (.svg or .html)" => "Introduce la ruta de la imagen del grafo (.svg o .html)",
+        "run" => "ejecutar",
+        "Run a command" => "Ejecutar un comando",
+        "Command palette" => "Paleta de comandos",
+        "no linked worktrees" => "no hay worktrees vinculados",
+        "no commits are marked" => "no hay commits marcados",
+        "no operation in progress" => "no hay ninguna operación en curso",
+        "open a repository first" => "abre primero un repositorio",
+        "no stashes" => "no hay stashes",
+        "no submodules" => "no hay submódulos",
+        "not inside a submodule" => "no estás dentro de un submódulo",
+        "nothing to undo" => "nada que deshacer",
+        "only on the start screen" => "solo en la pantalla de inicio",
+        "Export graph" => "Exportar grafo",
+        "Custom command failed" => "El comando personalizado falló",
+        "Custom command failed: nothing selected for placeholder" => "El comando personalizado falló: no hay nada seleccionado para el marcador",
+        "Custom command failed: worker thread panicked" => "El comando personalizado falló: el hilo de trabajo entró en pánico",
+        "Custom command failed: no command with this name in custom_commands.json" => "El comando personalizado falló: no hay ningún comando con este nombre en custom_commands.json",
+        "command failed" => "el comando falló",
+        "command finished" => "comando terminado",
+        "Press Enter to return to guitar" => "Pulsa Enter para volver a guitar",
+        "running command" => "ejecutando comando",
+        "not available in this pane" => "no disponible en este panel",
+        "remove key" => "quitar tecla",
+        "backspace" => "retroceso",
+        "Leader key" => "Tecla líder",
+        "Jump failed: the commit is not in the graph, it may be hidden or gone" => "El salto falló: el commit no está en el grafo, puede estar oculto o ya no existir",
+        "Jump to mark failed: no mark with this name" => "Ir a la marca falló: no hay ninguna marca con este nombre",
+        "the jump list is empty" => "la lista de saltos está vacía",
+        "no marks are set" => "no hay marcas definidas",
+        "Set mark" => "Poner marca",
+        "Jump to mark" => "Ir a la marca",
+        "Jump back" => "Saltar atrás",
+        "Jump forward" => "Saltar adelante",
+        "Delete branch failed: marked remote branches must all be on the same remote" => "Error al eliminar la rama: las ramas remotas marcadas deben estar en el mismo remoto",
+        "visual" => "visual",
+        "count" => "cuenta",
+        "Start count" => "Empezar cuenta",
+        "Visual mode" => "Modo visual",
+        "nothing is marked" => "no hay nada marcado",
+        "Merge into here" => "Hacer merge aquí",
+        "Rebase onto here" => "Hacer rebase sobre aquí",
+        "Reset branch to here" => "Restablecer la rama aquí",
+        "Branch reset failed" => "Falló el restablecimiento de la rama",
+        "New tab" => "Nueva pestaña",
+        "Close tab" => "Cerrar pestaña",
+        "Next tab" => "Pestaña siguiente",
+        "Previous tab" => "Pestaña anterior",
+        "new tab" => "nueva pestaña",
+        "only one tab is open" => "solo hay una pestaña abierta",
+        "Switch tab failed: wait for the running operation to finish" => "Error al cambiar de pestaña: espera a que termine la operación en curso",
+        "Load full diff" => "Cargar diff completo",
+        "Enter viewer file size limit (KiB)" => "Introduce el límite de tamaño de archivo del visor (KiB)",
+        " viewer file size limit:" => " límite de tamaño de archivo del visor:",
+        "the viewer shows the whole file" => "el visor ya muestra el archivo completo",
+        "after:" => "después:",
+        "before:" => "antes:",
+        "binary file" => "archivo binario",
+        "hex preview:" => "vista previa hexadecimal:",
+        "git lfs pointer" => "puntero de git lfs",
+        "load full diff:" => "cargar diff completo:",
+        "missing" => "ausente",
+        "shown:" => "mostrado:",
+        "size:" => "tamaño:",
+        "file truncated at the viewer size limit" => "archivo truncado en el límite de tamaño del visor",
+        "Search file contents" => "Buscar en el contenido de los archivos",
+        "regex on/off" => "activar/desactivar regex",
+        " invalid regex:" => " regex no válida:",
+        "searching..." => "buscando...",
+        "too many matches, showing the first ones" => "demasiadas coincidencias, se muestran las primeras",
+        "working tree" => "árbol de trabajo",
+        "Deepen" => "Profundizar",
+        "Deepen shallow history" => "Profundizar historial superficial",
+        "Toggle original parents" => "Alternar padres originales",
+        "original parents" => "padres originales",
+        "Enter number of commits to deepen by" => "Introduce cuántos commits profundizar",
+        "the repository is not a shallow clone" => "el repositorio no es un clon superficial",
         _ => en,
     }
 }
@@ -1140,6 +1287,7 @@ fn fr_extra(en: &'static str) -> &'static str {
         "Revert commit" => "Commit de revert",
         "Revert failed: no commit is pending" => "Échec du revert : aucun commit en attente",
         "Revert failed: no commit message was provided" => "Échec du revert : aucun message de commit fourni",
+        "Cherry-pick failed: cherry-picking marked merge commits is not supported" => "Échec du cherry-pick : le cherry-pick des commits de merge marqués n’est pas pris en charge",
         "Revert failed: reverting merge commits is not supported" => "Échec du revert : le revert des commits de merge n’est pas pris en charge",
         "Revert stopped because conflicts need to be resolved." => "Revert arrêté car des conflits doivent être résolus.",
         "SHAs" => "SHAs",
@@ -1211,6 +1359,156 @@ fn fr_extra(en: &'static str) -> &'static str {
         "stashes" => "stashes",
         "tab" => "tabulation",
         "username/password or token prompt " => "invite nom d’utilisateur/mot de passe ou jeton ",
+        "Mark commit" => "Marquer le commit",
+        "Mark range" => "Marquer la plage",
+        "Clear marks" => "Effacer les marques",
+        "Unmark commit" => "Démarquer le commit",
+        "Cherry-pick marked commits" => "Cherry-pick des commits marqués",
+        "Revert marked commits" => "Revert des commits marqués",
+        "Sequence aborted and HEAD restored." => "Séquence abandonnée et HEAD restauré.",
+        "Commit failed: worker thread panicked" => "Échec du commit : le thread de travail a paniqué",
+        "Signing the commit..." => "Signature du commit...",
+        "Mark range failed: mark a commit first" => "Échec du marquage de plage : marquez d'abord un commit",
+        "Mark range failed" => "Échec du marquage de plage",
+        "select the parent to keep" => "sélectionnez le parent à conserver",
+        "diff against selected parent:" => "diff par rapport au parent sélectionné :",
+        "no changes" => "aucun changement",
+        "Undo failed" => "Échec de l'annulation",
+        "Undo failed: the journal is empty" => "Échec de l'annulation : le journal est vide",
+        "no journal entries" => "aucune entrée de journal",
+        "select an entry to undo back to" => "sélectionnez l'entrée jusqu'à laquelle annuler",
+        "Undo" => "Annuler",
+        "Operation journal" => "Journal des opérations",
+        "Reset preview failed" => "Échec de l'aperçu de la réinitialisation",
+        "Soft reset failed" => "Échec du soft reset",
+        "Soft reset" => "Soft reset",
+        "Reset branch" => "Réinitialiser la branche",
+        "branch:" => "branche :",
+        "target:" => "cible :",
+        "local changes a hard reset overwrites:" => "modifications locales écrasées par un hard reset :",
+        "commits leaving the branch:" => "commits qui quittent la branche :",
+        "no local changes are overwritten" => "aucune modification locale n'est écrasée",
+        "no commits leave the branch" => "aucun commit ne quitte la branche",
+        "Apply patch" => "Appliquer un patch",
+        "Apply to index" => "Appliquer à l'index",
+        "Apply to worktree" => "Appliquer au worktree",
+        "Commit with original authors" => "Commiter avec les auteurs d'origine",
+        "Export marked commits as patches" => "Exporter les commits marqués en patchs",
+        "Export as patch" => "Exporter en patch",
+        "Export patches" => "Exporter des patchs",
+        "patches in file:" => "patchs dans le fichier :",
+        "patch without subject" => "patch sans sujet",
+        "Enter patch or mailbox path" => "Saisir le chemin du patch ou de la mailbox",
+        "Enter patch file path" => "Saisir le chemin du fichier de patchs",
+        "Apply patch failed" => "Échec de l'application du patch",
+        "Apply patch failed: no path was provided" => "Échec de l'application du patch : aucun chemin fourni",
+        "Apply patch failed: the file contains no patches" => "Échec de l'application du patch : le fichier ne contient aucun patch",
+        "Export patches failed" => "Échec de l'export des patchs",
+        "Export patches failed: no path was provided" => "Échec de l'export des patchs : aucun chemin fourni",
+        "Export patches failed: no commit is selected" => "Échec de l'export des patchs : aucun commit sélectionné",
+        "signature:" => "signature :",
+        "bad signature" => "signature invalide",
+        "checking..." => "vérification...",
+        "good signature by" => "signature valide de",
+        "signed with an unknown key" => "signé avec une clé inconnue",
+        "unsigned" => "non signé",
+        "bad" => "invalide",
+        "good" => "valide",
+        "unknown" => "inconnue",
+        "signatures" => "signatures",
+        "Commit failed: the commit message is empty" => "Échec du commit : le message de commit est vide",
+        "Hook failed" => "Échec du hook",
+        "The merge is left staged; continue it to commit anyway or abort it." => "La fusion reste indexée ; continuez-la pour la valider quand même ou abandonnez-la.",
+        "Hook failed: worker thread panicked" => "Échec du hook : le thread de travail a paniqué",
+        "Merge staged and waiting to be committed." => "Fusion indexée en attente de commit.",
+        "running hooks" => "exécution des hooks",
+        "skip hooks" => "ignorer les hooks",
+        "Skip hooks once" => "Ignorer les hooks une fois",
+        "hooks skipped" => "hooks ignorés",
+        "Export graph failed" => "Échec de l'export du graphe",
+        "Export graph failed: no path was provided" => "Échec de l'export du graphe : aucun chemin fourni",
+        "Export graph failed: the graph is still loading" => "Échec de l'export du graphe : le graphe est encore en chargement",
+        "Enter graph image path (.svg or .html)" => "Saisissez le chemin de l'image du graphe (.svg ou .html)",
+        "run" => "exécuter",
+        "Run a command" => "Exécuter une commande",
+        "Command palette" => "Palette de commandes",
+        "no linked worktrees" => "aucun worktree lié",
+        "no commits are marked" => "aucun commit n'est marqué",
+        "no operation in progress" => "aucune opération en cours",
+        "open a repository first" => "ouvrez d'abord un dépôt",
+        "no stashes" => "aucun stash",
+        "no submodules" => "aucun sous-module",
+        "not inside a submodule" => "pas dans un sous-module",
+        "nothing to undo" => "rien à annuler",
+        "only on the start screen" => "uniquement sur l'écran d'accueil",
+        "Export graph" => "Exporter le graphe",
+        "Custom command failed" => "La commande personnalisée a échoué",
+        "Custom command failed: nothing selected for placeholder" => "La commande personnalisée a échoué : rien n'est sélectionné pour l'espace réservé",
+        "Custom command failed: worker thread panicked" => "La commande personnalisée a échoué : le thread de travail a paniqué",
+        "Custom command failed: no command with this name in custom_commands.json" => "La commande personnalisée a échoué : aucune commande de ce nom dans custom_commands.json",
+        "command failed" => "la commande a échoué",
+        "command finished" => "commande terminée",
+        "Press Enter to return to guitar" => "Appuyez sur Entrée pour revenir à guitar",
+        "running command" => "exécution de la commande",
+        "not available in this pane" => "indisponible dans ce panneau",
+        "remove key" => "retirer la touche",
+        "backspace" => "retour arrière",
+        "Leader key" => "Touche leader",
+        "Jump failed: the commit is not in the graph, it may be hidden or gone" => "Le saut a échoué : le commit n'est pas dans le graphe, il est peut-être masqué ou supprimé",
+        "Jump to mark failed: no mark with this name" => "Aller à la marque a échoué : aucune marque ne porte ce nom",
+        "the jump list is empty" => "la liste des sauts est vide",
+        "no marks are set" => "aucune marque n'est définie",
+        "Set mark" => "Poser une marque",
+        "Jump to mark" => "Aller à la marque",
+        "Jump back" => "Saut arrière",
+        "Jump forward" => "Saut avant",
+        "Delete branch failed: marked remote branches must all be on the same remote" => {
+            "Échec de la suppression de la branche : les branches distantes marquées doivent être sur le même dépôt distant"
+        },
+        "visual" => "visuel",
+        "count" => "compte",
+        "Start count" => "Commencer un compte",
+        "Visual mode" => "Mode visuel",
+        "nothing is marked" => "rien n'est marqué",
+        "Merge into here" => "Fusionner ici",
+        "Rebase onto here" => "Rebaser ici",
+        "Reset branch to here" => "Réinitialiser la branche ici",
+        "Branch reset failed" => "Échec de la réinitialisation de la branche",
+        "New tab" => "Nouvel onglet",
+        "Close tab" => "Fermer l'onglet",
+        "Next tab" => "Onglet suivant",
+        "Previous tab" => "Onglet précédent",
+        "new tab" => "nouvel onglet",
+        "only one tab is open" => "un seul onglet est ouvert",
+        "Switch tab failed: wait for the running operation to finish" => "Échec du changement d'onglet : attendez la fin de l'opération en cours",
+        "Load full diff" => "Charger le diff complet",
+        "Enter viewer file size limit (KiB)" => "Saisir la limite de taille de fichier de la visionneuse (Kio)",
+        " viewer file size limit:" => " limite de taille de fichier de la visionneuse :",
+        "the viewer shows the whole file" => "la visionneuse affiche déjà le fichier entier",
+        "after:" => "après :",
+        "before:" => "avant :",
+        "binary file" => "fichier binaire",
+        "hex preview:" => "aperçu hexadécimal :",
+        "git lfs pointer" => "pointeur git lfs",
+        "load full diff:" => "charger le diff complet :",
+        "missing" => "absent",
+        "oid:" => "oid :",
+        "shown:" => "affiché :",
+        "size:" => "taille :",
+        "file truncated at the viewer size limit" => "fichier tronqué à la limite de taille de la visionneuse",
+        "Search file contents" => "Rechercher dans le contenu des fichiers",
+        "regex on/off" => "activer/désactiver regex",
+        " invalid regex:" => " regex invalide :",
+        "literal" => "littéral",
+        "searching..." => "recherche...",
+        "too many matches, showing the first ones" => "trop de correspondances, seules les premières sont affichées",
+        "working tree" => "arbre de travail",
+        "Deepen" => "Approfondir",
+        "Deepen shallow history" => "Approfondir l'historique superficiel",
+        "Toggle original parents" => "Basculer les parents d'origine",
+        "original parents" => "parents d'origine",
+        "Enter number of commits to deepen by" => "Saisissez le nombre de commits à ajouter",
+        "the repository is not a shallow clone" => "le dépôt n'est pas un clone superficiel",
         _ => en,
     }
 }
@@ -1358,6 +1656,7 @@ fn ru_extra(en: &'static str) -> &'static str {
         "Revert failed" => "Revert не удался",
         "Revert failed: no commit is pending" => "Revert не удался: нет ожидающего commit",
         "Revert failed: no commit message was provided" => "Revert не удался: сообщение commit не указано",
+        "Cherry-pick failed: cherry-picking marked merge commits is not supported" => "Cherry-pick не удался: cherry-pick отмеченных merge-коммитов не поддерживается",
         "Revert failed: reverting merge commits is not supported" => "Revert не удался: revert merge-коммитов не поддерживается",
         "Revert stopped because conflicts need to be resolved." => "Revert остановлен: нужно разрешить конфликты.",
         "SHAs" => "SHA",
@@ -1487,6 +1786,153 @@ fn ru_extra(en: &'static str) -> &'static str {
         "user:" => "пользователь:",
         "username/password or token prompt " => "запрос имени пользователя/пароля или токена ",
         "worktrees" => "worktree",
+        "Mark commit" => "Отметить коммит",
+        "Mark range" => "Отметить диапазон",
+        "Clear marks" => "Снять отметки",
+        "Unmark commit" => "Снять отметку с коммита",
+        "Cherry-pick marked commits" => "Cherry-pick отмеченных коммитов",
+        "Revert marked commits" => "Revert отмеченных коммитов",
+        "Sequence aborted and HEAD restored." => "Последовательность прервана, HEAD восстановлен.",
+        "Commit failed: worker thread panicked" => "Ошибка коммита: рабочий поток аварийно завершился",
+        "Signing the commit..." => "Подписание коммита...",
+        "Mark range failed: mark a commit first" => "Не удалось отметить диапазон: сначала отметьте коммит",
+        "Mark range failed" => "Не удалось отметить диапазон",
+        "select the parent to keep" => "выберите родителя, которого сохранить",
+        "diff against selected parent:" => "diff относительно выбранного родителя:",
+        "no changes" => "нет изменений",
+        "Undo failed" => "Не удалось отменить",
+        "Undo failed: the journal is empty" => "Не удалось отменить: журнал пуст",
+        "no journal entries" => "нет записей журнала",
+        "select an entry to undo back to" => "выберите запись, до которой отменить",
+        "Undo" => "Отменить",
+        "Operation journal" => "Журнал операций",
+        "Reset preview failed" => "Не удалось подготовить предпросмотр сброса",
+        "Soft reset failed" => "Soft reset не удался",
+        "Soft reset" => "Soft reset",
+        "Reset branch" => "Сбросить ветку",
+        "branch:" => "ветка:",
+        "target:" => "цель:",
+        "local changes a hard reset overwrites:" => "локальные изменения, которые перезапишет hard reset:",
+        "commits leaving the branch:" => "коммиты, которые покинут ветку:",
+        "no local changes are overwritten" => "локальные изменения не перезаписываются",
+        "no commits leave the branch" => "ни один коммит не покинет ветку",
+        "Apply patch" => "Применить патч",
+        "Apply to index" => "Применить к индексу",
+        "Apply to worktree" => "Применить к worktree",
+        "Commit with original authors" => "Закоммитить с исходными авторами",
+        "Export marked commits as patches" => "Экспортировать отмеченные коммиты как патчи",
+        "Export as patch" => "Экспортировать как патч",
+        "Export patches" => "Экспорт патчей",
+        "patches in file:" => "патчи в файле:",
+        "patch without subject" => "патч без темы",
+        "Enter patch or mailbox path" => "Введите путь к патчу или mailbox",
+        "Enter patch file path" => "Введите путь к файлу патчей",
+        "Apply patch failed" => "Не удалось применить патч",
+        "Apply patch failed: no path was provided" => "Не удалось применить патч: путь не указан",
+        "Apply patch failed: the file contains no patches" => "Не удалось применить патч: в файле нет патчей",
+        "Export patches failed" => "Не удалось экспортировать патчи",
+        "Export patches failed: no path was provided" => "Не удалось экспортировать патчи: путь не указан",
+        "Export patches failed: no commit is selected" => "Не удалось экспортировать патчи: commit не выбран",
+        "signature:" => "подпись:",
+        "bad signature" => "неверная подпись",
+        "checking..." => "проверка...",
+        "good signature by" => "верная подпись от",
+        "signed with an unknown key" => "подписано неизвестным ключом",
+        "unsigned" => "без подписи",
+        "bad" => "неверна",
+        "good" => "верна",
+        "unknown" => "неизвестна",
+        "signatures" => "подписи",
+        "Commit failed: the commit message is empty" => "Не удалось создать коммит: сообщение коммита пустое",
+        "Hook failed" => "Ошибка хука",
+        "The merge is left staged; continue it to commit anyway or abort it." => "Слияние оставлено в индексе; продолжите его, чтобы всё равно закоммитить, или прервите.",
+        "Hook failed: worker thread panicked" => "Ошибка хука: рабочий поток аварийно завершился",
+        "Merge staged and waiting to be committed." => "Слияние подготовлено и ожидает коммита.",
+        "running hooks" => "выполнение хуков",
+        "skip hooks" => "пропустить хуки",
+        "Skip hooks once" => "Пропустить хуки один раз",
+        "hooks skipped" => "хуки пропущены",
+        "Export graph failed" => "Не удалось экспортировать граф",
+        "Export graph failed: no path was provided" => "Не удалось экспортировать граф: путь не указан",
+        "Export graph failed: the graph is still loading" => "Не удалось экспортировать граф: граф ещё загружается",
+        "Enter graph image path (.svg or .html)" => "Введите путь к изображению графа (.svg или .html)",
+        "run" => "выполнить",
+        "Run a command" => "Выполнить команду",
+        "Command palette" => "Палитра команд",
+        "no linked worktrees" => "нет связанных рабочих деревьев",
+        "no commits are marked" => "нет отмеченных коммитов",
+        "no operation in progress" => "нет выполняющейся операции",
+        "open a repository first" => "сначала откройте репозиторий",
+        "no stashes" => "нет stash-записей",
+        "no submodules" => "нет подмодулей",
+        "not inside a submodule" => "вы не внутри подмодуля",
+        "nothing to undo" => "нечего отменять",
+        "only on the start screen" => "только на стартовом экране",
+        "Export graph" => "Экспортировать граф",
+        "Custom command failed" => "Пользовательская команда не выполнена",
+        "Custom command failed: nothing selected for placeholder" => "Пользовательская команда не выполнена: для подстановки ничего не выбрано",
+        "Custom command failed: worker thread panicked" => "Пользовательская команда не выполнена: рабочий поток аварийно завершился",
+        "Custom command failed: no command with this name in custom_commands.json" => "Пользовательская команда не выполнена: в custom_commands.json нет команды с таким именем",
+        "command failed" => "команда не выполнена",
+        "command finished" => "команда завершена",
+        "Press Enter to return to guitar" => "Нажмите Enter, чтобы вернуться в guitar",
+        "running command" => "выполнение команды",
+        "not available in this pane" => "недоступно в этой панели",
+        "remove key" => "убрать клавишу",
+        "backspace" => "backspace",
+        "Leader key" => "Клавиша-лидер",
+        "Jump failed: the commit is not in the graph, it may be hidden or gone" => "Переход не удался: коммита нет в графе, возможно, он скрыт или удалён",
+        "Jump to mark failed: no mark with this name" => "Переход к метке не удался: метки с таким именем нет",
+        "the jump list is empty" => "список переходов пуст",
+        "no marks are set" => "метки не заданы",
+        "Set mark" => "Поставить метку",
+        "Jump to mark" => "Перейти к метке",
+        "Jump back" => "Переход назад",
+        "Jump forward" => "Переход вперёд",
+        "Delete branch failed: marked remote branches must all be on the same remote" => "Не удалось удалить ветку: отмеченные удалённые ветки должны относиться к одному удалённому репозиторию",
+        "visual" => "выделение",
+        "count" => "счёт",
+        "Start count" => "Начать счёт",
+        "Visual mode" => "Режим выделения",
+        "nothing is marked" => "ничего не отмечено",
+        "Merge into here" => "Слить сюда",
+        "Rebase onto here" => "Перебазировать сюда",
+        "Reset branch to here" => "Сбросить ветку сюда",
+        "Branch reset failed" => "Не удалось сбросить ветку",
+        "New tab" => "Новая вкладка",
+        "Close tab" => "Закрыть вкладку",
+        "Next tab" => "Следующая вкладка",
+        "Previous tab" => "Предыдущая вкладка",
+        "new tab" => "новая вкладка",
+        "only one tab is open" => "открыта только одна вкладка",
+        "Switch tab failed: wait for the running operation to finish" => "Не удалось переключить вкладку: дождитесь завершения текущей операции",
+        "Load full diff" => "Загрузить полный diff",
+        "Enter viewer file size limit (KiB)" => "Введите лимит размера файла для просмотра (КиБ)",
+        " viewer file size limit:" => " лимит размера файла для просмотра:",
+        "the viewer shows the whole file" => "файл уже показан целиком",
+        "after:" => "после:",
+        "before:" => "до:",
+        "binary file" => "двоичный файл",
+        "hex preview:" => "шестнадцатеричный просмотр:",
+        "git lfs pointer" => "указатель git lfs",
+        "load full diff:" => "загрузить полный diff:",
+        "missing" => "отсутствует",
+        "shown:" => "показано:",
+        "size:" => "размер:",
+        "file truncated at the viewer size limit" => "файл обрезан по лимиту размера просмотра",
+        "Search file contents" => "Искать в содержимом файлов",
+        "regex on/off" => "вкл/выкл regex",
+        " invalid regex:" => " неверное регулярное выражение:",
+        "literal" => "текст",
+        "searching..." => "поиск...",
+        "too many matches, showing the first ones" => "слишком много совпадений, показаны первые",
+        "working tree" => "рабочее дерево",
+        "Deepen" => "Углубить",
+        "Deepen shallow history" => "Углубить неполную историю",
+        "Toggle original parents" => "Переключить исходных родителей",
+        "original parents" => "исходные родители",
+        "Enter number of commits to deepen by" => "Введите, на сколько коммитов углубить",
+        "the repository is not a shallow clone" => "репозиторий не является неполным клоном",
         _ => en,
     }
 }
@@ -1637,6 +2083,7 @@ fn tr_extra(en: &'static str) -> &'static str {
         "Revert failed" => "Revert başarısız",
         "Revert failed: no commit is pending" => "Revert başarısız: bekleyen commit yok",
         "Revert failed: no commit message was provided" => "Revert başarısız: commit mesajı verilmedi",
+        "Cherry-pick failed: cherry-picking marked merge commits is not supported" => "Cherry-pick başarısız: işaretli merge commitlerini cherry-pick etmek desteklenmiyor",
         "Revert failed: reverting merge commits is not supported" => "Revert başarısız: merge commitlerini revert etmek desteklenmiyor",
         "Revert stopped because conflicts need to be resolved." => "Revert durdu: çakışmalar çözülmeli.",
         "SHAs" => "SHA’lar",
@@ -1764,6 +2211,153 @@ fn tr_extra(en: &'static str) -> &'static str {
         "user:" => "kullanıcı:",
         "username/password or token prompt " => "kullanıcı adı/parola veya token istemi ",
         "worktrees" => "worktree’ler",
+        "Mark commit" => "Commit'i işaretle",
+        "Mark range" => "Aralığı işaretle",
+        "Clear marks" => "İşaretleri temizle",
+        "Unmark commit" => "Commit işaretini kaldır",
+        "Cherry-pick marked commits" => "İşaretli commitleri cherry-pick et",
+        "Revert marked commits" => "İşaretli commitleri revert et",
+        "Sequence aborted and HEAD restored." => "Dizi iptal edildi ve HEAD geri yüklendi.",
+        "Commit failed: worker thread panicked" => "Commit başarısız: çalışan iş parçacığı çöktü",
+        "Signing the commit..." => "Commit imzalanıyor...",
+        "Mark range failed: mark a commit first" => "Aralık işaretleme başarısız: önce bir commit işaretleyin",
+        "Mark range failed" => "Aralık işaretleme başarısız",
+        "select the parent to keep" => "korunacak üst commit'i seçin",
+        "diff against selected parent:" => "seçili üst commit'e göre diff:",
+        "no changes" => "değişiklik yok",
+        "Undo failed" => "Geri alma başarısız",
+        "Undo failed: the journal is empty" => "Geri alma başarısız: günlük boş",
+        "no journal entries" => "günlük kaydı yok",
+        "select an entry to undo back to" => "geri alınacak kaydı seçin",
+        "Undo" => "Geri al",
+        "Operation journal" => "İşlem günlüğü",
+        "Reset preview failed" => "Sıfırlama önizlemesi başarısız",
+        "Soft reset failed" => "Soft reset başarısız",
+        "Soft reset" => "Soft reset",
+        "Reset branch" => "Dalı sıfırla",
+        "branch:" => "dal:",
+        "target:" => "hedef:",
+        "local changes a hard reset overwrites:" => "hard reset'in üzerine yazacağı yerel değişiklikler:",
+        "commits leaving the branch:" => "daldan çıkacak commitler:",
+        "no local changes are overwritten" => "hiçbir yerel değişikliğin üzerine yazılmaz",
+        "no commits leave the branch" => "daldan hiçbir commit çıkmaz",
+        "Apply patch" => "Yama uygula",
+        "Apply to index" => "Index'e uygula",
+        "Apply to worktree" => "Worktree'ye uygula",
+        "Commit with original authors" => "Orijinal yazarlarla commit et",
+        "Export marked commits as patches" => "İşaretli commitleri yama olarak dışa aktar",
+        "Export as patch" => "Yama olarak dışa aktar",
+        "Export patches" => "Yamaları dışa aktar",
+        "patches in file:" => "dosyadaki yamalar:",
+        "patch without subject" => "konusuz yama",
+        "Enter patch or mailbox path" => "Yama veya mailbox yolunu gir",
+        "Enter patch file path" => "Yama dosyası yolunu gir",
+        "Apply patch failed" => "Yama uygulama başarısız",
+        "Apply patch failed: no path was provided" => "Yama uygulama başarısız: yol girilmedi",
+        "Apply patch failed: the file contains no patches" => "Yama uygulama başarısız: dosyada yama yok",
+        "Export patches failed" => "Yamaları dışa aktarma başarısız",
+        "Export patches failed: no path was provided" => "Yamaları dışa aktarma başarısız: yol girilmedi",
+        "Export patches failed: no commit is selected" => "Yamaları dışa aktarma başarısız: commit seçilmedi",
+        "signature:" => "imza:",
+        "bad signature" => "geçersiz imza",
+        "checking..." => "denetleniyor...",
+        "good signature by" => "geçerli imza:",
+        "signed with an unknown key" => "bilinmeyen bir anahtarla imzalanmış",
+        "unsigned" => "imzasız",
+        "bad" => "geçersiz",
+        "good" => "geçerli",
+        "unknown" => "bilinmiyor",
+        "signatures" => "imzalar",
+        "Commit failed: the commit message is empty" => "Commit başarısız: commit mesajı boş",
+        "Hook failed" => "Hook başarısız",
+        "The merge is left staged; continue it to commit anyway or abort it." => "Birleştirme hazırlanmış olarak bırakıldı; yine de commit etmek için devam edin ya da iptal edin.",
+        "Hook failed: worker thread panicked" => "Hook başarısız: çalışan iş parçacığı çöktü",
+        "Merge staged and waiting to be committed." => "Birleştirme hazırlandı ve commit edilmeyi bekliyor.",
+        "running hooks" => "hook'lar çalışıyor",
+        "skip hooks" => "hook'ları atla",
+        "Skip hooks once" => "Hook'ları bir kez atla",
+        "hooks skipped" => "hook'lar atlanıyor",
+        "Export graph failed" => "Grafik dışa aktarılamadı",
+        "Export graph failed: no path was provided" => "Grafik dışa aktarılamadı: yol belirtilmedi",
+        "Export graph failed: the graph is still loading" => "Grafik dışa aktarılamadı: grafik hâlâ yükleniyor",
+        "Enter graph image path (.svg or .html)" => "Grafik görüntüsü yolunu girin (.svg veya .html)",
+        "run" => "çalıştır",
+        "Run a command" => "Bir komut çalıştır",
+        "Command palette" => "Komut paleti",
+        "no linked worktrees" => "bağlı çalışma ağacı yok",
+        "no commits are marked" => "işaretli commit yok",
+        "no operation in progress" => "devam eden işlem yok",
+        "open a repository first" => "önce bir depo açın",
+        "no stashes" => "stash yok",
+        "no submodules" => "alt modül yok",
+        "not inside a submodule" => "bir alt modülün içinde değilsiniz",
+        "nothing to undo" => "geri alınacak bir şey yok",
+        "only on the start screen" => "yalnızca başlangıç ekranında",
+        "Export graph" => "Grafiği dışa aktar",
+        "Custom command failed" => "Özel komut başarısız oldu",
+        "Custom command failed: nothing selected for placeholder" => "Özel komut başarısız oldu: yer tutucu için hiçbir şey seçilmedi",
+        "Custom command failed: worker thread panicked" => "Özel komut başarısız oldu: çalışan iş parçacığı çöktü",
+        "Custom command failed: no command with this name in custom_commands.json" => "Özel komut başarısız oldu: custom_commands.json içinde bu adda bir komut yok",
+        "command failed" => "komut başarısız oldu",
+        "command finished" => "komut tamamlandı",
+        "Press Enter to return to guitar" => "guitar'a dönmek için Enter'a basın",
+        "running command" => "komut çalışıyor",
+        "not available in this pane" => "bu panelde kullanılamaz",
+        "remove key" => "tuşu kaldır",
+        "backspace" => "backspace",
+        "Leader key" => "Lider tuşu",
+        "Jump failed: the commit is not in the graph, it may be hidden or gone" => "Atlama başarısız: commit grafikte yok, gizlenmiş ya da silinmiş olabilir",
+        "Jump to mark failed: no mark with this name" => "İşarete gitme başarısız: bu adla bir işaret yok",
+        "the jump list is empty" => "atlama listesi boş",
+        "no marks are set" => "hiç işaret yok",
+        "Set mark" => "İşaret koy",
+        "Jump to mark" => "İşarete git",
+        "Jump back" => "Geri atla",
+        "Jump forward" => "İleri atla",
+        "Delete branch failed: marked remote branches must all be on the same remote" => "Dal silme başarısız: işaretli uzak dallar aynı uzak depoda olmalı",
+        "visual" => "görsel",
+        "count" => "sayı",
+        "Start count" => "Sayı girmeye başla",
+        "Visual mode" => "Görsel mod",
+        "nothing is marked" => "işaretli bir şey yok",
+        "Merge into here" => "Buraya merge et",
+        "Rebase onto here" => "Buraya rebase et",
+        "Reset branch to here" => "Dalı buraya sıfırla",
+        "Branch reset failed" => "Dal sıfırlama başarısız",
+        "New tab" => "Yeni sekme",
+        "Close tab" => "Sekmeyi kapat",
+        "Next tab" => "Sonraki sekme",
+        "Previous tab" => "Önceki sekme",
+        "new tab" => "yeni sekme",
+        "only one tab is open" => "yalnızca bir sekme açık",
+        "Switch tab failed: wait for the running operation to finish" => "Sekme değiştirilemedi: çalışan işlemin bitmesini bekleyin",
+        "Load full diff" => "Tam farkı yükle",
+        "Enter viewer file size limit (KiB)" => "Görüntüleyici dosya boyutu sınırını gir (KiB)",
+        " viewer file size limit:" => " görüntüleyici dosya boyutu sınırı:",
+        "the viewer shows the whole file" => "görüntüleyici dosyanın tamamını gösteriyor",
+        "after:" => "sonra:",
+        "before:" => "önce:",
+        "binary file" => "ikili dosya",
+        "hex preview:" => "onaltılık önizleme:",
+        "git lfs pointer" => "git lfs işaretçisi",
+        "load full diff:" => "tam farkı yükle:",
+        "missing" => "yok",
+        "shown:" => "gösterilen:",
+        "size:" => "boyut:",
+        "file truncated at the viewer size limit" => "dosya görüntüleyici boyut sınırında kesildi",
+        "Search file contents" => "Dosya içeriklerinde ara",
+        "regex on/off" => "regex aç/kapat",
+        " invalid regex:" => " geçersiz regex:",
+        "literal" => "düz metin",
+        "searching..." => "aranıyor...",
+        "too many matches, showing the first ones" => "çok fazla eşleşme, ilkleri gösteriliyor",
+        "working tree" => "çalışma ağacı",
+        "Deepen" => "Derinleştir",
+        "Deepen shallow history" => "Sığ geçmişi derinleştir",
+        "Toggle original parents" => "Özgün ebeveynleri aç/kapat",
+        "original parents" => "özgün ebeveynler",
+        "Enter number of commits to deepen by" => "Kaç commit derinleştirileceğini girin",
+        "the repository is not a shallow clone" => "depo sığ bir klon değil",
         _ => en,
     }
 }
@@ -1796,9 +2390,12 @@ localized_module!(common {
 localized_module!(empty {
     NO_BODY => "no body",
     NO_BRANCHES => "no branches",
+    NO_CHANGES => "no changes",
     NO_COMMITS => "no commits",
     NO_HEAD_REFLOG => "no HEAD reflog",
+    NO_JOURNAL_ENTRIES => "no journal entries",
     NO_MESSAGE => "no message",
+    NO_OVERWRITTEN_FILES => "no local changes are overwritten",
     NO_RECENT_REPOSITORIES => "no recent repositories",
     NO_REMOTES => "no remotes",
     NO_STAGED_CHANGES => "no staged changes",
@@ -1806,6 +2403,7 @@ localized_module!(empty {
     NO_SUBMODULES => "no submodules",
     NO_SUMMARY => "no summary",
     NO_TAGS => "no tags",
+    NO_UNREACHABLE_COMMITS => "no commits leave the branch",
     NO_UNSTAGED_CHANGES => "no unstaged changes",
     NO_WORKTREES => "no worktrees",
     SEARCH => "search",
@@ -1823,6 +2421,15 @@ pub mod errors {
     pub fn ADD_REMOTE() -> &'static str {
         tr("Add remote failed")
     }
+    pub fn APPLY_PATCH() -> &'static str {
+        tr("Apply patch failed")
+    }
+    pub fn APPLY_PATCH_EMPTY_PATH() -> &'static str {
+        tr("Apply patch failed: no path was provided")
+    }
+    pub fn APPLY_PATCH_NO_PATCHES() -> &'static str {
+        tr("Apply patch failed: the file contains no patches")
+    }
     pub fn CHECKOUT() -> &'static str {
         tr("Checkout failed")
     }
@@ -1838,6 +2445,12 @@ pub mod errors {
     pub fn COMMIT() -> &'static str {
         tr("Commit failed")
     }
+    pub fn COMMIT_EMPTY_MESSAGE() -> &'static str {
+        tr("Commit failed: the commit message is empty")
+    }
+    pub fn COMMIT_PANICKED() -> &'static str {
+        tr("Commit failed: worker thread panicked")
+    }
     pub fn CONTINUE_NO_OPERATION() -> &'static str {
         tr("Continue failed: no rebase, cherry-pick, revert, or merge in progress")
     }
@@ -1865,12 +2478,27 @@ pub mod errors {
     pub fn CREATE_WORKTREE_NO_COMMIT() -> &'static str {
         tr("Create worktree failed: no commit is selected")
     }
+    pub fn CUSTOM_COMMAND() -> &'static str {
+        tr("Custom command failed")
+    }
+    pub fn CUSTOM_COMMAND_NO_VALUE() -> &'static str {
+        tr("Custom command failed: nothing selected for placeholder")
+    }
+    pub fn CUSTOM_COMMAND_PANICKED() -> &'static str {
+        tr("Custom command failed: worker thread panicked")
+    }
+    pub fn CUSTOM_COMMAND_UNKNOWN() -> &'static str {
+        tr("Custom command failed: no command with this name in custom_commands.json")
+    }
     pub fn DELETE_BRANCH() -> &'static str {
         tr("Delete branch failed")
     }
     pub fn DELETE_BRANCH_CURRENT() -> &'static str {
         tr("Delete branch failed: cannot delete the current branch")
     }
+    pub fn DELETE_BRANCH_REMOTE_BATCH() -> &'static str {
+        tr("Delete branch failed: marked remote branches must all be on the same remote")
+    }
     pub fn DELETE_BRANCH_INVALID_REMOTE() -> &'static str {
         tr("Delete branch failed: remote branch name is invalid")
     }
@@ -1892,6 +2520,24 @@ pub mod errors {
     pub fn EDIT_REMOTE_NO_PENDING() -> &'static str {
         tr("Edit remote failed: no remote is pending")
     }
+    pub fn EXPORT_GRAPH() -> &'static str {
+        tr("Export graph failed")
+    }
+    pub fn EXPORT_GRAPH_EMPTY_PATH() -> &'static str {
+        tr("Export graph failed: no path was provided")
+    }
+    pub fn EXPORT_GRAPH_NOT_LOADED() -> &'static str {
+        tr("Export graph failed: the graph is still loading")
+    }
+    pub fn EXPORT_PATCHES() -> &'static str {
+        tr("Export patches failed")
+    }
+    pub fn EXPORT_PATCHES_EMPTY_PATH() -> &'static str {
+        tr("Export patches failed: no path was provided")
+    }
+    pub fn EXPORT_PATCHES_NO_COMMIT() -> &'static str {
+        tr("Export patches failed: no commit is selected")
+    }
     pub fn FILE_DIFF() -> &'static str {
         tr("Couldn't get the file diff")
     }
@@ -1910,12 +2556,27 @@ pub mod errors {
     pub fn HARD_RESET() -> &'static str {
         tr("Hard reset failed")
     }
+    pub fn HOOK() -> &'static str {
+        tr("Hook failed")
+    }
+    pub fn HOOK_MERGE_STAGED() -> &'static str {
+        tr("The merge is left staged; continue it to commit anyway or abort it.")
+    }
+    pub fn HOOK_PANICKED() -> &'static str {
+        tr("Hook failed: worker thread panicked")
+    }
+    pub fn JUMP_TARGET_NOT_IN_GRAPH() -> &'static str {
+        tr("Jump failed: the commit is not in the graph, it may be hidden or gone")
+    }
     pub fn LOCK_WORKTREE() -> &'static str {
         tr("Lock worktree failed")
     }
     pub fn LOCK_WORKTREE_INVALID() -> &'static str {
         tr("Lock worktree failed: only valid linked worktrees can be locked")
     }
+    pub fn MARK_NOT_SET() -> &'static str {
+        tr("Jump to mark failed: no mark with this name")
+    }
     pub fn MERGE() -> &'static str {
         tr("Merge failed")
     }
@@ -1964,15 +2625,30 @@ pub mod errors {
     pub fn REFLOG_COMMIT_HIDDEN() -> &'static str {
         tr("Reflog commit is hidden from the graph. Press 9 to show graph reflogs.")
     }
+    pub fn RESET_BRANCH() -> &'static str {
+        tr("Branch reset failed")
+    }
     pub fn RESET_FILE() -> &'static str {
         tr("Reset file failed")
     }
+    pub fn RESET_PREVIEW() -> &'static str {
+        tr("Reset preview failed")
+    }
     pub fn REVERT() -> &'static str {
         tr("Revert failed")
     }
+    pub fn CHERRYPICK_MERGE_UNSUPPORTED() -> &'static str {
+        tr("Cherry-pick failed: cherry-picking marked merge commits is not supported")
+    }
     pub fn REVERT_MERGE_UNSUPPORTED() -> &'static str {
         tr("Revert failed: reverting merge commits is not supported")
     }
+    pub fn MARK_RANGE_NO_ANCHOR() -> &'static str {
+        tr("Mark range failed: mark a commit first")
+    }
+    pub fn MARK_RANGE() -> &'static str {
+        tr("Mark range failed")
+    }
     pub fn REVERT_NO_MESSAGE() -> &'static str {
         tr("Revert failed: no commit message was provided")
     }
@@ -1985,6 +2661,9 @@ pub mod errors {
     pub fn SET_DEFAULT_REMOTE() -> &'static str {
         tr("Set default remote failed")
     }
+    pub fn SOFT_RESET() -> &'static str {
+        tr("Soft reset failed")
+    }
     pub fn STAGE_ALL() -> &'static str {
         tr("Stage all failed")
     }
@@ -2000,9 +2679,18 @@ pub mod errors {
     pub fn STASH() -> &'static str {
         tr("Stash failed")
     }
+    pub fn SWITCH_TAB_BUSY() -> &'static str {
+        tr("Switch tab failed: wait for the running operation to finish")
+    }
     pub fn SYNC_SUBMODULE() -> &'static str {
         tr("Sync submodule failed")
     }
+    pub fn UNDO() -> &'static str {
+        tr("Undo failed")
+    }
+    pub fn UNDO_EMPTY() -> &'static str {
+        tr("Undo failed: the journal is empty")
+    }
     pub fn UNSTAGE_ALL() -> &'static str {
         tr("Unstage all failed")
     }
@@ -2081,6 +2769,7 @@ localized_module!(inspector {
     CONFLICTED_FILES => "conflicted files:",
     FEATURED_BRANCHES => "featured branches:",
     HEAD_REFLOG => "head reflog:",
+    MAINLINE_DIFF => "diff against selected parent:",
     MESSAGE_BODY => "message body:",
     MESSAGE_SUMMARY => "message summary:",
     NEXT_ACTION => "next action:",
@@ -2088,6 +2777,12 @@ localized_module!(inspector {
     PARENT_SHAS => "parent shas:",
     REPOSITORY_STATE => "repository state:",
     RESOLVE_CONFLICTS_ACTION => "resolve files externally, then action+Shift+C",
+    SIGNATURE => "signature:",
+    SIGNATURE_BAD => "bad signature",
+    SIGNATURE_CHECKING => "checking...",
+    SIGNATURE_GOOD => "good signature by",
+    SIGNATURE_UNKNOWN_KEY => "signed with an unknown key",
+    SIGNATURE_UNSIGNED => "unsigned",
 });
 
 localized_module!(keymap {
@@ -2131,6 +2826,9 @@ pub mod menu {
     localized_fns! {
     ABORT_OPERATION => "Abort operation",
     ADD_REMOTE => "Add remote",
+    APPLY_PATCH => "Apply patch",
+    APPLY_TO_INDEX => "Apply to index",
+    APPLY_TO_WORKTREE => "Apply to worktree",
     APPLY_THEME => "Apply theme",
     APPLY_LANGUAGE => "Apply language",
     BACK => "Back",
@@ -2138,7 +2836,10 @@ pub mod menu {
     CHECKOUT => "Checkout",
     CHECKOUT_BRANCH => "Checkout branch",
     CHERRYPICK => "Cherry-pick",
+    CHERRYPICK_MARKED => "Cherry-pick marked commits",
+    CLEAR_MARKS => "Clear marks",
     COMMIT => "Commit",
+    COMMIT_PATCHES => "Commit with original authors",
     CONTINUE_OPERATION => "Continue operation",
     CREATE_BRANCH => "Create branch",
     CREATE_BRANCH_HERE => "Create branch here",
@@ -2152,12 +2853,19 @@ pub mod menu {
     EDIT_FETCH_URL => "Edit fetch URL",
     EDIT_PUSH_URL => "Edit push URL",
     EXIT => "Exit",
+    EXPORT_MARKED_PATCHES => "Export marked commits as patches",
+    EXPORT_PATCH => "Export as patch",
+    DEEPEN_HISTORY => "Deepen shallow history",
     FETCH => "Fetch",
     FIND => "Find",
     FIND_FILE => "Find file",
+    SEARCH_CONTENTS => "Search file contents",
     HARD_RESET => "Hard reset",
     LOCK_WORKTREE => "Lock worktree",
+    MARK_COMMIT => "Mark commit",
+    MARK_RANGE => "Mark range",
     MERGE => "Merge",
+    MERGE_INTO_HERE => "Merge into here",
     MIXED_RESET => "Mixed reset",
     MOVE_DOWN => "Move down",
     MOVE_UP => "Move up",
@@ -2170,22 +2878,27 @@ pub mod menu {
     POP_STASH => "Pop stash",
     PUSH => "Push",
     REBASE => "Rebase",
+    REBASE_ONTO_HERE => "Rebase onto here",
     REBIND_SHORTCUT => "Rebind shortcut",
     RELOAD => "Reload",
     REMOVE => "Remove",
     REMOVE_WORKTREE => "Remove worktree",
     RENAME_BRANCH => "Rename branch",
     RENAME_REMOTE => "Rename remote",
+    RESET_BRANCH_TO_HERE => "Reset branch to here",
     RETURN_TO_PARENT_REPOSITORY => "Return to parent repository",
     REVERT => "Revert",
+    REVERT_MARKED => "Revert marked commits",
     SET_AS_DEFAULT => "Set as default",
     SETTINGS => "Settings",
     SHOW_DETAILS => "Show details",
     SHOW_FILES_STATUS => "Show files/status",
     SHOW_FULL_DIFF => "Show full diff",
+    LOAD_FULL_DIFF => "Load full diff",
     SHOW_HUNK_ROWS => "Show hunk rows",
     SHOW_SPLIT_DIFF => "Show split diff",
     SHOW_UNIFIED_DIFF => "Show unified diff",
+    SOFT_RESET => "Soft reset",
     SOLO_BRANCH => "Solo branch",
     SPLASH_SCREEN => "Splash screen",
     STAGE_ALL => "Stage all",
@@ -2195,6 +2908,7 @@ pub mod menu {
     SYNC_URL => "Sync URL",
     TOGGLE_BRANCH => "Toggle branch",
     UNLOCK_WORKTREE => "Unlock worktree",
+    UNMARK_COMMIT => "Unmark commit",
     UNSTAGE_ALL => "Unstage all",
     UNSTAGE_FILE => "Unstage file",
     UNSTAGE_SUBMODULE => "Unstage submodule",
@@ -2230,6 +2944,8 @@ pub mod modal {
     ACTION_CONFIRM => "confirm",
     ACTION_MOVE => "move",
     ACTION_OK => "ok",
+    ACTION_REMOVE_KEY => "remove key",
+    ACTION_RUN => "run",
     ACTION_SAVE => "save",
     ACTION_SUBMIT => "submit",
     ACTION_SWITCH_FIELD => "switch field",
@@ -2238,26 +2954,40 @@ pub mod modal {
     AUTH_PASSWORD_TOKEN => "password / token",
     AUTH_USER => "user:",
     AUTH_USERNAME => "username",
+    BRANCH_LABEL => "branch:",
+    COMMAND_FAILED => "command failed",
+    COMMAND_FINISHED => "command finished",
     CURRENT_SHORTCUT => "current:",
     DELETE_SELECTED_REMOTE => "delete selected remote?",
     ERROR_TITLE => "error",
+    KEY_BACKSPACE => "backspace",
     KEY_ENTER => "enter",
     KEY_TAB => "tab",
     KEY_CTRL_J_K => "ctrl+j/k",
     NAME_LABEL => "name:",
     NEW_SHORTCUT => "new:",
     NEW_SHORTCUT_WAITING => "new: waiting for key",
+    PATCHES_IN_FILE => "patches in file:",
+    PATCH_WITHOUT_SUBJECT => "patch without subject",
     PATH_LABEL => "path:",
+    PRESS_ENTER_TO_RETURN => "Press Enter to return to guitar",
     PRESS_KEY => "press key",
+    PROMPT_APPLY_PATCH => "Enter patch or mailbox path",
     PROMPT_CHERRYPICK_COMMIT => "Enter cherry-pick commit message",
     PROMPT_CREATE_BRANCH => "Enter new branch name",
     PROMPT_CREATE_COMMIT => "Enter commit message",
     PROMPT_CREATE_TAG => "Enter new tag name",
     PROMPT_CREATE_WORKTREE_NAME => "Enter new worktree name",
     PROMPT_CREATE_WORKTREE_PATH => "Enter new worktree path",
+    PROMPT_EXPORT_GRAPH => "Enter graph image path (.svg or .html)",
+    PROMPT_EXPORT_PATCHES => "Enter patch file path",
+    PROMPT_COMMAND_PALETTE => "Run a command",
     PROMPT_FIND_FILE => "Search repository files",
+    PROMPT_SEARCH_CONTENTS => "Search file contents",
     PROMPT_FIND_SHA => "Enter commit SHA to search for",
     PROMPT_GRAPH_LANE_LIMIT => "Enter graph lane limit",
+    PROMPT_VIEWER_FILE_SIZE_LIMIT => "Enter viewer file size limit (KiB)",
+    PROMPT_DEEPEN => "Enter number of commits to deepen by",
     PROMPT_LOCK_WORKTREE => "Enter lock reason",
     PROMPT_REMOTE_ADD_NAME => "Enter new remote name",
     PROMPT_REMOTE_ADD_URL => "Enter new remote URL",
@@ -2269,17 +2999,42 @@ pub mod modal {
     REMOTE_FALLBACK => "remote",
     REMOTE_LABEL => "remote:",
     REMOVE_SELECTED_WORKTREE => "remove selected worktree?",
+    RESET_OVERWRITTEN_FILES => "local changes a hard reset overwrites:",
+    RESET_UNREACHABLE_COMMITS => "commits leaving the branch:",
+    RUNNING_COMMAND => "running command",
+    RUNNING_HOOKS => "running hooks",
     SELECT_BRANCH_CHECKOUT => "select a branch to checkout",
     SELECT_BRANCH_DELETE => "select a branch to delete",
     SELECT_BRANCH_RENAME => "select a branch to rename",
     SELECT_BRANCH_SOLO => "select a branch to solo",
     SELECT_BRANCH_TOGGLE => "select a branch to toggle",
+    SELECT_JOURNAL_ENTRY => "select an entry to undo back to",
+    SELECT_MAINLINE_PARENT => "select the parent to keep",
     SELECT_TAG_DELETE => "select a tag to delete",
     SELECT_WORKTREE_OPEN => "select a worktree to open",
     SELECT_WORKTREE_REMOVE => "select a worktree to remove",
     SET_SHORTCUT => "set shortcut",
+    SKIP_HOOKS => "skip hooks",
+    TARGET_LABEL => "target:",
     TYPE_TO_SEARCH => " type to search",
     NO_MATCHES => " no matches",
+    ACTION_TOGGLE_REGEX => "regex on/off",
+    INVALID_REGEX => " invalid regex:",
+    LITERAL => "literal",
+    REGEX => "regex",
+    SEARCHING => "searching...",
+    TOO_MANY_MATCHES => "too many matches, showing the first ones",
+    WORKING_TREE => "working tree",
+    }
+
+    pub fn match_count(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("{count} coincidencias"),
+            Language::French => format!("{count} correspondances"),
+            Language::Russian => format!("совпадений: {count}"),
+            Language::Turkish => format!("{count} eşleşme"),
+            Language::English => format!("{count} matches"),
+        }
     }
 
     pub fn auth_title(protocol: &str) -> String {
@@ -2292,6 +3047,86 @@ pub mod modal {
         }
     }
 
+    pub fn batch_stage(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("¿preparar {count} archivos?"),
+            Language::French => format!("indexer {count} fichiers ?"),
+            Language::Russian => format!("проиндексировать файлы: {count}?"),
+            Language::Turkish => format!("{count} dosya hazırlansın mı?"),
+            Language::English => format!("stage {count} files?"),
+        }
+    }
+
+    pub fn batch_unstage(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("¿quitar {count} archivos del área de preparación?"),
+            Language::French => format!("désindexer {count} fichiers ?"),
+            Language::Russian => format!("убрать из индекса файлы: {count}?"),
+            Language::Turkish => format!("{count} dosya hazırlıktan çıkarılsın mı?"),
+            Language::English => format!("unstage {count} files?"),
+        }
+    }
+
+    pub fn batch_discard(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("¿descartar los cambios de {count} archivos?"),
+            Language::French => format!("abandonner les modifications de {count} fichiers ?"),
+            Language::Russian => format!("отменить изменения в файлах: {count}?"),
+            Language::Turkish => format!("{count} dosyadaki değişiklikler atılsın mı?"),
+            Language::English => format!("discard changes in {count} files?"),
+        }
+    }
+
+    pub fn batch_delete_branches(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("¿eliminar {count} ramas?"),
+            Language::French => format!("supprimer {count} branches ?"),
+            Language::Russian => format!("удалить ветки: {count}?"),
+            Language::Turkish => format!("{count} dal silinsin mi?"),
+            Language::English => format!("delete {count} branches?"),
+        }
+    }
+
+    pub fn batch_delete_tags(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("¿eliminar {count} etiquetas?"),
+            Language::French => format!("supprimer {count} tags ?"),
+            Language::Russian => format!("удалить теги: {count}?"),
+            Language::Turkish => format!("{count} etiket silinsin mi?"),
+            Language::English => format!("delete {count} tags?"),
+        }
+    }
+
+    pub fn batch_cherrypick(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("¿aplicar {count} commits con cherry-pick?"),
+            Language::French => format!("cherry-picker {count} commits ?"),
+            Language::Russian => format!("применить коммиты через cherry-pick: {count}?"),
+            Language::Turkish => format!("{count} commit cherry-pick edilsin mi?"),
+            Language::English => format!("cherry-pick {count} commits?"),
+        }
+    }
+
+    pub fn batch_revert(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("¿revertir {count} commits?"),
+            Language::French => format!("annuler {count} commits ?"),
+            Language::Russian => format!("отменить коммиты: {count}?"),
+            Language::Turkish => format!("{count} commit geri alınsın mı?"),
+            Language::English => format!("revert {count} commits?"),
+        }
+    }
+
+    pub fn batch_drop_stashes(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("¿descartar {count} stashes?"),
+            Language::French => format!("supprimer {count} remisages ?"),
+            Language::Russian => format!("удалить stash-записи: {count}?"),
+            Language::Turkish => format!("{count} stash silinsin mi?"),
+            Language::English => format!("drop {count} stashes?"),
+        }
+    }
+
     pub fn keymap_conflict(mode: &str, key: &str, command: &str) -> String {
         match active_language() {
             Language::Spanish => format!("conflicto: {mode} {key} ya ejecuta {command}"),
@@ -2302,6 +3137,16 @@ pub mod modal {
         }
     }
 
+    pub fn keymap_prefix_conflict(mode: &str, key: &str, command: &str) -> String {
+        match active_language() {
+            Language::Spanish => format!("conflicto de secuencia: {mode} {key} ({command}) comparte el comienzo"),
+            Language::French => format!("conflit de séquence : {mode} {key} ({command}) commence de la même façon"),
+            Language::Russian => format!("конфликт последовательности: {mode} {key} ({command}) начинается так же"),
+            Language::Turkish => format!("dizi çakışması: {mode} {key} ({command}) aynı şekilde başlıyor"),
+            Language::English => format!("sequence conflict: {mode} {key} ({command}) starts the same way"),
+        }
+    }
+
     pub fn keymap_missing_mode(mode: &str) -> String {
         match active_language() {
             Language::Spanish => format!("modo de mapa de teclas faltante: {mode}"),
@@ -2337,6 +3182,7 @@ pub mod network {
     use super::{Language, active_language, tr};
 
     localized_fns! {
+    DEEPEN => "Deepen",
     DELETE_REMOTE_BRANCH => "Delete remote branch",
     FETCH => "Fetch",
     GIT_NETWORK_OPERATION => "Git network operation",
@@ -2350,13 +3196,24 @@ pub mod network {
     UPDATE_SUBMODULE => "Update submodule",
     }
 
-    pub fn deleting_remote_branch(remote_name: &str, branch: &str) -> String {
+    pub fn deleting_remote_branches(remote_name: &str, branches: &[String]) -> String {
+        let names = branches.iter().map(|branch| format!("{remote_name}/{branch}")).collect::<Vec<_>>().join(", ");
         match active_language() {
-            Language::Spanish => format!("Eliminando {remote_name}/{branch}..."),
-            Language::French => format!("Suppression de {remote_name}/{branch}..."),
-            Language::Russian => format!("Удаление {remote_name}/{branch}..."),
-            Language::Turkish => format!("{remote_name}/{branch} siliniyor..."),
-            Language::English => format!("Deleting {remote_name}/{branch}..."),
+            Language::Spanish => format!("Eliminando {names}..."),
+            Language::French => format!("Suppression de {names}..."),
+            Language::Russian => format!("Удаление {names}..."),
+            Language::Turkish => format!("{names} siliniyor..."),
+            Language::English => format!("Deleting {names}..."),
+        }
+    }
+
+    pub fn deepening(remote_name: &str, by: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("Profundizando {by} commits desde {remote_name}..."),
+            Language::French => format!("Approfondissement de {by} commits depuis {remote_name}..."),
+            Language::Russian => format!("Углубление на {by} коммитов из {remote_name}..."),
+            Language::Turkish => format!("{remote_name} üzerinden {by} commit derinleştiriliyor..."),
+            Language::English => format!("Deepening by {by} commits from {remote_name}..."),
         }
     }
 
@@ -2421,6 +3278,7 @@ pub mod operations {
     CHERRYPICK_COMMIT_FALLBACK => "Cherry-pick commit",
     CHERRYPICK_COMPLETED => "Cherry-pick completed.",
     CHERRYPICK_CONFLICT => "Cherry-pick stopped because conflicts need to be resolved.",
+    COMMIT_SIGNING => "Signing the commit...",
     COMPLETE => "complete",
     CONFLICT => "conflict",
     MERGE => "merge",
@@ -2429,6 +3287,7 @@ pub mod operations {
     MERGE_COMPLETED => "Merge completed.",
     MERGE_CONFLICT => "Merge stopped because conflicts need to be resolved.",
     MERGE_FAST_FORWARDED => "Merge fast-forwarded.",
+    MERGE_STAGED => "Merge staged and waiting to be committed.",
     REBASE => "rebase",
     REBASE_ABORTED => "Rebase aborted.",
     REBASE_CONFLICT => "Rebase stopped because conflicts need to be resolved.",
@@ -2437,6 +3296,7 @@ pub mod operations {
     REVERT_COMMIT_FALLBACK => "Revert commit",
     REVERT_COMPLETED => "Revert completed.",
     REVERT_CONFLICT => "Revert stopped because conflicts need to be resolved.",
+    SEQUENCE_ABORTED => "Sequence aborted and HEAD restored.",
     RESOLVE_CONFLICTS => "resolve conflicts in your editor, then action+Shift+C",
     }
 
@@ -2489,6 +3349,41 @@ pub mod operations {
         }
     }
 
+    pub fn sequence_completed(applied: usize) -> String {
+        match active_language() {
+            Language::Spanish if applied == 1 => "Secuencia completada tras aplicar 1 commit.".to_string(),
+            Language::Spanish => format!("Secuencia completada tras aplicar {applied} commits."),
+            Language::French if applied == 1 => "Séquence terminée après application d'un commit.".to_string(),
+            Language::French => format!("Séquence terminée après application de {applied} commits."),
+            Language::Russian if applied == 1 => "Последовательность завершена после применения 1 коммита.".to_string(),
+            Language::Russian => format!("Последовательность завершена после применения {applied} коммитов."),
+            Language::Turkish if applied == 1 => "Dizi 1 commit uygulandıktan sonra tamamlandı.".to_string(),
+            Language::Turkish => format!("Dizi {applied} commit uygulandıktan sonra tamamlandı."),
+            Language::English if applied == 1 => "Sequence completed after applying 1 commit.".to_string(),
+            Language::English => format!("Sequence completed after applying {applied} commits."),
+        }
+    }
+
+    pub fn sequence_conflict(remaining: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("La secuencia se detuvo porque hay conflictos que resolver; quedan {remaining} commits."),
+            Language::French => format!("La séquence s'est arrêtée car des conflits doivent être résolus ; {remaining} commits restants."),
+            Language::Russian => format!("Последовательность остановлена из-за конфликтов; осталось коммитов: {remaining}."),
+            Language::Turkish => format!("Dizi çözülmesi gereken çakışmalar nedeniyle durdu; {remaining} commit kaldı."),
+            Language::English => format!("Sequence stopped because conflicts need to be resolved; {remaining} commits remain."),
+        }
+    }
+
+    pub fn applying_marked_commits(count: usize) -> String {
+        match active_language() {
+            Language::Spanish => format!("Aplicando {count} commits marcados..."),
+            Language::French => format!("Application de {count} commits marqués..."),
+            Language::Russian => format!("Применение отмеченных коммитов: {count}..."),
+            Language::Turkish => format!("{count} işaretli commit uygulanıyor..."),
+            Language::English => format!("Applying {count} marked commits..."),
+        }
+    }
+
     pub fn reverted(original_message: &str) -> String {
         match active_language() {
             Language::Spanish => format!("revertido: {original_message}"),
@@ -2509,6 +3404,16 @@ pub mod operations {
         }
     }
 
+    pub fn merging_branch(branch: &str) -> String {
+        match active_language() {
+            Language::Spanish => format!("Haciendo merge de {branch} en la rama actual..."),
+            Language::French => format!("Merge de {branch} dans la branche actuelle..."),
+            Language::Russian => format!("Merge {branch} в текущую ветку..."),
+            Language::Turkish => format!("{branch} geçerli dala merge ediliyor..."),
+            Language::English => format!("Merging {branch} into the current branch..."),
+        }
+    }
+
     pub fn merging_selected_commit() -> String {
         match active_language() {
             Language::Spanish => "Haciendo merge del commit seleccionado en la rama actual...".to_string(),
@@ -2520,6 +3425,26 @@ pub mod operations {
     }
 }
 
+// Why the command palette greys a command out.
+localized_module!(palette {
+    NOT_IN_CONTEXT => "not available in this pane",
+    NO_LINKED_WORKTREES => "no linked worktrees",
+    NO_JUMPS => "the jump list is empty",
+    NO_MARKED_COMMITS => "no commits are marked",
+    NO_MARKED_ROWS => "nothing is marked",
+    NO_NAMED_MARKS => "no marks are set",
+    NO_OPERATION => "no operation in progress",
+    NO_REPOSITORY => "open a repository first",
+    NO_STASHES => "no stashes",
+    NO_SUBMODULES => "no submodules",
+    NOT_IN_SUBMODULE => "not inside a submodule",
+    NOTHING_TO_UNDO => "nothing to undo",
+    ONE_TAB => "only one tab is open",
+    START_SCREEN_ONLY => "only on the start screen",
+    NOTHING_TRUNCATED => "the viewer shows the whole file",
+    NOT_SHALLOW => "the repository is not a shallow clone",
+});
+
 localized_module!(settings {
     ACTIONS => " actions:",
     ACTIVE_CUSTOM => " active custom:",
@@ -2537,6 +3462,7 @@ localized_module!(settings {
     GENERAL => "general",
     GRAPH_METADATA => " graph metadata:",
     GRAPH_LANE_LIMIT => " graph lane limit:",
+    VIEWER_FILE_SIZE_LIMIT => " viewer file size limit:",
     GRAPH_REFLOG_COMMITS => "graph reflog commits",
     HTTPS => " https:",
     HTTPS_DETAIL => "username/password or token prompt ",
@@ -2545,6 +3471,7 @@ localized_module!(settings {
     LANGUAGE => " language:",
     LAYOUT => " layout:",
     NAME => " name:",
+    ORIGINAL_PARENTS => "original parents",
     PANE_VISIBILITY => " pane visibility:",
     PERFORMANCE => " performance:",
     PATHS => "paths",
@@ -2566,6 +3493,7 @@ localized_module!(settings {
     SHORTCUTS => "shortcuts",
     SHORTCUTS_ACTION_MODE => " shortcuts / action mode:",
     SHORTCUTS_NORMAL_MODE => " shortcuts / normal mode:",
+    SIGNATURES => "signatures",
     SSH_FALLBACK => " ssh fallback:",
     SSH_FALLBACK_DETAIL => "key passphrase prompt ",
     SSH_AGENT_DETAIL => "ssh-agent when available ",
@@ -2618,12 +3546,29 @@ pub mod splash {
     }
 }
 
+// Summaries the viewer shows instead of a line diff.
+localized_module!(viewer {
+    AFTER => "after:",
+    BEFORE => "before:",
+    BINARY_FILE => "binary file",
+    HEX_PREVIEW => "hex preview:",
+    LFS_POINTER => "git lfs pointer",
+    LOAD_FULL_DIFF => "load full diff:",
+    MISSING => "missing",
+    OID => "oid:",
+    SHOWN => "shown:",
+    SIZE => "size:",
+    TRUNCATED => "file truncated at the viewer size limit",
+});
+
 localized_module!(status {
     DETACHED => "detached",
     DETACHED_HEAD => "detached head:",
     GRAPH => "graph",
+    HOOKS_SKIPPED => "hooks skipped",
     INSPECTOR => "inspector",
     MODAL => "modal",
+    NEW_TAB => "new tab",
     NOT_INITIALIZED => "not initialized",
     NO_HEAD_NO_COMMITS => "no head (no commits yet)",
     SEARCH => "search",
@@ -2633,7 +3578,12 @@ localized_module!(status {
     VIEWER => "viewer",
     MODIFIED => "modified",
     NEW_COMMITS => "new commits",
+    SIGNATURE_BAD => "bad",
+    SIGNATURE_GOOD => "good",
+    SIGNATURE_UNKNOWN => "unknown",
     UNTRACKED => "untracked",
+    VISUAL => "visual",
+    COUNT => "count",
 });
 
 #[cfg(test)]
@@ -0,0 +1,103 @@
+use facet::Facet;
+use git2::Oid;
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Named marks on commits, keyed by the letter typed after the mark key.
+pub type Marks = BTreeMap<char, Oid>;
+
+#[derive(Facet, Clone)]
+pub struct MarkEntry {
+    pub name: String,
+    pub oid: String,
+}
+
+#[derive(Facet, Clone)]
+pub struct RepositoryMarks {
+    pub path: String,
+    #[facet(default)]
+    pub marks: Vec<MarkEntry>,
+}
+
+#[derive(Facet, Clone, Default)]
+pub struct MarksConfig {
+    #[facet(default)]
+    pub repositories: Vec<RepositoryMarks>,
+}
+
+pub fn marks_config_path() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap();
+    path.push("guitar");
+    path.push("marks.json");
+    path
+}
+
+// Letters only, like vim's a-z and A-Z marks.
+pub fn is_mark_name(name: char) -> bool {
+    name.is_ascii_alphabetic()
+}
+
+pub fn load_marks(repo_path: &str) -> Marks {
+    load_marks_from_path(&marks_config_path(), repo_path)
+}
+
+pub fn save_marks(repo_path: &str, marks: &Marks) {
+    save_marks_to_path(&marks_config_path(), repo_path, marks);
+}
+
+// Entries with a bad name or sha are skipped rather than failing the whole file.
+pub fn load_marks_from_path(path: &Path, repo_path: &str) -> Marks {
+    let config = load_config_from_path(path);
+    let Some(entry) = config.repositories.into_iter().find(|entry| entry.path == repo_path) else {
+        return Marks::new();
+    };
+
+    entry
+        .marks
+        .into_iter()
+        .filter_map(|mark| {
+            let mut chars = mark.name.chars();
+            let name = chars.next().filter(|name| is_mark_name(*name) && chars.next().is_none())?;
+            Some((name, Oid::from_str(&mark.oid).ok()?))
+        })
+        .collect()
+}
+
+pub fn save_marks_to_path(path: &Path, repo_path: &str, marks: &Marks) {
+    let mut config = load_config_from_path(path);
+    let marks: Vec<MarkEntry> = marks.iter().map(|(name, oid)| MarkEntry { name: name.to_string(), oid: oid.to_string() }).collect();
+
+    config.repositories.retain(|entry| entry.path != repo_path);
+    if !marks.is_empty() {
+        config.repositories.push(RepositoryMarks { path: repo_path.to_string(), marks });
+    }
+    config.repositories.sort_by(|a, b| a.path.cmp(&b.path));
+
+    if let Some(parent) = path.parent()
+        && !parent.exists()
+    {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    if let Ok(config_string) = facet_json::to_string_pretty(&config) {
+        let _ = fs::write(path, config_string);
+    }
+}
+
+fn load_config_from_path(path: &Path) -> MarksConfig {
+    if !path.exists() {
+        return MarksConfig::default();
+    }
+
+    let Ok(contents) = fs::read_to_string(path) else {
+        return MarksConfig::default();
+    };
+    facet_json::from_str::<MarksConfig>(&contents).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "../tests/helpers/marks.rs"]
+mod tests;
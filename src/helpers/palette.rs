@@ -2484,14 +2484,14 @@ fn theme_from_config(config: ThemeConfig) -> Option<Theme> {
     Some(theme)
 }
 
+fn read_theme_from_path(path: &Path) -> Option<Theme> {
+    let contents = fs::read_to_string(path).ok()?;
+    facet_json::from_str::<ThemeConfig>(&contents).ok().and_then(theme_from_config)
+}
+
 fn load_theme_from_path(path: &Path) -> Theme {
-    if path.exists() {
-        let contents = fs::read_to_string(path).unwrap_or_default();
-        if let Ok(config) = facet_json::from_str::<ThemeConfig>(&contents)
-            && let Some(theme) = theme_from_config(config)
-        {
-            return theme;
-        }
+    if let Some(theme) = read_theme_from_path(path) {
+        return theme;
     }
 
     let theme = Theme::default();
@@ -2515,6 +2515,11 @@ pub fn load_theme() -> Theme {
     load_theme_from_path(&theme_path())
 }
 
+// Headless commands use the saved theme without writing a default one.
+pub fn saved_theme() -> Theme {
+    read_theme_from_path(&theme_path()).unwrap_or_default()
+}
+
 pub fn save_theme(theme: &Theme) {
     save_theme_to_path(&theme_path(), theme);
 }
@@ -0,0 +1,67 @@
+use git2::Error;
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    process::{Command, ExitStatus, Stdio},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+// Process output is shared with the UI thread, which shows the latest lines while it runs.
+pub type ProcessLog = Arc<Mutex<Vec<String>>>;
+
+pub fn push_line(log: &ProcessLog, line: String) {
+    if let Ok(mut lines) = log.lock() {
+        lines.push(line);
+    }
+}
+
+fn stream_lines(reader: impl Read, log: &ProcessLog) {
+    for line in BufReader::new(reader).lines().map_while(Result::ok) {
+        push_line(log, line);
+    }
+}
+
+// `name` reads as the subject of the error, such as "the pre-commit hook".
+pub fn exit_result(name: &str, status: ExitStatus) -> Result<(), Error> {
+    match status.code() {
+        Some(0) => Ok(()),
+        Some(code) => Err(Error::from_str(&format!("{name} failed with exit code {code}"))),
+        None => Err(Error::from_str(&format!("{name} was terminated"))),
+    }
+}
+
+// Runs `command` with stdout and stderr streamed into the log; a non-zero exit is an error.
+// Without `stdin` the process reads from null.
+pub fn run_logged(mut command: Command, name: &str, stdin: Option<Vec<u8>>, log: &ProcessLog) -> Result<(), Error> {
+    let mut child = command
+        .stdin(if stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| Error::from_str(&format!("could not run {name}: {error}")))?;
+
+    // Input is written from its own thread so a chatty process cannot block on a full stdout pipe.
+    // It may also exit without reading it, so a broken pipe is not a failure.
+    let writer = child.stdin.take().zip(stdin).map(|(mut pipe, input)| {
+        thread::spawn(move || {
+            let _ = pipe.write_all(&input);
+        })
+    });
+    let stderr = child.stderr.take().map(|stderr| {
+        let log = log.clone();
+        thread::spawn(move || stream_lines(stderr, &log))
+    });
+    if let Some(stdout) = child.stdout.take() {
+        stream_lines(stdout, log);
+    }
+    for handle in [writer, stderr].into_iter().flatten() {
+        let _ = handle.join();
+    }
+
+    let status = child.wait().map_err(|error| Error::from_str(&format!("{name} failed: {error}")))?;
+    exit_result(name, status)
+}
+
+#[cfg(test)]
+#[path = "../tests/helpers/process.rs"]
+mod tests;
@@ -0,0 +1,122 @@
+use facet::Facet;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// The pane that had focus, with the graph and the file viewer told apart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Facet)]
+#[facet(default)]
+#[repr(C)]
+pub enum SessionPane {
+    #[default]
+    Graph,
+    Viewer,
+    Branches,
+    Tags,
+    Stashes,
+    Reflogs,
+    Worktrees,
+    Submodules,
+    Search,
+    Inspector,
+    Staged,
+    Unstaged,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Facet)]
+#[facet(default)]
+#[repr(C)]
+pub enum SessionViewerMode {
+    #[default]
+    Full,
+    Hunks,
+    Split,
+}
+
+// Where a repository was left: the selected commit by sha, so it survives history changes, and pane rows by name.
+// Stashes are named by their sha; the indices are what older sessions saved and are used when no name was.
+#[derive(Facet, Debug, Clone, Default, PartialEq, Eq)]
+pub struct RepositorySession {
+    pub path: String,
+    #[facet(default)]
+    pub oid: Option<String>,
+    #[facet(default)]
+    pub offset: usize,
+    #[facet(default)]
+    pub pane: SessionPane,
+    #[facet(default)]
+    pub file_name: Option<String>,
+    #[facet(default)]
+    pub viewer_mode: SessionViewerMode,
+    #[facet(default)]
+    pub branches_selected: usize,
+    #[facet(default)]
+    pub tags_selected: usize,
+    #[facet(default)]
+    pub stashes_selected: usize,
+    #[facet(default)]
+    pub branch_name: Option<String>,
+    #[facet(default)]
+    pub tag_name: Option<String>,
+    #[facet(default)]
+    pub stash_oid: Option<String>,
+}
+
+#[derive(Facet, Clone, Default)]
+pub struct SessionConfig {
+    #[facet(default)]
+    pub repositories: Vec<RepositorySession>,
+}
+
+pub fn session_config_path() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap();
+    path.push("guitar");
+    path.push("session.json");
+    path
+}
+
+pub fn load_session(repo_path: &str) -> Option<RepositorySession> {
+    load_session_from_path(&session_config_path(), repo_path)
+}
+
+pub fn save_session(session: &RepositorySession) {
+    save_session_to_path(&session_config_path(), session);
+}
+
+pub fn load_session_from_path(path: &Path, repo_path: &str) -> Option<RepositorySession> {
+    load_config_from_path(path).repositories.into_iter().find(|entry| entry.path == repo_path)
+}
+
+pub fn save_session_to_path(path: &Path, session: &RepositorySession) {
+    let mut config = load_config_from_path(path);
+
+    config.repositories.retain(|entry| entry.path != session.path);
+    config.repositories.push(session.clone());
+    config.repositories.sort_by(|a, b| a.path.cmp(&b.path));
+
+    if let Some(parent) = path.parent()
+        && !parent.exists()
+    {
+        let _ = fs::create_dir_all(parent);
+    }
+
+    if let Ok(config_string) = facet_json::to_string_pretty(&config) {
+        let _ = fs::write(path, config_string);
+    }
+}
+
+fn load_config_from_path(path: &Path) -> SessionConfig {
+    if !path.exists() {
+        return SessionConfig::default();
+    }
+
+    let Ok(contents) = fs::read_to_string(path) else {
+        return SessionConfig::default();
+    };
+    facet_json::from_str::<SessionConfig>(&contents).unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "../tests/helpers/session.rs"]
+mod tests;
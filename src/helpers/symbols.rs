@@ -76,6 +76,7 @@ impl SymbolTheme {
             commit_branch: branch.local_visible.clone(),
             commit: branch.local_hidden.clone(),
             commit_stash: s("◎"),
+            commit_shallow: s("◓"),
             empty: s(" "),
             horizontal: border.horizontal.clone(),
             horizontal_dotted: s("┄"),
@@ -180,6 +181,7 @@ impl SymbolTheme {
             commit_branch: branch.local_visible.clone(),
             commit: branch.local_hidden.clone(),
             commit_stash: s("@"),
+            commit_shallow: s("~"),
             empty: s(" "),
             horizontal: border.horizontal.clone(),
             horizontal_dotted: s("."),
@@ -405,6 +407,8 @@ pub struct GraphSymbols {
     pub commit_branch: String,
     pub commit: String,
     pub commit_stash: String,
+    // Shallow clone boundary: a commit whose parents were not fetched.
+    pub commit_shallow: String,
     pub empty: String,
     pub horizontal: String,
     pub horizontal_dotted: String,
@@ -425,6 +429,7 @@ impl GraphSymbols {
             self.commit_branch.as_str(),
             self.commit.as_str(),
             self.commit_stash.as_str(),
+            self.commit_shallow.as_str(),
             self.empty.as_str(),
             self.horizontal.as_str(),
             self.horizontal_dotted.as_str(),
@@ -691,6 +696,7 @@ optional_symbol_config!(GraphSymbolConfig {
     commit_branch,
     commit,
     commit_stash,
+    commit_shallow,
     empty,
     horizontal,
     horizontal_dotted,
@@ -788,6 +794,7 @@ fn apply_symbols(theme: &mut SymbolTheme, config: &SymbolConfig) {
         apply_symbol(&mut theme.graph.commit_branch, &graph.commit_branch);
         apply_symbol(&mut theme.graph.commit, &graph.commit);
         apply_symbol(&mut theme.graph.commit_stash, &graph.commit_stash);
+        apply_symbol(&mut theme.graph.commit_shallow, &graph.commit_shallow);
         apply_symbol(&mut theme.graph.empty, &graph.empty);
         apply_symbol(&mut theme.graph.horizontal, &graph.horizontal);
         apply_symbol(&mut theme.graph.horizontal_dotted, &graph.horizontal_dotted);
@@ -917,6 +924,7 @@ fn symbol_config(theme: &SymbolTheme) -> SymbolConfig {
             commit_branch: Some(theme.graph.commit_branch.clone()),
             commit: Some(theme.graph.commit.clone()),
             commit_stash: Some(theme.graph.commit_stash.clone()),
+            commit_shallow: Some(theme.graph.commit_shallow.clone()),
             empty: Some(theme.graph.empty.clone()),
             horizontal: Some(theme.graph.horizontal.clone()),
             horizontal_dotted: Some(theme.graph.horizontal_dotted.clone()),
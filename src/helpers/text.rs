@@ -174,6 +174,34 @@ pub fn decode(bytes: &[u8]) -> String {
     }
 }
 
+// Human readable byte count, in binary units like `ls -h`.
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+// Sixteen bytes per row in `xxd` layout: offset, hex pairs, then printable ASCII.
+pub fn hex_dump(bytes: &[u8]) -> Vec<String> {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{byte:02x}")).collect();
+            let ascii: String = chunk.iter().map(|&byte| if byte.is_ascii_graphic() || byte == b' ' { byte as char } else { '.' }).collect();
+            format!("{:08x}  {:<47}  {ascii}", row * 16, hex.join(" "))
+        })
+        .collect()
+}
+
 // Normalize text for terminal display and strip non-newline control characters.
 pub fn sanitize(string: String) -> String {
     string
@@ -23,3 +23,9 @@ pub fn timestamp_to_utc_date_time(time: Time) -> String {
 
     final_utc.format("%Y-%m-%d %H:%M").to_string()
 }
+
+// RFC 3339, the ISO 8601 profile, kept in the offset the commit was made in.
+pub fn timestamp_to_iso8601(time: Time) -> String {
+    let offset = FixedOffset::east_opt(time.offset_minutes() * 60).unwrap();
+    offset.timestamp_opt(time.seconds(), 0).single().map(|date_time| date_time.to_rfc3339()).unwrap_or_default()
+}
@@ -4,6 +4,10 @@ pub mod core {
     pub mod branches;
     pub mod buffer;
     pub mod chunk;
+    pub mod commit_graph;
+    pub mod content_search;
+    pub mod diff_service;
+    pub mod export;
     pub mod graph_service;
     pub mod layers;
     pub mod oids;
@@ -12,6 +16,7 @@ pub mod core {
     pub mod stashes;
     pub mod submodules;
     pub mod tags;
+    pub mod walk_cache;
     pub mod walker;
     pub mod worktrees;
 }
@@ -24,13 +29,18 @@ pub mod git {
         pub mod committing;
         pub mod conflicts;
         pub mod fetching;
+        pub mod hooks;
+        pub mod journal;
         pub mod merging;
         pub mod network;
+        pub mod patching;
         pub mod pushing;
         pub mod rebasing;
         pub mod remotes;
         pub mod resetting;
         pub mod reverting;
+        pub mod sequencing;
+        pub mod signing;
         pub mod staging;
         pub mod stashing;
         pub mod submodules;
@@ -41,27 +51,37 @@ pub mod git {
         pub mod path;
     }
     pub mod queries {
+        pub mod blobs;
         pub mod commits;
         pub mod diffs;
         pub mod file_history;
         pub mod files;
+        pub mod grafts;
+        pub mod grep;
         pub mod helpers;
         pub mod reflogs;
         pub mod remotes;
+        pub mod signatures;
         pub mod submodules;
         pub mod worktrees;
     }
 }
 pub mod helpers {
     pub mod branch_visibility;
+    pub mod cli;
     pub mod colors;
+    pub mod custom_commands;
     pub mod heatmap;
+    pub mod journal;
     pub mod keymap;
     pub mod layout;
     pub mod localisation;
     pub mod logger;
+    pub mod marks;
     pub mod palette;
+    pub mod process;
     pub mod recent;
+    pub mod session;
     pub mod spinner;
     pub mod symbols;
     pub mod text;
@@ -1,10 +1,18 @@
-use std::{env, fs, io, path::PathBuf};
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::PathBuf,
+    process,
+};
 
-use guitar::{App, VERSION};
-
-const RESET_CONFIG: &str = "--reset";
-const VERSION_LONG: &str = "--version";
-const VERSION_SHORT: &str = "-v";
+use guitar::{
+    App, VERSION,
+    core::{export::export_graph, walk_cache::walk_cache_dir},
+    helpers::{
+        cli::{CliError, USAGE, parse_args, resolve_repository},
+        palette::{Theme, saved_theme},
+    },
+};
 
 fn guitar_config_dir() -> io::Result<PathBuf> {
     let mut path = dirs::config_dir().ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not find config directory"))?;
@@ -20,25 +28,70 @@ fn reset_saved_config() -> io::Result<()> {
         fs::remove_file(&path)?;
     }
     println!("Reset saved guitar config at {}", path.display());
+
+    // Stored graph walks are derived data, but a reset should not leave them behind.
+    let walks = walk_cache_dir();
+    if walks.is_dir() {
+        fs::remove_dir_all(&walks)?;
+    }
     Ok(())
 }
 
+fn exit_with(error: CliError) -> ! {
+    eprintln!("guitar: {error}");
+    process::exit(error.exit_code());
+}
+
 fn main() -> io::Result<()> {
     // Meta flags are handled before ratatui takes over the terminal.
-    let args: Vec<String> = env::args().collect();
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|error| exit_with(error));
 
     // Version output must stay plain so scripts can consume it.
-    if args.iter().any(|a| a == VERSION_LONG || a == VERSION_SHORT) {
+    if args.is_version {
         println!("{VERSION}");
         return Ok(());
     }
 
-    if args.iter().any(|a| a == RESET_CONFIG) {
+    if args.is_help {
+        println!("{USAGE}");
+        return Ok(());
+    }
+
+    // With `log`, `--reset` only exports with the default theme; the saved config is left alone.
+    if args.is_reset && args.log.is_none() {
         reset_saved_config()?;
     }
 
+    // A missing repository is reported before the terminal switches to the alternate screen.
+    let target = resolve_repository(&args, |key| env::var(key).ok()).unwrap_or_else(|error| exit_with(error));
+
+    // `guitar log` prints the graph and never touches the terminal or saved config.
+    if let Some(options) = &args.log {
+        let Some(target) = target else {
+            exit_with(CliError::NotARepository(".".to_string()));
+        };
+        let theme = if args.is_reset { Theme::default() } else { saved_theme() };
+        let mut out = io::BufWriter::new(io::stdout().lock());
+        match export_graph(target.path.display().to_string(), options, &theme, &mut out).and_then(|()| out.flush()) {
+            Ok(()) => {},
+            // A pager or `head` that stops reading early is not a failure.
+            Err(error) if error.kind() == io::ErrorKind::BrokenPipe => {},
+            Err(error) => {
+                eprintln!("guitar: {error}");
+                process::exit(1);
+            },
+        }
+        return Ok(());
+    }
+
+    let mut app = App::default();
+    if let Some(target) = target {
+        app.tab.path = Some(target.path.display().to_string());
+        app.tab.work_tree = target.work_tree;
+    }
+
     let mut terminal = ratatui::init();
-    let app_result = App::default().run(&mut terminal);
+    let app_result = app.run(&mut terminal);
     ratatui::restore();
     app_result
 }
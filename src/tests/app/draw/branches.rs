@@ -1,6 +1,6 @@
 use crate::{
     app::{
-        app::{App, Focus},
+        app::{App, Focus, RepoTab},
         state::layout::Layout,
     },
     core::branches::Branches,
@@ -25,7 +25,11 @@ fn branches_render_hidden_local_rows_with_hollow_icon() {
     branches.local.insert(2, vec!["main".to_string()]);
     branches.hidden_branch_names.insert("feature".to_string());
 
-    let mut app = App { focus: Focus::Branches, branches, layout: Layout { branches: Rect::new(0, 0, 30, 5), branches_scrollbar: Rect::new(29, 0, 1, 5), ..Default::default() }, ..Default::default() };
+    let mut app = App {
+        layout: Layout { branches: Rect::new(0, 0, 30, 5), branches_scrollbar: Rect::new(29, 0, 1, 5), ..Default::default() },
+        tab: RepoTab { focus: Focus::Branches, branches, ..Default::default() },
+        ..Default::default()
+    };
 
     let rendered = rendered_branches(&mut app);
 
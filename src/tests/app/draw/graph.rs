@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
     app::{
-        app::{GraphWindowCache, Viewport},
+        app::{GraphWindowCache, RepoTab, Viewport},
         state::layout::Layout,
     },
     core::{
@@ -59,6 +59,7 @@ fn graph_row(index: usize, alias: u32, oid: Oid, summary: &str) -> GraphRow {
         branches: Vec::new(),
         tags: Vec::new(),
         is_stash: false,
+        is_shallow: false,
         stash_lane: None,
         worktrees: Vec::new(),
         reflog: None,
@@ -71,15 +72,14 @@ fn history_row(graph_index: usize, oid: Oid) -> GraphFileHistoryRow {
 
 fn app_with_cached_window(start: usize, summaries: &[&str], oid: Oid) -> App {
     let mut app = App {
-        viewport: Viewport::Graph,
-        focus: Focus::Viewport,
         layout: Layout { graph: Rect::new(0, 0, 80, 3), graph_scrollbar: Rect::new(79, 0, 1, 3), ..Default::default() },
+        tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.layout_config.is_shas = false;
     app.layout_config.is_zen = false;
-    app.graph.total = 4;
-    app.graph.graph_window = Some(GraphWindowCache {
+    app.tab.graph.total = 4;
+    app.tab.graph.graph_window = Some(GraphWindowCache {
         version: 1,
         start,
         end: start + summaries.len(),
@@ -100,16 +100,15 @@ fn graph_history(len: usize) -> GraphHistory {
 
 fn app_with_uncommitted_window(window_end: usize, history_len: usize, oid: Oid) -> App {
     let mut app = App {
-        viewport: Viewport::Graph,
-        focus: Focus::Viewport,
         layout: Layout { graph: Rect::new(0, 0, 80, 3), graph_scrollbar: Rect::new(79, 0, 1, 3), ..Default::default() },
+        tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.layout_config.is_shas = false;
     app.layout_config.is_zen = false;
-    app.graph.total = 3;
-    app.uncommitted.modified_count = 2;
-    app.graph.graph_window = Some(GraphWindowCache {
+    app.tab.graph.total = 3;
+    app.tab.uncommitted.modified_count = 2;
+    app.tab.graph.graph_window = Some(GraphWindowCache {
         version: 1,
         start: 0,
         end: window_end,
@@ -129,11 +128,11 @@ fn rendered_lines(terminal: &Terminal<TestBackend>) -> Vec<String> {
 fn graph_highlights_file_history_rows_when_search_pane_is_open() {
     let (_path, repo, oid) = temp_repo("file-search-highlight");
     let mut app = app_with_cached_window(0, &["uncommitted", "touch searched file", "other commit"], oid);
-    app.focus = Focus::Search;
+    app.tab.focus = Focus::Search;
     app.layout_config.is_search = true;
-    app.graph_selected = 2;
-    app.search_path = Some("src/lib.rs".to_string());
-    app.search_rows = vec![history_row(1, oid)];
+    app.tab.graph_selected = 2;
+    app.tab.search_path = Some("src/lib.rs".to_string());
+    app.tab.search_rows = vec![history_row(1, oid)];
 
     let backend = TestBackend::new(80, 3);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -149,15 +148,35 @@ fn graph_highlights_file_history_rows_when_search_pane_is_open() {
     assert_ne!(buffer[(1, 2)].bg, selected_bg);
 }
 
+#[test]
+fn graph_draws_the_selection_on_top_of_marked_rows() {
+    let (_path, repo, oid) = temp_repo("marked-selection");
+    let mut app = app_with_cached_window(0, &["uncommitted", "marked and selected", "marked"], oid);
+    app.tab.graph_selected = 1;
+    app.tab.graph_marked_oids.push(oid);
+
+    let backend = TestBackend::new(80, 3);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal
+        .draw(|frame| {
+            app.draw_graph(frame, &repo);
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer();
+    assert_eq!(buffer[(1, 1)].bg, app.theme.background_or_default(app.theme.COLOR_GREY_600));
+    assert_eq!(buffer[(1, 2)].bg, app.theme.background_or_default(app.theme.COLOR_GREY_700));
+}
+
 #[test]
 fn graph_does_not_highlight_file_history_rows_when_search_pane_is_closed() {
     let (_path, repo, oid) = temp_repo("file-search-highlight-closed");
     let mut app = app_with_cached_window(0, &["uncommitted", "touch searched file", "other commit"], oid);
-    app.focus = Focus::Search;
+    app.tab.focus = Focus::Search;
     app.layout_config.is_search = false;
-    app.graph_selected = 2;
-    app.search_path = Some("src/lib.rs".to_string());
-    app.search_rows = vec![history_row(1, oid)];
+    app.tab.graph_selected = 2;
+    app.tab.search_path = Some("src/lib.rs".to_string());
+    app.tab.search_rows = vec![history_row(1, oid)];
 
     let backend = TestBackend::new(80, 3);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -176,8 +195,8 @@ fn graph_does_not_highlight_file_history_rows_when_search_pane_is_closed() {
 fn graph_cached_rows_shift_up_when_requested_window_moves_down() {
     let (_path, repo, oid) = temp_repo("shift-down");
     let mut app = app_with_cached_window(0, &["row0", "row1", "row2"], oid);
-    app.graph_selected = 1;
-    app.graph_scroll.set(1);
+    app.tab.graph_selected = 1;
+    app.tab.graph_scroll.set(1);
 
     let backend = TestBackend::new(80, 3);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -198,8 +217,8 @@ fn graph_cached_rows_shift_up_when_requested_window_moves_down() {
 fn graph_cached_rows_shift_down_when_requested_window_moves_up() {
     let (_path, repo, oid) = temp_repo("shift-up");
     let mut app = app_with_cached_window(1, &["row1", "row2", "row3"], oid);
-    app.graph_selected = 0;
-    app.graph_scroll.set(0);
+    app.tab.graph_selected = 0;
+    app.tab.graph_scroll.set(0);
 
     let backend = TestBackend::new(80, 3);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -220,9 +239,9 @@ fn graph_cached_rows_shift_down_when_requested_window_moves_up() {
 fn graph_short_page_stripes_blank_tail_rows() {
     let (_path, repo, oid) = temp_repo("blank-tail");
     let mut app = app_with_cached_window(0, &["row0"], oid);
-    app.graph.total = 1;
-    app.graph_selected = 0;
-    app.graph_scroll.set(0);
+    app.tab.graph.total = 1;
+    app.tab.graph_selected = 0;
+    app.tab.graph_scroll.set(0);
     let zebra = app.theme.background_or_default(app.theme.COLOR_GREY_900);
 
     let backend = TestBackend::new(80, 3);
@@ -257,9 +276,8 @@ fn graph_ascii_symbol_theme_renders_ascii_only_output() {
 fn graph_empty_state_stripes_backdrop() {
     let (_path, repo) = temp_unborn_repo("empty-backdrop");
     let mut app = App {
-        viewport: Viewport::Graph,
-        focus: Focus::Viewport,
         layout: Layout { graph: Rect::new(0, 0, 80, 3), graph_scrollbar: Rect::new(79, 0, 1, 3), ..Default::default() },
+        tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.layout_config.is_zen = false;
@@ -282,8 +300,8 @@ fn graph_empty_state_stripes_backdrop() {
 fn uncommitted_row_waits_for_visible_page_before_rendering() {
     let (_path, repo, oid) = temp_repo("uncommitted-waits");
     let mut app = app_with_uncommitted_window(2, 2, oid);
-    app.graph_selected = 0;
-    app.graph_scroll.set(0);
+    app.tab.graph_selected = 0;
+    app.tab.graph_scroll.set(0);
 
     let backend = TestBackend::new(80, 3);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -302,8 +320,8 @@ fn uncommitted_row_waits_for_visible_page_before_rendering() {
 fn uncommitted_row_renders_when_visible_page_is_ready() {
     let (_path, repo, oid) = temp_repo("uncommitted-ready");
     let mut app = app_with_uncommitted_window(3, 3, oid);
-    app.graph_selected = 0;
-    app.graph_scroll.set(0);
+    app.tab.graph_selected = 0;
+    app.tab.graph_scroll.set(0);
 
     let backend = TestBackend::new(80, 3);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -323,18 +341,16 @@ fn graph_draw_prefetches_one_screen_before_and_after_visible_window() {
     let (_path, repo, _oid) = temp_repo("prefetch-window");
     let (tx, rx) = std::sync::mpsc::channel();
     let mut app = App {
-        viewport: Viewport::Graph,
-        focus: Focus::Viewport,
-        graph_tx: Some(tx),
         layout: Layout { graph: Rect::new(0, 0, 80, 3), graph_scrollbar: Rect::new(79, 0, 1, 3), ..Default::default() },
+        tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, graph_tx: Some(tx), ..Default::default() },
         ..Default::default()
     };
     app.layout_config.is_shas = false;
     app.layout_config.is_zen = false;
-    app.graph.generation = 7;
-    app.graph.total = 20;
-    app.graph_selected = 5;
-    app.graph_scroll.set(5);
+    app.tab.graph.generation = 7;
+    app.tab.graph.total = 20;
+    app.tab.graph_selected = 5;
+    app.tab.graph_scroll.set(5);
 
     let backend = TestBackend::new(80, 3);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -358,9 +374,9 @@ fn graph_draw_prefetches_one_screen_before_and_after_visible_window() {
 fn graph_draw_keeps_prefetched_rows_out_of_visible_table() {
     let (_path, repo, oid) = temp_repo("prefetch-render");
     let mut app = app_with_cached_window(2, &["row2", "row3", "row4", "row5", "row6", "row7", "row8", "row9", "row10"], oid);
-    app.graph.total = 20;
-    app.graph_selected = 5;
-    app.graph_scroll.set(5);
+    app.tab.graph.total = 20;
+    app.tab.graph_selected = 5;
+    app.tab.graph_scroll.set(5);
 
     let backend = TestBackend::new(80, 3);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -383,8 +399,8 @@ fn zero_sized_graph_draw_does_not_request_empty_window() {
     let (_path, repo, oid) = temp_repo("zero-graph");
     let mut app = app_with_cached_window(0, &["row0"], oid);
     let (tx, rx) = std::sync::mpsc::channel();
-    app.graph_tx = Some(tx);
-    app.graph.generation = 7;
+    app.tab.graph_tx = Some(tx);
+    app.tab.graph.generation = 7;
     app.layout.graph = Rect::new(0, 0, 0, 0);
     app.layout.graph_scrollbar = Rect::new(0, 0, 0, 0);
 
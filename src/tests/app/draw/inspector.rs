@@ -1,11 +1,11 @@
 use crate::{
     app::{
-        app::{App, Focus},
+        app::{App, Focus, GraphWindowCache, RepoTab},
         state::layout::Layout,
     },
-    core::graph_service::{GraphCommand, GraphLookupKind},
+    core::graph_service::{GraphCommand, GraphLookupKind, GraphRow},
 };
-use git2::Repository;
+use git2::{Oid, Repository, Signature};
 use ratatui::{Terminal, backend::TestBackend, layout::Rect};
 use std::{
     fs,
@@ -30,13 +30,11 @@ fn inspector_loading_requests_missing_graph_row() {
     let (_path, repo) = temp_repo("loading");
     let (tx, rx) = std::sync::mpsc::channel();
     let mut app = App {
-        focus: Focus::Inspector,
-        graph_selected: 42,
-        graph_tx: Some(tx),
         layout: Layout { inspector: Rect::new(0, 0, 48, 5), inspector_scrollbar: Rect::new(47, 0, 1, 5), ..Default::default() },
+        tab: RepoTab { focus: Focus::Inspector, graph_selected: 42, graph_tx: Some(tx), ..Default::default() },
         ..Default::default()
     };
-    app.graph.generation = 7;
+    app.tab.graph.generation = 7;
     app.layout_config.is_zen = false;
 
     let backend = TestBackend::new(48, 5);
@@ -55,3 +53,62 @@ fn inspector_loading_requests_missing_graph_row() {
         other => panic!("expected graph row lookup, got {other:?}"),
     }
 }
+
+fn commit(repo: &Repository, file: &str, message: &str, parents: &[Oid]) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), message).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(std::path::Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parents: Vec<git2::Commit<'_>> = parents.iter().map(|oid| repo.find_commit(*oid).unwrap()).collect();
+    let parents: Vec<&git2::Commit<'_>> = parents.iter().collect();
+    repo.commit(None, &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+#[test]
+fn replaced_commits_show_the_replacement_in_the_inspector_and_file_list() {
+    let (path, repo) = temp_repo("replaced");
+    let root = commit(&repo, "root.txt", "root", &[]);
+    let original = commit(&repo, "original.txt", "original message", &[root]);
+    let replacement = commit(&repo, "replacement.txt", "replacement message", &[]);
+    let row = GraphRow {
+        index: 1,
+        alias: 0,
+        oid: original,
+        summary: "replacement message".to_string(),
+        committer_date: String::new(),
+        committer_name: String::new(),
+        is_merge: false,
+        has_any_branch: false,
+        branches: Vec::new(),
+        tags: Vec::new(),
+        is_stash: false,
+        is_shallow: false,
+        stash_lane: None,
+        worktrees: Vec::new(),
+        reflog: None,
+    };
+    let mut app = App {
+        layout: Layout { inspector: Rect::new(0, 0, 48, 30), inspector_scrollbar: Rect::new(47, 0, 1, 30), ..Default::default() },
+        tab: RepoTab { focus: Focus::Inspector, graph_selected: 1, replacements: [(original, replacement)].into_iter().collect(), ..Default::default() },
+        ..Default::default()
+    };
+    app.tab.graph.graph_window = Some(GraphWindowCache { version: 1, start: 1, end: 2, head_alias: 0, rows: vec![row], history: Default::default() });
+    app.layout_config.is_zen = false;
+    app.tab.repo = Some(std::rc::Rc::new(Repository::open(&path).unwrap()));
+
+    let backend = TestBackend::new(48, 30);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|frame| app.draw_inspector(frame, &repo)).unwrap();
+    let rendered = rendered(&terminal);
+    assert!(rendered.contains("replacement message"), "{rendered}");
+    assert!(!rendered.contains("original message"), "{rendered}");
+    // Parents are the replacement's too, and it has none.
+    assert!(!rendered.contains(&root.to_string()[..7]), "{rendered}");
+
+    // The file list is the replacement's: a root commit adding every file, not the original's one change.
+    app.request_selected_commit_diff();
+    assert_eq!(app.tab.current_diff.iter().map(|change| change.filename.as_str()).collect::<Vec<_>>(), vec!["original.txt", "replacement.txt", "root.txt"]);
+    let _ = fs::remove_dir_all(path);
+}
@@ -0,0 +1,48 @@
+use super::*;
+use ratatui::{Terminal, backend::TestBackend, buffer::Buffer};
+
+fn row_symbols(buffer: &Buffer, row: u16) -> String {
+    (0..buffer.area.width).map(|x| buffer[(x, row)].symbol()).collect::<String>()
+}
+
+fn find_row(buffer: &Buffer, text: &str) -> u16 {
+    (0..buffer.area.height).find(|&y| row_symbols(buffer, y).contains(text)).unwrap()
+}
+
+#[test]
+fn content_search_modal_renders_locations_highlighted_matches_and_status() {
+    let mut app = App::default();
+    app.modal_input.set_value("needle");
+    app.modal_content_search_results = vec![
+        GrepMatch { path: "src/a.rs".to_string(), line: 12, snippet: "let needle = 1;".to_string(), matched: (4, 10) },
+        GrepMatch { path: "b.txt".to_string(), line: 3, snippet: "needle".to_string(), matched: (0, 6) },
+    ];
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|frame| app.draw_modal_content_search(frame)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    assert!(row_symbols(buffer, find_row(buffer, "Search file contents")).contains("(working tree)"));
+    assert!(row_symbols(buffer, find_row(buffer, "literal")).contains("2 matches"));
+    assert!(row_symbols(buffer, find_row(buffer, "choose (enter)")).contains("regex on/off (tab)"));
+
+    let row = find_row(buffer, "src/a.rs:12 let needle = 1;");
+    let symbols = row_symbols(buffer, row);
+    let start = symbols[..symbols.find("needle").unwrap()].chars().count() as u16;
+    assert_eq!(buffer[(start, row)].fg, app.theme.COLOR_GRASS);
+    assert_eq!(buffer[(start - 2, row)].fg, app.theme.COLOR_HIGHLIGHTED);
+    assert_eq!(buffer[(start + 6, row)].fg, app.theme.COLOR_HIGHLIGHTED);
+}
+
+#[test]
+fn content_search_modal_shows_invalid_regex_errors() {
+    let mut app = App { modal_content_search_is_regex: true, modal_content_search_error: Some("regex parse error:\n    (al\n    ^\nerror: unclosed group".to_string()), ..Default::default() };
+    app.modal_input.set_value("(al");
+
+    let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+    terminal.draw(|frame| app.draw_modal_content_search(frame)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let row = row_symbols(buffer, find_row(buffer, "regex  error: unclosed group"));
+    assert!(!row.contains("no matches"));
+}
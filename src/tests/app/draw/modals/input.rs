@@ -1,4 +1,5 @@
 use super::*;
+use crate::app::app::RepoTab;
 use ratatui::{Terminal, backend::TestBackend};
 
 fn rendered_symbols(terminal: &Terminal<TestBackend>) -> String {
@@ -21,3 +22,14 @@ fn input_modal_renders_esc_title_action_row_and_bordered_input() {
     assert!(rendered.contains("commit message"));
     assert!(rendered.contains("─"));
 }
+
+#[test]
+fn commit_prompt_shows_skip_hooks_toggle() {
+    let mut app = App { skip_hooks_once: true, tab: RepoTab { focus: Focus::ModalCommit, ..Default::default() }, ..Default::default() };
+
+    let backend = TestBackend::new(80, 24);
+    let mut terminal = Terminal::new(backend).unwrap();
+    terminal.draw(|frame| app.draw_modal_input(frame, "commit")).unwrap();
+
+    assert!(rendered_symbols(&terminal).contains("[x] skip hooks (tab)"));
+}
@@ -8,13 +8,13 @@ fn solo_modal_renders_toggle_prompt_and_highlights_selected_branch() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
     let mut app = App { modal_branch_action: BranchModalAction::Toggle, modal_solo_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(Oid::from_bytes(&[7; 20]).unwrap());
+    let alias = app.tab.oids.get_alias_by_oid(Oid::from_bytes(&[7; 20]).unwrap());
 
-    app.graph_selected = 1;
-    app.oids.sorted_aliases = vec![NONE, alias];
-    app.branches.sorted = vec![(alias, "main".to_string()), (alias, "feature".to_string())];
-    app.branches.local.insert(alias, vec!["main".to_string()]);
-    app.branches.colors.insert(alias, Color::Yellow);
+    app.tab.graph_selected = 1;
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+    app.tab.branches.sorted = vec![(alias, "main".to_string()), (alias, "feature".to_string())];
+    app.tab.branches.local.insert(alias, vec!["main".to_string()]);
+    app.tab.branches.colors.insert(alias, Color::Yellow);
 
     terminal.draw(|frame| app.draw_modal_solo(frame)).unwrap();
 
@@ -41,12 +41,12 @@ fn solo_modal_highlights_selected_branch_without_lane_color() {
     let backend = TestBackend::new(80, 24);
     let mut terminal = Terminal::new(backend).unwrap();
     let mut app = App { modal_branch_action: BranchModalAction::Solo, modal_solo_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(Oid::from_bytes(&[8; 20]).unwrap());
+    let alias = app.tab.oids.get_alias_by_oid(Oid::from_bytes(&[8; 20]).unwrap());
 
-    app.graph_selected = 1;
-    app.oids.sorted_aliases = vec![NONE, alias];
-    app.branches.sorted = vec![(alias, "main".to_string()), (alias, "feature".to_string())];
-    app.branches.local.insert(alias, vec!["main".to_string()]);
+    app.tab.graph_selected = 1;
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+    app.tab.branches.sorted = vec![(alias, "main".to_string()), (alias, "feature".to_string())];
+    app.tab.branches.local.insert(alias, vec!["main".to_string()]);
 
     terminal.draw(|frame| app.draw_modal_solo(frame)).unwrap();
 
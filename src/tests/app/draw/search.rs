@@ -1,6 +1,6 @@
 use crate::{
     app::{
-        app::{App, Focus},
+        app::{App, Focus, RepoTab},
         state::layout::Layout,
     },
     core::graph_service::GraphFileHistoryRow,
@@ -16,9 +16,9 @@ fn rendered(terminal: &Terminal<TestBackend>) -> String {
 
 fn search_app() -> App {
     let mut app = App {
-        focus: Focus::Search,
         layout: Layout { search: Rect::new(0, 0, 40, 5), search_scrollbar: Rect::new(39, 0, 1, 5), ..Default::default() },
         layout_config: LayoutConfig { is_search: true, ..Default::default() },
+        tab: RepoTab { focus: Focus::Search, ..Default::default() },
         ..Default::default()
     };
     app.layout_config.is_zen = false;
@@ -59,8 +59,8 @@ fn search_empty_state_stripes_backdrop() {
 #[test]
 fn search_loading_state_renders_selected_path() {
     let mut app = search_app();
-    app.search_path = Some("src/app/draw/search.rs".to_string());
-    app.search_is_loading = true;
+    app.tab.search_path = Some("src/app/draw/search.rs".to_string());
+    app.tab.search_is_loading = true;
 
     let backend = TestBackend::new(40, 5);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -74,7 +74,7 @@ fn search_loading_state_renders_selected_path() {
 #[test]
 fn search_results_render_rows_and_selection() {
     let mut app = search_app();
-    app.search_rows = vec![history_row(1, "touch search pane", FileStatus::Modified)];
+    app.tab.search_rows = vec![history_row(1, "touch search pane", FileStatus::Modified)];
 
     let backend = TestBackend::new(50, 5);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -93,13 +93,13 @@ fn search_results_render_rows_and_selection() {
 #[test]
 fn search_results_scroll_long_lists() {
     let mut app = search_app();
-    app.search_selected = 8;
-    app.search_rows = (0..12).map(|idx| history_row(idx, &format!("commit {idx}"), FileStatus::Modified)).collect();
+    app.tab.search_selected = 8;
+    app.tab.search_rows = (0..12).map(|idx| history_row(idx, &format!("commit {idx}"), FileStatus::Modified)).collect();
 
     let backend = TestBackend::new(50, 5);
     let mut terminal = Terminal::new(backend).unwrap();
     terminal.draw(|frame| app.draw_search(frame)).unwrap();
 
-    assert!(app.search_scroll.get() > 0);
+    assert!(app.tab.search_scroll.get() > 0);
     assert!(rendered(&terminal).contains("commit 8"));
 }
@@ -1,6 +1,6 @@
 use super::*;
 use crate::{
-    app::app::{SettingsTab, Viewport},
+    app::app::{RepoTab, SettingsTab, Viewport},
     git::queries::remotes::GUITAR_DEFAULT_REMOTE_CONFIG,
     helpers::{
         keymap::{Command, InputMode, KeyBinding},
@@ -36,7 +36,7 @@ fn temp_repo(name: &str) -> (std::path::PathBuf, Repository) {
 }
 
 fn settings_app() -> App {
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() };
+    let mut app = App { tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     let mut keymaps = IndexMap::new();
     let mut normal = IndexMap::new();
     normal.insert(KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
@@ -49,6 +49,7 @@ fn graph_row(index: usize, alias: u32, oid: Oid) -> GraphRow {
         branches: Vec::new(),
         tags: Vec::new(),
         is_stash: false,
+        is_shallow: false,
         stash_lane: None,
         worktrees: Vec::new(),
         reflog: None,
@@ -58,13 +59,13 @@ fn graph_row(index: usize, alias: u32, oid: Oid) -> GraphRow {
 #[test]
 fn status_highlights_matching_staged_and_unstaged_paths_when_search_pane_is_open() {
     let mut app = status_app();
-    app.graph_selected = 0;
-    app.is_uncommitted_loaded = true;
-    app.focus = Focus::Viewport;
+    app.tab.graph_selected = 0;
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.focus = Focus::Viewport;
     app.layout_config.is_search = true;
-    app.search_path = Some("src/selected.rs".to_string());
-    app.uncommitted.staged = FileChanges { modified: vec!["src/selected.rs".to_string(), "src/other.rs".to_string()], ..Default::default() };
-    app.uncommitted.unstaged = FileChanges { added: vec!["src/selected.rs".to_string()], ..Default::default() };
+    app.tab.search_path = Some("src/selected.rs".to_string());
+    app.tab.uncommitted.staged = FileChanges { modified: vec!["src/selected.rs".to_string(), "src/other.rs".to_string()], ..Default::default() };
+    app.tab.uncommitted.unstaged = FileChanges { added: vec!["src/selected.rs".to_string()], ..Default::default() };
 
     let backend = TestBackend::new(48, 10);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -86,12 +87,12 @@ fn status_highlights_matching_staged_and_unstaged_paths_when_search_pane_is_open
 #[test]
 fn status_highlights_matching_conflict_paths_when_search_pane_is_open() {
     let mut app = status_app();
-    app.graph_selected = 0;
-    app.is_uncommitted_loaded = true;
-    app.focus = Focus::Viewport;
+    app.tab.graph_selected = 0;
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.focus = Focus::Viewport;
     app.layout_config.is_search = true;
-    app.search_path = Some("src/conflict.rs".to_string());
-    app.uncommitted.conflicts = vec!["src/conflict.rs".to_string()];
+    app.tab.search_path = Some("src/conflict.rs".to_string());
+    app.tab.uncommitted.conflicts = vec!["src/conflict.rs".to_string()];
 
     let backend = TestBackend::new(48, 10);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -115,12 +116,12 @@ fn status_does_not_highlight_partial_matches_or_closed_search_pane() {
     let selected_bg;
     {
         let mut app = status_app();
-        app.graph_selected = 0;
-        app.is_uncommitted_loaded = true;
-        app.focus = Focus::Viewport;
+        app.tab.graph_selected = 0;
+        app.tab.is_uncommitted_loaded = true;
+        app.tab.focus = Focus::Viewport;
         app.layout_config.is_search = true;
-        app.search_path = Some("src/selected.rs".to_string());
-        app.uncommitted.staged = FileChanges { modified: vec!["src/selected.rs.bak".to_string()], ..Default::default() };
+        app.tab.search_path = Some("src/selected.rs".to_string());
+        app.tab.uncommitted.staged = FileChanges { modified: vec!["src/selected.rs.bak".to_string()], ..Default::default() };
         selected_bg = app.theme.background_or_default(app.theme.COLOR_GREY_800);
 
         let backend = TestBackend::new(48, 10);
@@ -134,12 +135,12 @@ fn status_does_not_highlight_partial_matches_or_closed_search_pane() {
     }
 
     let mut app = status_app();
-    app.graph_selected = 0;
-    app.is_uncommitted_loaded = true;
-    app.focus = Focus::Viewport;
+    app.tab.graph_selected = 0;
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.focus = Focus::Viewport;
     app.layout_config.is_search = false;
-    app.search_path = Some("src/selected.rs".to_string());
-    app.uncommitted.staged = FileChanges { modified: vec!["src/selected.rs".to_string()], ..Default::default() };
+    app.tab.search_path = Some("src/selected.rs".to_string());
+    app.tab.uncommitted.staged = FileChanges { modified: vec!["src/selected.rs".to_string()], ..Default::default() };
 
     let backend = TestBackend::new(48, 10);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -154,9 +155,9 @@ fn status_does_not_highlight_partial_matches_or_closed_search_pane() {
 #[test]
 fn status_shows_loading_instead_of_stale_commit_diff() {
     let mut app = status_app();
-    app.graph_selected = 1;
-    app.current_diff = vec![FileChange { filename: "stale.txt".to_string(), status: FileStatus::Modified }];
-    app.current_diff_identity = None;
+    app.tab.graph_selected = 1;
+    app.tab.current_diff = vec![FileChange { filename: "stale.txt".to_string(), status: FileStatus::Modified }];
+    app.tab.current_diff_identity = None;
 
     let backend = TestBackend::new(48, 10);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -171,9 +172,9 @@ fn status_shows_loading_instead_of_stale_commit_diff() {
 fn status_preserves_known_empty_commit_diff_state() {
     let mut app = status_app();
     let identity = GraphIndexIdentity { index: 1, alias: 1, oid: Oid::zero() };
-    app.graph_selected = 1;
-    app.graph.index_rows.insert(1, graph_row(1, 1, identity.oid));
-    app.current_diff_identity = Some(identity);
+    app.tab.graph_selected = 1;
+    app.tab.graph.index_rows.insert(1, graph_row(1, 1, identity.oid));
+    app.tab.current_diff_identity = Some(identity);
 
     let backend = TestBackend::new(48, 10);
     let mut terminal = Terminal::new(backend).unwrap();
@@ -187,10 +188,10 @@ fn status_preserves_known_empty_commit_diff_state() {
 #[test]
 fn staged_status_short_page_stripes_blank_tail_rows() {
     let mut app = status_app();
-    app.graph_selected = 0;
-    app.is_uncommitted_loaded = true;
-    app.focus = Focus::Viewport;
-    app.uncommitted.staged = FileChanges { modified: vec!["staged.txt".to_string()], ..Default::default() };
+    app.tab.graph_selected = 0;
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.focus = Focus::Viewport;
+    app.tab.uncommitted.staged = FileChanges { modified: vec!["staged.txt".to_string()], ..Default::default() };
     let zebra = app.theme.background_or_default(app.theme.COLOR_GREY_900);
 
     let backend = TestBackend::new(48, 10);
@@ -206,10 +207,10 @@ fn staged_status_short_page_stripes_blank_tail_rows() {
 #[test]
 fn unstaged_status_short_page_stripes_blank_tail_rows() {
     let mut app = status_app();
-    app.graph_selected = 0;
-    app.is_uncommitted_loaded = true;
-    app.focus = Focus::Viewport;
-    app.uncommitted.unstaged = FileChanges { modified: vec!["unstaged.txt".to_string()], ..Default::default() };
+    app.tab.graph_selected = 0;
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.focus = Focus::Viewport;
+    app.tab.uncommitted.unstaged = FileChanges { modified: vec!["unstaged.txt".to_string()], ..Default::default() };
     let zebra = app.theme.background_or_default(app.theme.COLOR_GREY_900);
 
     let backend = TestBackend::new(48, 10);
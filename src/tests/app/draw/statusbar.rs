@@ -1,4 +1,5 @@
 use super::*;
+use crate::app::app::RepoTab;
 use crate::{app::state::layout::Layout, core::submodules::SubmoduleStackEntry, helpers::symbols::submodule::DEFAULT as SYM_SUBMODULE};
 use git2::{Repository, Signature};
 use ratatui::{Terminal, backend::TestBackend, layout::Rect};
@@ -39,10 +40,13 @@ fn statusbar_renders_submodule_stack_before_branch() {
     let (path, repo) = temp_repo("submodule-stack");
     let mut app = App {
         layout: Layout { statusbar_left: Rect::new(0, 0, 180, 1), statusbar_right: Rect::new(180, 0, 20, 1), ..Default::default() },
-        submodule_stack: vec![
-            SubmoduleStackEntry::new(path.clone(), PathBuf::from("deps/child"), "deps/child".into()),
-            SubmoduleStackEntry::new(path.join("deps/child"), PathBuf::from("vendor/grandchild"), "vendor/grandchild".into()),
-        ],
+        tab: RepoTab {
+            submodule_stack: vec![
+                SubmoduleStackEntry::new(path.clone(), PathBuf::from("deps/child"), "deps/child".into()),
+                SubmoduleStackEntry::new(path.join("deps/child"), PathBuf::from("vendor/grandchild"), "vendor/grandchild".into()),
+            ],
+            ..Default::default()
+        },
         ..Default::default()
     };
     let backend = TestBackend::new(200, 1);
@@ -1,6 +1,6 @@
 use crate::{
     app::{
-        app::{App, Focus},
+        app::{App, Focus, RepoTab},
         state::layout::Layout,
     },
     core::submodules::{SubmoduleEntry, Submodules},
@@ -33,9 +33,13 @@ fn submodule_entry(name: &str) -> SubmoduleEntry {
 }
 
 fn submodules_app(entries: Vec<SubmoduleEntry>) -> App {
-    let mut app = App { focus: Focus::Viewport, layout: Layout { submodules: Rect::new(0, 0, 40, 5), submodules_scrollbar: Rect::new(39, 0, 1, 5), ..Default::default() }, ..Default::default() };
+    let mut app = App {
+        layout: Layout { submodules: Rect::new(0, 0, 40, 5), submodules_scrollbar: Rect::new(39, 0, 1, 5), ..Default::default() },
+        tab: RepoTab { focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
     app.layout_config.is_zen = false;
-    app.submodules = Submodules::from_entries(entries);
+    app.tab.submodules = Submodules::from_entries(entries);
     app
 }
 
@@ -1,4 +1,5 @@
 use super::*;
+use crate::app::app::RepoTab;
 use ratatui::{Terminal, backend::TestBackend, layout::Rect};
 
 fn rendered_symbols(terminal: &Terminal<TestBackend>) -> String {
@@ -6,7 +7,10 @@ fn rendered_symbols(terminal: &Terminal<TestBackend>) -> String {
 }
 
 fn title_app(viewport: Viewport) -> App {
-    let mut app = App { path: Some("/tmp/guitar-title-repo".to_string()), file_name: Some("src/lib.rs".to_string()), viewport, focus: Focus::StatusTop, ..Default::default() };
+    let mut app = App {
+        tab: RepoTab { path: Some("/tmp/guitar-title-repo".to_string()), file_name: Some("src/lib.rs".to_string()), viewport, focus: Focus::StatusTop, ..Default::default() },
+        ..Default::default()
+    };
     app.layout.title_left = Rect::new(0, 0, 100, 1);
     app.layout.title_right = Rect::new(100, 0, 20, 1);
     app
@@ -40,7 +44,7 @@ fn title_shows_repo_file_path_inside_viewer() {
 #[test]
 fn title_names_settings_viewport() {
     let mut app = title_app(Viewport::Settings);
-    app.focus = Focus::Viewport;
+    app.tab.focus = Focus::Viewport;
     let backend = TestBackend::new(120, 1);
     let mut terminal = Terminal::new(backend).unwrap();
 
@@ -53,7 +57,7 @@ fn title_names_settings_viewport() {
 #[test]
 fn title_names_viewer_viewport() {
     let mut app = title_app(Viewport::Viewer);
-    app.focus = Focus::Viewport;
+    app.tab.focus = Focus::Viewport;
     let backend = TestBackend::new(120, 1);
     let mut terminal = Terminal::new(backend).unwrap();
 
@@ -62,3 +66,20 @@ fn title_names_viewer_viewport() {
     let rendered = rendered_symbols(&terminal);
     assert!(rendered.contains("viewer"));
 }
+
+#[test]
+fn title_lists_tabs_once_a_second_one_is_open() {
+    let mut app = title_app(Viewport::Graph);
+    let backend = TestBackend::new(120, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal.draw(|frame| app.draw_title(frame)).unwrap();
+    assert!(!rendered_symbols(&terminal).contains("1:guitar-title-repo"));
+
+    app.tabs.push(Default::default());
+    terminal.draw(|frame| app.draw_title(frame)).unwrap();
+
+    let rendered = rendered_symbols(&terminal);
+    assert!(rendered.contains("1:guitar-title-repo"), "{rendered}");
+    assert!(rendered.contains("2:new tab"), "{rendered}");
+}
@@ -1,8 +1,9 @@
 use super::*;
 use crate::{
     app::state::{defaults::ViewerMode, layout::Layout},
+    core::diff_service::DiffEvent,
     git::queries::helpers::FileChanges,
-    helpers::symbols::status as status_symbol,
+    helpers::{localisation::common, symbols::status as status_symbol},
 };
 use git2::{Repository, Signature};
 use ratatui::{Terminal, backend::TestBackend, buffer::Buffer, layout::Rect};
@@ -72,9 +73,9 @@ fn viewer_app() -> App {
         ..Default::default()
     };
     app.layout_config.is_zen = false;
-    app.focus = Focus::StatusTop;
-    app.viewport = Viewport::Viewer;
-    app.file_name = Some("new.txt".to_string());
+    app.tab.focus = Focus::StatusTop;
+    app.tab.viewport = Viewport::Viewer;
+    app.tab.file_name = Some("new.txt".to_string());
     app
 }
 
@@ -89,23 +90,24 @@ fn find_text(buffer: &Buffer, needle: &str) -> Option<(u16, u16)> {
 #[test]
 fn selected_status_file_helpers_match_rendered_group_order() {
     let mut app = App::default();
-    app.uncommitted.conflicts = vec!["conflict.txt".to_string()];
-    app.uncommitted.staged = FileChanges { modified: vec!["staged-modified.txt".to_string()], added: vec!["staged-added.txt".to_string()], deleted: vec!["staged-deleted.txt".to_string()] };
-    app.uncommitted.unstaged = FileChanges { modified: vec!["unstaged-modified.txt".to_string()], added: vec!["unstaged-added.txt".to_string()], deleted: vec!["unstaged-deleted.txt".to_string()] };
+    app.tab.uncommitted.conflicts = vec!["conflict.txt".to_string()];
+    app.tab.uncommitted.staged = FileChanges { modified: vec!["staged-modified.txt".to_string()], added: vec!["staged-added.txt".to_string()], deleted: vec!["staged-deleted.txt".to_string()] };
+    app.tab.uncommitted.unstaged =
+        FileChanges { modified: vec!["unstaged-modified.txt".to_string()], added: vec!["unstaged-added.txt".to_string()], deleted: vec!["unstaged-deleted.txt".to_string()] };
 
-    app.status_top_selected = 0;
+    app.tab.status_top_selected = 0;
     assert!(app.selected_staged_status_file_is_conflict());
     assert_eq!(app.selected_staged_status_file_name().as_deref(), Some("conflict.txt"));
 
-    app.status_top_selected = 2;
+    app.tab.status_top_selected = 2;
     assert!(!app.selected_staged_status_file_is_conflict());
     assert_eq!(app.selected_staged_status_file_name().as_deref(), Some("staged-added.txt"));
 
-    app.status_bottom_selected = 0;
+    app.tab.status_bottom_selected = 0;
     assert!(app.selected_unstaged_status_file_is_conflict());
     assert_eq!(app.selected_unstaged_status_file_name().as_deref(), Some("conflict.txt"));
 
-    app.status_bottom_selected = 3;
+    app.tab.status_bottom_selected = 3;
     assert!(!app.selected_unstaged_status_file_is_conflict());
     assert_eq!(app.selected_unstaged_status_file_name().as_deref(), Some("unstaged-deleted.txt"));
 }
@@ -134,8 +136,8 @@ fn unstaged_added_file_viewer_renders_added_lines() {
     assert_eq!(buffer[(alpha_x, plus_y)].bg, added_bg);
 
     app.viewer_mode = ViewerMode::Split;
-    app.viewer_selected = 0;
-    app.viewer_scroll.set(0);
+    app.tab.viewer_selected = 0;
+    app.tab.viewer_scroll.set(0);
 
     let mut terminal = Terminal::new(TestBackend::new(80, 6)).unwrap();
     terminal.draw(|frame| app.draw_viewer(frame)).unwrap();
@@ -150,3 +152,99 @@ fn unstaged_added_file_viewer_renders_added_lines() {
     assert_eq!(buffer[(split_plus_x, split_plus_y)].fg, app.theme.COLOR_GREEN);
     assert_eq!(buffer[(split_plus_x, split_plus_y)].bg, added_bg);
 }
+
+#[test]
+fn viewer_waits_for_the_diff_service_and_ignores_stale_files() {
+    let (dir, repo) = temp_repo("diff-service");
+    write(&dir.path, "tracked.txt", "base\n");
+    commit(&repo, "tracked.txt", "initial");
+    write(&dir.path, "new.txt", "alpha\nbeta\n");
+
+    let (command_tx, command_rx) = std::sync::mpsc::channel();
+    let (event_tx, event_rx) = std::sync::mpsc::channel();
+    let mut app = viewer_app();
+    app.tab.diff_tx = Some(command_tx);
+    app.tab.diff_rx = Some(event_rx);
+
+    // Moving to another file before the first answer arrives supersedes it.
+    app.update_viewer(Oid::zero(), &repo);
+    app.update_viewer(Oid::zero(), &repo);
+    let requests: Vec<_> = command_rx.try_iter().collect();
+    let [DiffCommand::QueryFile { request_id: stale, .. }, DiffCommand::QueryFile { request_id: current, .. }] = requests[..] else {
+        panic!("expected two file queries, got {requests:?}");
+    };
+    assert!(app.tab.viewer_lines.is_empty());
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 6)).unwrap();
+    terminal.draw(|frame| app.draw_viewer(frame)).unwrap();
+    assert!(find_text(terminal.backend().buffer(), common::LOADING()).is_some());
+
+    let generation = app.tab.graph.generation;
+    let file =
+        |request_id| DiffEvent::File { generation, request_id, oid: Oid::zero(), filename: "new.txt".to_string(), diff: load_file_diff(&repo, Oid::zero(), "new.txt", false, None, &mut || false) };
+    event_tx.send(file(stale)).unwrap();
+    app.sync(&repo);
+    assert!(app.tab.viewer_lines.is_empty());
+    assert_eq!(app.tab.graph.requested_viewer, Some(current));
+
+    event_tx.send(file(current)).unwrap();
+    app.sync(&repo);
+    assert_eq!(app.tab.graph.requested_viewer, None);
+    assert!(!app.tab.viewer_lines.is_empty());
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 6)).unwrap();
+    terminal.draw(|frame| app.draw_viewer(frame)).unwrap();
+    assert!(find_text(terminal.backend().buffer(), "alpha").is_some());
+}
+
+#[test]
+fn truncated_files_show_a_notice_until_the_full_diff_is_loaded() {
+    let (dir, repo) = temp_repo("truncated");
+    write(&dir.path, "tracked.txt", "base\n");
+    commit(&repo, "tracked.txt", "initial");
+    write(&dir.path, "new.txt", &"line\n".repeat(400));
+
+    let mut app = viewer_app();
+    app.layout_config.viewer_file_size_limit = 1;
+    app.update_viewer(Oid::zero(), &repo);
+    assert!(matches!(app.tab.viewer_diff, Some(FileDiff::Truncated { size: 2000, limit: 1024, .. })));
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 6)).unwrap();
+    terminal.draw(|frame| app.draw_viewer(frame)).unwrap();
+    let buffer = terminal.backend().buffer();
+    let (notice_x, notice_y) = find_text(buffer, viewer::TRUNCATED()).unwrap();
+    assert_eq!(buffer[(notice_x, notice_y)].fg, app.theme.COLOR_ORANGE);
+    assert!(find_text(buffer, "1.0 KiB / 2.0 KiB").is_some());
+    // The kept part of the file still shows as a diff.
+    assert!(find_text(buffer, "1  + line").is_some());
+
+    app.tab.repo = Some(std::rc::Rc::new(repo));
+    app.dispatch_command(&Command::LoadFullDiff);
+    let Some(FileDiff::Patch { original_lines, .. }) = &app.tab.viewer_diff else {
+        panic!("expected the whole file");
+    };
+    assert_eq!(original_lines.len(), 400);
+    assert!(app.tab.viewer_lines.len() >= 400);
+}
+
+#[test]
+fn binary_files_render_sizes_and_a_hex_preview() {
+    let (dir, repo) = temp_repo("binary");
+    write(&dir.path, "tracked.txt", "base\n");
+    commit(&repo, "tracked.txt", "initial");
+    fs::write(dir.path.join("new.txt"), b"GIF89a\0\x01").unwrap();
+
+    let mut app = viewer_app();
+    app.layout.graph = Rect::new(0, 0, 80, 12);
+    app.update_viewer(Oid::zero(), &repo);
+    assert!(matches!(app.tab.viewer_diff, Some(FileDiff::Binary { old_size: None, new_size: Some(8), .. })));
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+    terminal.draw(|frame| app.draw_viewer(frame)).unwrap();
+    let buffer = terminal.backend().buffer();
+    assert!(find_text(buffer, viewer::BINARY_FILE()).is_some());
+    assert!(find_text(buffer, &format!("{} {}", viewer::BEFORE(), viewer::MISSING())).is_some());
+    assert!(find_text(buffer, &format!("{} 8 B", viewer::AFTER())).is_some());
+    assert!(find_text(buffer, "00000000  47 49 46 38 39 61 00 01").is_some());
+    assert!(find_text(buffer, "GIF89a..").is_some());
+}
@@ -0,0 +1,34 @@
+use super::*;
+use crate::helpers::keymap::{Command, InputMode};
+use indexmap::IndexMap;
+use ratatui::{
+    Terminal,
+    backend::TestBackend,
+    crossterm::event::{KeyCode, KeyModifiers},
+};
+
+fn rendered_symbols(terminal: &Terminal<TestBackend>) -> String {
+    terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect::<String>()
+}
+
+fn key(ch: char) -> KeyBinding {
+    KeyBinding::new(KeyCode::Char(ch), KeyModifiers::NONE)
+}
+
+#[test]
+fn which_key_lists_commands_and_counts_longer_sequences() {
+    let normal = IndexMap::from([
+        (KeyBinding::sequence(&[key(' '), key('f'), key('f')]).unwrap(), Command::FindFile),
+        (KeyBinding::sequence(&[key(' '), key('f'), key('c')]).unwrap(), Command::Find),
+        (KeyBinding::sequence(&[key(' '), key('p')]).unwrap(), Command::CommandPalette),
+    ]);
+    let mut app = App { keymaps: IndexMap::from([(InputMode::Normal, normal)]), pending_keys: vec![key(' ')], ..Default::default() };
+
+    assert_eq!(app.which_key_rows(), vec![("f".to_string(), "+2".to_string()), ("p".to_string(), "Command palette".to_string())]);
+
+    let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+    terminal.draw(|frame| app.draw_which_key(frame)).unwrap();
+    let rendered = rendered_symbols(&terminal);
+    assert!(rendered.contains("Command palette"));
+    assert!(rendered.contains("+2"));
+}
@@ -1,6 +1,6 @@
 use crate::{
     app::{
-        app::{App, Focus},
+        app::{App, Focus, RepoTab},
         state::layout::Layout,
     },
     core::worktrees::{WorktreeEntry, WorktreeKind, Worktrees},
@@ -26,9 +26,13 @@ fn worktree_entry(name: &str) -> WorktreeEntry {
 }
 
 fn worktrees_app(entries: Vec<WorktreeEntry>) -> App {
-    let mut app = App { focus: Focus::Viewport, layout: Layout { worktrees: Rect::new(0, 0, 40, 5), worktrees_scrollbar: Rect::new(39, 0, 1, 5), ..Default::default() }, ..Default::default() };
+    let mut app = App {
+        layout: Layout { worktrees: Rect::new(0, 0, 40, 5), worktrees_scrollbar: Rect::new(39, 0, 1, 5), ..Default::default() },
+        tab: RepoTab { focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
     app.layout_config.is_zen = false;
-    app.worktrees = Worktrees::from_entries(entries);
+    app.tab.worktrees = Worktrees::from_entries(entries);
     app
 }
 
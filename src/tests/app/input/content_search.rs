@@ -0,0 +1,125 @@
+use super::*;
+use crate::app::app::RepoTab;
+use crate::app::state::{defaults::ViewerMode, layout::Layout};
+use git2::{Repository, Signature};
+use ratatui::layout::Rect;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-content-search-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    (path.clone(), Repository::init(&path).unwrap())
+}
+
+fn commit(repo: &Repository, file: &str, contents: &str) {
+    fs::write(repo.workdir().unwrap().join(file), contents).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, file, &tree, &[]).unwrap();
+}
+
+fn search_app(path: &Path, repo: Repository) -> App {
+    App {
+        layout: Layout { graph: Rect::new(0, 0, 80, 12), ..Default::default() },
+        tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() },
+        ..Default::default()
+    }
+}
+
+fn type_query(app: &mut App, query: &str) {
+    for ch in query.chars() {
+        app.handle_content_search_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+}
+
+fn wait_for_search(app: &mut App) {
+    let started = Instant::now();
+    while (app.modal_content_search_task.is_some() || app.modal_content_search_due.is_some()) && started.elapsed() < Duration::from_secs(5) {
+        app.poll_content_search();
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn choosing_a_match_opens_the_viewer_at_its_line() {
+    let (path, repo) = temp_repo("open");
+    let lines: Vec<String> = (1..=20).map(|idx| format!("line {idx}")).collect();
+    commit(&repo, "a.txt", &(lines.join("\n") + "\n"));
+    fs::write(path.join("a.txt"), lines.join("\n").replace("line 2\n", "changed\n") + "\n").unwrap();
+    let mut app = search_app(&path, repo);
+    app.viewer_mode = ViewerMode::Hunks;
+
+    app.on_search_contents();
+    assert_eq!((app.tab.focus, app.modal_content_search_scope), (Focus::ModalContentSearch, GrepScope::Workdir));
+    type_query(&mut app, "line 15");
+    wait_for_search(&mut app);
+    assert_eq!(app.modal_content_search_results.iter().map(|found| (found.path.as_str(), found.line)).collect::<Vec<_>>(), vec![("a.txt", 15)]);
+
+    app.handle_content_search_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!((app.tab.viewport, app.tab.focus), (Viewport::Viewer, Focus::Viewport));
+    assert_eq!(app.tab.file_name.as_deref(), Some("a.txt"));
+    // The removed "line 2" row sits above, so new line 15 is the sixteenth row.
+    assert!(app.viewer_mode == ViewerMode::Full);
+    assert_eq!(app.tab.viewer_selected, 15);
+    assert!(app.modal_content_search_results.is_empty());
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn tab_switches_to_regex_and_reports_invalid_patterns() {
+    let (path, repo) = temp_repo("regex");
+    commit(&repo, "a.txt", "alpha\nbeta\n");
+    let mut app = search_app(&path, repo);
+
+    app.on_search_contents();
+    type_query(&mut app, "(al");
+    wait_for_search(&mut app);
+    assert!(app.modal_content_search_results.is_empty());
+    assert_eq!(app.modal_content_search_error, None);
+
+    app.handle_content_search_event(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+    assert!(app.modal_content_search_is_regex);
+    assert!(app.modal_content_search_error.is_some());
+    assert!(app.modal_content_search_task.is_none());
+
+    app.handle_content_search_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+    app.handle_content_search_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+    type_query(&mut app, "a|b)");
+    wait_for_search(&mut app);
+    assert_eq!(app.modal_content_search_results.iter().map(|found| found.line).collect::<Vec<_>>(), vec![1, 2]);
+
+    app.handle_content_search_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(app.tab.focus, Focus::Branches);
+    assert!(!app.modal_content_search_is_regex);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn typing_waits_for_a_pause_and_cursor_moves_keep_the_results() {
+    let (path, repo) = temp_repo("debounce");
+    commit(&repo, "a.txt", "alpha\nbeta\n");
+    let mut app = search_app(&path, repo);
+
+    app.on_search_contents();
+    type_query(&mut app, "alp");
+    assert!(app.modal_content_search_task.is_none());
+    assert!(app.modal_content_search_due.is_some());
+    wait_for_search(&mut app);
+    assert_eq!(app.modal_content_search_results.len(), 1);
+
+    app.handle_content_search_event(KeyEvent::new(KeyCode::Left, KeyModifiers::NONE));
+    app.handle_content_search_event(KeyEvent::new(KeyCode::Home, KeyModifiers::NONE));
+    assert_eq!(app.modal_content_search_due, None);
+    assert_eq!(app.modal_content_search_results.len(), 1);
+    let _ = fs::remove_dir_all(path);
+}
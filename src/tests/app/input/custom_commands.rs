@@ -0,0 +1,133 @@
+use super::*;
+use crate::app::app::RepoTab;
+use crate::helpers::{custom_commands::CustomCommandOutput, keymap::Command, localisation::palette};
+use git2::Signature;
+use std::{
+    fs,
+    path::Path,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-custom-commands-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    fs::write(path.join("README.md"), "hello\n").unwrap();
+    {
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README.md")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = Signature::now("Test User", "test@example.com").unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "initial", &tree, &[]).unwrap();
+    }
+    repo.remote("upstream", "https://example.com/upstream.git").unwrap();
+    repo.remote("origin", "https://example.com/origin.git").unwrap();
+    (path, repo)
+}
+
+fn command(name: &str, template: &str, context: CustomCommandContext) -> CustomCommand {
+    CustomCommand { name: name.to_string(), command: template.to_string(), context, output: CustomCommandOutput::Modal }
+}
+
+fn app_with(repo: Repository, commands: Vec<CustomCommand>) -> App {
+    App { custom_commands: commands, tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() }
+}
+
+#[test]
+fn values_use_the_head_branch_and_origin_outside_the_branches_pane() {
+    let (path, repo) = temp_repo("values");
+    let head = repo.head().unwrap().shorthand().unwrap().to_string();
+    let app = app_with(repo, Vec::new());
+    let repo = app.tab.repo.clone().unwrap();
+
+    let values = app.custom_command_values(&repo);
+    assert_eq!(values.sha, None);
+    assert_eq!(values.branch, Some(head));
+    assert_eq!(values.path, None);
+    assert_eq!(values.remote_url.as_deref(), Some("https://example.com/origin.git"));
+    assert_eq!(values.repo.as_deref().map(Path::new), Some(repo.workdir().unwrap().components().as_path()));
+
+    let _ = fs::remove_dir_all(path);
+}
+
+#[cfg(unix)]
+#[test]
+fn modal_commands_run_in_the_repository_and_capture_output() {
+    let (path, repo) = temp_repo("modal");
+    let head = repo.head().unwrap().shorthand().unwrap().to_string();
+    let mut app = app_with(repo, vec![command("Show", "echo {branch} && ls", CustomCommandContext::Any)]);
+
+    app.dispatch_command(&Command::Custom("Show".to_string()));
+    assert_eq!(app.tab.focus, Focus::ModalCustomCommand);
+
+    let task = app.custom_command_task.as_mut().unwrap();
+    assert!(task.handle.take().unwrap().join().unwrap().is_ok());
+    assert_eq!(*task.log.lock().unwrap(), vec![head, "README.md".to_string()]);
+
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn commands_outside_their_context_do_not_run_and_are_greyed_in_the_palette() {
+    let (path, repo) = temp_repo("context");
+    let mut app = app_with(repo, vec![command("Branch only", "true", CustomCommandContext::Branches)]);
+
+    app.on_custom_command("Branch only");
+    assert!(app.custom_command_task.is_none());
+    assert_eq!(app.tab.focus, Focus::Viewport);
+
+    app.on_command_palette();
+    assert_eq!(app.command_disabled_reason(&Command::Custom("Branch only".to_string())), Some(palette::NOT_IN_CONTEXT()));
+    app.modal_palette_return_focus = Focus::Branches;
+    assert_eq!(app.command_disabled_reason(&Command::Custom("Branch only".to_string())), None);
+
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn missing_placeholder_values_and_unknown_names_show_an_error() {
+    let (path, repo) = temp_repo("errors");
+    let mut app = app_with(repo, vec![command("Review", "open {sha}", CustomCommandContext::Any)]);
+
+    app.on_custom_command("Review");
+    assert_eq!(app.tab.focus, Focus::ModalError);
+    assert!(app.custom_command_task.is_none());
+
+    app.tab.focus = Focus::Viewport;
+    app.on_custom_command("Missing");
+    assert_eq!(app.tab.focus, Focus::ModalError);
+
+    let _ = fs::remove_dir_all(path);
+}
+
+#[cfg(unix)]
+#[test]
+fn background_failures_go_to_the_status_bar_and_file_edits_only_reread_the_status() {
+    let (path, repo) = temp_repo("background");
+    let background = |name: &str, template: &str| CustomCommand { output: CustomCommandOutput::Background, ..command(name, template, CustomCommandContext::Any) };
+    let mut app = app_with(repo, vec![background("Fail", "echo broken pipe; exit 3"), background("Touch", "echo changed > README.md")]);
+    app.tab.path = Some(path.display().to_string());
+    let generation = app.tab.graph.generation;
+    let wait = |app: &mut App| {
+        while !app.background_custom_commands.is_empty() {
+            app.poll_custom_commands();
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+    };
+
+    app.on_custom_command("Fail");
+    wait(&mut app);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    let (notice, _) = app.status_notice.clone().unwrap();
+    assert!(notice.contains("Fail") && notice.contains("broken pipe"));
+
+    app.on_custom_command("Touch");
+    wait(&mut app);
+    assert_eq!(app.tab.graph.generation, generation);
+    assert_eq!(app.tab.uncommitted.unstaged.modified, vec!["README.md".to_string()]);
+
+    let _ = fs::remove_dir_all(path);
+}
@@ -1,7 +1,7 @@
 use super::*;
 use crate::{
     app::{
-        app::{ContextMenuAction, SettingsSelection, SettingsSelectionKind, SettingsTab},
+        app::{ContextMenuAction, OperationKind, PendingOperationAction, RepoTab, SettingsSelection, SettingsSelectionKind, SettingsTab},
         state::defaults::ViewerMode,
         state::layout::Layout,
     },
@@ -59,10 +59,11 @@ fn right_down(column: u16, row: u16) -> MouseEvent {
 }
 
 fn graph_app() -> App {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.app = Rect::new(0, 0, 30, 8);
     app.layout.graph = Rect::new(0, 0, 30, 8);
-    app.graph.total = 20;
+    app.tab.graph.total = 20;
     app
 }
 
@@ -70,7 +71,7 @@ fn context_menu_app() -> App {
     let mut app = graph_app();
     app.layout.app = Rect::new(0, 0, 80, 24);
     app.layout.graph = Rect::new(0, 0, 80, 24);
-    app.graph.total = 0;
+    app.tab.graph.total = 0;
     app
 }
 
@@ -92,7 +93,7 @@ fn right_click_opens_context_menu_with_first_enabled_item() {
 #[test]
 fn right_click_does_not_open_context_menu_over_modal_focus() {
     let mut app = context_menu_app();
-    app.focus = Focus::ModalCommit;
+    app.tab.focus = Focus::ModalCommit;
 
     app.handle_mouse_event(right_down(5, 3));
 
@@ -102,85 +103,85 @@ fn right_click_does_not_open_context_menu_over_modal_focus() {
 #[test]
 fn modal_esc_corner_click_closes_dismissible_modal_like_escape() {
     let mut app = graph_app();
-    app.focus = Focus::ModalCommit;
+    app.tab.focus = Focus::ModalCommit;
     app.modal_area = Some(Rect::new(10, 4, 40, 12));
     app.modal_input.set_value("draft message");
 
     app.handle_mouse_event(left_down(45, 4));
 
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert!(app.modal_input.value().is_empty());
 }
 
 #[test]
 fn modal_esc_corner_click_closes_error_modal_like_escape() {
     let mut app = graph_app();
-    app.focus = Focus::ModalError;
+    app.tab.focus = Focus::ModalError;
     app.modal_area = Some(Rect::new(10, 4, 40, 12));
     app.modal_error_return_focus = Focus::Branches;
     app.modal_error_message = "boom".into();
 
     app.handle_mouse_event(left_down(45, 4));
 
-    assert_eq!(app.focus, Focus::Branches);
+    assert_eq!(app.tab.focus, Focus::Branches);
     assert!(app.modal_error_message.is_empty());
 }
 
 #[test]
 fn modal_esc_corner_click_does_not_dismiss_progress_modal() {
     let mut app = graph_app();
-    app.focus = Focus::ModalOperationProgress;
+    app.tab.focus = Focus::ModalOperationProgress;
     app.modal_area = Some(Rect::new(10, 4, 40, 12));
 
     app.handle_mouse_event(left_down(45, 4));
 
-    assert_eq!(app.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
 }
 
 #[test]
 fn modal_left_click_outside_esc_corner_is_consumed() {
     let mut app = graph_app();
-    app.focus = Focus::ModalCommit;
+    app.tab.focus = Focus::ModalCommit;
     app.modal_area = Some(Rect::new(10, 4, 40, 12));
-    app.graph_selected = 4;
+    app.tab.graph_selected = 4;
 
     app.handle_mouse_event(left_down(1, 1));
 
-    assert_eq!(app.focus, Focus::ModalCommit);
-    assert_eq!(app.graph_selected, 4);
+    assert_eq!(app.tab.focus, Focus::ModalCommit);
+    assert_eq!(app.tab.graph_selected, 4);
 }
 
 #[test]
 fn left_click_outside_context_menu_dismisses_without_selecting_underlying_row() {
     let mut app = context_menu_app();
-    app.graph_selected = 4;
+    app.tab.graph_selected = 4;
 
     app.handle_mouse_event(right_down(20, 10));
     app.handle_mouse_event(left_down(1, 1));
 
     assert!(app.context_menu.is_none());
-    assert_eq!(app.graph_selected, 4);
+    assert_eq!(app.tab.graph_selected, 4);
 }
 
 #[test]
 fn left_click_context_menu_settings_opens_settings_when_repo_exists() {
     let (_path, repo) = temp_repo("context-menu-settings");
     let mut app = context_menu_app();
-    app.repo = Some(Rc::new(repo));
+    app.tab.repo = Some(Rc::new(repo));
 
     app.handle_mouse_event(right_down(5, 5));
     app.handle_mouse_event(left_down(6, 11));
 
     assert!(app.context_menu.is_none());
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
 }
 
 #[test]
 fn repo_context_menu_includes_reload_global_action() {
     let (_path, repo) = temp_repo("context-menu-reload");
     let mut app = context_menu_app();
-    app.repo = Some(Rc::new(repo));
+    app.tab.repo = Some(Rc::new(repo));
 
     app.handle_mouse_event(right_down(5, 5));
 
@@ -206,7 +207,7 @@ fn left_click_context_menu_disabled_settings_keeps_menu_open() {
     app.handle_mouse_event(left_down(6, 7));
 
     assert!(app.context_menu.is_some());
-    assert_eq!(app.viewport, Viewport::Graph);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
 }
 
 #[test]
@@ -228,13 +229,13 @@ fn context_menu_keyboard_navigation_and_enter_activate_selected_item() {
 #[test]
 fn right_click_selects_graph_row_and_opens_contextual_actions() {
     let mut app = graph_app();
-    app.graph_scroll.set(2);
+    app.tab.graph_scroll.set(2);
 
     app.handle_mouse_event(right_down(1, 3));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.graph_selected, 5);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.graph_selected, 5);
     let labels = context_menu_labels(&app);
     assert!(labels.iter().any(|label| label == "Show details"), "{labels:?}");
     assert!(labels.iter().any(|label| label == "Create branch"), "{labels:?}");
@@ -244,8 +245,8 @@ fn right_click_selects_graph_row_and_opens_contextual_actions() {
 fn graph_context_menu_includes_fetch_and_push_when_repo_is_loaded() {
     let (_path, repo) = temp_repo("graph-network-menu");
     let mut app = graph_app();
-    app.repo = Some(Rc::new(repo));
-    app.graph_scroll.set(2);
+    app.tab.repo = Some(Rc::new(repo));
+    app.tab.graph_scroll.set(2);
 
     app.handle_mouse_event(right_down(1, 3));
 
@@ -258,8 +259,8 @@ fn graph_context_menu_includes_fetch_and_push_when_repo_is_loaded() {
 fn graph_context_menu_groups_regular_action_mode_and_navigation_sections() {
     let (_path, repo) = temp_repo("graph-section-menu");
     let mut app = graph_app();
-    app.repo = Some(Rc::new(repo));
-    app.graph_scroll.set(2);
+    app.tab.repo = Some(Rc::new(repo));
+    app.tab.graph_scroll.set(2);
 
     app.handle_mouse_event(right_down(1, 3));
 
@@ -281,11 +282,11 @@ fn graph_context_menu_groups_regular_action_mode_and_navigation_sections() {
 fn uncommitted_graph_context_menu_includes_fetch_and_push_when_repo_is_loaded() {
     let (_path, repo) = temp_repo("uncommitted-network-menu");
     let mut app = graph_app();
-    app.repo = Some(Rc::new(repo));
+    app.tab.repo = Some(Rc::new(repo));
 
     app.handle_mouse_event(right_down(1, 0));
 
-    assert_eq!(app.graph_selected, 0);
+    assert_eq!(app.tab.graph_selected, 0);
     let labels = context_menu_labels(&app);
     assert!(labels.iter().any(|label| label == "Fetch"), "{labels:?}");
     assert!(labels.iter().any(|label| label == "Push"), "{labels:?}");
@@ -296,12 +297,12 @@ fn right_click_selects_left_pane_row_and_opens_contextual_actions() {
     let mut app = graph_app();
     app.layout_config.is_branches = true;
     app.layout.branches = Rect::new(0, 0, 20, 6);
-    app.branches.sorted = (0..10).map(|idx| (idx, format!("branch-{idx}"))).collect();
-    app.branches_scroll.set(2);
+    app.tab.branches.sorted = (0..10).map(|idx| (idx, format!("branch-{idx}"))).collect();
+    app.tab.branches_scroll.set(2);
 
     app.handle_mouse_event(right_down(1, 1));
 
-    assert_eq!((app.focus, app.branches_selected), (Focus::Branches, 3));
+    assert_eq!((app.tab.focus, app.tab.branches_selected), (Focus::Branches, 3));
     let labels = context_menu_labels(&app);
     assert!(labels.iter().any(|label| label == "Open commit"), "{labels:?}");
     assert!(labels.iter().any(|label| label == "Checkout branch"), "{labels:?}");
@@ -313,14 +314,14 @@ fn right_click_selects_status_row_and_opens_contextual_actions() {
     let mut app = graph_app();
     app.layout_config.is_status = true;
     app.layout.status_top = Rect::new(0, 0, 30, 6);
-    app.is_uncommitted_loaded = true;
-    app.uncommitted.is_staged = true;
-    app.uncommitted.staged.modified = vec!["a".into(), "b".into(), "c".into(), "d".into()];
-    app.status_top_scroll.set(1);
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.uncommitted.is_staged = true;
+    app.tab.uncommitted.staged.modified = vec!["a".into(), "b".into(), "c".into(), "d".into()];
+    app.tab.status_top_scroll.set(1);
 
     app.handle_mouse_event(right_down(1, 1));
 
-    assert_eq!((app.focus, app.status_top_selected), (Focus::StatusTop, 2));
+    assert_eq!((app.tab.focus, app.tab.status_top_selected), (Focus::StatusTop, 2));
     let labels = context_menu_labels(&app);
     assert!(labels.iter().any(|label| label == "Open file"), "{labels:?}");
     assert!(labels.iter().any(|label| label == "Unstage file"), "{labels:?}");
@@ -329,7 +330,8 @@ fn right_click_selects_status_row_and_opens_contextual_actions() {
 
 #[test]
 fn right_click_selects_splash_recent_repo_and_opens_contextual_actions() {
-    let mut app = App { viewport: Viewport::Splash, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.app = Rect::new(0, 0, 120, 20);
     app.layout.graph = Rect::new(0, 0, 120, 20);
     app.recent = vec!["/repo/a".into(), "/repo/b".into(), "/repo/c".into()];
@@ -351,7 +353,12 @@ fn right_click_selects_splash_recent_repo_and_opens_contextual_actions() {
 #[test]
 fn splash_context_menu_back_returns_to_graph_when_repo_loaded() {
     let (_path, repo) = temp_repo("splash-context-back");
-    let mut app = App { viewport: Viewport::Splash, focus: Focus::Viewport, repo: Some(Rc::new(repo)), layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app = App {
+        layout_config: LayoutConfig::default(),
+        layout: Layout::default(),
+        tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, repo: Some(Rc::new(repo)), ..Default::default() },
+        ..Default::default()
+    };
     app.layout.app = Rect::new(0, 0, 80, 24);
     app.layout.graph = Rect::new(0, 0, 80, 24);
 
@@ -366,15 +373,20 @@ fn splash_context_menu_back_returns_to_graph_when_repo_loaded() {
 
     app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert!(app.context_menu.is_none());
 }
 
 #[test]
 fn right_click_selects_settings_row_and_opens_contextual_actions() {
     let (_path, repo) = temp_repo("settings-context-reload");
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, repo: Some(Rc::new(repo)), layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app = App {
+        layout_config: LayoutConfig::default(),
+        layout: Layout::default(),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, repo: Some(Rc::new(repo)), ..Default::default() },
+        ..Default::default()
+    };
     app.layout.graph = Rect::new(0, 0, 40, 5);
     app.settings_scroll.set(10);
     app.settings_selections = vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::Theme(0) }];
@@ -394,7 +406,8 @@ fn right_click_selects_settings_row_and_opens_contextual_actions() {
 
 #[test]
 fn settings_context_menu_back_returns_to_graph() {
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.app = Rect::new(0, 0, 80, 24);
     app.layout.graph = Rect::new(0, 0, 80, 24);
 
@@ -405,8 +418,8 @@ fn settings_context_menu_back_returns_to_graph() {
 
     app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert!(app.context_menu.is_none());
 }
 
@@ -472,48 +485,48 @@ fn submodule_entry(name: &str, absolute_path: PathBuf) -> SubmoduleEntry {
 #[test]
 fn mouse_click_selects_graph_row() {
     let mut app = graph_app();
-    app.graph_scroll.set(2);
+    app.tab.graph_scroll.set(2);
 
     app.handle_mouse_event(left_down(1, 3));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.graph_selected, 5);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.graph_selected, 5);
 }
 
 #[test]
 fn mouse_click_on_graph_scrollbar_track_jumps_and_focuses() {
     let mut app = graph_app();
     app.layout.graph_scrollbar = Rect::new(29, 0, 1, 8);
-    app.graph.total = 100;
+    app.tab.graph.total = 100;
 
     app.handle_mouse_event(left_down(29, 4));
     app.handle_mouse_event(left_up(29, 4));
 
     let max_scroll = app.graph_commit_count().saturating_sub(app.layout.graph.height as usize);
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert!(app.graph_scroll.get() > 0);
-    assert!(app.graph_scroll.get() < max_scroll);
-    assert!(app.graph_selected >= app.graph_scroll.get());
-    assert!(app.graph_selected < app.graph_scroll.get() + app.layout.graph.height as usize);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert!(app.tab.graph_scroll.get() > 0);
+    assert!(app.tab.graph_scroll.get() < max_scroll);
+    assert!(app.tab.graph_selected >= app.tab.graph_scroll.get());
+    assert!(app.tab.graph_selected < app.tab.graph_scroll.get() + app.layout.graph.height as usize);
 }
 
 #[test]
 fn mouse_drag_on_graph_scrollbar_clamps_to_top_and_bottom() {
     let mut app = graph_app();
     app.layout.graph_scrollbar = Rect::new(29, 0, 1, 8);
-    app.graph.total = 100;
+    app.tab.graph.total = 100;
     let max_scroll = app.graph_commit_count().saturating_sub(app.layout.graph.height as usize);
 
     app.handle_mouse_event(left_down(29, 1));
     app.handle_mouse_event(left_drag(29, 99));
-    assert_eq!(app.graph_scroll.get(), max_scroll);
-    assert!(app.graph_selected >= app.graph_scroll.get());
+    assert_eq!(app.tab.graph_scroll.get(), max_scroll);
+    assert!(app.tab.graph_selected >= app.tab.graph_scroll.get());
 
     app.handle_mouse_event(left_drag(29, 0));
     app.handle_mouse_event(left_up(29, 0));
-    assert_eq!(app.graph_scroll.get(), 0);
-    assert!(app.graph_selected < app.layout.graph.height as usize);
+    assert_eq!(app.tab.graph_scroll.get(), 0);
+    assert!(app.tab.graph_selected < app.layout.graph.height as usize);
 }
 
 #[test]
@@ -522,43 +535,43 @@ fn mouse_drag_on_left_pane_scrollbar_scrolls_and_focuses_pane() {
     app.layout_config.is_branches = true;
     app.layout.branches = Rect::new(0, 0, 20, 8);
     app.layout.branches_scrollbar = Rect::new(0, 0, 20, 8);
-    app.branches.sorted = (0..80).map(|idx| (idx, format!("branch-{idx}"))).collect();
+    app.tab.branches.sorted = (0..80).map(|idx| (idx, format!("branch-{idx}"))).collect();
 
     app.handle_mouse_event(left_down(19, 5));
     app.handle_mouse_event(left_up(19, 5));
 
-    assert_eq!(app.focus, Focus::Branches);
-    assert!(app.branches_scroll.get() > 0);
-    assert!(app.branches_selected >= app.branches_scroll.get());
+    assert_eq!(app.tab.focus, Focus::Branches);
+    assert!(app.tab.branches_scroll.get() > 0);
+    assert!(app.tab.branches_selected >= app.tab.branches_scroll.get());
 }
 
 #[test]
 fn mouse_scrollbars_work_for_inspector_and_status_panes() {
     let mut inspector = graph_app();
     inspector.layout_config.is_inspector = true;
-    inspector.uncommitted.has_conflicts = true;
+    inspector.tab.uncommitted.has_conflicts = true;
     inspector.layout.inspector = Rect::new(0, 0, 40, 5);
     inspector.layout.inspector_scrollbar = Rect::new(0, 0, 40, 5);
 
     inspector.handle_mouse_event(left_down(39, 3));
     inspector.handle_mouse_event(left_up(39, 3));
 
-    assert_eq!(inspector.focus, Focus::Inspector);
-    assert!(inspector.inspector_scroll.get() > 0);
+    assert_eq!(inspector.tab.focus, Focus::Inspector);
+    assert!(inspector.tab.inspector_scroll.get() > 0);
 
     let mut status = graph_app();
     status.layout_config.is_status = true;
     status.layout.status_top = Rect::new(0, 0, 40, 5);
     status.layout.status_top_scrollbar = Rect::new(0, 0, 40, 5);
-    status.is_uncommitted_loaded = true;
-    status.uncommitted.is_staged = true;
-    status.uncommitted.staged.modified = (0..20).map(|idx| format!("file-{idx}.rs")).collect();
+    status.tab.is_uncommitted_loaded = true;
+    status.tab.uncommitted.is_staged = true;
+    status.tab.uncommitted.staged.modified = (0..20).map(|idx| format!("file-{idx}.rs")).collect();
 
     status.handle_mouse_event(left_down(39, 3));
     status.handle_mouse_event(left_up(39, 3));
 
-    assert_eq!(status.focus, Focus::StatusTop);
-    assert!(status.status_top_scroll.get() > 0);
+    assert_eq!(status.tab.focus, Focus::StatusTop);
+    assert!(status.tab.status_top_scroll.get() > 0);
 
     let mut bottom_status = graph_app();
     bottom_status.layout_config.is_status = true;
@@ -566,29 +579,34 @@ fn mouse_scrollbars_work_for_inspector_and_status_panes() {
     bottom_status.layout.status_top_scrollbar = Rect::new(0, 0, 40, 3);
     bottom_status.layout.status_bottom = Rect::new(0, 2, 40, 5);
     bottom_status.layout.status_bottom_scrollbar = Rect::new(0, 2, 40, 5);
-    bottom_status.is_uncommitted_loaded = true;
-    bottom_status.uncommitted.is_unstaged = true;
-    bottom_status.uncommitted.unstaged.modified = (0..20).map(|idx| format!("file-{idx}.rs")).collect();
+    bottom_status.tab.is_uncommitted_loaded = true;
+    bottom_status.tab.uncommitted.is_unstaged = true;
+    bottom_status.tab.uncommitted.unstaged.modified = (0..20).map(|idx| format!("file-{idx}.rs")).collect();
 
     bottom_status.handle_mouse_event(left_down(39, 5));
     bottom_status.handle_mouse_event(left_up(39, 5));
 
-    assert_eq!(bottom_status.focus, Focus::StatusBottom);
-    assert!(bottom_status.status_bottom_scroll.get() > 0);
+    assert_eq!(bottom_status.tab.focus, Focus::StatusBottom);
+    assert!(bottom_status.tab.status_bottom_scroll.get() > 0);
 }
 
 #[test]
 fn mouse_click_on_settings_scrollbar_scrolls_settings() {
     let (_path, repo) = temp_repo("settings-scrollbar");
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, repo: Some(Rc::new(repo)), layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app = App {
+        layout_config: LayoutConfig::default(),
+        layout: Layout::default(),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, repo: Some(Rc::new(repo)), ..Default::default() },
+        ..Default::default()
+    };
     app.layout.app = Rect::new(0, 0, 90, 10);
     app.layout.graph = Rect::new(0, 0, 90, 10);
 
     app.handle_mouse_event(left_down(89, 8));
     app.handle_mouse_event(left_up(89, 8));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
     assert!(app.settings_scroll.get() > 0);
     assert!(app.settings_selections.iter().any(|selection| selection.line == app.settings_selected));
 }
@@ -597,40 +615,40 @@ fn mouse_click_on_settings_scrollbar_scrolls_settings() {
 fn non_scrollable_or_too_short_scrollbars_fall_back_to_row_clicks() {
     let mut non_scrollable = graph_app();
     non_scrollable.layout.graph_scrollbar = Rect::new(29, 0, 1, 8);
-    non_scrollable.graph.total = 5;
+    non_scrollable.tab.graph.total = 5;
 
     non_scrollable.handle_mouse_event(left_down(29, 3));
 
-    assert_eq!(non_scrollable.graph_scroll.get(), 0);
-    assert_eq!(non_scrollable.graph_selected, 3);
+    assert_eq!(non_scrollable.tab.graph_scroll.get(), 0);
+    assert_eq!(non_scrollable.tab.graph_selected, 3);
 
     let mut too_short = graph_app();
     too_short.layout.graph_scrollbar = Rect::new(29, 0, 1, 2);
-    too_short.graph.total = 100;
+    too_short.tab.graph.total = 100;
 
     too_short.handle_mouse_event(left_down(29, 1));
 
-    assert_eq!(too_short.graph_scroll.get(), 0);
-    assert_eq!(too_short.graph_selected, 1);
+    assert_eq!(too_short.tab.graph_scroll.get(), 0);
+    assert_eq!(too_short.tab.graph_selected, 1);
 }
 
 #[test]
 fn mouse_click_selects_viewer_row_without_leaving_viewer() {
     let mut app = graph_app();
-    app.viewport = Viewport::Viewer;
-    app.viewer_lines = (0..10).map(|idx| ListItem::new(format!("line {idx}"))).collect();
-    app.viewer_scroll.set(2);
+    app.tab.viewport = Viewport::Viewer;
+    app.tab.viewer_lines = (0..10).map(|idx| ListItem::new(format!("line {idx}"))).collect();
+    app.tab.viewer_scroll.set(2);
 
     app.handle_mouse_event(left_down(1, 3));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Viewer);
-    assert_eq!(app.viewer_selected, 5);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Viewer);
+    assert_eq!(app.tab.viewer_selected, 5);
 
     app.handle_mouse_event(left_down(1, 3));
 
-    assert_eq!(app.viewport, Viewport::Viewer);
-    assert_eq!(app.viewer_selected, 5);
+    assert_eq!(app.tab.viewport, Viewport::Viewer);
+    assert_eq!(app.tab.viewer_selected, 5);
 }
 
 #[test]
@@ -648,10 +666,10 @@ fn mouse_click_selects_left_pane_rows() {
     app.layout.reflogs = Rect::new(60, 0, 20, 6);
     app.layout.worktrees = Rect::new(80, 0, 20, 6);
     app.layout.submodules = Rect::new(100, 0, 20, 6);
-    app.branches.sorted = (0..10).map(|idx| (idx, format!("branch-{idx}"))).collect();
-    app.tags.sorted = (0..10).map(|idx| (idx, format!("tag-{idx}"))).collect();
-    app.oids.stashes = (0..10).collect();
-    app.reflogs.entries = (0..10)
+    app.tab.branches.sorted = (0..10).map(|idx| (idx, format!("branch-{idx}"))).collect();
+    app.tab.tags.sorted = (0..10).map(|idx| (idx, format!("tag-{idx}"))).collect();
+    app.tab.oids.stashes = (0..10).collect();
+    app.tab.reflogs.entries = (0..10)
         .map(|idx| HeadReflogAliasEntry {
             selector: format!("HEAD@{{{idx}}}"),
             old_oid: test_oid(1),
@@ -661,32 +679,32 @@ fn mouse_click_selects_left_pane_rows() {
             time: git2::Time::new(idx as i64, 0),
         })
         .collect();
-    app.worktrees.entries = (0..10).map(|idx| worktree_entry(&format!("worktree-{idx}"))).collect();
-    app.submodules.entries = (0..10).map(|idx| submodule_entry(&format!("submodule-{idx}"), PathBuf::from(format!("/tmp/submodule-{idx}")))).collect();
-    app.branches_scroll.set(2);
-    app.tags_scroll.set(2);
-    app.stashes_scroll.set(2);
-    app.reflogs_scroll.set(2);
-    app.worktrees_scroll.set(2);
-    app.submodules_scroll.set(2);
+    app.tab.worktrees.entries = (0..10).map(|idx| worktree_entry(&format!("worktree-{idx}"))).collect();
+    app.tab.submodules.entries = (0..10).map(|idx| submodule_entry(&format!("submodule-{idx}"), PathBuf::from(format!("/tmp/submodule-{idx}")))).collect();
+    app.tab.branches_scroll.set(2);
+    app.tab.tags_scroll.set(2);
+    app.tab.stashes_scroll.set(2);
+    app.tab.reflogs_scroll.set(2);
+    app.tab.worktrees_scroll.set(2);
+    app.tab.submodules_scroll.set(2);
 
     app.handle_mouse_event(left_down(1, 1));
-    assert_eq!((app.focus, app.branches_selected), (Focus::Branches, 3));
+    assert_eq!((app.tab.focus, app.tab.branches_selected), (Focus::Branches, 3));
 
     app.handle_mouse_event(left_down(21, 1));
-    assert_eq!((app.focus, app.tags_selected), (Focus::Tags, 3));
+    assert_eq!((app.tab.focus, app.tab.tags_selected), (Focus::Tags, 3));
 
     app.handle_mouse_event(left_down(41, 1));
-    assert_eq!((app.focus, app.stashes_selected), (Focus::Stashes, 3));
+    assert_eq!((app.tab.focus, app.tab.stashes_selected), (Focus::Stashes, 3));
 
     app.handle_mouse_event(left_down(61, 1));
-    assert_eq!((app.focus, app.reflogs_selected), (Focus::Reflogs, 3));
+    assert_eq!((app.tab.focus, app.tab.reflogs_selected), (Focus::Reflogs, 3));
 
     app.handle_mouse_event(left_down(81, 1));
-    assert_eq!((app.focus, app.worktrees_selected), (Focus::Worktrees, 3));
+    assert_eq!((app.tab.focus, app.tab.worktrees_selected), (Focus::Worktrees, 3));
 
     app.handle_mouse_event(left_down(101, 1));
-    assert_eq!((app.focus, app.submodules_selected), (Focus::Submodules, 3));
+    assert_eq!((app.tab.focus, app.tab.submodules_selected), (Focus::Submodules, 3));
 }
 
 #[test]
@@ -695,40 +713,41 @@ fn mouse_click_selects_status_rows() {
     app.layout_config.is_status = true;
     app.layout.status_top = Rect::new(0, 0, 30, 6);
     app.layout.status_bottom = Rect::new(0, 10, 30, 6);
-    app.is_uncommitted_loaded = true;
-    app.uncommitted.is_staged = true;
-    app.uncommitted.is_unstaged = true;
-    app.uncommitted.staged.modified = vec!["a".into(), "b".into(), "c".into(), "d".into()];
-    app.uncommitted.unstaged.modified = vec!["e".into(), "f".into(), "g".into(), "h".into()];
-    app.status_top_scroll.set(1);
-    app.status_bottom_scroll.set(1);
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.uncommitted.is_staged = true;
+    app.tab.uncommitted.is_unstaged = true;
+    app.tab.uncommitted.staged.modified = vec!["a".into(), "b".into(), "c".into(), "d".into()];
+    app.tab.uncommitted.unstaged.modified = vec!["e".into(), "f".into(), "g".into(), "h".into()];
+    app.tab.status_top_scroll.set(1);
+    app.tab.status_bottom_scroll.set(1);
 
     app.handle_mouse_event(left_down(1, 1));
-    assert_eq!((app.focus, app.status_top_selected), (Focus::StatusTop, 2));
+    assert_eq!((app.tab.focus, app.tab.status_top_selected), (Focus::StatusTop, 2));
 
     app.handle_mouse_event(left_down(1, 12));
-    assert_eq!((app.focus, app.status_bottom_selected), (Focus::StatusBottom, 2));
+    assert_eq!((app.tab.focus, app.tab.status_bottom_selected), (Focus::StatusBottom, 2));
 }
 
 #[test]
 fn mouse_click_selects_inspector_row_when_loaded() {
     let mut app = graph_app();
     let oid = test_oid(5);
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
-    app.graph_selected = 1;
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+    app.tab.graph_selected = 1;
     app.layout_config.is_inspector = true;
     app.layout.inspector = Rect::new(0, 0, 30, 6);
-    app.inspector_scroll.set(2);
+    app.tab.inspector_scroll.set(2);
 
     app.handle_mouse_event(left_down(1, 1));
 
-    assert_eq!((app.focus, app.inspector_selected), (Focus::Inspector, 3));
+    assert_eq!((app.tab.focus, app.tab.inspector_selected), (Focus::Inspector, 3));
 }
 
 #[test]
 fn mouse_click_selects_splash_recent_repo() {
-    let mut app = App { viewport: Viewport::Splash, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.app = Rect::new(0, 0, 120, 20);
     app.layout.graph = Rect::new(0, 0, 120, 20);
     app.recent = vec!["/repo/a".into(), "/repo/b".into(), "/repo/c".into()];
@@ -740,7 +759,8 @@ fn mouse_click_selects_splash_recent_repo() {
 
 #[test]
 fn mouse_click_selects_only_selectable_settings_rows() {
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.graph = Rect::new(0, 0, 40, 5);
     app.settings_scroll.set(10);
     app.settings_selected = 12;
@@ -758,13 +778,11 @@ fn mouse_click_on_settings_tab_switches_active_tab() {
     let (_path, repo) = temp_repo("settings-tab-click");
     let repo = Rc::new(repo);
     let mut app = App {
-        repo: Some(repo.clone()),
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         layout_config: LayoutConfig::default(),
         layout: Layout::default(),
         settings_tab: SettingsTab::General,
         settings_selected: 99,
+        tab: RepoTab { repo: Some(repo.clone()), viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.layout.app = Rect::new(0, 0, 120, 40);
@@ -777,8 +795,8 @@ fn mouse_click_on_settings_tab_switches_active_tab() {
 
     app.handle_mouse_event(left_down(column, row));
 
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_tab, SettingsTab::Repo);
     assert_eq!(app.settings_scroll.get(), 0);
     assert!(app.settings_selections.iter().any(|selection| selection.line == app.settings_selected));
@@ -787,15 +805,16 @@ fn mouse_click_on_settings_tab_switches_active_tab() {
 
 #[test]
 fn mouse_single_click_on_settings_layout_row_toggles_once() {
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.graph = Rect::new(0, 0, 40, 5);
     app.layout_config.is_branches = true;
     app.settings_selections = vec![SettingsSelection { line: 2, kind: SettingsSelectionKind::LayoutCommand(Command::ToggleBranches) }];
 
     app.handle_mouse_event(left_down(1, 2));
     assert!(!app.layout_config.is_branches);
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 2);
 
     app.handle_mouse_event(left_down(1, 2));
@@ -805,20 +824,22 @@ fn mouse_single_click_on_settings_layout_row_toggles_once() {
 #[test]
 fn double_click_on_settings_selectable_row_acts_like_enter() {
     let key_selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.graph = Rect::new(0, 0, 40, 5);
     app.settings_selections = vec![SettingsSelection { line: 2, kind: SettingsSelectionKind::KeyBinding(key_selection.clone()) }];
 
     app.handle_mouse_event(left_down(1, 2));
     app.handle_mouse_event(left_down(1, 2));
 
-    assert_eq!(app.focus, Focus::ModalKeyCapture);
+    assert_eq!(app.tab.focus, Focus::ModalKeyCapture);
     assert_eq!(app.modal_key_capture_selection, Some(key_selection));
 }
 
 #[test]
 fn double_click_on_settings_recent_repository_row_is_selection_only() {
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut app =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.graph = Rect::new(0, 0, 40, 5);
     app.recent = vec!["/repo/a".into()];
     app.settings_selections = vec![SettingsSelection { line: 2, kind: SettingsSelectionKind::RecentRepository(0) }];
@@ -826,10 +847,10 @@ fn double_click_on_settings_recent_repository_row_is_selection_only() {
     app.handle_mouse_event(left_down(1, 2));
     app.handle_mouse_event(left_down(1, 2));
 
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 2);
-    assert!(app.repo.is_none());
+    assert!(app.tab.repo.is_none());
 }
 
 #[test]
@@ -837,18 +858,18 @@ fn double_click_on_branch_row_acts_like_enter() {
     let (_path, repo) = temp_repo("branch-double");
     let mut app = graph_app();
     let oid = commit_file(&repo, "feature.txt", "feature");
-    app.repo = Some(Rc::new(repo));
+    app.tab.repo = Some(Rc::new(repo));
     app.layout_config.is_branches = true;
     app.layout.branches = Rect::new(0, 0, 20, 6);
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
-    app.branches.sorted = vec![(alias, "feature".into())];
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+    app.tab.branches.sorted = vec![(alias, "feature".into())];
 
     app.handle_mouse_event(left_down(1, 0));
     app.handle_mouse_event(left_down(1, 0));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.graph_selected, 1);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.graph_selected, 1);
 }
 
 #[test]
@@ -856,20 +877,20 @@ fn viewer_mode_double_click_on_branch_row_acts_like_enter() {
     let (_path, repo) = temp_repo("viewer-branch-double");
     let mut app = graph_app();
     let oid = commit_file(&repo, "feature.txt", "feature");
-    app.repo = Some(Rc::new(repo));
-    app.viewport = Viewport::Viewer;
+    app.tab.repo = Some(Rc::new(repo));
+    app.tab.viewport = Viewport::Viewer;
     app.layout_config.is_branches = true;
     app.layout.branches = Rect::new(0, 0, 20, 6);
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
-    app.branches.sorted = vec![(alias, "feature".into())];
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+    app.tab.branches.sorted = vec![(alias, "feature".into())];
 
     app.handle_mouse_event(left_down(1, 0));
     app.handle_mouse_event(left_down(1, 0));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.graph_selected, 1);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.graph_selected, 1);
 }
 
 #[test]
@@ -881,41 +902,41 @@ fn double_click_on_tag_stash_and_reflog_rows_act_like_enter() {
     let repo = Rc::new(repo);
 
     let mut tag_app = graph_app();
-    tag_app.repo = Some(repo.clone());
+    tag_app.tab.repo = Some(repo.clone());
     tag_app.layout_config.is_tags = true;
     tag_app.layout.tags = Rect::new(0, 0, 20, 6);
-    let tag_alias = tag_app.oids.get_alias_by_oid(tag_oid);
-    tag_app.oids.sorted_aliases = vec![NONE, tag_alias];
-    tag_app.tags.sorted = vec![(tag_alias, "v1.0.0".into())];
+    let tag_alias = tag_app.tab.oids.get_alias_by_oid(tag_oid);
+    tag_app.tab.oids.sorted_aliases = vec![NONE, tag_alias];
+    tag_app.tab.tags.sorted = vec![(tag_alias, "v1.0.0".into())];
 
     tag_app.handle_mouse_event(left_down(1, 0));
     tag_app.handle_mouse_event(left_down(1, 0));
-    assert_eq!((tag_app.focus, tag_app.graph_selected), (Focus::Viewport, 1));
+    assert_eq!((tag_app.tab.focus, tag_app.tab.graph_selected), (Focus::Viewport, 1));
 
     let mut stash_app = graph_app();
-    stash_app.repo = Some(repo.clone());
+    stash_app.tab.repo = Some(repo.clone());
     stash_app.layout_config.is_stashes = true;
     stash_app.layout.stashes = Rect::new(0, 0, 20, 6);
-    let stash_alias = stash_app.oids.get_alias_by_oid(stash_oid);
-    stash_app.oids.sorted_aliases = vec![NONE, stash_alias];
-    stash_app.oids.stashes = vec![stash_alias];
+    let stash_alias = stash_app.tab.oids.get_alias_by_oid(stash_oid);
+    stash_app.tab.oids.sorted_aliases = vec![NONE, stash_alias];
+    stash_app.tab.oids.stashes = vec![stash_alias];
 
     stash_app.handle_mouse_event(left_down(1, 0));
     stash_app.handle_mouse_event(left_down(1, 0));
-    assert_eq!((stash_app.focus, stash_app.graph_selected), (Focus::Viewport, 1));
+    assert_eq!((stash_app.tab.focus, stash_app.tab.graph_selected), (Focus::Viewport, 1));
 
     let mut reflog_app = graph_app();
-    reflog_app.repo = Some(repo);
+    reflog_app.tab.repo = Some(repo);
     reflog_app.layout_config.is_reflogs = true;
     reflog_app.layout.reflogs = Rect::new(0, 0, 20, 6);
-    let reflog_alias = reflog_app.oids.get_alias_by_oid(reflog_oid);
-    reflog_app.oids.sorted_aliases = vec![NONE, reflog_alias];
-    reflog_app.reflogs.entries =
+    let reflog_alias = reflog_app.tab.oids.get_alias_by_oid(reflog_oid);
+    reflog_app.tab.oids.sorted_aliases = vec![NONE, reflog_alias];
+    reflog_app.tab.reflogs.entries =
         vec![HeadReflogAliasEntry { selector: "HEAD@{0}".into(), old_oid: tag_oid, new_oid: reflog_oid, new_alias: reflog_alias, message: "commit reflog".into(), time: git2::Time::new(0, 0) }];
 
     reflog_app.handle_mouse_event(left_down(1, 0));
     reflog_app.handle_mouse_event(left_down(1, 0));
-    assert_eq!((reflog_app.focus, reflog_app.graph_selected), (Focus::Viewport, 1));
+    assert_eq!((reflog_app.tab.focus, reflog_app.tab.graph_selected), (Focus::Viewport, 1));
 }
 
 #[test]
@@ -923,13 +944,15 @@ fn double_click_on_worktree_row_acts_like_enter() {
     let (current_path, repo) = temp_repo("worktree-current");
     let (target_path, _target_repo) = temp_repo("worktree-target");
     let mut app = graph_app();
-    app.repo = Some(Rc::new(repo));
-    app.path = Some(current_path.display().to_string());
+    app.session_save_path = Some(current_path.with_extension("session.json"));
+    app.walk_cache_dir = Some(current_path.with_extension("walks"));
+    app.tab.repo = Some(Rc::new(repo));
+    app.tab.path = Some(current_path.display().to_string());
     let canonical_target = fs::canonicalize(&target_path).unwrap().display().to_string();
     app.recent = vec![canonical_target.clone()];
     app.layout_config.is_worktrees = true;
     app.layout.worktrees = Rect::new(0, 0, 20, 6);
-    app.worktrees.entries = vec![WorktreeEntry {
+    app.tab.worktrees.entries = vec![WorktreeEntry {
         name: "linked".into(),
         path: target_path.clone(),
         branch: Some("main".into()),
@@ -946,10 +969,10 @@ fn double_click_on_worktree_row_acts_like_enter() {
     app.handle_mouse_event(left_down(1, 0));
     app.handle_mouse_event(left_down(1, 0));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.graph_selected, 0);
-    assert_eq!(app.path.as_deref(), Some(canonical_target.as_str()));
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.graph_selected, 0);
+    assert_eq!(app.tab.path.as_deref(), Some(canonical_target.as_str()));
 }
 
 #[test]
@@ -957,21 +980,23 @@ fn double_click_on_submodule_row_acts_like_enter() {
     let (current_path, repo) = temp_repo("submodule-current");
     let (target_path, _target_repo) = temp_repo("submodule-target");
     let mut app = graph_app();
-    app.repo = Some(Rc::new(repo));
-    app.path = Some(current_path.display().to_string());
+    app.session_save_path = Some(current_path.with_extension("session.json"));
+    app.walk_cache_dir = Some(current_path.with_extension("walks"));
+    app.tab.repo = Some(Rc::new(repo));
+    app.tab.path = Some(current_path.display().to_string());
     let canonical_target = fs::canonicalize(&target_path).unwrap().display().to_string();
     app.recent = vec![canonical_target.clone()];
     app.layout_config.is_submodules = true;
     app.layout.submodules = Rect::new(0, 0, 20, 6);
-    app.submodules.entries = vec![submodule_entry("deps/child", target_path)];
+    app.tab.submodules.entries = vec![submodule_entry("deps/child", target_path)];
 
     app.handle_mouse_event(left_down(1, 0));
     app.handle_mouse_event(left_down(1, 0));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.graph_selected, 0);
-    assert_eq!(app.path.as_deref(), Some(canonical_target.as_str()));
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.graph_selected, 0);
+    assert_eq!(app.tab.path.as_deref(), Some(canonical_target.as_str()));
 }
 
 #[test]
@@ -979,19 +1004,19 @@ fn double_click_on_status_row_acts_like_enter() {
     let (path, repo) = temp_repo("status-double");
     fs::write(path.join("file.txt"), "hello\n").unwrap();
     let mut app = graph_app();
-    app.repo = Some(Rc::new(repo));
-    app.path = Some(path.display().to_string());
+    app.tab.repo = Some(Rc::new(repo));
+    app.tab.path = Some(path.display().to_string());
     app.layout_config.is_status = true;
     app.layout.status_top = Rect::new(0, 0, 30, 6);
-    app.is_uncommitted_loaded = true;
-    app.uncommitted.is_staged = true;
-    app.uncommitted.staged.modified = vec!["file.txt".into()];
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.uncommitted.is_staged = true;
+    app.tab.uncommitted.staged.modified = vec!["file.txt".into()];
 
     app.handle_mouse_event(left_down(1, 0));
     app.handle_mouse_event(left_down(1, 0));
 
-    assert_eq!(app.viewport, Viewport::Viewer);
-    assert_eq!(app.file_name.as_deref(), Some("file.txt"));
+    assert_eq!(app.tab.viewport, Viewport::Viewer);
+    assert_eq!(app.tab.file_name.as_deref(), Some("file.txt"));
 }
 
 #[test]
@@ -1000,36 +1025,37 @@ fn viewer_mode_double_click_on_status_row_refreshes_viewer_file() {
     fs::write(path.join("old.txt"), "old\n").unwrap();
     fs::write(path.join("new.txt"), "new\n").unwrap();
     let mut app = graph_app();
-    app.repo = Some(Rc::new(repo));
-    app.path = Some(path.display().to_string());
-    app.viewport = Viewport::Viewer;
+    app.tab.repo = Some(Rc::new(repo));
+    app.tab.path = Some(path.display().to_string());
+    app.tab.viewport = Viewport::Viewer;
     app.viewer_mode = ViewerMode::Full;
-    app.file_name = Some("old.txt".into());
+    app.tab.file_name = Some("old.txt".into());
     app.layout_config.is_status = true;
     app.layout.status_top = Rect::new(40, 0, 30, 6);
-    app.is_uncommitted_loaded = true;
-    app.uncommitted.is_staged = true;
-    app.uncommitted.staged.modified = vec!["old.txt".into(), "new.txt".into()];
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.uncommitted.is_staged = true;
+    app.tab.uncommitted.staged.modified = vec!["old.txt".into(), "new.txt".into()];
 
     app.handle_mouse_event(left_down(41, 1));
     app.handle_mouse_event(left_down(41, 1));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Viewer);
-    assert_eq!(app.file_name.as_deref(), Some("new.txt"));
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Viewer);
+    assert_eq!(app.tab.file_name.as_deref(), Some("new.txt"));
 }
 
 #[test]
 fn double_click_on_graph_and_splash_only_selects() {
     let mut app = graph_app();
-    app.graph_scroll.set(2);
+    app.tab.graph_scroll.set(2);
 
     app.handle_mouse_event(left_down(1, 1));
     app.handle_mouse_event(left_down(1, 1));
-    assert_eq!(app.graph_selected, 3);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.graph_selected, 3);
+    assert_eq!(app.tab.focus, Focus::Viewport);
 
-    let mut splash = App { viewport: Viewport::Splash, focus: Focus::Viewport, layout_config: LayoutConfig::default(), layout: Layout::default(), ..Default::default() };
+    let mut splash =
+        App { layout_config: LayoutConfig::default(), layout: Layout::default(), tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     splash.layout.app = Rect::new(0, 0, 120, 20);
     splash.layout.graph = Rect::new(0, 0, 120, 20);
     splash.recent = vec!["/repo/a".into(), "/repo/b".into()];
@@ -1038,8 +1064,8 @@ fn double_click_on_graph_and_splash_only_selects() {
     splash.handle_mouse_event(left_down(1, 15));
 
     assert_eq!(splash.splash_selected, 0);
-    assert_eq!(splash.viewport, Viewport::Splash);
-    assert_eq!(splash.path, None);
+    assert_eq!(splash.tab.viewport, Viewport::Splash);
+    assert_eq!(splash.tab.path, None);
 }
 
 fn resizable_columns_app() -> App {
@@ -1059,9 +1085,9 @@ fn shared_graph_scrollbar_divider_app() -> App {
     app.layout.graph = Rect::new(30, 0, 40, 30);
     app.layout.graph_scrollbar = Rect::new(30, 0, 40, 30);
     app.layout.divider_right = Rect::new(69, 0, 1, 30);
-    app.graph.total = 100;
-    app.graph_selected = 0;
-    app.graph_scroll.set(0);
+    app.tab.graph.total = 100;
+    app.tab.graph_selected = 0;
+    app.tab.graph_scroll.set(0);
     app
 }
 
@@ -1072,7 +1098,7 @@ fn horizontal_drag_on_shared_scrollbar_divider_resizes_pane() {
     app.handle_mouse_event(left_down(69, 10));
     app.handle_mouse_event(left_drag(60, 10));
 
-    assert_eq!(app.graph_scroll.get(), 0);
+    assert_eq!(app.tab.graph_scroll.get(), 0);
     assert!(app.layout_config.width_right_pane > 30);
 }
 
@@ -1085,8 +1111,8 @@ fn vertical_drag_on_shared_scrollbar_divider_scrolls_graph() {
     app.handle_mouse_event(left_up(69, 20));
 
     assert_eq!(app.layout_config.width_right_pane, 30);
-    assert!(app.graph_scroll.get() > 0);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert!(app.tab.graph_scroll.get() > 0);
+    assert_eq!(app.tab.focus, Focus::Viewport);
 }
 
 #[test]
@@ -1097,32 +1123,32 @@ fn click_on_shared_scrollbar_divider_scrolls_without_resizing() {
     app.handle_mouse_event(left_up(69, 20));
 
     assert_eq!(app.layout_config.width_right_pane, 30);
-    assert!(app.graph_scroll.get() > 0);
+    assert!(app.tab.graph_scroll.get() > 0);
 }
 
 #[test]
 fn keyboard_resize_updates_side_columns_for_focused_panes_and_viewport() {
     let mut app = resizable_columns_app();
 
-    app.focus = Focus::Branches;
+    app.tab.focus = Focus::Branches;
     app.on_resize_pane_right();
     assert_eq!(app.layout_config.width_left_pane, 31);
     app.on_resize_pane_left();
     assert_eq!(app.layout_config.width_left_pane, 30);
 
-    app.focus = Focus::Search;
+    app.tab.focus = Focus::Search;
     app.on_resize_pane_right();
     assert_eq!(app.layout_config.width_left_pane, 31);
     app.on_resize_pane_left();
     assert_eq!(app.layout_config.width_left_pane, 30);
 
-    app.focus = Focus::StatusTop;
+    app.tab.focus = Focus::StatusTop;
     app.on_resize_pane_left();
     assert_eq!(app.layout_config.width_right_pane, 31);
     app.on_resize_pane_right();
     assert_eq!(app.layout_config.width_right_pane, 30);
 
-    app.focus = Focus::Viewport;
+    app.tab.focus = Focus::Viewport;
     app.on_resize_pane_left();
     assert_eq!(app.layout_config.width_left_pane, 29);
     app.on_resize_pane_right();
@@ -1132,7 +1158,7 @@ fn keyboard_resize_updates_side_columns_for_focused_panes_and_viewport() {
 #[test]
 fn keyboard_resize_clamps_side_columns() {
     let mut app = resizable_columns_app();
-    app.focus = Focus::Branches;
+    app.tab.focus = Focus::Branches;
     app.layout.app = Rect::new(0, 0, 70, 30);
     app.layout.pane_right = Rect::new(36, 0, 34, 30);
     app.layout_config.width_left_pane = 16;
@@ -1165,7 +1191,7 @@ fn left_stack_app() -> App {
 #[test]
 fn keyboard_resize_grows_focused_left_stack_toward_direction() {
     let mut app = left_stack_app();
-    app.focus = Focus::Tags;
+    app.tab.focus = Focus::Tags;
 
     app.on_resize_pane_up();
     assert!(app.layout_config.weight_branches < 100);
@@ -1173,7 +1199,7 @@ fn keyboard_resize_grows_focused_left_stack_toward_direction() {
     assert_eq!(app.layout_config.weight_stashes, 100);
 
     let mut app = left_stack_app();
-    app.focus = Focus::Tags;
+    app.tab.focus = Focus::Tags;
 
     app.on_resize_pane_down();
     assert_eq!(app.layout_config.weight_branches, 100);
@@ -1184,14 +1210,14 @@ fn keyboard_resize_grows_focused_left_stack_toward_direction() {
 #[test]
 fn keyboard_resize_edge_stack_direction_shrinks_from_opposite_edge() {
     let mut app = left_stack_app();
-    app.focus = Focus::Branches;
+    app.tab.focus = Focus::Branches;
 
     app.on_resize_pane_up();
     assert!(app.layout_config.weight_branches < 100);
     assert!(app.layout_config.weight_tags > 100);
 
     let mut app = left_stack_app();
-    app.focus = Focus::Stashes;
+    app.tab.focus = Focus::Stashes;
 
     app.on_resize_pane_down();
     assert!(app.layout_config.weight_tags > 100);
@@ -1204,7 +1230,7 @@ fn keyboard_resize_updates_search_stack_weight() {
     app.layout_config.is_search = true;
     app.layout_config.weight_search = 100;
     app.layout.pane_search = Rect::new(0, 30, 30, 10);
-    app.focus = Focus::Search;
+    app.tab.focus = Focus::Search;
 
     app.on_resize_pane_up();
 
@@ -1233,8 +1259,8 @@ fn mouse_resize_updates_search_stack_weight() {
 #[test]
 fn keyboard_resize_updates_right_stack_weights() {
     let mut app = graph_app();
-    app.graph_selected = 1;
-    app.focus = Focus::StatusTop;
+    app.tab.graph_selected = 1;
+    app.tab.focus = Focus::StatusTop;
     app.layout_config.is_inspector = true;
     app.layout_config.is_status = true;
     app.layout_config.weight_inspector = 100;
@@ -1250,8 +1276,8 @@ fn keyboard_resize_updates_right_stack_weights() {
     assert_eq!(app.layout_config.weight_status_top, 100);
 
     let mut app = graph_app();
-    app.graph_selected = 0;
-    app.focus = Focus::StatusTop;
+    app.tab.graph_selected = 0;
+    app.tab.focus = Focus::StatusTop;
     app.layout_config.is_inspector = false;
     app.layout_config.is_status = true;
     app.layout_config.weight_status_top = 100;
@@ -1266,8 +1292,8 @@ fn keyboard_resize_updates_right_stack_weights() {
 
 fn split_viewer_app(is_zen: bool) -> App {
     let mut app = resizable_columns_app();
-    app.viewport = Viewport::Viewer;
-    app.focus = Focus::Viewport;
+    app.tab.viewport = Viewport::Viewer;
+    app.tab.focus = Focus::Viewport;
     app.viewer_mode = ViewerMode::Split;
     app.layout_config.is_zen = is_zen;
     app.layout_config.weight_viewer_split_left = 100;
@@ -1312,19 +1338,117 @@ fn keyboard_resize_in_zen_split_viewer_is_noop() {
 #[test]
 fn keyboard_resize_noops_in_settings_modals_and_non_split_zen() {
     let mut app = resizable_columns_app();
-    app.viewport = Viewport::Settings;
-    app.focus = Focus::Viewport;
+    app.tab.viewport = Viewport::Settings;
+    app.tab.focus = Focus::Viewport;
     app.on_resize_pane_left();
     assert_eq!(app.layout_config.width_left_pane, 30);
 
     let mut app = resizable_columns_app();
-    app.focus = Focus::ModalCheckout;
+    app.tab.focus = Focus::ModalCheckout;
     app.on_resize_pane_right();
     assert_eq!(app.layout_config.width_right_pane, 30);
 
     let mut app = resizable_columns_app();
     app.layout_config.is_zen = true;
-    app.focus = Focus::Branches;
+    app.tab.focus = Focus::Branches;
     app.on_resize_pane_right();
     assert_eq!(app.layout_config.width_left_pane, 30);
 }
+
+// A repo whose graph rows are, top to bottom: uncommitted, tip (HEAD), mid, root; `feature` sits on root.
+fn branch_drop_app(name: &str) -> (PathBuf, App, [Oid; 3]) {
+    let (path, repo) = temp_repo(name);
+    let root = commit_file(&repo, "a.txt", "root");
+    let mid = commit_file(&repo, "b.txt", "mid");
+    let tip = commit_file(&repo, "c.txt", "tip");
+    repo.branch("feature", &repo.find_commit(root).unwrap(), false).unwrap();
+    let current = repo.head().unwrap().shorthand().unwrap().to_string();
+
+    let mut app = graph_app();
+    app.tab.path = Some(path.display().to_string());
+    app.tab.repo = Some(Rc::new(repo));
+    app.journal_save_path = Some(path.with_extension("journal.json"));
+    app.recent_save_path = Some(path.with_extension("recent.json"));
+    app.walk_cache_dir = Some(path.with_extension("walks"));
+    app.layout.app = Rect::new(0, 0, 80, 8);
+    app.layout.graph = Rect::new(20, 0, 60, 8);
+    app.layout_config.is_branches = true;
+    app.layout.branches = Rect::new(0, 0, 20, 8);
+    let aliases: Vec<u32> = [tip, mid, root].into_iter().map(|oid| app.tab.oids.get_alias_by_oid(oid)).collect();
+    app.tab.oids.sorted_aliases = std::iter::once(NONE).chain(aliases.iter().copied()).collect();
+    app.tab.graph.total = 4;
+    app.tab.branches.sorted = vec![(aliases[2], "feature".to_string()), (aliases[0], current)];
+    (path, app, [tip, mid, root])
+}
+
+fn cleanup_branch_drop(path: PathBuf) {
+    for extension in ["journal.json", "recent.json"] {
+        let _ = fs::remove_file(path.with_extension(extension));
+    }
+    let _ = fs::remove_dir_all(path);
+}
+
+fn drop_menu_item(app: &App, label: &str) -> (usize, bool) {
+    let menu = app.context_menu.as_ref().unwrap();
+    let index = menu.items.iter().position(|item| item.label == label).unwrap();
+    (index, menu.items[index].enabled)
+}
+
+#[test]
+fn dragging_a_branch_onto_a_graph_row_offers_a_drop_menu_and_resets_it_there() {
+    let (path, mut app, [_, mid, _]) = branch_drop_app("drop-reset");
+
+    // A press and release without moving is just a click.
+    app.handle_mouse_event(left_down(1, 0));
+    app.handle_mouse_event(left_up(1, 0));
+    assert_eq!(app.tab.focus, Focus::Branches);
+    assert!(app.context_menu.is_none());
+
+    app.handle_mouse_event(left_down(1, 0));
+    app.handle_mouse_event(left_drag(30, 2));
+    assert_eq!(app.branch_drop_index(), Some(2));
+    app.handle_mouse_event(left_up(30, 2));
+
+    assert_eq!(app.tab.graph_selected, 2);
+    assert!(!drop_menu_item(&app, "Rebase onto here").1);
+    assert!(!drop_menu_item(&app, "Merge into here").1);
+    assert!(drop_menu_item(&app, "Create branch here").1);
+    let (reset, is_enabled) = drop_menu_item(&app, "Reset branch to here");
+    assert!(is_enabled);
+
+    app.context_menu.as_mut().unwrap().selected = reset;
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(app.tab.focus, Focus::ModalReset);
+    assert_eq!(app.modal_reset_branch.as_deref(), Some("feature"));
+    assert_eq!(app.modal_reset_kind, Command::ResetBranch);
+    assert!(app.modal_reset_preview.unreachable.is_empty());
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    let repo = app.tab.repo.as_ref().unwrap();
+    assert_eq!(repo.find_branch("feature", git2::BranchType::Local).unwrap().get().target(), Some(mid));
+    assert_eq!(app.tab.journal.last().map(|entry| entry.operation.clone()), Some(Command::ResetBranch));
+    assert!(app.mouse_drag.is_none());
+
+    cleanup_branch_drop(path);
+}
+
+#[test]
+fn dropping_a_branch_on_the_head_row_merges_it_through_the_progress_modal() {
+    let (path, mut app, [_, _, root]) = branch_drop_app("drop-merge");
+
+    app.handle_mouse_event(left_down(1, 0));
+    app.handle_mouse_event(left_drag(30, 1));
+    app.handle_mouse_event(left_up(30, 1));
+
+    let (merge, is_enabled) = drop_menu_item(&app, "Merge into here");
+    assert!(is_enabled);
+    assert!(!drop_menu_item(&app, "Rebase onto here").1);
+
+    app.context_menu.as_mut().unwrap().selected = merge;
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.pending_operation_action, Some(PendingOperationAction::Start { kind: OperationKind::Merge, oid: root }));
+
+    cleanup_branch_drop(path);
+}
@@ -0,0 +1,83 @@
+use super::*;
+use crate::{
+    app::app::{GraphWindowCache, RepoTab, Viewport},
+    core::{
+        graph_service::{graph_rows, head_alias},
+        walker::Walker,
+        worktrees::Worktrees,
+    },
+    helpers::{layout::GRAPH_LANE_LIMIT_DEFAULT, symbols::SymbolTheme},
+};
+use git2::{Repository, Signature};
+use im::HashSet;
+use ratatui::layout::Rect;
+use std::{
+    path::Path,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-export-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn commit(repo: &Repository, file: &str, message: &str) {
+    fs::write(repo.workdir().unwrap().join(file), format!("{message}\n")).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap();
+}
+
+// The graph pane shows the uncommitted row and the newest commit only.
+fn export_app(path: &Path, repo: Repository) -> App {
+    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), false, true, GRAPH_LANE_LIMIT_DEFAULT).unwrap();
+    while walker.walk() {}
+    let end = walker.oids.get_commit_count();
+    let history = walker.buffer.borrow().window(0, end + 1);
+    let rows = graph_rows(&walker, &Worktrees::default(), &HashSet::new(), &SymbolTheme::main(), 0, end);
+
+    let mut app =
+        App { tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
+    app.layout.graph = Rect::new(0, 0, 80, 2);
+    app.layout_config.is_zen = false;
+    app.tab.graph.total = end;
+    app.tab.graph.graph_window = Some(GraphWindowCache { version: 1, start: 0, end, head_alias: head_alias(&walker), rows, history });
+    app
+}
+
+#[test]
+fn exporting_the_graph_writes_only_the_visible_rows() {
+    let (path, repo) = temp_repo("visible");
+    commit(&repo, "file.txt", "older commit");
+    commit(&repo, "file.txt", "newest commit");
+
+    let mut app = export_app(&path, repo);
+    app.on_export_graph();
+    assert_eq!(app.tab.focus, Focus::ModalExportGraph);
+    assert!(app.modal_input.value().ends_with(EXPORT_GRAPH_FILE_NAME));
+
+    let image_path = path.with_extension("html");
+    app.modal_input.set_value(image_path.display().to_string());
+    app.confirm_export_graph();
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    let html = fs::read_to_string(&image_path).unwrap();
+    assert!(html.contains("newest commit"));
+    assert!(!html.contains("older commit"));
+    let _ = fs::remove_file(image_path);
+    let _ = fs::remove_dir_all(path);
+}
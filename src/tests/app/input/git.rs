@@ -1,9 +1,12 @@
 use super::*;
+use crate::app::app::RepoTab;
 use crate::core::chunk::NONE;
 use crate::core::reflogs::HeadReflogAliasEntry;
+use crate::core::walk_cache::walk_cache_path;
 use crate::git::actions::merging::{MergeOutcome, start_merge};
 use crate::git::actions::remotes::set_default_remote;
 use crate::git::actions::reverting::{RevertOutcome, start_revert};
+use crate::git::actions::sequencing::{SequenceKind, SequenceOutcome, SequenceStep, start_sequence};
 use crate::git::auth::{AuthChallenge, AuthProtocol};
 use crate::git::queries::diffs::get_filenames_diff_at_workdir;
 use crate::helpers::keymap::{Command, InputMode, KeyBinding};
@@ -124,11 +127,11 @@ fn file_search_keymaps() -> crate::helpers::keymap::Keymaps {
 #[test]
 fn shift_f_opens_file_search_modal_from_repo_views() {
     let (_path, repo) = temp_repo("file-search-shortcut");
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, keymaps: file_search_keymaps(), ..Default::default() };
+    let mut app = App { keymaps: file_search_keymaps(), tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('F'), KeyModifiers::SHIFT));
 
-    assert_eq!(app.focus, Focus::ModalFileSearch);
+    assert_eq!(app.tab.focus, Focus::ModalFileSearch);
     assert_eq!(app.modal_file_search_return_focus, Focus::Branches);
 }
 
@@ -137,13 +140,13 @@ fn file_search_modal_does_not_open_from_splash_or_settings() {
     let (_path, repo) = temp_repo("file-search-blocked");
     let repo = Rc::new(repo);
 
-    let mut splash = App { repo: Some(repo.clone()), viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() };
+    let mut splash = App { tab: RepoTab { repo: Some(repo.clone()), viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     splash.on_find_file();
-    assert_eq!(splash.focus, Focus::Viewport);
+    assert_eq!(splash.tab.focus, Focus::Viewport);
 
-    let mut settings = App { repo: Some(repo), viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() };
+    let mut settings = App { tab: RepoTab { repo: Some(repo), viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     settings.on_find_file();
-    assert_eq!(settings.focus, Focus::Viewport);
+    assert_eq!(settings.tab.focus, Focus::Viewport);
 }
 
 #[test]
@@ -153,32 +156,36 @@ fn status_panes_stage_and_unstage_submodule_pointer_change() {
     commit_with_content(&sub_repo, "file.txt", "advanced\n", "advance child");
 
     let mut app = App {
-        path: Some(parent_path.display().to_string()),
-        repo: Some(Rc::new(parent)),
-        viewport: Viewport::Graph,
-        focus: Focus::StatusBottom,
-        graph_selected: 0,
-        is_uncommitted_loaded: true,
         recent_save_path: Some(parent_path.join("recent.json")),
+        walk_cache_dir: Some(parent_path.join("walks")),
+        tab: RepoTab {
+            path: Some(parent_path.display().to_string()),
+            repo: Some(Rc::new(parent)),
+            viewport: Viewport::Graph,
+            focus: Focus::StatusBottom,
+            graph_selected: 0,
+            is_uncommitted_loaded: true,
+            ..Default::default()
+        },
         ..Default::default()
     };
-    app.uncommitted = get_filenames_diff_at_workdir(app.repo.as_ref().unwrap()).unwrap();
-    app.status_bottom_selected = 0;
+    app.tab.uncommitted = get_filenames_diff_at_workdir(app.tab.repo.as_ref().unwrap()).unwrap();
+    app.tab.status_bottom_selected = 0;
 
     app.on_stage();
 
-    let staged = get_filenames_diff_at_workdir(app.repo.as_ref().unwrap()).unwrap();
+    let staged = get_filenames_diff_at_workdir(app.tab.repo.as_ref().unwrap()).unwrap();
     assert_eq!(staged.staged.modified, vec!["deps/child".to_string()]);
     assert!(staged.unstaged.modified.is_empty());
 
-    app.uncommitted = staged;
-    app.is_uncommitted_loaded = true;
-    app.focus = Focus::StatusTop;
-    app.status_top_selected = 0;
+    app.tab.uncommitted = staged;
+    app.tab.is_uncommitted_loaded = true;
+    app.tab.focus = Focus::StatusTop;
+    app.tab.status_top_selected = 0;
 
     app.on_unstage();
 
-    let unstaged = get_filenames_diff_at_workdir(app.repo.as_ref().unwrap()).unwrap();
+    let unstaged = get_filenames_diff_at_workdir(app.tab.repo.as_ref().unwrap()).unwrap();
     assert!(unstaged.staged.modified.is_empty());
     assert_eq!(unstaged.unstaged.modified, vec!["deps/child".to_string()]);
 }
@@ -190,7 +197,7 @@ fn fetch_all_uses_configured_default_remote() {
     let _remote_path = add_local_bare_remote(&repo, "upstream");
     set_default_remote(&repo, "upstream").unwrap();
     let path_string = path.display().to_string();
-    let mut app = App { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
+    let mut app = App { tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
 
     app.on_fetch_all();
 
@@ -198,6 +205,130 @@ fn fetch_all_uses_configured_default_remote() {
     join_network_worker(&mut app);
 }
 
+#[test]
+fn deepen_prompt_opens_only_in_shallow_clones_and_starts_a_deepen_request() {
+    let (path, repo) = temp_repo("deepen");
+    commit(&repo, "file.txt", "initial");
+    let boundary = commit(&repo, "file.txt", "boundary");
+    let _remote_path = add_local_bare_remote(&repo, "upstream");
+    let path_string = path.display().to_string();
+    let mut app = App { tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
+
+    app.on_deepen_history();
+    assert_eq!(app.tab.focus, Focus::Viewport);
+
+    fs::write(path.join(".git/shallow"), format!("{boundary}\n")).unwrap();
+    app.tab.repo = Some(Rc::new(Repository::open(&path).unwrap()));
+    app.on_deepen_history();
+    assert_eq!(app.tab.focus, Focus::ModalDeepen);
+    assert_eq!(app.modal_input.value(), "50");
+
+    // Zero deepens nothing, so the prompt stays open.
+    app.modal_input.set_value("0".to_string());
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(app.tab.focus, Focus::ModalDeepen);
+
+    app.modal_input.set_value("20".to_string());
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(app.pending_network_request, Some(NetworkRequest::Deepen { repo_path: path_string, remote_name: "upstream".to_string(), by: 20 }));
+    join_network_worker(&mut app);
+}
+
+// libgit2's local transport refuses shallow fetches, so the origin is served over git://.
+struct Daemon {
+    child: std::process::Child,
+    url: String,
+}
+
+impl Drop for Daemon {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn serve(origin: &Path) -> Option<Daemon> {
+    let port = std::net::TcpListener::bind("127.0.0.1:0").ok()?.local_addr().ok()?.port();
+    let base = origin.parent()?;
+    let child = std::process::Command::new("git")
+        .args(["daemon", "--export-all", "--reuseaddr", "--listen=127.0.0.1"])
+        .arg(format!("--port={port}"))
+        .arg(format!("--base-path={}", base.display()))
+        .arg(base)
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()
+        .ok()?;
+    let daemon = Daemon { child, url: format!("git://127.0.0.1:{port}/{}", origin.file_name()?.to_str()?) };
+    (0..50)
+        .any(|_| {
+            std::net::TcpStream::connect(("127.0.0.1", port)).is_ok() || {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                false
+            }
+        })
+        .then_some(daemon)
+}
+
+#[test]
+fn deepening_fetches_older_history_and_walks_the_graph_again() {
+    let (origin_path, origin) = temp_repo("deepen-origin");
+    for index in 1..=6 {
+        commit_with_content(&origin, "file.txt", &format!("{index}\n"), &format!("c{index}"));
+    }
+    let clone_path = origin_path.with_extension("clone");
+    let cloned =
+        serve(&origin_path).filter(|daemon| std::process::Command::new("git").args(["clone", "--depth=2"]).arg(&daemon.url).arg(&clone_path).output().is_ok_and(|output| output.status.success()));
+    let Some(daemon) = cloned else {
+        eprintln!("skipped: the git CLI is needed to serve the origin and make a shallow clone");
+        let _ = fs::remove_dir_all(origin_path);
+        return;
+    };
+
+    let clone = Repository::open(&clone_path).unwrap();
+    clone.config().unwrap().set_str("user.name", "Test User").unwrap();
+    clone.config().unwrap().set_str("user.email", "test@example.com").unwrap();
+
+    let walks = clone_path.with_extension("walks");
+    let path_string = clone_path.display().to_string();
+    let mut app = App {
+        walk_cache_dir: Some(walks.clone()),
+        recent_save_path: Some(clone_path.with_extension("recent.json")),
+        journal_save_path: Some(clone_path.with_extension("journal.json")),
+        tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(clone)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
+    let cache_path = walk_cache_path(&walks, &path_string);
+    fs::create_dir_all(&walks).unwrap();
+    fs::write(&cache_path, b"stale").unwrap();
+
+    app.on_deepen_history();
+    app.modal_input.set_value("2".to_string());
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    let handle = app.network_handle.take().unwrap();
+    let result = handle.join().unwrap();
+    drop(daemon);
+    assert!(matches!(result, NetworkResult::Success), "{result:?}");
+    app.handle_network_result(result);
+
+    // The stored walk is gone and the new walk reaches two commits further down.
+    assert!(!cache_path.exists() || fs::read(&cache_path).unwrap() != b"stale");
+    let repo = app.tab.repo.clone().unwrap();
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(10);
+    while !app.tab.graph.is_complete && std::time::Instant::now() < deadline {
+        app.sync(&repo);
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert!(app.tab.graph.is_complete);
+    // Four commits plus the uncommitted row.
+    assert_eq!(app.tab.graph.total, 5);
+    app.stop_graph_service();
+    let _ = fs::remove_dir_all(&walks);
+    let _ = fs::remove_file(clone_path.with_extension("recent.json"));
+    let _ = fs::remove_dir_all(clone_path);
+    let _ = fs::remove_dir_all(origin_path);
+}
+
 #[test]
 fn force_push_uses_configured_default_remote() {
     let (path, repo) = temp_repo("push-default-remote");
@@ -205,7 +336,7 @@ fn force_push_uses_configured_default_remote() {
     let _remote_path = add_local_bare_remote(&repo, "upstream");
     set_default_remote(&repo, "upstream").unwrap();
     let path_string = path.display().to_string();
-    let mut app = App { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
+    let mut app = App { tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
 
     app.on_force_push();
 
@@ -220,7 +351,7 @@ fn push_tags_uses_configured_default_remote() {
     let _remote_path = add_local_bare_remote(&repo, "upstream");
     set_default_remote(&repo, "upstream").unwrap();
     let path_string = path.display().to_string();
-    let mut app = App { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
+    let mut app = App { tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
 
     app.on_push_tags();
 
@@ -233,13 +364,13 @@ fn cherrypick_opens_message_modal_with_prefilled_summary() {
     let (_path, repo) = temp_repo("cherrypick-modal");
     let oid = commit(&repo, "file.txt", "original summary\n\nbody");
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
 
     app.on_cherrypick();
 
-    assert_eq!(app.focus, Focus::ModalCherrypick);
+    assert_eq!(app.tab.focus, Focus::ModalCherrypick);
     assert_eq!(app.pending_cherrypick_oid, Some(oid));
     assert_eq!(app.modal_input.value(), "cherrypicked: original summary");
 }
@@ -249,45 +380,104 @@ fn revert_opens_message_modal_with_prefilled_summary() {
     let (_path, repo) = temp_repo("revert-modal");
     let oid = commit(&repo, "file.txt", "original summary\n\nbody");
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
 
     app.on_revert();
 
-    assert_eq!(app.focus, Focus::ModalRevert);
+    assert_eq!(app.tab.focus, Focus::ModalRevert);
     assert_eq!(app.pending_revert_oid, Some(oid));
     assert_eq!(app.modal_input.value(), "reverted: original summary");
 }
 
+fn merge_commit_fixture(repo: &Repository) -> Oid {
+    commit_with_content(repo, "base.txt", "base\n", "base");
+    checkout_new_branch(repo, "feature");
+    let feature = commit_with_content(repo, "feature.txt", "feature\n", "feature");
+    checkout_branch(repo, "master");
+    let main = commit_with_content(repo, "main.txt", "main\n", "main");
+
+    let feature_commit = repo.find_commit(feature).unwrap();
+    let main_commit = repo.find_commit(main).unwrap();
+    let mut index = repo.index().unwrap();
+    let tree_oid = index.write_tree().unwrap();
+    let tree = repo.find_tree(tree_oid).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, "merge", &tree, &[&main_commit, &feature_commit]).unwrap()
+}
+
 #[test]
-fn revert_rejects_merge_commits_before_opening_modal() {
+fn revert_of_merge_commit_asks_for_mainline_before_message() {
     let (_path, repo) = temp_repo("revert-merge-modal");
-    commit_with_content(&repo, "base.txt", "base\n", "base");
-    checkout_new_branch(&repo, "feature");
-    let feature = commit_with_content(&repo, "feature.txt", "feature\n", "feature");
-    checkout_branch(&repo, "master");
-    let main = commit_with_content(&repo, "main.txt", "main\n", "main");
-
-    let merge = {
-        let feature_commit = repo.find_commit(feature).unwrap();
-        let main_commit = repo.find_commit(main).unwrap();
-        let mut index = repo.index().unwrap();
-        let tree_oid = index.write_tree().unwrap();
-        let tree = repo.find_tree(tree_oid).unwrap();
-        let sig = Signature::now("Test User", "test@example.com").unwrap();
-        repo.commit(Some("HEAD"), &sig, &sig, "merge", &tree, &[&main_commit, &feature_commit]).unwrap()
-    };
+    let merge = merge_commit_fixture(&repo);
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(merge);
-    app.oids.sorted_aliases = vec![NONE, alias];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(merge);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
 
     app.on_revert();
 
-    assert_eq!(app.focus, Focus::ModalError);
-    assert!(app.modal_error_message.contains("merge commits"));
-    assert_eq!(app.pending_revert_oid, None);
+    assert_eq!(app.tab.focus, Focus::ModalMainline);
+    assert_eq!(app.pending_revert_oid, Some(merge));
+    assert_eq!(app.mainline_parents().len(), 2);
+    assert!(app.modal_mainline_preview.is_empty());
+
+    // The second parent lacks main.txt, so the preview against it shows the file as added.
+    app.on_scroll_down();
+    assert_eq!(app.modal_mainline_selected, 1);
+    assert!(app.modal_mainline_preview.iter().any(|change| change.filename == "main.txt"));
+
+    app.on_select();
+
+    assert_eq!(app.tab.focus, Focus::ModalRevert);
+    assert_eq!(app.pending_mainline, Some(2));
+    assert_eq!(app.modal_input.value(), "reverted: merge");
+    assert!(app.modal_mainline_preview.is_empty());
+}
+
+#[test]
+fn cherrypick_mainline_modal_back_clears_pending_state() {
+    let (_path, repo) = temp_repo("cherrypick-merge-modal");
+    let merge = merge_commit_fixture(&repo);
+
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(merge);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+
+    app.on_cherrypick();
+    assert_eq!(app.tab.focus, Focus::ModalMainline);
+    assert_eq!(app.pending_cherrypick_oid, Some(merge));
+
+    app.on_back();
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.pending_cherrypick_oid, None);
+    assert_eq!(app.pending_mainline, None);
+}
+
+#[test]
+fn marked_merge_commits_are_rejected_for_cherrypick_and_revert() {
+    let (_path, repo) = temp_repo("marked-merge");
+    let merge = merge_commit_fixture(&repo);
+
+    for kind in [OperationKind::Cherrypick, OperationKind::Revert] {
+        let repo = Repository::open(repo.path()).unwrap();
+        let mut app = App {
+            tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, graph_marked_oids: vec![merge], ..Default::default() },
+            ..Default::default()
+        };
+        let alias = app.tab.oids.get_alias_by_oid(merge);
+        app.tab.oids.sorted_aliases = vec![NONE, alias];
+
+        if kind == OperationKind::Cherrypick {
+            app.on_cherrypick();
+        } else {
+            app.on_revert();
+        }
+        assert_eq!(app.tab.focus, Focus::ModalError);
+        assert_eq!(app.pending_operation_action, None);
+    }
 }
 
 #[test]
@@ -295,13 +485,13 @@ fn merge_queues_selected_commit_operation() {
     let (_path, repo) = temp_repo("merge-queue");
     let oid = commit(&repo, "file.txt", "merge target");
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
 
     app.on_merge();
 
-    assert_eq!(app.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
     assert_eq!(app.modal_operation_kind, OperationKind::Merge);
     assert_eq!(app.pending_operation_action, Some(PendingOperationAction::Start { kind: OperationKind::Merge, oid }));
 }
@@ -315,23 +505,106 @@ fn revert_state_routes_continue_and_abort_operations() {
 
     assert_eq!(start_revert(&repo, feature, "reverted: feature").unwrap(), RevertOutcome::Conflict);
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.on_revert();
 
-    assert_eq!(app.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
     assert_eq!(app.modal_operation_kind, OperationKind::Revert);
     assert_eq!(app.pending_operation_action, Some(PendingOperationAction::Continue));
 
-    app.focus = Focus::Viewport;
+    app.tab.focus = Focus::Viewport;
     app.pending_operation_action = None;
     app.on_abort_operation();
 
-    assert_eq!(app.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
     assert_eq!(app.modal_operation_kind, OperationKind::Revert);
     assert_eq!(app.pending_operation_action, Some(PendingOperationAction::Abort));
     let _ = fs::remove_dir_all(path);
 }
 
+#[test]
+fn mark_commit_toggles_and_mark_range_fills_the_gap() {
+    let (path, repo) = temp_repo("mark-range");
+    let first = commit_with_content(&repo, "file.txt", "one\n", "one");
+    let second = commit_with_content(&repo, "file.txt", "two\n", "two");
+    let third = commit_with_content(&repo, "file.txt", "three\n", "three");
+    let fourth = commit_with_content(&repo, "file.txt", "four\n", "four");
+
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 4, ..Default::default() }, ..Default::default() };
+    let aliases = [fourth, third, second, first].map(|oid| app.tab.oids.get_alias_by_oid(oid));
+    app.tab.oids.sorted_aliases = vec![NONE, aliases[0], aliases[1], aliases[2], aliases[3]];
+
+    app.on_mark_commit();
+    assert_eq!(app.tab.graph_marked_oids, vec![first]);
+    app.on_mark_commit();
+    assert!(app.tab.graph_marked_oids.is_empty());
+
+    app.on_mark_range();
+    assert_eq!(app.tab.focus, Focus::ModalError);
+
+    app.tab.focus = Focus::Viewport;
+    app.tab.graph_selected = 3;
+    app.on_mark_commit();
+    app.tab.graph_selected = 1;
+    app.on_mark_range();
+    assert_eq!(app.tab.graph_marked_oids, vec![second, fourth, third]);
+
+    app.on_clear_marks();
+    assert!(app.tab.graph_marked_oids.is_empty());
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn cherrypick_with_marked_commits_queues_sequence() {
+    let (path, repo) = temp_repo("cherrypick-marked");
+    let oid = commit(&repo, "file.txt", "marked");
+
+    let mut app = App {
+        tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, graph_marked_oids: vec![oid], ..Default::default() },
+        ..Default::default()
+    };
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+
+    app.on_cherrypick();
+    assert_eq!(app.tab.focus, Focus::ModalBatch);
+    app.confirm_batch();
+
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.modal_operation_kind, OperationKind::Cherrypick);
+    assert_eq!(app.pending_operation_action, Some(PendingOperationAction::StartSequence { kind: OperationKind::Cherrypick }));
+    assert_eq!(app.pending_cherrypick_oid, None);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn paused_sequence_routes_continue_and_abort_operations() {
+    let (path, repo) = temp_repo("sequence-active-operation");
+    commit_with_content(&repo, "file.txt", "base\n", "base");
+    checkout_new_branch(&repo, "feature");
+    let feature = commit_with_content(&repo, "file.txt", "feature\n", "feature");
+    checkout_branch(&repo, "master");
+    commit_with_content(&repo, "file.txt", "main\n", "main");
+
+    let steps = vec![SequenceStep { oid: feature, message: "cherrypicked: feature".to_string() }];
+    assert_eq!(start_sequence(&repo, SequenceKind::Cherrypick, steps).unwrap(), SequenceOutcome::Conflict { oid: feature, remaining: 0 });
+    assert_eq!(App::active_operation_kind(&repo), Some(OperationKind::Cherrypick));
+
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
+    app.on_continue_operation();
+
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.modal_operation_kind, OperationKind::Cherrypick);
+    assert_eq!(app.pending_operation_action, Some(PendingOperationAction::Continue));
+
+    app.tab.focus = Focus::Viewport;
+    app.pending_operation_action = None;
+    app.on_abort_operation();
+
+    assert_eq!(app.pending_operation_action, Some(PendingOperationAction::Abort));
+    let _ = fs::remove_dir_all(path);
+}
+
 #[test]
 fn merge_state_routes_continue_and_abort_operations() {
     let (path, repo) = temp_repo("merge-active-operation");
@@ -343,18 +616,18 @@ fn merge_state_routes_continue_and_abort_operations() {
 
     assert_eq!(start_merge(&repo, feature).unwrap(), MergeOutcome::Conflict);
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.on_continue_operation();
 
-    assert_eq!(app.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
     assert_eq!(app.modal_operation_kind, OperationKind::Merge);
     assert_eq!(app.pending_operation_action, Some(PendingOperationAction::Continue));
 
-    app.focus = Focus::Viewport;
+    app.tab.focus = Focus::Viewport;
     app.pending_operation_action = None;
     app.on_abort_operation();
 
-    assert_eq!(app.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
     assert_eq!(app.modal_operation_kind, OperationKind::Merge);
     assert_eq!(app.pending_operation_action, Some(PendingOperationAction::Abort));
     let _ = fs::remove_dir_all(path);
@@ -366,11 +639,11 @@ fn create_branch_from_reflog_uses_reflog_commit_target() {
     let graph_oid = commit(&repo, "graph.txt", "graph");
     let reflog_oid = commit(&repo, "reflog.txt", "reflog");
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Reflogs, graph_selected: 1, ..Default::default() };
-    let graph_alias = app.oids.get_alias_by_oid(graph_oid);
-    let reflog_alias = app.oids.get_alias_by_oid(reflog_oid);
-    app.oids.sorted_aliases = vec![NONE, graph_alias, reflog_alias];
-    app.reflogs.entries.push(HeadReflogAliasEntry {
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Reflogs, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let graph_alias = app.tab.oids.get_alias_by_oid(graph_oid);
+    let reflog_alias = app.tab.oids.get_alias_by_oid(reflog_oid);
+    app.tab.oids.sorted_aliases = vec![NONE, graph_alias, reflog_alias];
+    app.tab.reflogs.entries.push(HeadReflogAliasEntry {
         selector: "HEAD@{0}".to_string(),
         old_oid: graph_oid,
         new_oid: reflog_oid,
@@ -381,7 +654,7 @@ fn create_branch_from_reflog_uses_reflog_commit_target() {
 
     app.on_create_branch();
 
-    assert_eq!(app.focus, Focus::ModalCreateBranch);
+    assert_eq!(app.tab.focus, Focus::ModalCreateBranch);
     assert_eq!(app.selected_branch_target_oid(), Some(reflog_oid));
 }
 
@@ -393,12 +666,12 @@ fn rename_branch_from_pane_opens_prefilled_modal_for_local_branch() {
     repo.branch("feature", &target, false).unwrap();
     drop(target);
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() };
-    app.branches.sorted = vec![(1, "feature".to_string())];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
+    app.tab.branches.sorted = vec![(1, "feature".to_string())];
 
     app.on_rename_branch();
 
-    assert_eq!(app.focus, Focus::ModalRenameBranch);
+    assert_eq!(app.tab.focus, Focus::ModalRenameBranch);
     assert_eq!(app.modal_rename_branch_source.as_deref(), Some("feature"));
     assert_eq!(app.modal_input.value(), "feature");
 }
@@ -406,12 +679,12 @@ fn rename_branch_from_pane_opens_prefilled_modal_for_local_branch() {
 #[test]
 fn rename_branch_from_pane_rejects_remote_branch() {
     let (_path, repo) = temp_repo("rename-pane-remote");
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() };
-    app.branches.sorted = vec![(1, "origin/feature".to_string())];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
+    app.tab.branches.sorted = vec![(1, "origin/feature".to_string())];
 
     app.on_rename_branch();
 
-    assert_eq!(app.focus, Focus::ModalError);
+    assert_eq!(app.tab.focus, Focus::ModalError);
     assert!(app.modal_error_message.contains("only local branches"));
     assert_eq!(app.modal_rename_branch_source, None);
 }
@@ -424,14 +697,14 @@ fn rename_branch_from_graph_single_local_label_opens_modal() {
     repo.branch("feature", &target, false).unwrap();
     drop(target);
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
-    app.branches.sorted = vec![(alias, "feature".to_string())];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+    app.tab.branches.sorted = vec![(alias, "feature".to_string())];
 
     app.on_rename_branch();
 
-    assert_eq!(app.focus, Focus::ModalRenameBranch);
+    assert_eq!(app.tab.focus, Focus::ModalRenameBranch);
     assert_eq!(app.modal_rename_branch_source.as_deref(), Some("feature"));
 }
 
@@ -444,20 +717,20 @@ fn rename_branch_from_graph_multiple_local_labels_uses_branch_choice_modal() {
     repo.branch("topic", &target, false).unwrap();
     drop(target);
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
-    app.branches.sorted = vec![(alias, "feature".to_string()), (alias, "topic".to_string())];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+    app.tab.branches.sorted = vec![(alias, "feature".to_string()), (alias, "topic".to_string())];
 
     app.on_rename_branch();
 
-    assert_eq!(app.focus, Focus::ModalSolo);
+    assert_eq!(app.tab.focus, Focus::ModalSolo);
     assert_eq!(app.modal_branch_action, BranchModalAction::Rename);
 
     app.modal_solo_selected = 1;
     app.on_select();
 
-    assert_eq!(app.focus, Focus::ModalRenameBranch);
+    assert_eq!(app.tab.focus, Focus::ModalRenameBranch);
     assert_eq!(app.modal_rename_branch_source.as_deref(), Some("topic"));
     assert_eq!(app.modal_input.value(), "topic");
 }
@@ -468,14 +741,14 @@ fn rename_branch_from_graph_rejects_remote_only_labels() {
     let oid = commit(&repo, "file.txt", "initial");
     repo.reference("refs/remotes/origin/feature", oid, true, "remote").unwrap();
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE, alias];
-    app.branches.sorted = vec![(alias, "origin/feature".to_string())];
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+    app.tab.branches.sorted = vec![(alias, "origin/feature".to_string())];
 
     app.on_rename_branch();
 
-    assert_eq!(app.focus, Focus::ModalError);
+    assert_eq!(app.tab.focus, Focus::ModalError);
     assert!(app.modal_error_message.contains("only local branches"));
 }
 
@@ -490,14 +763,13 @@ fn auth_required_network_result_opens_auth_modal() {
     };
     let mut app = App {
         pending_network_request: Some(NetworkRequest::Fetch { repo_path: ".".to_string(), remote_name: "origin".to_string() }),
-        viewport: Viewport::Graph,
-        focus: Focus::ModalNetworkProgress,
+        tab: RepoTab { viewport: Viewport::Graph, focus: Focus::ModalNetworkProgress, ..Default::default() },
         ..Default::default()
     };
 
     app.handle_network_result(NetworkResult::AuthRequired(AuthRequired { challenge: challenge.clone(), rejected: Vec::new() }));
 
-    assert_eq!(app.focus, Focus::ModalAuth);
+    assert_eq!(app.tab.focus, Focus::ModalAuth);
     assert_eq!(app.pending_auth_prompt, Some(challenge));
     assert_eq!(app.auth_username_input.value(), "octo");
     assert_eq!(app.auth_input_field, AuthInputField::Secret);
@@ -509,7 +781,7 @@ fn submitting_https_auth_stores_session_secret_and_retries_request() {
     let mut app = App {
         pending_network_request: Some(NetworkRequest::Fetch { repo_path: "/tmp/missing".to_string(), remote_name: "origin".to_string() }),
         pending_auth_prompt: Some(challenge.clone()),
-        focus: Focus::ModalAuth,
+        tab: RepoTab { focus: Focus::ModalAuth, ..Default::default() },
         ..Default::default()
     };
     app.auth_username_input.set_value("octo");
@@ -519,7 +791,7 @@ fn submitting_https_auth_stores_session_secret_and_retries_request() {
 
     assert_eq!(app.pending_auth_prompt, None);
     assert_eq!(app.network_auth_attempts, 1);
-    assert_eq!(app.focus, Focus::ModalNetworkProgress);
+    assert_eq!(app.tab.focus, Focus::ModalNetworkProgress);
     let handle = app.network_handle.take().expect("retry should start a worker");
     let _ = handle.join();
     assert!(app.auth_session.has_secret_for(&challenge, Some("octo")));
@@ -536,7 +808,7 @@ fn cancelling_auth_prompt_clears_pending_network_state() {
             operation: "Fetch".to_string(),
             key_path: None,
         }),
-        focus: Focus::ModalAuth,
+        tab: RepoTab { focus: Focus::ModalAuth, ..Default::default() },
         ..Default::default()
     };
 
@@ -544,5 +816,71 @@ fn cancelling_auth_prompt_clears_pending_network_state() {
 
     assert!(app.pending_network_request.is_none());
     assert!(app.pending_auth_prompt.is_none());
-    assert_eq!(app.focus, Focus::ModalError);
+    assert_eq!(app.tab.focus, Focus::ModalError);
+}
+
+#[test]
+fn soft_reset_from_tags_pane_previews_then_moves_branch() {
+    let (path, repo) = temp_repo("soft-reset-tag");
+    let tagged = commit_with_content(&repo, "file.txt", "tagged\n", "tagged");
+    let head = commit_with_content(&repo, "file.txt", "head\n", "head");
+    repo.tag_lightweight("v1.0.0", repo.find_commit(tagged).unwrap().as_object(), false).unwrap();
+
+    let mut app = App {
+        journal_save_path: Some(path.with_extension("journal.json")),
+        recent_save_path: Some(path.with_extension("recent.json")),
+        walk_cache_dir: Some(path.with_extension("walks")),
+        tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Tags, ..Default::default() },
+        ..Default::default()
+    };
+    let alias = app.tab.oids.get_alias_by_oid(tagged);
+    app.tab.tags.sorted = vec![(alias, "v1.0.0".to_string())];
+
+    app.on_soft_reset();
+
+    assert_eq!(app.tab.focus, Focus::ModalReset);
+    assert_eq!(app.modal_reset_kind, Command::SoftReset);
+    assert_eq!(app.modal_reset_target, Some(tagged));
+    assert_eq!(app.modal_reset_label, "v1.0.0");
+    assert_eq!(app.modal_reset_preview.unreachable, vec![head]);
+
+    app.confirm_reset();
+
+    let repo = app.tab.repo.as_ref().unwrap();
+    assert_eq!(repo.head().unwrap().target(), Some(tagged));
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "head\n");
+    assert!(repo.statuses(None).unwrap().iter().all(|entry| entry.status() == git2::Status::INDEX_MODIFIED));
+}
+
+#[test]
+fn hard_reset_from_reflog_lists_overwritten_files_and_back_cancels() {
+    let (path, repo) = temp_repo("hard-reset-reflog");
+    let older = commit_with_content(&repo, "file.txt", "older\n", "older");
+    let newer = commit_with_content(&repo, "file.txt", "newer\n", "newer");
+    fs::write(path.join("file.txt"), "local edit\n").unwrap();
+
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Reflogs, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(older);
+    app.tab.reflogs.entries.push(HeadReflogAliasEntry {
+        selector: "HEAD@{1}".to_string(),
+        old_oid: older,
+        new_oid: older,
+        new_alias: alias,
+        message: "commit: older".to_string(),
+        time: git2::Time::new(1, 0),
+    });
+
+    app.on_hard_reset();
+
+    assert_eq!(app.tab.focus, Focus::ModalReset);
+    assert_eq!(app.modal_reset_label, "HEAD@{1}");
+    assert_eq!(app.modal_reset_preview.unreachable, vec![newer]);
+    assert_eq!(app.modal_reset_preview.overwritten, vec!["file.txt".to_string()]);
+
+    app.on_back();
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.modal_reset_target, None);
+    assert_eq!(app.tab.repo.as_ref().unwrap().head().unwrap().target(), Some(newer));
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "local edit\n");
 }
@@ -0,0 +1,181 @@
+use super::*;
+use crate::app::app::RepoTab;
+use crate::helpers::keymap::{Keymaps, ModeKeymap};
+use indexmap::IndexMap;
+
+fn key(ch: char) -> KeyBinding {
+    KeyBinding::new(KeyCode::Char(ch), KeyModifiers::NONE)
+}
+
+fn sequence(text: &str) -> KeyBinding {
+    KeyBinding::sequence(&text.chars().map(key).collect::<Vec<_>>()).unwrap()
+}
+
+fn press(app: &mut App, ch: char) {
+    app.handle_key_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+}
+
+fn keymaps() -> Keymaps {
+    let normal: ModeKeymap = IndexMap::from([
+        (key('\\'), Command::Leader),
+        (sequence("\\p"), Command::CommandPalette),
+        (key('g'), Command::ActionMode),
+        (sequence("gg"), Command::GoToBeginning),
+        (key(':'), Command::CommandPalette),
+        (KeyBinding::new(KeyCode::Char('a'), KeyModifiers::CONTROL), Command::ActionMode),
+    ]);
+    let action: ModeKeymap = IndexMap::from([(sequence("dd"), Command::CommandPalette)]);
+    IndexMap::from([(InputMode::Normal, normal), (InputMode::Action, action)])
+}
+
+fn app() -> App {
+    App { keymaps: keymaps(), tab: RepoTab { viewport: Viewport::Graph, ..Default::default() }, ..Default::default() }
+}
+
+#[test]
+fn sequence_waits_for_its_last_key() {
+    let mut app = app();
+
+    press(&mut app, '\\');
+    assert_eq!(app.pending_keys, vec![key('\\')]);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+
+    press(&mut app, 'p');
+    assert_eq!(app.tab.focus, Focus::ModalCommandPalette);
+    assert!(app.pending_keys.is_empty());
+    assert_eq!(app.pending_keys_at, None);
+}
+
+#[test]
+fn esc_abandons_a_sequence_and_other_keys_start_over() {
+    let mut app = app();
+
+    press(&mut app, '\\');
+    app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert!(app.pending_keys.is_empty());
+    assert_eq!(app.tab.focus, Focus::Viewport);
+
+    press(&mut app, '\\');
+    press(&mut app, ':');
+    assert!(app.pending_keys.is_empty());
+    assert_eq!(app.tab.focus, Focus::ModalCommandPalette);
+}
+
+#[test]
+fn timeout_runs_the_keys_bound_on_their_own_but_not_the_leader() {
+    let mut app = app();
+
+    press(&mut app, '\\');
+    app.pending_keys_at = Instant::now().checked_sub(KEY_SEQUENCE_TIMEOUT * 2);
+    app.expire_pending_keys();
+    assert!(app.pending_keys.is_empty());
+    assert_eq!(app.tab.focus, Focus::Viewport);
+
+    press(&mut app, 'g');
+    app.expire_pending_keys();
+    assert_eq!(app.pending_keys, vec![key('g')]);
+
+    app.pending_keys_at = Instant::now().checked_sub(KEY_SEQUENCE_TIMEOUT * 2);
+    app.expire_pending_keys();
+    assert!(app.pending_keys.is_empty());
+    assert_eq!(app.mode, InputMode::Action);
+}
+
+#[test]
+fn action_mode_lasts_until_the_sequence_completes() {
+    let mut app = app();
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
+    press(&mut app, 'd');
+    assert_eq!(app.mode, InputMode::Action);
+
+    press(&mut app, 'd');
+    assert_eq!(app.mode, InputMode::Normal);
+    assert_eq!(app.tab.focus, Focus::ModalCommandPalette);
+}
+
+#[test]
+fn leader_command_opens_the_which_key_popup() {
+    let mut app = app();
+
+    app.dispatch_command(&Command::Leader);
+
+    assert_eq!(app.pending_keys, vec![key('\\')]);
+    assert_eq!(app.which_key_rows(), vec![("p".to_string(), "Command palette".to_string())]);
+}
+
+fn count_app() -> App {
+    let normal: ModeKeymap =
+        IndexMap::from([(key('j'), Command::ScrollDown), (key('}'), Command::ScrollDownBranch), (key('1'), Command::ActionMode), (key('0'), Command::CommandPalette), (key(';'), Command::Count)]);
+    let mut app = App { keymaps: IndexMap::from([(InputMode::Normal, normal)]), tab: RepoTab { viewport: Viewport::Graph, ..Default::default() }, ..Default::default() };
+    app.tab.graph.total = 100;
+    app
+}
+
+#[test]
+fn count_repeats_a_graph_motion() {
+    let mut app = count_app();
+
+    press(&mut app, ';');
+    assert_eq!(app.pending_count, Some(0));
+    press(&mut app, '1');
+    press(&mut app, '0');
+    assert_eq!(app.pending_count, Some(10));
+    press(&mut app, 'j');
+    assert_eq!(app.tab.graph_selected, 10);
+    assert_eq!(app.pending_count, None);
+    assert_eq!(app.mode, InputMode::Normal);
+}
+
+#[test]
+fn digits_without_a_motion_run_their_own_bindings() {
+    let mut app = count_app();
+
+    press(&mut app, '0');
+    assert_eq!(app.pending_count, None);
+    assert_eq!(app.tab.focus, Focus::ModalCommandPalette);
+
+    let mut app = count_app();
+    press(&mut app, '1');
+    assert_eq!(app.pending_count, None);
+    assert_eq!(app.mode, InputMode::Action);
+
+    let mut app = count_app();
+    app.tab.focus = Focus::Branches;
+    press(&mut app, '1');
+    assert_eq!(app.pending_count, None);
+    assert_eq!(app.mode, InputMode::Action);
+}
+
+#[test]
+fn counted_lookups_run_one_after_another_and_record_one_jump() {
+    let mut app = count_app();
+    let (tx, rx) = std::sync::mpsc::channel();
+    app.tab.graph_tx = Some(tx);
+
+    press(&mut app, '3');
+    press(&mut app, '}');
+    assert!(rx.try_recv().is_ok());
+    assert!(rx.try_recv().is_err());
+    assert_eq!(app.tab.graph_repeat, Some((Command::ScrollDownBranch, 2)));
+
+    app.tab.graph_repeat = None;
+    app.continue_graph_repeat(Command::ScrollDownBranch, 2);
+    assert!(rx.try_recv().is_ok());
+    assert_eq!(app.tab.graph_repeat, Some((Command::ScrollDownBranch, 1)));
+    assert!(app.tab.jump_list.is_empty());
+}
+
+#[test]
+fn a_count_is_dropped_by_keys_that_are_not_motions() {
+    let mut app = count_app();
+
+    press(&mut app, ';');
+    press(&mut app, '1');
+    assert_eq!(app.pending_count, Some(1));
+    assert_eq!(app.mode, InputMode::Normal);
+    press(&mut app, 'q');
+    assert_eq!(app.pending_count, None);
+    press(&mut app, 'j');
+    assert_eq!(app.tab.graph_selected, 1);
+}
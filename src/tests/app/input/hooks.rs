@@ -0,0 +1,195 @@
+use super::*;
+use crate::app::app::RepoTab;
+use crate::git::actions::hooks::hooks_dir;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-hooks-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    fs::write(path.join("file.txt"), "content\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("file.txt")).unwrap();
+    index.write().unwrap();
+    (path, repo)
+}
+
+#[cfg(unix)]
+fn write_hook(repo: &Repository, hook: Hook, script: &str) {
+    use std::os::unix::fs::PermissionsExt;
+    let dir = hooks_dir(repo);
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(hook.name());
+    fs::write(&path, format!("#!/bin/sh\n{script}\n")).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+// Saved state lives beside the repository so it never touches the user config.
+fn hooks_app(path: &Path, repo: Repository, message: &str) -> App {
+    let mut app = App {
+        journal_save_path: Some(path.with_extension("journal.json")),
+        recent_save_path: Some(path.with_extension("recent.json")),
+        walk_cache_dir: Some(path.with_extension("walks")),
+        tab: RepoTab {
+            path: Some(path.display().to_string()),
+            repo: Some(Rc::new(repo)),
+            viewport: Viewport::Graph,
+            focus: Focus::ModalCommit,
+            name: "Test User".to_string(),
+            email: "test@example.com".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    app.modal_input.set_value(message);
+    app
+}
+
+fn wait_for_hooks(app: &mut App) {
+    while app.hook_task.is_some() {
+        thread::sleep(Duration::from_millis(5));
+        app.poll_hook_task();
+    }
+}
+
+fn cleanup(path: &Path) {
+    let _ = fs::remove_file(path.with_extension("journal.json"));
+    let _ = fs::remove_file(path.with_extension("recent.json"));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[cfg(unix)]
+#[test]
+fn failing_pre_commit_keeps_the_prompt_and_skipping_commits() {
+    let (path, repo) = temp_repo("pre-commit");
+    write_hook(&repo, Hook::PreCommit, "echo 'lint failed'\nexit 1");
+    let mut app = hooks_app(&path, repo, "message");
+
+    app.confirm_commit();
+    assert_eq!(app.tab.focus, Focus::ModalHookProgress);
+    wait_for_hooks(&mut app);
+
+    assert_eq!(app.tab.focus, Focus::ModalError);
+    assert_eq!(app.modal_error_return_focus, Focus::ModalCommit);
+    assert!(app.modal_error_message.contains("lint failed"));
+    assert_eq!(app.modal_input.value(), "message");
+    assert!(app.tab.repo.as_ref().unwrap().head().is_err());
+
+    app.tab.focus = Focus::ModalCommit;
+    app.on_skip_hooks();
+    app.confirm_commit();
+    wait_for_hooks(&mut app);
+
+    assert!(!app.skip_hooks_once);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    let head = app.tab.repo.as_ref().unwrap().head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("message"));
+    cleanup(&path);
+}
+
+#[cfg(unix)]
+#[test]
+fn reloading_while_a_hook_runs_keeps_the_task_and_its_follow_up() {
+    let (path, repo) = temp_repo("reload");
+    write_hook(&repo, Hook::PreCommit, "sleep 0.3");
+    let mut app = hooks_app(&path, repo, "message");
+
+    app.confirm_commit();
+    app.on_reload();
+    assert_eq!(app.tab.focus, Focus::ModalHookProgress);
+    assert!(app.hook_task.is_some());
+    wait_for_hooks(&mut app);
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    let head = app.tab.repo.as_ref().unwrap().head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("message"));
+    cleanup(&path);
+}
+
+#[cfg(unix)]
+#[test]
+fn commit_uses_the_message_left_by_commit_msg() {
+    let (path, repo) = temp_repo("commit-msg");
+    write_hook(&repo, Hook::CommitMsg, "printf 'checked: %s\\n' \"$(cat \"$1\")\" > \"$1\"");
+    write_hook(&repo, Hook::PostCommit, "touch post-commit-ran");
+    let mut app = hooks_app(&path, repo, "message");
+
+    app.confirm_commit();
+    wait_for_hooks(&mut app);
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    let head = app.tab.repo.as_ref().unwrap().head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("checked: message"));
+    assert!(path.join("post-commit-ran").exists());
+    cleanup(&path);
+}
+
+#[cfg(unix)]
+fn wait_for_commit(app: &mut App) {
+    while app.commit_task.is_some() {
+        thread::sleep(Duration::from_millis(5));
+        app.poll_commit_task();
+    }
+}
+
+// A stand-in for gpg that answers after a pause, or fails like a missing key.
+#[cfg(unix)]
+fn fake_gpg(repo: &Repository, path: &Path, script: &str) {
+    use std::os::unix::fs::PermissionsExt;
+    let program = path.with_extension("gpg");
+    fs::write(&program, format!("#!/bin/sh\ncat > /dev/null\n{script}\n")).unwrap();
+    fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+    let mut config = repo.config().unwrap();
+    config.set_bool("commit.gpgsign", true).unwrap();
+    config.set_str("gpg.program", &program.display().to_string()).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn signed_commits_are_made_on_a_worker_and_report_signing_errors() {
+    let (path, repo) = temp_repo("signed");
+    fake_gpg(&repo, &path, "sleep 0.2\nprintf -- '-----BEGIN PGP SIGNATURE-----\\nfake\\n-----END PGP SIGNATURE-----\\n'");
+    let mut app = hooks_app(&path, repo, "signed");
+
+    app.confirm_commit();
+    assert!(app.commit_task.is_some());
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert!(app.tab.repo.as_ref().unwrap().head().is_err());
+    wait_for_commit(&mut app);
+
+    let repo = app.tab.repo.clone().unwrap();
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.message(), Some("signed"));
+    assert!(repo.extract_signature(&head.id(), None).unwrap().0.as_str().unwrap().contains("fake"));
+    assert!(app.modal_input.value().is_empty());
+
+    fake_gpg(&repo, &path, "echo 'gpg: signing failed: No secret key' >&2\nexit 2");
+    fs::write(path.join("file.txt"), "changed\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("file.txt")).unwrap();
+    index.write().unwrap();
+    app.tab.focus = Focus::ModalCommit;
+    app.modal_input.set_value("second");
+    app.confirm_commit();
+    wait_for_commit(&mut app);
+
+    assert_eq!(app.tab.focus, Focus::ModalError);
+    assert_eq!(app.modal_error_return_focus, Focus::ModalCommit);
+    assert!(app.modal_error_message.contains("No secret key"));
+    assert_eq!(app.modal_input.value(), "second");
+    assert_eq!(repo.head().unwrap().target(), Some(head.id()));
+    let _ = fs::remove_file(path.with_extension("gpg"));
+    cleanup(&path);
+}
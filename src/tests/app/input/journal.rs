@@ -0,0 +1,152 @@
+use super::*;
+use crate::app::app::RepoTab;
+use crate::{core::chunk::NONE, helpers::journal::load_journal_from_path};
+use git2::{BranchType, Oid, Repository, Signature};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-journal-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn commit(repo: &Repository, file: &str, message: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), format!("{message}\n")).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree_oid = index.write_tree().unwrap();
+    let tree = repo.find_tree(tree_oid).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+// Saved state lives beside the repository so it never dirties the worktree.
+fn journal_path(path: &Path) -> PathBuf {
+    path.with_extension("journal.json")
+}
+
+fn journal_app(path: &Path, repo: Repository) -> App {
+    App {
+        journal_save_path: Some(journal_path(path)),
+        recent_save_path: Some(path.with_extension("recent.json")),
+        walk_cache_dir: Some(path.with_extension("walks")),
+        tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() },
+        ..Default::default()
+    }
+}
+
+fn cleanup(path: &Path) {
+    let _ = fs::remove_file(journal_path(path));
+    let _ = fs::remove_file(path.with_extension("recent.json"));
+    let _ = fs::remove_dir_all(path);
+}
+
+fn master_tip(app: &App) -> Oid {
+    app.tab.repo.as_ref().unwrap().refname_to_id("refs/heads/master").unwrap()
+}
+
+#[test]
+fn hard_reset_is_journaled_and_undo_restores_the_branch() {
+    let (path, repo) = temp_repo("hard-reset");
+    let first = commit(&repo, "file.txt", "first");
+    let second = commit(&repo, "file.txt", "second");
+
+    let mut app = journal_app(&path, repo);
+    let alias = app.tab.oids.get_alias_by_oid(first);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+
+    app.on_hard_reset();
+    assert_eq!(app.tab.focus, Focus::ModalReset);
+    app.confirm_reset();
+
+    assert_eq!(master_tip(&app), first);
+    assert_eq!(app.tab.journal.len(), 1);
+    assert_eq!(app.tab.journal[0].operation, Command::HardReset);
+    assert_eq!(load_journal_from_path(&journal_path(&path), app.tab.path.as_deref().unwrap()).len(), 1);
+
+    app.on_undo();
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(master_tip(&app), second);
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "second\n");
+    assert!(app.tab.journal.is_empty());
+    assert!(load_journal_from_path(&journal_path(&path), app.tab.path.as_deref().unwrap()).is_empty());
+    cleanup(&path);
+}
+
+#[test]
+fn undo_with_empty_journal_shows_error() {
+    let (path, repo) = temp_repo("empty");
+    commit(&repo, "file.txt", "first");
+
+    let mut app = journal_app(&path, repo);
+    app.on_undo();
+
+    assert_eq!(app.tab.focus, Focus::ModalError);
+    cleanup(&path);
+}
+
+#[test]
+fn journal_modal_undoes_every_entry_down_to_the_selection() {
+    let (path, repo) = temp_repo("modal");
+    let first = commit(&repo, "file.txt", "first");
+    let second = commit(&repo, "file.txt", "second");
+    repo.branch("topic", &repo.find_commit(second).unwrap(), false).unwrap();
+
+    let mut app = journal_app(&path, repo);
+    let alias = app.tab.oids.get_alias_by_oid(first);
+    app.tab.oids.sorted_aliases = vec![NONE, alias];
+
+    app.on_hard_reset();
+    app.confirm_reset();
+    app.delete_branch_from_ui("topic");
+    assert_eq!(app.tab.journal.iter().map(|entry| entry.operation.clone()).collect::<Vec<_>>(), vec![Command::HardReset, Command::DeleteBranch]);
+
+    app.on_toggle_journal();
+    assert_eq!(app.tab.focus, Focus::ModalJournal);
+    app.on_scroll_down();
+    assert_eq!(app.modal_journal_selected, 1);
+
+    app.on_select();
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert!(app.tab.journal.is_empty());
+    assert_eq!(master_tip(&app), second);
+    assert_eq!(app.tab.repo.as_ref().unwrap().find_branch("topic", BranchType::Local).unwrap().get().target(), Some(second));
+    cleanup(&path);
+}
+
+#[test]
+fn queued_actions_are_journaled_separately() {
+    let (path, repo) = temp_repo("queue");
+    let first = commit(&repo, "file.txt", "first");
+    let mut app = journal_app(&path, repo);
+
+    // A second action starting before the first one finished no longer replaces it.
+    app.begin_journal(Command::CreateBranch);
+    app.tab.repo.as_ref().unwrap().branch("one", &app.tab.repo.as_ref().unwrap().find_commit(first).unwrap(), false).unwrap();
+    app.begin_journal(Command::Tag);
+    app.tab.repo.as_ref().unwrap().tag_lightweight("v1", app.tab.repo.as_ref().unwrap().find_commit(first).unwrap().as_object(), false).unwrap();
+    app.finish_journal();
+
+    assert!(app.tab.pending_journal.is_empty());
+    assert_eq!(app.tab.journal.iter().map(|entry| entry.operation.clone()).collect::<Vec<_>>(), vec![Command::CreateBranch, Command::Tag]);
+    assert_eq!(app.tab.journal[0].refs.iter().map(|journal_ref| journal_ref.name.as_str()).collect::<Vec<_>>(), vec!["refs/heads/one"]);
+    assert_eq!(app.tab.journal[1].refs.iter().map(|journal_ref| journal_ref.name.as_str()).collect::<Vec<_>>(), vec!["refs/tags/v1"]);
+    cleanup(&path);
+}
@@ -0,0 +1,178 @@
+use super::*;
+use crate::app::app::RepoTab;
+use crate::{
+    core::{
+        chunk::NONE,
+        graph_service::{GraphCommand, GraphEvent, GraphLookupResult},
+    },
+    helpers::{
+        keymap::{InputMode, KeyBinding, ModeKeymap},
+        marks::load_marks_from_path,
+    },
+};
+use git2::{Repository, Signature};
+use indexmap::IndexMap;
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-jumps-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    (path, repo)
+}
+
+fn commit_file(repo: &Repository, file: &str, message: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), format!("{message}\n")).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+fn keymaps() -> IndexMap<InputMode, ModeKeymap> {
+    let normal: ModeKeymap = IndexMap::from([
+        (KeyBinding::new(KeyCode::Char('m'), KeyModifiers::NONE), Command::SetMark),
+        (KeyBinding::new(KeyCode::Char('\''), KeyModifiers::NONE), Command::JumpToMark),
+        (KeyBinding::new(KeyCode::Char('o'), KeyModifiers::CONTROL), Command::JumpBack),
+        (KeyBinding::new(KeyCode::Char('f'), KeyModifiers::CONTROL), Command::JumpForward),
+        (KeyBinding::new(KeyCode::Char('G'), KeyModifiers::SHIFT), Command::GoToEnd),
+    ]);
+    IndexMap::from([(InputMode::Normal, normal)])
+}
+
+// Rows are the uncommitted row, then child, parent and root.
+fn history_app(name: &str) -> (App, PathBuf, [Oid; 3]) {
+    let (path, repo) = temp_repo(name);
+    let root = commit_file(&repo, "root.txt", "root");
+    let parent = commit_file(&repo, "parent.txt", "parent");
+    let child = commit_file(&repo, "child.txt", "child");
+
+    let mut app = App {
+        keymaps: keymaps(),
+        marks_save_path: Some(path.with_extension("marks.json")),
+        tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
+    let aliases = [child, parent, root].map(|oid| app.tab.oids.get_alias_by_oid(oid));
+    app.tab.oids.sorted_aliases = vec![NONE, aliases[0], aliases[1], aliases[2]];
+    (app, path, [child, parent, root])
+}
+
+fn press(app: &mut App, code: KeyCode, modifiers: KeyModifiers) {
+    app.handle_key_event(KeyEvent::new(code, modifiers));
+}
+
+#[test]
+fn marks_are_saved_and_jumped_to_by_commit_after_rows_move() {
+    let (mut app, path, [_, parent, root]) = history_app("marks");
+    app.tab.graph_selected = 2;
+
+    press(&mut app, KeyCode::Char('m'), KeyModifiers::NONE);
+    assert_eq!(app.pending_mark, Some(Command::SetMark));
+    press(&mut app, KeyCode::Char('a'), KeyModifiers::NONE);
+    assert_eq!(app.pending_mark, None);
+    assert_eq!(app.tab.graph_marks.get(&'a'), Some(&parent));
+    assert_eq!(load_marks_from_path(app.marks_save_path.as_ref().unwrap(), &path.display().to_string()), app.tab.graph_marks);
+
+    // A reload that drops the child moves the marked commit up a row.
+    let aliases = [parent, root].map(|oid| app.tab.oids.get_alias_by_oid(oid));
+    app.tab.oids.sorted_aliases = vec![NONE, aliases[0], aliases[1]];
+    app.tab.graph_selected = 2;
+    press(&mut app, KeyCode::Char('\''), KeyModifiers::NONE);
+    assert_eq!(app.which_key_rows(), vec![("a".to_string(), format!("{:.7} parent", parent.to_string()))]);
+    press(&mut app, KeyCode::Char('a'), KeyModifiers::NONE);
+
+    assert_eq!(app.tab.graph_selected, 1);
+    assert_eq!(app.tab.jump_list, vec![root]);
+
+    let _ = fs::remove_file(path.with_extension("marks.json"));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn unknown_marks_fail_and_other_keys_cancel() {
+    let (mut app, path, _) = history_app("unknown");
+    app.tab.graph_selected = 1;
+
+    press(&mut app, KeyCode::Char('m'), KeyModifiers::NONE);
+    press(&mut app, KeyCode::Esc, KeyModifiers::NONE);
+    assert_eq!(app.pending_mark, None);
+    assert!(app.tab.graph_marks.is_empty());
+    assert_eq!(app.tab.focus, Focus::Viewport);
+
+    press(&mut app, KeyCode::Char('\''), KeyModifiers::NONE);
+    press(&mut app, KeyCode::Char('z'), KeyModifiers::NONE);
+    assert_eq!(app.tab.focus, Focus::ModalError);
+
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn jump_list_walks_back_and_forward() {
+    let (mut app, path, [child, _, root]) = history_app("jump-list");
+    app.tab.graph_selected = 1;
+
+    press(&mut app, KeyCode::Char('G'), KeyModifiers::SHIFT);
+    assert_eq!(app.tab.graph_selected, 3);
+    assert_eq!(app.tab.jump_list, vec![child]);
+
+    press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL);
+    assert_eq!(app.tab.graph_selected, 1);
+    assert_eq!(app.tab.jump_list, vec![child, root]);
+    assert_eq!(app.tab.jump_index, 0);
+
+    press(&mut app, KeyCode::Char('o'), KeyModifiers::CONTROL);
+    assert_eq!(app.tab.graph_selected, 1);
+
+    press(&mut app, KeyCode::Char('f'), KeyModifiers::CONTROL);
+    assert_eq!(app.tab.graph_selected, 3);
+    press(&mut app, KeyCode::Char('f'), KeyModifiers::CONTROL);
+    assert_eq!(app.tab.graph_selected, 3);
+    assert_eq!(app.tab.jump_index, 1);
+
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn graph_service_jumps_resolve_through_oid_lookups() {
+    let (mut app, path, [child, ..]) = history_app("lookup");
+    let repo = app.tab.repo.clone().unwrap();
+    let (tx, rx) = std::sync::mpsc::channel();
+    let (event_tx, event_rx) = std::sync::mpsc::channel();
+    app.tab.graph_tx = Some(tx);
+    app.tab.graph_rx = Some(event_rx);
+    app.tab.graph.generation = 7;
+    app.tab.graph.total = 10;
+    app.tab.graph_marks.insert('a', child);
+
+    app.jump_to_mark('a');
+    let request_id = match rx.try_recv().unwrap() {
+        GraphCommand::Lookup { request_id, kind: GraphLookupKind::Oid { oid }, .. } => {
+            assert_eq!(oid, child);
+            request_id
+        },
+        other => panic!("expected oid lookup, got {other:?}"),
+    };
+    event_tx.send(GraphEvent::LookupResult { generation: 7, request_id, result: GraphLookupResult::Index(Some(5)) }).unwrap();
+    app.sync(&repo);
+    assert_eq!(app.tab.graph_selected, 5);
+
+    app.jump_to_mark('a');
+    let GraphCommand::Lookup { request_id, .. } = rx.try_recv().unwrap() else { panic!("expected lookup") };
+    event_tx.send(GraphEvent::LookupResult { generation: 7, request_id, result: GraphLookupResult::Index(None) }).unwrap();
+    app.sync(&repo);
+    assert_eq!(app.tab.focus, Focus::ModalError);
+
+    let _ = fs::remove_dir_all(path);
+}
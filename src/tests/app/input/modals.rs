@@ -1,5 +1,5 @@
 use super::*;
-use crate::app::app::{RemoteInputAction, SettingsSelection, SettingsSelectionKind, Viewport};
+use crate::app::app::{RemoteInputAction, RepoTab, SettingsSelection, SettingsSelectionKind, Viewport};
 use crate::core::graph_service::GraphCommand;
 use crate::git::actions::network::NetworkRequest;
 use crate::git::queries::remotes::{GUITAR_DEFAULT_REMOTE_CONFIG, PUSH_DEFAULT_CONFIG};
@@ -57,7 +57,11 @@ fn commit_files(repo: &Repository, files: &[&str], message: &str) {
 fn modal_app(name: &str) -> App {
     let (_path, repo) = temp_repo(name);
     commit_files(&repo, &["src/app/draw/search.rs", "src/app/draw/status.rs", "src/app/draw/stashes.rs"], "files");
-    App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::ModalFileSearch, modal_file_search_return_focus: Focus::Branches, ..Default::default() }
+    App {
+        modal_file_search_return_focus: Focus::Branches,
+        tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::ModalFileSearch, ..Default::default() },
+        ..Default::default()
+    }
 }
 
 fn key(code: KeyCode, modifiers: KeyModifiers) -> KeyEvent {
@@ -75,13 +79,13 @@ fn graph_lane_limit_shortcut_keymaps() -> Keymaps {
 }
 
 fn shutdown_graph_worker(app: &mut App) {
-    if let Some(tx) = app.graph_tx.take() {
+    if let Some(tx) = app.tab.graph_tx.take() {
         let _ = tx.send(GraphCommand::Shutdown);
     }
-    if let Some(cancel) = &app.walker_cancel {
+    if let Some(cancel) = &app.tab.walker_cancel {
         cancel.store(true, std::sync::atomic::Ordering::SeqCst);
     }
-    if let Some(handle) = app.walker_handle.take() {
+    if let Some(handle) = app.tab.walker_handle.take() {
         handle.join().unwrap();
     }
 }
@@ -89,14 +93,14 @@ fn shutdown_graph_worker(app: &mut App) {
 #[test]
 fn file_search_esc_closes_and_restores_prior_focus() {
     let (_path, repo) = temp_repo("esc");
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Reflogs, ..Default::default() };
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Reflogs, ..Default::default() }, ..Default::default() };
     app.on_find_file();
     app.modal_input.set_value("search");
     app.modal_file_search_results.push(crate::git::queries::files::FileSearchResult { path: "src/app/draw/search.rs".to_string(), score: 1, matched_indices: vec![13] });
 
     app.handle_modal_key_event(key(KeyCode::Esc, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::Reflogs);
+    assert_eq!(app.tab.focus, Focus::Reflogs);
     assert!(app.modal_input.value().is_empty());
     assert!(app.modal_file_search_results.is_empty());
 }
@@ -138,8 +142,8 @@ fn file_search_ctrl_and_arrow_keys_move_selection() {
 fn file_search_enter_starts_file_history_search() {
     let mut app = modal_app("enter");
     let (tx, rx) = std::sync::mpsc::channel();
-    app.graph.generation = 5;
-    app.graph_tx = Some(tx);
+    app.tab.graph.generation = 5;
+    app.tab.graph_tx = Some(tx);
     for ch in "search".chars() {
         app.handle_modal_key_event(key(KeyCode::Char(ch), KeyModifiers::NONE));
     }
@@ -147,11 +151,11 @@ fn file_search_enter_starts_file_history_search() {
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
     assert!(app.modal_input.value().is_empty());
-    assert_eq!(app.focus, Focus::Search);
-    assert_eq!(app.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Search);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
     assert!(app.layout_config.is_search);
-    assert_eq!(app.search_path.as_deref(), Some("src/app/draw/search.rs"));
-    assert!(app.search_is_loading);
+    assert_eq!(app.tab.search_path.as_deref(), Some("src/app/draw/search.rs"));
+    assert!(app.tab.search_is_loading);
 
     match rx.try_recv().unwrap() {
         GraphCommand::QueryFileHistory { generation, request_id, path } => {
@@ -181,19 +185,18 @@ fn rename_branch_submit_renames_and_clears_modal_state() {
     drop(target);
 
     let mut app = App {
-        path: Some(path.display().to_string()),
         recent: vec![path.display().to_string()],
-        repo: Some(Rc::new(repo)),
-        viewport: Viewport::Graph,
-        focus: Focus::ModalRenameBranch,
         modal_rename_branch_source: Some("feature".to_string()),
+        journal_save_path: Some(path.with_extension("journal.json")),
+        walk_cache_dir: Some(path.with_extension("walks")),
+        tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::ModalRenameBranch, ..Default::default() },
         ..Default::default()
     };
     app.modal_input.set_value("topic");
 
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert!(app.modal_input.value().is_empty());
     assert_eq!(app.modal_rename_branch_source, None);
 
@@ -211,31 +214,39 @@ fn rename_branch_error_returns_to_input_with_text_preserved() {
     repo.branch("existing", &target, false).unwrap();
     drop(target);
 
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::ModalRenameBranch, modal_rename_branch_source: Some("feature".to_string()), ..Default::default() };
+    let mut app = App {
+        modal_rename_branch_source: Some("feature".to_string()),
+        tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::ModalRenameBranch, ..Default::default() },
+        ..Default::default()
+    };
     app.modal_input.set_value("existing");
 
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::ModalError);
+    assert_eq!(app.tab.focus, Focus::ModalError);
     assert_eq!(app.modal_error_return_focus, Focus::ModalRenameBranch);
     assert_eq!(app.modal_input.value(), "existing");
     assert_eq!(app.modal_rename_branch_source.as_deref(), Some("feature"));
 
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::ModalRenameBranch);
+    assert_eq!(app.tab.focus, Focus::ModalRenameBranch);
     assert_eq!(app.modal_input.value(), "existing");
 }
 
 #[test]
 fn rename_branch_esc_closes_and_clears_modal_state() {
     let (_path, repo) = temp_repo("rename-esc");
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::ModalRenameBranch, modal_rename_branch_source: Some("feature".to_string()), ..Default::default() };
+    let mut app = App {
+        modal_rename_branch_source: Some("feature".to_string()),
+        tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::ModalRenameBranch, ..Default::default() },
+        ..Default::default()
+    };
     app.modal_input.set_value("topic");
 
     app.handle_modal_key_event(key(KeyCode::Esc, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert!(app.modal_input.value().is_empty());
     assert_eq!(app.modal_rename_branch_source, None);
 }
@@ -244,7 +255,12 @@ fn remote_app(name: &str) -> (PathBuf, App) {
     let (path, repo) = temp_repo(name);
     commit_files(&repo, &["file.txt"], "initial");
     let path_string = path.display().to_string();
-    let app = App { path: Some(path_string.clone()), recent: vec![path_string], repo: Some(Rc::new(repo)), viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() };
+    let app = App {
+        tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
+        recent: vec![path_string],
+        walk_cache_dir: Some(path.with_extension("walks")),
+        ..Default::default()
+    };
     (path, app)
 }
 
@@ -256,7 +272,7 @@ fn settings_remote_add_row_opens_add_name_prompt() {
 
     app.on_select();
 
-    assert_eq!(app.focus, Focus::ModalRemoteName);
+    assert_eq!(app.tab.focus, Focus::ModalRemoteName);
     assert_eq!(app.modal_remote_input_action, RemoteInputAction::AddName);
     assert!(app.modal_input.value().is_empty());
 }
@@ -269,7 +285,7 @@ fn settings_remote_row_opens_remote_action_modal() {
 
     app.on_select();
 
-    assert_eq!(app.focus, Focus::ModalRemoteAction);
+    assert_eq!(app.tab.focus, Focus::ModalRemoteAction);
     assert_eq!(app.modal_remote_target.as_deref(), Some("origin"));
     assert_eq!(app.modal_remote_selected, 0);
 }
@@ -283,50 +299,50 @@ fn settings_graph_lane_limit_row_opens_prefilled_prompt() {
 
     app.on_select();
 
-    assert_eq!(app.focus, Focus::ModalGraphLaneLimit);
+    assert_eq!(app.tab.focus, Focus::ModalGraphLaneLimit);
     assert_eq!(app.modal_input.value(), "34");
 }
 
 #[test]
 fn graph_lane_limit_input_ignores_zero_and_invalid_values() {
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::ModalGraphLaneLimit, ..Default::default() };
+    let mut app = App { tab: RepoTab { viewport: Viewport::Settings, focus: Focus::ModalGraphLaneLimit, ..Default::default() }, ..Default::default() };
     app.layout_config.graph_lane_limit = 20;
 
     app.modal_input.set_value("0");
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::ModalGraphLaneLimit);
+    assert_eq!(app.tab.focus, Focus::ModalGraphLaneLimit);
     assert_eq!(app.layout_config.graph_lane_limit, 20);
     assert_eq!(app.modal_input.value(), "0");
 
     app.modal_input.set_value("not-a-number");
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::ModalGraphLaneLimit);
+    assert_eq!(app.tab.focus, Focus::ModalGraphLaneLimit);
     assert_eq!(app.layout_config.graph_lane_limit, 20);
     assert_eq!(app.modal_input.value(), "not-a-number");
 }
 
 #[test]
 fn graph_lane_limit_input_updates_and_saves_value() {
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::ModalGraphLaneLimit, settings_selected: 12, ..Default::default() };
+    let mut app = App { settings_selected: 12, tab: RepoTab { viewport: Viewport::Settings, focus: Focus::ModalGraphLaneLimit, ..Default::default() }, ..Default::default() };
     app.settings_scroll.set(4);
     app.modal_input.set_value("7");
 
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
     assert_eq!(app.layout_config.graph_lane_limit, 7);
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 12);
     assert_eq!(app.settings_scroll.get(), 4);
     assert!(app.modal_input.value().is_empty());
-    assert!(app.graph_tx.is_none());
+    assert!(app.tab.graph_tx.is_none());
 }
 
 #[test]
 fn graph_lane_limit_shortcuts_adjust_and_clamp_without_repo() {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, keymaps: graph_lane_limit_shortcut_keymaps(), ..Default::default() };
+    let mut app = App { keymaps: graph_lane_limit_shortcut_keymaps(), tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout_config.graph_lane_limit = 3;
 
     app.handle_key_event(key(KeyCode::Char('-'), KeyModifiers::NONE));
@@ -339,7 +355,7 @@ fn graph_lane_limit_shortcuts_adjust_and_clamp_without_repo() {
     app.handle_key_event(key(KeyCode::Char('-'), KeyModifiers::NONE));
 
     assert_eq!(app.layout_config.graph_lane_limit, 1);
-    assert!(app.graph_tx.is_none());
+    assert!(app.tab.graph_tx.is_none());
 }
 
 #[test]
@@ -348,13 +364,11 @@ fn graph_lane_limit_shortcut_reloads_open_repo_and_preserves_settings_position()
     commit_files(&repo, &["file.txt"], "initial");
     let repo_path = path.display().to_string();
     let mut app = App {
-        path: Some(repo_path.clone()),
+        tab: RepoTab { path: Some(repo_path.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         recent: vec![repo_path],
-        repo: Some(Rc::new(repo)),
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         settings_selected: 12,
         keymaps: graph_lane_limit_shortcut_keymaps(),
+        walk_cache_dir: Some(path.with_extension("walks")),
         ..Default::default()
     };
     app.settings_scroll.set(4);
@@ -363,12 +377,12 @@ fn graph_lane_limit_shortcut_reloads_open_repo_and_preserves_settings_position()
     app.handle_key_event(key(KeyCode::Char('-'), KeyModifiers::NONE));
 
     assert_eq!(app.layout_config.graph_lane_limit, 19);
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 12);
     assert_eq!(app.settings_scroll.get(), 4);
-    assert!(app.graph_tx.is_some());
-    assert!(app.walker_handle.is_some());
+    assert!(app.tab.graph_tx.is_some());
+    assert!(app.tab.walker_handle.is_some());
 
     shutdown_graph_worker(&mut app);
 }
@@ -379,12 +393,10 @@ fn graph_lane_limit_input_reloads_open_repo_and_preserves_settings_position() {
     commit_files(&repo, &["file.txt"], "initial");
     let repo_path = path.display().to_string();
     let mut app = App {
-        path: Some(repo_path.clone()),
+        tab: RepoTab { path: Some(repo_path.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Settings, focus: Focus::ModalGraphLaneLimit, ..Default::default() },
         recent: vec![repo_path],
-        repo: Some(Rc::new(repo)),
-        viewport: Viewport::Settings,
-        focus: Focus::ModalGraphLaneLimit,
         settings_selected: 12,
+        walk_cache_dir: Some(path.with_extension("walks")),
         ..Default::default()
     };
     app.settings_scroll.set(4);
@@ -394,12 +406,12 @@ fn graph_lane_limit_input_reloads_open_repo_and_preserves_settings_position() {
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
     assert_eq!(app.layout_config.graph_lane_limit, 7);
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 12);
     assert_eq!(app.settings_scroll.get(), 4);
-    assert!(app.graph_tx.is_some());
-    assert!(app.walker_handle.is_some());
+    assert!(app.tab.graph_tx.is_some());
+    assert!(app.tab.walker_handle.is_some());
 
     shutdown_graph_worker(&mut app);
 }
@@ -411,15 +423,15 @@ fn add_remote_flow_creates_remote_and_returns_to_settings() {
 
     app.modal_input.set_value("origin");
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
-    assert_eq!(app.focus, Focus::ModalRemoteUrl);
+    assert_eq!(app.tab.focus, Focus::ModalRemoteUrl);
     assert_eq!(app.modal_remote_input_action, RemoteInputAction::AddUrl);
     assert_eq!(app.modal_remote_name, "origin");
 
     app.modal_input.set_value("https://example.com/repo.git");
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert!(app.modal_input.value().is_empty());
     assert_eq!(Repository::open(path).unwrap().find_remote("origin").unwrap().url(), Some("https://example.com/repo.git"));
 }
@@ -432,7 +444,7 @@ fn add_remote_invalid_name_returns_to_name_prompt_with_text_preserved() {
 
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::ModalError);
+    assert_eq!(app.tab.focus, Focus::ModalError);
     assert_eq!(app.modal_error_return_focus, Focus::ModalRemoteName);
     assert_eq!(app.modal_input.value(), "bad\nname");
 }
@@ -440,20 +452,20 @@ fn add_remote_invalid_name_returns_to_name_prompt_with_text_preserved() {
 #[test]
 fn rename_remote_flow_rewrites_hidden_remote_branch_names() {
     let (path, mut app) = remote_app("rename-remote");
-    app.repo.as_ref().unwrap().remote("origin", "https://example.com/repo.git").unwrap();
+    app.tab.repo.as_ref().unwrap().remote("origin", "https://example.com/repo.git").unwrap();
     {
-        let mut config = app.repo.as_ref().unwrap().config().unwrap();
+        let mut config = app.tab.repo.as_ref().unwrap().config().unwrap();
         config.set_str(GUITAR_DEFAULT_REMOTE_CONFIG, "origin").unwrap();
         config.set_str(PUSH_DEFAULT_CONFIG, "origin").unwrap();
     }
-    let oid = app.repo.as_ref().unwrap().head().unwrap().target().unwrap();
-    app.repo.as_ref().unwrap().reference("refs/remotes/origin/topic", oid, true, "test").unwrap();
-    app.repo.as_ref().unwrap().reference("refs/remotes/other/topic", oid, true, "test").unwrap();
-    app.branches.hidden_branch_names.insert("origin/topic".to_string());
-    app.branches.hidden_branch_names.insert("other/topic".to_string());
+    let oid = app.tab.repo.as_ref().unwrap().head().unwrap().target().unwrap();
+    app.tab.repo.as_ref().unwrap().reference("refs/remotes/origin/topic", oid, true, "test").unwrap();
+    app.tab.repo.as_ref().unwrap().reference("refs/remotes/other/topic", oid, true, "test").unwrap();
+    app.tab.branches.hidden_branch_names.insert("origin/topic".to_string());
+    app.tab.branches.hidden_branch_names.insert("other/topic".to_string());
     app.modal_remote_target = Some("origin".to_string());
     app.modal_remote_input_action = RemoteInputAction::Rename;
-    app.focus = Focus::ModalRemoteName;
+    app.tab.focus = Focus::ModalRemoteName;
     app.modal_input.set_value("upstream");
 
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
@@ -464,18 +476,18 @@ fn rename_remote_flow_rewrites_hidden_remote_branch_names() {
     let config = repo.config().unwrap();
     assert_eq!(config.get_string(GUITAR_DEFAULT_REMOTE_CONFIG).unwrap(), "upstream");
     assert_eq!(config.get_string(PUSH_DEFAULT_CONFIG).unwrap(), "upstream");
-    assert!(app.branches.hidden_branch_names.contains("upstream/topic"));
-    assert!(app.branches.hidden_branch_names.contains("other/topic"));
-    assert!(!app.branches.hidden_branch_names.contains("origin/topic"));
+    assert!(app.tab.branches.hidden_branch_names.contains("upstream/topic"));
+    assert!(app.tab.branches.hidden_branch_names.contains("other/topic"));
+    assert!(!app.tab.branches.hidden_branch_names.contains("origin/topic"));
 }
 
 #[test]
 fn remote_action_sets_selected_remote_as_default() {
     let (path, mut app) = remote_app("set-default");
-    app.repo.as_ref().unwrap().remote("upstream", "https://example.com/repo.git").unwrap();
+    app.tab.repo.as_ref().unwrap().remote("upstream", "https://example.com/repo.git").unwrap();
     app.modal_remote_target = Some("upstream".to_string());
     app.modal_remote_selected = 1;
-    app.focus = Focus::ModalRemoteAction;
+    app.tab.focus = Focus::ModalRemoteAction;
 
     app.on_select();
 
@@ -483,34 +495,34 @@ fn remote_action_sets_selected_remote_as_default() {
     let config = repo.config().unwrap();
     assert_eq!(config.get_string(GUITAR_DEFAULT_REMOTE_CONFIG).unwrap(), "upstream");
     assert_eq!(config.get_string(PUSH_DEFAULT_CONFIG).unwrap(), "upstream");
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
 }
 
 #[test]
 fn edit_remote_fetch_url_flow_updates_url() {
     let (path, mut app) = remote_app("edit-fetch-url");
-    app.repo.as_ref().unwrap().remote("origin", "https://example.com/repo.git").unwrap();
+    app.tab.repo.as_ref().unwrap().remote("origin", "https://example.com/repo.git").unwrap();
     app.modal_remote_target = Some("origin".to_string());
     app.modal_remote_input_action = RemoteInputAction::EditUrl;
-    app.focus = Focus::ModalRemoteUrl;
+    app.tab.focus = Focus::ModalRemoteUrl;
     app.modal_input.set_value("https://example.com/renamed.git");
 
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
 
     assert_eq!(Repository::open(path).unwrap().find_remote("origin").unwrap().url(), Some("https://example.com/renamed.git"));
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
 }
 
 #[test]
 fn edit_remote_empty_push_url_clears_push_url() {
     let (path, mut app) = remote_app("edit-push-url");
-    app.repo.as_ref().unwrap().remote("origin", "https://example.com/repo.git").unwrap();
-    app.repo.as_ref().unwrap().remote_set_pushurl("origin", Some("ssh://example.com/repo.git")).unwrap();
+    app.tab.repo.as_ref().unwrap().remote("origin", "https://example.com/repo.git").unwrap();
+    app.tab.repo.as_ref().unwrap().remote_set_pushurl("origin", Some("ssh://example.com/repo.git")).unwrap();
     app.modal_remote_target = Some("origin".to_string());
     app.modal_remote_input_action = RemoteInputAction::EditPushUrl;
-    app.focus = Focus::ModalRemoteUrl;
+    app.tab.focus = Focus::ModalRemoteUrl;
     app.modal_input.set_value("");
 
     app.handle_modal_key_event(key(KeyCode::Enter, KeyModifiers::NONE));
@@ -521,18 +533,18 @@ fn edit_remote_empty_push_url_clears_push_url() {
 #[test]
 fn delete_remote_confirmation_deletes_remote_and_prunes_hidden_remote_branches() {
     let (path, mut app) = remote_app("delete-remote");
-    app.repo.as_ref().unwrap().remote("origin", "https://example.com/repo.git").unwrap();
+    app.tab.repo.as_ref().unwrap().remote("origin", "https://example.com/repo.git").unwrap();
     {
-        let mut config = app.repo.as_ref().unwrap().config().unwrap();
+        let mut config = app.tab.repo.as_ref().unwrap().config().unwrap();
         config.set_str(GUITAR_DEFAULT_REMOTE_CONFIG, "origin").unwrap();
         config.set_str(PUSH_DEFAULT_CONFIG, "origin").unwrap();
     }
-    let oid = app.repo.as_ref().unwrap().head().unwrap().target().unwrap();
-    app.repo.as_ref().unwrap().reference("refs/remotes/other/topic", oid, true, "test").unwrap();
-    app.branches.hidden_branch_names.insert("origin/topic".to_string());
-    app.branches.hidden_branch_names.insert("other/topic".to_string());
+    let oid = app.tab.repo.as_ref().unwrap().head().unwrap().target().unwrap();
+    app.tab.repo.as_ref().unwrap().reference("refs/remotes/other/topic", oid, true, "test").unwrap();
+    app.tab.branches.hidden_branch_names.insert("origin/topic".to_string());
+    app.tab.branches.hidden_branch_names.insert("other/topic".to_string());
     app.modal_remote_target = Some("origin".to_string());
-    app.focus = Focus::ModalRemoteDelete;
+    app.tab.focus = Focus::ModalRemoteDelete;
 
     app.on_select();
 
@@ -541,10 +553,10 @@ fn delete_remote_confirmation_deletes_remote_and_prunes_hidden_remote_branches()
     let config = repo.config().unwrap();
     assert!(config.get_string(GUITAR_DEFAULT_REMOTE_CONFIG).is_err());
     assert!(config.get_string(PUSH_DEFAULT_CONFIG).is_err());
-    assert!(!app.branches.hidden_branch_names.contains("origin/topic"));
-    assert!(app.branches.hidden_branch_names.contains("other/topic"));
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Settings);
+    assert!(!app.tab.branches.hidden_branch_names.contains("origin/topic"));
+    assert!(app.tab.branches.hidden_branch_names.contains("other/topic"));
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
 }
 
 #[test]
@@ -553,12 +565,12 @@ fn remote_modal_cancel_clears_pending_state_and_returns_to_settings() {
     app.modal_remote_target = Some("origin".to_string());
     app.modal_remote_name = "origin".to_string();
     app.modal_input.set_value("value");
-    app.focus = Focus::ModalRemoteUrl;
+    app.tab.focus = Focus::ModalRemoteUrl;
 
     app.handle_modal_key_event(key(KeyCode::Esc, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
     assert_eq!(app.modal_remote_target, None);
     assert!(app.modal_remote_name.is_empty());
     assert!(app.modal_input.value().is_empty());
@@ -568,16 +580,16 @@ fn remote_modal_cancel_clears_pending_state_and_returns_to_settings() {
 fn selected_remote_fetch_uses_selected_remote_name() {
     let (_path, mut app) = remote_app("fetch-selected");
     {
-        let mut config = app.repo.as_ref().unwrap().config().unwrap();
+        let mut config = app.tab.repo.as_ref().unwrap().config().unwrap();
         config.set_str(GUITAR_DEFAULT_REMOTE_CONFIG, "origin").unwrap();
         config.set_str(PUSH_DEFAULT_CONFIG, "origin").unwrap();
     }
     app.modal_remote_target = Some("upstream".to_string());
     app.modal_remote_selected = 0;
-    app.focus = Focus::ModalRemoteAction;
+    app.tab.focus = Focus::ModalRemoteAction;
 
     app.on_select();
 
-    assert_eq!(app.pending_network_request, Some(NetworkRequest::Fetch { repo_path: app.path.clone().unwrap(), remote_name: "upstream".to_string() }));
-    assert_eq!(app.focus, Focus::ModalNetworkProgress);
+    assert_eq!(app.pending_network_request, Some(NetworkRequest::Fetch { repo_path: app.tab.path.clone().unwrap(), remote_name: "upstream".to_string() }));
+    assert_eq!(app.tab.focus, Focus::ModalNetworkProgress);
 }
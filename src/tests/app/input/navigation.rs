@@ -1,7 +1,8 @@
 use super::*;
-use crate::app::app::{GraphWindowCache, PaneWindowCache};
+use crate::app::app::{GraphWindowCache, PaneWindowCache, RepoTab};
 use crate::core::{
     chunk::{LaneRef, NONE},
+    diff_service::{DiffCommand, DiffEvent},
     graph_service::{GraphCommand, GraphEvent, GraphFileHistoryRow, GraphLookupKind, GraphLookupResult, GraphPane, GraphPaneRow, GraphReflogLabel, GraphRow},
     reflogs::HeadReflogAliasEntry,
 };
@@ -10,9 +11,9 @@ use crate::{
         app::{SettingsSelection, SettingsSelectionKind, SettingsTab},
         state::layout::Layout,
     },
-    git::queries::helpers::FileStatus,
+    git::queries::helpers::{FileChange, FileStatus},
     helpers::{
-        keymap::{Command, InputMode, KeyBinding, KeymapSelection, Keymaps, load_keymaps_from_path},
+        keymap::{Command, InputMode, KeyBinding, KeymapEditError, KeymapSelection, Keymaps, load_keymaps_from_path},
         layout::LayoutConfig,
         localisation::Language,
         symbols::SymbolTheme,
@@ -99,24 +100,25 @@ fn graph_app_with_history() -> (App, git2::Oid, git2::Oid, git2::Oid) {
     let parent_oid = commit_file(&repo, "parent.txt", "parent");
     let child_oid = commit_file(&repo, "child.txt", "child");
 
-    let mut app = App { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
-    let root_alias = app.oids.get_alias_by_oid(root_oid);
-    let parent_alias = app.oids.get_alias_by_oid(parent_oid);
-    let child_alias = app.oids.get_alias_by_oid(child_oid);
-    app.oids.sorted_aliases = vec![NONE, child_alias, parent_alias, root_alias];
+    let mut app =
+        App { tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
+    let root_alias = app.tab.oids.get_alias_by_oid(root_oid);
+    let parent_alias = app.tab.oids.get_alias_by_oid(parent_oid);
+    let child_alias = app.tab.oids.get_alias_by_oid(child_oid);
+    app.tab.oids.sorted_aliases = vec![NONE, child_alias, parent_alias, root_alias];
 
-    app.branches.all.insert(parent_alias, vec!["parent".to_string()]);
-    app.branches.sorted.push((parent_alias, "parent".to_string()));
+    app.tab.branches.all.insert(parent_alias, vec!["parent".to_string()]);
+    app.tab.branches.sorted.push((parent_alias, "parent".to_string()));
 
     (app, root_oid, parent_oid, child_oid)
 }
 
 fn diff_filenames(app: &App) -> Vec<String> {
-    app.current_diff.iter().map(|change| change.filename.clone()).collect()
+    app.tab.current_diff.iter().map(|change| change.filename.clone()).collect()
 }
 
 fn stop_graph_service(app: &mut App) {
-    if let Some(tx) = app.graph_tx.take() {
+    if let Some(tx) = app.tab.graph_tx.take() {
         let _ = tx.send(GraphCommand::Shutdown);
     }
 }
@@ -126,26 +128,25 @@ fn search_history_row(graph_index: usize, oid: git2::Oid) -> GraphFileHistoryRow
 }
 
 fn branch_app() -> App {
-    let mut app = App { path: Some(temp_non_repo_path("branches")), viewport: Viewport::Graph, ..Default::default() };
-    app.branches.sorted = vec![(0, "feature".to_string()), (1, "main".to_string())];
-    app.branches.all.insert(0, vec!["feature".to_string()]);
-    app.branches.all.insert(1, vec!["main".to_string()]);
-    app.oids.sorted_aliases = vec![NONE, 1];
+    let mut app = App { tab: RepoTab { path: Some(temp_non_repo_path("branches")), viewport: Viewport::Graph, ..Default::default() }, ..Default::default() };
+    app.tab.branches.sorted = vec![(0, "feature".to_string()), (1, "main".to_string())];
+    app.tab.branches.all.insert(0, vec!["feature".to_string()]);
+    app.tab.branches.all.insert(1, vec!["main".to_string()]);
+    app.tab.oids.sorted_aliases = vec![NONE, 1];
     app
 }
 
 fn hidden_branches(app: &App) -> Vec<String> {
-    let mut branches: Vec<String> = app.branches.hidden_branch_names.iter().cloned().collect();
+    let mut branches: Vec<String> = app.tab.branches.hidden_branch_names.iter().cloned().collect();
     branches.sort();
     branches
 }
 
 fn directional_focus_app() -> App {
     App {
-        viewport: Viewport::Graph,
-        focus: Focus::Viewport,
         layout: Layout::default(),
         layout_config: LayoutConfig { is_branches: false, is_tags: false, is_stashes: false, is_reflogs: false, is_worktrees: false, is_status: false, is_inspector: false, ..Default::default() },
+        tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     }
 }
@@ -158,10 +159,10 @@ fn directional_focus_moves_left_and_right_between_side_panes_and_viewport() {
     app.layout.graph = Rect::new(20, 0, 40, 20);
 
     app.on_focus_pane_left();
-    assert_eq!(app.focus, Focus::Branches);
+    assert_eq!(app.tab.focus, Focus::Branches);
 
     app.on_focus_pane_right();
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
 }
 
 #[test]
@@ -170,18 +171,18 @@ fn directional_focus_moves_up_and_down_inside_left_stack() {
     app.layout_config.is_branches = true;
     app.layout_config.is_tags = true;
     app.layout_config.is_stashes = true;
-    app.focus = Focus::Tags;
+    app.tab.focus = Focus::Tags;
     app.layout.pane_branches = Rect::new(0, 0, 20, 5);
     app.layout.pane_tags = Rect::new(0, 5, 20, 5);
     app.layout.pane_stashes = Rect::new(0, 10, 20, 5);
     app.layout.graph = Rect::new(20, 0, 40, 15);
 
     app.on_focus_pane_up();
-    assert_eq!(app.focus, Focus::Branches);
+    assert_eq!(app.tab.focus, Focus::Branches);
 
-    app.focus = Focus::Tags;
+    app.tab.focus = Focus::Tags;
     app.on_focus_pane_down();
-    assert_eq!(app.focus, Focus::Stashes);
+    assert_eq!(app.tab.focus, Focus::Stashes);
 }
 
 #[test]
@@ -190,7 +191,7 @@ fn directional_focus_reaches_submodules_then_search_at_bottom_of_left_stack() {
     app.layout_config.is_worktrees = true;
     app.layout_config.is_submodules = true;
     app.layout_config.is_search = true;
-    app.focus = Focus::Worktrees;
+    app.tab.focus = Focus::Worktrees;
     app.layout.pane_worktrees = Rect::new(0, 0, 20, 5);
     app.layout.pane_submodules = Rect::new(0, 5, 20, 5);
     app.layout.pane_search = Rect::new(0, 10, 20, 5);
@@ -198,11 +199,11 @@ fn directional_focus_reaches_submodules_then_search_at_bottom_of_left_stack() {
 
     app.on_focus_pane_down();
 
-    assert_eq!(app.focus, Focus::Submodules);
+    assert_eq!(app.tab.focus, Focus::Submodules);
 
     app.on_focus_pane_down();
 
-    assert_eq!(app.focus, Focus::Search);
+    assert_eq!(app.tab.focus, Focus::Search);
 }
 
 #[test]
@@ -210,19 +211,19 @@ fn directional_focus_moves_up_and_down_inside_right_stack() {
     let mut app = directional_focus_app();
     app.layout_config.is_status = true;
     app.layout_config.is_inspector = true;
-    app.uncommitted.has_conflicts = true;
-    app.focus = Focus::StatusTop;
+    app.tab.uncommitted.has_conflicts = true;
+    app.tab.focus = Focus::StatusTop;
     app.layout.graph = Rect::new(20, 0, 40, 20);
     app.layout.pane_inspector = Rect::new(60, 0, 20, 6);
     app.layout.pane_status_top = Rect::new(60, 6, 20, 7);
     app.layout.pane_status_bottom = Rect::new(60, 13, 20, 7);
 
     app.on_focus_pane_up();
-    assert_eq!(app.focus, Focus::Inspector);
+    assert_eq!(app.tab.focus, Focus::Inspector);
 
-    app.focus = Focus::StatusTop;
+    app.tab.focus = Focus::StatusTop;
     app.on_focus_pane_down();
-    assert_eq!(app.focus, Focus::StatusBottom);
+    assert_eq!(app.tab.focus, Focus::StatusBottom);
 }
 
 #[test]
@@ -230,61 +231,61 @@ fn directional_focus_chooses_nearest_perpendicular_center() {
     let mut app = directional_focus_app();
     app.layout_config.is_status = true;
     app.layout_config.is_inspector = true;
-    app.graph_selected = 1;
+    app.tab.graph_selected = 1;
     app.layout.graph = Rect::new(20, 0, 40, 20);
     app.layout.pane_inspector = Rect::new(60, 0, 20, 6);
     app.layout.pane_status_top = Rect::new(60, 6, 20, 7);
 
     app.on_focus_pane_right();
 
-    assert_eq!(app.focus, Focus::StatusTop);
+    assert_eq!(app.tab.focus, Focus::StatusTop);
 }
 
 #[test]
 fn directional_focus_noops_for_strict_diagonal_candidate() {
     let mut app = directional_focus_app();
     app.layout_config.is_branches = true;
-    app.focus = Focus::Branches;
+    app.tab.focus = Focus::Branches;
     app.layout.pane_branches = Rect::new(0, 0, 10, 5);
     app.layout.graph = Rect::new(20, 10, 40, 10);
 
     app.on_focus_pane_right();
 
-    assert_eq!(app.focus, Focus::Branches);
+    assert_eq!(app.tab.focus, Focus::Branches);
 }
 
 #[test]
 fn directional_focus_noops_in_settings_and_without_candidate() {
     let mut settings = directional_focus_app();
-    settings.viewport = Viewport::Settings;
+    settings.tab.viewport = Viewport::Settings;
     settings.layout_config.is_branches = true;
     settings.layout.pane_branches = Rect::new(0, 0, 20, 20);
     settings.layout.graph = Rect::new(20, 0, 40, 20);
 
     settings.on_focus_pane_left();
-    assert_eq!(settings.focus, Focus::Viewport);
+    assert_eq!(settings.tab.focus, Focus::Viewport);
 
     let mut splash = directional_focus_app();
-    splash.viewport = Viewport::Splash;
+    splash.tab.viewport = Viewport::Splash;
     splash.layout_config.is_branches = true;
     splash.layout.pane_branches = Rect::new(0, 0, 20, 20);
     splash.layout.graph = Rect::new(20, 0, 40, 20);
 
     splash.on_focus_pane_left();
-    assert_eq!(splash.focus, Focus::Viewport);
+    assert_eq!(splash.tab.focus, Focus::Viewport);
 
     let mut solo = directional_focus_app();
     solo.layout.graph = Rect::new(20, 0, 40, 20);
 
     solo.on_focus_pane_left();
-    assert_eq!(solo.focus, Focus::Viewport);
+    assert_eq!(solo.tab.focus, Focus::Viewport);
 }
 
 #[test]
 fn solo_branch_from_pane_keeps_selected_as_only_visible() {
     let mut app = branch_app();
-    app.focus = Focus::Branches;
-    app.branches_selected = 1;
+    app.tab.focus = Focus::Branches;
+    app.tab.branches_selected = 1;
 
     app.on_solo_branch();
 
@@ -294,8 +295,8 @@ fn solo_branch_from_pane_keeps_selected_as_only_visible() {
 #[test]
 fn toggle_visible_branch_adds_selected_branch_to_hidden_layer() {
     let mut app = branch_app();
-    app.focus = Focus::Branches;
-    app.branches_selected = 1;
+    app.tab.focus = Focus::Branches;
+    app.tab.branches_selected = 1;
 
     app.on_toggle_branch();
 
@@ -305,32 +306,32 @@ fn toggle_visible_branch_adds_selected_branch_to_hidden_layer() {
 #[test]
 fn toggle_hidden_branch_removes_selected_branch_from_hidden_layer() {
     let mut app = branch_app();
-    app.focus = Focus::Branches;
-    app.branches_selected = 1;
-    app.branches.hidden_branch_names.insert("main".to_string());
+    app.tab.focus = Focus::Branches;
+    app.tab.branches_selected = 1;
+    app.tab.branches.hidden_branch_names.insert("main".to_string());
 
     app.on_toggle_branch();
 
-    assert!(app.branches.hidden_branch_names.is_empty());
+    assert!(app.tab.branches.hidden_branch_names.is_empty());
 }
 
 #[test]
 fn toggle_last_visible_branch_clears_hidden_layer() {
     let mut app = branch_app();
-    app.focus = Focus::Branches;
-    app.branches_selected = 1;
-    app.branches.hidden_branch_names.insert("feature".to_string());
+    app.tab.focus = Focus::Branches;
+    app.tab.branches_selected = 1;
+    app.tab.branches.hidden_branch_names.insert("feature".to_string());
 
     app.on_toggle_branch();
 
-    assert!(app.branches.hidden_branch_names.is_empty());
+    assert!(app.tab.branches.hidden_branch_names.is_empty());
 }
 
 #[test]
 fn graph_solo_uses_selected_commit_branch() {
     let mut app = branch_app();
-    app.focus = Focus::Viewport;
-    app.graph_selected = 1;
+    app.tab.focus = Focus::Viewport;
+    app.tab.graph_selected = 1;
 
     app.on_solo_branch();
 
@@ -340,8 +341,8 @@ fn graph_solo_uses_selected_commit_branch() {
 #[test]
 fn graph_toggle_uses_selected_commit_branch() {
     let mut app = branch_app();
-    app.focus = Focus::Viewport;
-    app.graph_selected = 1;
+    app.tab.focus = Focus::Viewport;
+    app.tab.graph_selected = 1;
 
     app.on_toggle_branch();
 
@@ -351,13 +352,13 @@ fn graph_toggle_uses_selected_commit_branch() {
 #[test]
 fn graph_toggle_multiple_branch_commit_opens_toggle_modal() {
     let mut app = branch_app();
-    app.focus = Focus::Viewport;
-    app.graph_selected = 1;
-    app.branches.sorted.push((1, "release".to_string()));
+    app.tab.focus = Focus::Viewport;
+    app.tab.graph_selected = 1;
+    app.tab.branches.sorted.push((1, "release".to_string()));
 
     app.on_toggle_branch();
 
-    assert_eq!(app.focus, Focus::ModalSolo);
+    assert_eq!(app.tab.focus, Focus::ModalSolo);
     assert_eq!(app.modal_branch_action, BranchModalAction::Toggle);
     assert_eq!(app.modal_solo_selected, 0);
 }
@@ -373,15 +374,12 @@ fn branch_toggle_uses_git_branch_universe_when_pane_window_is_partial() {
     }
 
     let mut app = App {
-        path: Some(path.display().to_string()),
-        repo: Some(Rc::new(repo)),
         recent_save_path: Some(temp_recent_path("branch-window-toggle")),
-        viewport: Viewport::Graph,
-        focus: Focus::Branches,
-        branches_selected: 1,
+        walk_cache_dir: Some(path.with_extension("walks")),
+        tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, branches_selected: 1, ..Default::default() },
         ..Default::default()
     };
-    app.graph.branches_window =
+    app.tab.graph.branches_window =
         Some(PaneWindowCache { version: 1, start: 1, end: 2, total: 3, rows: vec![GraphPaneRow::Branch { alias: 1, name: "main".to_string(), is_local: true, lane: None, graph_index: Some(1) }] });
 
     app.on_toggle_branch();
@@ -391,60 +389,60 @@ fn branch_toggle_uses_git_branch_universe_when_pane_window_is_partial() {
 
 #[test]
 fn reload_all_branches_clears_hidden_branch_layer() {
-    let mut app = App { path: Some(temp_non_repo_path("reload-all-branches")), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() };
-    app.branches.hidden_branch_names.insert("main".to_string());
-    app.branches.hidden_branch_names.insert("origin/archive".to_string());
+    let mut app = App { tab: RepoTab { path: Some(temp_non_repo_path("reload-all-branches")), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
+    app.tab.branches.hidden_branch_names.insert("main".to_string());
+    app.tab.branches.hidden_branch_names.insert("origin/archive".to_string());
 
     app.on_reload_all_branches();
 
-    assert!(app.branches.hidden_branch_names.is_empty());
-    assert_eq!(app.focus, Focus::Branches);
+    assert!(app.tab.branches.hidden_branch_names.is_empty());
+    assert_eq!(app.tab.focus, Focus::Branches);
 }
 
 #[test]
 fn search_pane_navigation_uses_result_count() {
     let (_path, repo) = temp_repo("search-nav");
     let oid = commit_file(&repo, "target.txt", "target");
-    let mut app = App { repo: Some(Rc::new(repo)), focus: Focus::Search, ..Default::default() };
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), focus: Focus::Search, ..Default::default() }, ..Default::default() };
     app.layout.search.height = 5;
-    app.search_rows = (0..10).map(|idx| search_history_row(idx, oid)).collect();
+    app.tab.search_rows = (0..10).map(|idx| search_history_row(idx, oid)).collect();
 
     app.on_scroll_down();
-    assert_eq!(app.search_selected, 1);
+    assert_eq!(app.tab.search_selected, 1);
 
     app.on_scroll_page_down();
-    assert_eq!(app.search_selected, 5);
+    assert_eq!(app.tab.search_selected, 5);
 
     app.on_scroll_to_end();
-    assert_eq!(app.search_selected, 9);
+    assert_eq!(app.tab.search_selected, 9);
 
     app.on_scroll_down();
-    assert_eq!(app.search_selected, 9);
+    assert_eq!(app.tab.search_selected, 9);
 
     app.on_scroll_page_up();
-    assert_eq!(app.search_selected, 5);
+    assert_eq!(app.tab.search_selected, 5);
 }
 
 #[test]
 fn search_narrow_jumps_to_related_graph_commit() {
     let (mut app, _root, parent, _child) = graph_app_with_history();
-    app.focus = Focus::Search;
+    app.tab.focus = Focus::Search;
     app.layout.graph.height = 10;
-    app.search_selected = 0;
-    app.search_rows = vec![search_history_row(2, parent)];
+    app.tab.search_selected = 0;
+    app.tab.search_rows = vec![search_history_row(2, parent)];
 
     app.on_narrow_scope();
 
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.graph_selected, 2);
-    assert_eq!(app.graph_scroll.get(), 0);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.graph_selected, 2);
+    assert_eq!(app.tab.graph_scroll.get(), 0);
     assert_eq!(diff_filenames(&app), vec!["parent.txt".to_string()]);
 }
 
 #[test]
 fn empty_recent_splash_scrolls_keep_selection_at_zero() {
-    let mut app = App { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() };
+    let mut app = App { tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.graph.height = 10;
     app.splash_selected = 3;
 
@@ -470,12 +468,11 @@ fn splash_d_key_removes_selected_recent_repository_and_persists() {
     let mut keymaps = minimal_keymaps();
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('d'), KeyModifiers::NONE), Command::RemoveRecentRepository);
     let mut app = App {
-        viewport: Viewport::Splash,
-        focus: Focus::Viewport,
         keymaps,
         recent: vec!["/repo/a".into(), "/repo/b".into(), "/repo/c".into()],
         splash_selected: 1,
         recent_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
@@ -490,11 +487,10 @@ fn splash_d_key_removes_selected_recent_repository_and_persists() {
 #[test]
 fn splash_remove_recent_last_item_selects_previous() {
     let mut app = App {
-        viewport: Viewport::Splash,
-        focus: Focus::Viewport,
         recent: vec!["/repo/a".into(), "/repo/b".into()],
         splash_selected: 1,
         recent_save_path: Some(temp_recent_path("remove-last")),
+        tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
@@ -509,28 +505,25 @@ fn splash_remove_recent_current_repo_keeps_repo_open() {
     let (repo_path, repo) = temp_repo("remove-current");
     let current = repo_path.display().to_string();
     let mut app = App {
-        path: Some(current.clone()),
-        repo: Some(Rc::new(repo)),
-        viewport: Viewport::Splash,
-        focus: Focus::Viewport,
         recent: vec![current.clone(), "/repo/other".into()],
         splash_selected: 0,
         recent_save_path: Some(temp_recent_path("remove-current")),
+        tab: RepoTab { path: Some(current.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
     app.on_remove_recent_repository();
 
     assert_eq!(app.recent, vec!["/repo/other".to_string()]);
-    assert_eq!(app.path.as_deref(), Some(current.as_str()));
-    assert!(app.repo.is_some());
-    assert_eq!(app.viewport, Viewport::Splash);
+    assert_eq!(app.tab.path.as_deref(), Some(current.as_str()));
+    assert!(app.tab.repo.is_some());
+    assert_eq!(app.tab.viewport, Viewport::Splash);
 }
 
 #[test]
 fn splash_remove_recent_empty_list_normalizes_selection_without_saving() {
     let path = temp_recent_path("remove-empty");
-    let mut app = App { viewport: Viewport::Splash, focus: Focus::Viewport, splash_selected: 7, recent_save_path: Some(path.clone()), ..Default::default() };
+    let mut app = App { splash_selected: 7, recent_save_path: Some(path.clone()), tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
 
     app.on_remove_recent_repository();
 
@@ -542,8 +535,13 @@ fn splash_remove_recent_empty_list_normalizes_selection_without_saving() {
 #[test]
 fn splash_remove_recent_noops_while_loading() {
     let path = temp_recent_path("remove-loading");
-    let mut app =
-        App { viewport: Viewport::Splash, focus: Focus::Viewport, recent: vec!["/repo/a".into(), "/repo/b".into()], splash_selected: 1, recent_save_path: Some(path.clone()), ..Default::default() };
+    let mut app = App {
+        recent: vec!["/repo/a".into(), "/repo/b".into()],
+        splash_selected: 1,
+        recent_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
     app.spinner.running.store(true, std::sync::atomic::Ordering::SeqCst);
 
     app.on_remove_recent_repository();
@@ -559,12 +557,11 @@ fn splash_shift_k_moves_selected_recent_repository_up_and_persists() {
     let mut keymaps = minimal_keymaps();
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('K'), KeyModifiers::SHIFT), Command::MoveRecentRepositoryUp);
     let mut app = App {
-        viewport: Viewport::Splash,
-        focus: Focus::Viewport,
         keymaps,
         recent: vec!["/repo/a".into(), "/repo/b".into(), "/repo/c".into()],
         splash_selected: 1,
         recent_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
@@ -582,12 +579,11 @@ fn splash_shift_j_moves_selected_recent_repository_down_and_persists() {
     let mut keymaps = minimal_keymaps();
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('J'), KeyModifiers::SHIFT), Command::MoveRecentRepositoryDown);
     let mut app = App {
-        viewport: Viewport::Splash,
-        focus: Focus::Viewport,
         keymaps,
         recent: vec!["/repo/a".into(), "/repo/b".into(), "/repo/c".into()],
         splash_selected: 1,
         recent_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
@@ -602,8 +598,13 @@ fn splash_shift_j_moves_selected_recent_repository_down_and_persists() {
 #[test]
 fn splash_move_recent_boundary_noops_without_saving() {
     let path = temp_recent_path("move-boundary");
-    let mut app =
-        App { viewport: Viewport::Splash, focus: Focus::Viewport, recent: vec!["/repo/a".into(), "/repo/b".into()], splash_selected: 0, recent_save_path: Some(path.clone()), ..Default::default() };
+    let mut app = App {
+        recent: vec!["/repo/a".into(), "/repo/b".into()],
+        splash_selected: 0,
+        recent_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
 
     app.on_move_recent_repository_up();
     assert_eq!(app.recent, vec!["/repo/a".to_string(), "/repo/b".to_string()]);
@@ -621,12 +622,11 @@ fn splash_move_recent_boundary_noops_without_saving() {
 fn settings_recent_repository_remove_persists_selected_row() {
     let path = temp_recent_path("settings-remove");
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         recent: vec!["/repo/a".into(), "/repo/b".into(), "/repo/c".into()],
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::RecentRepository(1) }],
         recent_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
@@ -642,12 +642,11 @@ fn settings_recent_repository_remove_persists_selected_row() {
 fn settings_recent_repository_move_up_and_down_persist_and_follow_row() {
     let up_path = temp_recent_path("settings-move-up");
     let mut up = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         recent: vec!["/repo/a".into(), "/repo/b".into(), "/repo/c".into()],
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::RecentRepository(1) }],
         recent_save_path: Some(up_path.clone()),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
@@ -660,12 +659,11 @@ fn settings_recent_repository_move_up_and_down_persist_and_follow_row() {
 
     let down_path = temp_recent_path("settings-move-down");
     let mut down = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         recent: vec!["/repo/a".into(), "/repo/b".into(), "/repo/c".into()],
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::RecentRepository(1) }],
         recent_save_path: Some(down_path.clone()),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
@@ -685,13 +683,12 @@ fn settings_recent_repository_commands_noop_on_non_recent_rows() {
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('K'), KeyModifiers::SHIFT), Command::MoveRecentRepositoryUp);
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('J'), KeyModifiers::SHIFT), Command::MoveRecentRepositoryDown);
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         keymaps,
         recent: vec!["/repo/a".into(), "/repo/b".into()],
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::Info }],
         recent_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
@@ -707,26 +704,26 @@ fn settings_recent_repository_commands_noop_on_non_recent_rows() {
 #[test]
 fn empty_branch_pane_select_and_narrow_are_noops() {
     let (_path, repo) = temp_repo("empty-branches");
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() };
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
 
     app.on_select();
-    assert_eq!(app.focus, Focus::Branches);
+    assert_eq!(app.tab.focus, Focus::Branches);
 
     app.on_narrow_scope();
-    assert_eq!(app.focus, Focus::Branches);
+    assert_eq!(app.tab.focus, Focus::Branches);
 }
 
 fn assert_offscreen_pane_narrow_requests_walker_row(focus: Focus, pane: GraphPane, selection: usize) {
     let (_path, repo) = temp_repo("offscreen-pane");
     let (tx, rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(Rc::new(repo)), graph_tx: Some(tx), viewport: Viewport::Graph, focus, ..Default::default() };
-    app.graph.generation = 7;
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), graph_tx: Some(tx), viewport: Viewport::Graph, focus, ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
 
     match pane {
-        GraphPane::Branches => app.branches_selected = selection,
-        GraphPane::Tags => app.tags_selected = selection,
-        GraphPane::Stashes => app.stashes_selected = selection,
-        GraphPane::Reflogs => app.reflogs_selected = selection,
+        GraphPane::Branches => app.tab.branches_selected = selection,
+        GraphPane::Tags => app.tab.tags_selected = selection,
+        GraphPane::Stashes => app.tab.stashes_selected = selection,
+        GraphPane::Reflogs => app.tab.reflogs_selected = selection,
     }
 
     app.on_narrow_scope();
@@ -755,14 +752,14 @@ fn offscreen_pane_narrow_requests_selected_row_from_walker() {
 fn offscreen_graph_narrow_requests_row_before_opening_inspector() {
     let (_path, repo) = temp_repo("offscreen-inspector");
     let (tx, rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(Rc::new(repo)), graph_tx: Some(tx), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
-    app.graph.generation = 7;
-    app.graph_selected = 42;
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), graph_tx: Some(tx), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
+    app.tab.graph_selected = 42;
     app.layout_config.is_zen = false;
 
     app.on_narrow_scope();
 
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert!(app.layout_config.is_inspector);
     let command = rx.try_recv().unwrap();
     match command {
@@ -779,14 +776,14 @@ fn offscreen_graph_narrow_requests_row_before_opening_inspector() {
 fn zen_offscreen_graph_narrow_opens_inspector_while_requesting_row() {
     let (_path, repo) = temp_repo("zen-offscreen-inspector");
     let (tx, rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(Rc::new(repo)), graph_tx: Some(tx), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
-    app.graph.generation = 7;
-    app.graph_selected = 42;
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), graph_tx: Some(tx), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
+    app.tab.graph_selected = 42;
     app.layout_config.is_zen = true;
 
     app.on_narrow_scope();
 
-    assert_eq!(app.focus, Focus::Inspector);
+    assert_eq!(app.tab.focus, Focus::Inspector);
     assert!(app.layout_config.is_inspector);
     let command = rx.try_recv().unwrap();
     match command {
@@ -804,12 +801,12 @@ fn zen_graph_narrow_promotes_cached_window_row_before_opening_inspector() {
     let (_path, repo) = temp_repo("zen-cached-inspector");
     let oid = commit_file(&repo, "cached.txt", "cached");
     let (tx, rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(Rc::new(repo)), graph_tx: Some(tx), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
-    app.graph.generation = 7;
-    app.graph.total = 43;
-    app.graph_selected = 42;
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), graph_tx: Some(tx), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
+    app.tab.graph.total = 43;
+    app.tab.graph_selected = 42;
     app.layout_config.is_zen = true;
-    app.graph.graph_window = Some(GraphWindowCache {
+    app.tab.graph.graph_window = Some(GraphWindowCache {
         version: 1,
         start: 42,
         end: 43,
@@ -826,6 +823,7 @@ fn zen_graph_narrow_promotes_cached_window_row_before_opening_inspector() {
             branches: Vec::new(),
             tags: Vec::new(),
             is_stash: false,
+            is_shallow: false,
             stash_lane: None,
             worktrees: Vec::new(),
             reflog: None,
@@ -835,11 +833,11 @@ fn zen_graph_narrow_promotes_cached_window_row_before_opening_inspector() {
 
     app.on_narrow_scope();
 
-    assert_eq!(app.focus, Focus::Inspector);
+    assert_eq!(app.tab.focus, Focus::Inspector);
     assert!(app.layout_config.is_inspector);
     assert!(rx.try_recv().is_err());
 
-    app.graph.graph_window = None;
+    app.tab.graph.graph_window = None;
     let identity = app.graph_identity_at(42).unwrap();
     assert_eq!(identity.alias, 99);
     assert_eq!(identity.oid, oid);
@@ -852,9 +850,12 @@ fn graph_row_lookup_result_opens_inspector_with_reflog() {
     let repo = Rc::new(repo);
     let (cmd_tx, _cmd_rx) = std::sync::mpsc::channel();
     let (event_tx, event_rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(repo.clone()), graph_tx: Some(cmd_tx), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 42, ..Default::default() };
-    app.graph.generation = 7;
-    app.graph.pending_lookup = Some((3, PendingGraphLookup::OpenInspector));
+    let mut app = App {
+        tab: RepoTab { repo: Some(repo.clone()), graph_tx: Some(cmd_tx), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 42, ..Default::default() },
+        ..Default::default()
+    };
+    app.tab.graph.generation = 7;
+    app.tab.graph.pending_lookup = Some((3, PendingGraphLookup::OpenInspector));
 
     event_tx
         .send(GraphEvent::LookupResult {
@@ -872,6 +873,7 @@ fn graph_row_lookup_result_opens_inspector_with_reflog() {
                 branches: Vec::new(),
                 tags: Vec::new(),
                 is_stash: false,
+                is_shallow: false,
                 stash_lane: None,
                 worktrees: Vec::new(),
                 reflog: Some(GraphReflogLabel { selector: "HEAD@{0}".to_string(), message: "commit: commit".to_string(), lane: Some(LaneRef::new(2, false)) }),
@@ -880,7 +882,7 @@ fn graph_row_lookup_result_opens_inspector_with_reflog() {
         .unwrap();
     app.sync(&repo);
 
-    assert_eq!(app.focus, Focus::Inspector);
+    assert_eq!(app.tab.focus, Focus::Inspector);
     assert_eq!(app.graph_alias_at(42), Some(99));
     assert_eq!(app.graph_oid_at(42), Some(oid));
     assert_eq!(app.graph_row_at(42).and_then(|row| row.reflog.as_ref()).map(|entry| entry.selector.as_str()), Some("HEAD@{0}"));
@@ -890,81 +892,81 @@ fn graph_row_lookup_result_opens_inspector_with_reflog() {
 #[test]
 fn pane_row_jump_uses_graph_index_and_refreshes_diff() {
     let (mut app, _root_oid, _parent_oid, _child_oid) = graph_app_with_history();
-    app.focus = Focus::Branches;
-    app.graph_selected = 3;
-    app.current_diff.clear();
+    app.tab.focus = Focus::Branches;
+    app.tab.graph_selected = 3;
+    app.tab.current_diff.clear();
 
     assert!(app.open_graph_pane_row(GraphPaneRow::Branch { alias: 99, name: "parent".to_string(), is_local: true, lane: None, graph_index: Some(2) }));
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.graph_selected, 2);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.graph_selected, 2);
     assert_eq!(diff_filenames(&app), vec!["parent.txt"]);
 }
 
 #[test]
 fn pane_row_jump_centers_selected_graph_row() {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() };
-    app.graph.total = 100;
+    let mut app = App { tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
+    app.tab.graph.total = 100;
     app.layout.graph.height = 10;
     app.layout_config.is_zen = false;
 
     assert!(app.open_graph_pane_row(GraphPaneRow::Branch { alias: 99, name: "feature".to_string(), is_local: true, lane: None, graph_index: Some(40) }));
 
-    assert_eq!(app.graph_selected, 40);
-    assert_eq!(app.graph_scroll.get(), 35);
+    assert_eq!(app.tab.graph_selected, 40);
+    assert_eq!(app.tab.graph_scroll.get(), 35);
 }
 
 #[test]
 fn pane_row_jump_centering_clamps_near_graph_edges() {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() };
-    app.graph.total = 100;
+    let mut app = App { tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
+    app.tab.graph.total = 100;
     app.layout.graph.height = 10;
     app.layout_config.is_zen = false;
 
     assert!(app.open_graph_pane_row(GraphPaneRow::Tag { alias: 99, name: "v1".to_string(), lane: None, graph_index: Some(2) }));
-    assert_eq!(app.graph_scroll.get(), 0);
+    assert_eq!(app.tab.graph_scroll.get(), 0);
 
     assert!(app.open_graph_pane_row(GraphPaneRow::Stash { alias: 99, summary: "stash".to_string(), lane: None, graph_index: Some(98) }));
-    assert_eq!(app.graph_scroll.get(), 90);
+    assert_eq!(app.tab.graph_scroll.get(), 90);
 }
 
 #[test]
 fn zen_pane_row_jump_uses_inner_graph_height_for_centering() {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Reflogs, ..Default::default() };
-    app.graph.total = 100;
+    let mut app = App { tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Reflogs, ..Default::default() }, ..Default::default() };
+    app.tab.graph.total = 100;
     app.layout.graph.height = 10;
     app.layout_config.is_zen = true;
 
     assert!(app.open_graph_pane_row(GraphPaneRow::Reflog { selector: "HEAD@{0}".to_string(), message: "commit: feature".to_string(), alias: 99, lane: None, graph_index: Some(40) }));
 
-    assert_eq!(app.graph_selected, 40);
-    assert_eq!(app.graph_scroll.get(), 36);
+    assert_eq!(app.tab.graph_selected, 40);
+    assert_eq!(app.tab.graph_scroll.get(), 36);
 }
 
 #[test]
 fn pane_alias_fallback_jump_centers_selected_graph_row() {
     let (_path, repo) = temp_repo("pane-alias-center");
     let oid = commit_file(&repo, "feature.txt", "feature");
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() };
-    let alias = app.oids.get_alias_by_oid(oid);
-    app.oids.sorted_aliases = vec![NONE; 100];
-    app.oids.sorted_aliases[40] = alias;
-    app.branches.sorted = vec![(alias, "feature".to_string())];
-    app.graph.total = 100;
+    let mut app = App { tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
+    let alias = app.tab.oids.get_alias_by_oid(oid);
+    app.tab.oids.sorted_aliases = vec![NONE; 100];
+    app.tab.oids.sorted_aliases[40] = alias;
+    app.tab.branches.sorted = vec![(alias, "feature".to_string())];
+    app.tab.graph.total = 100;
     app.layout.graph.height = 10;
     app.layout_config.is_zen = false;
 
     app.on_narrow_scope();
 
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.graph_selected, 40);
-    assert_eq!(app.graph_scroll.get(), 35);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.graph_selected, 40);
+    assert_eq!(app.tab.graph_scroll.get(), 35);
 }
 
 #[test]
 fn empty_delete_tag_modal_navigation_stays_at_zero() {
-    let mut app = App { focus: Focus::ModalDeleteTag, modal_delete_tag_selected: 4, ..Default::default() };
+    let mut app = App { modal_delete_tag_selected: 4, tab: RepoTab { focus: Focus::ModalDeleteTag, ..Default::default() }, ..Default::default() };
 
     app.on_scroll_up();
     assert_eq!(app.modal_delete_tag_selected, 0);
@@ -978,16 +980,15 @@ fn empty_delete_tag_modal_navigation_stays_at_zero() {
 fn settings_shortcut_selection_opens_key_capture() {
     let key_selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::KeyBinding(key_selection.clone()) }],
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
 
     app.on_select();
 
-    assert_eq!(app.focus, Focus::ModalKeyCapture);
+    assert_eq!(app.tab.focus, Focus::ModalKeyCapture);
     assert_eq!(app.modal_key_capture_selection, Some(key_selection));
     assert_eq!(app.modal_key_capture_candidate, None);
     assert_eq!(app.modal_key_capture_error, None);
@@ -999,7 +1000,13 @@ fn settings_tab_commands_cycle_tabs_and_reset_selection() {
     let mut keymaps = minimal_keymaps();
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Tab, KeyModifiers::NONE), Command::FocusNextPane);
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::BackTab, KeyModifiers::SHIFT), Command::FocusPreviousPane);
-    let mut app = App { repo: Some(Rc::new(repo)), viewport: Viewport::Settings, focus: Focus::Viewport, settings_tab: SettingsTab::General, settings_selected: 99, keymaps, ..Default::default() };
+    let mut app = App {
+        settings_tab: SettingsTab::General,
+        settings_selected: 99,
+        keymaps,
+        tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
     app.layout.graph = Rect::new(0, 0, 120, 40);
     app.layout.app = Rect::new(0, 0, 120, 40);
     app.settings_scroll.set(12);
@@ -1021,13 +1028,13 @@ fn settings_tab_commands_cycle_tabs_and_reset_selection() {
 
 #[test]
 fn toggle_help_opens_settings_on_general_tab() {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, settings_tab: SettingsTab::Shortcuts, settings_selected: 42, ..Default::default() };
+    let mut app = App { settings_tab: SettingsTab::Shortcuts, settings_selected: 42, tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.settings_scroll.set(9);
 
     app.on_toggle_help();
 
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_tab, SettingsTab::General);
     assert_eq!(app.settings_selected, 0);
     assert_eq!(app.settings_scroll.get(), 0);
@@ -1036,10 +1043,9 @@ fn toggle_help_opens_settings_on_general_tab() {
 #[test]
 fn settings_layout_command_toggles_and_stays_in_settings() {
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::LayoutCommand(Command::ToggleBranches) }],
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.layout_config.is_branches = true;
@@ -1048,8 +1054,8 @@ fn settings_layout_command_toggles_and_stays_in_settings() {
     app.on_select();
 
     assert!(!app.layout_config.is_branches);
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 12);
     assert_eq!(app.settings_scroll.get(), 4);
 }
@@ -1057,10 +1063,9 @@ fn settings_layout_command_toggles_and_stays_in_settings() {
 #[test]
 fn settings_submodule_layout_command_toggles_and_stays_in_settings() {
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::LayoutCommand(Command::ToggleSubmodules) }],
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.layout_config.is_submodules = false;
@@ -1069,8 +1074,8 @@ fn settings_submodule_layout_command_toggles_and_stays_in_settings() {
     app.on_select();
 
     assert!(app.layout_config.is_submodules);
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 12);
     assert_eq!(app.settings_scroll.get(), 4);
 }
@@ -1080,11 +1085,10 @@ fn settings_symbol_theme_selection_updates_persists_and_stays_in_settings() {
     let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
     let path = std::env::temp_dir().join(format!("guitar-symbol-theme-select-{id}.json"));
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::SymbolTheme(1) }],
         symbol_theme_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.settings_scroll.set(4);
@@ -1092,8 +1096,8 @@ fn settings_symbol_theme_selection_updates_persists_and_stays_in_settings() {
     app.on_select();
 
     assert_eq!(app.symbols, SymbolTheme::ascii());
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 12);
     assert_eq!(app.settings_scroll.get(), 4);
     let saved = fs::read_to_string(path).unwrap();
@@ -1105,11 +1109,10 @@ fn settings_symbol_theme_selection_updates_persists_and_stays_in_settings() {
 fn settings_language_selection_updates_persists_and_stays_in_settings() {
     let path = temp_language_path("select");
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::Language(1) }],
         language_save_path: Some(path.clone()),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.settings_scroll.set(4);
@@ -1117,8 +1120,8 @@ fn settings_language_selection_updates_persists_and_stays_in_settings() {
     app.on_select();
 
     assert_eq!(app.language, Language::Spanish);
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.settings_selected, 12);
     assert_eq!(app.settings_scroll.get(), 4);
     assert_eq!(fs::read_to_string(path).unwrap(), "\"spanish\"");
@@ -1129,10 +1132,9 @@ fn settings_language_selection_updates_persists_and_stays_in_settings() {
 #[test]
 fn settings_reset_layout_command_resets_and_stays_in_settings() {
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::Viewport,
         settings_selected: 12,
         settings_selections: vec![SettingsSelection { line: 12, kind: SettingsSelectionKind::LayoutCommand(Command::ResetLayout) }],
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
         ..Default::default()
     };
     app.layout_config.is_branches = false;
@@ -1142,21 +1144,21 @@ fn settings_reset_layout_command_resets_and_stays_in_settings() {
 
     assert!(app.layout_config.is_branches);
     assert!(app.layout_config.is_shas);
-    assert_eq!(app.viewport, Viewport::Settings);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Settings);
+    assert_eq!(app.tab.focus, Focus::Viewport);
 }
 
 #[test]
 fn toggle_shas_shortcut_works_from_left_and_right_panes() {
     let mut keymaps = minimal_keymaps();
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('1'), KeyModifiers::SHIFT), Command::ToggleShas);
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Branches, keymaps, ..Default::default() };
+    let mut app = App { keymaps, tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() };
     app.layout_config.is_shas = true;
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('1'), KeyModifiers::SHIFT));
     assert!(!app.layout_config.is_shas);
 
-    app.focus = Focus::StatusTop;
+    app.tab.focus = Focus::StatusTop;
     app.handle_key_event(KeyEvent::new(KeyCode::Char('1'), KeyModifiers::SHIFT));
     assert!(app.layout_config.is_shas);
 }
@@ -1168,18 +1170,22 @@ fn graph_metadata_shift_digit_shortcuts_toggle_display_flags() {
     normal.insert(KeyBinding::new(KeyCode::Char('2'), KeyModifiers::SHIFT), Command::ToggleGraphDates);
     normal.insert(KeyBinding::new(KeyCode::Char('3'), KeyModifiers::SHIFT), Command::ToggleGraphCommitters);
     normal.insert(KeyBinding::new(KeyCode::Char('4'), KeyModifiers::SHIFT), Command::ToggleGraphRefs);
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, keymaps, ..Default::default() };
+    normal.insert(KeyBinding::new(KeyCode::Char('5'), KeyModifiers::SHIFT), Command::ToggleGraphSignatures);
+    let mut app = App { keymaps, tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout_config.is_graph_dates = false;
     app.layout_config.is_graph_committers = false;
     app.layout_config.is_graph_refs = true;
+    app.layout_config.is_graph_signatures = false;
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('2'), KeyModifiers::SHIFT));
     app.handle_key_event(KeyEvent::new(KeyCode::Char('3'), KeyModifiers::SHIFT));
     app.handle_key_event(KeyEvent::new(KeyCode::Char('4'), KeyModifiers::SHIFT));
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('5'), KeyModifiers::SHIFT));
 
     assert!(app.layout_config.is_graph_dates);
     assert!(app.layout_config.is_graph_committers);
     assert!(!app.layout_config.is_graph_refs);
+    assert!(app.layout_config.is_graph_signatures);
 }
 
 #[test]
@@ -1191,7 +1197,7 @@ fn graph_metadata_shifted_punctuation_aliases_toggle_display_flags() {
     normal.insert(KeyBinding::new(KeyCode::Char('2'), KeyModifiers::SHIFT), Command::ToggleGraphDates);
     normal.insert(KeyBinding::new(KeyCode::Char('3'), KeyModifiers::SHIFT), Command::ToggleGraphCommitters);
     normal.insert(KeyBinding::new(KeyCode::Char('4'), KeyModifiers::SHIFT), Command::ToggleGraphRefs);
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, keymaps, ..Default::default() };
+    let mut app = App { keymaps, tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout_config.is_graph_reflogs = false;
     app.layout_config.is_shas = true;
     app.layout_config.is_graph_dates = false;
@@ -1211,6 +1217,34 @@ fn graph_metadata_shifted_punctuation_aliases_toggle_display_flags() {
     assert!(!app.layout_config.is_graph_refs);
 }
 
+#[test]
+fn original_parents_shortcut_toggles_and_reloads() {
+    let (path, repo) = temp_repo("original-parents-shortcut");
+    commit_file(&repo, "head.txt", "head");
+    let walk_cache_dir = Some(path.with_extension("walks"));
+    let path = path.display().to_string();
+    let mut keymaps = minimal_keymaps();
+    keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('6'), KeyModifiers::SHIFT), Command::ToggleOriginalParents);
+    let mut app = App {
+        tab: RepoTab { path: Some(path.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() },
+        recent: vec![path],
+        keymaps,
+        walk_cache_dir,
+        ..Default::default()
+    };
+
+    // Terminals that report the shifted character reach the same command.
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('^'), KeyModifiers::NONE));
+
+    assert!(app.layout_config.is_graph_original_parents);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert!(app.tab.graph_tx.is_some());
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('6'), KeyModifiers::SHIFT));
+    assert!(!app.layout_config.is_graph_original_parents);
+    stop_graph_service(&mut app);
+}
+
 #[test]
 fn graph_metadata_legacy_ctrl_digit_aliases_toggle_display_flags() {
     let mut keymaps = minimal_keymaps();
@@ -1218,7 +1252,7 @@ fn graph_metadata_legacy_ctrl_digit_aliases_toggle_display_flags() {
     normal.insert(KeyBinding::new(KeyCode::Char('2'), KeyModifiers::SHIFT), Command::ToggleGraphDates);
     normal.insert(KeyBinding::new(KeyCode::Char('3'), KeyModifiers::SHIFT), Command::ToggleGraphCommitters);
     normal.insert(KeyBinding::new(KeyCode::Char('4'), KeyModifiers::SHIFT), Command::ToggleGraphRefs);
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, keymaps, ..Default::default() };
+    let mut app = App { keymaps, tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout_config.is_graph_dates = false;
     app.layout_config.is_graph_committers = false;
     app.layout_config.is_graph_refs = true;
@@ -1236,18 +1270,25 @@ fn graph_metadata_legacy_ctrl_digit_aliases_toggle_display_flags() {
 fn graph_reflog_shift_digit_shortcut_toggles_and_reloads() {
     let (path, repo) = temp_repo("graph-reflog-shortcut");
     commit_file(&repo, "head.txt", "head");
+    let walk_cache_dir = Some(path.with_extension("walks"));
     let path = path.display().to_string();
     let mut keymaps = minimal_keymaps();
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('0'), KeyModifiers::SHIFT), Command::ToggleGraphReflogs);
-    let mut app = App { path: Some(path.clone()), recent: vec![path], repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, keymaps, ..Default::default() };
+    let mut app = App {
+        tab: RepoTab { path: Some(path.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() },
+        recent: vec![path],
+        keymaps,
+        walk_cache_dir,
+        ..Default::default()
+    };
     app.layout_config.is_graph_reflogs = false;
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('0'), KeyModifiers::SHIFT));
 
     assert!(app.layout_config.is_graph_reflogs);
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.focus, Focus::Viewport);
-    assert!(app.graph_tx.is_some());
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert!(app.tab.graph_tx.is_some());
     stop_graph_service(&mut app);
 }
 
@@ -1255,43 +1296,48 @@ fn graph_reflog_shift_digit_shortcut_toggles_and_reloads() {
 fn toggle_search_shortcut_opens_and_closes_search_pane() {
     let mut keymaps = minimal_keymaps();
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('7'), KeyModifiers::NONE), Command::ToggleSearch);
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, keymaps, ..Default::default() };
+    let mut app = App { keymaps, tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout_config.is_search = false;
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('7'), KeyModifiers::NONE));
     assert!(app.layout_config.is_search);
-    assert_eq!(app.focus, Focus::Search);
+    assert_eq!(app.tab.focus, Focus::Search);
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('7'), KeyModifiers::NONE));
     assert!(!app.layout_config.is_search);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
 }
 
 #[test]
 fn toggle_submodules_shortcut_opens_and_closes_submodule_pane() {
     let mut keymaps = minimal_keymaps();
     keymaps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(KeyCode::Char('6'), KeyModifiers::NONE), Command::ToggleSubmodules);
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, keymaps, ..Default::default() };
+    let mut app = App { keymaps, tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout_config.is_submodules = false;
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('6'), KeyModifiers::NONE));
     assert!(app.layout_config.is_submodules);
-    assert_eq!(app.focus, Focus::Submodules);
+    assert_eq!(app.tab.focus, Focus::Submodules);
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('6'), KeyModifiers::NONE));
     assert!(!app.layout_config.is_submodules);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
 }
 
 #[test]
 fn key_capture_conflict_does_not_change_keymaps() {
     let key_selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
     let keymaps = minimal_keymaps();
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::ModalKeyCapture, keymaps: keymaps.clone(), modal_key_capture_selection: Some(key_selection), ..Default::default() };
+    let mut app = App {
+        keymaps: keymaps.clone(),
+        modal_key_capture_selection: Some(key_selection),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::ModalKeyCapture, ..Default::default() },
+        ..Default::default()
+    };
 
     app.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::ModalKeyCapture);
+    assert_eq!(app.tab.focus, Focus::ModalKeyCapture);
     assert!(app.modal_key_capture_error.is_some());
     assert_eq!(app.keymaps, keymaps);
 }
@@ -1301,11 +1347,10 @@ fn key_capture_confirm_updates_memory_and_persists_keymap() {
     let path = temp_keymap_path("capture-save");
     let key_selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::ModalKeyCapture,
         keymaps: minimal_keymaps(),
         keymap_save_path: Some(path.clone()),
         modal_key_capture_selection: Some(key_selection),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::ModalKeyCapture, ..Default::default() },
         ..Default::default()
     };
 
@@ -1313,7 +1358,7 @@ fn key_capture_confirm_updates_memory_and_persists_keymap() {
     app.handle_key_event(KeyEvent::new(KeyCode::Char('n'), KeyModifiers::ALT));
     app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.keymaps.get(&InputMode::Normal).unwrap().get(&new_key), Some(&Command::ScrollDown));
     assert_eq!(app.keymaps.get(&InputMode::Action).unwrap().get(&new_key), Some(&Command::ScrollDown));
     assert_eq!(app.keymaps.get(&InputMode::Normal).unwrap().get(&KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE)), None);
@@ -1327,34 +1372,86 @@ fn key_capture_can_assign_enter_key() {
     let path = temp_keymap_path("capture-enter");
     let key_selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
     let mut app = App {
-        viewport: Viewport::Settings,
-        focus: Focus::ModalKeyCapture,
         keymaps: minimal_keymaps(),
         keymap_save_path: Some(path.clone()),
         modal_key_capture_selection: Some(key_selection),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::ModalKeyCapture, ..Default::default() },
         ..Default::default()
     };
 
     let enter_key = KeyBinding::new(KeyCode::Enter, KeyModifiers::NONE);
     app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
-    assert_eq!(app.focus, Focus::ModalKeyCapture);
+    assert_eq!(app.tab.focus, Focus::ModalKeyCapture);
     assert_eq!(app.modal_key_capture_candidate, Some(enter_key.clone()));
 
     app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.keymaps.get(&InputMode::Normal).unwrap().get(&enter_key), Some(&Command::ScrollDown));
     assert_eq!(load_keymaps_from_path(path.as_path()).unwrap(), app.keymaps);
 }
 
+#[test]
+fn key_capture_records_sequences_and_backspace_removes_the_last_key() {
+    let path = temp_keymap_path("capture-sequence");
+    let key_selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
+    let mut app = App {
+        keymaps: minimal_keymaps(),
+        keymap_save_path: Some(path.clone()),
+        modal_key_capture_selection: Some(key_selection),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::ModalKeyCapture, ..Default::default() },
+        ..Default::default()
+    };
+    let g = KeyBinding::new(KeyCode::Char('g'), KeyModifiers::NONE);
+    let sequence = KeyBinding::sequence(&[g.clone(), KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE)]).unwrap();
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+    app.handle_key_event(KeyEvent::new(KeyCode::Backspace, KeyModifiers::NONE));
+    assert_eq!(app.modal_key_capture_candidate, Some(g));
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE));
+    assert_eq!(app.modal_key_capture_candidate, Some(sequence.clone()));
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.keymaps.get(&InputMode::Normal).unwrap().get(&sequence), Some(&Command::ScrollDown));
+    assert_eq!(load_keymaps_from_path(path.as_path()).unwrap(), app.keymaps);
+}
+
+#[test]
+fn key_capture_reports_prefix_conflicts_until_another_key_extends_the_sequence() {
+    let key_selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
+    let g = KeyBinding::new(KeyCode::Char('g'), KeyModifiers::NONE);
+    let gg = KeyBinding::sequence(&[g.clone(), g.clone()]).unwrap();
+    let mut keymaps = minimal_keymaps();
+    keymaps.get_mut(&InputMode::Normal).unwrap().insert(gg.clone(), Command::GoToBeginning);
+    let mut app =
+        App { keymaps, modal_key_capture_selection: Some(key_selection), tab: RepoTab { viewport: Viewport::Settings, focus: Focus::ModalKeyCapture, ..Default::default() }, ..Default::default() };
+
+    // An outright conflict is replaced by the next key rather than extended.
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('k'), KeyModifiers::NONE));
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE));
+    assert_eq!(app.modal_key_capture_candidate, Some(g.clone()));
+    assert_eq!(app.modal_key_capture_error, Some(KeymapEditError::PrefixConflict { mode: InputMode::Normal, key: gg, command: Command::GoToBeginning }));
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE));
+    assert_eq!(app.modal_key_capture_candidate, KeyBinding::sequence(&[g, KeyBinding::new(KeyCode::Char('x'), KeyModifiers::NONE)]));
+    assert_eq!(app.modal_key_capture_error, None);
+}
+
 #[test]
 fn key_capture_esc_closes_without_capturing_key() {
     let key_selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(KeyCode::Char('j'), KeyModifiers::NONE), Command::ScrollDown);
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::ModalKeyCapture, keymaps: minimal_keymaps(), modal_key_capture_selection: Some(key_selection), ..Default::default() };
+    let mut app = App {
+        keymaps: minimal_keymaps(),
+        modal_key_capture_selection: Some(key_selection),
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::ModalKeyCapture, ..Default::default() },
+        ..Default::default()
+    };
 
     app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
 
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.modal_key_capture_selection, None);
     assert_eq!(app.modal_key_capture_candidate, None);
     assert_eq!(app.modal_key_capture_error, None);
@@ -1362,93 +1459,133 @@ fn key_capture_esc_closes_without_capturing_key() {
 
 #[test]
 fn raw_esc_exits_settings_even_without_keymap_binding() {
-    let mut app = App { viewport: Viewport::Settings, focus: Focus::Viewport, keymaps: Keymaps::new(), settings_selected: 12, settings_tab: SettingsTab::Shortcuts, ..Default::default() };
+    let mut app = App {
+        keymaps: Keymaps::new(),
+        settings_selected: 12,
+        settings_tab: SettingsTab::Shortcuts,
+        tab: RepoTab { viewport: Viewport::Settings, focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
 
     app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
 
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Viewport);
     assert_eq!(app.mode, InputMode::Normal);
 }
 
 #[test]
 fn raw_esc_closes_choice_and_confirmation_modals() {
-    let mut checkout = App { focus: Focus::ModalCheckout, modal_checkout_selected: 3, ..Default::default() };
+    let mut checkout = App { modal_checkout_selected: 3, tab: RepoTab { focus: Focus::ModalCheckout, ..Default::default() }, ..Default::default() };
     checkout.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
-    assert_eq!(checkout.focus, Focus::Viewport);
+    assert_eq!(checkout.tab.focus, Focus::Viewport);
     assert_eq!(checkout.modal_checkout_selected, 0);
 
-    let mut solo = App { focus: Focus::ModalSolo, modal_solo_selected: 2, modal_branch_action: BranchModalAction::Rename, ..Default::default() };
+    let mut solo = App { modal_solo_selected: 2, modal_branch_action: BranchModalAction::Rename, tab: RepoTab { focus: Focus::ModalSolo, ..Default::default() }, ..Default::default() };
     solo.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
-    assert_eq!(solo.focus, Focus::Viewport);
+    assert_eq!(solo.tab.focus, Focus::Viewport);
     assert_eq!(solo.modal_solo_selected, 0);
     assert_eq!(solo.modal_branch_action, BranchModalAction::Solo);
 
-    let mut delete_branch = App { focus: Focus::ModalDeleteBranch, modal_delete_branch_selected: 4, ..Default::default() };
+    let mut delete_branch = App { modal_delete_branch_selected: 4, tab: RepoTab { focus: Focus::ModalDeleteBranch, ..Default::default() }, ..Default::default() };
     delete_branch.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
-    assert_eq!(delete_branch.focus, Focus::Viewport);
+    assert_eq!(delete_branch.tab.focus, Focus::Viewport);
     assert_eq!(delete_branch.modal_delete_branch_selected, 0);
 
-    let mut delete_tag = App { focus: Focus::ModalDeleteTag, modal_delete_tag_selected: 5, ..Default::default() };
+    let mut delete_tag = App { modal_delete_tag_selected: 5, tab: RepoTab { focus: Focus::ModalDeleteTag, ..Default::default() }, ..Default::default() };
     delete_tag.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
-    assert_eq!(delete_tag.focus, Focus::Viewport);
+    assert_eq!(delete_tag.tab.focus, Focus::Viewport);
     assert_eq!(delete_tag.modal_delete_tag_selected, 0);
 }
 
 #[test]
 fn raw_esc_closes_remote_action_modals() {
-    let mut action = App { focus: Focus::ModalRemoteAction, modal_remote_selected: 3, modal_remote_target: Some("origin".into()), ..Default::default() };
+    let mut action = App { modal_remote_selected: 3, modal_remote_target: Some("origin".into()), tab: RepoTab { focus: Focus::ModalRemoteAction, ..Default::default() }, ..Default::default() };
     action.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
-    assert_eq!(action.focus, Focus::Viewport);
+    assert_eq!(action.tab.focus, Focus::Viewport);
     assert_eq!(action.modal_remote_selected, 0);
     assert_eq!(action.modal_remote_target, None);
 
-    let mut delete = App { focus: Focus::ModalRemoteDelete, modal_remote_selected: 5, modal_remote_target: Some("origin".into()), ..Default::default() };
+    let mut delete = App { modal_remote_selected: 5, modal_remote_target: Some("origin".into()), tab: RepoTab { focus: Focus::ModalRemoteDelete, ..Default::default() }, ..Default::default() };
     delete.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
-    assert_eq!(delete.focus, Focus::Viewport);
+    assert_eq!(delete.tab.focus, Focus::Viewport);
     assert_eq!(delete.modal_remote_selected, 0);
     assert_eq!(delete.modal_remote_target, None);
 }
 
 #[test]
 fn raw_esc_does_not_dismiss_live_progress_modals() {
-    let mut network = App { focus: Focus::ModalNetworkProgress, ..Default::default() };
+    let mut network = App { tab: RepoTab { focus: Focus::ModalNetworkProgress, ..Default::default() }, ..Default::default() };
     network.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
-    assert_eq!(network.focus, Focus::ModalNetworkProgress);
+    assert_eq!(network.tab.focus, Focus::ModalNetworkProgress);
 
-    let mut operation = App { focus: Focus::ModalOperationProgress, ..Default::default() };
+    let mut operation = App { tab: RepoTab { focus: Focus::ModalOperationProgress, ..Default::default() }, ..Default::default() };
     operation.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
-    assert_eq!(operation.focus, Focus::ModalOperationProgress);
+    assert_eq!(operation.tab.focus, Focus::ModalOperationProgress);
 }
 
 #[test]
 fn graph_branch_and_commit_jumps_refresh_current_diff() {
     let (mut app, _root_oid, _parent_oid, _child_oid) = graph_app_with_history();
 
-    app.graph_selected = 3;
-    app.current_diff.clear();
+    app.tab.graph_selected = 3;
+    app.tab.current_diff.clear();
     app.on_scroll_up_branch();
-    assert_eq!(app.graph_selected, 2);
+    assert_eq!(app.tab.graph_selected, 2);
     assert_eq!(diff_filenames(&app), vec!["parent.txt"]);
 
-    app.current_diff.clear();
+    app.tab.current_diff.clear();
     app.on_scroll_up_commit();
-    assert_eq!(app.graph_selected, 1);
+    assert_eq!(app.tab.graph_selected, 1);
     assert_eq!(diff_filenames(&app), vec!["child.txt"]);
 
-    app.current_diff.clear();
+    app.tab.current_diff.clear();
     app.on_scroll_down_commit();
-    assert_eq!(app.graph_selected, 2);
+    assert_eq!(app.tab.graph_selected, 2);
     assert_eq!(diff_filenames(&app), vec!["parent.txt"]);
 }
 
+#[test]
+fn commit_file_lists_from_the_diff_service_drop_answers_for_earlier_selections() {
+    let (mut app, _root_oid, parent_oid, child_oid) = graph_app_with_history();
+    let (command_tx, command_rx) = std::sync::mpsc::channel();
+    let (event_tx, event_rx) = std::sync::mpsc::channel();
+    app.tab.diff_tx = Some(command_tx);
+    app.tab.diff_rx = Some(event_rx);
+    let repo = app.tab.repo.clone().unwrap();
+
+    app.tab.graph_selected = 3;
+    app.on_scroll_up_commit();
+    app.on_scroll_up_commit();
+    assert_eq!(app.tab.graph_selected, 1);
+    assert!(!app.selected_commit_diff_is_loaded());
+
+    let requests: Vec<_> = command_rx.try_iter().collect();
+    let [DiffCommand::QueryFileList { request_id: stale, oid: stale_oid, .. }, DiffCommand::QueryFileList { request_id: current, oid: current_oid, .. }] = requests[..] else {
+        panic!("expected two file list queries, got {requests:?}");
+    };
+    assert_eq!((stale_oid, current_oid), (parent_oid, child_oid));
+
+    let generation = app.tab.graph.generation;
+    let list = |request_id, oid, filename: &str| DiffEvent::FileList { generation, request_id, oid, changes: vec![FileChange { filename: filename.to_string(), status: FileStatus::Added }] };
+    event_tx.send(list(stale, parent_oid, "parent.txt")).unwrap();
+    app.sync(&repo);
+    assert!(!app.selected_commit_diff_is_loaded());
+    assert!(diff_filenames(&app).is_empty());
+
+    event_tx.send(list(current, child_oid, "child.txt")).unwrap();
+    app.sync(&repo);
+    assert!(app.selected_commit_diff_is_loaded());
+    assert_eq!(diff_filenames(&app), vec!["child.txt"]);
+}
+
 #[test]
 fn reflog_selection_refreshes_current_diff() {
     let (mut app, root_oid, _parent_oid, child_oid) = graph_app_with_history();
-    let child_alias = app.oids.get_alias_by_oid(child_oid);
-    let root_alias = app.oids.get_alias_by_oid(root_oid);
-    app.focus = Focus::Reflogs;
-    app.reflogs.entries.push(HeadReflogAliasEntry {
+    let child_alias = app.tab.oids.get_alias_by_oid(child_oid);
+    let root_alias = app.tab.oids.get_alias_by_oid(root_oid);
+    app.tab.focus = Focus::Reflogs;
+    app.tab.reflogs.entries.push(HeadReflogAliasEntry {
         selector: "HEAD@{0}".to_string(),
         old_oid: root_oid,
         new_oid: child_oid,
@@ -1456,12 +1593,12 @@ fn reflog_selection_refreshes_current_diff() {
         message: "commit: child".to_string(),
         time: git2::Time::new(1, 0),
     });
-    app.graph_selected = 3;
-    app.current_diff.clear();
+    app.tab.graph_selected = 3;
+    app.tab.current_diff.clear();
 
     app.on_select();
 
-    assert_eq!(app.graph_selected, 1);
-    assert_eq!(app.oids.get_alias_by_idx(3), root_alias);
+    assert_eq!(app.tab.graph_selected, 1);
+    assert_eq!(app.tab.oids.get_alias_by_idx(3), root_alias);
     assert_eq!(diff_filenames(&app), vec!["child.txt"]);
 }
@@ -0,0 +1,136 @@
+use super::*;
+use crate::helpers::keymap::{KeyBinding, Keymaps, ModeKeymap};
+use crate::{app::app::RepoTab, helpers::custom_commands::CustomCommand};
+use git2::Repository;
+use indexmap::IndexMap;
+use std::{
+    fs,
+    path::PathBuf,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-palette-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    (path, repo)
+}
+
+fn keymaps() -> Keymaps {
+    let normal: ModeKeymap = IndexMap::from([
+        (KeyBinding::new(KeyCode::Char(':'), KeyModifiers::NONE), Command::CommandPalette),
+        (KeyBinding::new(KeyCode::Char('a'), KeyModifiers::NONE), Command::ActionMode),
+        (KeyBinding::new(KeyCode::Char('J'), KeyModifiers::SHIFT), Command::ToggleJournal),
+    ]);
+    let action: ModeKeymap = IndexMap::from([(KeyBinding::new(KeyCode::Char('e'), KeyModifiers::NONE), Command::ExportGraph)]);
+    IndexMap::from([(InputMode::Normal, normal), (InputMode::Action, action)])
+}
+
+fn graph_app(repo: Repository) -> App {
+    App { keymaps: keymaps(), tab: RepoTab { repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Branches, ..Default::default() }, ..Default::default() }
+}
+
+fn type_query(app: &mut App, query: &str) {
+    for ch in query.chars() {
+        app.handle_palette_event(KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE));
+    }
+}
+
+fn entry(app: &App, command: Command) -> PaletteEntry {
+    app.palette_entries().into_iter().find(|entry| entry.command == command).unwrap()
+}
+
+#[test]
+fn empty_query_lists_every_command_but_the_palette_with_its_keys() {
+    let app = App { keymaps: keymaps(), ..Default::default() };
+    let entries = app.palette_entries();
+
+    assert_eq!(entries.len(), COMMANDS.len() - 1);
+    assert!(entries.iter().all(|entry| entry.command != Command::CommandPalette));
+    assert_eq!(entry(&app, Command::ToggleJournal).keys, keybinding_to_visual_string(&KeyBinding::new(KeyCode::Char('J'), KeyModifiers::SHIFT)));
+    assert_eq!(entry(&app, Command::ExportGraph).keys, "a e");
+    assert_eq!(entry(&app, Command::Commit).keys, "");
+}
+
+#[test]
+fn query_ranks_labels_with_file_search_ranking() {
+    let mut app = App { keymaps: keymaps(), ..Default::default() };
+    app.on_command_palette();
+    type_query(&mut app, "export gr");
+
+    let entries = app.palette_entries();
+    assert_eq!(entries[0].command, Command::ExportGraph);
+    assert_eq!(entries[0].matched_indices, vec![0, 1, 2, 3, 4, 5, 7, 8]);
+    assert!(entries.len() < COMMANDS.len() - 1);
+}
+
+#[test]
+fn a_custom_command_named_like_a_built_in_one_keeps_its_own_row() {
+    let custom = CustomCommand { name: "Export graph".to_string(), command: "true".to_string(), context: Default::default(), output: Default::default() };
+    let mut app = App { keymaps: keymaps(), custom_commands: vec![custom], ..Default::default() };
+    app.on_command_palette();
+    type_query(&mut app, "export gr");
+
+    let commands: Vec<Command> = app.palette_entries().into_iter().take(2).map(|entry| entry.command).collect();
+    assert!(commands.contains(&Command::ExportGraph), "{commands:?}");
+    assert!(commands.contains(&Command::Custom("Export graph".to_string())), "{commands:?}");
+}
+
+#[test]
+fn disabled_commands_carry_a_reason() {
+    let splash = App { tab: RepoTab { viewport: Viewport::Splash, ..Default::default() }, ..Default::default() };
+    assert_eq!(splash.command_disabled_reason(&Command::Commit), Some(palette::NO_REPOSITORY()));
+    assert_eq!(splash.command_disabled_reason(&Command::RemoveRecentRepository), None);
+    assert_eq!(splash.command_disabled_reason(&Command::ReturnToParentRepository), Some(palette::NOT_IN_SUBMODULE()));
+
+    let (path, repo) = temp_repo("disabled");
+    let app = graph_app(repo);
+    assert_eq!(app.command_disabled_reason(&Command::RemoveRecentRepository), Some(palette::START_SCREEN_ONLY()));
+    assert_eq!(app.command_disabled_reason(&Command::Undo), Some(palette::NOTHING_TO_UNDO()));
+    assert_eq!(app.command_disabled_reason(&Command::AbortOperation), Some(palette::NO_OPERATION()));
+    assert_eq!(app.command_disabled_reason(&Command::MarkRange), Some(palette::NO_MARKED_COMMITS()));
+    assert_eq!(app.command_disabled_reason(&Command::ClearMarks), Some(palette::NO_MARKED_ROWS()));
+    assert_eq!(app.command_disabled_reason(&Command::Pop), Some(palette::NO_STASHES()));
+    assert_eq!(app.command_disabled_reason(&Command::ToggleWorktreeLock), Some(palette::NO_LINKED_WORKTREES()));
+    assert_eq!(app.command_disabled_reason(&Command::SyncSubmodule), Some(palette::NO_SUBMODULES()));
+    assert_eq!(app.command_disabled_reason(&Command::DeepenHistory), Some(palette::NOT_SHALLOW()));
+    assert_eq!(app.command_disabled_reason(&Command::ToggleJournal), None);
+
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn enter_runs_the_selected_command_from_the_previous_focus() {
+    let (path, repo) = temp_repo("run");
+    let mut app = graph_app(repo);
+    app.tab.focus = Focus::Viewport;
+
+    app.on_command_palette();
+    assert_eq!(app.tab.focus, Focus::ModalCommandPalette);
+    type_query(&mut app, "operation journal");
+    app.handle_palette_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    assert_eq!(app.tab.focus, Focus::ModalJournal);
+    assert!(app.modal_input.value().is_empty());
+
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn enter_on_a_disabled_command_keeps_the_palette_open() {
+    let (path, repo) = temp_repo("disabled-enter");
+    let mut app = graph_app(repo);
+
+    app.on_command_palette();
+    type_query(&mut app, "undo");
+    assert_eq!(app.palette_entries()[0].command, Command::Undo);
+    app.handle_palette_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(app.tab.focus, Focus::ModalCommandPalette);
+
+    app.handle_palette_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(app.tab.focus, Focus::Branches);
+
+    let _ = fs::remove_dir_all(path);
+}
@@ -0,0 +1,138 @@
+use super::*;
+use crate::{
+    app::app::{RepoTab, Viewport},
+    git::actions::patching::export_patches,
+};
+use git2::{Oid, Repository, ResetType, Signature};
+use std::{
+    path::Path,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-patching-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn commit(repo: &Repository, file: &str, message: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), format!("{message}\n")).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree_oid = index.write_tree().unwrap();
+    let tree = repo.find_tree(tree_oid).unwrap();
+    let sig = Signature::now("Mail Author", "mail@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+// Saved state and patch files live beside the repository so they never dirty the worktree.
+fn patch_app(path: &Path, repo: Repository) -> App {
+    App {
+        journal_save_path: Some(path.with_extension("journal.json")),
+        recent_save_path: Some(path.with_extension("recent.json")),
+        walk_cache_dir: Some(path.with_extension("walks")),
+        tab: RepoTab {
+            path: Some(path.display().to_string()),
+            repo: Some(Rc::new(repo)),
+            viewport: Viewport::Graph,
+            focus: Focus::Viewport,
+            name: "Applier".to_string(),
+            email: "applier@example.com".to_string(),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+fn cleanup(path: &Path) {
+    for extension in ["journal.json", "recent.json", "mbox"] {
+        let _ = fs::remove_file(path.with_extension(extension));
+    }
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn exporting_marked_commits_writes_a_numbered_series_oldest_first() {
+    let (path, repo) = temp_repo("export");
+    commit(&repo, "file.txt", "base");
+    let first = commit(&repo, "file.txt", "first");
+    let second = commit(&repo, "file.txt", "second");
+
+    let mut app = patch_app(&path, repo);
+    app.tab.graph_marked_oids = vec![second, first];
+    app.on_export_patches();
+
+    assert_eq!(app.tab.focus, Focus::ModalExportPatches);
+    assert!(app.modal_input.value().ends_with(&format!("{}-{}.mbox", &first.to_string()[..7], &second.to_string()[..7])));
+    // The default target is outside the worktree.
+    assert!(!std::path::Path::new(app.modal_input.value()).starts_with(&path));
+
+    let patch_path = path.with_extension("mbox");
+    app.modal_input.set_value(patch_path.display().to_string());
+    app.confirm_export_patches();
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    let mbox = fs::read_to_string(&patch_path).unwrap();
+    assert!(mbox.find("[PATCH 1/2] first").unwrap() < mbox.find("[PATCH 2/2] second").unwrap());
+    cleanup(&path);
+}
+
+#[test]
+fn applying_a_mailbox_as_commits_keeps_authors_and_is_journaled() {
+    let (path, repo) = temp_repo("am");
+    let base = commit(&repo, "file.txt", "base");
+    let first = commit(&repo, "file.txt", "first");
+    let second = commit(&repo, "other.txt", "second");
+    let patch_path = path.with_extension("mbox");
+    fs::write(&patch_path, export_patches(&repo, &[first, second]).unwrap()).unwrap();
+    repo.reset(repo.find_commit(base).unwrap().as_object(), ResetType::Hard, None).unwrap();
+
+    let mut app = patch_app(&path, repo);
+    app.on_apply_patch();
+    assert_eq!(app.tab.focus, Focus::ModalApplyPatchPath);
+    app.modal_input.set_value(patch_path.display().to_string());
+    app.confirm_apply_patch_path();
+
+    assert_eq!(app.tab.focus, Focus::ModalApplyPatch);
+    assert_eq!(app.modal_patch_mails.iter().map(|mail| mail.subject.as_str()).collect::<Vec<_>>(), vec!["first", "second"]);
+    app.on_scroll_up();
+    assert_eq!(PATCH_TARGETS[app.modal_patch_selected as usize], PatchTarget::Commit);
+    app.on_select();
+
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    let repo = app.tab.repo.as_ref().unwrap();
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.tree_id(), repo.find_commit(second).unwrap().tree_id());
+    assert_eq!(head.author().name(), Some("Mail Author"));
+    assert_eq!(head.committer().name(), Some("Applier"));
+    assert_eq!(app.tab.journal.last().map(|entry| entry.operation.clone()), Some(Command::ApplyPatch));
+    cleanup(&path);
+}
+
+#[test]
+fn applying_a_file_without_patches_shows_error() {
+    let (path, repo) = temp_repo("empty");
+    commit(&repo, "file.txt", "base");
+    let patch_path = path.with_extension("mbox");
+    fs::write(&patch_path, "nothing to see here\n").unwrap();
+
+    let mut app = patch_app(&path, repo);
+    app.on_apply_patch();
+    app.modal_input.set_value(patch_path.display().to_string());
+    app.confirm_apply_patch_path();
+
+    assert_eq!(app.tab.focus, Focus::ModalError);
+    assert_eq!(app.modal_error_message, errors::APPLY_PATCH_NO_PATCHES());
+    cleanup(&path);
+}
@@ -0,0 +1,283 @@
+use super::*;
+use crate::{
+    app::app::{OperationKind, RepoTab},
+    core::{
+        chunk::NONE,
+        graph_service::{GraphCommand, GraphEvent, GraphLookupResult},
+    },
+    git::{actions::network::NetworkRequest, auth::NetworkResult, queries::diffs::get_filenames_diff_at_workdir},
+};
+use git2::{Repository, Signature};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::{
+    fs,
+    path::PathBuf,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-selection-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+// Commits every file at once so each test starts from a clean tree.
+fn commit_files(repo: &Repository, files: &[&str], message: &str) -> Oid {
+    let mut index = repo.index().unwrap();
+    for file in files {
+        fs::write(repo.workdir().unwrap().join(file), format!("{message}\n")).unwrap();
+        index.add_path(Path::new(file)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+fn repo_app(path: &Path, repo: Repository, focus: Focus) -> App {
+    App {
+        journal_save_path: Some(path.with_extension("journal.json")),
+        recent_save_path: Some(path.with_extension("recent.json")),
+        walk_cache_dir: Some(path.with_extension("walks")),
+        marks_save_path: Some(path.with_extension("marks.json")),
+        tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus, ..Default::default() },
+        ..Default::default()
+    }
+}
+
+fn cleanup(path: PathBuf) {
+    for extension in ["journal.json", "recent.json", "marks.json"] {
+        let _ = fs::remove_file(path.with_extension(extension));
+    }
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn visual_range_stages_files_after_one_confirmation() {
+    let (path, repo) = temp_repo("stage");
+    commit_files(&repo, &["a.txt", "b.txt", "c.txt"], "base");
+    for file in ["a.txt", "b.txt", "c.txt"] {
+        fs::remove_file(path.join(file)).unwrap();
+    }
+    let mut app = repo_app(&path, repo, Focus::StatusBottom);
+    app.tab.uncommitted = get_filenames_diff_at_workdir(app.tab.repo.as_ref().unwrap()).unwrap();
+
+    app.dispatch_command(&Command::VisualMode);
+    app.tab.status_bottom_selected = 1;
+    assert_eq!(app.visual_range(), Some(0..=1));
+
+    app.dispatch_command(&Command::Stage);
+    assert_eq!(app.tab.focus, Focus::ModalBatch);
+    assert_eq!(app.tab.visual, None);
+    assert_eq!(app.batch_items(BatchAction::Stage), vec!["a.txt".to_string(), "b.txt".to_string()]);
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+    let status = get_filenames_diff_at_workdir(app.tab.repo.as_ref().unwrap()).unwrap();
+    assert_eq!(status.staged.deleted, vec!["a.txt".to_string(), "b.txt".to_string()]);
+    assert_eq!(status.unstaged.deleted, vec!["c.txt".to_string()]);
+    assert!(app.tab.pane_marks.unstaged.is_empty());
+    assert_eq!(app.tab.focus, Focus::StatusBottom);
+
+    cleanup(path);
+}
+
+#[test]
+fn marked_rows_and_ranges_combine_into_one_tag_batch() {
+    let (path, repo) = temp_repo("tags");
+    let head = commit_files(&repo, &["a.txt"], "base");
+    let names = ["v1", "v2", "v3", "v4"];
+    for name in names {
+        repo.tag_lightweight(name, repo.find_commit(head).unwrap().as_object(), false).unwrap();
+    }
+    let mut app = repo_app(&path, repo, Focus::Tags);
+    let alias = app.tab.oids.get_alias_by_oid(head);
+    app.tab.tags.sorted = names.iter().map(|name| (alias, name.to_string())).collect();
+
+    app.dispatch_command(&Command::MarkCommit);
+    app.tab.tags_selected = 2;
+    app.dispatch_command(&Command::VisualMode);
+    app.tab.tags_selected = 3;
+    assert!(app.is_pane_row_selected(Focus::Tags, 0));
+    assert!(!app.is_pane_row_selected(Focus::Tags, 1));
+    assert!(app.is_pane_row_selected(Focus::Tags, 3));
+
+    // Marking in visual mode only folds the range in.
+    app.dispatch_command(&Command::MarkCommit);
+    assert_eq!(app.tab.visual, None);
+    assert_eq!(app.tab.pane_marks.tags, vec!["v1".to_string(), "v3".to_string(), "v4".to_string()]);
+
+    // Escape leaves visual mode first and keeps the pane focused.
+    app.dispatch_command(&Command::VisualMode);
+    app.dispatch_command(&Command::Back);
+    assert_eq!(app.tab.visual, None);
+    assert_eq!(app.tab.focus, Focus::Tags);
+
+    app.dispatch_command(&Command::Untag);
+    assert_eq!(app.tab.focus, Focus::ModalBatch);
+    app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(app.tab.focus, Focus::Tags);
+    assert_eq!(app.tab.pane_marks.tags.len(), 3);
+
+    app.dispatch_command(&Command::Untag);
+    app.confirm_batch();
+
+    let repo = app.tab.repo.as_ref().unwrap();
+    let remaining: Vec<String> = repo.tag_names(None).unwrap().iter().flatten().map(str::to_string).collect();
+    assert_eq!(remaining, vec!["v2".to_string()]);
+    assert_eq!(app.tab.journal.len(), 1);
+
+    cleanup(path);
+}
+
+#[test]
+fn branch_batches_refuse_the_current_branch() {
+    let (path, repo) = temp_repo("branches");
+    let head = commit_files(&repo, &["a.txt"], "base");
+    repo.branch("feature", &repo.find_commit(head).unwrap(), false).unwrap();
+    let current = repo.head().unwrap().shorthand().unwrap().to_string();
+    let mut app = repo_app(&path, repo, Focus::Branches);
+    app.tab.pane_marks.branches = vec!["feature".to_string(), current];
+
+    app.dispatch_command(&Command::DeleteBranch);
+
+    assert_eq!(app.tab.focus, Focus::ModalError);
+    assert!(app.tab.repo.as_ref().unwrap().find_branch("feature", git2::BranchType::Local).is_ok());
+
+    cleanup(path);
+}
+
+#[test]
+fn graph_ranges_outside_the_cache_are_looked_up_before_the_command_runs() {
+    let (path, repo) = temp_repo("graph");
+    let root = commit_files(&repo, &["a.txt"], "root");
+    let child = commit_files(&repo, &["b.txt"], "child");
+    let tip = commit_files(&repo, &["c.txt"], "tip");
+    let mut app = repo_app(&path, repo, Focus::Viewport);
+    let repo = app.tab.repo.clone().unwrap();
+    let (tx, rx) = std::sync::mpsc::channel();
+    let (event_tx, event_rx) = std::sync::mpsc::channel();
+    app.tab.graph_tx = Some(tx);
+    app.tab.graph_rx = Some(event_rx);
+    app.tab.graph.generation = 3;
+    app.tab.graph.total = 4;
+    app.tab.oids.sorted_aliases = vec![NONE];
+    app.tab.graph_selected = 1;
+
+    app.dispatch_command(&Command::VisualMode);
+    app.tab.graph_selected = 2;
+    app.dispatch_command(&Command::Cherrypick);
+
+    let request_id = match rx.try_recv().unwrap() {
+        GraphCommand::Lookup { request_id, kind: GraphLookupKind::GraphOidRange { start, end }, .. } => {
+            assert_eq!((start, end), (1, 3));
+            request_id
+        },
+        other => panic!("expected range lookup, got {other:?}"),
+    };
+    assert_eq!(app.tab.focus, Focus::Viewport);
+
+    event_tx.send(GraphEvent::LookupResult { generation: 3, request_id, result: GraphLookupResult::Oids(vec![tip, child]) }).unwrap();
+    app.sync(&repo);
+
+    assert_eq!(app.tab.graph_marked_oids, vec![tip, child]);
+    assert_eq!(app.tab.visual, None);
+    assert_eq!(app.tab.focus, Focus::ModalBatch);
+    assert_eq!(app.batch_items(BatchAction::Cherrypick), vec!["tip".to_string(), "child".to_string()]);
+
+    app.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.modal_operation_kind, OperationKind::Cherrypick);
+    assert!(!app.tab.graph_marked_oids.contains(&root));
+
+    cleanup(path);
+}
+
+#[test]
+fn marked_commits_are_confirmed_once_before_they_are_replayed() {
+    let (path, repo) = temp_repo("replay");
+    commit_files(&repo, &["a.txt"], "root");
+    let child = commit_files(&repo, &["b.txt"], "child");
+    let mut app = repo_app(&path, repo, Focus::Viewport);
+    app.tab.graph_marked_oids = vec![child];
+    app.tab.oids.sorted_aliases = vec![NONE, app.tab.oids.get_alias_by_oid(child)];
+    app.tab.graph_selected = 1;
+
+    app.dispatch_command(&Command::Revert);
+    assert_eq!(app.tab.focus, Focus::ModalBatch);
+    assert_eq!(app.batch_items(BatchAction::Revert), vec!["child".to_string()]);
+
+    // Backing out keeps the marks and starts nothing.
+    app.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.graph_marked_oids, vec![child]);
+    assert_eq!(app.pending_operation_action, None);
+
+    app.dispatch_command(&Command::Revert);
+    app.confirm_batch();
+    assert_eq!(app.tab.focus, Focus::ModalOperationProgress);
+    assert_eq!(app.modal_operation_kind, OperationKind::Revert);
+
+    cleanup(path);
+}
+
+#[test]
+fn marked_remote_branches_are_deleted_in_one_push() {
+    let (path, repo) = temp_repo("remote-branches");
+    let head = commit_files(&repo, &["a.txt"], "base");
+    let origin = path.with_extension("origin");
+    Repository::init_bare(&origin).unwrap();
+    repo.remote("origin", origin.to_str().unwrap()).unwrap();
+    for name in ["a", "b", "c"] {
+        repo.branch(name, &repo.find_commit(head).unwrap(), false).unwrap();
+    }
+    {
+        let mut remote = repo.find_remote("origin").unwrap();
+        remote.push(&["refs/heads/a:refs/heads/a", "refs/heads/b:refs/heads/b"], None).unwrap();
+        remote.fetch(&["refs/heads/*:refs/remotes/origin/*"], None, None).unwrap();
+    }
+    repo.reference("refs/remotes/upstream/a", head, false, "test").unwrap();
+    let mut app = repo_app(&path, repo, Focus::Branches);
+    app.reload(None);
+
+    // Branches from two remotes would need two pushes, so the batch is refused before anything is deleted.
+    app.tab.pane_marks.branches = vec!["c".to_string(), "origin/a".to_string(), "upstream/a".to_string()];
+    app.dispatch_command(&Command::DeleteBranch);
+    assert_eq!(app.tab.focus, Focus::ModalError);
+    assert!(app.tab.repo.as_ref().unwrap().find_branch("c", git2::BranchType::Local).is_ok());
+
+    app.tab.focus = Focus::Branches;
+    app.tab.pane_marks.branches = vec!["origin/a".to_string(), "c".to_string(), "origin/b".to_string()];
+    app.dispatch_command(&Command::DeleteBranch);
+    assert_eq!(app.tab.focus, Focus::ModalBatch);
+    app.confirm_batch();
+
+    assert!(app.tab.repo.as_ref().unwrap().find_branch("c", git2::BranchType::Local).is_err());
+    assert_eq!(
+        app.pending_network_request,
+        Some(NetworkRequest::DeleteRemoteBranches { repo_path: path.display().to_string(), remote_name: "origin".to_string(), branches: vec!["a".to_string(), "b".to_string()] })
+    );
+    let result = app.network_handle.take().unwrap().join().unwrap();
+    assert!(matches!(result, NetworkResult::Success), "{result:?}");
+    app.handle_network_result(result);
+
+    let origin_repo = Repository::open_bare(&origin).unwrap();
+    assert!(origin_repo.find_reference("refs/heads/a").is_err());
+    assert!(origin_repo.find_reference("refs/heads/b").is_err());
+    assert_eq!(app.tab.focus, Focus::Viewport);
+
+    app.stop_graph_service();
+    let _ = fs::remove_dir_all(path.with_extension("walks"));
+    let _ = fs::remove_dir_all(origin);
+    cleanup(path);
+}
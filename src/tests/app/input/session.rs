@@ -0,0 +1,242 @@
+use super::*;
+use crate::{
+    app::app::{PendingGraphLookup, RepoTab},
+    core::{chunk::NONE, graph_service::GraphIndexIdentity},
+    git::queries::diffs::get_filenames_diff_at_oid,
+    helpers::session::{load_session_from_path, save_session_to_path},
+};
+use git2::{Repository, Signature};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-input-session-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn commit_file(repo: &Repository, file: &str, message: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), format!("{message}\n")).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+// Saved state lives beside the repository so it never touches the user config.
+fn session_app(path: &Path) -> App {
+    App {
+        journal_save_path: Some(path.with_extension("journal.json")),
+        recent_save_path: Some(path.with_extension("recent.json")),
+        marks_save_path: Some(path.with_extension("marks.json")),
+        session_save_path: Some(path.with_extension("session.json")),
+        walk_cache_dir: Some(path.with_extension("walks")),
+        ..Default::default()
+    }
+}
+
+fn cleanup(path: PathBuf) {
+    for extension in ["journal.json", "recent.json", "marks.json", "session.json"] {
+        let _ = fs::remove_file(path.with_extension(extension));
+    }
+    let _ = fs::remove_dir_all(path.with_extension("walks"));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn snapshot_records_the_selected_commit_and_focused_pane() {
+    let (path, repo) = temp_repo("snapshot");
+    let oid = commit_file(&repo, "a.txt", "base");
+    let mut app = App { tab: RepoTab { path: Some(path.display().to_string()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Tags, ..Default::default() }, ..session_app(&path) };
+    app.tab.oids.sorted_aliases = vec![NONE, app.tab.oids.get_alias_by_oid(oid)];
+    app.tab.graph_selected = 1;
+    app.tab.tags_selected = 3;
+
+    let session = app.session_snapshot().unwrap();
+
+    assert_eq!(session.oid, Some(oid.to_string()));
+    assert_eq!(session.offset, 1);
+    assert_eq!(session.pane, SessionPane::Tags);
+    assert_eq!(session.file_name, None);
+    assert_eq!(session.tags_selected, 3);
+
+    // The start screen has nothing worth restoring.
+    app.tab.viewport = Viewport::Splash;
+    assert_eq!(app.session_snapshot(), None);
+
+    cleanup(path);
+}
+
+#[test]
+fn restoring_reopens_the_viewer_on_the_saved_commit() {
+    let (path, repo) = temp_repo("viewer");
+    let oid = commit_file(&repo, "a.txt", "base");
+    let session_path = path.with_extension("session.json");
+    let repo_path = path.display().to_string();
+    save_session_to_path(
+        &session_path,
+        &RepositorySession {
+            path: repo_path.clone(),
+            oid: Some(oid.to_string()),
+            pane: SessionPane::Viewer,
+            file_name: Some("a.txt".to_string()),
+            viewer_mode: SessionViewerMode::Hunks,
+            tags_selected: 9,
+            ..Default::default()
+        },
+    );
+    let repo = Rc::new(repo);
+    let mut app = App { tab: RepoTab { path: Some(repo_path.clone()), repo: Some(repo.clone()), viewport: Viewport::Graph, ..Default::default() }, ..session_app(&path) };
+
+    let restore = app.begin_session_restore(&repo_path).unwrap();
+    assert_eq!(restore.oid, oid);
+
+    app.restore_session_pane_row(GraphPane::Tags, 4);
+    assert_eq!(app.tab.tags_selected, 3);
+    app.restore_session_pane_row(GraphPane::Tags, 10);
+    assert_eq!(app.tab.tags_selected, 3);
+
+    // The viewer waits for the diff service to list the commit's files.
+    app.tab.graph_selected = 1;
+    app.finish_session_restore(&repo);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert!(app.tab.pending_session.is_some());
+
+    app.tab.current_diff = get_filenames_diff_at_oid(&repo, oid);
+    app.tab.current_diff_identity = Some(GraphIndexIdentity { index: 1, alias: 1, oid });
+    app.finish_session_restore(&repo);
+
+    assert_eq!(app.tab.viewport, Viewport::Viewer);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.file_name.as_deref(), Some("a.txt"));
+    assert!(app.viewer_mode == ViewerMode::Hunks);
+    assert!(app.tab.pending_session.is_none());
+
+    cleanup(path);
+}
+
+#[test]
+fn a_missing_commit_falls_back_to_the_graph() {
+    let (path, repo) = temp_repo("missing");
+    commit_file(&repo, "a.txt", "base");
+    let repo_path = path.display().to_string();
+    save_session_to_path(
+        &path.with_extension("session.json"),
+        &RepositorySession { path: repo_path.clone(), oid: Some("1".repeat(40)), pane: SessionPane::Viewer, file_name: Some("a.txt".to_string()), ..Default::default() },
+    );
+    let repo = Rc::new(repo);
+    let mut app = App { tab: RepoTab { path: Some(repo_path.clone()), repo: Some(repo.clone()), viewport: Viewport::Graph, ..Default::default() }, ..session_app(&path) };
+
+    assert!(app.begin_session_restore(&repo_path).is_some());
+    app.abandon_session_restore(&repo);
+
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.file_name, None);
+    assert_eq!(app.tab.graph_selected, 0);
+
+    cleanup(path);
+}
+
+#[test]
+fn reopening_a_repository_selects_the_saved_commit_through_the_graph_worker() {
+    let (path, repo) = temp_repo("reload");
+    let base = commit_file(&repo, "a.txt", "base");
+    commit_file(&repo, "b.txt", "tip");
+    let repo_path = path.display().to_string();
+    save_session_to_path(&path.with_extension("session.json"), &RepositorySession { path: repo_path.clone(), oid: Some(base.to_string()), pane: SessionPane::Branches, ..Default::default() });
+    let mut app = session_app(&path);
+
+    app.reload(Some(repo_path.clone()));
+    let repo = app.tab.repo.clone().unwrap();
+    let started = Instant::now();
+    while app.tab.pending_session.is_some() && started.elapsed() < Duration::from_secs(10) {
+        app.sync(&repo);
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    // Row 0 is the uncommitted row and the tip comes first.
+    assert_eq!(app.tab.graph_selected, 2);
+    assert_eq!(app.tab.focus, Focus::Branches);
+
+    // Leaving for another path saves where this repository was left, once its row is on screen.
+    app.request_graph_row_lookup(2, PendingGraphLookup::CacheGraphRow);
+    while app.graph_row_at(2).is_none() && started.elapsed() < Duration::from_secs(10) {
+        app.sync(&repo);
+        thread::sleep(Duration::from_millis(5));
+    }
+    app.tab.focus = Focus::Viewport;
+    app.reload(Some(std::env::temp_dir().join("guitar-input-session-nowhere").display().to_string()));
+    let saved = load_session_from_path(&path.with_extension("session.json"), &repo_path).unwrap();
+    assert_eq!(saved.oid, Some(base.to_string()));
+    assert_eq!(saved.pane, SessionPane::Graph);
+
+    cleanup(path);
+}
+
+#[test]
+fn reopening_a_repository_restores_pane_rows_by_name_and_the_viewer_from_the_diff_service() {
+    let (path, repo) = temp_repo("names");
+    commit_file(&repo, "a.txt", "base");
+    let tip = commit_file(&repo, "b.txt", "tip");
+    let head = repo.find_commit(tip).unwrap();
+    for name in ["alpha", "zulu"] {
+        repo.branch(name, &head, false).unwrap();
+        repo.tag_lightweight(name, head.as_object(), false).unwrap();
+    }
+    let repo_path = path.display().to_string();
+    // The saved indices point at other rows; the names win.
+    save_session_to_path(
+        &path.with_extension("session.json"),
+        &RepositorySession {
+            path: repo_path.clone(),
+            oid: Some(tip.to_string()),
+            pane: SessionPane::Viewer,
+            file_name: Some("b.txt".to_string()),
+            branch_name: Some("zulu".to_string()),
+            tag_name: Some("zulu".to_string()),
+            tags_selected: 0,
+            ..Default::default()
+        },
+    );
+    let mut app = session_app(&path);
+
+    app.reload(Some(repo_path.clone()));
+    let repo = app.tab.repo.clone().unwrap();
+    let started = Instant::now();
+    while (app.tab.pending_session.is_some() || !app.tab.pending_session_rows.is_empty() || app.tab.graph.pending_lookup.is_some()) && started.elapsed() < Duration::from_secs(10) {
+        // Drawing is what asks for the windows, clamped to the rows there are.
+        if app.tab.graph.total > 0 {
+            app.request_graph_window(0, app.tab.graph.total);
+        }
+        app.request_pane_window(GraphPane::Branches, 0, 10);
+        app.request_pane_window(GraphPane::Tags, 0, 10);
+        app.request_pane_window(GraphPane::Stashes, 0, 10);
+        app.sync(&repo);
+        thread::sleep(Duration::from_millis(5));
+    }
+
+    // Local branches sort as alpha, master, zulu.
+    assert_eq!(app.tab.branches_selected, 2);
+    assert_eq!(app.tab.tags_selected, 1);
+    assert_eq!(app.tab.viewport, Viewport::Viewer);
+    assert_eq!(app.tab.file_name.as_deref(), Some("b.txt"));
+
+    cleanup(path);
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::app::app::RepoTab;
 use crate::core::submodules::{SubmoduleEntry, SubmoduleStackEntry, Submodules};
 use crate::git::{actions::network::NetworkRequest, auth::NetworkResult, queries::submodules::list_submodules};
 use crate::helpers::keymap::{Command, InputMode, KeyBinding};
@@ -115,20 +116,23 @@ fn submodule_action_keymaps() -> IndexMap<InputMode, IndexMap<KeyBinding, Comman
 
 #[test]
 fn selected_submodule_name_reads_current_selection() {
-    let app = App { submodules: Submodules::from_entries(vec![entry("first", true), entry("second", true)]), submodules_selected: 1, ..Default::default() };
+    let app = App { tab: RepoTab { submodules: Submodules::from_entries(vec![entry("first", true), entry("second", true)]), submodules_selected: 1, ..Default::default() }, ..Default::default() };
 
     assert_eq!(app.selected_submodule_name().as_deref(), Some("second"));
 }
 
 #[test]
 fn opening_uninitialized_submodule_shows_error() {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Submodules, submodules: Submodules::from_entries(vec![entry("deps/child", false)]), ..Default::default() };
+    let mut app = App {
+        tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Submodules, submodules: Submodules::from_entries(vec![entry("deps/child", false)]), ..Default::default() },
+        ..Default::default()
+    };
 
     app.open_selected_submodule();
 
-    assert_eq!(app.focus, Focus::ModalError);
+    assert_eq!(app.tab.focus, Focus::ModalError);
     assert!(app.modal_error_message.contains("not initialized"));
-    assert!(app.submodule_stack.is_empty());
+    assert!(app.tab.submodule_stack.is_empty());
 }
 
 #[test]
@@ -139,24 +143,29 @@ fn opening_checked_out_submodule_pushes_stack_entry_and_reloads_submodule() {
     let entries = list_submodules(&parent).unwrap();
     let child_path = fs::canonicalize(entries[0].absolute_path.clone()).unwrap();
     let mut app = App {
-        path: Some(parent_path.display().to_string()),
-        repo: Some(Rc::new(parent)),
-        viewport: Viewport::Graph,
-        focus: Focus::Submodules,
-        submodules: Submodules::from_entries(entries),
         recent_save_path: Some(dir.path.join("recent-open.json")),
+        session_save_path: Some(dir.path.join("session-open.json")),
+        walk_cache_dir: Some(dir.path.join("walks-open")),
+        tab: RepoTab {
+            path: Some(parent_path.display().to_string()),
+            repo: Some(Rc::new(parent)),
+            viewport: Viewport::Graph,
+            focus: Focus::Submodules,
+            submodules: Submodules::from_entries(entries),
+            ..Default::default()
+        },
         ..Default::default()
     };
 
     app.open_selected_submodule();
 
-    assert_eq!(app.submodule_stack.len(), 1);
-    assert_eq!(app.submodule_stack[0].parent_path, parent_path);
-    assert_eq!(app.submodule_stack[0].submodule_path, PathBuf::from("deps/child"));
-    assert_eq!(app.submodule_stack[0].submodule_name, "deps/child");
-    assert_eq!(app.path, Some(child_path.display().to_string()));
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Graph);
+    assert_eq!(app.tab.submodule_stack.len(), 1);
+    assert_eq!(app.tab.submodule_stack[0].parent_path, parent_path);
+    assert_eq!(app.tab.submodule_stack[0].submodule_path, PathBuf::from("deps/child"));
+    assert_eq!(app.tab.submodule_stack[0].submodule_name, "deps/child");
+    assert_eq!(app.tab.path, Some(child_path.display().to_string()));
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
 }
 
 #[test]
@@ -172,23 +181,28 @@ fn opening_nested_submodule_appends_to_existing_stack() {
     nested.path = PathBuf::from("vendor/grandchild");
     nested.absolute_path = grandchild_path.clone();
     let mut app = App {
-        path: Some(child_path.display().to_string()),
-        repo: Some(Rc::new(child)),
-        viewport: Viewport::Graph,
-        focus: Focus::Submodules,
-        submodules: Submodules::from_entries(vec![nested]),
-        submodule_stack: vec![SubmoduleStackEntry::new(root_path.clone(), PathBuf::from("deps/child"), "deps/child".into())],
         recent_save_path: Some(dir.path.join("recent-nested.json")),
+        session_save_path: Some(dir.path.join("session-nested.json")),
+        walk_cache_dir: Some(dir.path.join("walks-nested")),
+        tab: RepoTab {
+            path: Some(child_path.display().to_string()),
+            repo: Some(Rc::new(child)),
+            viewport: Viewport::Graph,
+            focus: Focus::Submodules,
+            submodules: Submodules::from_entries(vec![nested]),
+            submodule_stack: vec![SubmoduleStackEntry::new(root_path.clone(), PathBuf::from("deps/child"), "deps/child".into())],
+            ..Default::default()
+        },
         ..Default::default()
     };
 
     app.open_selected_submodule();
 
-    assert_eq!(app.submodule_stack.len(), 2);
-    assert_eq!(app.submodule_stack[0].parent_path, root_path);
-    assert_eq!(app.submodule_stack[1].parent_path, child_path);
-    assert_eq!(app.submodule_stack[1].submodule_path, PathBuf::from("vendor/grandchild"));
-    assert_eq!(app.path, Some(grandchild_path.display().to_string()));
+    assert_eq!(app.tab.submodule_stack.len(), 2);
+    assert_eq!(app.tab.submodule_stack[0].parent_path, root_path);
+    assert_eq!(app.tab.submodule_stack[1].parent_path, child_path);
+    assert_eq!(app.tab.submodule_stack[1].submodule_path, PathBuf::from("vendor/grandchild"));
+    assert_eq!(app.tab.path, Some(grandchild_path.display().to_string()));
 }
 
 #[test]
@@ -200,32 +214,37 @@ fn return_to_parent_repository_pops_one_stack_entry_and_reloads_parent() {
     let child_path = fs::canonicalize(child.workdir().unwrap()).unwrap();
     drop(parent);
     let mut app = App {
-        path: Some(child_path.display().to_string()),
-        repo: Some(Rc::new(child)),
-        viewport: Viewport::Graph,
-        focus: Focus::Submodules,
-        submodule_stack: vec![SubmoduleStackEntry::new(parent_path.clone(), PathBuf::from("deps/child"), "deps/child".into())],
         recent_save_path: Some(dir.path.join("recent-return.json")),
+        session_save_path: Some(dir.path.join("session-return.json")),
+        walk_cache_dir: Some(dir.path.join("walks-return")),
+        tab: RepoTab {
+            path: Some(child_path.display().to_string()),
+            repo: Some(Rc::new(child)),
+            viewport: Viewport::Graph,
+            focus: Focus::Submodules,
+            submodule_stack: vec![SubmoduleStackEntry::new(parent_path.clone(), PathBuf::from("deps/child"), "deps/child".into())],
+            ..Default::default()
+        },
         ..Default::default()
     };
 
     app.on_return_to_parent_repository();
 
-    assert!(app.submodule_stack.is_empty());
-    assert_eq!(app.path, Some(parent_path.display().to_string()));
-    assert_eq!(app.focus, Focus::Viewport);
-    assert_eq!(app.viewport, Viewport::Graph);
+    assert!(app.tab.submodule_stack.is_empty());
+    assert_eq!(app.tab.path, Some(parent_path.display().to_string()));
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
 }
 
 #[test]
 fn return_to_parent_repository_noops_with_empty_stack() {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Submodules, graph_selected: 7, ..Default::default() };
+    let mut app = App { tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Submodules, graph_selected: 7, ..Default::default() }, ..Default::default() };
 
     app.on_return_to_parent_repository();
 
-    assert!(app.submodule_stack.is_empty());
-    assert_eq!(app.focus, Focus::Submodules);
-    assert_eq!(app.graph_selected, 7);
+    assert!(app.tab.submodule_stack.is_empty());
+    assert_eq!(app.tab.focus, Focus::Submodules);
+    assert_eq!(app.tab.graph_selected, 7);
 }
 
 #[test]
@@ -236,12 +255,15 @@ fn action_keys_dispatch_update_and_sync_submodule() {
     let entries = list_submodules(&parent).unwrap();
 
     let mut update_app = App {
-        path: Some(parent_path.clone()),
-        repo: Some(Rc::new(parent)),
-        viewport: Viewport::Graph,
-        focus: Focus::Submodules,
-        submodules: Submodules::from_entries(entries),
         keymaps: submodule_action_keymaps(),
+        tab: RepoTab {
+            path: Some(parent_path.clone()),
+            repo: Some(Rc::new(parent)),
+            viewport: Viewport::Graph,
+            focus: Focus::Submodules,
+            submodules: Submodules::from_entries(entries),
+            ..Default::default()
+        },
         ..Default::default()
     };
 
@@ -249,7 +271,7 @@ fn action_keys_dispatch_update_and_sync_submodule() {
     update_app.handle_key_event(KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE));
 
     assert_eq!(update_app.pending_network_request, Some(NetworkRequest::UpdateSubmodule { repo_path: parent_path.clone(), name: "deps/child".into() }));
-    assert_eq!(update_app.focus, Focus::ModalNetworkProgress);
+    assert_eq!(update_app.tab.focus, Focus::ModalNetworkProgress);
     let handle = update_app.network_handle.take().expect("update should start a network worker");
     match handle.join().unwrap() {
         NetworkResult::Success => {},
@@ -259,20 +281,17 @@ fn action_keys_dispatch_update_and_sync_submodule() {
     let parent = Repository::open(&parent_path).unwrap();
     let entries = list_submodules(&parent).unwrap();
     let mut sync_app = App {
-        path: Some(parent_path),
-        repo: Some(Rc::new(parent)),
-        viewport: Viewport::Graph,
-        focus: Focus::Submodules,
-        submodules: Submodules::from_entries(entries),
         keymaps: submodule_action_keymaps(),
         recent_save_path: Some(dir.path.join("recent.json")),
+        walk_cache_dir: Some(dir.path.join("walks")),
+        tab: RepoTab { path: Some(parent_path), repo: Some(Rc::new(parent)), viewport: Viewport::Graph, focus: Focus::Submodules, submodules: Submodules::from_entries(entries), ..Default::default() },
         ..Default::default()
     };
 
     sync_app.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL));
     sync_app.handle_key_event(KeyEvent::new(KeyCode::Char('I'), KeyModifiers::SHIFT));
 
-    assert_eq!(sync_app.focus, Focus::Submodules);
+    assert_eq!(sync_app.tab.focus, Focus::Submodules);
     assert!(sync_app.pending_network_request.is_none());
     assert!(sync_app.network_handle.is_none());
 }
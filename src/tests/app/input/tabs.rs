@@ -0,0 +1,92 @@
+use crate::{
+    app::app::{App, Focus, RepoTab, Viewport},
+    core::graph_service::GraphCommand,
+    helpers::keymap::Command,
+};
+
+fn repo_tab_app() -> App {
+    App {
+        tab: RepoTab { path: Some("/tmp/guitar-tabs-first".to_string()), viewport: Viewport::Graph, focus: Focus::Branches, graph_selected: 5, branches_selected: 2, ..Default::default() },
+        ..Default::default()
+    }
+}
+
+#[test]
+fn switching_tabs_parks_and_restores_repository_state() {
+    let mut app = repo_tab_app();
+    app.tab.graph.is_complete = true;
+    assert!(app.tab.commit_graph.set(None).is_ok());
+
+    app.dispatch_command(&Command::NewTab);
+    assert!(app.tab.commit_graph.get().is_none());
+
+    assert_eq!((app.tabs.len(), app.active_tab), (2, 1));
+    assert_eq!(app.tab.path, None);
+    assert_eq!(app.tab.viewport, Viewport::Splash);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert_eq!(app.tab.graph_selected, 0);
+
+    app.dispatch_command(&Command::PreviousTab);
+
+    assert_eq!(app.active_tab, 0);
+    assert_eq!(app.tab.path.as_deref(), Some("/tmp/guitar-tabs-first"));
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Branches);
+    assert_eq!((app.tab.graph_selected, app.tab.branches_selected), (5, 2));
+    assert!(app.tab.graph.is_complete);
+    assert!(app.tab.commit_graph.get().is_some());
+    assert_eq!(app.tab_names(), vec![Some("guitar-tabs-first".to_string()), None]);
+
+    // Cycling wraps around in both directions.
+    app.dispatch_command(&Command::PreviousTab);
+    assert_eq!(app.active_tab, 1);
+    app.dispatch_command(&Command::NextTab);
+    assert_eq!(app.active_tab, 0);
+}
+
+#[test]
+fn closing_a_tab_shuts_down_its_graph_service() {
+    let mut app = repo_tab_app();
+    let (tx, rx) = std::sync::mpsc::channel();
+    app.tab.graph_tx = Some(tx);
+
+    // The last tab stays open.
+    app.dispatch_command(&Command::CloseTab);
+    assert_eq!(app.tabs.len(), 1);
+    assert!(app.tab.graph_tx.is_some());
+
+    app.dispatch_command(&Command::NewTab);
+    app.dispatch_command(&Command::PreviousTab);
+    app.dispatch_command(&Command::CloseTab);
+
+    assert!(matches!(rx.try_iter().last(), Some(GraphCommand::Shutdown)));
+    assert_eq!((app.tabs.len(), app.active_tab), (1, 0));
+    assert_eq!(app.tab.path, None);
+    assert!(app.tab.graph_tx.is_none());
+}
+
+#[test]
+fn tabs_do_not_switch_while_an_operation_is_pending() {
+    let mut app = repo_tab_app();
+    app.dispatch_command(&Command::NewTab);
+    app.pending_operation_action = Some(crate::app::app::PendingOperationAction::Continue);
+
+    app.dispatch_command(&Command::PreviousTab);
+
+    assert_eq!(app.active_tab, 1);
+    assert_eq!(app.tab.focus, Focus::ModalError);
+}
+
+#[test]
+fn parked_tabs_pause_their_graph_service() {
+    let mut app = repo_tab_app();
+    let (tx, rx) = std::sync::mpsc::channel();
+    app.tab.graph_tx = Some(tx);
+
+    app.dispatch_command(&Command::NewTab);
+    assert!(matches!(rx.try_recv(), Ok(GraphCommand::Pause { is_paused: true })));
+    assert!(rx.try_recv().is_err());
+
+    app.dispatch_command(&Command::PreviousTab);
+    assert!(matches!(rx.try_recv(), Ok(GraphCommand::Pause { is_paused: false })));
+}
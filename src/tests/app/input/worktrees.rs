@@ -1,4 +1,5 @@
 use super::*;
+use crate::app::app::RepoTab;
 use crate::core::{
     submodules::SubmoduleStackEntry,
     worktrees::{WorktreeEntry, WorktreeKind, Worktrees},
@@ -26,13 +27,13 @@ fn worktree_entry(name: &str, head: Oid) -> WorktreeEntry {
 }
 
 fn app_with_graph_worktrees(entries: Vec<WorktreeEntry>) -> App {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
+    let mut app = App { tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
 
     let head = entries.first().and_then(|entry| entry.head).unwrap_or_else(|| test_oid(1));
-    let alias = app.oids.get_alias_by_oid(head);
-    let uncommitted = app.oids.sorted_aliases[0];
-    app.oids.sorted_aliases = vec![uncommitted, alias];
-    app.worktrees = Worktrees::from_entries(entries);
+    let alias = app.tab.oids.get_alias_by_oid(head);
+    let uncommitted = app.tab.oids.sorted_aliases[0];
+    app.tab.oids.sorted_aliases = vec![uncommitted, alias];
+    app.tab.worktrees = Worktrees::from_entries(entries);
     app
 }
 
@@ -53,16 +54,17 @@ fn graph_worktree_target_resolution_handles_zero_one_and_multiple_rows() {
 fn opening_worktree_clears_submodule_stack() {
     let head = test_oid(7);
     let mut app = App {
-        focus: Focus::Worktrees,
-        submodule_stack: vec![SubmoduleStackEntry::new(PathBuf::from("/repo"), PathBuf::from("deps/child"), "deps/child".into())],
         recent_save_path: Some(PathBuf::from("/tmp/guitar-worktree-stack-recent.json")),
+        walk_cache_dir: Some(PathBuf::from("/tmp/guitar-worktree-stack-walks")),
         ..app_with_graph_worktrees(vec![worktree_entry("feature", head)])
     };
+    app.tab.focus = Focus::Worktrees;
+    app.tab.submodule_stack = vec![SubmoduleStackEntry::new(PathBuf::from("/repo"), PathBuf::from("deps/child"), "deps/child".into())];
 
     app.open_selected_worktree();
 
-    assert!(app.submodule_stack.is_empty());
-    assert_eq!(app.focus, Focus::Viewport);
+    assert!(app.tab.submodule_stack.is_empty());
+    assert_eq!(app.tab.focus, Focus::Viewport);
 }
 
 #[test]
@@ -72,7 +74,7 @@ fn graph_enter_opens_chooser_for_multiple_valid_worktrees() {
 
     app.on_select();
 
-    assert_eq!(app.focus, Focus::ModalWorktreeChooser);
+    assert_eq!(app.tab.focus, Focus::ModalWorktreeChooser);
     assert_eq!(app.modal_worktree_action, WorktreeModalAction::Open);
     assert_eq!(app.modal_worktree_candidates, vec![0, 1]);
     assert_eq!(app.modal_worktree_return_focus, Focus::Viewport);
@@ -91,7 +93,7 @@ fn graph_remove_uses_existing_worktree_removal_guards() {
 
     app.on_remove_worktree();
 
-    assert_eq!(app.focus, Focus::ModalError);
+    assert_eq!(app.tab.focus, Focus::ModalError);
     assert!(app.modal_error_message.contains("cannot remove current, main, or locked worktrees"));
 }
 
@@ -100,13 +102,13 @@ fn graph_remove_opens_confirmation_or_chooser_for_removable_worktrees() {
     let head = test_oid(5);
     let mut one = app_with_graph_worktrees(vec![worktree_entry("feature", head)]);
     one.on_remove_worktree();
-    assert_eq!(one.focus, Focus::ModalRemoveWorktree);
+    assert_eq!(one.tab.focus, Focus::ModalRemoveWorktree);
     assert_eq!(one.modal_worktree_target, Some(0));
     assert_eq!(one.modal_worktree_return_focus, Focus::Viewport);
 
     let mut multiple = app_with_graph_worktrees(vec![worktree_entry("feature", head), worktree_entry("review", head)]);
     multiple.on_remove_worktree();
-    assert_eq!(multiple.focus, Focus::ModalWorktreeChooser);
+    assert_eq!(multiple.tab.focus, Focus::ModalWorktreeChooser);
     assert_eq!(multiple.modal_worktree_action, WorktreeModalAction::Remove);
     assert_eq!(multiple.modal_worktree_candidates, vec![0, 1]);
 }
@@ -119,12 +121,12 @@ fn worktree_chooser_confirmation_routes_open_and_remove_actions() {
     let mut open = app_with_graph_worktrees(vec![invalid]);
     open.open_worktree_chooser(WorktreeModalAction::Open, vec![0], Focus::Viewport);
     open.confirm_worktree_chooser();
-    assert_eq!(open.focus, Focus::ModalError);
+    assert_eq!(open.tab.focus, Focus::ModalError);
     assert!(open.modal_error_message.contains("path is invalid"));
 
     let mut remove = app_with_graph_worktrees(vec![worktree_entry("feature", head)]);
     remove.open_worktree_chooser(WorktreeModalAction::Remove, vec![0], Focus::Viewport);
     remove.confirm_worktree_chooser();
-    assert_eq!(remove.focus, Focus::ModalRemoveWorktree);
+    assert_eq!(remove.tab.focus, Focus::ModalRemoveWorktree);
     assert_eq!(remove.modal_worktree_target, Some(0));
 }
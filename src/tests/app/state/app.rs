@@ -103,6 +103,7 @@ fn graph_row(index: usize, alias: u32, oid: git2::Oid) -> GraphRow {
         branches: Vec::new(),
         tags: Vec::new(),
         is_stash: false,
+        is_shallow: false,
         stash_lane: None,
         worktrees: Vec::new(),
         reflog: None,
@@ -114,13 +115,13 @@ fn history_row(index: usize, oid: git2::Oid) -> GraphFileHistoryRow {
 }
 
 fn stop_graph_service(app: &mut App) {
-    if let Some(tx) = app.graph_tx.take() {
+    if let Some(tx) = app.tab.graph_tx.take() {
         let _ = tx.send(GraphCommand::Shutdown);
     }
-    if let Some(cancel) = app.walker_cancel.take() {
+    if let Some(cancel) = app.tab.walker_cancel.take() {
         cancel.store(true, Ordering::SeqCst);
     }
-    if let Some(handle) = app.walker_handle.take() {
+    if let Some(handle) = app.tab.walker_handle.take() {
         let _ = handle.join();
     }
 }
@@ -141,7 +142,7 @@ fn default_splash_draw_has_no_reset_backgrounds() {
 fn splash_draws_recent_repository_actions() {
     let backend = TestBackend::new(140, 24);
     let mut terminal = Terminal::new(backend).unwrap();
-    let mut app = App { viewport: Viewport::Splash, focus: Focus::Viewport, recent: vec!["/repo/a".into(), "/repo/b".into()], ..Default::default() };
+    let mut app = App { recent: vec!["/repo/a".into(), "/repo/b".into()], tab: RepoTab { viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
     app.layout.app = Rect::new(0, 0, 140, 24);
     app.layout.graph = Rect::new(0, 0, 140, 24);
 
@@ -159,14 +160,18 @@ fn reload_captures_selected_commit_oid_and_visual_offset_for_restore() {
     let (path, repo) = temp_repo("restore-capture");
     let oid = commit_file(&repo, "selected.txt", "selected");
     let path_string = path.display().to_string();
-    let mut app =
-        App { path: Some(path_string.clone()), recent: vec![path_string], repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 4, ..Default::default() };
-    app.graph_scroll.set(2);
-    app.graph.graph_window = Some(GraphWindowCache { version: 1, start: 4, end: 5, head_alias: 9, rows: vec![graph_row(4, 9, oid)], history: Default::default() });
+    let mut app = App {
+        tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 4, ..Default::default() },
+        recent: vec![path_string],
+        walk_cache_dir: Some(path.with_extension("walks")),
+        ..Default::default()
+    };
+    app.tab.graph_scroll.set(2);
+    app.tab.graph.graph_window = Some(GraphWindowCache { version: 1, start: 4, end: 5, head_alias: 9, rows: vec![graph_row(4, 9, oid)], history: Default::default() });
 
     app.reload(None);
 
-    assert_eq!(app.graph.pending_selection_restore, Some(GraphSelectionRestore { oid, selected_offset: 2 }));
+    assert_eq!(app.tab.graph.pending_selection_restore, Some(GraphSelectionRestore { oid, selected_offset: 2 }));
     stop_graph_service(&mut app);
 }
 
@@ -175,13 +180,17 @@ fn reload_keeps_uncommitted_row_without_restore_lookup() {
     let (path, repo) = temp_repo("restore-uncommitted");
     commit_file(&repo, "head.txt", "head");
     let path_string = path.display().to_string();
-    let mut app =
-        App { path: Some(path_string.clone()), recent: vec![path_string], repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 0, ..Default::default() };
+    let mut app = App {
+        tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 0, ..Default::default() },
+        recent: vec![path_string],
+        walk_cache_dir: Some(path.with_extension("walks")),
+        ..Default::default()
+    };
 
     app.reload(None);
 
-    assert_eq!(app.graph_selected, 0);
-    assert_eq!(app.graph.pending_selection_restore, None);
+    assert_eq!(app.tab.graph_selected, 0);
+    assert_eq!(app.tab.graph.pending_selection_restore, None);
     stop_graph_service(&mut app);
 }
 
@@ -192,9 +201,12 @@ fn pending_restore_requests_oid_lookup_on_progress() {
     let repo = Rc::new(repo);
     let (cmd_tx, cmd_rx) = std::sync::mpsc::channel();
     let (event_tx, event_rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(repo.clone()), graph_tx: Some(cmd_tx), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() };
-    app.graph.generation = 7;
-    app.graph.pending_selection_restore = Some(GraphSelectionRestore { oid, selected_offset: 2 });
+    let mut app = App {
+        tab: RepoTab { repo: Some(repo.clone()), graph_tx: Some(cmd_tx), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() },
+        ..Default::default()
+    };
+    app.tab.graph.generation = 7;
+    app.tab.graph.pending_selection_restore = Some(GraphSelectionRestore { oid, selected_offset: 2 });
 
     event_tx.send(GraphEvent::Progress { generation: 7, version: 1, total: 2, is_first: false, is_complete: false }).unwrap();
     app.sync(&repo);
@@ -208,7 +220,7 @@ fn pending_restore_requests_oid_lookup_on_progress() {
         other => panic!("expected oid restore lookup, got {other:?}"),
     }
 
-    let (pending_id, pending_action) = app.graph.pending_lookup.unwrap();
+    let (pending_id, pending_action) = app.tab.graph.pending_lookup.unwrap();
     assert_eq!(pending_id, 1);
     assert!(matches!(pending_action, PendingGraphLookup::RestoreSelection));
 }
@@ -220,16 +232,16 @@ fn first_graph_progress_with_dirty_submodule_status_stays_in_graph_view() {
     fs::write(parent.workdir().unwrap().join("deps/child/file.txt"), "dirty\n").unwrap();
     let repo = Rc::new(parent);
     let (event_tx, event_rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(repo.clone()), graph_rx: Some(event_rx), viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() };
-    app.graph.generation = 9;
+    let mut app = App { tab: RepoTab { repo: Some(repo.clone()), graph_rx: Some(event_rx), viewport: Viewport::Splash, focus: Focus::Viewport, ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 9;
 
     event_tx.send(GraphEvent::Progress { generation: 9, version: 1, total: 1, is_first: true, is_complete: false }).unwrap();
     app.sync(&repo);
 
-    assert_eq!(app.viewport, Viewport::Graph);
-    assert_eq!(app.focus, Focus::Viewport);
-    assert!(app.is_uncommitted_loaded);
-    assert!(app.uncommitted.is_clean);
+    assert_eq!(app.tab.viewport, Viewport::Graph);
+    assert_eq!(app.tab.focus, Focus::Viewport);
+    assert!(app.tab.is_uncommitted_loaded);
+    assert!(app.tab.uncommitted.is_clean);
 }
 
 #[test]
@@ -238,18 +250,19 @@ fn restore_lookup_success_selects_index_and_preserves_visual_offset() {
     let oid = commit_file(&repo, "selected.txt", "selected");
     let repo = Rc::new(repo);
     let (event_tx, event_rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(repo.clone()), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    app.graph.generation = 7;
-    app.graph.total = 10;
-    app.graph.pending_selection_restore = Some(GraphSelectionRestore { oid, selected_offset: 2 });
-    app.graph.pending_lookup = Some((3, PendingGraphLookup::RestoreSelection));
+    let mut app =
+        App { tab: RepoTab { repo: Some(repo.clone()), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
+    app.tab.graph.total = 10;
+    app.tab.graph.pending_selection_restore = Some(GraphSelectionRestore { oid, selected_offset: 2 });
+    app.tab.graph.pending_lookup = Some((3, PendingGraphLookup::RestoreSelection));
 
     event_tx.send(GraphEvent::LookupResult { generation: 7, request_id: 3, result: GraphLookupResult::Index(Some(4)) }).unwrap();
     app.sync(&repo);
 
-    assert_eq!(app.graph_selected, 4);
-    assert_eq!(app.graph_scroll.get(), 2);
-    assert_eq!(app.graph.pending_selection_restore, None);
+    assert_eq!(app.tab.graph_selected, 4);
+    assert_eq!(app.tab.graph_scroll.get(), 2);
+    assert_eq!(app.tab.graph.pending_selection_restore, None);
 }
 
 #[test]
@@ -258,18 +271,19 @@ fn restore_lookup_success_clamps_scroll_offset_near_graph_top() {
     let oid = commit_file(&repo, "selected.txt", "selected");
     let repo = Rc::new(repo);
     let (event_tx, event_rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(repo.clone()), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 6, ..Default::default() };
-    app.graph.generation = 7;
-    app.graph.total = 10;
-    app.graph.pending_selection_restore = Some(GraphSelectionRestore { oid, selected_offset: 4 });
-    app.graph.pending_lookup = Some((3, PendingGraphLookup::RestoreSelection));
+    let mut app =
+        App { tab: RepoTab { repo: Some(repo.clone()), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 6, ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
+    app.tab.graph.total = 10;
+    app.tab.graph.pending_selection_restore = Some(GraphSelectionRestore { oid, selected_offset: 4 });
+    app.tab.graph.pending_lookup = Some((3, PendingGraphLookup::RestoreSelection));
 
     event_tx.send(GraphEvent::LookupResult { generation: 7, request_id: 3, result: GraphLookupResult::Index(Some(1)) }).unwrap();
     app.sync(&repo);
 
-    assert_eq!(app.graph_selected, 1);
-    assert_eq!(app.graph_scroll.get(), 0);
-    assert_eq!(app.graph.pending_selection_restore, None);
+    assert_eq!(app.tab.graph_selected, 1);
+    assert_eq!(app.tab.graph_scroll.get(), 0);
+    assert_eq!(app.tab.graph.pending_selection_restore, None);
 }
 
 #[test]
@@ -278,30 +292,31 @@ fn restore_lookup_missing_after_completion_clears_pending_restore() {
     let oid = commit_file(&repo, "selected.txt", "selected");
     let repo = Rc::new(repo);
     let (event_tx, event_rx) = std::sync::mpsc::channel();
-    let mut app = App { repo: Some(repo.clone()), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 2, ..Default::default() };
-    app.graph.generation = 7;
-    app.graph.total = 6;
-    app.graph.is_complete = true;
-    app.graph.pending_selection_restore = Some(GraphSelectionRestore { oid, selected_offset: 2 });
-    app.graph.pending_lookup = Some((3, PendingGraphLookup::RestoreSelection));
+    let mut app =
+        App { tab: RepoTab { repo: Some(repo.clone()), graph_rx: Some(event_rx), viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 2, ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
+    app.tab.graph.total = 6;
+    app.tab.graph.is_complete = true;
+    app.tab.graph.pending_selection_restore = Some(GraphSelectionRestore { oid, selected_offset: 2 });
+    app.tab.graph.pending_lookup = Some((3, PendingGraphLookup::RestoreSelection));
 
     event_tx.send(GraphEvent::LookupResult { generation: 7, request_id: 3, result: GraphLookupResult::Index(None) }).unwrap();
     app.sync(&repo);
 
-    assert_eq!(app.graph_selected, 2);
-    assert_eq!(app.graph.pending_selection_restore, None);
+    assert_eq!(app.tab.graph_selected, 2);
+    assert_eq!(app.tab.graph.pending_selection_restore, None);
 }
 
 #[test]
 fn explicit_graph_navigation_clears_pending_restore() {
-    let mut app = App { viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() };
-    app.graph.total = 5;
-    app.graph.pending_selection_restore = Some(GraphSelectionRestore { oid: git2::Oid::zero(), selected_offset: 0 });
+    let mut app = App { tab: RepoTab { viewport: Viewport::Graph, focus: Focus::Viewport, graph_selected: 1, ..Default::default() }, ..Default::default() };
+    app.tab.graph.total = 5;
+    app.tab.graph.pending_selection_restore = Some(GraphSelectionRestore { oid: git2::Oid::zero(), selected_offset: 0 });
 
     app.on_scroll_down();
 
-    assert_eq!(app.graph_selected, 2);
-    assert_eq!(app.graph.pending_selection_restore, None);
+    assert_eq!(app.tab.graph_selected, 2);
+    assert_eq!(app.tab.graph.pending_selection_restore, None);
 }
 
 #[test]
@@ -311,40 +326,43 @@ fn file_history_event_updates_only_matching_request() {
     let repo = Rc::new(repo);
     let (event_tx, event_rx) = std::sync::mpsc::channel();
     let mut app = App {
-        repo: Some(repo.clone()),
-        graph_rx: Some(event_rx),
-        viewport: Viewport::Graph,
-        focus: Focus::Search,
-        search_path: Some("target.txt".to_string()),
-        search_request_id: Some(3),
-        search_is_loading: true,
+        tab: RepoTab {
+            repo: Some(repo.clone()),
+            graph_rx: Some(event_rx),
+            viewport: Viewport::Graph,
+            focus: Focus::Search,
+            search_path: Some("target.txt".to_string()),
+            search_request_id: Some(3),
+            search_is_loading: true,
+            ..Default::default()
+        },
         ..Default::default()
     };
-    app.graph.generation = 7;
+    app.tab.graph.generation = 7;
 
     event_tx.send(GraphEvent::FileHistory { generation: 7, request_id: 2, path: "target.txt".to_string(), rows: vec![history_row(1, oid)], error: None }).unwrap();
     app.sync(&repo);
 
-    assert!(app.search_is_loading);
-    assert!(app.search_rows.is_empty());
-    assert_eq!(app.search_request_id, Some(3));
+    assert!(app.tab.search_is_loading);
+    assert!(app.tab.search_rows.is_empty());
+    assert_eq!(app.tab.search_request_id, Some(3));
 
     event_tx.send(GraphEvent::FileHistory { generation: 7, request_id: 3, path: "target.txt".to_string(), rows: vec![history_row(1, oid)], error: None }).unwrap();
     app.sync(&repo);
 
-    assert!(!app.search_is_loading);
-    assert_eq!(app.search_request_id, None);
-    assert_eq!(app.search_rows.len(), 1);
-    assert_eq!(app.search_rows[0].graph_index, 1);
+    assert!(!app.tab.search_is_loading);
+    assert_eq!(app.tab.search_request_id, None);
+    assert_eq!(app.tab.search_rows.len(), 1);
+    assert_eq!(app.tab.search_rows[0].graph_index, 1);
 }
 
 #[test]
 fn graph_window_request_reuses_cached_window_that_covers_range() {
     let (tx, rx) = std::sync::mpsc::channel();
-    let mut app = App { graph_tx: Some(tx), ..Default::default() };
-    app.graph.generation = 7;
-    app.graph.version = 2;
-    app.graph.graph_window = Some(GraphWindowCache { version: 2, start: 0, end: 10, head_alias: 1, rows: Vec::new(), history: Default::default() });
+    let mut app = App { tab: RepoTab { graph_tx: Some(tx), ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
+    app.tab.graph.version = 2;
+    app.tab.graph.graph_window = Some(GraphWindowCache { version: 2, start: 0, end: 10, head_alias: 1, rows: Vec::new(), history: Default::default() });
 
     app.request_graph_window(2, 8);
 
@@ -365,10 +383,10 @@ fn graph_window_request_reuses_cached_window_that_covers_range() {
 #[test]
 fn pane_window_request_reuses_cached_window_that_covers_range() {
     let (tx, rx) = std::sync::mpsc::channel();
-    let mut app = App { graph_tx: Some(tx), ..Default::default() };
-    app.graph.generation = 7;
-    app.graph.version = 2;
-    app.graph.branches_window = Some(PaneWindowCache { version: 2, start: 0, end: 10, total: 20, rows: Vec::new() });
+    let mut app = App { tab: RepoTab { graph_tx: Some(tx), ..Default::default() }, ..Default::default() };
+    app.tab.graph.generation = 7;
+    app.tab.graph.version = 2;
+    app.tab.graph.branches_window = Some(PaneWindowCache { version: 2, start: 0, end: 10, total: 20, rows: Vec::new() });
 
     app.request_pane_window(GraphPane::Branches, 2, 8);
 
@@ -385,3 +403,27 @@ fn pane_window_request_reuses_cached_window_that_covers_range() {
         other => panic!("expected pane window request, got {other:?}"),
     }
 }
+
+#[test]
+fn same_repo_reload_refreshes_the_running_graph_service() {
+    let (path, repo) = temp_repo("refresh-running");
+    commit_file(&repo, "head.txt", "head");
+    let path_string = path.display().to_string();
+    let mut app = App {
+        tab: RepoTab { path: Some(path_string.clone()), repo: Some(Rc::new(repo)), viewport: Viewport::Graph, focus: Focus::Viewport, ..Default::default() },
+        recent: vec![path_string],
+        walk_cache_dir: Some(path.with_extension("walks")),
+        ..Default::default()
+    };
+
+    app.reload(None);
+    let thread_id = app.tab.walker_handle.as_ref().unwrap().thread().id();
+    let generation = app.tab.graph.generation;
+
+    app.reload(None);
+
+    assert_eq!(app.tab.walker_handle.as_ref().unwrap().thread().id(), thread_id);
+    assert_eq!(app.tab.graph.generation, generation + 1);
+    stop_graph_service(&mut app);
+    let _ = fs::remove_dir_all(path.with_extension("walks"));
+}
@@ -0,0 +1,175 @@
+use super::*;
+use crate::core::batcher::Batcher;
+use git2::{Signature, Time};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    path::PathBuf,
+    process::{Command, Stdio},
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-commit-graph-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    (path.clone(), Repository::init(&path).unwrap())
+}
+
+fn commit(repo: &Repository, message: &str, parents: &[Oid], time: i64) -> Oid {
+    let tree = repo.find_tree(repo.treebuilder(None).unwrap().write().unwrap()).unwrap();
+    let sig = Signature::new("Test User", "test@example.com", &Time::new(time, 0)).unwrap();
+    let parents: Vec<_> = parents.iter().map(|oid| repo.find_commit(*oid).unwrap()).collect();
+    let parents: Vec<&git2::Commit<'_>> = parents.iter().collect();
+    repo.commit(None, &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+// `false` when git is not installed, so the test has nothing to read.
+fn write_commit_graph(path: &Path, split: bool) -> bool {
+    let mut command = Command::new("git");
+    command.arg("-C").arg(path).args(["commit-graph", "write", "--reachable"]);
+    if split {
+        command.arg("--split=no-merge");
+    }
+    command.stdout(Stdio::null()).stderr(Stdio::null()).status().is_ok_and(|status| status.success())
+}
+
+// root - a - b - octopus(b, side, other) - tip, with side and other branching off root.
+fn octopus_history(repo: &Repository) -> Vec<Oid> {
+    let root = commit(repo, "root", &[], 100);
+    let a = commit(repo, "a", &[root], 200);
+    let side = commit(repo, "side", &[root], 250);
+    let other = commit(repo, "other", &[root], 260);
+    let b = commit(repo, "b", &[a], 300);
+    let octopus = commit(repo, "octopus", &[b, side, other], 400);
+    let tip = commit(repo, "tip", &[octopus], 500);
+    repo.reference("refs/heads/main", tip, true, "test").unwrap();
+    repo.reference("refs/heads/side", side, true, "test").unwrap();
+    repo.reference("refs/heads/a", a, true, "test").unwrap();
+    vec![root, a, side, other, b, octopus, tip]
+}
+
+fn pages(batcher: &Batcher) -> Vec<Oid> {
+    std::iter::from_fn(|| Some(batcher.next(2)).filter(|page| !page.is_empty())).flatten().collect()
+}
+
+#[test]
+fn commit_graph_agrees_with_libgit2_on_parents_and_reachability() {
+    let (path, repo) = temp_repo("parents");
+    let commits = octopus_history(&repo);
+    if !write_commit_graph(&path, false) {
+        eprintln!("skipped: the git CLI is needed to write a commit-graph file");
+        let _ = fs::remove_dir_all(path);
+        return;
+    }
+    let graph = CommitGraph::open(&repo).unwrap();
+
+    assert_eq!(graph.len(), commits.len());
+    for &oid in &commits {
+        let position = graph.position(oid).unwrap();
+        let commit = repo.find_commit(oid).unwrap();
+        assert_eq!(graph.oid(position), oid);
+        assert_eq!(graph.parents(position).map(|parent| graph.oid(parent)).collect::<Vec<_>>(), commit.parent_ids().collect::<Vec<_>>());
+        assert_eq!(graph.commit_time(position), commit.time().seconds());
+        assert!(commit.parent_ids().all(|parent| graph.generation(graph.position(parent).unwrap()) < graph.generation(position)));
+    }
+    assert_eq!(graph.position(Oid::zero()), None);
+
+    for &commit in &commits {
+        for &ancestor in &commits {
+            let (position, ancestor_position) = (graph.position(commit).unwrap(), graph.position(ancestor).unwrap());
+            assert_eq!(graph.is_descendant_of(position, ancestor_position), repo.graph_descendant_of(commit, ancestor).unwrap(), "{commit} descends from {ancestor}");
+        }
+    }
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn split_chains_and_newer_commits_walk_in_the_same_order_as_libgit2() {
+    let (path, repo) = temp_repo("chain");
+    let commits = octopus_history(&repo);
+    if !write_commit_graph(&path, true) {
+        eprintln!("skipped: the git CLI is needed to write a commit-graph file");
+        let _ = fs::remove_dir_all(path);
+        return;
+    }
+    let next = commit(&repo, "next", &[commits[6]], 600);
+    let feature = commit(&repo, "feature", &[commits[1]], 650);
+    repo.reference("refs/heads/main", next, true, "test").unwrap();
+    repo.reference("refs/heads/feature", feature, true, "test").unwrap();
+    assert!(write_commit_graph(&path, true));
+    assert!(path.join(".git/objects/info/commit-graphs/commit-graph-chain").exists());
+
+    // The chain now has two layers, and the last commit is not in either of them.
+    let last = commit(&repo, "last", &[next, feature], 700);
+    repo.reference("refs/heads/main", last, true, "test").unwrap();
+    let graph = Rc::new(CommitGraph::open(&repo).unwrap());
+    assert_eq!(graph.layers.len(), 2);
+    assert_eq!(graph.position(last), None);
+    assert_eq!(graph.parents(graph.position(next).unwrap()).map(|parent| graph.oid(parent)).collect::<Vec<_>>(), vec![commits[6]]);
+
+    let repo = Rc::new(RefCell::new(repo));
+    let hidden = im::HashSet::new();
+    let with_graph = pages(&Batcher::new(repo.clone(), Some(graph), &hidden, &[], &HashMap::new()).unwrap());
+    let with_revwalk = pages(&Batcher::new(repo.clone(), None, &hidden, &[], &HashMap::new()).unwrap());
+
+    assert_eq!(with_graph.len(), commits.len() + 3);
+    assert_eq!(with_graph, with_revwalk);
+
+    // Commits from the same second come out in libgit2's heap order, in the graph and after it.
+    let tied: Vec<Oid> = (0..7).map(|i| commit(&repo.borrow(), &format!("tied {i}"), &[last], 800)).collect();
+    for (i, &oid) in tied.iter().enumerate() {
+        let oid = if i % 2 == 0 { commit(&repo.borrow(), &format!("tied {i} child"), &[oid], 800) } else { oid };
+        repo.borrow().reference(&format!("refs/heads/tied-{i}"), oid, true, "test").unwrap();
+    }
+    let merge = commit(&repo.borrow(), "tied merge", &[tied[1], tied[3]], 800);
+    repo.borrow().reference("refs/heads/tied-merge", merge, true, "test").unwrap();
+    assert!(write_commit_graph(&path, true));
+    for (i, &oid) in tied.iter().enumerate().skip(3) {
+        let extra = commit(&repo.borrow(), &format!("tied {i} extra"), &[oid], 800);
+        repo.borrow().reference(&format!("refs/heads/tied-extra-{i}"), extra, true, "test").unwrap();
+    }
+    repo.borrow().reference("refs/heads/zz-duplicate", tied[5], true, "test").unwrap();
+
+    let graph = Rc::new(CommitGraph::open(&repo.borrow()).unwrap());
+    let with_graph = pages(&Batcher::new(repo.clone(), Some(graph), &hidden, &[], &HashMap::new()).unwrap());
+    let with_revwalk = pages(&Batcher::new(repo.clone(), None, &hidden, &[], &HashMap::new()).unwrap());
+    assert_eq!(with_graph.len(), commits.len() + 3 + 7 + 4 + 1 + 4);
+    assert_eq!(with_graph, with_revwalk);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn malformed_or_disabled_commit_graphs_are_ignored() {
+    let (path, repo) = temp_repo("malformed");
+    octopus_history(&repo);
+    if !write_commit_graph(&path, false) {
+        eprintln!("skipped: the git CLI is needed to write a commit-graph file");
+        let _ = fs::remove_dir_all(path);
+        return;
+    }
+    let file = path.join(".git/objects/info/commit-graph");
+    let bytes = fs::read(&file).unwrap();
+
+    repo.config().unwrap().set_bool("core.commitGraph", false).unwrap();
+    assert!(CommitGraph::open(&repo).is_none());
+    repo.config().unwrap().set_bool("core.commitGraph", true).unwrap();
+    assert!(CommitGraph::open(&repo).is_some());
+
+    assert!(CommitGraph::from_files(vec![bytes[..bytes.len() / 2].to_vec()]).is_none());
+    assert!(CommitGraph::from_files(vec![b"not a commit graph".to_vec()]).is_none());
+    // A lone layer that claims a base graph below it is not a chain we can resolve.
+    let mut based = bytes.clone();
+    based[7] = 1;
+    assert!(CommitGraph::from_files(vec![based]).is_none());
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn headers_cut_short_are_ignored() {
+    let header = [b"CGPH".as_slice(), &[1, 1, 3, 0]].concat();
+    for len in 0..=header.len() {
+        assert!(CommitGraph::from_files(vec![header[..len].to_vec()]).is_none());
+    }
+}
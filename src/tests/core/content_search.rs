@@ -0,0 +1,65 @@
+use super::*;
+use git2::{Oid, Repository, Signature};
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-content-search-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    (path.clone(), Repository::init(&path).unwrap())
+}
+
+fn commit(repo: &Repository, file: &str, contents: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), contents).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, file, &tree, &parents).unwrap()
+}
+
+fn collect(task: &ContentSearchTask) -> (Vec<GrepMatch>, ContentSearchEvent) {
+    let mut found = Vec::new();
+    loop {
+        match task.events.recv_timeout(Duration::from_secs(5)).unwrap() {
+            ContentSearchEvent::Match(result) => found.push(result),
+            finished => return (found, finished),
+        }
+    }
+}
+
+#[test]
+fn searches_stream_matches_then_finish() {
+    let (path, repo) = temp_repo("stream");
+    let oid = commit(&repo, "a.txt", "alpha\nbeta\nalphabet\n");
+    let pattern = GrepPattern::new("alpha", false).unwrap();
+
+    let task = spawn_content_search(path.display().to_string(), None, GrepScope::Tree(oid), pattern, u64::MAX);
+    let (found, finished) = collect(&task);
+    assert_eq!(found.iter().map(|found| found.line).collect::<Vec<_>>(), vec![1, 3]);
+    assert!(matches!(finished, ContentSearchEvent::Done { is_truncated: false }));
+
+    let missing = Oid::from_str("0123456789012345678901234567890123456789").unwrap();
+    let task = spawn_content_search(path.display().to_string(), None, GrepScope::Tree(missing), GrepPattern::new("alpha", false).unwrap(), u64::MAX);
+    assert!(matches!(collect(&task), (found, ContentSearchEvent::Failed(_)) if found.is_empty()));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn searches_stop_at_the_result_limit() {
+    let (path, repo) = temp_repo("limit");
+    let oid = commit(&repo, "a.txt", &"x\n".repeat(CONTENT_SEARCH_RESULT_LIMIT + 5));
+
+    let task = spawn_content_search(path.display().to_string(), None, GrepScope::Tree(oid), GrepPattern::new("x", false).unwrap(), u64::MAX);
+    let (found, finished) = collect(&task);
+    assert_eq!(found.len(), CONTENT_SEARCH_RESULT_LIMIT);
+    assert!(matches!(finished, ContentSearchEvent::Done { is_truncated: true }));
+    let _ = fs::remove_dir_all(path);
+}
@@ -0,0 +1,156 @@
+use super::*;
+use git2::Signature;
+use std::{
+    fs,
+    path::Path,
+    sync::mpsc::channel,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-diff-service-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    (path.clone(), Repository::init(&path).unwrap())
+}
+
+fn commit(repo: &Repository, file: &str, contents: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), contents).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, file, &tree, &parents).unwrap()
+}
+
+fn config(path: &Path) -> DiffServiceConfig {
+    DiffServiceConfig { generation: 1, path: path.display().to_string(), work_tree: None }
+}
+
+#[test]
+fn diff_service_answers_file_list_and_file_queries() {
+    let (path, repo) = temp_repo("answers");
+    commit(&repo, "a.txt", "one\n");
+    let oid = commit(&repo, "a.txt", "one\ntwo\n");
+    fs::write(path.join("a.txt"), "one\ntwo\nthree\n").unwrap();
+
+    let (cmd_tx, cmd_rx) = channel();
+    let (event_tx, event_rx) = channel();
+    let handle = spawn_diff_service(config(&path), cmd_rx, event_tx);
+
+    cmd_tx.send(DiffCommand::QueryFileList { generation: 1, request_id: 1, oid }).unwrap();
+    match event_rx.recv_timeout(Duration::from_secs(5)).unwrap() {
+        DiffEvent::FileList { generation, request_id, oid: listed, changes } => {
+            assert_eq!((generation, request_id, listed), (1, 1, oid));
+            assert_eq!(changes.iter().map(|change| change.filename.as_str()).collect::<Vec<_>>(), vec!["a.txt"]);
+        },
+        event => panic!("unexpected event {event:?}"),
+    }
+
+    cmd_tx.send(DiffCommand::QueryFile { generation: 2, request_id: 2, oid: Oid::zero(), filename: "a.txt".to_string(), is_conflict: false, size_limit: None }).unwrap();
+    match event_rx.recv_timeout(Duration::from_secs(5)).unwrap() {
+        DiffEvent::File { generation, request_id, filename, diff: FileDiff::Patch { original_lines, hunks }, .. } => {
+            assert_eq!((generation, request_id, filename.as_str()), (2, 2, "a.txt"));
+            assert_eq!(original_lines, vec!["one", "two", "three"]);
+            assert!(!hunks.is_empty());
+        },
+        event => panic!("unexpected event {event:?}"),
+    }
+
+    cmd_tx.send(DiffCommand::Shutdown).unwrap();
+    handle.join().unwrap();
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn queued_requests_only_answer_the_newest_of_each_kind() {
+    let (path, repo) = temp_repo("coalesce");
+    let first = commit(&repo, "a.txt", "one\n");
+    let second = commit(&repo, "b.txt", "two\n");
+
+    // Everything is queued before the worker starts, so it sees the whole backlog at once.
+    let (cmd_tx, cmd_rx) = channel();
+    let (event_tx, event_rx) = channel();
+    cmd_tx.send(DiffCommand::QueryFileList { generation: 1, request_id: 1, oid: first }).unwrap();
+    cmd_tx.send(DiffCommand::QueryFile { generation: 1, request_id: 2, oid: first, filename: "a.txt".to_string(), is_conflict: false, size_limit: None }).unwrap();
+    cmd_tx.send(DiffCommand::QueryFileList { generation: 1, request_id: 3, oid: second }).unwrap();
+    cmd_tx.send(DiffCommand::QueryFile { generation: 1, request_id: 4, oid: second, filename: "b.txt".to_string(), is_conflict: false, size_limit: None }).unwrap();
+    drop(cmd_tx);
+
+    let handle = spawn_diff_service(config(&path), cmd_rx, event_tx);
+    handle.join().unwrap();
+
+    let answered: Vec<_> = event_rx
+        .try_iter()
+        .map(|event| match event {
+            DiffEvent::FileList { request_id, .. } | DiffEvent::File { request_id, .. } => request_id,
+        })
+        .collect();
+    assert_eq!(answered, vec![3, 4]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn binary_lfs_and_oversized_files_load_as_summaries() {
+    let (path, repo) = temp_repo("summaries");
+    commit(&repo, "image.bin", "old\0bytes");
+    let lfs = "version https://git-lfs.github.com/spec/v1\noid sha256:abc123\nsize 2048\n";
+    fs::write(path.join("image.bin"), "new\0longer bytes").unwrap();
+    fs::write(path.join("model.psd"), lfs).unwrap();
+    fs::write(path.join("big.txt"), "first line\nsecond line\nthird line\n").unwrap();
+
+    match load_file_diff(&repo, Oid::zero(), "image.bin", false, None, &mut || false) {
+        FileDiff::Binary { old_size, new_size, preview } => {
+            assert_eq!((old_size, new_size), (Some(9), Some(16)));
+            assert_eq!(preview, b"new\0longer bytes");
+        },
+        diff => panic!("unexpected diff {diff:?}"),
+    }
+    match load_file_diff(&repo, Oid::zero(), "model.psd", false, None, &mut || false) {
+        FileDiff::LfsPointer { old: None, new: Some(pointer) } => assert_eq!((pointer.oid.as_str(), pointer.size), ("sha256:abc123", 2048)),
+        diff => panic!("unexpected diff {diff:?}"),
+    }
+    match load_file_diff(&repo, Oid::zero(), "big.txt", false, Some(16), &mut || false) {
+        FileDiff::Truncated { lines, hunks, size, limit } => {
+            assert_eq!(lines, vec!["first line", "secon"]);
+            assert_eq!((size, limit), (34, 16));
+            // The diff is kept too, up to the same number of bytes.
+            let added: Vec<&str> = hunks.iter().flat_map(|hunk| &hunk.lines).filter(|line| line.origin == '+').map(|line| line.content.as_str()).collect();
+            assert_eq!(added, vec!["first line\n"]);
+        },
+        diff => panic!("unexpected diff {diff:?}"),
+    }
+    // Without a limit, or under it, the same file is an ordinary patch.
+    assert!(matches!(load_file_diff(&repo, Oid::zero(), "big.txt", false, None, &mut || false), FileDiff::Patch { .. }));
+    assert!(matches!(load_file_diff(&repo, Oid::zero(), "big.txt", false, Some(1024), &mut || false), FileDiff::Patch { .. }));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn cancelled_diffs_stop_at_the_next_line_or_file() {
+    let (path, repo) = temp_repo("cancel");
+    let lines: String = (0..500).map(|line| format!("line {line}\n")).collect();
+    commit(&repo, "a.txt", &lines);
+    let oid = commit(&repo, "b.txt", &lines);
+    fs::write(path.join("a.txt"), lines.replace("line", "changed")).unwrap();
+
+    let FileDiff::Patch { hunks, .. } = load_file_diff(&repo, Oid::zero(), "a.txt", false, None, &mut || false) else { panic!("expected a patch") };
+    assert!(hunks.iter().map(|hunk| hunk.lines.len()).sum::<usize>() >= 1000);
+
+    // The worker drains its queue in the callback; a newer request turns it true and the patch stops there.
+    let mut asked = 0;
+    let diff = load_file_diff(&repo, Oid::zero(), "a.txt", false, None, &mut || {
+        asked += 1;
+        asked > 10
+    });
+    let FileDiff::Patch { hunks, .. } = diff else { panic!("expected a patch") };
+    assert!(hunks.is_empty());
+    assert_eq!(asked, 11);
+
+    assert!(get_filenames_diff_at_oid_cancellable(&repo, oid, &mut || true).is_none());
+    assert_eq!(get_filenames_diff_at_oid_cancellable(&repo, oid, &mut || false).map(|changes| changes.len()), Some(1));
+    let _ = fs::remove_dir_all(path);
+}
@@ -0,0 +1,184 @@
+use super::*;
+use crate::helpers::colors::ColorPicker;
+use git2::{Signature, build::CheckoutBuilder};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-export-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    (path, repo)
+}
+
+fn commit(repo: &Repository, file: &str, message: &str, parents: &[Oid]) -> Oid {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    fs::write(workdir.join(file), message).unwrap();
+
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Ada Author", "ada@example.com").unwrap();
+    let parents: Vec<git2::Commit<'_>> = parents.iter().map(|oid| repo.find_commit(*oid).unwrap()).collect();
+    let parents: Vec<&git2::Commit<'_>> = parents.iter().collect();
+    repo.commit(None, &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+// main: base -> left -> merge(left, right); topic: base -> right
+fn merged_repo(name: &str) -> (PathBuf, Repository, Oid) {
+    let (path, repo) = temp_repo(name);
+    let base = commit(&repo, "base.txt", "base", &[]);
+    let right = commit(&repo, "right.txt", "right", &[base]);
+    let left = commit(&repo, "left.txt", "left", &[base]);
+    let merge = commit(&repo, "merge.txt", "merge", &[left, right]);
+    repo.reference("refs/heads/main", merge, true, "main").unwrap();
+    repo.reference("refs/heads/topic", right, true, "topic").unwrap();
+    repo.set_head("refs/heads/main").unwrap();
+    repo.checkout_head(Some(CheckoutBuilder::new().force())).unwrap();
+    (path, repo, merge)
+}
+
+fn export(path: &Path, options: &ExportOptions, theme: &Theme) -> String {
+    let mut out = Vec::new();
+    export_graph(path.display().to_string(), options, theme, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+// Writes the export in chunks of `chunk_rows`, to check rows come out the same whatever the chunk size.
+fn export_in_chunks(path: &Path, options: &ExportOptions, chunk_rows: usize) -> String {
+    let symbols = SymbolTheme::main();
+    let mut walker = Walker::new(path.display().to_string(), EXPORT_WALK_AMOUNT, HashSet::new(), false, true, GRAPH_LANE_LIMIT_DEFAULT).unwrap();
+    let mut out = Vec::new();
+    write_export(&mut walker, options, &Theme::default(), &symbols, &mut out, chunk_rows).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn text_export_prints_sha_date_author_graph_and_message_columns() {
+    let (path, _repo, merge) = merged_repo("text");
+
+    let options = ExportOptions { symbols: Some("ascii".to_string()), ..Default::default() };
+    let output = export(&path, &options, &Theme::default());
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].starts_with(&format!("{merge:.9} ")));
+    assert!(lines[0].contains("Ada Author"));
+    assert!(lines[0].contains("main"));
+    assert!(lines[0].ends_with("merge"));
+    assert!(lines[3].ends_with("base"));
+    assert!(!output.contains('\x1b'));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn exports_show_the_author_and_author_date_rather_than_the_committer() {
+    let (path, repo) = temp_repo("author");
+    fs::write(path.join("file.txt"), "rebased").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("file.txt")).unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let author = Signature::new("Ada Author", "ada@example.com", &git2::Time::new(1_600_000_000, 120)).unwrap();
+    let committer = Signature::new("Cid Committer", "cid@example.com", &git2::Time::new(1_700_000_000, 0)).unwrap();
+    repo.commit(Some("HEAD"), &author, &committer, "rebased", &tree, &[]).unwrap();
+
+    let output = export(&path, &ExportOptions::default(), &Theme::default());
+    assert!(output.contains("2020-09-13 12:26 Ada Author"), "{output}");
+    assert!(!output.contains("Cid Committer"));
+
+    let options = ExportOptions { format: ExportFormat::Json, ..Default::default() };
+    let graph: ExportGraph = facet_json::from_str(&export(&path, &options, &Theme::default())).unwrap();
+    assert_eq!(graph.rows[0].date, "2020-09-13T14:26:40+02:00");
+    assert_eq!(graph.rows[0].committer_name, "Cid Committer");
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn limit_caps_rows_and_ansi_export_colors_them() {
+    let (path, _repo, _) = merged_repo("limit");
+
+    let options = ExportOptions { format: ExportFormat::Ansi, limit: Some(2), ..Default::default() };
+    let output = export(&path, &options, &Theme::default());
+
+    assert_eq!(output.lines().count(), 2);
+    assert!(output.lines().all(|line| line.starts_with("\x1b[") && line.ends_with(ANSI_RESET)));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn json_export_includes_lanes_and_parent_edges() {
+    let (path, _repo, merge) = merged_repo("json");
+
+    let options = ExportOptions { format: ExportFormat::Json, ..Default::default() };
+    let output = export(&path, &options, &Theme::default());
+    let graph: ExportGraph = facet_json::from_str(&output).unwrap();
+
+    assert_eq!(graph.head, Some(merge.to_string()));
+    assert_eq!(graph.rows.len(), 4);
+    let merge_row = &graph.rows[0];
+    assert_eq!(merge_row.branches, vec!["main".to_string()]);
+    assert_eq!(merge_row.author_email, "ada@example.com");
+    assert_eq!(merge_row.parents.len(), 2);
+
+    // The second parent is drawn in a lane of its own, and edges point at the rows they join.
+    let lanes: Vec<Option<usize>> = merge_row.parents.iter().map(|edge| edge.lane).collect();
+    assert_eq!(merge_row.lane, Some(0));
+    assert!(lanes.contains(&Some(0)) && lanes.contains(&Some(1)));
+    for edge in &merge_row.parents {
+        let index = edge.index.unwrap();
+        assert_eq!(graph.rows[index].sha, edge.sha);
+        assert_eq!(graph.rows[index].lane, edge.lane);
+    }
+    assert!(graph.rows[3].parents.is_empty());
+    // Dates are ISO 8601, in the offset the commit was made in.
+    assert!(chrono::DateTime::parse_from_rfc3339(&merge_row.date).is_ok(), "{}", merge_row.date);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn streamed_exports_match_whatever_the_chunk_size() {
+    let (path, _repo, _) = merged_repo("chunks");
+
+    // Edges to rows in later chunks still carry their index and lane.
+    let options = ExportOptions { format: ExportFormat::Json, ..Default::default() };
+    assert_eq!(export_in_chunks(&path, &options, 1), export_in_chunks(&path, &options, EXPORT_CHUNK_ROWS));
+    let limited = ExportOptions { limit: Some(2), ..options };
+    assert_eq!(export_in_chunks(&path, &limited, 1), export_in_chunks(&path, &limited, EXPORT_CHUNK_ROWS));
+
+    let svg = ExportOptions { format: ExportFormat::Svg, ..Default::default() };
+    let chunked = export_in_chunks(&path, &svg, 1);
+    assert_eq!(chunked.lines().next(), export_in_chunks(&path, &svg, EXPORT_CHUNK_ROWS).lines().next());
+    assert_eq!(chunked.matches("<text ").count(), 4);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn image_exports_color_lanes_from_the_theme_and_escape_messages() {
+    let (path, repo) = temp_repo("image");
+    let base = commit(&repo, "base.txt", "base", &[]);
+    let head = commit(&repo, "head.txt", "fix <script> & \"quotes\"", &[base]);
+    repo.reference("refs/heads/main", head, true, "main").unwrap();
+    repo.set_head("refs/heads/main").unwrap();
+
+    let theme = Theme::default();
+    let lane_color = css_color(ColorPicker::from_theme(&theme).get_lane(0)).unwrap();
+    let svg = export(&path, &ExportOptions { format: ExportFormat::Svg, ..Default::default() }, &theme);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert_eq!(svg.matches("<text ").count(), 2);
+    assert!(svg.contains(&format!("fill=\"{lane_color}\"")));
+    assert!(svg.contains("fix &lt;script&gt; &amp; &quot;quotes&quot;"));
+    assert!(svg.contains("main"));
+
+    let html = export(&path, &ExportOptions { format: ExportFormat::Html, ..Default::default() }, &theme);
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains(&format!("color: {lane_color}")));
+    assert!(!html.contains("<script>"));
+    assert_eq!(ExportFormat::from_path(Path::new("graph.HTML")), ExportFormat::Html);
+    assert_eq!(ExportFormat::from_path(Path::new("graph")), ExportFormat::Svg);
+    let _ = fs::remove_dir_all(path);
+}
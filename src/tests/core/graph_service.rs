@@ -40,7 +40,7 @@ fn commit(repo: &Repository, file: &str, message: &str) -> Oid {
 #[test]
 fn graph_service_reports_progress_and_answers_visible_window() {
     let (path, repo) = temp_repo("window");
-    commit(&repo, "one.txt", "one");
+    let one = commit(&repo, "one.txt", "one");
     let two = commit(&repo, "two.txt", "two");
 
     let generation = 42;
@@ -54,9 +54,11 @@ fn graph_service_reports_progress_and_answers_visible_window() {
             amount: 1,
             hidden_branch_names: HashSet::new(),
             include_head_reflog_roots: false,
+            use_replace_refs: true,
             graph_lane_limit: 20,
             worktrees: Vec::new(),
             symbols: SymbolTheme::main(),
+            walk_cache_path: None,
         },
         cmd_rx,
         event_tx,
@@ -123,6 +125,22 @@ fn graph_service_reports_progress_and_answers_visible_window() {
     }
     assert!(saw_oid_lookup);
 
+    // Range lookups skip the uncommitted row and stop at the end of the graph.
+    cmd_tx.send(GraphCommand::Lookup { generation, request_id: 10, kind: GraphLookupKind::GraphOidRange { start: 0, end: 5 } }).unwrap();
+
+    let mut saw_range_lookup = false;
+    for _ in 0..20 {
+        match event_rx.recv_timeout(Duration::from_millis(250)).unwrap() {
+            GraphEvent::LookupResult { generation: event_generation, request_id, result: GraphLookupResult::Oids(oids), .. } if event_generation == generation && request_id == 10 => {
+                saw_range_lookup = true;
+                assert_eq!(oids, vec![two, one]);
+                break;
+            },
+            _ => {},
+        }
+    }
+    assert!(saw_range_lookup);
+
     let _ = cmd_tx.send(GraphCommand::Shutdown);
     cancel.store(true, std::sync::atomic::Ordering::SeqCst);
     handle.join().unwrap();
@@ -146,9 +164,11 @@ fn graph_service_file_history_returns_visible_graph_indices() {
             amount: 10000,
             hidden_branch_names: HashSet::new(),
             include_head_reflog_roots: false,
+            use_replace_refs: true,
             graph_lane_limit: 20,
             worktrees: Vec::new(),
             symbols: SymbolTheme::main(),
+            walk_cache_path: None,
         },
         cmd_rx,
         event_tx,
@@ -215,9 +235,11 @@ fn graph_service_uses_hidden_branch_names_as_deny_list() {
             amount: 10000,
             hidden_branch_names: hidden_set(&["hidden"]),
             include_head_reflog_roots: false,
+            use_replace_refs: true,
             graph_lane_limit: 20,
             worktrees: Vec::new(),
             symbols: SymbolTheme::main(),
+            walk_cache_path: None,
         },
         cmd_rx,
         event_tx,
@@ -291,9 +313,11 @@ fn graph_service_omits_hidden_labels_on_visible_commits() {
             amount: 10000,
             hidden_branch_names: hidden_set(&["hidden", "origin/archive"]),
             include_head_reflog_roots: false,
+            use_replace_refs: true,
             graph_lane_limit: 20,
             worktrees: Vec::new(),
             symbols: SymbolTheme::main(),
+            walk_cache_path: None,
         },
         cmd_rx,
         event_tx,
@@ -339,3 +363,234 @@ fn graph_service_omits_hidden_labels_on_visible_commits() {
 fn hidden_set(names: &[&str]) -> HashSet<String> {
     names.iter().map(|name| name.to_string()).collect()
 }
+
+#[test]
+fn graph_service_resumes_a_finished_walk_from_the_walk_cache() {
+    let (path, repo) = temp_repo("walk-cache");
+    commit(&repo, "one.txt", "one");
+    commit(&repo, "two.txt", "two");
+    let cache_path = path.with_extension("walk.bin");
+
+    let spawn = |generation| {
+        let (cmd_tx, cmd_rx) = channel();
+        let (event_tx, event_rx) = channel();
+        let config = GraphServiceConfig {
+            generation,
+            path: path.display().to_string(),
+            amount: 1,
+            hidden_branch_names: HashSet::new(),
+            include_head_reflog_roots: false,
+            use_replace_refs: true,
+            graph_lane_limit: 20,
+            worktrees: Vec::new(),
+            symbols: SymbolTheme::main(),
+            walk_cache_path: Some(cache_path.clone()),
+        };
+        let handle = spawn_graph_service(config, cmd_rx, event_tx, Arc::new(AtomicBool::new(false)));
+        (cmd_tx, event_rx, handle)
+    };
+
+    // The first run walks page by page and stores the result once complete.
+    let (cmd_tx, event_rx, handle) = spawn(1);
+    let mut progress = Vec::new();
+    while let Ok(event) = event_rx.recv_timeout(Duration::from_secs(5)) {
+        match event {
+            GraphEvent::Progress { is_first, is_complete, .. } => progress.push((is_first, is_complete)),
+            GraphEvent::Heatmap { .. } => break,
+            _ => {},
+        }
+    }
+    cmd_tx.send(GraphCommand::Shutdown).unwrap();
+    handle.join().unwrap();
+    assert!(progress.len() > 1);
+    assert!(cache_path.exists());
+
+    // The second run finishes on its first progress report.
+    let (cmd_tx, event_rx, handle) = spawn(2);
+    let first = loop {
+        if let GraphEvent::Progress { is_first, is_complete, total, .. } = event_rx.recv_timeout(Duration::from_secs(5)).unwrap() {
+            break (is_first, is_complete, total);
+        }
+    };
+    cmd_tx.send(GraphCommand::Shutdown).unwrap();
+    handle.join().unwrap();
+    assert_eq!(first, (true, true, 3));
+
+    let _ = fs::remove_file(cache_path);
+    let _ = fs::remove_dir_all(path);
+}
+
+fn wait_for_complete(event_rx: &std::sync::mpsc::Receiver<GraphEvent>, generation: Generation) -> Vec<(bool, usize)> {
+    let mut progress = Vec::new();
+    loop {
+        if let GraphEvent::Progress { generation: event_generation, is_first, is_complete, total, .. } = event_rx.recv_timeout(Duration::from_secs(5)).unwrap()
+            && event_generation == generation
+        {
+            progress.push((is_first, total));
+            if is_complete {
+                return progress;
+            }
+        }
+    }
+}
+
+#[test]
+fn graph_service_refresh_extends_the_walk_or_starts_over_when_history_was_rewritten() {
+    let (path, repo) = temp_repo("refresh");
+    let base = commit(&repo, "one.txt", "one");
+    commit(&repo, "two.txt", "two");
+
+    let (cmd_tx, cmd_rx) = channel();
+    let (event_tx, event_rx) = channel();
+    let config = GraphServiceConfig {
+        generation: 1,
+        path: path.display().to_string(),
+        amount: 1,
+        hidden_branch_names: HashSet::new(),
+        include_head_reflog_roots: false,
+        use_replace_refs: true,
+        graph_lane_limit: 20,
+        worktrees: Vec::new(),
+        symbols: SymbolTheme::main(),
+        walk_cache_path: None,
+    };
+    let handle = spawn_graph_service(config, cmd_rx, event_tx, Arc::new(AtomicBool::new(false)));
+    assert_eq!(wait_for_complete(&event_rx, 1).last(), Some(&(false, 3)));
+
+    let refresh = |generation| GraphCommand::Refresh {
+        generation,
+        hidden_branch_names: HashSet::new(),
+        include_head_reflog_roots: false,
+        use_replace_refs: true,
+        graph_lane_limit: 20,
+        worktrees: Vec::new(),
+        symbols: Box::new(SymbolTheme::main()),
+    };
+
+    // A new commit on HEAD is placed above the finished walk in one step; queries behind the
+    // refresh already answer for the new generation.
+    let three = commit(&repo, "three.txt", "three");
+    cmd_tx.send(refresh(2)).unwrap();
+    cmd_tx.send(GraphCommand::Lookup { generation: 2, request_id: 1, kind: GraphLookupKind::Oid { oid: three } }).unwrap();
+    let mut progress = Vec::new();
+    let mut index = None;
+    while progress.last().is_none_or(|(_, _, is_complete)| !is_complete) || index.is_none() {
+        match event_rx.recv_timeout(Duration::from_secs(5)).unwrap() {
+            GraphEvent::Progress { generation: 2, is_first, is_complete, total, .. } => progress.push((is_first, total, is_complete)),
+            GraphEvent::LookupResult { generation: 2, request_id: 1, result: GraphLookupResult::Index(found) } => index = Some(found),
+            _ => {},
+        }
+    }
+    assert_eq!(progress, vec![(true, 4, true)]);
+    let index = index.unwrap();
+    assert_eq!(index, Some(1));
+
+    // Rewinding HEAD drops stored rows, so the service walks page by page again.
+    repo.reset(repo.find_commit(base).unwrap().as_object(), git2::ResetType::Hard, None).unwrap();
+    cmd_tx.send(refresh(3)).unwrap();
+    let progress = wait_for_complete(&event_rx, 3);
+    assert!(progress.len() > 1);
+    assert_eq!(progress.first().map(|(is_first, _)| *is_first), Some(true));
+    assert_eq!(progress.last().map(|(_, total)| *total), Some(2));
+
+    cmd_tx.send(GraphCommand::Shutdown).unwrap();
+    handle.join().unwrap();
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn refreshed_walks_are_written_to_the_walk_cache_once_the_service_stops() {
+    let (path, repo) = temp_repo("refresh-save");
+    commit(&repo, "one.txt", "one");
+    let cache_path = path.with_extension("walk.bin");
+
+    let (cmd_tx, cmd_rx) = channel();
+    let (event_tx, event_rx) = channel();
+    let config = GraphServiceConfig {
+        generation: 1,
+        path: path.display().to_string(),
+        amount: 1,
+        hidden_branch_names: HashSet::new(),
+        include_head_reflog_roots: false,
+        use_replace_refs: true,
+        graph_lane_limit: 20,
+        worktrees: Vec::new(),
+        symbols: SymbolTheme::main(),
+        walk_cache_path: Some(cache_path.clone()),
+    };
+    let handle = spawn_graph_service(config, cmd_rx, event_tx, Arc::new(AtomicBool::new(false)));
+    wait_for_complete(&event_rx, 1);
+    // The heatmap follows the save, so the stored walk is on disk once it arrives.
+    while !matches!(event_rx.recv_timeout(Duration::from_secs(5)).unwrap(), GraphEvent::Heatmap { .. }) {}
+    let stored = load_walk_cache(&cache_path).unwrap();
+
+    let two = commit(&repo, "two.txt", "two");
+    cmd_tx
+        .send(GraphCommand::Refresh {
+            generation: 2,
+            hidden_branch_names: HashSet::new(),
+            include_head_reflog_roots: false,
+            use_replace_refs: true,
+            graph_lane_limit: 20,
+            worktrees: Vec::new(),
+            symbols: Box::new(SymbolTheme::main()),
+        })
+        .unwrap();
+    assert_eq!(wait_for_complete(&event_rx, 2), vec![(true, 3)]);
+    assert_eq!(load_walk_cache(&cache_path), Some(stored));
+
+    cmd_tx.send(GraphCommand::Shutdown).unwrap();
+    handle.join().unwrap();
+    assert_eq!(load_walk_cache(&cache_path).map(|cache| cache.tips), Some(vec![two]));
+
+    let _ = fs::remove_file(cache_path);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn paused_graph_service_stops_walking_until_resumed() {
+    let (path, repo) = temp_repo("pause");
+    for index in 0..4 {
+        commit(&repo, "file.txt", &format!("commit {index}"));
+    }
+
+    let generation = 99;
+    let (cmd_tx, cmd_rx) = channel();
+    let (event_tx, event_rx) = channel();
+    let cancel = Arc::new(AtomicBool::new(false));
+    // Queued before the service starts, so not even the first page is walked.
+    cmd_tx.send(GraphCommand::Pause { is_paused: true }).unwrap();
+    let handle = spawn_graph_service(
+        GraphServiceConfig {
+            generation,
+            path: path.display().to_string(),
+            amount: 1,
+            hidden_branch_names: HashSet::new(),
+            include_head_reflog_roots: false,
+            use_replace_refs: true,
+            graph_lane_limit: 20,
+            worktrees: Vec::new(),
+            symbols: SymbolTheme::main(),
+            walk_cache_path: None,
+        },
+        cmd_rx,
+        event_tx,
+        cancel.clone(),
+    );
+
+    assert!(event_rx.recv_timeout(Duration::from_millis(300)).is_err());
+
+    cmd_tx.send(GraphCommand::Pause { is_paused: false }).unwrap();
+    let mut complete = false;
+    for _ in 0..20 {
+        if let GraphEvent::Progress { is_complete: true, .. } = event_rx.recv_timeout(Duration::from_millis(250)).unwrap() {
+            complete = true;
+            break;
+        }
+    }
+    assert!(complete);
+
+    cmd_tx.send(GraphCommand::Shutdown).unwrap();
+    handle.join().unwrap();
+    let _ = fs::remove_dir_all(path);
+}
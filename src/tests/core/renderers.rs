@@ -24,6 +24,7 @@ fn graph_row(index: usize, oid: Oid, summary: &str) -> GraphRow {
         branches: Vec::new(),
         tags: Vec::new(),
         is_stash: false,
+        is_shallow: false,
         stash_lane: None,
         worktrees: Vec::new(),
         reflog: None,
@@ -161,6 +162,36 @@ fn message_projection_uses_text_and_highlighted_text_colors() {
     assert_eq!(lines[1].spans[0].style.fg, Some(theme.COLOR_HIGHLIGHTED));
 }
 
+#[test]
+fn branch_label_hit_test_matches_the_rendered_label_columns() {
+    let theme = Theme::classic();
+    let symbols = SymbolTheme::main();
+    let mut row = graph_row(1, Oid::from_str("2222222222222222222222222222222222222222").unwrap(), "commit");
+    row.worktrees = vec![WorktreeEntry {
+        name: "wt".to_string(),
+        path: PathBuf::from("/tmp/wt"),
+        branch: None,
+        head: None,
+        alias: None,
+        kind: WorktreeKind::Linked,
+        is_current: false,
+        is_valid: true,
+        is_prunable: false,
+        locked_reason: None,
+        is_dirty: false,
+    }];
+    row.branches = vec![GraphBranchLabel { name: "main".to_string(), is_local: true, lane: None }, GraphBranchLabel { name: "origin/main".to_string(), is_local: false, lane: None }];
+
+    let lines = render_message_projection(&theme, &symbols, std::slice::from_ref(&row), false, true, 0, &UncommittedChanges::default(), true);
+    let text = line_text(&lines[0]);
+    let column_of = |needle: &str| text[..text.find(needle).unwrap()].chars().count();
+
+    assert!(branch_label_at(&symbols, &row, column_of("wt")).is_none());
+    assert_eq!(branch_label_at(&symbols, &row, column_of(" main")).map(|label| label.name.as_str()), Some("main"));
+    assert_eq!(branch_label_at(&symbols, &row, column_of("origin/main")).map(|label| label.name.as_str()), Some("origin/main"));
+    assert!(branch_label_at(&symbols, &row, column_of("commit")).is_none());
+}
+
 #[test]
 fn date_projection_renders_commit_dates_and_blanks_uncommitted_rows() {
     let theme = Theme::classic();
@@ -0,0 +1,76 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    std::env::temp_dir().join(format!("guitar-walk-cache-{name}-{id}"))
+}
+
+fn sample_cache() -> WalkCache {
+    let one = Oid::from_str("1111111111111111111111111111111111111111").unwrap();
+    let two = Oid::from_str("2222222222222222222222222222222222222222").unwrap();
+    let hidden: HashSet<String> = ["origin/old".to_string()].into_iter().collect();
+    WalkCache {
+        key: WalkKey::new("/repo", &hidden, true, 8, &StdHashSet::from([one])),
+        tips: vec![two],
+        stashes: Vec::new(),
+        oids: vec![two, one],
+        sorted_aliases: vec![NONE, 0, 1],
+        deltas: vec![
+            Delta::default(),
+            Delta { ops: vec![DeltaOp::Insert { index: 0, item: Chunk::uncommitted(0, NONE) }] },
+            Delta { ops: vec![DeltaOp::Replace { index: 0, new: Chunk::commit(0, 1, NONE) }, DeltaOp::Insert { index: 1, item: Chunk::dummy() }, DeltaOp::CompressedParentInsert { parent: 1 }] },
+            Delta { ops: vec![DeltaOp::Replace { index: 0, new: Chunk::commit(1, NONE, NONE).with_flattened(true) }, DeltaOp::CompressedParentRemove { parent: 1 }, DeltaOp::Remove { index: 1 }] },
+        ],
+    }
+}
+
+#[test]
+fn walk_cache_round_trips_through_disk() {
+    let dir = temp_dir("round-trip");
+    let path = walk_cache_path(&dir, "/repo");
+    let cache = sample_cache();
+
+    save_walk_cache(&path, &cache);
+
+    assert_eq!(load_walk_cache(&path), Some(cache));
+    assert_eq!(walk_cache_path(&dir, "/repo"), path);
+    assert_ne!(walk_cache_path(&dir, "/other"), path);
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn truncated_or_foreign_files_are_ignored() {
+    let dir = temp_dir("corrupt");
+    let path = walk_cache_path(&dir, "/repo");
+    save_walk_cache(&path, &sample_cache());
+    let bytes = fs::read(&path).unwrap();
+
+    fs::write(&path, &bytes[..bytes.len() - 3]).unwrap();
+    assert_eq!(load_walk_cache(&path), None);
+
+    fs::write(&path, b"not a walk cache").unwrap();
+    assert_eq!(load_walk_cache(&path), None);
+    let _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn deltas_pointing_outside_the_lanes_or_the_alias_table_are_ignored() {
+    let dir = temp_dir("bad-deltas");
+    let path = walk_cache_path(&dir, "/repo");
+    let bad_ops = [
+        DeltaOp::Insert { index: 2, item: Chunk::commit(1, NONE, NONE) },
+        DeltaOp::Remove { index: 1 },
+        DeltaOp::Replace { index: 1, new: Chunk::commit(1, NONE, NONE) },
+        DeltaOp::Replace { index: 0, new: Chunk::commit(2, NONE, NONE) },
+        DeltaOp::Insert { index: 0, item: Chunk::uncommitted(0, 7) },
+        DeltaOp::CompressedParentInsert { parent: 2 },
+    ];
+    for op in bad_ops {
+        let mut cache = sample_cache();
+        cache.deltas[1].ops.push(op);
+        save_walk_cache(&path, &cache);
+        assert_eq!(load_walk_cache(&path), None);
+    }
+    let _ = fs::remove_dir_all(dir);
+}
@@ -1,6 +1,10 @@
 use super::*;
 use crate::{
-    core::{graph_service::GraphRow, renderers::render_graph_projection},
+    core::{
+        graph_service::{GraphRow, graph_rows},
+        renderers::render_graph_projection,
+        worktrees::Worktrees,
+    },
     helpers::{
         palette::Theme,
         symbols::{SymbolTheme, graph},
@@ -77,6 +81,7 @@ fn graph_row(index: usize, alias: u32, oid: Oid) -> GraphRow {
         branches: Vec::new(),
         tags: Vec::new(),
         is_stash: false,
+        is_shallow: false,
         stash_lane: None,
         worktrees: Vec::new(),
         reflog: None,
@@ -95,7 +100,7 @@ fn walker_loads_commit_reachable_only_from_head_reflog() {
     let base_commit = repo.find_commit(base).unwrap();
     repo.reset(base_commit.as_object(), ResetType::Hard, None).unwrap();
 
-    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), true, 20).unwrap();
+    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), true, true, 20).unwrap();
     walker.walk();
     let lost_alias = walker.oids.aliases.get(&lost).copied().unwrap();
 
@@ -110,7 +115,7 @@ fn walker_can_hide_commit_reachable_only_from_head_reflog() {
     let base_commit = repo.find_commit(base).unwrap();
     repo.reset(base_commit.as_object(), ResetType::Hard, None).unwrap();
 
-    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), false, 20).unwrap();
+    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), false, true, 20).unwrap();
     walker.walk();
     let lost_alias = walker.oids.aliases.get(&lost).copied().unwrap();
 
@@ -133,7 +138,7 @@ fn walker_expires_new_right_merge_lane_before_next_rendered_row() {
     repo.reference("refs/heads/merge", merge, true, "test").unwrap();
     repo.set_head("refs/heads/main").unwrap();
 
-    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), false, 20).unwrap();
+    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), false, true, 20).unwrap();
     while walker.walk() {}
 
     let merge_alias = walker.oids.aliases.get(&merge).copied().unwrap();
@@ -177,7 +182,7 @@ fn walker_records_ref_stash_and_reflog_lanes_from_update_lane() {
     }
     let stash = stash_tracked_change(&mut repo, "file.txt", "stashed change");
 
-    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), true, 20).unwrap();
+    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), true, true, 20).unwrap();
     while walker.walk() {}
 
     let base_alias = walker.oids.aliases.get(&base).copied().unwrap();
@@ -195,7 +200,7 @@ fn walker_keeps_stash_adjacent_to_its_base_parent() {
     let base = commit(&repo, "file.txt", "base");
     let stash = stash_tracked_change(&mut repo, "file.txt", "stashed change");
 
-    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), false, 20).unwrap();
+    let mut walker = Walker::new(path.display().to_string(), 100, HashSet::new(), false, true, 20).unwrap();
     while walker.walk() {}
 
     let aliases = walker.oids.get_sorted_aliases();
@@ -206,3 +211,228 @@ fn walker_keeps_stash_adjacent_to_its_base_parent() {
 
     assert_eq!(stash_idx + 1, base_idx);
 }
+
+type LaneOids = (Oid, Oid, Oid, bool);
+
+// Rows and lanes spelled out in oids so walks with different alias tables can be compared.
+fn walk_by_oid(walker: &Walker) -> Vec<(Oid, Vec<LaneOids>)> {
+    let oid = |alias: u32| if alias == NONE { Oid::zero() } else { *walker.oids.get_oid_by_alias(alias) };
+    let aliases = walker.oids.get_sorted_aliases();
+    let history = walker.buffer.borrow().window(0, aliases.len().saturating_add(1));
+    aliases
+        .iter()
+        .zip(history.iter())
+        .map(|(&alias, snapshot)| (oid(alias), snapshot.iter().map(|chunk| (oid(chunk.alias), oid(chunk.parent_a), oid(chunk.parent_b), chunk.is_flattened)).collect()))
+        .collect()
+}
+
+fn branch_lanes_by_oid(walker: &Walker) -> std::collections::BTreeMap<Oid, LaneRef> {
+    walker.branches_lanes.iter().map(|(&alias, &lane)| (*walker.oids.get_oid_by_alias(alias), lane)).collect()
+}
+
+fn branchy_repo(name: &str) -> (PathBuf, Repository, Oid) {
+    let (path, repo) = temp_repo(name);
+    let root = commit_with_parents(&repo, "root.txt", "root", &[], 1);
+    let side = commit_with_parents(&repo, "side.txt", "side", &[root], 2);
+    let main = commit_with_parents(&repo, "main.txt", "main", &[root], 3);
+    let merge = commit_with_parents(&repo, "merge.txt", "merge", &[main, side], 4);
+    repo.reference("refs/heads/main", merge, true, "test").unwrap();
+    repo.reference("refs/heads/side", side, true, "test").unwrap();
+    repo.set_head("refs/heads/main").unwrap();
+    (path, repo, merge)
+}
+
+fn full_walk(path: &Path) -> Walker {
+    full_walk_with(path, true)
+}
+
+fn full_walk_with(path: &Path, use_replace_refs: bool) -> Walker {
+    let mut walker = Walker::new(path.display().to_string(), 2, HashSet::new(), false, use_replace_refs, 20).unwrap();
+    while walker.walk() {}
+    walker
+}
+
+#[test]
+fn resumed_walk_with_unchanged_refs_reuses_the_cache_as_is() {
+    let (path, _repo, _) = branchy_repo("cache-unchanged");
+    let walker = full_walk(&path);
+    let cache = walker.walk_cache();
+
+    let resumed = Walker::resume(path.display().to_string(), 2, HashSet::new(), false, true, 20, cache.clone()).unwrap().unwrap();
+
+    assert!(resumed.is_cache_current);
+    assert_eq!(resumed.walk_cache(), cache);
+    assert_eq!(walk_by_oid(&resumed), walk_by_oid(&walker));
+    assert_eq!(branch_lanes_by_oid(&resumed), branch_lanes_by_oid(&walker));
+}
+
+#[test]
+fn resumed_walk_places_new_commits_above_the_cached_history() {
+    let (path, repo, merge) = branchy_repo("cache-extend");
+    let cache = full_walk(&path).walk_cache();
+
+    let next = commit_with_parents(&repo, "next.txt", "next", &[merge], 5);
+    let feature = commit_with_parents(&repo, "feature.txt", "feature", &[merge], 6);
+    repo.reference("refs/heads/main", next, true, "test").unwrap();
+    repo.reference("refs/heads/feature", feature, true, "test").unwrap();
+    repo.tag_lightweight("v-next", repo.find_commit(next).unwrap().as_object(), false).unwrap();
+
+    let resumed = Walker::resume(path.display().to_string(), 2, HashSet::new(), false, true, 20, cache).unwrap().unwrap();
+    let fresh = full_walk(&path);
+
+    assert!(!resumed.is_cache_current);
+    assert_eq!(walk_by_oid(&resumed), walk_by_oid(&fresh));
+    assert_eq!(branch_lanes_by_oid(&resumed), branch_lanes_by_oid(&fresh));
+    let next_alias = resumed.oids.aliases.get(&next).copied().unwrap();
+    assert!(resumed.tags_lanes.contains_key(&next_alias));
+}
+
+// New commits go above the stored rows whatever their dates, so a commit dated before the
+// history it joins is not sorted in between stored rows the way a fresh walk sorts it.
+#[test]
+fn resumed_walk_places_an_older_dated_new_commit_above_the_cached_rows() {
+    let (path, repo, _) = branchy_repo("cache-older");
+    let cached = full_walk(&path);
+    let side = repo.find_branch("side", git2::BranchType::Local).unwrap().get().target().unwrap();
+    let old = commit_with_parents(&repo, "old.txt", "old", &[side], 0);
+    repo.reference("refs/heads/side", old, true, "test").unwrap();
+
+    let resumed = Walker::resume(path.display().to_string(), 2, HashSet::new(), false, true, 20, cached.walk_cache()).unwrap().unwrap();
+    let rows = sorted_oids(&resumed);
+
+    assert_eq!(rows.first(), Some(&old));
+    assert_eq!(rows[1..], sorted_oids(&cached)[..]);
+    assert_ne!(rows, sorted_oids(&full_walk(&path)));
+    // Still children before parents, and the moved branch is labelled on its new tip.
+    for (index, oid) in rows.iter().enumerate() {
+        assert!(repo.find_commit(*oid).unwrap().parent_ids().all(|parent| !rows[..index].contains(&parent)));
+    }
+    assert!(resumed.branches_lanes.contains_key(&resumed.oids.aliases[&old]));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn refreshed_walk_keeps_its_aliases_and_places_new_commits_above_its_rows() {
+    let (path, repo, merge) = branchy_repo("refresh-in-place");
+    let mut walker = full_walk(&path);
+    let merge_alias = walker.oids.aliases.get(&merge).copied().unwrap();
+
+    let next = commit_with_parents(&repo, "next.txt", "next", &[merge], 5);
+    repo.reference("refs/heads/main", next, true, "test").unwrap();
+
+    assert!(walker.refresh(HashSet::new(), false, true, 20).unwrap());
+    let fresh = full_walk(&path);
+
+    assert_eq!(walker.oids.aliases.get(&merge), Some(&merge_alias));
+    assert_eq!(walk_by_oid(&walker), walk_by_oid(&fresh));
+    assert_eq!(branch_lanes_by_oid(&walker), branch_lanes_by_oid(&fresh));
+
+    // A changed setting describes other lanes, so the walk cannot be extended.
+    assert!(!walker.refresh(HashSet::new(), false, true, 8).unwrap());
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn rewound_tips_and_changed_settings_invalidate_the_cache() {
+    let (path, repo, merge) = branchy_repo("cache-rewound");
+    let cache = full_walk(&path).walk_cache();
+
+    assert!(Walker::resume(path.display().to_string(), 2, HashSet::new(), false, true, 8, cache.clone()).unwrap().is_none());
+
+    let main = repo.find_commit(merge).unwrap().parent_id(0).unwrap();
+    repo.reference("refs/heads/main", main, true, "test").unwrap();
+
+    assert!(Walker::resume(path.display().to_string(), 2, HashSet::new(), false, true, 20, cache).unwrap().is_none());
+}
+
+#[test]
+fn walks_read_through_the_commit_graph_match_walks_without_it() {
+    let (path, repo, merge) = branchy_repo("commit-graph");
+    let written = std::process::Command::new("git").arg("-C").arg(&path).args(["commit-graph", "write", "--reachable"]).output().is_ok_and(|output| output.status.success());
+    if !written {
+        let _ = fs::remove_dir_all(path);
+        return;
+    }
+    // Newer than the file, so its parents are still read from the object database.
+    let next = commit_with_parents(&repo, "next.txt", "next", &[merge], 5);
+    repo.reference("refs/heads/main", next, true, "test").unwrap();
+
+    let with_graph = full_walk(&path);
+    fs::remove_file(path.join(".git/objects/info/commit-graph")).unwrap();
+    let without_graph = full_walk(&path);
+
+    assert!(with_graph.commit_graph.is_some());
+    assert!(without_graph.commit_graph.is_none());
+    assert_eq!(walk_by_oid(&with_graph), walk_by_oid(&without_graph));
+    assert_eq!(branch_lanes_by_oid(&with_graph), branch_lanes_by_oid(&without_graph));
+    let _ = fs::remove_dir_all(path);
+}
+
+fn sorted_oids(walker: &Walker) -> Vec<Oid> {
+    walker.oids.get_sorted_aliases().iter().skip(1).map(|&alias| *walker.oids.get_oid_by_alias(alias)).collect()
+}
+
+#[test]
+fn replace_refs_swap_parents_unless_original_parents_are_shown() {
+    let (path, repo) = temp_repo("replace");
+    let root = commit_with_parents(&repo, "root.txt", "root", &[], 1);
+    let middle = commit_with_parents(&repo, "middle.txt", "middle", &[root], 2);
+    let tip = commit_with_parents(&repo, "tip.txt", "tip", &[middle], 3);
+    repo.reference("refs/heads/main", tip, true, "test").unwrap();
+    repo.set_head("refs/heads/main").unwrap();
+    let replacement = commit_with_parents(&repo, "middle.txt", "replaced", &[], 2);
+    repo.reference(&format!("refs/replace/{middle}"), replacement, false, "test").unwrap();
+
+    let mut replaced = Walker::new(path.display().to_string(), 2, HashSet::new(), false, true, 20).unwrap();
+    while replaced.walk() {}
+    // The replaced commit keeps its id but takes the replacement's parents and message.
+    assert_eq!(sorted_oids(&replaced), vec![tip, middle]);
+    let rows = graph_rows(&replaced, &Worktrees::default(), &HashSet::new(), &SymbolTheme::main(), 0, 3);
+    assert_eq!(rows[2].summary, "replaced");
+    assert!(!replaced.is_cacheable());
+    assert!(Walker::resume(path.display().to_string(), 2, HashSet::new(), false, true, 20, replaced.walk_cache()).unwrap().is_none());
+
+    let original = full_walk_with(&path, false);
+    assert_eq!(sorted_oids(&original), vec![tip, middle, root]);
+    assert!(original.is_cacheable());
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn shallow_boundaries_are_flagged_on_their_rows() {
+    let (path, repo) = temp_repo("shallow");
+    commit(&repo, "file.txt", "root");
+    let boundary = commit(&repo, "file.txt", "boundary");
+    let tip = commit(&repo, "file.txt", "tip");
+    fs::write(path.join(".git/shallow"), format!("{boundary}\n")).unwrap();
+
+    let walker = full_walk_with(&path, true);
+    // History stops at the boundary instead of running on to the root.
+    assert_eq!(sorted_oids(&walker), vec![tip, boundary]);
+    let rows = graph_rows(&walker, &Worktrees::default(), &HashSet::new(), &SymbolTheme::main(), 0, 3);
+    assert_eq!(rows.iter().map(|row| row.is_shallow).collect::<Vec<_>>(), vec![false, false, true]);
+
+    let symbols = SymbolTheme::main();
+    let history = walker.buffer.borrow().window(0, 4);
+    let lines = render_graph_projection(&Theme::classic(), &symbols, &rows, &history, walker.oids.aliases[&tip], 0, 3, true);
+    assert!(line_text(&lines[2]).contains(&symbols.graph.commit_shallow));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn deepening_a_shallow_clone_invalidates_the_cache() {
+    let (path, repo) = temp_repo("shallow-deepen");
+    let root = commit(&repo, "file.txt", "root");
+    let middle = commit(&repo, "file.txt", "middle");
+    let boundary = commit(&repo, "file.txt", "boundary");
+    let tip = commit(&repo, "file.txt", "tip");
+    fs::write(path.join(".git/shallow"), format!("{boundary}\n")).unwrap();
+    let cache = full_walk(&path).walk_cache();
+
+    // A deepen moves the boundary down while every tip stays where it was.
+    fs::write(path.join(".git/shallow"), format!("{root}\n")).unwrap();
+
+    assert!(Walker::resume(path.display().to_string(), 2, HashSet::new(), false, true, 20, cache).unwrap().is_none());
+    assert_eq!(sorted_oids(&full_walk(&path)), vec![tip, boundary, middle, root]);
+    let _ = fs::remove_dir_all(path);
+}
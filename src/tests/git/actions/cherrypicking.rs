@@ -71,6 +71,7 @@ fn clean_cherrypick_commits_with_edited_message() {
     assert_eq!(head.id(), oid);
     assert_eq!(head.parent(0).unwrap().id(), main);
     assert_eq!(head.summary(), Some("cherrypicked: feature"));
+    assert_eq!(repo.reflog("HEAD").unwrap().get(0).unwrap().message(), Some("cherry-pick: cherrypicked: feature"));
     assert!(!is_cherrypick_in_progress(&repo));
     assert!(!message_path(&repo).exists());
     let _ = fs::remove_dir_all(path);
@@ -128,3 +129,37 @@ fn abort_restores_pre_cherrypick_state() {
     assert!(!message_path(&repo).exists());
     let _ = fs::remove_dir_all(path);
 }
+
+#[test]
+fn merge_commit_cherrypicks_against_chosen_mainline() {
+    let (path, repo) = temp_repo("merge-mainline");
+    write(&path, "base.txt", "base\n");
+    commit(&repo, "base.txt", "base");
+    checkout_new_branch(&repo, "release");
+    checkout_branch(&repo, "master");
+    checkout_new_branch(&repo, "feature");
+    write(&path, "feature.txt", "feature\n");
+    let feature = commit(&repo, "feature.txt", "feature");
+    checkout_branch(&repo, "master");
+    write(&path, "main.txt", "main\n");
+    let main = commit(&repo, "main.txt", "main");
+
+    write(&path, "feature.txt", "feature\n");
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("feature.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parents = [&repo.find_commit(main).unwrap(), &repo.find_commit(feature).unwrap()];
+    let merge = repo.commit(Some("HEAD"), &sig, &sig, "merge feature", &tree, &parents).unwrap();
+
+    checkout_branch(&repo, "release");
+    assert!(start_cherrypick(&repo, merge, "cherrypicked: merge").unwrap_err().message().contains("merge commits"));
+
+    let outcome = start_cherrypick_with_mainline(&repo, merge, Some(1), "cherrypicked: merge feature").unwrap();
+    assert!(matches!(outcome, CherrypickOutcome::Committed { .. }));
+    assert!(path.join("feature.txt").exists());
+    assert!(!path.join("main.txt").exists());
+    assert_eq!(repo.head().unwrap().peel_to_commit().unwrap().parent_count(), 1);
+    let _ = fs::remove_dir_all(path);
+}
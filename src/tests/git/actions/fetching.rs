@@ -0,0 +1,97 @@
+use super::*;
+use git2::Signature;
+use std::collections::HashSet;
+use std::{
+    fs,
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    thread::sleep,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+fn temp_path(name: &str) -> PathBuf {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    std::env::temp_dir().join(format!("guitar-fetching-{name}-{id}"))
+}
+
+fn commit(repo: &Repository, message: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join("file.txt"), message).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("file.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+// libgit2's local transport refuses shallow fetches, so the origin is served over git://.
+struct Daemon {
+    child: Child,
+    url: String,
+}
+
+impl Drop for Daemon {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+fn serve(origin: &Path) -> Option<Daemon> {
+    let port = TcpListener::bind("127.0.0.1:0").ok()?.local_addr().ok()?.port();
+    let base = origin.parent()?;
+    let child = Command::new("git")
+        .args(["daemon", "--export-all", "--reuseaddr", "--listen=127.0.0.1"])
+        .arg(format!("--port={port}"))
+        .arg(format!("--base-path={}", base.display()))
+        .arg(base)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    let daemon = Daemon { child, url: format!("git://127.0.0.1:{port}/{}", origin.file_name()?.to_str()?) };
+    (0..50)
+        .any(|_| {
+            TcpStream::connect(("127.0.0.1", port)).is_ok() || {
+                sleep(Duration::from_millis(100));
+                false
+            }
+        })
+        .then_some(daemon)
+}
+
+fn shallow_clone(url: &str, clone: &Path, depth: usize) -> bool {
+    Command::new("git").arg("clone").arg(format!("--depth={depth}")).arg(url).arg(clone).output().is_ok_and(|output| output.status.success())
+}
+
+#[test]
+fn deepen_counts_from_the_boundary_even_when_the_remote_moved_on() {
+    let origin_path = temp_path("origin");
+    let clone_path = temp_path("clone");
+    let origin = Repository::init(&origin_path).unwrap();
+    let history: Vec<Oid> = (1..=6).map(|index| commit(&origin, &format!("c{index}"))).collect();
+    let Some(daemon) = serve(&origin_path).filter(|daemon| shallow_clone(&daemon.url, &clone_path, 2)) else {
+        eprintln!("skipped: the git CLI is needed to serve the origin and make a shallow clone");
+        let _ = fs::remove_dir_all(origin_path);
+        return;
+    };
+    let clone = Repository::open(&clone_path).unwrap();
+    assert_eq!(get_shallow_boundaries(&clone), HashSet::from([history[4]]));
+
+    // Three new commits on the remote would swallow a depth counted from the old tips.
+    (7..=9).for_each(|index| {
+        commit(&origin, &format!("c{index}"));
+    });
+    let result = deepen_remote(clone_path.to_str().unwrap(), None, "origin", 2, AuthSession::default()).join().unwrap();
+    drop(daemon);
+    assert!(matches!(result, NetworkResult::Success), "{result:?}");
+
+    let clone = Repository::open(&clone_path).unwrap();
+    assert_eq!(get_shallow_boundaries(&clone), HashSet::from([history[2]]));
+    assert!(clone.find_commit(history[3]).unwrap().parent_count() == 1);
+    let _ = fs::remove_dir_all(origin_path);
+    let _ = fs::remove_dir_all(clone_path);
+}
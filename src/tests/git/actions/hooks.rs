@@ -0,0 +1,99 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-hooks-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    (path, repo)
+}
+
+#[cfg(unix)]
+fn write_hook(dir: &Path, hook: Hook, script: &str) {
+    use std::os::unix::fs::PermissionsExt;
+    fs::create_dir_all(dir).unwrap();
+    let path = dir.join(hook.name());
+    fs::write(&path, format!("#!/bin/sh\n{script}\n")).unwrap();
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[test]
+fn hooks_dir_resolves_relative_hooks_path_from_the_worktree() {
+    let (path, repo) = temp_repo("hooks-path");
+    assert_eq!(hooks_dir(&repo), repo.path().join("hooks"));
+
+    repo.config().unwrap().set_str("core.hooksPath", "tools/hooks").unwrap();
+    assert_eq!(hooks_dir(&repo), repo.workdir().unwrap().join("tools/hooks"));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[cfg(unix)]
+#[test]
+fn failing_hook_stops_the_run_and_keeps_its_output() {
+    let (path, repo) = temp_repo("failing");
+    let dir = hooks_dir(&repo);
+    write_hook(&dir, Hook::PreCommit, "echo checking\necho 'lint failed' >&2\nexit 3");
+    write_hook(&dir, Hook::CommitMsg, "echo should not run");
+
+    let runs = commit_hook_runs(&repo, "message", true).unwrap();
+    let log = ProcessLog::default();
+    let error = run_hooks(&runs, &log).unwrap_err();
+
+    assert!(error.message().contains("pre-commit"));
+    assert!(error.message().contains("exit code 3"));
+    let lines = log.lock().unwrap().clone();
+    assert!(lines.contains(&"checking".to_string()));
+    assert!(lines.contains(&"lint failed".to_string()));
+    assert!(!lines.contains(&"should not run".to_string()));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[cfg(unix)]
+#[test]
+fn message_hooks_rewrite_the_commit_message() {
+    let (path, repo) = temp_repo("message");
+    let dir = hooks_dir(&repo);
+    write_hook(&dir, Hook::PrepareCommitMsg, "test \"$2\" = message || exit 1");
+    write_hook(&dir, Hook::CommitMsg, "printf 'ticket: %s\\n\\n\\n' \"$(cat \"$1\")\" > \"$1\"");
+
+    let runs = commit_hook_runs(&repo, "fix parser", true).unwrap();
+    run_hooks(&runs, &ProcessLog::default()).unwrap();
+
+    assert_eq!(read_commit_message(&repo).unwrap(), "ticket: fix parser");
+    let _ = fs::remove_dir_all(path);
+}
+
+#[cfg(unix)]
+#[test]
+fn skipping_verification_keeps_only_prepare_commit_msg() {
+    let (path, repo) = temp_repo("skip");
+    let dir = hooks_dir(&repo);
+    for hook in [Hook::PreCommit, Hook::PrepareCommitMsg, Hook::CommitMsg] {
+        write_hook(&dir, hook, "exit 0");
+    }
+
+    let hooks: Vec<Hook> = commit_hook_runs(&repo, "message", false).unwrap().iter().map(|run| run.hook).collect();
+    assert_eq!(hooks, vec![Hook::PrepareCommitMsg]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[cfg(unix)]
+#[test]
+fn pre_push_gets_remote_arguments_and_pushed_refs() {
+    let (path, repo) = temp_repo("pre-push");
+    let signature = git2::Signature::now("Test User", "test@example.com").unwrap();
+    let tree = repo.find_tree(repo.index().unwrap().write_tree().unwrap()).unwrap();
+    let oid = repo.commit(Some("HEAD"), &signature, &signature, "base", &tree, &[]).unwrap();
+    let branch = repo.head().unwrap().shorthand().unwrap().to_string();
+    repo.remote("origin", "https://example.com/repo.git").unwrap();
+    write_hook(&hooks_dir(&repo), Hook::PrePush, "echo \"$1 $2\"\ncat");
+
+    let run = pre_push_run(&repo, "origin", PushedRef::Branch { branch: branch.clone() }).unwrap().unwrap();
+    let log = ProcessLog::default();
+    run.run(&log).unwrap();
+
+    let refname = format!("refs/heads/{branch}");
+    assert_eq!(*log.lock().unwrap(), vec!["origin https://example.com/repo.git".to_string(), format!("{refname} {oid} {refname} {ZERO_OID}")]);
+    let _ = fs::remove_dir_all(path);
+}
@@ -0,0 +1,173 @@
+use super::*;
+use crate::git::actions::{
+    branching::delete_branch,
+    resetting::reset_to_commit,
+    stashing::{pop, stash},
+};
+use git2::{ResetType, Signature};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-journal-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn commit(repo: &Repository, file: &str, content: &str, message: &str) -> Oid {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    fs::write(workdir.join(file), content).unwrap();
+
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+fn record(repo: &Repository, operation: Command, action: impl FnOnce(&Repository)) -> Option<JournalEntry> {
+    let before = capture_ref_state(repo).unwrap();
+    action(repo);
+    let after = capture_ref_state(repo).unwrap();
+    journal_entry(operation, &before, &after)
+}
+
+#[test]
+fn untouched_refs_produce_no_entry() {
+    let (path, repo) = temp_repo("noop");
+    commit(&repo, "file.txt", "one\n", "one");
+
+    assert_eq!(record(&repo, Command::Stage, |_| {}), None);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn hard_reset_is_recorded_and_undone() {
+    let (path, repo) = temp_repo("hard-reset");
+    let first = commit(&repo, "file.txt", "one\n", "one");
+    let second = commit(&repo, "file.txt", "two\n", "two");
+
+    let entry = record(&repo, Command::HardReset, |repo| reset_to_commit(repo, first, ResetType::Hard).unwrap()).unwrap();
+    assert_eq!(entry.refs, vec![JournalRef { name: "refs/heads/master".to_string(), before: Some(second.to_string()), after: Some(first.to_string()) }]);
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "one\n");
+
+    undo_journal_entry(&repo, &entry).unwrap();
+
+    assert_eq!(repo.head().unwrap().target(), Some(second));
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "two\n");
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn deleted_branch_tip_is_restored() {
+    let (path, repo) = temp_repo("delete-branch");
+    let tip = commit(&repo, "file.txt", "one\n", "one");
+    repo.branch("topic", &repo.find_commit(tip).unwrap(), false).unwrap();
+
+    let entry = record(&repo, Command::DeleteBranch, |repo| delete_branch(repo, "topic").unwrap()).unwrap();
+    assert!(entry.refs.iter().any(|journal_ref| journal_ref.name == "refs/heads/topic" && journal_ref.is_deleted()));
+
+    undo_journal_entry(&repo, &entry).unwrap();
+
+    assert_eq!(repo.refname_to_id("refs/heads/topic").unwrap(), tip);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn dropped_stash_is_stored_again() {
+    let (path, _) = temp_repo("drop-stash");
+    let mut repo = Repository::open(&path).unwrap();
+    commit(&repo, "file.txt", "one\n", "one");
+    fs::write(path.join("file.txt"), "dirty\n").unwrap();
+    let stashed = stash(&mut repo).unwrap();
+
+    let before = capture_ref_state(&repo).unwrap();
+    pop(&mut repo, &stashed, false).unwrap();
+    let after = capture_ref_state(&repo).unwrap();
+    let entry = journal_entry(Command::Drop, &before, &after).unwrap();
+    assert_eq!(entry.dropped_stashes.len(), 1);
+    assert_eq!(entry.dropped_stashes[0].oid, stashed.to_string());
+
+    undo_journal_entry(&repo, &entry).unwrap();
+
+    let mut stashes = Vec::new();
+    repo.stash_foreach(|_, message, oid| {
+        stashes.push((*oid, message.to_string()));
+        true
+    })
+    .unwrap();
+    assert_eq!(stashes, vec![(stashed, entry.dropped_stashes[0].message.clone())]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn branch_switch_restores_symbolic_head() {
+    let (path, repo) = temp_repo("switch");
+    commit(&repo, "file.txt", "one\n", "one");
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    repo.branch("topic", &head, false).unwrap();
+
+    let entry = record(&repo, Command::Checkout, |repo| repo.set_head("refs/heads/topic").unwrap()).unwrap();
+    assert!(entry.refs.is_empty());
+    assert_eq!(entry.head_after, "refs/heads/topic");
+
+    undo_journal_entry(&repo, &entry).unwrap();
+
+    assert_eq!(repo.head().unwrap().name(), Some("refs/heads/master"));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn undo_refuses_refs_that_moved_afterwards() {
+    let (path, repo) = temp_repo("moved");
+    let first = commit(&repo, "file.txt", "one\n", "one");
+    commit(&repo, "file.txt", "two\n", "two");
+
+    let entry = record(&repo, Command::HardReset, |repo| reset_to_commit(repo, first, ResetType::Hard).unwrap()).unwrap();
+    let third = commit(&repo, "file.txt", "three\n", "three");
+
+    assert!(undo_journal_entry(&repo, &entry).unwrap_err().message().contains("moved"));
+    assert_eq!(repo.head().unwrap().target(), Some(third));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn undo_keeps_uncommitted_changes_and_refuses_to_move_a_dirty_worktree() {
+    let (path, repo) = temp_repo("dirty");
+    let first = commit(&repo, "file.txt", "one\n", "one");
+    commit(&repo, "file.txt", "two\n", "two");
+
+    let entry = record(&repo, Command::HardReset, |repo| reset_to_commit(repo, first, ResetType::Hard).unwrap()).unwrap();
+    fs::write(path.join("file.txt"), "local edit\n").unwrap();
+
+    assert!(undo_journal_entry(&repo, &entry).is_err());
+    assert_eq!(repo.head().unwrap().target(), Some(first));
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "local edit\n");
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn remote_only_entries_are_refused() {
+    let (path, repo) = temp_repo("remote-only");
+    let tip = commit(&repo, "file.txt", "one\n", "one");
+    repo.reference("refs/remotes/origin/master", tip, true, "fetch").unwrap();
+
+    let entry = record(&repo, Command::ForcePush, |repo| repo.find_reference("refs/remotes/origin/master").unwrap().delete().unwrap()).unwrap();
+
+    assert!(undo_journal_entry(&repo, &entry).unwrap_err().message().contains("remote"));
+    assert!(repo.find_reference("refs/remotes/origin/master").is_err());
+    let _ = fs::remove_dir_all(path);
+}
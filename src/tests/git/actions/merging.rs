@@ -107,6 +107,33 @@ fn divergent_clean_merge_creates_two_parent_commit() {
     let _ = fs::remove_dir_all(path);
 }
 
+#[test]
+fn staged_merge_waits_for_continue_to_commit() {
+    let (path, repo) = temp_repo("staged");
+    write(&path, "base.txt", "base\n");
+    commit(&repo, "base.txt", "base");
+    checkout_new_branch(&repo, "feature");
+    write(&path, "feature.txt", "feature\n");
+    let feature = commit(&repo, "feature.txt", "feature");
+    checkout_branch(&repo, "master");
+    write(&path, "main.txt", "main\n");
+    let main = commit(&repo, "main.txt", "main");
+
+    assert_eq!(stage_merge(&repo, feature).unwrap(), MergeOutcome::Staged);
+    assert!(is_merge_in_progress(&repo));
+    assert_eq!(repo.head().unwrap().target(), Some(main));
+    assert_eq!(fs::read_to_string(path.join("feature.txt")).unwrap(), "feature\n");
+
+    let MergeOutcome::Completed { oid } = continue_merge(&repo).unwrap() else {
+        panic!("expected completed merge");
+    };
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.id(), oid);
+    assert_eq!(head.parent(1).unwrap().id(), feature);
+    assert!(!is_merge_in_progress(&repo));
+    let _ = fs::remove_dir_all(path);
+}
+
 #[test]
 fn conflict_then_continue_finishes_after_workdir_resolution() {
     let (path, repo) = temp_repo("conflict-continue");
@@ -0,0 +1,190 @@
+use super::*;
+use git2::{Repository, ResetType, Signature, Status};
+use std::{
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-patching-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn commit_as(repo: &Repository, author: &Signature<'_>, file: &str, content: &str, message: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), content).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree_oid = index.write_tree().unwrap();
+    let tree = repo.find_tree(tree_oid).unwrap();
+    let committer = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), author, &committer, message, &tree, &parents).unwrap()
+}
+
+fn commit(repo: &Repository, file: &str, content: &str, message: &str) -> Oid {
+    commit_as(repo, &Signature::now("Test User", "test@example.com").unwrap(), file, content, message)
+}
+
+fn hard_reset(repo: &Repository, oid: Oid) {
+    repo.reset(repo.find_commit(oid).unwrap().as_object(), ResetType::Hard, None).unwrap();
+}
+
+#[test]
+fn exported_series_commits_back_with_original_author_and_message() {
+    let (path, repo) = temp_repo("roundtrip");
+    let base = commit(&repo, "file.txt", "base\n", "base");
+    let author = Signature::new("Mail Author", "mail@example.com", &Time::new(1_600_000_000, 120)).unwrap();
+    let first = commit_as(&repo, &author, "file.txt", "base\nfirst\n", "first change\n\nExplains the first change.\n>From the archive.\n");
+    let second = commit_as(&repo, &author, "other.txt", "other\n", "second change");
+
+    // Marked order does not matter; the series always runs oldest first.
+    let mbox = export_patches(&repo, &[second, first]).unwrap();
+    let text = String::from_utf8_lossy(&mbox);
+    assert!(text.contains("Subject: [PATCH 1/2] first change"));
+    assert!(text.contains("Subject: [PATCH 2/2] second change"));
+
+    let patch_path = path.with_extension("mbox");
+    fs::write(&patch_path, &mbox).unwrap();
+    hard_reset(&repo, base);
+    assert_eq!(apply_patches(&repo, &patch_path, PatchTarget::Commit, "Applier", "applier@example.com").unwrap(), 2);
+
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.tree_id(), repo.find_commit(second).unwrap().tree_id());
+    assert_eq!(head.message(), Some("second change\n"));
+    assert_eq!(head.author().name(), Some("Mail Author"));
+    assert_eq!(head.author().when().seconds(), 1_600_000_000);
+    assert_eq!(head.committer().name(), Some("Applier"));
+    let parent = head.parent(0).unwrap();
+    assert_eq!(parent.message(), Some("first change\n\nExplains the first change.\n>From the archive.\n"));
+    assert_eq!(parent.parent_id(0).unwrap(), base);
+    let _ = fs::remove_file(patch_path);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn plain_diff_applies_to_the_index_without_committing() {
+    let (path, repo) = temp_repo("index");
+    let base = commit(&repo, "file.txt", "base\n", "base");
+    let changed = commit(&repo, "file.txt", "changed\n", "change");
+    let patch_path = path.with_extension("patch");
+    fs::write(&patch_path, export_patches(&repo, &[changed]).unwrap()).unwrap();
+    hard_reset(&repo, base);
+
+    apply_patches(&repo, &patch_path, PatchTarget::Index, "Applier", "applier@example.com").unwrap();
+
+    assert_eq!(repo.head().unwrap().target(), Some(base));
+    assert_eq!(repo.status_file(Path::new("file.txt")).unwrap(), Status::INDEX_MODIFIED | Status::WT_MODIFIED);
+    let _ = fs::remove_file(patch_path);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn split_mbox_reads_folded_subjects_and_stops_the_body_at_the_diffstat() {
+    let mbox = "From 0123456789abcdef0123456789abcdef01234567 Mon Sep 17 00:00:00 2001\n\
+From: \"Mail Author\" <mail@example.com>\n\
+Date: Sun, 13 Sep 2020 14:26:40 +0200\n\
+Subject: [PATCH v2 3/7] a subject that\n wraps\n\
+\n\
+From the body, not a separator.\n\
+---\n\
+ file.txt | 2 +-\n\
+\n\
+diff --git a/file.txt b/file.txt\n\
+--- a/file.txt\n\
++++ b/file.txt\n\
+@@ -1 +1 @@\n\
+-base\n\
++changed\n\
+-- \n\
+2.43.0\n";
+
+    let mails = split_mbox(mbox.as_bytes());
+    assert_eq!(mails.len(), 1);
+    assert_eq!(mails[0].author, Some(("Mail Author".to_string(), "mail@example.com".to_string())));
+    assert_eq!(mails[0].time, Some(Time::new(1_600_000_000, 120)));
+    assert_eq!(mails[0].subject, "a subject that wraps");
+    assert_eq!(mails[0].body, "From the body, not a separator.");
+    assert!(mails[0].diff.starts_with(b"diff --git") && mails[0].diff.ends_with(b"+changed\n"));
+}
+
+#[test]
+fn binary_and_non_utf8_changes_survive_export_and_commit() {
+    let (path, repo) = temp_repo("binary");
+    let base = commit(&repo, "file.txt", "base\n", "base");
+    let binary: Vec<u8> = (0..=255u8).cycle().take(2048).collect();
+    fs::write(path.join("image.bin"), &binary).unwrap();
+    // Latin-1 text is valid in a patch but not in a Rust string.
+    fs::write(path.join("file.txt"), b"caf\xe9\n").unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("image.bin")).unwrap();
+    index.add_path(Path::new("file.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.find_commit(base).unwrap();
+    let changed = repo.commit(Some("HEAD"), &sig, &sig, "binary", &tree, &[&parent]).unwrap();
+
+    let patch_path = path.with_extension("patch");
+    let written = write_patches(&repo, &[changed], &patch_path).unwrap();
+    assert_eq!(written, 1);
+    assert!(String::from_utf8_lossy(&fs::read(&patch_path).unwrap()).contains("GIT binary patch"));
+    hard_reset(&repo, base);
+
+    apply_patches(&repo, &patch_path, PatchTarget::Commit, "Applier", "applier@example.com").unwrap();
+
+    assert_eq!(repo.head().unwrap().peel_to_commit().unwrap().tree_id(), repo.find_commit(changed).unwrap().tree_id());
+    assert_eq!(fs::read(path.join("image.bin")).unwrap(), binary);
+    assert_eq!(fs::read(path.join("file.txt")).unwrap(), b"caf\xe9\n");
+    let _ = fs::remove_file(patch_path);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn git_format_patch_output_keeps_non_ascii_authors_and_quoted_from_lines() {
+    let (path, repo) = temp_repo("encoded-words");
+    let base = commit(&repo, "file.txt", "base\n", "base");
+    let author = Signature::new("Jörg Müller", "j@x", &Time::new(1_600_000_000, 0)).unwrap();
+    let message = "Grüße aus Köln\n\nFrom the office:\n>From the archive\n";
+    let change = commit_as(&repo, &author, "file.txt", "base\nchange\n", message);
+
+    let output = std::process::Command::new("git").args(["format-patch", "--stdout", "--pretty=mboxrd", "-1"]).current_dir(&path).output().unwrap();
+    assert!(output.status.success());
+    let text = String::from_utf8_lossy(&output.stdout);
+    assert!(text.contains("From: =?UTF-8?q?J=C3=B6rg=20M=C3=BCller?= <j@x>"));
+    assert!(text.contains("\n>From the office:\n>>From the archive\n"));
+
+    let patch_path = path.with_extension("mbox");
+    fs::write(&patch_path, &output.stdout).unwrap();
+    hard_reset(&repo, base);
+    assert_eq!(apply_patches(&repo, &patch_path, PatchTarget::Commit, "Applier", "applier@example.com").unwrap(), 1);
+
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(head.tree_id(), repo.find_commit(change).unwrap().tree_id());
+    assert_eq!(head.author().name(), Some("Jörg Müller"));
+    assert_eq!(head.author().email(), Some("j@x"));
+    assert_eq!(head.message(), Some(message));
+    let _ = fs::remove_file(patch_path);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn split_mbox_joins_adjacent_base64_and_q_encoded_words() {
+    let mbox = b"From 0000000000000000000000000000000000000000 Mon Sep 17 00:00:00 2001\n\
+From: \"=?ISO-8859-1?Q?Fran=E7ois?= Dupont\" <f@x>\n\
+Subject: [PATCH] =?UTF-8?B?R3LDvMOfZQ==?=\n =?UTF-8?q?_aus_K=C3=B6ln?= =?bogus?\n\
+\n\
+diff --git a/a.txt b/a.txt\n";
+    let mails = split_mbox(mbox);
+    assert_eq!(mails[0].author, Some(("François Dupont".to_string(), "f@x".to_string())));
+    assert_eq!(mails[0].subject, "Grüße aus Köln =?bogus?");
+}
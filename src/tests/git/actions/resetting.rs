@@ -0,0 +1,129 @@
+use super::*;
+use git2::Signature;
+use std::{
+    fs,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-resetting-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn commit(repo: &Repository, file: &str, content: &str, message: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), content).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree_oid = index.write_tree().unwrap();
+    let tree = repo.find_tree(tree_oid).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+#[test]
+fn soft_reset_moves_branch_and_keeps_changes_staged() {
+    let (path, repo) = temp_repo("soft");
+    let first = commit(&repo, "file.txt", "first\n", "first");
+    commit(&repo, "file.txt", "second\n", "second");
+
+    reset_to_commit(&repo, first, ResetType::Soft).unwrap();
+
+    assert_eq!(repo.head().unwrap().target(), Some(first));
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "second\n");
+    let statuses = repo.statuses(None).unwrap();
+    assert_eq!(statuses.iter().map(|entry| entry.status()).collect::<Vec<_>>(), vec![Status::INDEX_MODIFIED]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn preview_lists_commits_lost_from_the_branch_and_edited_files() {
+    let (path, repo) = temp_repo("preview");
+    commit(&repo, "file.txt", "first\n", "first");
+    let second = commit(&repo, "file.txt", "second\n", "second");
+    let third = commit(&repo, "file.txt", "third\n", "third");
+
+    // Another branch keeps `third` alive, but it still drops off the current branch.
+    repo.branch("keep", &repo.find_commit(third).unwrap(), false).unwrap();
+    fs::write(path.join("file.txt"), "edited\n").unwrap();
+    fs::write(path.join("scratch.txt"), "untracked\n").unwrap();
+
+    let preview = reset_preview(&repo, second).unwrap();
+    assert_eq!(preview.unreachable, vec![third]);
+    assert_eq!(preview.overwritten, vec!["file.txt".to_string()]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn preview_counts_untracked_files_only_when_the_target_writes_them() {
+    let (path, repo) = temp_repo("untracked");
+    commit(&repo, "file.txt", "first\n", "first");
+    let with_notes = commit(&repo, "notes.txt", "notes\n", "add notes");
+
+    let mut index = repo.index().unwrap();
+    index.remove_path(Path::new("notes.txt")).unwrap();
+    index.write().unwrap();
+    let without_notes = commit(&repo, "file.txt", "second\n", "drop notes");
+    assert!(repo.find_commit(without_notes).unwrap().tree().unwrap().get_path(Path::new("notes.txt")).is_err());
+
+    // notes.txt is now untracked again, and only the older commit would write over it.
+    fs::write(path.join("notes.txt"), "local notes\n").unwrap();
+    assert!(reset_preview(&repo, without_notes).unwrap().overwritten.is_empty());
+    assert_eq!(reset_preview(&repo, with_notes).unwrap().overwritten, vec!["notes.txt".to_string()]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn branch_reset_moves_only_the_named_branch() {
+    let (path, repo) = temp_repo("branch");
+    let first = commit(&repo, "file.txt", "first\n", "first");
+    let second = commit(&repo, "file.txt", "second\n", "second");
+    repo.branch("feature", &repo.find_commit(second).unwrap(), false).unwrap();
+    fs::write(path.join("file.txt"), "edited\n").unwrap();
+
+    let preview = reset_branch_preview(&repo, "feature", first).unwrap();
+    assert_eq!(preview.unreachable, vec![second]);
+    assert!(preview.overwritten.is_empty());
+
+    reset_branch(&repo, "feature", first).unwrap();
+
+    assert_eq!(repo.find_branch("feature", BranchType::Local).unwrap().get().target(), Some(first));
+    assert_eq!(repo.head().unwrap().target(), Some(second));
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "edited\n");
+    let current = repo.head().unwrap().shorthand().unwrap().to_string();
+    assert!(reset_branch(&repo, &current, first).is_err());
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn branch_reset_refuses_a_branch_checked_out_in_any_worktree() {
+    let (path, repo) = temp_repo("branch-worktree");
+    let first = commit(&repo, "file.txt", "first\n", "first");
+    let second = commit(&repo, "file.txt", "second\n", "second");
+    let feature = repo.branch("feature", &repo.find_commit(second).unwrap(), false).unwrap();
+    let linked_path = path.with_extension("linked");
+    let mut options = git2::WorktreeAddOptions::new();
+    options.reference(Some(feature.get()));
+    repo.worktree("linked", &linked_path, Some(&options)).unwrap();
+
+    assert!(reset_branch(&repo, "feature", first).is_err());
+    assert_eq!(repo.find_branch("feature", BranchType::Local).unwrap().get().target(), Some(second));
+
+    // From the linked worktree, the main worktree's branch is the one checked out elsewhere.
+    let linked = Repository::open(&linked_path).unwrap();
+    let main = repo.head().unwrap().shorthand().unwrap().to_string();
+    assert!(reset_branch(&linked, &main, first).is_err());
+    let _ = fs::remove_dir_all(linked_path);
+    let _ = fs::remove_dir_all(path);
+}
@@ -150,3 +150,57 @@ fn merge_commits_are_rejected() {
     assert!(!message_path(&repo).exists());
     let _ = fs::remove_dir_all(path);
 }
+
+fn merge_feature_into_master(repo: &Repository, path: &Path) -> Oid {
+    write(path, "base.txt", "base\n");
+    commit(repo, "base.txt", "base");
+    checkout_new_branch(repo, "feature");
+    write(path, "feature.txt", "feature\n");
+    let feature = commit(repo, "feature.txt", "feature");
+    checkout_branch(repo, "master");
+    write(path, "main.txt", "main\n");
+    let main = commit(repo, "main.txt", "main");
+
+    write(path, "feature.txt", "feature\n");
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("feature.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let feature_commit = repo.find_commit(feature).unwrap();
+    let main_commit = repo.find_commit(main).unwrap();
+    repo.commit(Some("HEAD"), &sig, &sig, "merge feature", &tree, &[&main_commit, &feature_commit]).unwrap()
+}
+
+#[test]
+fn merge_commit_reverts_against_chosen_mainline() {
+    let (path, repo) = temp_repo("merge-mainline");
+    let merge = merge_feature_into_master(&repo, &path);
+
+    let outcome = start_revert_with_mainline(&repo, merge, Some(1), "reverted: merge feature").unwrap();
+    let RevertOutcome::Committed { oid } = outcome else {
+        panic!("expected committed outcome");
+    };
+
+    let head = repo.find_commit(oid).unwrap();
+    assert_eq!(head.parent_count(), 1);
+    assert_eq!(head.parent_id(0).unwrap(), merge);
+    assert!(!path.join("feature.txt").exists());
+    assert!(path.join("main.txt").exists());
+    assert!(!message_path(&repo).exists());
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn invalid_mainline_is_rejected_before_touching_the_worktree() {
+    let (path, repo) = temp_repo("mainline-invalid");
+    let merge = merge_feature_into_master(&repo, &path);
+    let plain = repo.find_commit(merge).unwrap().parent_id(0).unwrap();
+
+    assert!(start_revert_with_mainline(&repo, merge, Some(3), "reverted").unwrap_err().message().contains("no parent 3"));
+    assert!(start_revert_with_mainline(&repo, merge, Some(0), "reverted").is_err());
+    assert!(start_revert_with_mainline(&repo, plain, Some(1), "reverted").unwrap_err().message().contains("not a merge"));
+    assert!(!is_revert_in_progress(&repo));
+    assert!(!message_path(&repo).exists());
+    let _ = fs::remove_dir_all(path);
+}
@@ -0,0 +1,255 @@
+use super::*;
+use git2::{Repository, Signature};
+use std::{
+    fs,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-sequence-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn write(path: &Path, file: &str, content: &str) {
+    fs::write(path.join(file), content).unwrap();
+}
+
+fn commit(repo: &Repository, file: &str, message: &str) -> Oid {
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree_oid = index.write_tree().unwrap();
+    let tree = repo.find_tree(tree_oid).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+fn checkout_new_branch(repo: &Repository, name: &str) {
+    let head = repo.head().unwrap().peel_to_commit().unwrap();
+    repo.branch(name, &head, false).unwrap();
+    repo.set_head(&format!("refs/heads/{name}")).unwrap();
+    repo.checkout_head(Some(CheckoutBuilder::default().force())).unwrap();
+}
+
+fn checkout_branch(repo: &Repository, name: &str) {
+    repo.set_head(&format!("refs/heads/{name}")).unwrap();
+    repo.checkout_head(Some(CheckoutBuilder::default().force())).unwrap();
+}
+
+fn step(oid: Oid, message: &str) -> SequenceStep {
+    SequenceStep { oid, message: message.to_string() }
+}
+
+fn head_summaries(repo: &Repository, count: usize) -> Vec<String> {
+    let mut commit = repo.head().unwrap().peel_to_commit().unwrap();
+    let mut summaries = vec![commit.summary().unwrap().to_string()];
+    while summaries.len() < count {
+        commit = commit.parent(0).unwrap();
+        summaries.push(commit.summary().unwrap().to_string());
+    }
+    summaries
+}
+
+#[test]
+fn order_sequence_replays_oldest_first_and_reverts_newest_first() {
+    let (path, repo) = temp_repo("order");
+    write(&path, "a.txt", "a\n");
+    let a = commit(&repo, "a.txt", "a");
+    write(&path, "b.txt", "b\n");
+    let b = commit(&repo, "b.txt", "b");
+    write(&path, "c.txt", "c\n");
+    let c = commit(&repo, "c.txt", "c");
+
+    assert_eq!(order_sequence(&repo, SequenceKind::Cherrypick, &[c, a, b]).unwrap(), vec![a, b, c]);
+    assert_eq!(order_sequence(&repo, SequenceKind::Revert, &[a, c, b]).unwrap(), vec![c, b, a]);
+    assert_eq!(range_oids(&repo, a, c).unwrap(), vec![c, b]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn order_sequence_orders_marks_on_diverged_branches() {
+    let (path, repo) = temp_repo("order-diverged");
+    write(&path, "root.txt", "root\n");
+    commit(&repo, "root.txt", "root");
+    write(&path, "base.txt", "base\n");
+    let base = commit(&repo, "base.txt", "base");
+    checkout_new_branch(&repo, "x");
+    write(&path, "x.txt", "x1\n");
+    let x1 = commit(&repo, "x.txt", "x1");
+    write(&path, "x.txt", "x2\n");
+    let x2 = commit(&repo, "x.txt", "x2");
+    repo.set_head_detached(base).unwrap();
+    checkout_new_branch(&repo, "y");
+    write(&path, "y.txt", "y1\n");
+    let y1 = commit(&repo, "y.txt", "y1");
+
+    let picks = order_sequence(&repo, SequenceKind::Cherrypick, &[x2, y1, x1, base]).unwrap();
+    assert_eq!(picks.len(), 4);
+    assert_eq!(picks[0], base);
+    assert!(picks.iter().position(|oid| *oid == x1) < picks.iter().position(|oid| *oid == x2));
+    assert_eq!(order_sequence(&repo, SequenceKind::Revert, &[y1]).unwrap(), vec![y1]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn cherrypick_sequence_applies_every_commit_in_order() {
+    let (path, repo) = temp_repo("clean");
+    write(&path, "base.txt", "base\n");
+    commit(&repo, "base.txt", "base");
+    checkout_new_branch(&repo, "feature");
+    write(&path, "one.txt", "one\n");
+    let one = commit(&repo, "one.txt", "one");
+    write(&path, "two.txt", "two\n");
+    let two = commit(&repo, "two.txt", "two");
+    checkout_branch(&repo, "master");
+
+    let ordered = order_sequence(&repo, SequenceKind::Cherrypick, &[two, one]).unwrap();
+    let steps = ordered.iter().map(|oid| step(*oid, &format!("cherrypicked: {}", repo.find_commit(*oid).unwrap().summary().unwrap()))).collect();
+    let outcome = start_sequence(&repo, SequenceKind::Cherrypick, steps).unwrap();
+
+    assert_eq!(outcome, SequenceOutcome::Completed { applied: 2 });
+    assert_eq!(head_summaries(&repo, 3), vec!["cherrypicked: two", "cherrypicked: one", "base"]);
+    assert!(!is_sequence_in_progress(&repo));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn conflict_pauses_sequence_and_continue_finishes_remaining_steps() {
+    let (path, repo) = temp_repo("conflict");
+    write(&path, "file.txt", "base\n");
+    commit(&repo, "file.txt", "base");
+    checkout_new_branch(&repo, "feature");
+    write(&path, "file.txt", "feature\n");
+    let conflicting = commit(&repo, "file.txt", "feature edit");
+    write(&path, "other.txt", "other\n");
+    let clean = commit(&repo, "other.txt", "other");
+    checkout_branch(&repo, "master");
+    write(&path, "file.txt", "main\n");
+    commit(&repo, "file.txt", "main edit");
+
+    let outcome = start_sequence(&repo, SequenceKind::Cherrypick, vec![step(conflicting, "picked feature"), step(clean, "picked other")]).unwrap();
+    assert_eq!(outcome, SequenceOutcome::Conflict { oid: conflicting, remaining: 1 });
+    assert!(is_sequence_in_progress(&repo));
+    assert!(is_cherrypick_in_progress(&repo));
+
+    // Reopening simulates restarting guitar while the sequence is paused.
+    let repo = Repository::open(&path).unwrap();
+    assert_eq!(sequence_kind(&repo), Some(SequenceKind::Cherrypick));
+    write(&path, "file.txt", "resolved\n");
+
+    let outcome = continue_sequence(&repo).unwrap();
+    assert_eq!(outcome, SequenceOutcome::Completed { applied: 2 });
+    assert_eq!(head_summaries(&repo, 3), vec!["picked other", "picked feature", "main edit"]);
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "resolved\n");
+    assert!(!is_sequence_in_progress(&repo));
+    assert!(!is_cherrypick_in_progress(&repo));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn abort_restores_head_from_before_the_sequence() {
+    let (path, repo) = temp_repo("abort");
+    write(&path, "file.txt", "base\n");
+    commit(&repo, "file.txt", "base");
+    write(&path, "a.txt", "a\n");
+    let a = commit(&repo, "a.txt", "add a");
+    write(&path, "file.txt", "changed\n");
+    let b = commit(&repo, "file.txt", "change file");
+    write(&path, "file.txt", "changed again\n");
+    let head = commit(&repo, "file.txt", "change file again");
+
+    let ordered = order_sequence(&repo, SequenceKind::Revert, &[a, b]).unwrap();
+    assert_eq!(ordered, vec![b, a]);
+    let outcome = start_sequence(&repo, SequenceKind::Revert, ordered.into_iter().map(|oid| step(oid, "reverted")).collect()).unwrap();
+    assert_eq!(outcome, SequenceOutcome::Conflict { oid: b, remaining: 1 });
+
+    assert_eq!(abort_sequence(&repo).unwrap(), SequenceOutcome::Aborted);
+    assert_eq!(repo.head().unwrap().peel_to_commit().unwrap().id(), head);
+    assert_eq!(fs::read_to_string(path.join("file.txt")).unwrap(), "changed again\n");
+    assert!(!is_sequence_in_progress(&repo));
+    assert!(!is_revert_in_progress(&repo));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn abort_leaves_head_alone_once_it_moved_off_the_sequence() {
+    let (path, repo) = temp_repo("abort-moved");
+    write(&path, "file.txt", "base\n");
+    commit(&repo, "file.txt", "base");
+    checkout_new_branch(&repo, "feature");
+    write(&path, "a.txt", "a\n");
+    let a = commit(&repo, "a.txt", "add a");
+    write(&path, "file.txt", "feature\n");
+    let conflicting = commit(&repo, "file.txt", "change file");
+    checkout_branch(&repo, "master");
+    write(&path, "file.txt", "master\n");
+    commit(&repo, "file.txt", "change file on master");
+
+    let outcome = start_sequence(&repo, SequenceKind::Cherrypick, vec![step(a, "add a"), step(conflicting, "change file")]).unwrap();
+    assert_eq!(outcome, SequenceOutcome::Conflict { oid: conflicting, remaining: 0 });
+    let state = read_state(&repo).unwrap();
+    assert_eq!(state.branch.as_deref(), Some("refs/heads/master"));
+    assert_eq!(state.last, repo.head().unwrap().peel_to_commit().unwrap().id());
+
+    // Another branch checked out: its HEAD is not the sequence's to rewind.
+    abort_cherrypick(&repo).unwrap();
+    checkout_branch(&repo, "feature");
+    assert!(abort_sequence(&repo).unwrap_err().message().contains("HEAD moved"));
+    assert_eq!(repo.head().unwrap().peel_to_commit().unwrap().id(), conflicting);
+    assert!(!is_sequence_in_progress(&repo));
+
+    // A commit made on top of the sequence outside guitar is kept too.
+    checkout_branch(&repo, "master");
+    write_state(&repo, &state).unwrap();
+    write(&path, "b.txt", "b\n");
+    let outside = commit(&repo, "b.txt", "add b");
+    assert!(abort_sequence(&repo).is_err());
+    assert_eq!(repo.head().unwrap().peel_to_commit().unwrap().id(), outside);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn start_rejects_a_second_sequence_and_empty_selection() {
+    let (path, repo) = temp_repo("reject");
+    write(&path, "file.txt", "base\n");
+    let base = commit(&repo, "file.txt", "base");
+
+    assert!(start_sequence(&repo, SequenceKind::Cherrypick, Vec::new()).is_err());
+    write_state(&repo, &SequenceState { kind: SequenceKind::Revert, head: base, branch: None, last: base, applied: 0, current: None, todo: vec![step(base, "reverted")] }).unwrap();
+    assert!(start_sequence(&repo, SequenceKind::Cherrypick, vec![step(base, "again")]).is_err());
+    assert_eq!(read_state(&repo).unwrap().todo, vec![step(base, "reverted")]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn validate_mainline_names_the_operation_and_checks_the_parent() {
+    let (path, repo) = temp_repo("mainline");
+    write(&path, "a.txt", "a\n");
+    let base = commit(&repo, "a.txt", "base");
+    write(&path, "a.txt", "b\n");
+    let side = commit(&repo, "a.txt", "side");
+    let base_commit = repo.find_commit(base).unwrap();
+    let side_commit = repo.find_commit(side).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let merge = repo.commit(None, &sig, &sig, "merge", &side_commit.tree().unwrap(), &[&base_commit, &side_commit]).unwrap();
+    let merge = repo.find_commit(merge).unwrap();
+
+    assert!(validate_mainline(SequenceKind::Cherrypick, &merge, None).unwrap_err().message().starts_with("cherry-picking"));
+    assert!(validate_mainline(SequenceKind::Revert, &merge, None).unwrap_err().message().starts_with("reverting"));
+    assert!(validate_mainline(SequenceKind::Revert, &merge, Some(2)).is_ok());
+    assert!(validate_mainline(SequenceKind::Revert, &merge, Some(3)).is_err());
+    assert!(validate_mainline(SequenceKind::Cherrypick, &side_commit, Some(1)).is_err());
+    assert!(validate_mainline(SequenceKind::Cherrypick, &side_commit, None).is_ok());
+    let _ = fs::remove_dir_all(path);
+}
@@ -0,0 +1,107 @@
+use super::*;
+use crate::git::actions::committing::commit_staged;
+use std::{fs, path::Path};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-signing-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn stage(repo: &Repository, file: &str, content: &str) {
+    fs::write(repo.workdir().unwrap().join(file), content).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+}
+
+// Keys live beside the repository; `None` when ssh-keygen is not installed.
+fn ssh_key(path: &Path) -> Option<PathBuf> {
+    let key = path.with_extension("key");
+    let status = Command::new("ssh-keygen").args(["-q", "-t", "ed25519", "-N", "", "-C", "test@example.com", "-f"]).arg(&key).stdout(Stdio::null()).stderr(Stdio::null()).status().ok()?;
+    status.success().then_some(key)
+}
+
+fn cleanup(path: &Path) {
+    for extension in ["key", "key.pub"] {
+        let _ = fs::remove_file(path.with_extension(extension));
+    }
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn commits_stay_unsigned_without_gpgsign() {
+    let (path, repo) = temp_repo("unsigned");
+    stage(&repo, "file.txt", "base\n");
+    let oid = commit_staged(&repo, "base", "Test User", "test@example.com").unwrap();
+
+    assert_eq!(signing_config(&repo).unwrap(), None);
+    assert!(repo.extract_signature(&oid, None).is_err());
+    cleanup(&path);
+}
+
+#[test]
+fn ssh_signed_commits_advance_the_branch() {
+    let (path, repo) = temp_repo("ssh");
+    let Some(key) = ssh_key(&path) else {
+        eprintln!("skipped: ssh-keygen is needed to create a signing key");
+        cleanup(&path);
+        return;
+    };
+    {
+        let mut config = repo.config().unwrap();
+        config.set_bool("commit.gpgsign", true).unwrap();
+        config.set_str("gpg.format", "ssh").unwrap();
+        config.set_str("user.signingkey", &key.display().to_string()).unwrap();
+    }
+
+    stage(&repo, "file.txt", "base\n");
+    let base = commit_staged(&repo, "base", "Test User", "test@example.com").unwrap();
+    stage(&repo, "file.txt", "second\n");
+    let second = commit_staged(&repo, "second", "Test User", "test@example.com").unwrap();
+
+    let head = repo.head().unwrap();
+    assert_eq!(head.shorthand(), repo.find_reference("HEAD").unwrap().symbolic_target().and_then(|target| target.strip_prefix("refs/heads/")));
+    assert_eq!(head.target(), Some(second));
+    assert_eq!(repo.find_commit(second).unwrap().parent_id(0).unwrap(), base);
+    let (signature, _) = repo.extract_signature(&second, None).unwrap();
+    assert!(signature.as_str().unwrap().starts_with("-----BEGIN SSH SIGNATURE-----"));
+    let reflog = repo.reflog("HEAD").unwrap();
+    assert_eq!(reflog.get(0).unwrap().message(), Some("commit: second"));
+    cleanup(&path);
+}
+
+#[test]
+fn ssh_signing_without_key_fails_and_keeps_head() {
+    let (path, repo) = temp_repo("no-key");
+    stage(&repo, "file.txt", "base\n");
+    let base = commit_staged(&repo, "base", "Test User", "test@example.com").unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_bool("commit.gpgsign", true).unwrap();
+        config.set_str("gpg.format", "ssh").unwrap();
+    }
+
+    stage(&repo, "file.txt", "second\n");
+    assert!(commit_staged(&repo, "second", "Test User", "test@example.com").is_err());
+    assert_eq!(repo.head().unwrap().target(), Some(base));
+    cleanup(&path);
+}
+
+#[test]
+fn programs_that_outlive_the_timeout_are_killed() {
+    let started = Instant::now();
+    let result = run_program_with_timeout("sleep", &["5"], &[], Duration::from_millis(100));
+
+    assert!(result.is_err());
+    assert!(started.elapsed() < Duration::from_secs(2));
+    let output = run_program_with_timeout("cat", &[], b"signed data", Duration::from_secs(5)).unwrap();
+    assert_eq!(output.stdout, b"signed data");
+}
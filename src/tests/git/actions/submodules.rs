@@ -124,7 +124,7 @@ fn update_submodule_initializes_plain_clone() {
     assert!(!list_submodules(&clone).unwrap()[0].is_open);
     drop(clone);
 
-    let handle = update_submodule(clone_path.to_str().unwrap(), "deps/child", Default::default());
+    let handle = update_submodule(clone_path.to_str().unwrap(), None, "deps/child", Default::default());
     match handle.join().unwrap() {
         NetworkResult::Success => {},
         other => panic!("unexpected update result: {other:?}"),
@@ -133,3 +133,26 @@ fn update_submodule_initializes_plain_clone() {
     let clone = Repository::open(&clone_path).unwrap();
     assert!(list_submodules(&clone).unwrap()[0].is_open);
 }
+
+#[test]
+fn update_submodule_checks_out_into_the_given_work_tree() {
+    let dir = TestDir::new("update-work-tree");
+    let (parent, _child_path) = parent_with_submodule(&dir);
+    let git_dir = dir.path.join("clone.git");
+    let work_tree = dir.path.join("work");
+    let mut builder = git2::build::RepoBuilder::new();
+    builder.bare(true);
+    drop(builder.clone(parent.workdir().unwrap().to_str().unwrap(), &git_dir).unwrap());
+    fs::create_dir_all(&work_tree).unwrap();
+    let clone = crate::git::os::path::open_repo(&git_dir, Some(&work_tree)).unwrap();
+    clone.checkout_head(Some(git2::build::CheckoutBuilder::new().force())).unwrap();
+    drop(clone);
+
+    let handle = update_submodule(git_dir.to_str().unwrap(), Some(work_tree.clone()), "deps/child", Default::default());
+    match handle.join().unwrap() {
+        NetworkResult::Success => {},
+        other => panic!("unexpected update result: {other:?}"),
+    }
+
+    assert_eq!(fs::read_to_string(work_tree.join("deps/child/file.txt")).unwrap(), "hello\n");
+}
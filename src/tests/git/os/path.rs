@@ -48,3 +48,14 @@ fn finds_worktree_root_with_git_file() {
     assert_eq!(try_into_git_repo_root(&nested).as_deref(), Some(worktree.as_path()));
     assert_eq!(try_into_git_repo_root(&worktree).as_deref(), Some(worktree.as_path()));
 }
+
+#[test]
+fn discovery_returns_the_git_dir_of_bare_repositories() {
+    let dir = TestDir::new("discover-bare");
+    let bare = dir.path.join("repo.git");
+    git2::Repository::init_bare(&bare).unwrap();
+
+    assert_eq!(discover_repo_root(&bare), Some(bare.canonicalize().unwrap()));
+    assert!(open_repo(&bare, None).unwrap().workdir().is_none());
+    assert_eq!(open_repo(&bare, Some(dir.path.as_path())).unwrap().workdir().map(|path| path.canonicalize().unwrap()), Some(dir.path.canonicalize().unwrap()));
+}
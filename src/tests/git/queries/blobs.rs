@@ -0,0 +1,109 @@
+use super::*;
+use git2::Signature;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_repo(name: &str) -> (std::path::PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-blobs-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    (path.clone(), Repository::init(&path).unwrap())
+}
+
+fn commit(repo: &Repository, file: &str, contents: &[u8]) -> Oid {
+    fs::write(repo.workdir().unwrap().join(file), contents).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, file, &tree, &parents).unwrap()
+}
+
+fn sides(new: &[u8]) -> FileSides {
+    FileSides { old: None, new: Some(FileSide { size: new.len() as u64, head: new.to_vec() }) }
+}
+
+#[test]
+fn binary_files_are_detected_by_content_or_attributes() {
+    let (path, repo) = temp_repo("binary");
+    assert!(is_binary_file(&repo, "image.png", None, &sides(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR")));
+    assert!(!is_binary_file(&repo, "notes.txt", None, &sides(b"plain text\n")));
+    // A NUL past the sniffed prefix does not make a text file binary, as in git.
+    let mut late_nul = vec![b'a'; BINARY_SNIFF_LEN];
+    late_nul.push(0);
+    assert!(!has_nul_byte(&late_nul));
+
+    fs::write(path.join(".gitattributes"), "*.dat binary\n*.lock -diff\n").unwrap();
+    assert!(is_binary_file(&repo, "table.dat", None, &sides(b"plain text\n")));
+    assert!(is_binary_file(&repo, "Cargo.lock", None, &sides(b"plain text\n")));
+    assert!(!is_binary_file(&repo, "notes.txt", None, &sides(b"plain text\n")));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn file_sides_read_the_parent_and_the_commit() {
+    let (path, repo) = temp_repo("sides");
+    commit(&repo, "a.bin", b"one\0");
+    let second = commit(&repo, "a.bin", b"one\0two\0");
+    fs::write(path.join("a.bin"), b"three").unwrap();
+
+    let head = |side: Option<&FileSide>| side.map(|side| side.head.clone());
+    let at_commit = get_file_sides_at_oid(&repo, second, "a.bin", None).unwrap();
+    assert_eq!((head(at_commit.old.as_ref()), head(at_commit.new.as_ref())), (Some(b"one\0".to_vec()), Some(b"one\0two\0".to_vec())));
+    let at_workdir = get_file_sides_at_workdir(&repo, "a.bin", None);
+    assert_eq!((head(at_workdir.old.as_ref()), head(at_workdir.shown())), (Some(b"one\0two\0".to_vec()), Some(b"three".to_vec())));
+
+    // A read limit keeps only the head of each side but still reports the whole size.
+    let limited = get_file_sides_at_oid(&repo, second, "a.bin", Some(3)).unwrap();
+    assert_eq!(limited.new, Some(FileSide { size: 8, head: b"one".to_vec() }));
+    assert!(limited.new.as_ref().is_some_and(FileSide::is_partial));
+    assert_eq!(get_file_sides_at_workdir(&repo, "a.bin", Some(2)).new, Some(FileSide { size: 5, head: b"th".to_vec() }));
+
+    fs::remove_file(path.join("a.bin")).unwrap();
+    let deleted = get_file_sides_at_workdir(&repo, "a.bin", None);
+    assert_eq!((head(deleted.new.as_ref()), head(deleted.shown())), (None, Some(b"one\0two\0".to_vec())));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn historical_commits_use_their_own_gitattributes() {
+    let (path, repo) = temp_repo("committed-attributes");
+    fs::create_dir_all(path.join("data/deep")).unwrap();
+    commit(&repo, ".gitattributes", b"*.dat -diff\n*.bin binary\n");
+    commit(&repo, "data/.gitattributes", b"deep/** -diff\nplain.dat diff\n");
+    let oid = commit(&repo, "data/plain.dat", b"text\n");
+    // Later commits stop marking anything as binary; the older commit still does.
+    commit(&repo, ".gitattributes", b"");
+    commit(&repo, "data/.gitattributes", b"");
+
+    let text = sides(b"text\n");
+    assert!(is_binary_file(&repo, "table.dat", Some(oid), &text));
+    assert!(!is_binary_file(&repo, "table.dat", None, &text));
+    assert!(is_binary_file(&repo, "data/image.bin", Some(oid), &text));
+    assert!(is_binary_file(&repo, "data/deep/nested/notes.txt", Some(oid), &text));
+    assert!(!is_binary_file(&repo, "data/notes.txt", Some(oid), &text));
+    // A deeper attributes file overrides the root one.
+    assert!(!is_binary_file(&repo, "data/plain.dat", Some(oid), &text));
+
+    assert!(attribute_pattern_matches("*.lock", "sub/Cargo.lock"));
+    assert!(attribute_pattern_matches("/docs/*.md", "docs/a.md"));
+    assert!(!attribute_pattern_matches("/docs/*.md", "docs/sub/a.md"));
+    assert!(attribute_pattern_matches("img[0-9].png", "img3.png"));
+    assert!(!attribute_pattern_matches("img[!0-9].png", "img3.png"));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn lfs_pointers_are_parsed_and_everything_else_is_content() {
+    let pointer = b"version https://git-lfs.github.com/spec/v1\noid sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393\nsize 12345\n";
+    assert_eq!(parse_lfs_pointer(pointer), Some(LfsPointer { oid: "sha256:4d7a214614ab2935c943f9e0ff69d22eadbb8f32b1258daaa5e2ca24d17e2393".to_string(), size: 12345 }));
+
+    assert_eq!(parse_lfs_pointer(b"version https://git-lfs.github.com/spec/v1\nsize 12\n"), None);
+    assert_eq!(parse_lfs_pointer(b"version https://git-lfs.github.com/spec/v1\noid sha256:abc\nsize twelve\n"), None);
+    assert_eq!(parse_lfs_pointer(b"oid sha256:abc\nsize 12\n"), None);
+    let mut oversized = pointer.to_vec();
+    oversized.extend(std::iter::repeat_n(b'\n', LFS_POINTER_MAX_LEN));
+    assert_eq!(parse_lfs_pointer(&oversized), None);
+}
@@ -153,7 +153,7 @@ fn workdir_file_diff_emits_untracked_file_contents_as_added_lines() {
     commit(&repo, "tracked.txt", "initial");
     write(&path, "new.txt", "alpha\nbeta\n");
 
-    let hunks = get_file_diff_at_workdir(&repo, "new.txt").unwrap();
+    let hunks = get_file_diff_at_workdir(&repo, "new.txt", &mut || false).unwrap();
     let content_lines = hunks.iter().flat_map(|hunk| hunk.lines.iter()).filter(|line| line.origin != 'H').collect::<Vec<_>>();
 
     assert!(!content_lines.is_empty());
@@ -0,0 +1,72 @@
+use super::*;
+use git2::Signature;
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-grafts-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    (path.clone(), Repository::init(&path).unwrap())
+}
+
+fn commit(repo: &Repository, message: &str) -> Oid {
+    fs::write(repo.workdir().unwrap().join("file.txt"), message).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new("file.txt")).unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &parents).unwrap()
+}
+
+#[test]
+fn shallow_file_lists_boundaries_and_sets_the_depth() {
+    let (path, repo) = temp_repo("shallow");
+    let first = commit(&repo, "first");
+    let second = commit(&repo, "second");
+    let third = commit(&repo, "third");
+    let fourth = commit(&repo, "fourth");
+    assert!(get_shallow_boundaries(&repo).is_empty());
+    assert_eq!(get_shallow_depth(&repo, &HashSet::new(), &[fourth]), 0);
+
+    fs::write(path.join(".git").join("shallow"), format!("{second}\n")).unwrap();
+    let repo = Repository::open(&path).unwrap();
+    let boundaries = get_shallow_boundaries(&repo);
+    assert_eq!(boundaries, HashSet::from([second]));
+    // The boundary looks like a root to libgit2, which is why the graph marks it.
+    assert!(repo.is_shallow());
+    assert_eq!(repo.find_commit(second).unwrap().parent_count(), 0);
+    assert!(repo.find_commit(first).is_ok());
+    assert_eq!(get_shallow_depth(&repo, &boundaries, &[fourth]), 3);
+    // Measured from the given tips, not from wherever the branches point.
+    assert_eq!(get_shallow_depth(&repo, &boundaries, &[third]), 2);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn replace_refs_map_commits_unless_disabled() {
+    let (path, repo) = temp_repo("replace");
+    let first = commit(&repo, "first");
+    let second = commit(&repo, "second");
+    commit(&repo, "third");
+    assert!(get_replacements(&repo).is_empty());
+
+    // A replacement for the second commit that has no parents.
+    let original = repo.find_commit(second).unwrap();
+    let replacement = repo.commit(None, &original.author(), &original.committer(), "replaced", &original.tree().unwrap(), &[]).unwrap();
+    repo.reference(&format!("refs/replace/{second}"), replacement, false, "replace").unwrap();
+    // Replace refs pointing at non-commits, or named after unknown objects, are ignored.
+    repo.reference(&format!("refs/replace/{}", original.tree_id()), original.tree_id(), false, "replace").unwrap();
+    repo.reference("refs/replace/0123456789012345678901234567890123456789", first, false, "replace").unwrap();
+
+    assert_eq!(get_replacements(&repo), HashMap::from([(second, replacement)]));
+
+    repo.config().unwrap().set_bool("core.useReplaceRefs", false).unwrap();
+    assert!(get_replacements(&repo).is_empty());
+    let _ = fs::remove_dir_all(path);
+}
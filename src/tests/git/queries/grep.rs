@@ -0,0 +1,121 @@
+use super::*;
+use git2::Signature;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_repo(name: &str) -> (std::path::PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-grep-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    (path.clone(), Repository::init(&path).unwrap())
+}
+
+fn commit(repo: &Repository, files: &[(&str, &[u8])]) -> Oid {
+    let mut index = repo.index().unwrap();
+    for (file, contents) in files {
+        let full_path = repo.workdir().unwrap().join(file);
+        fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+        fs::write(full_path, contents).unwrap();
+        index.add_path(Path::new(file)).unwrap();
+    }
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    repo.commit(Some("HEAD"), &sig, &sig, "commit", &tree, &parents).unwrap()
+}
+
+fn grep(repo: &Repository, scope: GrepScope, query: &str, is_regex: bool) -> Vec<GrepMatch> {
+    let mut found = Vec::new();
+    grep_files(repo, scope, &GrepPattern::new(query, is_regex).unwrap(), u64::MAX, &AtomicBool::new(false), |result| {
+        found.push(result);
+        true
+    })
+    .unwrap();
+    found
+}
+
+#[test]
+fn literal_and_regex_queries_report_the_line_and_match() {
+    let (path, repo) = temp_repo("queries");
+    let oid = commit(&repo, &[("src/main.rs", b"fn main() {\n\tlet total = 42;\n}\n"), ("notes.txt", b"total: 7\r\n")]);
+
+    let found = grep(&repo, GrepScope::Tree(oid), "total", false);
+    assert_eq!(found.iter().map(|found| (found.path.as_str(), found.line)).collect::<Vec<_>>(), vec![("notes.txt", 1), ("src/main.rs", 2)]);
+    // Tabs are expanded and the match range follows them.
+    assert_eq!(found[1].snippet, "    let total = 42;");
+    assert_eq!(found[1].matched, (8, 13));
+    assert_eq!(found[0].snippet, "total: 7");
+
+    let found = grep(&repo, GrepScope::Tree(oid), r"=\s*\d+", true);
+    assert_eq!(found.len(), 1);
+    assert_eq!(&found[0].snippet[found[0].matched.0..found[0].matched.1], "= 42");
+    // A pattern that only matches the empty string flags nothing.
+    assert!(grep(&repo, GrepScope::Tree(oid), "x*", true).is_empty());
+    assert!(GrepPattern::new("(unclosed", true).is_err());
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn workdir_scope_reads_tracked_files_from_disk_and_skips_binaries() {
+    let (path, repo) = temp_repo("workdir");
+    let oid = commit(&repo, &[("a.txt", b"old needle\n"), ("image.bin", b"needle\0")]);
+    fs::write(path.join("a.txt"), b"first\nnew needle\n").unwrap();
+    fs::write(path.join("untracked.txt"), b"needle\n").unwrap();
+
+    let found = grep(&repo, GrepScope::Workdir, "needle", false);
+    assert_eq!(found.iter().map(|found| (found.path.as_str(), found.line, found.snippet.as_str())).collect::<Vec<_>>(), vec![("a.txt", 2, "new needle")]);
+    let found = grep(&repo, GrepScope::Tree(oid), "needle", false);
+    assert_eq!(found.iter().map(|found| (found.path.as_str(), found.line)).collect::<Vec<_>>(), vec![("a.txt", 1)]);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn long_lines_are_cut_around_the_match() {
+    let line = format!("{}needle{}", "a".repeat(200), "b".repeat(200));
+    let (snippet, matched) = snippet_around(&line, (200, 206));
+    assert_eq!(snippet.chars().count(), SNIPPET_CONTEXT * 2 + 6);
+    assert_eq!(&snippet[matched.0..matched.1], "needle");
+}
+
+#[test]
+fn stopping_or_cancelling_ends_the_walk() {
+    let (path, repo) = temp_repo("cancel");
+    let oid = commit(&repo, &[("a.txt", b"x\nx\n"), ("b.txt", b"x\n")]);
+    let pattern = GrepPattern::new("x", false).unwrap();
+
+    let mut seen = 0;
+    grep_files(&repo, GrepScope::Tree(oid), &pattern, u64::MAX, &AtomicBool::new(false), |_| {
+        seen += 1;
+        false
+    })
+    .unwrap();
+    assert_eq!(seen, 1);
+
+    let mut seen = 0;
+    grep_files(&repo, GrepScope::Workdir, &pattern, u64::MAX, &AtomicBool::new(true), |_| {
+        seen += 1;
+        true
+    })
+    .unwrap();
+    assert_eq!(seen, 0);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn files_over_the_size_limit_are_skipped() {
+    let (path, repo) = temp_repo("size-limit");
+    let oid = commit(&repo, &[("big.txt", b"needle and then some\n"), ("small.txt", b"needle\n")]);
+    let pattern = GrepPattern::new("needle", false).unwrap();
+
+    for scope in [GrepScope::Workdir, GrepScope::Tree(oid)] {
+        let mut paths = Vec::new();
+        grep_files(&repo, scope, &pattern, 8, &AtomicBool::new(false), |result| {
+            paths.push(result.path);
+            true
+        })
+        .unwrap();
+        assert_eq!(paths, vec!["small.txt".to_string()]);
+    }
+    let _ = fs::remove_dir_all(path);
+}
@@ -0,0 +1,108 @@
+use super::*;
+use crate::git::actions::{
+    committing::commit_staged,
+    signing::{SigningConfig, sign_buffer},
+};
+use git2::Signature;
+use std::{
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn temp_repo(name: &str) -> (PathBuf, Repository) {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-signature-query-{name}-{id}"));
+    fs::create_dir_all(&path).unwrap();
+    let repo = Repository::init(&path).unwrap();
+    {
+        let mut config = repo.config().unwrap();
+        config.set_str("user.name", "Test User").unwrap();
+        config.set_str("user.email", "test@example.com").unwrap();
+    }
+    (path, repo)
+}
+
+fn stage(repo: &Repository, file: &str, content: &str) {
+    fs::write(repo.workdir().unwrap().join(file), content).unwrap();
+    let mut index = repo.index().unwrap();
+    index.add_path(Path::new(file)).unwrap();
+    index.write().unwrap();
+}
+
+// Configures ssh signing with a fresh key; `None` when ssh-keygen is not installed.
+fn ssh_signing(path: &Path, repo: &Repository) -> Option<PathBuf> {
+    let key = path.with_extension("key");
+    let status = Command::new("ssh-keygen").args(["-q", "-t", "ed25519", "-N", "", "-C", "test@example.com", "-f"]).arg(&key).stdout(Stdio::null()).stderr(Stdio::null()).status().ok()?;
+    if !status.success() {
+        return None;
+    }
+    let mut config = repo.config().unwrap();
+    config.set_bool("commit.gpgsign", true).unwrap();
+    config.set_str("gpg.format", "ssh").unwrap();
+    config.set_str("user.signingkey", &key.display().to_string()).unwrap();
+    Some(key)
+}
+
+fn trust(path: &Path, repo: &Repository, key: &Path) {
+    let public = fs::read_to_string(key.with_extension("key.pub")).unwrap();
+    let allowed = path.with_extension("allowed");
+    fs::write(&allowed, format!("test@example.com {public}")).unwrap();
+    repo.config().unwrap().set_str("gpg.ssh.allowedSignersFile", &allowed.display().to_string()).unwrap();
+}
+
+fn cleanup(path: &Path) {
+    for extension in ["key", "key.pub", "allowed"] {
+        let _ = fs::remove_file(path.with_extension(extension));
+    }
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn plain_commits_are_unsigned() {
+    let (path, repo) = temp_repo("unsigned");
+    stage(&repo, "file.txt", "base\n");
+    let oid = commit_staged(&repo, "base", "Test User", "test@example.com").unwrap();
+
+    assert_eq!(verify_commit(&repo, oid), SignatureStatus::Unsigned);
+    cleanup(&path);
+}
+
+#[test]
+fn ssh_signatures_need_an_allowed_signer_to_be_good() {
+    let (path, repo) = temp_repo("ssh");
+    let Some(key) = ssh_signing(&path, &repo) else {
+        eprintln!("skipped: ssh-keygen is needed to create a signing key");
+        cleanup(&path);
+        return;
+    };
+    stage(&repo, "file.txt", "base\n");
+    let oid = commit_staged(&repo, "base", "Test User", "test@example.com").unwrap();
+
+    assert_eq!(verify_commit(&repo, oid), SignatureStatus::UnknownKey);
+    trust(&path, &repo, &key);
+    assert_eq!(verify_commit(&repo, oid), SignatureStatus::Good { signer: "test@example.com".to_string() });
+    cleanup(&path);
+}
+
+#[test]
+fn signature_over_other_content_is_bad() {
+    let (path, repo) = temp_repo("bad");
+    let Some(key) = ssh_signing(&path, &repo) else {
+        eprintln!("skipped: ssh-keygen is needed to create a signing key");
+        cleanup(&path);
+        return;
+    };
+    trust(&path, &repo, &key);
+    stage(&repo, "file.txt", "base\n");
+    let tree = repo.find_tree(repo.index().unwrap().write_tree().unwrap()).unwrap();
+    let sig = Signature::now("Test User", "test@example.com").unwrap();
+    let signed = repo.commit_create_buffer(&sig, &sig, "signed", &tree, &[]).unwrap();
+    let tampered = repo.commit_create_buffer(&sig, &sig, "tampered", &tree, &[]).unwrap();
+    let signing = SigningConfig { format: SignFormat::Ssh, key: Some(key.display().to_string()), program: "ssh-keygen".to_string() };
+    let signature = sign_buffer(&signing, signed.as_str().unwrap(), &sig).unwrap();
+    let oid = repo.commit_signed(tampered.as_str().unwrap(), &signature, None).unwrap();
+
+    assert_eq!(verify_commit(&repo, oid), SignatureStatus::Bad);
+    cleanup(&path);
+}
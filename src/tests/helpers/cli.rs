@@ -0,0 +1,106 @@
+use super::*;
+use git2::Repository;
+use std::{
+    env, fs, process,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| value.to_string()).collect()
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    let path = env::temp_dir().join(format!("guitar-cli-{name}-{}-{id}", process::id()));
+    fs::create_dir_all(&path).unwrap();
+    path.canonicalize().unwrap()
+}
+
+fn no_env(_: &str) -> Option<String> {
+    None
+}
+
+#[test]
+fn parses_path_and_repository_flags() {
+    let parsed = parse_args(args(&["~/src/project", "--git-dir", "/tmp/dotfiles", "--work-tree=/home/user", "--reset"])).unwrap();
+
+    assert_eq!(parsed.path.as_deref(), Some("~/src/project"));
+    assert_eq!(parsed.git_dir.as_deref(), Some("/tmp/dotfiles"));
+    assert_eq!(parsed.work_tree.as_deref(), Some("/home/user"));
+    assert!(parsed.is_reset);
+    assert!(!parsed.is_version);
+}
+
+#[test]
+fn rejects_unknown_options_and_missing_values_as_usage_errors() {
+    let unknown = parse_args(args(&["--bogus"])).unwrap_err();
+    assert_eq!(unknown, CliError::Usage("unknown option: --bogus".to_string()));
+    assert_eq!(unknown.exit_code(), EXIT_USAGE);
+
+    assert_eq!(parse_args(args(&["--git-dir"])).unwrap_err().exit_code(), EXIT_USAGE);
+    assert_eq!(parse_args(args(&["one", "two"])).unwrap_err().exit_code(), EXIT_USAGE);
+}
+
+#[test]
+fn discovers_the_repository_root_from_a_subdirectory() {
+    let path = temp_dir("discover");
+    Repository::init(&path).unwrap();
+    let nested = path.join("src/app");
+    fs::create_dir_all(&nested).unwrap();
+
+    let parsed = CliArgs { path: Some(nested.display().to_string()), ..Default::default() };
+    assert_eq!(resolve_repository(&parsed, no_env).unwrap(), Some(RepoTarget { path: path.clone(), work_tree: None }));
+    assert_eq!(resolve_repository(&CliArgs::default(), no_env).unwrap(), None);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn missing_repository_exits_like_git() {
+    let path = temp_dir("missing");
+
+    let parsed = CliArgs { path: Some(path.display().to_string()), ..Default::default() };
+    let error = resolve_repository(&parsed, no_env).unwrap_err();
+    assert!(matches!(error, CliError::NotARepository(_)));
+    assert_eq!(error.exit_code(), EXIT_NOT_A_REPOSITORY);
+
+    let parsed = CliArgs { git_dir: Some(path.join("nope").display().to_string()), ..Default::default() };
+    assert_eq!(resolve_repository(&parsed, no_env).unwrap_err().exit_code(), EXIT_NOT_A_REPOSITORY);
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn git_dir_and_work_tree_come_from_the_environment() {
+    let path = temp_dir("bare");
+    let git_dir = path.join("dotfiles.git");
+    let home = path.join("home");
+    fs::create_dir_all(&home).unwrap();
+    Repository::init_bare(&git_dir).unwrap();
+    let env = |key: &str| match key {
+        "GIT_DIR" => Some(git_dir.display().to_string()),
+        "GIT_WORK_TREE" => Some(home.display().to_string()),
+        _ => None,
+    };
+
+    let target = resolve_repository(&CliArgs::default(), env).unwrap().unwrap();
+    assert_eq!(target, RepoTarget { path: git_dir.clone(), work_tree: Some(home.clone()) });
+    let repo = open_repo(&target.path, target.work_tree.as_deref()).unwrap();
+    assert_eq!(repo.workdir().map(|workdir| workdir.canonicalize().unwrap()), Some(home.clone()));
+
+    // Explicit flags override the environment.
+    let parsed = CliArgs { work_tree: Some(path.display().to_string()), ..Default::default() };
+    assert_eq!(resolve_repository(&parsed, env).unwrap().unwrap().work_tree, Some(path.clone()));
+    let _ = fs::remove_dir_all(path);
+}
+
+#[test]
+fn log_subcommand_parses_export_flags() {
+    let parsed = parse_args(args(&["log", "repo", "--format=json", "--limit", "20", "--symbols", "ascii"])).unwrap();
+    let log = parsed.log.clone().unwrap();
+    assert_eq!(parsed.path.as_deref(), Some("repo"));
+    assert_eq!((log.format, log.limit, log.symbols.as_deref()), (ExportFormat::Json, Some(20), Some("ascii")));
+
+    // Export flags mean nothing to the interactive app.
+    assert_eq!(parse_args(args(&["--limit", "5"])).unwrap_err(), CliError::Usage("unknown option: --limit".to_string()));
+    assert_eq!(parse_args(args(&["log", "--format", "xml"])).unwrap_err().exit_code(), EXIT_USAGE);
+    assert_eq!(parse_args(args(&["log", "--limit", "many"])).unwrap_err().exit_code(), EXIT_USAGE);
+}
@@ -0,0 +1,72 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_config_path(name: &str) -> PathBuf {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    std::env::temp_dir().join(format!("guitar-custom-commands-{name}-{id}.json"))
+}
+
+fn values() -> CustomCommandValues {
+    CustomCommandValues {
+        sha: Some("0123456789abcdef0123456789abcdef01234567".to_string()),
+        branch: Some("feature/it's".to_string()),
+        path: None,
+        repo: Some("/work/my repo".to_string()),
+        remote_url: Some("git@example.com:team/repo.git".to_string()),
+    }
+}
+
+#[test]
+fn template_substitutes_quoted_placeholder_values() {
+    let expanded = expand_template("review {short_sha} --branch={branch} -C {repo} {remote_url}", &values()).unwrap();
+
+    assert_eq!(expanded, r"review '0123456' --branch='feature/it'\''s' -C '/work/my repo' 'git@example.com:team/repo.git'");
+}
+
+#[cfg(windows)]
+#[test]
+fn windows_quoting_keeps_percent_signs_away_from_cmd_expansion() {
+    assert_eq!(shell_quote(r#"50% "off" %PATH%"#), r#""50"^%" ""off"" "^%"PATH"^%"""#);
+}
+
+#[test]
+fn template_reports_a_placeholder_without_a_value_and_keeps_unknown_braces() {
+    assert_eq!(expand_template("less {path}", &values()), Err("{path}".to_string()));
+    assert_eq!(expand_template("awk '{print $1}' {", &values()), Ok("awk '{print $1}' {".to_string()));
+}
+
+#[test]
+fn config_loads_with_defaults_and_tolerates_bad_files() {
+    let path = temp_config_path("load");
+    fs::write(
+        &path,
+        r#"{"commands": [
+            {"name": "Review", "command": "open https://review/{sha}", "context": "Graph", "output": "Background"},
+            {"name": "Test", "command": "make test"}
+        ]}"#,
+    )
+    .unwrap();
+
+    let commands = load_custom_commands_from_path(&path);
+    assert_eq!(commands.len(), 2);
+    assert_eq!((commands[0].context, commands[0].output), (CustomCommandContext::Graph, CustomCommandOutput::Background));
+    assert_eq!((commands[1].context, commands[1].output), (CustomCommandContext::Any, CustomCommandOutput::Modal));
+
+    fs::write(&path, "not json").unwrap();
+    assert!(load_custom_commands_from_path(&path).is_empty());
+    let _ = fs::remove_file(&path);
+    assert!(load_custom_commands_from_path(&path).is_empty());
+}
+
+#[cfg(unix)]
+#[test]
+fn run_streams_output_and_fails_on_non_zero_exit() {
+    let log = ProcessLog::default();
+    let run = CustomCommandRun { name: "Check".to_string(), script: "echo out; echo err >&2; exit 3".to_string(), cwd: std::env::temp_dir() };
+
+    let error = run.run(&log).unwrap_err();
+    assert_eq!(error.message(), "Check failed with exit code 3");
+    let mut lines = log.lock().unwrap().clone();
+    lines.sort();
+    assert_eq!(lines, vec!["err".to_string(), "out".to_string()]);
+}
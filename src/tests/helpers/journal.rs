@@ -0,0 +1,50 @@
+use super::*;
+use crate::{
+    git::actions::journal::{JournalRef, JournalStash},
+    helpers::keymap::Command,
+};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_config_path(name: &str) -> PathBuf {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    std::env::temp_dir().join(format!("guitar-journal-{name}-{id}.json"))
+}
+
+fn entry(time: i64) -> JournalEntry {
+    JournalEntry {
+        operation: Command::HardReset,
+        time,
+        head_before: "refs/heads/main".to_string(),
+        head_after: "refs/heads/main".to_string(),
+        refs: vec![JournalRef { name: "refs/heads/topic".to_string(), before: Some("a".repeat(40)), after: None }],
+        dropped_stashes: vec![JournalStash { oid: "b".repeat(40), message: "On main: wip".to_string() }],
+    }
+}
+
+#[test]
+fn journal_saves_and_loads_per_repository() {
+    let path = temp_config_path("per-repo");
+
+    save_journal_to_path(&path, "/repo/a", &[entry(1), entry(2)]);
+    save_journal_to_path(&path, "/repo/b", &[entry(3)]);
+
+    assert_eq!(load_journal_from_path(&path, "/repo/a"), vec![entry(1), entry(2)]);
+    assert_eq!(load_journal_from_path(&path, "/repo/b"), vec![entry(3)]);
+    assert!(load_journal_from_path(&path, "/repo/c").is_empty());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn journal_keeps_only_the_newest_entries() {
+    let path = temp_config_path("cap");
+    let entries: Vec<JournalEntry> = (0..MAX_JOURNAL_ENTRIES as i64 + 5).map(entry).collect();
+
+    save_journal_to_path(&path, "/repo/a", &entries);
+
+    let loaded = load_journal_from_path(&path, "/repo/a");
+    assert_eq!(loaded.len(), MAX_JOURNAL_ENTRIES);
+    assert_eq!(loaded.first().map(|entry| entry.time), Some(5));
+    save_journal_to_path(&path, "/repo/a", &[]);
+    assert!(load_journal_from_path(&path, "/repo/a").is_empty());
+    let _ = fs::remove_file(path);
+}
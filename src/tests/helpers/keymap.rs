@@ -1,4 +1,5 @@
 use super::*;
+use std::collections::HashSet;
 
 #[test]
 fn defaults_include_recent_repository_bindings() {
@@ -58,6 +59,8 @@ fn defaults_include_shift_numeric_graph_metadata_toggles() {
         assert_eq!(mode_map.get(&KeyBinding::new(Char('2'), KeyModifiers::SHIFT)), Some(&Command::ToggleGraphDates));
         assert_eq!(mode_map.get(&KeyBinding::new(Char('3'), KeyModifiers::SHIFT)), Some(&Command::ToggleGraphCommitters));
         assert_eq!(mode_map.get(&KeyBinding::new(Char('4'), KeyModifiers::SHIFT)), Some(&Command::ToggleGraphRefs));
+        assert_eq!(mode_map.get(&KeyBinding::new(Char('5'), KeyModifiers::SHIFT)), Some(&Command::ToggleGraphSignatures));
+        assert_eq!(mode_map.get(&KeyBinding::new(Char('6'), KeyModifiers::SHIFT)), Some(&Command::ToggleOriginalParents));
     }
 }
 
@@ -81,6 +84,8 @@ fn command_lookup_falls_back_from_shifted_punctuation_to_shift_digits() {
     assert_eq!(command_for_key_binding(normal, &KeyBinding::new(Char('@'), KeyModifiers::NONE)), Some(Command::ToggleGraphDates));
     assert_eq!(command_for_key_binding(normal, &KeyBinding::new(Char('#'), KeyModifiers::NONE)), Some(Command::ToggleGraphCommitters));
     assert_eq!(command_for_key_binding(normal, &KeyBinding::new(Char('$'), KeyModifiers::NONE)), Some(Command::ToggleGraphRefs));
+    assert_eq!(command_for_key_binding(normal, &KeyBinding::new(Char('%'), KeyModifiers::NONE)), Some(Command::ToggleGraphSignatures));
+    assert_eq!(command_for_key_binding(normal, &KeyBinding::new(Char('^'), KeyModifiers::NONE)), Some(Command::ToggleOriginalParents));
 }
 
 #[test]
@@ -272,9 +277,10 @@ fn existing_keymaps_rewrite_untouched_old_numeric_ui_defaults() {
         assert_eq!(mode_map.get(&KeyBinding::new(Char('9'), KeyModifiers::NONE)), Some(&Command::ToggleStatus));
         assert_eq!(mode_map.get(&KeyBinding::new(Char('1'), KeyModifiers::SHIFT)), Some(&Command::ToggleShas));
         assert_eq!(mode_map.get(&KeyBinding::new(Char('4'), KeyModifiers::SHIFT)), Some(&Command::ToggleGraphRefs));
-        assert_eq!(mode_map.get(&KeyBinding::new(Char('\\'), KeyModifiers::NONE)), None);
         assert_eq!(mode_map.get(&KeyBinding::new(Char('`'), KeyModifiers::NONE)), None);
     }
+    assert_eq!(maps.get(&InputMode::Normal).unwrap().get(&KeyBinding::new(Char('\\'), KeyModifiers::NONE)), Some(&Command::Leader));
+    assert_eq!(maps.get(&InputMode::Action).unwrap().get(&KeyBinding::new(Char('\\'), KeyModifiers::NONE)), None);
 }
 
 #[test]
@@ -303,6 +309,31 @@ fn existing_keymaps_rewrite_untouched_ctrl_graph_metadata_defaults() {
     }
 }
 
+#[test]
+fn existing_keymaps_move_untouched_mark_and_jump_defaults_off_hunk_mode_and_tab() {
+    let mut maps = IndexMap::new();
+    let mut normal = IndexMap::new();
+    normal.insert(KeyBinding::new(Char('m'), KeyModifiers::NONE), Command::ToggleHunkMode);
+    normal.insert(KeyBinding::new(Char('M'), KeyModifiers::SHIFT), Command::SetMark);
+    normal.insert(KeyBinding::new(Char('i'), KeyModifiers::CONTROL), Command::JumpForward);
+    let mut older = IndexMap::new();
+    older.insert(KeyBinding::new(Char('m'), KeyModifiers::NONE), Command::ToggleHunkMode);
+    maps.insert(InputMode::Normal, normal);
+    maps.insert(InputMode::Action, IndexMap::new());
+    let mut older_maps = IndexMap::from([(InputMode::Normal, older)]);
+
+    assert!(ensure_default_keymap_bindings(&mut maps));
+    assert!(ensure_default_keymap_bindings(&mut older_maps));
+
+    for normal in [maps.get(&InputMode::Normal).unwrap(), older_maps.get(&InputMode::Normal).unwrap()] {
+        assert_eq!(normal.get(&KeyBinding::new(Char('m'), KeyModifiers::NONE)), Some(&Command::SetMark));
+        assert_eq!(normal.get(&KeyBinding::new(Char('M'), KeyModifiers::SHIFT)), Some(&Command::ToggleHunkMode));
+        assert_eq!(normal.get(&KeyBinding::new(Char('f'), KeyModifiers::CONTROL)), Some(&Command::JumpForward));
+        assert_eq!(normal.get(&KeyBinding::new(Char('i'), KeyModifiers::CONTROL)), None);
+        assert_eq!(normal.get(&KeyBinding::new(Char(';'), KeyModifiers::NONE)), Some(&Command::Count));
+    }
+}
+
 #[test]
 fn defaults_include_keyboard_resize_bindings() {
     let maps = default_keymaps();
@@ -571,3 +602,185 @@ fn keymap_config_serialization_stays_english_while_visual_labels_localise() {
 
     crate::helpers::localisation::set_active_language(crate::helpers::localisation::Language::English);
 }
+
+#[test]
+fn commands_list_every_bound_command_once() {
+    let unique: HashSet<&Command> = COMMANDS.iter().collect();
+    assert_eq!(unique.len(), COMMANDS.len());
+
+    for mode_map in default_keymaps().values() {
+        assert!(mode_map.values().all(|command| unique.contains(command)));
+    }
+    assert_eq!(default_keymaps().get(&InputMode::Normal).unwrap().get(&KeyBinding::new(Char(':'), KeyModifiers::NONE)), Some(&Command::CommandPalette));
+}
+
+// The variants come from the enum's reflected shape, so a new command cannot be left out of COMMANDS.
+#[test]
+fn commands_cover_every_built_in_command() {
+    let facet::Type::User(facet::UserType::Enum(commands)) = Command::SHAPE.ty else {
+        panic!("Command is not an enum");
+    };
+    let built_in: Vec<_> = commands.variants.iter().filter(|variant| variant.data.fields.is_empty()).collect();
+
+    for variant in &built_in {
+        let command: Command = facet_json::from_str(&format!("\"{}\"", variant.name)).unwrap();
+        assert!(COMMANDS.contains(&command), "{} is missing from COMMANDS", variant.name);
+    }
+    assert_eq!(built_in.len(), COMMANDS.len());
+}
+
+#[test]
+fn custom_command_bindings_round_trip_through_keymap_json() {
+    let id = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-keymap-custom-{id}.json"));
+    let mut maps = default_keymaps();
+    maps.get_mut(&InputMode::Normal).unwrap().insert(KeyBinding::new(Char('o'), KeyModifiers::ALT), Command::Custom("Review".to_string()));
+
+    save_keymaps_to_path(&path, &maps).unwrap();
+    let loaded = load_keymaps_from_path(&path).unwrap();
+
+    assert_eq!(loaded.get(&InputMode::Normal).unwrap().get(&KeyBinding::new(Char('o'), KeyModifiers::ALT)), Some(&Command::Custom("Review".to_string())));
+    assert_eq!(command_to_visual_string(&Command::Custom("Review".to_string())), "Review");
+    let _ = fs::remove_file(path);
+}
+
+fn keys(text: &str) -> Vec<KeyBinding> {
+    key_sequence(text).keys()
+}
+
+#[test]
+fn key_sequences_match_prefixes_and_complete_bindings() {
+    let maps = default_keymaps();
+    let normal = maps.get(&InputMode::Normal).unwrap();
+    let g = KeyBinding::new(Char('g'), KeyModifiers::NONE);
+    let f = KeyBinding::new(Char('f'), KeyModifiers::NONE);
+
+    assert_eq!(match_key_sequence(normal, &[], &g), KeySequenceMatch::Prefix(None));
+    assert_eq!(match_key_sequence(normal, &keys("g"), &g), KeySequenceMatch::Command(Command::GoToBeginning));
+    assert_eq!(match_key_sequence(normal, &keys("g"), &KeyBinding::new(Char('x'), KeyModifiers::NONE)), KeySequenceMatch::None);
+    assert_eq!(match_key_sequence(normal, &[], &KeyBinding::new(Char('\\'), KeyModifiers::NONE)), KeySequenceMatch::Prefix(Some(Command::Leader)));
+    assert_eq!(match_key_sequence(normal, &keys("\\"), &f), KeySequenceMatch::Prefix(None));
+    assert_eq!(match_key_sequence(normal, &keys("\\f"), &f), KeySequenceMatch::Command(Command::FindFile));
+    assert_eq!(match_key_sequence(normal, &[], &f), KeySequenceMatch::Command(Command::FetchAll));
+    assert_eq!(keybinding_to_visual_string(&key_sequence("\\ff")), "\\ f f");
+}
+
+#[test]
+fn continuations_list_next_keys_with_their_command_or_sequence_count() {
+    let maps = default_keymaps();
+    let continuations = key_sequence_continuations(maps.get(&InputMode::Normal).unwrap(), &keys("\\"));
+
+    assert_eq!(continuations[0], KeyContinuation { key: KeyBinding::new(Char('f'), KeyModifiers::NONE), command: None, sequences: 3 });
+    assert!(continuations.contains(&KeyContinuation { key: KeyBinding::new(Char('p'), KeyModifiers::NONE), command: Some(Command::CommandPalette), sequences: 0 }));
+    assert!(continuations.contains(&KeyContinuation { key: KeyBinding::new(Char('t'), KeyModifiers::NONE), command: None, sequences: 2 }));
+    assert_eq!(continuations.len(), 5);
+}
+
+#[test]
+fn rebind_keymap_selection_blocks_prefix_conflicts() {
+    let mut maps = IndexMap::new();
+    let mut normal = IndexMap::new();
+    normal.insert(key_sequence("gg"), Command::GoToBeginning);
+    normal.insert(KeyBinding::new(Char('j'), KeyModifiers::NONE), Command::ScrollDown);
+    maps.insert(InputMode::Normal, normal);
+    maps.insert(InputMode::Action, IndexMap::new());
+    let before = maps.clone();
+    let selection = KeymapSelection::new(InputMode::Normal, KeyBinding::new(Char('j'), KeyModifiers::NONE), Command::ScrollDown);
+    let conflict = Err(KeymapEditError::PrefixConflict { mode: InputMode::Normal, key: key_sequence("gg"), command: Command::GoToBeginning });
+
+    assert_eq!(rebind_keymap_selection(&mut maps, &selection, KeyBinding::new(Char('g'), KeyModifiers::NONE)), conflict);
+    assert_eq!(rebind_keymap_selection(&mut maps, &selection, key_sequence("ggj")), conflict);
+    assert_eq!(maps, before);
+
+    rebind_keymap_selection(&mut maps, &selection, key_sequence("gj")).unwrap();
+    assert_eq!(maps.get(&InputMode::Normal).unwrap().get(&key_sequence("gj")), Some(&Command::ScrollDown));
+}
+
+#[test]
+fn rebinding_the_leader_moves_the_sequences_it_starts() {
+    let mut maps = default_keymaps();
+    let leader = KeyBinding::new(Char('\\'), KeyModifiers::NONE);
+
+    let outcome = rebind_keymap_selection(&mut maps, &KeymapSelection::new(InputMode::Normal, leader, Command::Leader), KeyBinding::new(Char(','), KeyModifiers::NONE)).unwrap();
+
+    // Action mode has no leader to keep in step.
+    assert!(!outcome.synced_action);
+    let normal = maps.get(&InputMode::Normal).unwrap();
+    assert_eq!(normal.get(&key_sequence(",ff")), Some(&Command::FindFile));
+    assert_eq!(normal.get(&key_sequence("\\ff")), None);
+    assert!(!maps.get(&InputMode::Action).unwrap().keys().any(|key| key.prefix.first().is_some_and(|first| first.code == Char(',') || first.code == Char('\\'))));
+}
+
+#[test]
+fn leader_sequences_round_trip_through_keymap_json() {
+    let id = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_nanos();
+    let path = std::env::temp_dir().join(format!("guitar-keymap-sequences-{id}.json"));
+    let maps = default_keymaps();
+
+    save_keymaps_to_path(&path, &maps).unwrap();
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(contents.contains("\"key\": \"Leader\""), "{contents}");
+    assert_eq!(contents.matches("\"prefix\"").count(), 14, "{contents}");
+    assert_eq!(load_keymaps_from_path(&path).unwrap(), maps);
+
+    // A hand-written leader carries the sequences that name it.
+    let written = r#"{"normal": [
+        {"key": "Char(,)", "modifiers": [], "command": "Leader"},
+        {"key": "Char(j)", "modifiers": [], "prefix": [{"key": "Leader"}], "command": "ToggleJournal"}
+    ], "action": []}"#;
+    fs::write(&path, written).unwrap();
+    let loaded = load_keymaps_from_path(&path).unwrap();
+    assert_eq!(loaded.get(&InputMode::Normal).unwrap().get(&key_sequence(",j")), Some(&Command::ToggleJournal));
+
+    // Without a leader binding there is nothing for "Leader" to stand for.
+    fs::write(&path, written.replace("\"command\": \"Leader\"", "\"command\": \"Exit\"")).unwrap();
+    assert!(load_keymaps_from_path(&path).is_err());
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn existing_keymaps_gain_the_leader_with_its_sequences_only_when_it_is_free() {
+    let mut maps = IndexMap::new();
+    maps.insert(InputMode::Normal, IndexMap::from([(KeyBinding::new(Char('j'), KeyModifiers::NONE), Command::ScrollDown)]));
+    maps.insert(InputMode::Action, IndexMap::from([(KeyBinding::new(Char('\\'), KeyModifiers::NONE), Command::Pop)]));
+
+    assert!(ensure_default_keymap_bindings(&mut maps));
+    let normal = maps.get(&InputMode::Normal).unwrap();
+    assert_eq!(normal.get(&KeyBinding::new(Char('\\'), KeyModifiers::NONE)), Some(&Command::Leader));
+    assert_eq!(normal.get(&key_sequence("\\p")), Some(&Command::CommandPalette));
+    let action = maps.get(&InputMode::Action).unwrap();
+    assert_eq!(action.get(&KeyBinding::new(Char('\\'), KeyModifiers::NONE)), Some(&Command::Pop));
+    assert!(!action.keys().any(KeyBinding::is_sequence));
+}
+
+#[test]
+fn existing_keymaps_gain_tab_sequences_under_their_own_leader() {
+    let mut maps = IndexMap::new();
+    let leader = KeyBinding::new(Char(','), KeyModifiers::NONE);
+    maps.insert(InputMode::Normal, IndexMap::from([(leader.clone(), Command::Leader), (KeyBinding::new(Char('g'), KeyModifiers::NONE), Command::GoToBeginning)]));
+    maps.insert(InputMode::Action, IndexMap::from([(leader.clone(), Command::Leader), (KeyBinding::new(Char('g'), KeyModifiers::NONE), Command::GoToBeginning)]));
+
+    assert!(ensure_default_keymap_bindings(&mut maps));
+    let normal = maps.get(&InputMode::Normal).unwrap();
+    assert_eq!(normal.get(&key_sequence(",tn")), Some(&Command::NewTab));
+    assert_eq!(normal.get(&key_sequence(",tc")), Some(&Command::CloseTab));
+    assert_eq!(normal.get(&key_sequence(",fg")), Some(&Command::SearchContents));
+    assert_eq!(normal.get(&key_sequence("\\tn")), None);
+    // The old single 'g' becomes 'g g', which frees 'g t' for tab cycling.
+    assert_eq!(normal.get(&KeyBinding::new(Char('g'), KeyModifiers::NONE)), None);
+    assert_eq!(normal.get(&key_sequence("gg")), Some(&Command::GoToBeginning));
+    assert_eq!(normal.get(&key_sequence("gt")), Some(&Command::NextTab));
+    // Action mode loses the leader it picked up, and keeps its own 'g g'.
+    let action = maps.get(&InputMode::Action).unwrap();
+    assert_eq!(action.get(&leader), None);
+    assert_eq!(action.get(&key_sequence("gg")), Some(&Command::GoToBeginning));
+
+    // 'g' bound to something else is the user's choice, so it stays and shadows the tab keys.
+    let mut maps = IndexMap::new();
+    maps.insert(InputMode::Normal, IndexMap::from([(leader, Command::Leader), (KeyBinding::new(Char('g'), KeyModifiers::NONE), Command::GoToEnd)]));
+    maps.insert(InputMode::Action, IndexMap::new());
+    ensure_default_keymap_bindings(&mut maps);
+    let normal = maps.get(&InputMode::Normal).unwrap();
+    assert_eq!(normal.get(&KeyBinding::new(Char('g'), KeyModifiers::NONE)), Some(&Command::GoToEnd));
+    assert!(!normal.values().any(|command| matches!(command, Command::NextTab | Command::PreviousTab)));
+}
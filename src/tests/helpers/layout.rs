@@ -35,6 +35,7 @@ fn layout_config_reads_old_boolean_only_config() {
     assert!(!config.is_graph_dates);
     assert!(!config.is_graph_committers);
     assert!(config.is_graph_refs);
+    assert!(!config.is_graph_original_parents);
     assert_eq!(config.weight_viewer_split_left, LAYOUT_WEIGHT_DEFAULT);
     assert_eq!(config.weight_viewer_split_right, LAYOUT_WEIGHT_DEFAULT);
     assert_eq!(config.graph_lane_limit, GRAPH_LANE_LIMIT_DEFAULT);
@@ -0,0 +1,44 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_config_path(name: &str) -> PathBuf {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    std::env::temp_dir().join(format!("guitar-marks-{name}-{id}.json"))
+}
+
+fn oid(byte: char) -> Oid {
+    Oid::from_str(&byte.to_string().repeat(40)).unwrap()
+}
+
+#[test]
+fn marks_save_and_load_per_repository() {
+    let path = temp_config_path("per-repo");
+    let marks = Marks::from([('a', oid('1')), ('B', oid('2'))]);
+
+    save_marks_to_path(&path, "/repo/a", &marks);
+    save_marks_to_path(&path, "/repo/b", &Marks::from([('a', oid('3'))]));
+
+    assert_eq!(load_marks_from_path(&path, "/repo/a"), marks);
+    assert_eq!(load_marks_from_path(&path, "/repo/b"), Marks::from([('a', oid('3'))]));
+    assert!(load_marks_from_path(&path, "/repo/c").is_empty());
+
+    save_marks_to_path(&path, "/repo/a", &Marks::new());
+    assert!(load_marks_from_path(&path, "/repo/a").is_empty());
+    assert!(!fs::read_to_string(&path).unwrap().contains("/repo/a"));
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn malformed_mark_entries_are_skipped() {
+    let path = temp_config_path("malformed");
+    let contents = format!(
+        r#"{{"repositories":[{{"path":"/repo","marks":[{{"name":"a","oid":"{}"}},{{"name":"ab","oid":"{}"}},{{"name":"1","oid":"{}"}},{{"name":"c","oid":"nope"}}]}}]}}"#,
+        oid('1'),
+        oid('2'),
+        oid('3')
+    );
+    fs::write(&path, contents).unwrap();
+
+    assert_eq!(load_marks_from_path(&path, "/repo"), Marks::from([('a', oid('1'))]));
+    let _ = fs::remove_file(path);
+}
@@ -0,0 +1,18 @@
+use super::*;
+
+#[cfg(unix)]
+#[test]
+fn output_streams_into_the_log_and_exit_codes_fail() {
+    let log = ProcessLog::default();
+    let mut command = Command::new("sh");
+    command.args(["-c", "read line; echo \"got $line\"; echo oops >&2; exit 3"]);
+
+    let error = run_logged(command, "the check", Some(b"input\n".to_vec()), &log).unwrap_err();
+    assert_eq!(error.message(), "the check failed with exit code 3");
+    let mut lines = log.lock().unwrap().clone();
+    lines.sort();
+    assert_eq!(lines, vec!["got input".to_string(), "oops".to_string()]);
+
+    let error = run_logged(Command::new("guitar-no-such-program"), "the check", None, &log).unwrap_err();
+    assert!(error.message().starts_with("could not run the check:"));
+}
@@ -0,0 +1,54 @@
+use super::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn temp_config_path(name: &str) -> PathBuf {
+    let id = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+    std::env::temp_dir().join(format!("guitar-session-{name}-{id}.json"))
+}
+
+#[test]
+fn sessions_save_and_load_per_repository() {
+    let path = temp_config_path("per-repo");
+    let session = RepositorySession {
+        path: "/repo/a".to_string(),
+        oid: Some("1".repeat(40)),
+        offset: 3,
+        pane: SessionPane::Viewer,
+        file_name: Some("src/lib.rs".to_string()),
+        viewer_mode: SessionViewerMode::Split,
+        branches_selected: 2,
+        tags_selected: 1,
+        stashes_selected: 4,
+        branch_name: Some("main".to_string()),
+        tag_name: Some("v1.0".to_string()),
+        stash_oid: Some("2".repeat(40)),
+    };
+
+    save_session_to_path(&path, &session);
+    save_session_to_path(&path, &RepositorySession { path: "/repo/b".to_string(), ..Default::default() });
+
+    assert_eq!(load_session_from_path(&path, "/repo/a"), Some(session.clone()));
+    assert_eq!(load_session_from_path(&path, "/repo/b").map(|entry| entry.pane), Some(SessionPane::Graph));
+    assert_eq!(load_session_from_path(&path, "/repo/c"), None);
+
+    // Saving again replaces the entry instead of adding a second one.
+    save_session_to_path(&path, &RepositorySession { offset: 0, ..session });
+    let contents = fs::read_to_string(&path).unwrap();
+    assert_eq!(contents.matches("/repo/a").count(), 1, "{contents}");
+    assert_eq!(load_session_from_path(&path, "/repo/a").map(|entry| entry.offset), Some(0));
+    let _ = fs::remove_file(path);
+}
+
+#[test]
+fn sessions_with_missing_fields_load_with_defaults() {
+    let path = temp_config_path("defaults");
+    fs::write(&path, r#"{"repositories":[{"path":"/repo","pane":"Tags"}]}"#).unwrap();
+
+    let session = load_session_from_path(&path, "/repo").unwrap();
+
+    assert_eq!(session.pane, SessionPane::Tags);
+    assert_eq!(session.oid, None);
+    assert_eq!(session.viewer_mode, SessionViewerMode::Full);
+    assert_eq!(session.branch_name, None);
+    let _ = fs::remove_file(path);
+}